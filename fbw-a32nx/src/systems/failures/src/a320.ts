@@ -25,6 +25,8 @@ export const A320Failure = Object.freeze({
   OutflowValve: 21017,
   SafetyValve: 21018,
   RapidDecompression: 21019,
+  AvionicsBlowerFan: 21020,
+  AvionicsExtractFan: 21021,
 
   Fac1Failure: 22000,
   Fac2Failure: 22001,
@@ -40,6 +42,12 @@ export const A320Failure = Object.freeze({
   Generator1: 24020,
   Generator2: 24021,
   ApuGenerator1: 24030,
+  Generator1VoltageRegulator: 24040,
+  Generator2VoltageRegulator: 24041,
+  ApuGenerator1VoltageRegulator: 24044,
+  Generator1FeederFault: 24050,
+  Generator2FeederFault: 24051,
+  ApuGenerator1FeederFault: 24054,
   AlternatingCurrent1: 24100,
   AlternatingCurrent2: 24101,
   AlternatingCurrentEssential: 24102,
@@ -55,6 +63,19 @@ export const A320Failure = Object.freeze({
   DirectCurrentHot2: 24112,
   DirectCurrentGndFltService: 24113,
 
+  Engine1Fire: 26001,
+  Engine2Fire: 26002,
+  APUFire: 26003,
+  CargoFire: 26004,
+  Engine1LoopA: 26005,
+  Engine1LoopB: 26006,
+  Engine2LoopA: 26007,
+  Engine2LoopB: 26008,
+  APULoopA: 26009,
+  APULoopB: 26010,
+  CargoSmokeDetectorLoopA: 26011,
+  CargoSmokeDetectorLoopB: 26012,
+
   Elac1Failure: 27000,
   Elac2Failure: 27001,
   Sec1Failure: 27002,
@@ -63,6 +84,26 @@ export const A320Failure = Object.freeze({
   Fcdc1Failure: 27005,
   Fcdc2Failure: 27006,
 
+  LeftTankPump1: 28000,
+  LeftTankPump2: 28001,
+  RightTankPump1: 28002,
+  RightTankPump2: 28003,
+  ApuFuelPump: 28004,
+  Engine1LpValve: 28010,
+  Engine2LpValve: 28011,
+  CrossfeedValve: 28012,
+  LeftOuterTankTransferValve: 28013,
+  RightOuterTankTransferValve: 28014,
+  LeftCenterTankTransferValve: 28015,
+  RightCenterTankTransferValve: 28016,
+  CenterTankLeak: 28020,
+  LeftInnerTankLeak: 28021,
+  LeftOuterTankLeak: 28022,
+  RightInnerTankLeak: 28023,
+  RightOuterTankLeak: 28024,
+  FqiChannel1: 28030,
+  FqiChannel2: 28031,
+
   GreenReservoirLeak: 29000,
   BlueReservoirLeak: 29001,
   YellowReservoirLeak: 29002,
@@ -77,6 +118,29 @@ export const A320Failure = Object.freeze({
   YellowEdpOverheat: 29011,
   YellowEpumpOverheat: 29012,
 
+  CaptPitotHeater: 30011,
+  CaptLeftStaticHeater: 30012,
+  CaptRightStaticHeater: 30013,
+  CaptTatHeater: 30014,
+  CaptAoaHeater: 30015,
+  FoPitotHeater: 30021,
+  FoLeftStaticHeater: 30022,
+  FoRightStaticHeater: 30023,
+  FoTatHeater: 30024,
+  FoAoaHeater: 30025,
+  StbyPitotHeater: 30031,
+  StbyLeftStaticHeater: 30032,
+  StbyRightStaticHeater: 30033,
+  StbyAoaHeater: 30035,
+  CaptWindshieldHeater: 30041,
+  CaptWindowHeater: 30042,
+  CaptWindowHeatRegulation: 30043,
+  FoWindshieldHeater: 30051,
+  FoWindowHeater: 30052,
+  FoWindowHeatRegulation: 30053,
+  Eng1AntiIceValve: 30061,
+  Eng2AntiIceValve: 30062,
+
   LeftPfdDisplay: 31000,
   RightPfdDisplay: 31001,
   LeftNdDisplay: 31002,
@@ -121,6 +185,53 @@ export const A320Failure = Object.freeze({
   RadioAntennaInterrupted2: 34011,
   RadioAntennaDirectCoupling1: 34020,
   RadioAntennaDirectCoupling2: 34021,
+  InertialReference1: 34030,
+  InertialReference2: 34031,
+  InertialReference3: 34032,
+  GpsReceiver1: 34040,
+  GpsReceiver2: 34041,
+
+  CrewOxygenBottleLeak: 35000,
+  CrewOxygenRegulatorLowPressure: 35010,
+
+  WingBleedLeak1: 36000,
+  WingBleedLeak2: 36001,
+  PylonBleedLeak1: 36010,
+  PylonBleedLeak2: 36011,
+  ApuBleedLeak: 36020,
+  WingLeakDetectionLoop1: 36100,
+  WingLeakDetectionLoop2: 36101,
+  PylonLeakDetectionLoop1: 36110,
+  PylonLeakDetectionLoop2: 36111,
+  ApuLeakDetectionLoop: 36120,
+  HpValveJammedOpen1: 36200,
+  HpValveJammedOpen2: 36201,
+  HpValveJammedClosed1: 36210,
+  HpValveJammedClosed2: 36211,
+  PrvValveJammedOpen1: 36220,
+  PrvValveJammedOpen2: 36221,
+  PrvValveJammedClosed1: 36230,
+  PrvValveJammedClosed2: 36231,
+  FavValveJammedOpen1: 36240,
+  FavValveJammedOpen2: 36241,
+  FavValveJammedClosed1: 36250,
+  FavValveJammedClosed2: 36251,
+  PrecoolerBlockage1: 36300,
+  PrecoolerBlockage2: 36301,
+  BleedOverpressure1: 36310,
+  BleedOverpressure2: 36311,
+  BleedOvertemperature1: 36320,
+  BleedOvertemperature2: 36321,
+  Bmc1: 36400,
+  Bmc2: 36401,
+
+  ApuStartMotor: 49000,
+  ApuFuelControlUnit: 49010,
+  ApuAirIntakeFlapJammed: 49020,
+  ApuEgtSensor: 49030,
+  ApuSpeedSensor1: 49040,
+  ApuSpeedSensor2: 49041,
+  ApuInletPressureSensor: 49050,
 });
 
 export const A320FailureDefinitions: FailureDefinition[] = [
@@ -144,6 +255,8 @@ export const A320FailureDefinitions: FailureDefinition[] = [
   [21, A320Failure.OutflowValve, 'Outflow Valve'],
   [21, A320Failure.SafetyValve, 'Safety Valve'],
   [21, A320Failure.RapidDecompression, 'Rapid Decompression'],
+  [21, A320Failure.AvionicsBlowerFan, 'Avionics Ventilation Blower Fan'],
+  [21, A320Failure.AvionicsExtractFan, 'Avionics Ventilation Extract Fan'],
 
   [22, A320Failure.Fac1Failure, 'FAC 1'],
   [22, A320Failure.Fac2Failure, 'FAC 2'],
@@ -159,6 +272,12 @@ export const A320FailureDefinitions: FailureDefinition[] = [
   [24, A320Failure.Generator1, 'Generator 1'],
   [24, A320Failure.Generator2, 'Generator 2'],
   [24, A320Failure.ApuGenerator1, 'APU Generator'],
+  [24, A320Failure.Generator1VoltageRegulator, 'Generator 1 voltage regulator'],
+  [24, A320Failure.Generator2VoltageRegulator, 'Generator 2 voltage regulator'],
+  [24, A320Failure.ApuGenerator1VoltageRegulator, 'APU Generator voltage regulator'],
+  [24, A320Failure.Generator1FeederFault, 'Generator 1 feeder fault'],
+  [24, A320Failure.Generator2FeederFault, 'Generator 2 feeder fault'],
+  [24, A320Failure.ApuGenerator1FeederFault, 'APU Generator feeder fault'],
   [24, A320Failure.AlternatingCurrent1, 'AC 1'],
   [24, A320Failure.AlternatingCurrent2, 'AC 2'],
   [24, A320Failure.AlternatingCurrentEssential, 'AC ESS'],
@@ -174,6 +293,19 @@ export const A320FailureDefinitions: FailureDefinition[] = [
   [24, A320Failure.DirectCurrentHot2, 'DC HOT 2'],
   [24, A320Failure.DirectCurrentGndFltService, 'DC GND FLT SRV'],
 
+  [26, A320Failure.Engine1Fire, 'Fire - Engine 1'],
+  [26, A320Failure.Engine2Fire, 'Fire - Engine 2'],
+  [26, A320Failure.APUFire, 'Fire - APU'],
+  [26, A320Failure.CargoFire, 'Fire - Cargo Compartment'],
+  [26, A320Failure.Engine1LoopA, 'Engine 1 Loop A'],
+  [26, A320Failure.Engine1LoopB, 'Engine 1 Loop B'],
+  [26, A320Failure.Engine2LoopA, 'Engine 2 Loop A'],
+  [26, A320Failure.Engine2LoopB, 'Engine 2 Loop B'],
+  [26, A320Failure.APULoopA, 'APU Loop A'],
+  [26, A320Failure.APULoopB, 'APU Loop B'],
+  [26, A320Failure.CargoSmokeDetectorLoopA, 'Cargo Smoke Detector Loop A'],
+  [26, A320Failure.CargoSmokeDetectorLoopB, 'Cargo Smoke Detector Loop B'],
+
   [27, A320Failure.Elac1Failure, 'ELAC 1'],
   [27, A320Failure.Elac2Failure, 'ELAC 2'],
   [27, A320Failure.Sec1Failure, 'SEC 1'],
@@ -182,6 +314,26 @@ export const A320FailureDefinitions: FailureDefinition[] = [
   [27, A320Failure.Fcdc1Failure, 'FCDC 1'],
   [27, A320Failure.Fcdc2Failure, 'FCDC 2'],
 
+  [28, A320Failure.LeftTankPump1, 'Left tank pump 1'],
+  [28, A320Failure.LeftTankPump2, 'Left tank pump 2'],
+  [28, A320Failure.RightTankPump1, 'Right tank pump 1'],
+  [28, A320Failure.RightTankPump2, 'Right tank pump 2'],
+  [28, A320Failure.ApuFuelPump, 'APU fuel pump'],
+  [28, A320Failure.Engine1LpValve, 'Engine 1 LP valve jammed'],
+  [28, A320Failure.Engine2LpValve, 'Engine 2 LP valve jammed'],
+  [28, A320Failure.CrossfeedValve, 'Crossfeed valve jammed'],
+  [28, A320Failure.LeftOuterTankTransferValve, 'Left outer tank transfer valve jammed'],
+  [28, A320Failure.RightOuterTankTransferValve, 'Right outer tank transfer valve jammed'],
+  [28, A320Failure.LeftCenterTankTransferValve, 'Left center tank transfer valve jammed'],
+  [28, A320Failure.RightCenterTankTransferValve, 'Right center tank transfer valve jammed'],
+  [28, A320Failure.CenterTankLeak, 'Center tank leak'],
+  [28, A320Failure.LeftInnerTankLeak, 'Left inner tank leak'],
+  [28, A320Failure.LeftOuterTankLeak, 'Left outer tank leak'],
+  [28, A320Failure.RightInnerTankLeak, 'Right inner tank leak'],
+  [28, A320Failure.RightOuterTankLeak, 'Right outer tank leak'],
+  [28, A320Failure.FqiChannel1, 'FQI channel 1'],
+  [28, A320Failure.FqiChannel2, 'FQI channel 2'],
+
   [29, A320Failure.GreenReservoirLeak, 'Green reservoir leak'],
   [29, A320Failure.BlueReservoirLeak, 'Blue reservoir leak'],
   [29, A320Failure.YellowReservoirLeak, 'Yellow reservoir leak'],
@@ -196,6 +348,29 @@ export const A320FailureDefinitions: FailureDefinition[] = [
   [29, A320Failure.YellowEdpOverheat, 'Yellow engine pump overheat'],
   [29, A320Failure.YellowEpumpOverheat, 'Yellow electric pump overheat'],
 
+  [30, A320Failure.CaptPitotHeater, 'Capt pitot heater'],
+  [30, A320Failure.CaptLeftStaticHeater, 'Capt left static heater'],
+  [30, A320Failure.CaptRightStaticHeater, 'Capt right static heater'],
+  [30, A320Failure.CaptTatHeater, 'Capt TAT heater'],
+  [30, A320Failure.CaptAoaHeater, 'Capt AOA heater'],
+  [30, A320Failure.FoPitotHeater, 'F/O pitot heater'],
+  [30, A320Failure.FoLeftStaticHeater, 'F/O left static heater'],
+  [30, A320Failure.FoRightStaticHeater, 'F/O right static heater'],
+  [30, A320Failure.FoTatHeater, 'F/O TAT heater'],
+  [30, A320Failure.FoAoaHeater, 'F/O AOA heater'],
+  [30, A320Failure.StbyPitotHeater, 'Stby pitot heater'],
+  [30, A320Failure.StbyLeftStaticHeater, 'Stby left static heater'],
+  [30, A320Failure.StbyRightStaticHeater, 'Stby right static heater'],
+  [30, A320Failure.StbyAoaHeater, 'Stby AOA heater'],
+  [30, A320Failure.CaptWindshieldHeater, 'Capt windshield heater'],
+  [30, A320Failure.CaptWindowHeater, 'Capt side window heater'],
+  [30, A320Failure.CaptWindowHeatRegulation, 'Capt window heat regulation'],
+  [30, A320Failure.FoWindshieldHeater, 'F/O windshield heater'],
+  [30, A320Failure.FoWindowHeater, 'F/O side window heater'],
+  [30, A320Failure.FoWindowHeatRegulation, 'F/O window heat regulation'],
+  [30, A320Failure.Eng1AntiIceValve, 'Engine 1 anti-ice valve'],
+  [30, A320Failure.Eng2AntiIceValve, 'Engine 2 anti-ice valve'],
+
   [31, A320Failure.LeftPfdDisplay, 'Captain PFD display'],
   [31, A320Failure.RightPfdDisplay, 'F/O PFD display'],
   [31, A320Failure.EcamControlPanel, 'ECAM Control Panel'],
@@ -235,4 +410,51 @@ export const A320FailureDefinitions: FailureDefinition[] = [
   [34, A320Failure.RadioAntennaInterrupted2, 'RA 2 Interrupted'],
   [34, A320Failure.RadioAntennaDirectCoupling1, 'RA 1 Direct Coupling'],
   [34, A320Failure.RadioAntennaDirectCoupling2, 'RA 2 Direct Coupling'],
+  [34, A320Failure.InertialReference1, 'IR 1'],
+  [34, A320Failure.InertialReference2, 'IR 2'],
+  [34, A320Failure.InertialReference3, 'IR 3'],
+  [34, A320Failure.GpsReceiver1, 'GPS 1'],
+  [34, A320Failure.GpsReceiver2, 'GPS 2'],
+
+  [35, A320Failure.CrewOxygenBottleLeak, 'Crew oxygen bottle leak'],
+  [35, A320Failure.CrewOxygenRegulatorLowPressure, 'Crew oxygen regulator low pressure'],
+
+  [36, A320Failure.WingBleedLeak1, 'Wing 1 bleed duct leak'],
+  [36, A320Failure.WingBleedLeak2, 'Wing 2 bleed duct leak'],
+  [36, A320Failure.PylonBleedLeak1, 'Pylon 1 bleed duct leak'],
+  [36, A320Failure.PylonBleedLeak2, 'Pylon 2 bleed duct leak'],
+  [36, A320Failure.ApuBleedLeak, 'APU bleed duct leak'],
+  [36, A320Failure.WingLeakDetectionLoop1, 'Wing 1 leak detection loop'],
+  [36, A320Failure.WingLeakDetectionLoop2, 'Wing 2 leak detection loop'],
+  [36, A320Failure.PylonLeakDetectionLoop1, 'Pylon 1 leak detection loop'],
+  [36, A320Failure.PylonLeakDetectionLoop2, 'Pylon 2 leak detection loop'],
+  [36, A320Failure.ApuLeakDetectionLoop, 'APU leak detection loop'],
+  [36, A320Failure.HpValveJammedOpen1, 'Engine 1 HP valve jammed open'],
+  [36, A320Failure.HpValveJammedOpen2, 'Engine 2 HP valve jammed open'],
+  [36, A320Failure.HpValveJammedClosed1, 'Engine 1 HP valve jammed closed'],
+  [36, A320Failure.HpValveJammedClosed2, 'Engine 2 HP valve jammed closed'],
+  [36, A320Failure.PrvValveJammedOpen1, 'Engine 1 PR valve jammed open'],
+  [36, A320Failure.PrvValveJammedOpen2, 'Engine 2 PR valve jammed open'],
+  [36, A320Failure.PrvValveJammedClosed1, 'Engine 1 PR valve jammed closed'],
+  [36, A320Failure.PrvValveJammedClosed2, 'Engine 2 PR valve jammed closed'],
+  [36, A320Failure.FavValveJammedOpen1, 'Engine 1 fan air valve jammed open'],
+  [36, A320Failure.FavValveJammedOpen2, 'Engine 2 fan air valve jammed open'],
+  [36, A320Failure.FavValveJammedClosed1, 'Engine 1 fan air valve jammed closed'],
+  [36, A320Failure.FavValveJammedClosed2, 'Engine 2 fan air valve jammed closed'],
+  [36, A320Failure.PrecoolerBlockage1, 'Engine 1 precooler blockage'],
+  [36, A320Failure.PrecoolerBlockage2, 'Engine 2 precooler blockage'],
+  [36, A320Failure.BleedOverpressure1, 'Engine 1 bleed overpressure'],
+  [36, A320Failure.BleedOverpressure2, 'Engine 2 bleed overpressure'],
+  [36, A320Failure.BleedOvertemperature1, 'Engine 1 bleed overtemperature'],
+  [36, A320Failure.BleedOvertemperature2, 'Engine 2 bleed overtemperature'],
+  [36, A320Failure.Bmc1, 'BMC 1'],
+  [36, A320Failure.Bmc2, 'BMC 2'],
+
+  [49, A320Failure.ApuStartMotor, 'APU start motor'],
+  [49, A320Failure.ApuFuelControlUnit, 'APU fuel control unit'],
+  [49, A320Failure.ApuAirIntakeFlapJammed, 'APU air intake flap jammed'],
+  [49, A320Failure.ApuEgtSensor, 'APU EGT sensor'],
+  [49, A320Failure.ApuSpeedSensor1, 'APU speed sensor 1'],
+  [49, A320Failure.ApuSpeedSensor2, 'APU speed sensor 2'],
+  [49, A320Failure.ApuInletPressureSensor, 'APU inlet pressure sensor'],
 ];
@@ -201,6 +201,8 @@ export abstract class FMCMainDisplay implements FmsDataInterface, FmsDisplayInte
   public flaps?: 0 | 1 | 2 | 3 | null = undefined;
   public ths?: number | null;
   public cruiseTemperature?: number;
+  /** The magnetic heading entered on the IRS INIT page for the IRs in ATT mode, in degrees. */
+  public irsSetHeading?: number;
   public taxiFuelWeight = 0.2;
   public blockFuel?: number;
   public zeroFuelWeight?: number;
@@ -281,6 +283,9 @@ export abstract class FMCMainDisplay implements FmsDataInterface, FmsDisplayInte
   private readonly arincFlightNumber2 = new FmArinc429OutputWord('FLIGHT_NUMBER_2');
   private readonly arincFlightNumber3 = new FmArinc429OutputWord('FLIGHT_NUMBER_3');
   private readonly arincFlightNumber4 = new FmArinc429OutputWord('FLIGHT_NUMBER_4');
+  private readonly arincIrsPresentLatitude = new FmArinc429OutputWord('IRS_PRESENT_LATITUDE');
+  private readonly arincIrsPresentLongitude = new FmArinc429OutputWord('IRS_PRESENT_LONGITUDE');
+  private readonly arincIrsSetHeading = new FmArinc429OutputWord('IRS_SET_HEADING');
 
   /** These arinc words will be automatically written to the bus, and automatically set to 0/NCD when the FMS resets */
   private readonly arincBusOutputs = [
@@ -305,6 +310,9 @@ export abstract class FMCMainDisplay implements FmsDataInterface, FmsDisplayInte
     this.arincFlightNumber2,
     this.arincFlightNumber3,
     this.arincFlightNumber4,
+    this.arincIrsPresentLatitude,
+    this.arincIrsPresentLongitude,
+    this.arincIrsSetHeading,
   ];
 
   private navDbIdent: DatabaseIdent | null = null;
@@ -498,6 +506,7 @@ export abstract class FMCMainDisplay implements FmsDataInterface, FmsDisplayInte
     this._gwInitDisplayed = 0;
     /* CPDLC Fields */
     this.tropo = undefined;
+    this.irsSetHeading = undefined;
     this._destDataChecked = false;
     this._towerHeadwind = 0;
     this._EfobBelowMinClr = false;
@@ -677,6 +686,8 @@ export abstract class FMCMainDisplay implements FmsDataInterface, FmsDisplayInte
       this.updateThrustReductionAcceleration();
       this.updateTransitionAltitudeLevel();
       this.updateMinimums();
+      this.updateIrsPresentPosition();
+      this.updateIrsSetHeading();
       this.updateIlsCourse();
       this.updatePerfPageAltPredictions();
       this.checkEFOBBelowMin();
@@ -1759,6 +1770,38 @@ export abstract class FMCMainDisplay implements FmsDataInterface, FmsDisplayInte
     this.arincEisWord2.setSsm(Arinc429SignStatusMatrix.NormalOperation);
   }
 
+  /** Sends the GPS position to the IRs, which need a present position to complete their alignment. */
+  private updateIrsPresentPosition() {
+    const latitude = SimVar.GetSimVarValue('GPS POSITION LAT', 'degree latitude');
+    const longitude = SimVar.GetSimVarValue('GPS POSITION LON', 'degree longitude');
+
+    this.arincIrsPresentLatitude.setBnrValue(latitude, Arinc429SignStatusMatrix.NormalOperation, 18, 180, -180);
+    this.arincIrsPresentLongitude.setBnrValue(longitude, Arinc429SignStatusMatrix.NormalOperation, 18, 180, -180);
+  }
+
+  /**
+   * Sends the heading entered on the IRS INIT page to the IRs in ATT mode, which can't determine the magnetic heading
+   * themselves. The entry is cleared once no IR is in ATT mode anymore, such that it has to be entered again.
+   */
+  private updateIrsSetHeading() {
+    if (!this.isAnyIrInAttMode()) {
+      this.irsSetHeading = undefined;
+    }
+
+    if (this.irsSetHeading !== undefined) {
+      const heading = this.irsSetHeading > 180 ? this.irsSetHeading - 360 : this.irsSetHeading;
+      this.arincIrsSetHeading.setBnrValue(heading, Arinc429SignStatusMatrix.NormalOperation, 15, 180, -180);
+    } else {
+      this.arincIrsSetHeading.setBnrValue(0, Arinc429SignStatusMatrix.NoComputedData, 15, 180, -180);
+    }
+  }
+
+  public isAnyIrInAttMode(): boolean {
+    return [1, 2, 3].some(
+      (ir) => SimVar.GetSimVarValue(`L:A32NX_OVHD_ADIRS_IR_${ir}_MODE_SELECTOR_KNOB`, 'Enum') === 2,
+    );
+  }
+
   private shouldTransmitMinimums() {
     const phase = this.flightPhaseManager.phase;
     const distanceToDestination = this.getDistanceToDestination();
@@ -2130,6 +2173,41 @@ export abstract class FMCMainDisplay implements FmsDataInterface, FmsDisplayInte
    * @param tropo Format: NNNN or NNNNN Leading 0’s must be included. Entry is rounded to the nearest 10 ft
    * @return Whether tropopause could be set or not
    */
+  /**
+   * Sets the magnetic heading for the IRs in ATT mode.
+   * @param heading Format: NNN or NNN.N, between 0 and 360 degrees.
+   * @return Whether the heading could be set or not
+   */
+  public tryUpdateIrsSetHeading(heading: string): boolean {
+    if (heading === Keypad.clrValue) {
+      if (this.irsSetHeading !== undefined) {
+        this.irsSetHeading = undefined;
+        return true;
+      }
+      this.setScratchpadMessage(NXSystemMessages.notAllowed);
+      return false;
+    }
+
+    if (!this.isAnyIrInAttMode()) {
+      this.setScratchpadMessage(NXSystemMessages.notAllowed);
+      return false;
+    }
+
+    if (!heading.match(/^\d{1,3}(\.\d)?$/)) {
+      this.setScratchpadMessage(NXSystemMessages.formatError);
+      return false;
+    }
+
+    const value = parseFloat(heading);
+    if (isFinite(value) && value >= 0 && value <= 360) {
+      this.irsSetHeading = value % 360;
+      return true;
+    }
+
+    this.setScratchpadMessage(NXSystemMessages.entryOutOfRange);
+    return false;
+  }
+
   public tryUpdateTropo(tropo: string): boolean {
     if (tropo === Keypad.clrValue) {
       if (this.tropo) {
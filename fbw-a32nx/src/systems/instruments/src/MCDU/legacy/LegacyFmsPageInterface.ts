@@ -142,6 +142,8 @@ interface LegacyFmsPageFmsInterface extends FmsDataInterface, FmsDisplayInterfac
   getCoRouteList(): Promise<void>;
   tryUpdateAltDestination(altDestIdent: string): Promise<boolean>;
   tryUpdateTropo(tropo: string): boolean;
+  tryUpdateIrsSetHeading(heading: string): boolean;
+  isAnyIrInAttMode(): boolean;
   tryUpdateFromTo(fromTo: string, callback?: typeof EmptyCallback.Boolean): void;
   trySetGroundTemp(scratchpadValue: string): void;
   goToFuelPredPage(): void;
@@ -257,6 +259,7 @@ interface LegacyFmsPageFmsInterface extends FmsDataInterface, FmsDisplayInterfac
   machToCasManualCrossoverCurve: any;
   tropo: number | undefined;
   isTropoPilotEntered: boolean;
+  irsSetHeading?: number;
   groundTemp?: number;
   groundTempPilot?: number;
   taxiFuelWeight: number;
@@ -204,6 +204,17 @@ export class CDUIRSInit {
       statusIRS3 = 'IRS3 OFF';
     }
 
+    // The IRs in ATT mode need the magnetic heading to be entered
+    let headingTitle = '';
+    let headingString = '';
+    if (mcdu.isAnyIrInAttMode()) {
+      headingTitle = 'HDG';
+      headingString =
+        mcdu.irsSetHeading !== undefined
+          ? mcdu.irsSetHeading.toFixed(1).padStart(5, '0') + '°[color]cyan'
+          : '___._°[color]amber';
+    }
+
     mcdu.setTemplate([
       ['IRS INIT'],
       originAirportTitle,
@@ -214,8 +225,8 @@ export class CDUIRSInit {
       ['', '', IRS1GpsString],
       ['', '', statusIRS2],
       ['', '', IRS2GpsString],
-      ['', '', statusIRS3],
-      ['', '', IRS3GpsString],
+      [headingTitle, '', statusIRS3],
+      [headingString, '', IRS3GpsString],
       [],
       ['<RETURN', alignMsg],
     ]);
@@ -228,6 +239,22 @@ export class CDUIRSInit {
       lon = true;
     };
 
+    mcdu.onLeftInput[4] = (value, scratchpadCallback) => {
+      if (mcdu.tryUpdateIrsSetHeading(value)) {
+        CDUIRSInit.ShowPage(
+          mcdu,
+          lon,
+          originAirportLat,
+          originAirportLon,
+          referenceName,
+          originAirportCoordinates,
+          alignMsg,
+        );
+      } else {
+        scratchpadCallback();
+      }
+    };
+
     mcdu.leftInputDelay[5] = () => {
       return mcdu.getDelaySwitchPage();
     };
@@ -20,8 +20,8 @@ export const FuelPage = () => {
   const [tankCenter] = useSimVar('FUEL TANK CENTER QUANTITY', 'gallons', 500);
   const [tankRightInner] = useSimVar('FUEL TANK RIGHT MAIN QUANTITY', 'gallons', 500);
   const [tankRightOuter] = useSimVar('FUEL TANK RIGHT AUX QUANTITY', 'gallons', 500);
-  const [leftOuterInnerValve] = useSimVar('FUELSYSTEM VALVE OPEN:4', 'bool', 500);
-  const [rightOuterInnerValve] = useSimVar('FUELSYSTEM VALVE OPEN:5', 'bool', 500);
+  const [leftOuterInnerValve] = useSimVar('L:A32NX_FUEL_VALVE_4_OPEN_AMOUNT', 'percent', 500);
+  const [rightOuterInnerValve] = useSimVar('L:A32NX_FUEL_VALVE_5_OPEN_AMOUNT', 'percent', 500);
   const [modelSelectManual] = useSimVar('L:A32NX_OVHD_FUEL_MODESEL_MANUAL', 'bool', 500);
   const [autoShutoffRequired] = useSimVar('FUELSYSTEM TRIGGER STATUS:9', 'bool', 500);
 
@@ -97,7 +97,7 @@ export const FuelPage = () => {
           {fuelInTanksForDisplay(tankLeftInner, unit, fuelWeightPerGallon)}
         </text>
 
-        {leftOuterInnerValve ? <Triangle x={77} y={319} colour="Green" fill={0} orientation={90} /> : null}
+        {leftOuterInnerValve > 50 ? <Triangle x={77} y={319} colour="Green" fill={0} orientation={90} /> : null}
 
         <text className="UnitTemp" x="70" y="355">
           °C
@@ -167,7 +167,7 @@ export const FuelPage = () => {
         <text className="TankQuantity" x={580} y={285}>
           {fuelInTanksForDisplay(tankRightOuter, unit, fuelWeightPerGallon)}
         </text>
-        {rightOuterInnerValve && <Triangle x={522} y={319} colour="Green" fill={0} orientation={-90} />}
+        {rightOuterInnerValve > 50 && <Triangle x={522} y={319} colour="Green" fill={0} orientation={-90} />}
 
         <text className="UnitTemp" x="510" y="355">
           °C
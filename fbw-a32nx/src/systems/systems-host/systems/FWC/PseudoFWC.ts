@@ -1207,7 +1207,7 @@ export class PseudoFWC {
 
   private readonly agentAPUDischargeTimer = new NXLogicClockNode(10, 0);
 
-  private readonly apuAgentPB = Subject.create(false);
+  private readonly apuAgentDischarged = Subject.create(false);
 
   private readonly apuFireTest = Subject.create(false);
 
@@ -1215,15 +1215,15 @@ export class PseudoFWC {
 
   private readonly cargoFireTest = Subject.create(false);
 
-  private readonly eng1Agent1PB = Subject.create(false);
+  private readonly eng1Agent1Discharged = Subject.create(false);
 
-  private readonly eng1Agent2PB = Subject.create(false);
+  private readonly eng1Agent2Discharged = Subject.create(false);
 
   private readonly eng1FireTest = Subject.create(false);
 
-  private readonly eng2Agent1PB = Subject.create(false);
+  private readonly eng2Agent1Discharged = Subject.create(false);
 
-  private readonly eng2Agent2PB = Subject.create(false);
+  private readonly eng2Agent2Discharged = Subject.create(false);
 
   private readonly eng2FireTest = Subject.create(false);
 
@@ -2886,29 +2886,29 @@ export class PseudoFWC {
     this.eng1FireTest.set(SimVar.GetSimVarValue('L:A32NX_FIRE_TEST_ENG1', 'bool'));
     this.eng2FireTest.set(SimVar.GetSimVarValue('L:A32NX_FIRE_TEST_ENG2', 'bool'));
     this.apuFireTest.set(SimVar.GetSimVarValue('L:A32NX_FIRE_TEST_APU', 'bool'));
-    this.eng1Agent1PB.set(SimVar.GetSimVarValue('L:A32NX_FIRE_ENG1_AGENT1_Discharge', 'bool'));
-    this.eng1Agent2PB.set(SimVar.GetSimVarValue('L:A32NX_FIRE_ENG1_AGENT2_Discharge', 'bool'));
-    this.eng2Agent1PB.set(SimVar.GetSimVarValue('L:A32NX_FIRE_ENG2_AGENT1_Discharge', 'bool'));
-    this.eng2Agent2PB.set(SimVar.GetSimVarValue('L:A32NX_FIRE_ENG2_AGENT2_Discharge', 'bool'));
-    this.apuAgentPB.set(SimVar.GetSimVarValue('L:A32NX_FIRE_APU_AGENT1_Discharge', 'bool'));
+    this.eng1Agent1Discharged.set(SimVar.GetSimVarValue('L:A32NX_FIRE_SQUIB_1_ENG_1_IS_DISCHARGED', 'bool'));
+    this.eng1Agent2Discharged.set(SimVar.GetSimVarValue('L:A32NX_FIRE_SQUIB_2_ENG_1_IS_DISCHARGED', 'bool'));
+    this.eng2Agent1Discharged.set(SimVar.GetSimVarValue('L:A32NX_FIRE_SQUIB_1_ENG_2_IS_DISCHARGED', 'bool'));
+    this.eng2Agent2Discharged.set(SimVar.GetSimVarValue('L:A32NX_FIRE_SQUIB_2_ENG_2_IS_DISCHARGED', 'bool'));
+    this.apuAgentDischarged.set(SimVar.GetSimVarValue('L:A32NX_FIRE_SQUIB_1_APU_1_IS_DISCHARGED', 'bool'));
     this.cargoFireTest.set(SimVar.GetSimVarValue('L:A32NX_FIRE_TEST_CARGO', 'bool'));
     this.cargoFireAgentDisch.set(SimVar.GetSimVarValue('L:A32NX_CARGOSMOKE_FWD_DISCHARGED', 'bool'));
 
     this.agent1Eng1Discharge.set(this.agent1Eng1DischargeTimer.write(this.fireButton1.get(), deltaTime));
     this.agent2Eng1Discharge.set(
       this.agent2Eng1DischargeTimer.write(
-        this.fireButton1.get() && this.eng1Agent1PB.get() && !this.aircraftOnGround.get(),
+        this.fireButton1.get() && this.eng1Agent1Discharged.get() && !this.aircraftOnGround.get(),
         deltaTime,
       ),
     );
     this.agent1Eng2Discharge.set(
-      this.agent1Eng2DischargeTimer.write(this.fireButton2.get() && !this.eng1Agent1PB.get(), deltaTime),
+      this.agent1Eng2DischargeTimer.write(this.fireButton2.get() && !this.eng1Agent1Discharged.get(), deltaTime),
     );
     this.agent2Eng2Discharge.set(
-      this.agent2Eng2DischargeTimer.write(this.fireButton2.get() && this.eng1Agent1PB.get(), deltaTime),
+      this.agent2Eng2DischargeTimer.write(this.fireButton2.get() && this.eng1Agent1Discharged.get(), deltaTime),
     );
     this.agentAPUDischarge.set(
-      this.agentAPUDischargeTimer.write(this.fireButton2.get() && this.eng1Agent1PB.get(), deltaTime),
+      this.agentAPUDischargeTimer.write(this.fireButton2.get() && this.eng1Agent1Discharged.get(), deltaTime),
     );
 
     /* ANTI ICE */
@@ -4035,15 +4035,19 @@ export class PseudoFWC {
         this.aircraftOnGround.get() ? 6 : null,
         !this.engine1ValueSwitch.get() ? null : 7,
         !this.fireButton1.get() ? 8 : null,
-        !this.aircraftOnGround.get() && this.agent1Eng1Discharge.get() === 1 && !this.eng1Agent1PB.get() ? 9 : null,
-        this.agent1Eng1Discharge.get() === 2 && !this.aircraftOnGround.get() && !this.eng1Agent1PB.get() ? 10 : null,
-        !this.eng1Agent1PB.get() && this.aircraftOnGround.get() ? 11 : null,
-        !this.eng1Agent2PB.get() && this.aircraftOnGround.get() ? 12 : null,
+        !this.aircraftOnGround.get() && this.agent1Eng1Discharge.get() === 1 && !this.eng1Agent1Discharged.get()
+          ? 9
+          : null,
+        this.agent1Eng1Discharge.get() === 2 && !this.aircraftOnGround.get() && !this.eng1Agent1Discharged.get()
+          ? 10
+          : null,
+        !this.eng1Agent1Discharged.get() && this.aircraftOnGround.get() ? 11 : null,
+        !this.eng1Agent2Discharged.get() && this.aircraftOnGround.get() ? 12 : null,
         this.aircraftOnGround.get() ? 13 : null,
         !this.aircraftOnGround.get() ? 14 : null,
-        this.agent2Eng1Discharge.get() === 1 && !this.eng1Agent2PB.get() ? 15 : null,
-        (this.agent2Eng1Discharge.get() === 1 && !this.eng1Agent2PB.get()) ||
-        (this.agent2Eng1Discharge.get() === 2 && !this.eng1Agent2PB.get())
+        this.agent2Eng1Discharge.get() === 1 && !this.eng1Agent2Discharged.get() ? 15 : null,
+        (this.agent2Eng1Discharge.get() === 1 && !this.eng1Agent2Discharged.get()) ||
+        (this.agent2Eng1Discharge.get() === 2 && !this.eng1Agent2Discharged.get())
           ? 16
           : null,
       ],
@@ -4091,15 +4095,19 @@ export class PseudoFWC {
         this.aircraftOnGround.get() ? 6 : null,
         !this.engine2ValueSwitch.get() ? null : 7,
         !this.fireButton2.get() ? 8 : null,
-        !this.aircraftOnGround.get() && this.agent1Eng2Discharge.get() === 1 && !this.eng2Agent1PB.get() ? 9 : null,
-        this.agent1Eng2Discharge.get() === 2 && !this.aircraftOnGround.get() && !this.eng2Agent1PB.get() ? 10 : null,
-        !this.eng2Agent1PB.get() && this.aircraftOnGround.get() ? 11 : null,
-        !this.eng2Agent2PB.get() && this.aircraftOnGround.get() ? 12 : null,
+        !this.aircraftOnGround.get() && this.agent1Eng2Discharge.get() === 1 && !this.eng2Agent1Discharged.get()
+          ? 9
+          : null,
+        this.agent1Eng2Discharge.get() === 2 && !this.aircraftOnGround.get() && !this.eng2Agent1Discharged.get()
+          ? 10
+          : null,
+        !this.eng2Agent1Discharged.get() && this.aircraftOnGround.get() ? 11 : null,
+        !this.eng2Agent2Discharged.get() && this.aircraftOnGround.get() ? 12 : null,
         this.aircraftOnGround.get() ? 13 : null,
         !this.aircraftOnGround.get() ? 14 : null,
-        this.agent2Eng2Discharge.get() === 1 && !this.eng2Agent2PB.get() ? 15 : null,
-        (this.agent2Eng2Discharge.get() === 1 && !this.eng2Agent2PB.get()) ||
-        (this.agent2Eng2Discharge.get() === 2 && !this.eng2Agent2PB.get())
+        this.agent2Eng2Discharge.get() === 1 && !this.eng2Agent2Discharged.get() ? 15 : null,
+        (this.agent2Eng2Discharge.get() === 1 && !this.eng2Agent2Discharged.get()) ||
+        (this.agent2Eng2Discharge.get() === 2 && !this.eng2Agent2Discharged.get())
           ? 16
           : null,
       ],
@@ -4137,8 +4145,8 @@ export class PseudoFWC {
       whichCodeToReturn: () => [
         0,
         !this.fireButtonAPU.get() ? 1 : null,
-        this.agentAPUDischarge.get() === 1 && !this.apuAgentPB.get() ? 2 : null,
-        this.agentAPUDischarge.get() === 2 && !this.apuAgentPB.get() ? 3 : null,
+        this.agentAPUDischarge.get() === 1 && !this.apuAgentDischarged.get() ? 2 : null,
+        this.agentAPUDischarge.get() === 2 && !this.apuAgentDischarged.get() ? 3 : null,
         this.apuMasterSwitch.get() === 1 ? 4 : null,
       ],
       codesToReturn: ['260003001', '260003002', '260003003', '260003004', '260003005'],
@@ -56,6 +56,7 @@ class EngineControl_A32NX {
   double prevThrustLimitType       = 0.0;
   double prevEngineMasterPos[2]    = {0, 0};
   bool   prevEngineStarterState[2] = {false, false};
+  bool   prevEngineIsFed[2]        = {false, false};
 
   // FLX->CLB thrust limit transition
   double transitionStartTime;
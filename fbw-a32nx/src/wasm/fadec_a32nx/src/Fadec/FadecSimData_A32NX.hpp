@@ -195,6 +195,7 @@ class FadecSimData_A32NX {
   NamedVariablePtr engineIdleN1;
   NamedVariablePtr engineIdleN2;
   NamedVariablePtr engineImbalance;
+  NamedVariablePtr engineIsFed[2];
   NamedVariablePtr engineN1[2];
   NamedVariablePtr engineN2[2];
   NamedVariablePtr engineOilTotal[2];
@@ -308,6 +309,9 @@ class FadecSimData_A32NX {
 
     engineImbalance = dm->make_named_var("A32NX_ENGINE_IMBALANCE", UNITS.Number, AUTO_READ_WRITE);
 
+    engineIsFed[L] = dm->make_named_var("A32NX_FUEL_ENGINE_1_IS_FED", UNITS.Bool, AUTO_READ);
+    engineIsFed[R] = dm->make_named_var("A32NX_FUEL_ENGINE_2_IS_FED", UNITS.Bool, AUTO_READ);
+
     engineN1[L] = dm->make_named_var("A32NX_ENGINE_N1:1", UNITS.Number, AUTO_READ_WRITE);
     engineN1[R] = dm->make_named_var("A32NX_ENGINE_N1:2", UNITS.Number, AUTO_READ_WRITE);
 
@@ -43,10 +43,14 @@ use uom::si::{
 
 use crate::payload::A320Pax;
 
+mod avionics_ventilation;
+use avionics_ventilation::A320AvionicsVentilation;
+
 pub(super) struct A320AirConditioning {
     a320_cabin: A320Cabin,
     a320_air_conditioning_system: A320AirConditioningSystem,
     a320_pressurization_system: A320PressurizationSystem,
+    a320_avionics_ventilation: A320AvionicsVentilation,
 
     pressurization_updater: MaxStepLoop,
 }
@@ -62,6 +66,7 @@ impl A320AirConditioning {
             a320_cabin: A320Cabin::new(context),
             a320_air_conditioning_system: A320AirConditioningSystem::new(context, &cabin_zones),
             a320_pressurization_system: A320PressurizationSystem::new(context),
+            a320_avionics_ventilation: A320AvionicsVentilation::new(context),
 
             pressurization_updater: MaxStepLoop::new(Self::PRESSURIZATION_SIM_MAX_TIME_STEP),
         }
@@ -111,6 +116,9 @@ impl A320AirConditioning {
                 &self.a320_cabin,
             );
         }
+
+        self.a320_avionics_ventilation
+            .update(context, lgciu, &self.a320_cabin);
     }
 
     pub fn mix_packs_air_update(&mut self, pack_container: &mut [impl PneumaticContainer; 2]) {
@@ -138,11 +146,26 @@ impl PackFlowControllers for A320AirConditioning {
     }
 }
 
+impl CabinSimulation for A320AirConditioning {
+    fn cabin_temperature(&self) -> Vec<ThermodynamicTemperature> {
+        self.a320_cabin.cabin_temperature()
+    }
+
+    fn exterior_pressure(&self) -> Pressure {
+        self.a320_cabin.exterior_pressure()
+    }
+
+    fn cabin_pressure(&self) -> Pressure {
+        self.a320_cabin.cabin_pressure()
+    }
+}
+
 impl SimulationElement for A320AirConditioning {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.a320_cabin.accept(visitor);
         self.a320_air_conditioning_system.accept(visitor);
         self.a320_pressurization_system.accept(visitor);
+        self.a320_avionics_ventilation.accept(visitor);
 
         visitor.visit(self);
     }
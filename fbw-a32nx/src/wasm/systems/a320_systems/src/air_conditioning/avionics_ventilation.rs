@@ -0,0 +1,840 @@
+use systems::{
+    failures::{Failure, FailureType},
+    overhead::AutoOffFaultPushButton,
+    shared::{
+        CabinSimulation, ConsumePower, ElectricalBusType, ElectricalBuses, LgciuWeightOnWheels,
+    },
+    simulation::{
+        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
+        VariableIdentifier, Write,
+    },
+};
+
+use std::time::Duration;
+use uom::si::{
+    f64::*,
+    mass_rate::kilogram_per_second,
+    power::watt,
+    ratio::{percent, ratio},
+    thermodynamic_temperature::{degree_celsius, kelvin},
+};
+
+/// The configurations of the avionics ventilation system, as selected by the AEVC.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum AvionicsVentilationConfiguration {
+    /// On ground with a warm skin: outside air is blown through the avionics compartment
+    /// and discharged overboard.
+    Open,
+    /// Air is recirculated through the skin heat exchanger.
+    Closed,
+    /// In flight with a hot skin: the outlet valve is partially opened to discharge part of
+    /// the recirculated air overboard.
+    Intermediate,
+    /// Both BLOWER and EXTRACT pushbuttons are OVRD: air conditioning air is supplied to the
+    /// avionics and the extracted air is discharged overboard.
+    Smoke,
+}
+
+struct A320AvionicsVentilationOverheadPanel {
+    blower: AutoOffFaultPushButton,
+    extract: AutoOffFaultPushButton,
+}
+impl A320AvionicsVentilationOverheadPanel {
+    fn new(context: &mut InitContext) -> Self {
+        Self {
+            blower: AutoOffFaultPushButton::new_auto(context, "VENT_BLOWER"),
+            extract: AutoOffFaultPushButton::new_auto(context, "VENT_EXTRACT"),
+        }
+    }
+}
+impl SimulationElement for A320AvionicsVentilationOverheadPanel {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.blower.accept(visitor);
+        self.extract.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
+/// The Avionics Equipment Ventilation Computer selects the configuration of the avionics
+/// ventilation system from the flight phase and the skin temperature, unless the crew
+/// overrides it with the BLOWER or EXTRACT pushbutton.
+struct AvionicsEquipmentVentilationController {
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+
+    configuration: AvionicsVentilationConfiguration,
+}
+impl AvionicsEquipmentVentilationController {
+    const GROUND_OPEN_SKIN_TEMPERATURE_DEG_C: f64 = 12.;
+    const GROUND_CLOSED_SKIN_TEMPERATURE_DEG_C: f64 = 9.;
+    const FLIGHT_INTERMEDIATE_SKIN_TEMPERATURE_DEG_C: f64 = 35.;
+    const FLIGHT_CLOSED_SKIN_TEMPERATURE_DEG_C: f64 = 32.;
+
+    const OUTLET_VALVE_INTERMEDIATE_OPENING_PERCENT: f64 = 30.;
+
+    fn new(powered_by: ElectricalBusType) -> Self {
+        Self {
+            powered_by,
+            is_powered: false,
+
+            configuration: AvionicsVentilationConfiguration::Closed,
+        }
+    }
+
+    fn update(
+        &mut self,
+        skin_temperature: ThermodynamicTemperature,
+        is_on_ground: bool,
+        overhead: &A320AvionicsVentilationOverheadPanel,
+    ) {
+        if !self.is_powered {
+            return;
+        }
+
+        let skin_temperature = skin_temperature.get::<degree_celsius>();
+        self.configuration = match (overhead.blower.is_auto(), overhead.extract.is_auto()) {
+            (false, false) => AvionicsVentilationConfiguration::Smoke,
+            (true, true) if is_on_ground => {
+                if skin_temperature > Self::GROUND_OPEN_SKIN_TEMPERATURE_DEG_C
+                    || (self.configuration == AvionicsVentilationConfiguration::Open
+                        && skin_temperature >= Self::GROUND_CLOSED_SKIN_TEMPERATURE_DEG_C)
+                {
+                    AvionicsVentilationConfiguration::Open
+                } else {
+                    AvionicsVentilationConfiguration::Closed
+                }
+            }
+            (true, true) => {
+                if skin_temperature > Self::FLIGHT_INTERMEDIATE_SKIN_TEMPERATURE_DEG_C
+                    || (self.configuration == AvionicsVentilationConfiguration::Intermediate
+                        && skin_temperature >= Self::FLIGHT_CLOSED_SKIN_TEMPERATURE_DEG_C)
+                {
+                    AvionicsVentilationConfiguration::Intermediate
+                } else {
+                    AvionicsVentilationConfiguration::Closed
+                }
+            }
+            // With a single pushbutton in OVRD, the system is in closed configuration with
+            // air conditioning air added to the avionics ventilation.
+            _ => AvionicsVentilationConfiguration::Closed,
+        };
+    }
+
+    fn inlet_valve_target(&self) -> Ratio {
+        match self.configuration {
+            AvionicsVentilationConfiguration::Open => Ratio::new::<ratio>(1.),
+            _ => Ratio::default(),
+        }
+    }
+
+    fn outlet_valve_target(&self) -> Ratio {
+        match self.configuration {
+            AvionicsVentilationConfiguration::Open => Ratio::new::<ratio>(1.),
+            AvionicsVentilationConfiguration::Closed => Ratio::default(),
+            AvionicsVentilationConfiguration::Intermediate
+            | AvionicsVentilationConfiguration::Smoke => {
+                Ratio::new::<percent>(Self::OUTLET_VALVE_INTERMEDIATE_OPENING_PERCENT)
+            }
+        }
+    }
+
+    fn skin_exchanger_isolation_valve_target(&self) -> Ratio {
+        match self.configuration {
+            AvionicsVentilationConfiguration::Closed
+            | AvionicsVentilationConfiguration::Intermediate => Ratio::new::<ratio>(1.),
+            _ => Ratio::default(),
+        }
+    }
+
+    fn conditioning_air_valve_target(
+        &self,
+        overhead: &A320AvionicsVentilationOverheadPanel,
+    ) -> Ratio {
+        if overhead.blower.is_off() || overhead.extract.is_off() {
+            Ratio::new::<ratio>(1.)
+        } else {
+            Ratio::default()
+        }
+    }
+
+    fn blower_fan_is_commanded(&self, overhead: &A320AvionicsVentilationOverheadPanel) -> bool {
+        overhead.blower.is_auto()
+    }
+
+    fn is_powered(&self) -> bool {
+        self.is_powered
+    }
+}
+impl SimulationElement for AvionicsEquipmentVentilationController {
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+}
+
+struct AvionicsVentilationFan {
+    failure: Failure,
+    is_running_id: VariableIdentifier,
+
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+    power_consumption: Power,
+
+    mass_flow: MassRate,
+    is_running: bool,
+}
+impl AvionicsVentilationFan {
+    fn new(
+        context: &mut InitContext,
+        name: &str,
+        failure_type: FailureType,
+        powered_by: ElectricalBusType,
+        power_consumption: Power,
+        mass_flow: MassRate,
+    ) -> Self {
+        Self {
+            failure: Failure::new(failure_type),
+            is_running_id: context.get_identifier(format!("VENT_{}_FAN_IS_RUNNING", name)),
+
+            powered_by,
+            is_powered: false,
+            power_consumption,
+
+            mass_flow,
+            is_running: false,
+        }
+    }
+
+    fn update(&mut self, is_commanded: bool) {
+        self.is_running = is_commanded && self.is_powered && !self.failure.is_active();
+    }
+
+    fn mass_flow(&self) -> MassRate {
+        if self.is_running {
+            self.mass_flow
+        } else {
+            MassRate::default()
+        }
+    }
+
+    fn is_running(&self) -> bool {
+        self.is_running
+    }
+}
+impl SimulationElement for AvionicsVentilationFan {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.is_running_id, self.is_running);
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+
+    fn consume_power<T: ConsumePower>(&mut self, _: &UpdateContext, consumption: &mut T) {
+        if self.is_running {
+            consumption.consume_from_bus(self.powered_by, self.power_consumption);
+        }
+    }
+}
+
+/// An electrically actuated valve of the avionics ventilation system. The valves are driven
+/// by the AEVC and stay in position when it is not powered.
+struct AvionicsVentilationValve {
+    open_amount: Ratio,
+}
+impl AvionicsVentilationValve {
+    const TRAVEL_TIME: Duration = Duration::from_secs(10);
+
+    fn new() -> Self {
+        Self {
+            open_amount: Ratio::default(),
+        }
+    }
+
+    fn update(&mut self, context: &UpdateContext, target_open_amount: Ratio, is_powered: bool) {
+        if !is_powered {
+            return;
+        }
+
+        let max_travel =
+            Ratio::new::<ratio>(context.delta_as_secs_f64() / Self::TRAVEL_TIME.as_secs_f64());
+        self.open_amount += (target_open_amount - self.open_amount)
+            .max(-max_travel)
+            .min(max_travel);
+    }
+
+    fn open_amount(&self) -> Ratio {
+        self.open_amount
+    }
+}
+
+/// The skin heat exchanger cools the recirculated avionics air with the aircraft skin.
+struct SkinHeatExchanger {
+    isolation_valve: AvionicsVentilationValve,
+}
+impl SkinHeatExchanger {
+    const EFFECTIVENESS: f64 = 0.7;
+    // Part of the ram temperature rise recovered by the skin
+    const SKIN_RECOVERY_FACTOR: f64 = 0.85;
+
+    fn new() -> Self {
+        Self {
+            isolation_valve: AvionicsVentilationValve::new(),
+        }
+    }
+
+    fn skin_temperature(context: &UpdateContext) -> ThermodynamicTemperature {
+        let mach = context.mach_number().0;
+
+        ThermodynamicTemperature::new::<kelvin>(
+            context.ambient_temperature().get::<kelvin>()
+                * (1. + 0.2 * Self::SKIN_RECOVERY_FACTOR * mach.powi(2)),
+        )
+    }
+
+    fn update(&mut self, context: &UpdateContext, target_open_amount: Ratio, is_powered: bool) {
+        self.isolation_valve
+            .update(context, target_open_amount, is_powered);
+    }
+
+    fn outlet_temperature(
+        &self,
+        inlet_temperature: ThermodynamicTemperature,
+        skin_temperature: ThermodynamicTemperature,
+    ) -> ThermodynamicTemperature {
+        let cooling = Self::EFFECTIVENESS
+            * self.isolation_valve.open_amount().get::<ratio>()
+            * (inlet_temperature.get::<kelvin>() - skin_temperature.get::<kelvin>());
+
+        ThermodynamicTemperature::new::<kelvin>(inlet_temperature.get::<kelvin>() - cooling)
+    }
+}
+
+/// The avionics compartment is heated by the equipment it contains and cooled by the
+/// ventilation air. When ventilation is lost, only a small amount of heat is lost to the
+/// surrounding cabin and the temperature rises.
+struct AvionicsCompartment {
+    temperature_id: VariableIdentifier,
+
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+
+    temperature: ThermodynamicTemperature,
+}
+impl AvionicsCompartment {
+    const EQUIPMENT_HEAT_LOAD_WATT: f64 = 3000.;
+    const HEAT_CAPACITY_JOULE_PER_KELVIN: f64 = 150_000.;
+    const CABIN_THERMAL_CONDUCTANCE_WATT_PER_KELVIN: f64 = 15.;
+    const AIR_SPECIFIC_HEAT_JOULE_PER_KILOGRAM_KELVIN: f64 = 1005.;
+    const INITIAL_TEMPERATURE_DEG_C: f64 = 20.;
+
+    fn new(context: &mut InitContext, powered_by: ElectricalBusType) -> Self {
+        Self {
+            temperature_id: context.get_identifier("VENT_AVIONICS_TEMPERATURE".to_owned()),
+
+            powered_by,
+            is_powered: false,
+
+            temperature: ThermodynamicTemperature::new::<degree_celsius>(
+                Self::INITIAL_TEMPERATURE_DEG_C,
+            ),
+        }
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        ventilation_mass_flow: MassRate,
+        ventilation_air_temperature: ThermodynamicTemperature,
+        cabin_temperature: ThermodynamicTemperature,
+    ) {
+        let temperature = self.temperature.get::<kelvin>();
+
+        let heat_load = if self.is_powered {
+            Self::EQUIPMENT_HEAT_LOAD_WATT
+        } else {
+            0.
+        };
+        let ventilation_heat_removal = ventilation_mass_flow.get::<kilogram_per_second>()
+            * Self::AIR_SPECIFIC_HEAT_JOULE_PER_KILOGRAM_KELVIN
+            * (temperature - ventilation_air_temperature.get::<kelvin>());
+        let cabin_heat_loss = Self::CABIN_THERMAL_CONDUCTANCE_WATT_PER_KELVIN
+            * (temperature - cabin_temperature.get::<kelvin>());
+
+        self.temperature = ThermodynamicTemperature::new::<kelvin>(
+            temperature
+                + (heat_load - ventilation_heat_removal - cabin_heat_loss)
+                    * context.delta_as_secs_f64()
+                    / Self::HEAT_CAPACITY_JOULE_PER_KELVIN,
+        );
+    }
+
+    fn temperature(&self) -> ThermodynamicTemperature {
+        self.temperature
+    }
+}
+impl SimulationElement for AvionicsCompartment {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.temperature_id, self.temperature);
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+}
+
+pub(super) struct A320AvionicsVentilation {
+    inlet_valve_id: VariableIdentifier,
+    outlet_valve_id: VariableIdentifier,
+
+    overhead: A320AvionicsVentilationOverheadPanel,
+    aevc: AvionicsEquipmentVentilationController,
+    blower_fan: AvionicsVentilationFan,
+    extract_fan: AvionicsVentilationFan,
+    inlet_valve: AvionicsVentilationValve,
+    outlet_valve: AvionicsVentilationValve,
+    conditioning_air_valve: AvionicsVentilationValve,
+    skin_heat_exchanger: SkinHeatExchanger,
+    avionics_compartment: AvionicsCompartment,
+}
+impl A320AvionicsVentilation {
+    const BLOWER_FAN_MASS_FLOW_KG_PER_S: f64 = 0.2;
+    const BLOWER_FAN_POWER_WATT: f64 = 600.;
+    const EXTRACT_FAN_MASS_FLOW_KG_PER_S: f64 = 0.15;
+    const EXTRACT_FAN_POWER_WATT: f64 = 450.;
+    const DUCT_OVERHEAT_TEMPERATURE_DEG_C: f64 = 60.;
+
+    pub fn new(context: &mut InitContext) -> Self {
+        Self {
+            inlet_valve_id: context.get_identifier("VENT_INLET_VALVE".to_owned()),
+            outlet_valve_id: context.get_identifier("VENT_OUTLET_VALVE".to_owned()),
+
+            overhead: A320AvionicsVentilationOverheadPanel::new(context),
+            aevc: AvionicsEquipmentVentilationController::new(ElectricalBusType::DirectCurrent(1)),
+            blower_fan: AvionicsVentilationFan::new(
+                context,
+                "BLOWER",
+                FailureType::AvionicsBlowerFan,
+                ElectricalBusType::AlternatingCurrent(1),
+                Power::new::<watt>(Self::BLOWER_FAN_POWER_WATT),
+                MassRate::new::<kilogram_per_second>(Self::BLOWER_FAN_MASS_FLOW_KG_PER_S),
+            ),
+            extract_fan: AvionicsVentilationFan::new(
+                context,
+                "EXTRACT",
+                FailureType::AvionicsExtractFan,
+                ElectricalBusType::AlternatingCurrent(2),
+                Power::new::<watt>(Self::EXTRACT_FAN_POWER_WATT),
+                MassRate::new::<kilogram_per_second>(Self::EXTRACT_FAN_MASS_FLOW_KG_PER_S),
+            ),
+            inlet_valve: AvionicsVentilationValve::new(),
+            outlet_valve: AvionicsVentilationValve::new(),
+            conditioning_air_valve: AvionicsVentilationValve::new(),
+            skin_heat_exchanger: SkinHeatExchanger::new(),
+            avionics_compartment: AvionicsCompartment::new(
+                context,
+                ElectricalBusType::AlternatingCurrentEssential,
+            ),
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        lgciu: [&impl LgciuWeightOnWheels; 2],
+        cabin: &impl CabinSimulation,
+    ) {
+        let is_on_ground = lgciu
+            .iter()
+            .any(|&a| a.left_and_right_gear_compressed(true));
+        let skin_temperature = SkinHeatExchanger::skin_temperature(context);
+        let cockpit_temperature = cabin.cabin_temperature()[0];
+
+        self.aevc
+            .update(skin_temperature, is_on_ground, &self.overhead);
+
+        let valves_are_powered = self.aevc.is_powered();
+        self.inlet_valve
+            .update(context, self.aevc.inlet_valve_target(), valves_are_powered);
+        self.outlet_valve
+            .update(context, self.aevc.outlet_valve_target(), valves_are_powered);
+        self.conditioning_air_valve.update(
+            context,
+            self.aevc.conditioning_air_valve_target(&self.overhead),
+            valves_are_powered,
+        );
+        self.skin_heat_exchanger.update(
+            context,
+            self.aevc.skin_exchanger_isolation_valve_target(),
+            valves_are_powered,
+        );
+
+        self.blower_fan
+            .update(self.aevc.blower_fan_is_commanded(&self.overhead));
+        self.extract_fan.update(true);
+
+        let ventilation_air_temperature =
+            self.ventilation_air_temperature(context, skin_temperature, cockpit_temperature);
+        self.avionics_compartment.update(
+            context,
+            self.blower_fan.mass_flow() + self.extract_fan.mass_flow(),
+            ventilation_air_temperature,
+            cockpit_temperature,
+        );
+
+        self.update_fault_lights();
+    }
+
+    /// The air blown through the avionics is a mix of outside air from the inlet valve,
+    /// air conditioning air and recirculated air cooled by the skin heat exchanger.
+    fn ventilation_air_temperature(
+        &self,
+        context: &UpdateContext,
+        skin_temperature: ThermodynamicTemperature,
+        cockpit_temperature: ThermodynamicTemperature,
+    ) -> ThermodynamicTemperature {
+        let outside_air_ratio = self.inlet_valve.open_amount().get::<ratio>();
+        let conditioning_air_ratio =
+            self.conditioning_air_valve.open_amount().get::<ratio>() * (1. - outside_air_ratio);
+        let recirculated_air_ratio = 1. - outside_air_ratio - conditioning_air_ratio;
+
+        let recirculated_air_temperature = self
+            .skin_heat_exchanger
+            .outlet_temperature(self.avionics_compartment.temperature(), skin_temperature);
+
+        ThermodynamicTemperature::new::<kelvin>(
+            outside_air_ratio * context.ambient_temperature().get::<kelvin>()
+                + conditioning_air_ratio * cockpit_temperature.get::<kelvin>()
+                + recirculated_air_ratio * recirculated_air_temperature.get::<kelvin>(),
+        )
+    }
+
+    fn update_fault_lights(&mut self) {
+        let duct_overheat = self.avionics_compartment.temperature()
+            > ThermodynamicTemperature::new::<degree_celsius>(
+                Self::DUCT_OVERHEAT_TEMPERATURE_DEG_C,
+            );
+        let aevc_failed = !self.aevc.is_powered();
+
+        self.overhead.blower.set_fault(
+            (self.overhead.blower.is_auto() && !self.blower_fan.is_running())
+                || duct_overheat
+                || aevc_failed,
+        );
+        self.overhead
+            .extract
+            .set_fault(!self.extract_fan.is_running() || aevc_failed);
+    }
+}
+impl SimulationElement for A320AvionicsVentilation {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.overhead.accept(visitor);
+        self.aevc.accept(visitor);
+        self.blower_fan.accept(visitor);
+        self.extract_fan.accept(visitor);
+        self.avionics_compartment.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(
+            &self.inlet_valve_id,
+            self.inlet_valve.open_amount().get::<percent>(),
+        );
+        writer.write(
+            &self.outlet_valve_id,
+            self.outlet_valve.open_amount().get::<percent>(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use systems::{
+        electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+        shared::PotentialOrigin,
+        simulation::{
+            test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+            Aircraft,
+        },
+    };
+
+    struct TestLgciu {
+        compressed: bool,
+    }
+    impl LgciuWeightOnWheels for TestLgciu {
+        fn left_and_right_gear_compressed(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            self.compressed
+        }
+        fn right_gear_compressed(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            self.compressed
+        }
+        fn right_gear_extended(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            !self.compressed
+        }
+        fn left_gear_compressed(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            self.compressed
+        }
+        fn left_gear_extended(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            !self.compressed
+        }
+        fn left_and_right_gear_extended(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            !self.compressed
+        }
+        fn nose_gear_compressed(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            self.compressed
+        }
+        fn nose_gear_extended(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            !self.compressed
+        }
+    }
+
+    struct TestCabin;
+    impl CabinSimulation for TestCabin {
+        fn cabin_temperature(&self) -> Vec<ThermodynamicTemperature> {
+            vec![ThermodynamicTemperature::new::<degree_celsius>(22.); 3]
+        }
+    }
+
+    struct TestAircraft {
+        avionics_ventilation: A320AvionicsVentilation,
+        lgciu: TestLgciu,
+        cabin: TestCabin,
+
+        powered_source: TestElectricitySource,
+        buses: [ElectricalBus; 4],
+        is_ac_2_powered: bool,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                avionics_ventilation: A320AvionicsVentilation::new(context),
+                lgciu: TestLgciu { compressed: true },
+                cabin: TestCabin,
+
+                powered_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::EngineGenerator(1),
+                ),
+                buses: [
+                    ElectricalBusType::AlternatingCurrent(1),
+                    ElectricalBusType::AlternatingCurrentEssential,
+                    ElectricalBusType::DirectCurrent(1),
+                    ElectricalBusType::AlternatingCurrent(2),
+                ]
+                .map(|bus_type| ElectricalBus::new(context, bus_type)),
+                is_ac_2_powered: true,
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _context: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            electricity.supplied_by(&self.powered_source);
+            for bus in &self.buses[..3] {
+                electricity.flow(&self.powered_source, bus);
+            }
+            if self.is_ac_2_powered {
+                electricity.flow(&self.powered_source, &self.buses[3]);
+            }
+        }
+
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.avionics_ventilation
+                .update(context, [&self.lgciu, &self.lgciu], &self.cabin);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<V: SimulationElementVisitor>(&mut self, visitor: &mut V) {
+            self.avionics_ventilation.accept(visitor);
+            for bus in &mut self.buses {
+                bus.accept(visitor);
+            }
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed(ambient_temperature_deg_c: f64) -> SimulationTestBed<TestAircraft> {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(
+            ambient_temperature_deg_c,
+        ));
+
+        test_bed
+    }
+
+    fn in_flight(test_bed: &mut SimulationTestBed<TestAircraft>, mach: f64) {
+        test_bed.set_on_ground(false);
+        test_bed.command(|a| a.lgciu.compressed = false);
+        test_bed.write_by_name("AIRSPEED MACH", mach);
+    }
+
+    fn run_for(test_bed: &mut SimulationTestBed<TestAircraft>, duration: Duration) {
+        test_bed.run_multiple_frames(duration);
+    }
+
+    fn inlet_valve_open_percent(test_bed: &mut SimulationTestBed<TestAircraft>) -> f64 {
+        test_bed.read_by_name("VENT_INLET_VALVE")
+    }
+
+    fn outlet_valve_open_percent(test_bed: &mut SimulationTestBed<TestAircraft>) -> f64 {
+        test_bed.read_by_name("VENT_OUTLET_VALVE")
+    }
+
+    fn is_set(test_bed: &mut SimulationTestBed<TestAircraft>, name: &str) -> bool {
+        test_bed.read_by_name(name)
+    }
+
+    fn avionics_temperature(test_bed: &mut SimulationTestBed<TestAircraft>) -> f64 {
+        let temperature: ThermodynamicTemperature =
+            test_bed.read_by_name("VENT_AVIONICS_TEMPERATURE");
+        temperature.get::<degree_celsius>()
+    }
+
+    #[test]
+    fn open_configuration_on_ground_with_warm_skin() {
+        let mut test_bed = test_bed(20.);
+        run_for(&mut test_bed, Duration::from_secs(20));
+
+        assert!((inlet_valve_open_percent(&mut test_bed) - 100.).abs() < f64::EPSILON);
+        assert!((outlet_valve_open_percent(&mut test_bed) - 100.).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn closed_configuration_on_ground_with_cold_skin() {
+        let mut test_bed = test_bed(0.);
+        run_for(&mut test_bed, Duration::from_secs(20));
+
+        assert!(inlet_valve_open_percent(&mut test_bed) < f64::EPSILON);
+        assert!(outlet_valve_open_percent(&mut test_bed) < f64::EPSILON);
+    }
+
+    #[test]
+    fn ground_configuration_has_skin_temperature_hysteresis() {
+        let mut test_bed = test_bed(20.);
+        run_for(&mut test_bed, Duration::from_secs(20));
+
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(10.));
+        run_for(&mut test_bed, Duration::from_secs(20));
+        assert!((inlet_valve_open_percent(&mut test_bed) - 100.).abs() < f64::EPSILON);
+
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(8.));
+        run_for(&mut test_bed, Duration::from_secs(20));
+        assert!(inlet_valve_open_percent(&mut test_bed) < f64::EPSILON);
+    }
+
+    #[test]
+    fn intermediate_configuration_in_flight_with_hot_skin() {
+        let mut test_bed = test_bed(40.);
+        in_flight(&mut test_bed, 0.3);
+        run_for(&mut test_bed, Duration::from_secs(20));
+
+        assert!(inlet_valve_open_percent(&mut test_bed) < f64::EPSILON);
+        assert!((outlet_valve_open_percent(&mut test_bed) - 30.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn closed_configuration_in_cruise() {
+        let mut test_bed = test_bed(-55.);
+        in_flight(&mut test_bed, 0.78);
+        run_for(&mut test_bed, Duration::from_secs(20));
+
+        assert!(inlet_valve_open_percent(&mut test_bed) < f64::EPSILON);
+        assert!(outlet_valve_open_percent(&mut test_bed) < f64::EPSILON);
+    }
+
+    #[test]
+    fn smoke_configuration_when_both_push_buttons_are_in_override() {
+        let mut test_bed = test_bed(20.);
+        test_bed.write_by_name("OVHD_VENT_BLOWER_PB_IS_AUTO", false);
+        test_bed.write_by_name("OVHD_VENT_EXTRACT_PB_IS_AUTO", false);
+        run_for(&mut test_bed, Duration::from_secs(20));
+
+        assert!(inlet_valve_open_percent(&mut test_bed) < f64::EPSILON);
+        assert!((outlet_valve_open_percent(&mut test_bed) - 30.).abs() < 1e-6);
+        assert!(!is_set(&mut test_bed, "VENT_BLOWER_FAN_IS_RUNNING"));
+        assert!(is_set(&mut test_bed, "VENT_EXTRACT_FAN_IS_RUNNING"));
+        assert!(!is_set(&mut test_bed, "OVHD_VENT_BLOWER_PB_HAS_FAULT"));
+        assert!(!is_set(&mut test_bed, "OVHD_VENT_EXTRACT_PB_HAS_FAULT"));
+    }
+
+    #[test]
+    fn fans_run_without_fault_in_normal_operation() {
+        let mut test_bed = test_bed(20.);
+        run_for(&mut test_bed, Duration::from_secs(20));
+
+        assert!(is_set(&mut test_bed, "VENT_BLOWER_FAN_IS_RUNNING"));
+        assert!(is_set(&mut test_bed, "VENT_EXTRACT_FAN_IS_RUNNING"));
+        assert!(!is_set(&mut test_bed, "OVHD_VENT_BLOWER_PB_HAS_FAULT"));
+        assert!(!is_set(&mut test_bed, "OVHD_VENT_EXTRACT_PB_HAS_FAULT"));
+    }
+
+    #[test]
+    fn failed_blower_fan_shows_blower_fault() {
+        let mut test_bed = test_bed(20.);
+        test_bed.fail(FailureType::AvionicsBlowerFan);
+        run_for(&mut test_bed, Duration::from_secs(1));
+
+        assert!(!is_set(&mut test_bed, "VENT_BLOWER_FAN_IS_RUNNING"));
+        assert!(is_set(&mut test_bed, "OVHD_VENT_BLOWER_PB_HAS_FAULT"));
+        assert!(!is_set(&mut test_bed, "OVHD_VENT_EXTRACT_PB_HAS_FAULT"));
+    }
+
+    #[test]
+    fn unpowered_extract_fan_shows_extract_fault() {
+        let mut test_bed = test_bed(20.);
+        test_bed.command(|a| a.is_ac_2_powered = false);
+        run_for(&mut test_bed, Duration::from_secs(1));
+
+        assert!(!is_set(&mut test_bed, "VENT_EXTRACT_FAN_IS_RUNNING"));
+        assert!(is_set(&mut test_bed, "OVHD_VENT_EXTRACT_PB_HAS_FAULT"));
+        assert!(!is_set(&mut test_bed, "OVHD_VENT_BLOWER_PB_HAS_FAULT"));
+    }
+
+    #[test]
+    fn avionics_temperature_is_stable_with_ventilation() {
+        let mut test_bed = test_bed(20.);
+        run_for(&mut test_bed, Duration::from_secs(3600));
+
+        assert!(avionics_temperature(&mut test_bed) < 35.);
+    }
+
+    #[test]
+    fn avionics_temperature_rises_when_ventilation_is_lost() {
+        let mut test_bed = test_bed(20.);
+        run_for(&mut test_bed, Duration::from_secs(600));
+        let ventilated_temperature = avionics_temperature(&mut test_bed);
+
+        test_bed.fail(FailureType::AvionicsBlowerFan);
+        test_bed.fail(FailureType::AvionicsExtractFan);
+        run_for(&mut test_bed, Duration::from_secs(600));
+
+        assert!(avionics_temperature(&mut test_bed) > ventilated_temperature + 5.);
+    }
+
+    #[test]
+    fn duct_overheat_shows_blower_fault() {
+        let mut test_bed = test_bed(20.);
+        test_bed.fail(FailureType::AvionicsBlowerFan);
+        test_bed.fail(FailureType::AvionicsExtractFan);
+        test_bed.write_by_name("OVHD_VENT_BLOWER_PB_IS_AUTO", false);
+        run_for(&mut test_bed, Duration::from_secs(600));
+        assert!(!is_set(&mut test_bed, "OVHD_VENT_BLOWER_PB_HAS_FAULT"));
+
+        run_for(&mut test_bed, Duration::from_secs(3600));
+        assert!(avionics_temperature(&mut test_bed) > 60.);
+        assert!(is_set(&mut test_bed, "OVHD_VENT_BLOWER_PB_HAS_FAULT"));
+    }
+}
@@ -297,6 +297,11 @@ impl A320AlternatingCurrentElectrical {
         self.main_power_sources.gen_contactor_open(number)
     }
 
+    pub fn engine_generator_output_power(&self, number: usize) -> Power {
+        self.main_power_sources
+            .engine_generator_output_power(number)
+    }
+
     pub fn emergency_generator_contactor_is_closed(&self) -> bool {
         self.emergency_gen_contactor.is_closed()
     }
@@ -495,6 +500,14 @@ impl A320MainPowerSources {
     pub fn gen_contactor_open(&self, number: usize) -> bool {
         self.engine_generator_contactors[number - 1].is_open()
     }
+
+    fn engine_generator_output_power(&self, number: usize) -> Power {
+        match number {
+            1 => self.engine_1_gen.output_power(),
+            2 => self.engine_2_gen.output_power(),
+            _ => panic!("Invalid engine generator number {}", number),
+        }
+    }
 }
 impl SimulationElement for A320MainPowerSources {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
@@ -17,9 +17,9 @@ use systems::electrical::Battery;
 use systems::{
     accept_iterable,
     electrical::{
-        AlternatingCurrentElectricalSystem, BatteryPushButtons, Electricity, EmergencyElectrical,
-        EmergencyGenerator, EngineGeneratorPushButtons, ExternalPowerSource, StaticInverter,
-        TransformerRectifier,
+        AlternatingCurrentElectricalSystem, ApuGeneratorPushButtons, BatteryPushButtons,
+        Electricity, EmergencyElectrical, EmergencyGenerator, EngineGeneratorPushButtons,
+        ExternalPowerSource, StaticInverter, TransformerRectifier,
     },
     engine::Engine,
     overhead::{
@@ -200,6 +200,11 @@ impl A320Electrical {
         self.alternating_current.gen_contactor_open(number)
     }
 
+    pub fn engine_generator_output_power(&self, number: usize) -> Power {
+        self.alternating_current
+            .engine_generator_output_power(number)
+    }
+
     pub fn in_emergency_elec(&self) -> bool {
         self.emergency_elec.is_active()
     }
@@ -327,6 +332,11 @@ impl A320ElectricalOverheadPanel {
         self.galy_and_cab.is_off()
     }
 }
+impl ApuGeneratorPushButtons for A320ElectricalOverheadPanel {
+    fn apu_gen_push_button_is_on(&self, _number: usize) -> bool {
+        self.apu_gen.is_on()
+    }
+}
 impl EngineGeneratorPushButtons for A320ElectricalOverheadPanel {
     fn engine_gen_push_button_is_on(&self, number: usize) -> bool {
         self.generators[number - 1].is_on()
@@ -2057,6 +2067,33 @@ mod a320_electrical_circuit_tests {
         assert!(test_bed.gen_has_fault(number));
     }
 
+    #[rstest]
+    #[case(1)]
+    #[case(2)]
+    fn when_gen_feeder_fault_gen_push_button_has_fault(#[case] number: usize) {
+        let mut test_bed = test_bed_with()
+            .running_engine(number)
+            .and()
+            .failed_gen_feeder(number)
+            .run();
+
+        assert!(test_bed.gen_has_fault(number));
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(2)]
+    fn when_gen_voltage_regulator_failed_gen_trips_on_over_voltage(#[case] number: usize) {
+        let mut test_bed = test_bed_with()
+            .running_engine(number)
+            .and()
+            .failed_gen_voltage_regulator(number)
+            .run()
+            .run();
+
+        assert!(test_bed.gen_has_fault(number));
+    }
+
     fn test_bed_with() -> A320ElectricalTestBed {
         test_bed()
     }
@@ -2144,7 +2181,14 @@ mod a320_electrical_circuit_tests {
         }
     }
     impl ApuGenerator for TestApuGenerator {
-        fn update(&mut self, _n: Ratio, _is_emergency_shutdown: bool) {}
+        fn update(
+            &mut self,
+            _context: &UpdateContext,
+            _n: Ratio,
+            _is_emergency_shutdown: bool,
+            _push_button_is_on: bool,
+        ) {
+        }
 
         fn output_within_normal_parameters(&self) -> bool {
             self.is_available
@@ -2657,6 +2701,20 @@ mod a320_electrical_circuit_tests {
             self
         }
 
+        fn failed_gen_feeder(mut self, number: usize) -> Self {
+            self.test_bed.fail(FailureType::GeneratorFeederFault(
+                PotentialOrigin::EngineGenerator(number),
+            ));
+            self
+        }
+
+        fn failed_gen_voltage_regulator(mut self, number: usize) -> Self {
+            self.test_bed.fail(FailureType::GeneratorVoltageRegulator(
+                PotentialOrigin::EngineGenerator(number),
+            ));
+            self
+        }
+
         fn running_emergency_generator(mut self) -> Self {
             self.command(|a| a.running_emergency_generator());
             self.run_waiting_for(Duration::from_secs(100))
@@ -0,0 +1,464 @@
+use systems::{
+    air_conditioning::{
+        acs_controller::AcscId, cabin_pressure_controller::CpcId, Channel, ZoneType,
+    },
+    failures::FailureType,
+    shared::{
+        AirbusElectricPumpId, AirbusEngineDrivenPumpId, ElectricalBusType, EngineBleedValveId,
+        FireDetectionLoopID, FireDetectionZone, GearActuatorId, HydraulicColor, LeakDetectionZone,
+        LgciuId, PotentialOrigin, ProbeType, ProximityDetectorId, WindowType,
+    },
+};
+
+/// The failures which can be activated on the A320, by the identifier which the failure
+/// manager uses to refer to them.
+pub const A320_FAILURES: &[(u64, FailureType)] = &[
+    (
+        21_000,
+        FailureType::Acsc(AcscId::Acsc1(Channel::ChannelOne)),
+    ),
+    (
+        21_001,
+        FailureType::Acsc(AcscId::Acsc1(Channel::ChannelTwo)),
+    ),
+    (
+        21_002,
+        FailureType::Acsc(AcscId::Acsc2(Channel::ChannelOne)),
+    ),
+    (
+        21_003,
+        FailureType::Acsc(AcscId::Acsc2(Channel::ChannelTwo)),
+    ),
+    (21_004, FailureType::HotAir(1)),
+    (21_005, FailureType::TrimAirHighPressure),
+    (21_006, FailureType::TrimAirFault(ZoneType::Cockpit)),
+    (21_007, FailureType::TrimAirFault(ZoneType::Cabin(1))),
+    (21_008, FailureType::TrimAirFault(ZoneType::Cabin(2))),
+    (21_009, FailureType::TrimAirOverheat(ZoneType::Cockpit)),
+    (21_010, FailureType::TrimAirOverheat(ZoneType::Cabin(1))),
+    (21_011, FailureType::TrimAirOverheat(ZoneType::Cabin(2))),
+    (21_012, FailureType::CabinFan(1)),
+    (21_013, FailureType::CabinFan(2)),
+    (21_014, FailureType::GalleyFans),
+    (21_015, FailureType::CpcFault(CpcId::Cpc1)),
+    (21_016, FailureType::CpcFault(CpcId::Cpc2)),
+    (21_017, FailureType::OutflowValveFault),
+    (21_018, FailureType::SafetyValveFault),
+    (21_019, FailureType::RapidDecompression),
+    (21_020, FailureType::AvionicsBlowerFan),
+    (21_021, FailureType::AvionicsExtractFan),
+    (24_000, FailureType::TransformerRectifier(1)),
+    (24_001, FailureType::TransformerRectifier(2)),
+    (24_002, FailureType::TransformerRectifier(3)),
+    (24_004, FailureType::StaticInverter),
+    (24_020, FailureType::Generator(1)),
+    (24_021, FailureType::Generator(2)),
+    (24_030, FailureType::ApuGenerator(1)),
+    (
+        24_040,
+        FailureType::GeneratorVoltageRegulator(PotentialOrigin::EngineGenerator(1)),
+    ),
+    (
+        24_041,
+        FailureType::GeneratorVoltageRegulator(PotentialOrigin::EngineGenerator(2)),
+    ),
+    (
+        24_044,
+        FailureType::GeneratorVoltageRegulator(PotentialOrigin::ApuGenerator(1)),
+    ),
+    (
+        24_050,
+        FailureType::GeneratorFeederFault(PotentialOrigin::EngineGenerator(1)),
+    ),
+    (
+        24_051,
+        FailureType::GeneratorFeederFault(PotentialOrigin::EngineGenerator(2)),
+    ),
+    (
+        24_054,
+        FailureType::GeneratorFeederFault(PotentialOrigin::ApuGenerator(1)),
+    ),
+    (
+        24_100,
+        FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrent(1)),
+    ),
+    (
+        24_101,
+        FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrent(2)),
+    ),
+    (
+        24_102,
+        FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrentEssential),
+    ),
+    (
+        24_103,
+        FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrentEssentialShed),
+    ),
+    (
+        24_104,
+        FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrentStaticInverter),
+    ),
+    (
+        24_105,
+        FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrentGndFltService),
+    ),
+    (
+        24_106,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrent(1)),
+    ),
+    (
+        24_107,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrent(2)),
+    ),
+    (
+        24_108,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrentEssential),
+    ),
+    (
+        24_109,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrentEssentialShed),
+    ),
+    (
+        24_110,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrentBattery),
+    ),
+    (
+        24_111,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrentHot(1)),
+    ),
+    (
+        24_112,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrentHot(2)),
+    ),
+    (
+        24_113,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrentGndFltService),
+    ),
+    (26_001, FailureType::SetOnFire(FireDetectionZone::Engine(1))),
+    (26_002, FailureType::SetOnFire(FireDetectionZone::Engine(2))),
+    (26_003, FailureType::SetOnFire(FireDetectionZone::Apu)),
+    (26_004, FailureType::SetOnFire(FireDetectionZone::Cargo)),
+    (
+        26_005,
+        FailureType::FireDetectionLoop(FireDetectionLoopID::A, FireDetectionZone::Engine(1)),
+    ),
+    (
+        26_006,
+        FailureType::FireDetectionLoop(FireDetectionLoopID::B, FireDetectionZone::Engine(1)),
+    ),
+    (
+        26_007,
+        FailureType::FireDetectionLoop(FireDetectionLoopID::A, FireDetectionZone::Engine(2)),
+    ),
+    (
+        26_008,
+        FailureType::FireDetectionLoop(FireDetectionLoopID::B, FireDetectionZone::Engine(2)),
+    ),
+    (
+        26_009,
+        FailureType::FireDetectionLoop(FireDetectionLoopID::A, FireDetectionZone::Apu),
+    ),
+    (
+        26_010,
+        FailureType::FireDetectionLoop(FireDetectionLoopID::B, FireDetectionZone::Apu),
+    ),
+    (
+        26_011,
+        FailureType::FireDetectionLoop(FireDetectionLoopID::A, FireDetectionZone::Cargo),
+    ),
+    (
+        26_012,
+        FailureType::FireDetectionLoop(FireDetectionLoopID::B, FireDetectionZone::Cargo),
+    ),
+    (28_000, FailureType::FuelPump(2)),
+    (28_001, FailureType::FuelPump(5)),
+    (28_002, FailureType::FuelPump(3)),
+    (28_003, FailureType::FuelPump(6)),
+    (28_004, FailureType::FuelPump(7)),
+    (28_010, FailureType::FuelValve(1)),
+    (28_011, FailureType::FuelValve(2)),
+    (28_012, FailureType::FuelValve(3)),
+    (28_013, FailureType::FuelValve(4)),
+    (28_014, FailureType::FuelValve(5)),
+    (28_015, FailureType::FuelValve(9)),
+    (28_016, FailureType::FuelValve(10)),
+    (28_020, FailureType::FuelTankLeak(1)),
+    (28_021, FailureType::FuelTankLeak(2)),
+    (28_022, FailureType::FuelTankLeak(3)),
+    (28_023, FailureType::FuelTankLeak(4)),
+    (28_024, FailureType::FuelTankLeak(5)),
+    (28_030, FailureType::FuelQuantityIndication(1)),
+    (28_031, FailureType::FuelQuantityIndication(2)),
+    (29_000, FailureType::ReservoirLeak(HydraulicColor::Green)),
+    (29_001, FailureType::ReservoirLeak(HydraulicColor::Blue)),
+    (29_002, FailureType::ReservoirLeak(HydraulicColor::Yellow)),
+    (29_003, FailureType::ReservoirAirLeak(HydraulicColor::Green)),
+    (29_004, FailureType::ReservoirAirLeak(HydraulicColor::Blue)),
+    (
+        29_005,
+        FailureType::ReservoirAirLeak(HydraulicColor::Yellow),
+    ),
+    (
+        29_006,
+        FailureType::ReservoirReturnLeak(HydraulicColor::Green),
+    ),
+    (
+        29_007,
+        FailureType::ReservoirReturnLeak(HydraulicColor::Blue),
+    ),
+    (
+        29_008,
+        FailureType::ReservoirReturnLeak(HydraulicColor::Yellow),
+    ),
+    (
+        29_009,
+        FailureType::EnginePumpOverheat(AirbusEngineDrivenPumpId::Green),
+    ),
+    (
+        29_010,
+        FailureType::ElecPumpOverheat(AirbusElectricPumpId::Blue),
+    ),
+    (
+        29_011,
+        FailureType::EnginePumpOverheat(AirbusEngineDrivenPumpId::Yellow),
+    ),
+    (
+        29_012,
+        FailureType::ElecPumpOverheat(AirbusElectricPumpId::Yellow),
+    ),
+    (30_011, FailureType::ProbeHeater(1, ProbeType::Pitot)),
+    (30_012, FailureType::ProbeHeater(1, ProbeType::LeftStatic)),
+    (30_013, FailureType::ProbeHeater(1, ProbeType::RightStatic)),
+    (
+        30_014,
+        FailureType::ProbeHeater(1, ProbeType::TotalAirTemperature),
+    ),
+    (
+        30_015,
+        FailureType::ProbeHeater(1, ProbeType::AngleOfAttack),
+    ),
+    (30_021, FailureType::ProbeHeater(2, ProbeType::Pitot)),
+    (30_022, FailureType::ProbeHeater(2, ProbeType::LeftStatic)),
+    (30_023, FailureType::ProbeHeater(2, ProbeType::RightStatic)),
+    (
+        30_024,
+        FailureType::ProbeHeater(2, ProbeType::TotalAirTemperature),
+    ),
+    (
+        30_025,
+        FailureType::ProbeHeater(2, ProbeType::AngleOfAttack),
+    ),
+    (30_031, FailureType::ProbeHeater(3, ProbeType::Pitot)),
+    (30_032, FailureType::ProbeHeater(3, ProbeType::LeftStatic)),
+    (30_033, FailureType::ProbeHeater(3, ProbeType::RightStatic)),
+    (
+        30_035,
+        FailureType::ProbeHeater(3, ProbeType::AngleOfAttack),
+    ),
+    (30_041, FailureType::WindowHeater(1, WindowType::Windshield)),
+    (30_042, FailureType::WindowHeater(1, WindowType::SideWindow)),
+    (30_043, FailureType::WindowHeatRegulation(1)),
+    (30_051, FailureType::WindowHeater(2, WindowType::Windshield)),
+    (30_052, FailureType::WindowHeater(2, WindowType::SideWindow)),
+    (30_053, FailureType::WindowHeatRegulation(2)),
+    (30_061, FailureType::EngineAntiIceValve(1)),
+    (30_062, FailureType::EngineAntiIceValve(2)),
+    (32_000, FailureType::LgciuPowerSupply(LgciuId::Lgciu1)),
+    (32_001, FailureType::LgciuPowerSupply(LgciuId::Lgciu2)),
+    (32_002, FailureType::LgciuInternalError(LgciuId::Lgciu1)),
+    (32_003, FailureType::LgciuInternalError(LgciuId::Lgciu2)),
+    (
+        32_004,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::UplockGearNose1),
+    ),
+    (
+        32_005,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockGearNose2),
+    ),
+    (
+        32_006,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::UplockGearRight1),
+    ),
+    (
+        32_007,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockGearRight2),
+    ),
+    (
+        32_008,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::UplockGearLeft2),
+    ),
+    (
+        32_009,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockGearLeft1),
+    ),
+    (
+        32_010,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::UplockDoorNose1),
+    ),
+    (
+        32_011,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockDoorNose2),
+    ),
+    (
+        32_012,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::UplockDoorRight2),
+    ),
+    (
+        32_013,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockDoorRight1),
+    ),
+    (
+        32_014,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::UplockDoorLeft2),
+    ),
+    (
+        32_015,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockDoorLeft1),
+    ),
+    (
+        32_020,
+        FailureType::GearActuatorJammed(GearActuatorId::GearNose),
+    ),
+    (
+        32_021,
+        FailureType::GearActuatorJammed(GearActuatorId::GearLeft),
+    ),
+    (
+        32_022,
+        FailureType::GearActuatorJammed(GearActuatorId::GearRight),
+    ),
+    (
+        32_023,
+        FailureType::GearActuatorJammed(GearActuatorId::GearDoorNose),
+    ),
+    (
+        32_024,
+        FailureType::GearActuatorJammed(GearActuatorId::GearDoorLeft),
+    ),
+    (
+        32_025,
+        FailureType::GearActuatorJammed(GearActuatorId::GearDoorRight),
+    ),
+    (
+        32_100,
+        FailureType::BrakeHydraulicLeak(HydraulicColor::Green),
+    ),
+    (
+        32_101,
+        FailureType::BrakeHydraulicLeak(HydraulicColor::Yellow),
+    ),
+    (32_150, FailureType::BrakeAccumulatorGasLeak),
+    (34_000, FailureType::RadioAltimeter(1)),
+    (34_001, FailureType::RadioAltimeter(2)),
+    (34_010, FailureType::RadioAntennaInterrupted(1)),
+    (34_011, FailureType::RadioAntennaInterrupted(2)),
+    (34_020, FailureType::RadioAntennaDirectCoupling(1)),
+    (34_021, FailureType::RadioAntennaDirectCoupling(2)),
+    (34_030, FailureType::InertialReference(1)),
+    (34_031, FailureType::InertialReference(2)),
+    (34_032, FailureType::InertialReference(3)),
+    (34_040, FailureType::GpsReceiver(1)),
+    (34_041, FailureType::GpsReceiver(2)),
+    (35_000, FailureType::CrewOxygenBottleLeak(1)),
+    (35_010, FailureType::CrewOxygenRegulatorLowPressure),
+    (
+        36_000,
+        FailureType::BleedAirLeak(LeakDetectionZone::Wing(1)),
+    ),
+    (
+        36_001,
+        FailureType::BleedAirLeak(LeakDetectionZone::Wing(2)),
+    ),
+    (
+        36_010,
+        FailureType::BleedAirLeak(LeakDetectionZone::Pylon(1)),
+    ),
+    (
+        36_011,
+        FailureType::BleedAirLeak(LeakDetectionZone::Pylon(2)),
+    ),
+    (36_020, FailureType::BleedAirLeak(LeakDetectionZone::Apu)),
+    (
+        36_100,
+        FailureType::LeakDetectionLoop(LeakDetectionZone::Wing(1)),
+    ),
+    (
+        36_101,
+        FailureType::LeakDetectionLoop(LeakDetectionZone::Wing(2)),
+    ),
+    (
+        36_110,
+        FailureType::LeakDetectionLoop(LeakDetectionZone::Pylon(1)),
+    ),
+    (
+        36_111,
+        FailureType::LeakDetectionLoop(LeakDetectionZone::Pylon(2)),
+    ),
+    (
+        36_120,
+        FailureType::LeakDetectionLoop(LeakDetectionZone::Apu),
+    ),
+    (
+        36_200,
+        FailureType::EngineBleedValveJammedOpen(1, EngineBleedValveId::HighPressure),
+    ),
+    (
+        36_201,
+        FailureType::EngineBleedValveJammedOpen(2, EngineBleedValveId::HighPressure),
+    ),
+    (
+        36_210,
+        FailureType::EngineBleedValveJammedClosed(1, EngineBleedValveId::HighPressure),
+    ),
+    (
+        36_211,
+        FailureType::EngineBleedValveJammedClosed(2, EngineBleedValveId::HighPressure),
+    ),
+    (
+        36_220,
+        FailureType::EngineBleedValveJammedOpen(1, EngineBleedValveId::PressureRegulating),
+    ),
+    (
+        36_221,
+        FailureType::EngineBleedValveJammedOpen(2, EngineBleedValveId::PressureRegulating),
+    ),
+    (
+        36_230,
+        FailureType::EngineBleedValveJammedClosed(1, EngineBleedValveId::PressureRegulating),
+    ),
+    (
+        36_231,
+        FailureType::EngineBleedValveJammedClosed(2, EngineBleedValveId::PressureRegulating),
+    ),
+    (
+        36_240,
+        FailureType::EngineBleedValveJammedOpen(1, EngineBleedValveId::FanAir),
+    ),
+    (
+        36_241,
+        FailureType::EngineBleedValveJammedOpen(2, EngineBleedValveId::FanAir),
+    ),
+    (
+        36_250,
+        FailureType::EngineBleedValveJammedClosed(1, EngineBleedValveId::FanAir),
+    ),
+    (
+        36_251,
+        FailureType::EngineBleedValveJammedClosed(2, EngineBleedValveId::FanAir),
+    ),
+    (36_300, FailureType::PrecoolerBlockage(1)),
+    (36_301, FailureType::PrecoolerBlockage(2)),
+    (36_310, FailureType::EngineBleedOverpressure(1)),
+    (36_311, FailureType::EngineBleedOverpressure(2)),
+    (36_320, FailureType::EngineBleedOvertemperature(1)),
+    (36_321, FailureType::EngineBleedOvertemperature(2)),
+    (36_400, FailureType::BleedMonitoringComputer(1)),
+    (36_401, FailureType::BleedMonitoringComputer(2)),
+    (49_000, FailureType::ApuStartMotor),
+    (49_010, FailureType::ApuFuelControlUnit),
+    (49_020, FailureType::ApuAirIntakeFlapJammed),
+    (49_030, FailureType::ApuEgtSensor),
+    (49_040, FailureType::ApuSpeedSensor(1)),
+    (49_041, FailureType::ApuSpeedSensor(2)),
+    (49_050, FailureType::ApuInletPressureSensor),
+];
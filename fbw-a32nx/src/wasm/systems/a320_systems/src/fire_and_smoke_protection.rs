@@ -0,0 +1,382 @@
+use systems::{
+    apu::AuxiliaryPowerUnitFireOverheadPanel,
+    fire_protection::{
+        ExtinguishingAgentBottle, FireDetectionUnit, FireExtinguishingSystem, FireTestPushButton,
+        SetOnFireModule,
+    },
+    shared::{
+        ElectricalBusType, EngineFirePushButtons, FireDetectionZone, FireTestPushButtons,
+        LgciuWeightOnWheels,
+    },
+    simulation::{InitContext, SimulationElement, SimulationElementVisitor, UpdateContext},
+};
+
+// The cargo compartment is monitored by two smoke detector loops, which the FDU treats
+// just like the fire detection loops of the engines and APU.
+const FIRE_DETECTION_ZONES: [FireDetectionZone; 4] = [
+    FireDetectionZone::Engine(1),
+    FireDetectionZone::Engine(2),
+    FireDetectionZone::Apu,
+    FireDetectionZone::Cargo,
+];
+
+pub(super) struct A320FireAndSmokeProtection {
+    fire_test_panel: A320FireTestPanel,
+    fire_detection_unit: FireDetectionUnit<4>,
+    fire_extinguishing_system: FireExtinguishingSystem<6>,
+
+    set_zone_on_fire: SetOnFireModule<4>,
+}
+impl A320FireAndSmokeProtection {
+    pub(super) fn new(context: &mut InitContext) -> Self {
+        Self {
+            fire_test_panel: A320FireTestPanel::new(context),
+            fire_detection_unit: FireDetectionUnit::new(
+                context,
+                FIRE_DETECTION_ZONES,
+                [
+                    ElectricalBusType::DirectCurrentEssential,
+                    ElectricalBusType::DirectCurrent(2),
+                ],
+            ),
+            fire_extinguishing_system: Self::new_fire_extinguishing_system(context),
+
+            set_zone_on_fire: SetOnFireModule::new(context, FIRE_DETECTION_ZONES),
+        }
+    }
+
+    /// Two bottles for each engine, one for the APU and one for the cargo compartment
+    fn new_fire_extinguishing_system(context: &mut InitContext) -> FireExtinguishingSystem<6> {
+        let squib_1_powered_by = [
+            ElectricalBusType::DirectCurrentHot(1),
+            ElectricalBusType::DirectCurrentEssential,
+        ];
+        let squib_2_powered_by = [
+            ElectricalBusType::DirectCurrentHot(2),
+            ElectricalBusType::DirectCurrent(2),
+        ];
+
+        FireExtinguishingSystem::new([
+            ExtinguishingAgentBottle::new(
+                context,
+                "1_ENG_1",
+                FireDetectionZone::Engine(1),
+                squib_1_powered_by,
+            ),
+            ExtinguishingAgentBottle::new(
+                context,
+                "2_ENG_1",
+                FireDetectionZone::Engine(1),
+                squib_2_powered_by,
+            ),
+            ExtinguishingAgentBottle::new(
+                context,
+                "1_ENG_2",
+                FireDetectionZone::Engine(2),
+                squib_1_powered_by,
+            ),
+            ExtinguishingAgentBottle::new(
+                context,
+                "2_ENG_2",
+                FireDetectionZone::Engine(2),
+                squib_2_powered_by,
+            ),
+            ExtinguishingAgentBottle::new(
+                context,
+                "1_APU_1",
+                FireDetectionZone::Apu,
+                [
+                    ElectricalBusType::DirectCurrentHot(1),
+                    ElectricalBusType::DirectCurrentBattery,
+                ],
+            ),
+            ExtinguishingAgentBottle::new(
+                context,
+                "1_CARGO_1",
+                FireDetectionZone::Cargo,
+                squib_1_powered_by,
+            ),
+        ])
+    }
+
+    pub(super) fn update(
+        &mut self,
+        context: &UpdateContext,
+        engine_fire_push_buttons: &impl EngineFirePushButtons,
+        apu_fire_overhead: &AuxiliaryPowerUnitFireOverheadPanel,
+        lgciu: [&impl LgciuWeightOnWheels; 2],
+    ) {
+        self.fire_test_panel.update(context);
+        self.fire_detection_unit
+            .update(context, &self.fire_test_panel, lgciu);
+        self.fire_extinguishing_system.update(
+            context,
+            engine_fire_push_buttons,
+            apu_fire_overhead,
+            &self.fire_test_panel,
+            self.fire_detection_unit.should_extinguish_apu_fire(),
+        );
+
+        self.set_zone_on_fire
+            .update(&self.fire_extinguishing_system);
+    }
+
+    pub fn apu_fire_on_ground(&self) -> bool {
+        self.fire_detection_unit.apu_fire_on_ground()
+    }
+}
+impl SimulationElement for A320FireAndSmokeProtection {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.fire_test_panel.accept(visitor);
+        self.fire_detection_unit.accept(visitor);
+        self.fire_extinguishing_system.accept(visitor);
+        self.set_zone_on_fire.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
+/// Unlike the A380, each zone has its own test push button.
+struct A320FireTestPanel {
+    engine_1_test_pushbutton: FireTestPushButton,
+    engine_2_test_pushbutton: FireTestPushButton,
+    apu_test_pushbutton: FireTestPushButton,
+    cargo_test_pushbutton: FireTestPushButton,
+}
+impl A320FireTestPanel {
+    fn new(context: &mut InitContext) -> Self {
+        Self {
+            engine_1_test_pushbutton: FireTestPushButton::new(context, "FIRE_TEST_ENG1"),
+            engine_2_test_pushbutton: FireTestPushButton::new(context, "FIRE_TEST_ENG2"),
+            apu_test_pushbutton: FireTestPushButton::new(context, "FIRE_TEST_APU"),
+            cargo_test_pushbutton: FireTestPushButton::new(context, "FIRE_TEST_CARGO"),
+        }
+    }
+
+    fn update(&mut self, context: &UpdateContext) {
+        self.engine_1_test_pushbutton.update(context);
+        self.engine_2_test_pushbutton.update(context);
+        self.apu_test_pushbutton.update(context);
+        self.cargo_test_pushbutton.update(context);
+    }
+}
+impl FireTestPushButtons for A320FireTestPanel {
+    fn is_pressed(&self, zone: FireDetectionZone) -> bool {
+        match zone {
+            FireDetectionZone::Engine(1) => self.engine_1_test_pushbutton.is_pressed(),
+            FireDetectionZone::Engine(2) => self.engine_2_test_pushbutton.is_pressed(),
+            FireDetectionZone::Apu => self.apu_test_pushbutton.is_pressed(),
+            FireDetectionZone::Cargo => self.cargo_test_pushbutton.is_pressed(),
+            _ => false,
+        }
+    }
+}
+impl SimulationElement for A320FireTestPanel {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.engine_1_test_pushbutton.accept(visitor);
+        self.engine_2_test_pushbutton.accept(visitor);
+        self.apu_test_pushbutton.accept(visitor);
+        self.cargo_test_pushbutton.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use systems::{
+        electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+        engine::EngineFireOverheadPanel,
+        shared::PotentialOrigin,
+        simulation::{
+            test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+            Aircraft,
+        },
+    };
+
+    struct TestLgciu {
+        compressed: bool,
+    }
+    impl LgciuWeightOnWheels for TestLgciu {
+        fn left_and_right_gear_compressed(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            self.compressed
+        }
+        fn right_gear_compressed(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            self.compressed
+        }
+        fn right_gear_extended(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            !self.compressed
+        }
+        fn left_gear_compressed(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            self.compressed
+        }
+        fn left_gear_extended(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            !self.compressed
+        }
+        fn left_and_right_gear_extended(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            !self.compressed
+        }
+        fn nose_gear_compressed(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            self.compressed
+        }
+        fn nose_gear_extended(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            !self.compressed
+        }
+    }
+
+    struct TestAircraft {
+        fire_and_smoke_protection: A320FireAndSmokeProtection,
+        engine_fire_overhead: EngineFireOverheadPanel<2>,
+        apu_fire_overhead: AuxiliaryPowerUnitFireOverheadPanel,
+        lgciu: TestLgciu,
+
+        powered_source: TestElectricitySource,
+        buses: [ElectricalBus; 5],
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                fire_and_smoke_protection: A320FireAndSmokeProtection::new(context),
+                engine_fire_overhead: EngineFireOverheadPanel::new(context),
+                apu_fire_overhead: AuxiliaryPowerUnitFireOverheadPanel::new(context),
+                lgciu: TestLgciu { compressed: true },
+
+                powered_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::Battery(1),
+                ),
+                buses: [
+                    ElectricalBusType::DirectCurrentEssential,
+                    ElectricalBusType::DirectCurrent(2),
+                    ElectricalBusType::DirectCurrentHot(1),
+                    ElectricalBusType::DirectCurrentHot(2),
+                    ElectricalBusType::DirectCurrentBattery,
+                ]
+                .map(|bus_type| ElectricalBus::new(context, bus_type)),
+            }
+        }
+
+        fn set_on_ground(&mut self, on_ground: bool) {
+            self.lgciu.compressed = on_ground;
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _context: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            electricity.supplied_by(&self.powered_source);
+            for bus in &self.buses {
+                electricity.flow(&self.powered_source, bus);
+            }
+        }
+
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.fire_and_smoke_protection.update(
+                context,
+                &self.engine_fire_overhead,
+                &self.apu_fire_overhead,
+                [&self.lgciu, &self.lgciu],
+            );
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<V: SimulationElementVisitor>(&mut self, visitor: &mut V) {
+            self.fire_and_smoke_protection.accept(visitor);
+            self.engine_fire_overhead.accept(visitor);
+            self.apu_fire_overhead.accept(visitor);
+            for bus in &mut self.buses {
+                bus.accept(visitor);
+            }
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed() -> SimulationTestBed<TestAircraft> {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.run();
+
+        test_bed
+    }
+
+    fn is_set(test_bed: &mut SimulationTestBed<TestAircraft>, name: &str) -> bool {
+        test_bed.read_by_name(name)
+    }
+
+    #[test]
+    fn engine_fire_is_detected() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("ENG ON FIRE:2", true);
+        test_bed.run();
+
+        assert!(!is_set(&mut test_bed, "FIRE_DETECTED_ENG1"));
+        assert!(is_set(&mut test_bed, "FIRE_DETECTED_ENG2"));
+    }
+
+    #[test]
+    fn cargo_smoke_is_detected() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("CARGO_ON_FIRE", true);
+        test_bed.run();
+
+        assert!(is_set(&mut test_bed, "FIRE_DETECTED_CARGO"));
+    }
+
+    #[test]
+    fn each_test_push_button_only_tests_its_own_zone() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("FIRE_TEST_ENG1", true);
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!(is_set(&mut test_bed, "FIRE_DETECTED_ENG1"));
+        assert!(is_set(&mut test_bed, "FIRE_SQUIB_1_ENG_1_IS_ARMED"));
+        assert!(!is_set(&mut test_bed, "FIRE_DETECTED_ENG2"));
+        assert!(!is_set(&mut test_bed, "FIRE_DETECTED_APU"));
+        assert!(!is_set(&mut test_bed, "FIRE_DETECTED_CARGO"));
+    }
+
+    #[test]
+    fn apu_test_on_ground_does_not_discharge_the_apu_bottle() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("FIRE_TEST_APU", true);
+        test_bed.run_with_delta(Duration::from_secs(15));
+
+        assert!(is_set(&mut test_bed, "FIRE_DETECTED_APU"));
+        assert!(!test_bed.query(|a| a.fire_and_smoke_protection.apu_fire_on_ground()));
+
+        test_bed.write_by_name("FIRE_TEST_APU", false);
+        test_bed.run();
+
+        assert!(!is_set(&mut test_bed, "FIRE_SQUIB_1_APU_1_IS_DISCHARGED"));
+    }
+
+    #[test]
+    fn apu_fire_on_ground_requests_apu_shutdown_and_discharges_the_apu_bottle() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("APU_ON_FIRE", true);
+        test_bed.run();
+
+        assert!(test_bed.query(|a| a.fire_and_smoke_protection.apu_fire_on_ground()));
+
+        test_bed.run_with_delta(Duration::from_secs(10));
+        test_bed.run_with_delta(Duration::from_secs(1));
+        test_bed.run();
+
+        assert!(is_set(&mut test_bed, "FIRE_SQUIB_1_APU_1_IS_DISCHARGED"));
+    }
+
+    #[test]
+    fn apu_fire_in_flight_does_not_request_apu_shutdown() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_on_ground(false));
+        test_bed.write_by_name("APU_ON_FIRE", true);
+        test_bed.run();
+
+        assert!(is_set(&mut test_bed, "FIRE_DETECTED_APU"));
+        assert!(!test_bed.query(|a| a.fire_and_smoke_protection.apu_fire_on_ground()));
+    }
+}
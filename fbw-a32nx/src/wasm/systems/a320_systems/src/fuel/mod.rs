@@ -1,12 +1,18 @@
-// Note: Fuel system for now is still handled in MSFS. This is used for calculating fuel-related factors.
-
 use nalgebra::Vector3;
 use systems::{
-    fuel::{FuelCG, FuelInfo, FuelPayload, FuelPump, FuelPumpProperties, FuelSystem},
+    accept_iterable,
+    failures::{Failure, FailureType},
+    fuel::{
+        EngineFeed, FeedPump, FuelCG, FuelInfo, FuelPayload, FuelPump, FuelPumpProperties,
+        FuelSystem, FuelValve, FUEL_GALLONS_TO_KG,
+    },
     shared::ElectricalBusType,
-    simulation::{InitContext, SimulationElement, SimulationElementVisitor},
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+    },
 };
-use uom::si::f64::*;
+use uom::si::{f64::*, mass::kilogram, mass_rate::kilogram_per_hour};
 
 #[cfg(test)]
 mod test;
@@ -20,6 +26,7 @@ pub trait FuelLevel {
     fn center_tank_has_fuel(&self) -> bool;
 }
 
+#[derive(Clone, Copy)]
 pub enum A320FuelTankType {
     Center,
     LeftInner,
@@ -48,6 +55,30 @@ impl From<usize> for A320FuelTankType {
 
 pub struct A320Fuel {
     fuel_system: FuelSystem<5, 5>,
+
+    engine_fuel_flow_ids: [VariableIdentifier; 2],
+    engine_fuel_flows: [MassRate; 2],
+    apu_fuel_flow_id: VariableIdentifier,
+    apu_fuel_flow: MassRate,
+
+    lp_valve_switch_ids: [VariableIdentifier; 2],
+    lp_valve_switches: [bool; 2],
+    lp_valves: [FuelValve; 2],
+    crossfeed_valve_switch_id: VariableIdentifier,
+    crossfeed_valve_switch: bool,
+    crossfeed_valve: FuelValve,
+    outer_tank_transfer_valves: [FuelValve; 2],
+    outer_tank_transfer_latched_open: [bool; 2],
+    center_tank_transfer_switch_ids: [VariableIdentifier; 2],
+    center_tank_transfer_switches: [bool; 2],
+    center_tank_transfer_valves: [FuelValve; 2],
+
+    engine_is_fed_ids: [VariableIdentifier; 2],
+    engine_is_fed: [bool; 2],
+    apu_fuel_feed_pressure: Pressure,
+
+    fuel_quantity_indication_is_valid_id: VariableIdentifier,
+    fuel_quantity_indication_failures: [Failure; 2],
 }
 impl A320Fuel {
     pub const A320_FUEL: [FuelInfo<'static>; 5] = [
@@ -78,7 +109,6 @@ impl A320Fuel {
         },
     ];
 
-    // TODO: connect MSFS fuel pumps to power logic
     const FUEL_PUMPS: [(usize, FuelPumpProperties); 5] = [
         // Left main tank pump 1
         (
@@ -122,18 +152,195 @@ impl A320Fuel {
         ),
     ];
 
+    const ENGINE_FEEDS: [EngineFeed; 2] = [
+        EngineFeed {
+            pumps: &[
+                FeedPump {
+                    pump: 0,
+                    tank: A320FuelTankType::LeftInner as usize,
+                    priority: 0,
+                },
+                FeedPump {
+                    pump: 1,
+                    tank: A320FuelTankType::LeftInner as usize,
+                    priority: 0,
+                },
+            ],
+            suction_tank: A320FuelTankType::LeftInner as usize,
+        },
+        EngineFeed {
+            pumps: &[
+                FeedPump {
+                    pump: 2,
+                    tank: A320FuelTankType::RightInner as usize,
+                    priority: 0,
+                },
+                FeedPump {
+                    pump: 3,
+                    tank: A320FuelTankType::RightInner as usize,
+                    priority: 0,
+                },
+            ],
+            suction_tank: A320FuelTankType::RightInner as usize,
+        },
+    ];
+
+    // The outer tank transfer valves open when an inner tank reaches this quantity
+    // and stay open until the outer tank is refuelled.
+    const OUTER_TANK_TRANSFER_INNER_TANK_QUANTITY_KG: f64 = 750.;
+    const OUTER_TANK_TRANSFER_RATE_KG_PER_HOUR: f64 = 2000.;
+    // The center tank is emptied into the inner tanks by jet pumps,
+    // which are driven by the pressure of the wing tank pumps.
+    const CENTER_TANK_TRANSFER_RATE_KG_PER_HOUR: f64 = 4000.;
+
     pub fn new(context: &mut InitContext) -> Self {
-        let fuel_tanks = Self::A320_FUEL.map(|f| f.into_fuel_tank(context, false));
+        let fuel_tanks = Self::A320_FUEL.map(|f| f.into_fuel_tank(context, true));
         let fuel_pumps =
             Self::FUEL_PUMPS.map(|(id, properties)| FuelPump::new(context, id, properties));
         A320Fuel {
             fuel_system: FuelSystem::new(context, fuel_tanks, fuel_pumps),
+
+            engine_fuel_flow_ids: [1, 2]
+                .map(|id| context.get_identifier(format!("ENGINE_FF:{id}"))),
+            engine_fuel_flows: [MassRate::default(); 2],
+            apu_fuel_flow_id: context.get_identifier("FUELSYSTEM LINE FUEL FLOW:18".to_owned()),
+            apu_fuel_flow: MassRate::default(),
+
+            lp_valve_switch_ids: [1, 2]
+                .map(|id| context.get_identifier(format!("FUELSYSTEM VALVE SWITCH:{id}"))),
+            lp_valve_switches: [false; 2],
+            lp_valves: [
+                FuelValve::new(context, 1, ElectricalBusType::DirectCurrentHot(1)),
+                FuelValve::new(context, 2, ElectricalBusType::DirectCurrent(2)),
+            ],
+            crossfeed_valve_switch_id: context
+                .get_identifier("FUELSYSTEM VALVE SWITCH:3".to_owned()),
+            crossfeed_valve_switch: false,
+            crossfeed_valve: FuelValve::new(context, 3, ElectricalBusType::DirectCurrentEssential),
+            outer_tank_transfer_valves: [
+                FuelValve::new(context, 4, ElectricalBusType::DirectCurrentEssential),
+                FuelValve::new(context, 5, ElectricalBusType::DirectCurrentEssential),
+            ],
+            outer_tank_transfer_latched_open: [false; 2],
+            center_tank_transfer_switch_ids: [9, 10]
+                .map(|id| context.get_identifier(format!("FUELSYSTEM VALVE SWITCH:{id}"))),
+            center_tank_transfer_switches: [false; 2],
+            center_tank_transfer_valves: [
+                FuelValve::new(context, 9, ElectricalBusType::DirectCurrent(1)),
+                FuelValve::new(context, 10, ElectricalBusType::DirectCurrent(2)),
+            ],
+
+            engine_is_fed_ids: [1, 2]
+                .map(|id| context.get_identifier(format!("FUEL_ENGINE_{id}_IS_FED"))),
+            engine_is_fed: [false; 2],
+            apu_fuel_feed_pressure: Pressure::default(),
+
+            fuel_quantity_indication_is_valid_id: context
+                .get_identifier("FUEL_QUANTITY_INDICATION_IS_VALID".to_owned()),
+            fuel_quantity_indication_failures: [1, 2]
+                .map(|channel| Failure::new(FailureType::FuelQuantityIndication(channel))),
         }
     }
 
-    pub fn left_inner_tank_has_fuel_remaining(&self) -> bool {
-        self.fuel_system
-            .tank_has_fuel(A320FuelTankType::LeftInner.into())
+    pub(crate) fn update(&mut self, context: &UpdateContext) {
+        self.fuel_system.update(context);
+
+        for (valve, should_open) in self.lp_valves.iter_mut().zip(self.lp_valve_switches) {
+            valve.update(context, should_open);
+        }
+        self.crossfeed_valve
+            .update(context, self.crossfeed_valve_switch);
+        for (valve, should_open) in self
+            .center_tank_transfer_valves
+            .iter_mut()
+            .zip(self.center_tank_transfer_switches)
+        {
+            valve.update(context, should_open);
+        }
+        self.update_outer_tank_transfer(context);
+        self.update_center_tank_transfer(context);
+
+        // A closed LP valve isolates the engine from its feed line.
+        // The APU is supplied from the left engine feed line.
+        let engine_demands: [MassRate; 2] = [0, 1].map(|index| {
+            if self.lp_valves[index].is_open() {
+                self.engine_fuel_flows[index]
+            } else {
+                MassRate::default()
+            }
+        });
+        let demands = [engine_demands[0] + self.apu_fuel_flow, engine_demands[1]];
+        let line_pressure = self.fuel_system.feed_engines(
+            context,
+            &Self::ENGINE_FEEDS,
+            [self.crossfeed_valve.is_open(); 2],
+            demands,
+        );
+        self.engine_is_fed = [0, 1].map(|index| {
+            line_pressure[index] > Pressure::default() && self.lp_valves[index].is_open()
+        });
+        self.apu_fuel_feed_pressure = line_pressure[0];
+    }
+
+    fn update_outer_tank_transfer(&mut self, context: &UpdateContext) {
+        let sides = [
+            (A320FuelTankType::LeftOuter, A320FuelTankType::LeftInner),
+            (A320FuelTankType::RightOuter, A320FuelTankType::RightInner),
+        ];
+        for (index, (outer, inner)) in sides.into_iter().enumerate() {
+            let (outer, inner) = (outer as usize, inner as usize);
+            if self.fuel_system.tank_is_full(outer) {
+                self.outer_tank_transfer_latched_open[index] = false;
+            } else if self.fuel_system.tank_mass(inner).get::<kilogram>()
+                < Self::OUTER_TANK_TRANSFER_INNER_TANK_QUANTITY_KG
+            {
+                self.outer_tank_transfer_latched_open[index] = true;
+            }
+
+            self.outer_tank_transfer_valves[index]
+                .update(context, self.outer_tank_transfer_latched_open[index]);
+            if self.outer_tank_transfer_valves[index].is_open() {
+                self.fuel_system.transfer_fuel(
+                    context,
+                    outer,
+                    inner,
+                    MassRate::new::<kilogram_per_hour>(Self::OUTER_TANK_TRANSFER_RATE_KG_PER_HOUR),
+                );
+            }
+        }
+    }
+
+    fn update_center_tank_transfer(&mut self, context: &UpdateContext) {
+        for (index, feed) in Self::ENGINE_FEEDS.iter().enumerate() {
+            let motive_flow_available = feed
+                .pumps
+                .iter()
+                .any(|feed_pump| self.fuel_system.pump_is_running(feed_pump.pump));
+
+            if motive_flow_available && self.center_tank_transfer_valves[index].is_open() {
+                self.fuel_system.transfer_fuel(
+                    context,
+                    A320FuelTankType::Center as usize,
+                    feed.suction_tank,
+                    MassRate::new::<kilogram_per_hour>(Self::CENTER_TANK_TRANSFER_RATE_KG_PER_HOUR),
+                );
+            }
+        }
+    }
+
+    pub fn apu_fuel_feed_pressure(&self) -> Pressure {
+        self.apu_fuel_feed_pressure
+    }
+
+    pub fn engine_is_fed(&self, engine_number: usize) -> bool {
+        self.engine_is_fed[engine_number - 1]
+    }
+
+    /// The fuel quantity indication computer remains valid while one of its two channels works.
+    fn fuel_quantity_indication_is_valid(&self) -> bool {
+        self.fuel_quantity_indication_failures
+            .iter()
+            .any(|failure| !failure.is_active())
     }
 
     fn center_tank_has_fuel(&self) -> bool {
@@ -206,6 +413,37 @@ impl FuelCG for A320Fuel {
 impl SimulationElement for A320Fuel {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.fuel_system.accept(visitor);
+        accept_iterable!(self.lp_valves, visitor);
+        self.crossfeed_valve.accept(visitor);
+        accept_iterable!(self.outer_tank_transfer_valves, visitor);
+        accept_iterable!(self.center_tank_transfer_valves, visitor);
+        accept_iterable!(self.fuel_quantity_indication_failures, visitor);
         visitor.visit(self);
     }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.engine_fuel_flows = self.engine_fuel_flow_ids.map(|id| {
+            let kilograms_per_hour: f64 = reader.read(&id);
+            MassRate::new::<kilogram_per_hour>(kilograms_per_hour)
+        });
+        let apu_gallons_per_hour: f64 = reader.read(&self.apu_fuel_flow_id);
+        self.apu_fuel_flow =
+            MassRate::new::<kilogram_per_hour>(apu_gallons_per_hour * FUEL_GALLONS_TO_KG);
+
+        self.lp_valve_switches = self.lp_valve_switch_ids.map(|id| reader.read(&id));
+        self.crossfeed_valve_switch = reader.read(&self.crossfeed_valve_switch_id);
+        self.center_tank_transfer_switches = self
+            .center_tank_transfer_switch_ids
+            .map(|id| reader.read(&id));
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        for (id, is_fed) in self.engine_is_fed_ids.iter().zip(self.engine_is_fed) {
+            writer.write(id, is_fed);
+        }
+        writer.write(
+            &self.fuel_quantity_indication_is_valid_id,
+            self.fuel_quantity_indication_is_valid(),
+        );
+    }
 }
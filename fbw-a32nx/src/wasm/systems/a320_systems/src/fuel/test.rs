@@ -2,27 +2,81 @@ use std::time::Duration;
 
 use super::*;
 use crate::systems::simulation::{
-    test::{SimulationTestBed, TestBed, WriteByName},
+    test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
     Aircraft, SimulationElement, SimulationElementVisitor,
 };
+use systems::{
+    electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+    failures::FailureType,
+    shared::PotentialOrigin,
+};
+use uom::si::{electric_potential::volt, length::foot};
 
 struct FuelTestAircraft {
     fuel: A320Fuel,
+
+    powered_source: TestElectricitySource,
+    buses: [ElectricalBus; 7],
+    is_powered: bool,
 }
 
 impl FuelTestAircraft {
     fn new(context: &mut InitContext) -> Self {
         Self {
             fuel: A320Fuel::new(context),
+
+            powered_source: TestElectricitySource::powered(
+                context,
+                PotentialOrigin::EngineGenerator(1),
+            ),
+            buses: [
+                ElectricalBusType::DirectCurrentHot(1),
+                ElectricalBusType::DirectCurrent(1),
+                ElectricalBusType::DirectCurrent(2),
+                ElectricalBusType::DirectCurrentEssential,
+                ElectricalBusType::Virtual("FUEL_PUMP_1_SUPPLY"),
+                ElectricalBusType::Virtual("FUEL_PUMP_2_SUPPLY"),
+                ElectricalBusType::Virtual("FUEL_PUMP_APU_SUPPLY"),
+            ]
+            .map(|bus_type| ElectricalBus::new(context, bus_type)),
+            is_powered: false,
         }
     }
 
     fn fore_aft_center_of_gravity(&self) -> f64 {
         self.fuel.fore_aft_center_of_gravity()
     }
+
+    fn tank_mass(&self, tank: A320FuelTankType) -> Mass {
+        self.fuel.fuel_system.tank_mass(tank.into())
+    }
+
+    fn set_powered(&mut self, is_powered: bool) {
+        self.is_powered = is_powered;
+    }
 }
 
-impl Aircraft for FuelTestAircraft {}
+impl Aircraft for FuelTestAircraft {
+    fn update_before_power_distribution(
+        &mut self,
+        _: &UpdateContext,
+        electricity: &mut Electricity,
+    ) {
+        self.powered_source
+            .power_with_potential(ElectricPotential::new::<volt>(28.));
+        electricity.supplied_by(&self.powered_source);
+
+        if self.is_powered {
+            for bus in &self.buses {
+                electricity.flow(&self.powered_source, bus);
+            }
+        }
+    }
+
+    fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+        self.fuel.update(context);
+    }
+}
 impl SimulationElement for FuelTestAircraft {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.fuel.accept(visitor);
@@ -82,6 +136,68 @@ impl FuelTestBed {
         self
     }
 
+    fn powered(mut self) -> Self {
+        self.command(|a| a.set_powered(true));
+
+        self
+    }
+
+    fn engines_running(mut self) -> Self {
+        self.write_by_name("FUELSYSTEM VALVE SWITCH:1", true);
+        self.write_by_name("FUELSYSTEM VALVE SWITCH:2", true);
+        self.write_by_name("ENGINE_FF:1", 1000.);
+        self.write_by_name("ENGINE_FF:2", 1000.);
+
+        self
+    }
+
+    fn left_pumps(mut self, is_on: bool) -> Self {
+        self.write_by_name("FUELSYSTEM PUMP SWITCH:2", is_on);
+        self.write_by_name("FUELSYSTEM PUMP SWITCH:5", is_on);
+
+        self
+    }
+
+    fn right_pumps(mut self, is_on: bool) -> Self {
+        self.write_by_name("FUELSYSTEM PUMP SWITCH:3", is_on);
+        self.write_by_name("FUELSYSTEM PUMP SWITCH:6", is_on);
+
+        self
+    }
+
+    fn crossfeed(mut self, is_open: bool) -> Self {
+        self.write_by_name("FUELSYSTEM VALVE SWITCH:3", is_open);
+
+        self
+    }
+
+    fn center_tank_transfer(mut self, is_on: bool) -> Self {
+        self.write_by_name("FUELSYSTEM VALVE SWITCH:9", is_on);
+        self.write_by_name("FUELSYSTEM VALVE SWITCH:10", is_on);
+
+        self
+    }
+
+    fn at_altitude(mut self, feet: f64) -> Self {
+        self.set_pressure_altitude(Length::new::<foot>(feet));
+
+        self
+    }
+
+    fn and_run_for(mut self, duration: Duration) -> Self {
+        self.test_bed.run_multiple_frames(duration);
+
+        self
+    }
+
+    fn engine_is_fed(&mut self, engine_number: usize) -> bool {
+        self.read_by_name(&format!("FUEL_ENGINE_{engine_number}_IS_FED"))
+    }
+
+    fn tank_mass(&self, tank: A320FuelTankType) -> Mass {
+        self.query(|a: &FuelTestAircraft| a.tank_mass(tank))
+    }
+
     fn fore_aft_center_of_gravity(&self) -> f64 {
         self.query(|a: &FuelTestAircraft| a.fore_aft_center_of_gravity())
     }
@@ -144,3 +260,208 @@ fn high_fuel() {
         (test_bed.fore_aft_center_of_gravity() * 100.).round() / 100.,
     );
 }
+
+#[test]
+fn engines_are_fed_by_their_wing_tank_pumps() {
+    let mut test_bed = test_bed_with()
+        .fuel_high()
+        .powered()
+        .engines_running()
+        .left_pumps(true)
+        .right_pumps(true)
+        .at_altitude(30000.)
+        .and_run();
+    let left_inner_before = test_bed.tank_mass(A320FuelTankType::LeftInner);
+    let right_inner_before = test_bed.tank_mass(A320FuelTankType::RightInner);
+
+    test_bed = test_bed.and_run_for(Duration::from_secs(60));
+
+    assert!(test_bed.engine_is_fed(1));
+    assert!(test_bed.engine_is_fed(2));
+    assert!(test_bed.tank_mass(A320FuelTankType::LeftInner) < left_inner_before);
+    assert!(test_bed.tank_mass(A320FuelTankType::RightInner) < right_inner_before);
+}
+
+#[test]
+fn engine_is_not_fed_when_lp_valve_is_closed() {
+    let mut test_bed = test_bed_with()
+        .fuel_high()
+        .powered()
+        .engines_running()
+        .left_pumps(true)
+        .right_pumps(true)
+        .and_run_for(Duration::from_secs(5));
+
+    test_bed.write_by_name("FUELSYSTEM VALVE SWITCH:1", false);
+    test_bed = test_bed.and_run_for(Duration::from_secs(5));
+
+    assert!(!test_bed.engine_is_fed(1));
+    assert!(test_bed.engine_is_fed(2));
+}
+
+#[test]
+fn engine_is_suction_fed_without_pumps_at_low_altitude() {
+    let mut test_bed = test_bed_with()
+        .fuel_high()
+        .powered()
+        .engines_running()
+        .at_altitude(5000.)
+        .and_run_for(Duration::from_secs(5));
+
+    assert!(test_bed.engine_is_fed(1));
+    assert!(test_bed.engine_is_fed(2));
+}
+
+#[test]
+fn engine_is_not_fed_without_pumps_at_high_altitude() {
+    let mut test_bed = test_bed_with()
+        .fuel_high()
+        .powered()
+        .engines_running()
+        .at_altitude(30000.)
+        .and_run_for(Duration::from_secs(5));
+
+    assert!(!test_bed.engine_is_fed(1));
+    assert!(!test_bed.engine_is_fed(2));
+}
+
+#[test]
+fn failed_pumps_do_not_feed_the_engine() {
+    let mut test_bed = test_bed_with()
+        .fuel_high()
+        .powered()
+        .engines_running()
+        .left_pumps(true)
+        .right_pumps(true)
+        .at_altitude(30000.);
+    test_bed.fail(FailureType::FuelPump(2));
+    test_bed.fail(FailureType::FuelPump(5));
+
+    test_bed = test_bed.and_run_for(Duration::from_secs(5));
+
+    assert!(!test_bed.engine_is_fed(1));
+    assert!(test_bed.engine_is_fed(2));
+}
+
+#[test]
+fn crossfeed_supplies_both_engines_from_one_side() {
+    let mut test_bed = test_bed_with()
+        .fuel_high()
+        .powered()
+        .engines_running()
+        .right_pumps(true)
+        .crossfeed(true)
+        .at_altitude(30000.)
+        .and_run_for(Duration::from_secs(5));
+    let left_inner_before = test_bed.tank_mass(A320FuelTankType::LeftInner);
+    let right_inner_before = test_bed.tank_mass(A320FuelTankType::RightInner);
+
+    test_bed = test_bed.and_run_for(Duration::from_secs(60));
+
+    assert!(test_bed.engine_is_fed(1));
+    assert!(test_bed.engine_is_fed(2));
+    assert_eq!(
+        test_bed.tank_mass(A320FuelTankType::LeftInner),
+        left_inner_before
+    );
+    assert!(test_bed.tank_mass(A320FuelTankType::RightInner) < right_inner_before);
+}
+
+#[test]
+fn jammed_crossfeed_valve_does_not_open() {
+    let mut test_bed = test_bed_with()
+        .fuel_high()
+        .powered()
+        .engines_running()
+        .right_pumps(true)
+        .crossfeed(true)
+        .at_altitude(30000.);
+    test_bed.fail(FailureType::FuelValve(3));
+
+    test_bed = test_bed.and_run_for(Duration::from_secs(5));
+
+    assert!(!test_bed.engine_is_fed(1));
+    assert!(test_bed.engine_is_fed(2));
+}
+
+#[test]
+fn outer_tanks_transfer_into_low_inner_tanks() {
+    let mut test_bed = test_bed_with().fuel_low().powered().and_run();
+    let left_outer_before = test_bed.tank_mass(A320FuelTankType::LeftOuter);
+    let left_inner_before = test_bed.tank_mass(A320FuelTankType::LeftInner);
+
+    test_bed = test_bed.and_run_for(Duration::from_secs(60));
+
+    assert!(test_bed.tank_mass(A320FuelTankType::LeftOuter) < left_outer_before);
+    assert!(test_bed.tank_mass(A320FuelTankType::LeftInner) > left_inner_before);
+}
+
+#[test]
+fn jammed_outer_tank_transfer_valve_does_not_open() {
+    let mut test_bed = test_bed_with().fuel_low().powered();
+    test_bed.fail(FailureType::FuelValve(4));
+    test_bed = test_bed.and_run();
+    let left_outer_before = test_bed.tank_mass(A320FuelTankType::LeftOuter);
+    let right_outer_before = test_bed.tank_mass(A320FuelTankType::RightOuter);
+
+    test_bed = test_bed.and_run_for(Duration::from_secs(60));
+
+    assert_eq!(
+        test_bed.tank_mass(A320FuelTankType::LeftOuter),
+        left_outer_before
+    );
+    assert!(test_bed.tank_mass(A320FuelTankType::RightOuter) < right_outer_before);
+}
+
+#[test]
+fn center_tank_transfers_when_wing_pumps_are_running() {
+    let mut test_bed = test_bed_with().fuel_high();
+    test_bed.write_by_name("FUEL TANK CENTER QUANTITY", 1000.);
+    test_bed = test_bed
+        .powered()
+        .left_pumps(true)
+        .center_tank_transfer(true)
+        .and_run();
+    let center_before = test_bed.tank_mass(A320FuelTankType::Center);
+    let right_inner_before = test_bed.tank_mass(A320FuelTankType::RightInner);
+
+    test_bed = test_bed.and_run_for(Duration::from_secs(60));
+
+    assert!(test_bed.tank_mass(A320FuelTankType::Center) < center_before);
+    assert_eq!(
+        test_bed.tank_mass(A320FuelTankType::RightInner),
+        right_inner_before
+    );
+}
+
+#[test]
+fn leaking_tank_loses_fuel() {
+    let mut test_bed = test_bed_with().fuel_high().and_run();
+    let left_inner_before = test_bed.tank_mass(A320FuelTankType::LeftInner);
+    let right_inner_before = test_bed.tank_mass(A320FuelTankType::RightInner);
+    test_bed.fail(FailureType::FuelTankLeak(4));
+
+    test_bed = test_bed.and_run_for(Duration::from_secs(60));
+
+    assert!(test_bed.tank_mass(A320FuelTankType::RightInner) < right_inner_before);
+    assert_eq!(
+        test_bed.tank_mass(A320FuelTankType::LeftInner),
+        left_inner_before
+    );
+}
+
+#[test]
+fn fuel_quantity_indication_is_lost_when_both_channels_fail() {
+    let mut test_bed = test_bed_with().fuel_high();
+    test_bed.fail(FailureType::FuelQuantityIndication(1));
+    test_bed = test_bed.and_run();
+
+    let is_valid: bool = test_bed.read_by_name("FUEL_QUANTITY_INDICATION_IS_VALID");
+    assert!(is_valid);
+
+    test_bed.fail(FailureType::FuelQuantityIndication(2));
+    test_bed = test_bed.and_run();
+
+    let is_valid: bool = test_bed.read_by_name("FUEL_QUANTITY_INDICATION_IS_VALID");
+    assert!(!is_valid);
+}
@@ -69,8 +69,9 @@ use systems::{
         DelayedPulseTrueLogicGate, DelayedTrueLogicGate, ElectricalBusType, ElectricalBuses,
         EmergencyElectricalRatPushButton, EmergencyElectricalState, EmergencyGeneratorControlUnit,
         EmergencyGeneratorPower, EngineFirePushButtons, GearWheel, HydraulicColor,
-        LandingGearHandle, LgciuInterface, LgciuWeightOnWheels, RamAirTurbineController,
-        ReservoirAirPressure, ReverserPosition, SectionPressure, TrimmableHorizontalStabilizer,
+        LandingGearHandle, LgciuInterface, LgciuWeightOnWheels, PositionPickoffUnit,
+        RamAirTurbineController, ReservoirAirPressure, ReverserPosition, SectionPressure,
+        TrimmableHorizontalStabilizer,
     },
     simulation::{
         InitContext, Read, Reader, SimulationElement, SimulationElementVisitor, SimulatorReader,
@@ -2648,6 +2649,10 @@ impl A320Hydraulic {
         &self.gear_system
     }
 
+    pub fn flaps_position_pickoff_unit(&self) -> &impl PositionPickoffUnit {
+        &self.flap_system
+    }
+
     pub fn brakes_hot(&self) -> bool {
         self.brake_steer_computer.brakes_hot()
     }
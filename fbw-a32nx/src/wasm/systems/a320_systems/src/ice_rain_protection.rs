@@ -0,0 +1,145 @@
+use systems::{
+    accept_iterable,
+    ice_rain_protection::{
+        engine_anti_ice::{EngineAntiIcePushButton, EngineAntiIceValve},
+        probe_heat::ProbeHeatComputer,
+        window_heat::WindowHeatComputer,
+        ProbeWindowHeatPushButton,
+    },
+    shared::{ElectricalBusType, EngineCorrectedN2, LgciuWeightOnWheels, ProbeType},
+    simulation::{InitContext, SimulationElement, SimulationElementVisitor, UpdateContext},
+};
+
+pub struct A320IceRainProtection {
+    probe_window_heat_push_button: ProbeWindowHeatPushButton,
+    probe_heat_computers: [ProbeHeatComputer; 3],
+    window_heat_computers: [WindowHeatComputer; 2],
+    engine_anti_ice_push_buttons: [EngineAntiIcePushButton; 2],
+    engine_anti_ice_valves: [EngineAntiIceValve; 2],
+}
+impl A320IceRainProtection {
+    const CAPT_AND_FO_PROBES: [ProbeType; 5] = [
+        ProbeType::Pitot,
+        ProbeType::LeftStatic,
+        ProbeType::RightStatic,
+        ProbeType::TotalAirTemperature,
+        ProbeType::AngleOfAttack,
+    ];
+    // The standby probes don't include a TAT probe.
+    const STBY_PROBES: [ProbeType; 4] = [
+        ProbeType::Pitot,
+        ProbeType::LeftStatic,
+        ProbeType::RightStatic,
+        ProbeType::AngleOfAttack,
+    ];
+
+    pub fn new(context: &mut InitContext) -> Self {
+        Self {
+            probe_window_heat_push_button: ProbeWindowHeatPushButton::new(context),
+            probe_heat_computers: [
+                ProbeHeatComputer::new(
+                    context,
+                    1,
+                    &Self::CAPT_AND_FO_PROBES,
+                    ElectricalBusType::DirectCurrentEssential,
+                    ElectricalBusType::AlternatingCurrentEssentialShed,
+                ),
+                ProbeHeatComputer::new(
+                    context,
+                    2,
+                    &Self::CAPT_AND_FO_PROBES,
+                    ElectricalBusType::DirectCurrent(2),
+                    ElectricalBusType::AlternatingCurrent(2),
+                ),
+                ProbeHeatComputer::new(
+                    context,
+                    3,
+                    &Self::STBY_PROBES,
+                    ElectricalBusType::DirectCurrent(1),
+                    ElectricalBusType::AlternatingCurrent(1),
+                ),
+            ],
+            window_heat_computers: [
+                WindowHeatComputer::new(
+                    context,
+                    1,
+                    ElectricalBusType::DirectCurrent(1),
+                    ElectricalBusType::AlternatingCurrent(1),
+                    ElectricalBusType::AlternatingCurrentEssentialShed,
+                ),
+                WindowHeatComputer::new(
+                    context,
+                    2,
+                    ElectricalBusType::DirectCurrent(2),
+                    ElectricalBusType::AlternatingCurrent(2),
+                    ElectricalBusType::AlternatingCurrent(2),
+                ),
+            ],
+            engine_anti_ice_push_buttons: [1, 2]
+                .map(|number| EngineAntiIcePushButton::new(context, number)),
+            engine_anti_ice_valves: [
+                EngineAntiIceValve::new(context, 1, ElectricalBusType::DirectCurrent(1)),
+                EngineAntiIceValve::new(context, 2, ElectricalBusType::DirectCurrent(2)),
+            ],
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        engines: &[&impl EngineCorrectedN2],
+        lgcius: [&impl LgciuWeightOnWheels; 2],
+    ) {
+        let [phc_1, phc_2, phc_3] = &mut self.probe_heat_computers;
+        phc_1.update(
+            context,
+            &self.probe_window_heat_push_button,
+            engines,
+            lgcius[0],
+        );
+        phc_2.update(
+            context,
+            &self.probe_window_heat_push_button,
+            engines,
+            lgcius[1],
+        );
+        phc_3.update(
+            context,
+            &self.probe_window_heat_push_button,
+            engines,
+            lgcius[0],
+        );
+
+        for (whc, lgciu) in self.window_heat_computers.iter_mut().zip(lgcius) {
+            whc.update(context, &self.probe_window_heat_push_button, engines, lgciu);
+        }
+
+        for ((valve, push_button), engine) in self
+            .engine_anti_ice_valves
+            .iter_mut()
+            .zip(&self.engine_anti_ice_push_buttons)
+            .zip(engines)
+        {
+            valve.update(context, push_button, *engine);
+        }
+    }
+
+    pub fn probe_heat_computers(&self) -> &[ProbeHeatComputer; 3] {
+        &self.probe_heat_computers
+    }
+
+    pub fn engine_anti_ice_valves(&self) -> &[EngineAntiIceValve; 2] {
+        &self.engine_anti_ice_valves
+    }
+}
+impl SimulationElement for A320IceRainProtection {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.probe_window_heat_push_button.accept(visitor);
+        accept_iterable!(self.probe_heat_computers, visitor);
+        accept_iterable!(self.window_heat_computers, visitor);
+        accept_iterable!(self.engine_anti_ice_push_buttons, visitor);
+        accept_iterable!(self.engine_anti_ice_valves, visitor);
+
+        visitor.visit(self);
+    }
+}
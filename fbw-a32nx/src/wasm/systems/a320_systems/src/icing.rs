@@ -0,0 +1,31 @@
+use systems::{
+    ice_rain_protection::airframe_icing::AirframeIcing,
+    icing_state::ActiveDeicingController,
+    simulation::{InitContext, SimulationElement, SimulationElementVisitor, UpdateContext},
+};
+
+pub struct Icing {
+    airframe_icing: AirframeIcing<2>,
+}
+impl Icing {
+    pub fn new(context: &mut InitContext) -> Self {
+        Self {
+            airframe_icing: AirframeIcing::new(context),
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        wing_anti_ice: &[impl ActiveDeicingController; 2],
+        engine_anti_ice: &[impl ActiveDeicingController; 2],
+    ) {
+        self.airframe_icing
+            .update(context, wing_anti_ice, engine_anti_ice);
+    }
+}
+impl SimulationElement for Icing {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.airframe_icing.accept(visitor);
+    }
+}
@@ -3,15 +3,23 @@ extern crate systems;
 mod air_conditioning;
 mod airframe;
 mod electrical;
+mod failures;
+mod fire_and_smoke_protection;
 mod fuel;
 pub mod hydraulic;
+mod ice_rain_protection;
+mod icing;
 mod navigation;
+mod oxygen;
 mod payload;
 mod pneumatic;
 mod power_consumption;
 
+pub use failures::A320_FAILURES;
+
 use self::{
     air_conditioning::A320AirConditioning,
+    fire_and_smoke_protection::A320FireAndSmokeProtection,
     fuel::A320Fuel,
     payload::A320Payload,
     pneumatic::{A320Pneumatic, A320PneumaticOverheadPanel},
@@ -22,11 +30,20 @@ use electrical::{
     APU_START_MOTOR_BUS_TYPE,
 };
 use hydraulic::{A320Hydraulic, A320HydraulicOverheadPanel};
-use navigation::{A320AirDataInertialReferenceSystemBuilder, A320RadioAltimeters};
+use ice_rain_protection::A320IceRainProtection;
+use icing::Icing;
+use navigation::{
+    A320AirDataInertialReferenceSystemBuilder, A320GpsReceivers, A320RadioAltimeters,
+};
+use oxygen::A320Oxygen;
 use power_consumption::A320PowerConsumption;
 use systems::enhanced_gpwc::EnhancedGroundProximityWarningComputer;
 use systems::{hydraulic::brake::BrakeFanPanel, simulation::InitContext};
-use uom::si::{f64::Length, length::nautical_mile};
+use uom::si::{
+    angle::degree,
+    f64::{Angle, Length},
+    length::nautical_mile,
+};
 
 use systems::{
     air_starter_unit::AirStarterUnit,
@@ -36,7 +53,10 @@ use systems::{
         AuxiliaryPowerUnitOverheadPanel,
     },
     electrical::{Electricity, ElectricitySource, ExternalPowerSource},
-    engine::{leap_engine::LeapEngine, reverser_thrust::ReverserForce, EngineFireOverheadPanel},
+    engine::{
+        leap_engine::LeapEngine, physical_model::EngineSimulationMode,
+        reverser_thrust::ReverserForce, EngineFireOverheadPanel,
+    },
     hydraulic::brake_circuit::AutobrakePanel,
     landing_gear::{LandingGear, LandingGearControlInterfaceUnitSet},
     navigation::adirs::{
@@ -60,6 +80,7 @@ pub struct A320 {
     payload: A320Payload,
     airframe: A320Airframe,
     fuel: A320Fuel,
+    fire_and_smoke_protection: A320FireAndSmokeProtection,
     engine_1: LeapEngine,
     engine_2: LeapEngine,
     engine_fire_overhead: EngineFireOverheadPanel<2>,
@@ -69,16 +90,24 @@ pub struct A320 {
     lgcius: LandingGearControlInterfaceUnitSet,
     hydraulic: A320Hydraulic,
     hydraulic_overhead: A320HydraulicOverheadPanel,
+    ice_rain_protection: A320IceRainProtection,
+    icing_simulation: Icing,
+    oxygen: A320Oxygen,
     autobrake_panel: AutobrakePanel,
     brake_fan_panel: BrakeFanPanel,
     landing_gear: LandingGear,
     pneumatic: A320Pneumatic,
     radio_altimeters: A320RadioAltimeters,
+    gps_receivers: A320GpsReceivers,
     egpwc: EnhancedGroundProximityWarningComputer,
     reverse_thrust: ReverserForce,
 }
 impl A320 {
     pub fn new(context: &mut InitContext) -> A320 {
+        Self::with_engine_mode(context, EngineSimulationMode::Simulator)
+    }
+
+    pub fn with_engine_mode(context: &mut InitContext, engine_mode: EngineSimulationMode) -> A320 {
         A320 {
             adirs: A320AirDataInertialReferenceSystemBuilder::build(context),
             adirs_overhead: AirDataInertialReferenceSystemOverheadPanel::new(context),
@@ -99,8 +128,9 @@ impl A320 {
             payload: A320Payload::new(context),
             airframe: A320Airframe::new(context),
             fuel: A320Fuel::new(context),
-            engine_1: LeapEngine::new(context, 1),
-            engine_2: LeapEngine::new(context, 2),
+            fire_and_smoke_protection: A320FireAndSmokeProtection::new(context),
+            engine_1: LeapEngine::with_mode(context, 1, engine_mode),
+            engine_2: LeapEngine::with_mode(context, 2, engine_mode),
             engine_fire_overhead: EngineFireOverheadPanel::new(context),
             electrical: A320Electrical::new(context),
             power_consumption: A320PowerConsumption::new(context),
@@ -112,11 +142,15 @@ impl A320 {
             ),
             hydraulic: A320Hydraulic::new(context),
             hydraulic_overhead: A320HydraulicOverheadPanel::new(context),
+            ice_rain_protection: A320IceRainProtection::new(context),
+            icing_simulation: Icing::new(context),
+            oxygen: A320Oxygen::new(context),
             autobrake_panel: AutobrakePanel::new(context),
             brake_fan_panel: BrakeFanPanel::new(context),
             landing_gear: LandingGear::new(context, false),
             pneumatic: A320Pneumatic::new(context),
             radio_altimeters: A320RadioAltimeters::new(context),
+            gps_receivers: A320GpsReceivers::new(context),
             egpwc: EnhancedGroundProximityWarningComputer::new(
                 context,
                 ElectricalBusType::DirectCurrent(1),
@@ -129,6 +163,8 @@ impl A320 {
                     Length::new::<nautical_mile>(320.0),
                 ],
                 0,
+                Angle::new::<degree>(163.7),
+                Angle::new::<degree>(247.8),
             ),
             reverse_thrust: ReverserForce::new(context),
         }
@@ -143,17 +179,21 @@ impl Aircraft for A320 {
         self.apu.update_before_electrical(
             context,
             &self.apu_overhead,
-            false, // Todo: fire detection system
+            self.fire_and_smoke_protection.apu_fire_on_ground(),
             &self.apu_fire_overhead,
-            self.pneumatic_overhead.apu_bleed_is_on(),
+            self.pneumatic_overhead.apu_bleed_is_on()
+                && !self
+                    .pneumatic
+                    .should_close_apu_bleed_valve_because_of_leak(),
             // This will be replaced when integrating the whole electrical system.
             // For now we use the same logic as found in the JavaScript code; ignoring whether or not
             // the engine generators are supplying electricity.
             self.electrical_overhead.apu_generator_is_on()
                 && !(self.electrical_overhead.external_power_is_on()
                     && self.electrical_overhead.external_power_is_available()),
+            &self.electrical_overhead,
             self.pneumatic.apu_bleed_air_valve(),
-            self.fuel.left_inner_tank_has_fuel_remaining(),
+            self.fuel.apu_fuel_feed_pressure(),
         );
 
         self.electrical.update(
@@ -181,6 +221,21 @@ impl Aircraft for A320 {
     }
 
     fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+        self.fuel.update(context);
+
+        self.engine_1.update(
+            context,
+            self.fuel.engine_is_fed(1),
+            &self.pneumatic,
+            self.electrical.engine_generator_output_power(1),
+        );
+        self.engine_2.update(
+            context,
+            self.fuel.engine_is_fed(2),
+            &self.pneumatic,
+            self.electrical.engine_generator_output_power(2),
+        );
+
         self.apu.update_after_power_distribution(
             &[&self.engine_1, &self.engine_2],
             [self.lgcius.lgciu1(), self.lgcius.lgciu2()],
@@ -196,7 +251,15 @@ impl Aircraft for A320 {
             self.ext_pwr.output_potential().is_powered(),
         );
 
+        self.fire_and_smoke_protection.update(
+            context,
+            &self.engine_fire_overhead,
+            &self.apu_fire_overhead,
+            [self.lgcius.lgciu1(), self.lgcius.lgciu2()],
+        );
+
         self.radio_altimeters.update(context);
+        self.gps_receivers.update(context);
 
         self.hydraulic.update(
             context,
@@ -228,8 +291,23 @@ impl Aircraft for A320 {
         self.hydraulic_overhead.update(&self.hydraulic);
         self.brake_fan_panel.update(self.hydraulic.brakes_hot());
 
-        self.adirs.update(context, &self.adirs_overhead);
-        self.adirs_overhead.update(context, &self.adirs);
+        self.ice_rain_protection.update(
+            context,
+            &[&self.engine_1, &self.engine_2],
+            [self.lgcius.lgciu1(), self.lgcius.lgciu2()],
+        );
+
+        self.adirs.update(
+            context,
+            &self.adirs_overhead,
+            self.ice_rain_protection.probe_heat_computers(),
+            [
+                self.gps_receivers.gps_receiver_1(),
+                self.gps_receivers.gps_receiver_2(),
+                self.gps_receivers.gps_receiver_1(),
+            ],
+        );
+        self.adirs_overhead.update(&self.adirs);
 
         self.power_consumption.update(context);
 
@@ -254,8 +332,25 @@ impl Aircraft for A320 {
             &self.pneumatic,
             [self.lgcius.lgciu1(), self.lgcius.lgciu2()],
         );
+        self.oxygen.update(context, &self.air_conditioning);
+
+        self.icing_simulation.update(
+            context,
+            self.pneumatic.wing_anti_ice_systems(),
+            self.ice_rain_protection.engine_anti_ice_valves(),
+        );
 
-        self.egpwc.update(&self.adirs, self.lgcius.lgciu1());
+        self.egpwc.update(
+            context,
+            &self.adirs,
+            self.gps_receivers.gps_receiver_1(),
+            [
+                self.radio_altimeters.radio_altimeter_1(),
+                self.radio_altimeters.radio_altimeter_2(),
+            ],
+            self.lgcius.lgciu1(),
+            self.hydraulic.flaps_position_pickoff_unit(),
+        );
     }
 }
 impl SimulationElement for A320 {
@@ -272,6 +367,7 @@ impl SimulationElement for A320 {
         self.electrical_overhead.accept(visitor);
         self.emergency_electrical_overhead.accept(visitor);
         self.fuel.accept(visitor);
+        self.fire_and_smoke_protection.accept(visitor);
         self.pneumatic_overhead.accept(visitor);
         self.engine_1.accept(visitor);
         self.engine_2.accept(visitor);
@@ -281,10 +377,14 @@ impl SimulationElement for A320 {
         self.ext_pwr.accept(visitor);
         self.lgcius.accept(visitor);
         self.radio_altimeters.accept(visitor);
+        self.gps_receivers.accept(visitor);
         self.autobrake_panel.accept(visitor);
         self.brake_fan_panel.accept(visitor);
         self.hydraulic.accept(visitor);
         self.hydraulic_overhead.accept(visitor);
+        self.ice_rain_protection.accept(visitor);
+        self.icing_simulation.accept(visitor);
+        self.oxygen.accept(visitor);
         self.landing_gear.accept(visitor);
         self.pneumatic.accept(visitor);
         self.egpwc.accept(visitor);
@@ -1,12 +1,16 @@
 use systems::navigation::adirs::{
-    AirDataInertialReferenceSystem, AirDataInertialReferenceUnitProgramming,
-    LowSpeedWarningThreshold,
+    AirDataInertialReferenceSystem, AirDataInertialReferenceUnitPowerSupply,
+    AirDataInertialReferenceUnitProgramming, LowSpeedWarningThreshold,
 };
 use systems::navigation::ala52b::{
     Ala52BAircraftInstallationDelay, Ala52BRadioAltimeter, Ala52BTransceiverPair,
 };
-use systems::navigation::radio_altimeter::AntennaInstallation;
-use systems::shared::{ElectricalBusType, MachNumber};
+use systems::navigation::gps::GpsReceiverUnit;
+use systems::navigation::radio_altimeter::{AntennaInstallation, RadioAltimeter};
+use systems::shared::{
+    arinc429::{Arinc429Bus, Arinc429Word},
+    ElectricalBusType, MachNumber,
+};
 use systems::simulation::{
     InitContext, SimulationElement, SimulationElementVisitor, UpdateContext,
 };
@@ -39,7 +43,24 @@ impl A320AirDataInertialReferenceSystemBuilder {
                 ),
             ],
         );
-        AirDataInertialReferenceSystem::new(context, adirs_programming)
+        AirDataInertialReferenceSystem::new(
+            context,
+            adirs_programming,
+            [
+                AirDataInertialReferenceUnitPowerSupply::new(
+                    ElectricalBusType::AlternatingCurrentEssential,
+                    ElectricalBusType::DirectCurrentHot(1),
+                ),
+                AirDataInertialReferenceUnitPowerSupply::new(
+                    ElectricalBusType::AlternatingCurrent(2),
+                    ElectricalBusType::DirectCurrentHot(2),
+                ),
+                AirDataInertialReferenceUnitPowerSupply::new(
+                    ElectricalBusType::AlternatingCurrent(1),
+                    ElectricalBusType::DirectCurrentHot(2),
+                ),
+            ],
+        )
     }
 }
 
@@ -91,6 +112,14 @@ impl A320RadioAltimeters {
         self.radio_altimeter_1.update(context);
         self.radio_altimeter_2.update(context);
     }
+
+    pub fn radio_altimeter_1(&self) -> &A320RadioAltimeter {
+        &self.radio_altimeter_1
+    }
+
+    pub fn radio_altimeter_2(&self) -> &A320RadioAltimeter {
+        &self.radio_altimeter_2
+    }
 }
 
 impl SimulationElement for A320RadioAltimeters {
@@ -131,6 +160,16 @@ impl A320RadioAltimeter {
     }
 }
 
+impl RadioAltimeter for A320RadioAltimeter {
+    fn radio_altitude(&self) -> Arinc429Word<Length> {
+        self.radio_altimeter.radio_altitude()
+    }
+
+    fn bus(&self) -> &Arinc429Bus {
+        self.radio_altimeter.bus()
+    }
+}
+
 impl SimulationElement for A320RadioAltimeter {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.transceivers.accept(visitor);
@@ -139,3 +178,47 @@ impl SimulationElement for A320RadioAltimeter {
         visitor.visit(self);
     }
 }
+
+pub struct A320GpsReceivers {
+    gps_receiver_1: GpsReceiverUnit,
+    gps_receiver_2: GpsReceiverUnit,
+}
+
+impl A320GpsReceivers {
+    pub fn new(context: &mut InitContext) -> Self {
+        Self {
+            gps_receiver_1: GpsReceiverUnit::new(
+                context,
+                1,
+                ElectricalBusType::AlternatingCurrentEssentialShed,
+            ),
+            gps_receiver_2: GpsReceiverUnit::new(
+                context,
+                2,
+                ElectricalBusType::AlternatingCurrent(2),
+            ),
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext) {
+        self.gps_receiver_1.update(context);
+        self.gps_receiver_2.update(context);
+    }
+
+    pub fn gps_receiver_1(&self) -> &GpsReceiverUnit {
+        &self.gps_receiver_1
+    }
+
+    pub fn gps_receiver_2(&self) -> &GpsReceiverUnit {
+        &self.gps_receiver_2
+    }
+}
+
+impl SimulationElement for A320GpsReceivers {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.gps_receiver_1.accept(visitor);
+        self.gps_receiver_2.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
@@ -0,0 +1,48 @@
+use std::time::Duration;
+
+use systems::{
+    oxygen::{CrewOxygenSystem, OxygenOverheadPanel, PassengerOxygenSystem},
+    shared::{CabinSimulation, ElectricalBusType},
+    simulation::{InitContext, SimulationElement, SimulationElementVisitor, UpdateContext},
+};
+
+pub(super) struct A320Oxygen {
+    oxygen_overhead: OxygenOverheadPanel,
+    // One bottle supplies the masks of the captain, first officer and both observers.
+    crew_oxygen: CrewOxygenSystem<1, 4>,
+    passenger_oxygen: PassengerOxygenSystem,
+}
+impl A320Oxygen {
+    const PASSENGER_OXYGEN_GENERATOR_DURATION: Duration = Duration::from_secs(15 * 60);
+
+    pub fn new(context: &mut InitContext) -> Self {
+        Self {
+            oxygen_overhead: OxygenOverheadPanel::new(context),
+            crew_oxygen: CrewOxygenSystem::new(
+                context,
+                ElectricalBusType::DirectCurrentEssentialShed,
+            ),
+            passenger_oxygen: PassengerOxygenSystem::new(
+                context,
+                ElectricalBusType::DirectCurrentEssential,
+                Self::PASSENGER_OXYGEN_GENERATOR_DURATION,
+            ),
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext, cabin: &impl CabinSimulation) {
+        self.crew_oxygen
+            .update(context, &self.oxygen_overhead, cabin);
+        self.passenger_oxygen
+            .update(context, &self.oxygen_overhead, cabin);
+    }
+}
+impl SimulationElement for A320Oxygen {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.oxygen_overhead.accept(visitor);
+        self.crew_oxygen.accept(visitor);
+        self.passenger_oxygen.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
@@ -13,23 +13,26 @@ use uom::si::{
 use systems::{
     accept_iterable,
     air_conditioning::PackFlowControllers,
+    failures::{Failure, FailureType},
     overhead::{AutoOffFaultPushButton, OnOffFaultPushButton},
+    pneumatic::wing_anti_ice::{WingAntiIceComplex, WingAntiIceConstants, WingAntiIceSystem},
     pneumatic::{
-        valve::*, BleedMonitoringComputerChannelOperationMode,
-        BleedMonitoringComputerIsAliveSignal, BleedTemperatureSensor, CompressionChamber,
-        ControllablePneumaticValve, CrossBleedValveSelectorKnob, CrossBleedValveSelectorMode,
-        DifferentialPressureTransducer, EngineCompressionChamberController, EngineModeSelector,
-        EngineState, PneumaticContainer, PneumaticPipe, PneumaticValveSignal, Precooler,
+        valve::*, BleedAirDuctLeak, BleedMonitoringComputerChannelOperationMode,
+        BleedMonitoringComputerIsAliveSignal, BleedOverheatMonitor, BleedOverpressureMonitor,
+        BleedTemperatureSensor, CompressionChamber, ControllablePneumaticValve,
+        CrossBleedValveSelectorKnob, CrossBleedValveSelectorMode, DifferentialPressureTransducer,
+        EngineBleedValveJam, EngineCompressionChamberController, EngineModeSelector, EngineState,
+        LeakDetectionLoop, PneumaticContainer, PneumaticPipe, PneumaticValveSignal, Precooler,
         PressureTransducer, PressurisedReservoirWithExhaustValve, PressurizeableReservoir,
         SolenoidSignal, TargetPressureTemperatureSignal, VariableVolumeContainer,
         WingAntiIcePushButton, WingAntiIceSelected,
     },
     shared::{
         pid::PidController, update_iterator::MaxStepLoop, ControllerSignal, DelayedTrueLogicGate,
-        ElectricalBusType, ElectricalBuses, EngineBleedPushbutton, EngineCorrectedN1,
-        EngineCorrectedN2, EngineFirePushButtons, EngineStartState, HydraulicColor,
-        LgciuWeightOnWheels, PackFlowValveState, PneumaticBleed, PneumaticValve,
-        ReservoirAirPressure,
+        ElectricalBusType, ElectricalBuses, EngineBleedPushbutton, EngineBleedValveId,
+        EngineCorrectedN1, EngineCorrectedN2, EngineFirePushButtons, EnginePneumaticLoads,
+        EngineStartState, HydraulicColor, LeakDetectionZone, LgciuWeightOnWheels,
+        PackFlowValveState, PneumaticBleed, PneumaticValve, ReservoirAirPressure,
     },
     simulation::{
         InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
@@ -38,9 +41,6 @@ use systems::{
     valve_signal_implementation,
 };
 
-mod wing_anti_ice;
-use wing_anti_ice::*;
-
 struct PressureRegulatingValveSignal {
     target_open_amount: Ratio,
 }
@@ -93,6 +93,35 @@ valve_signal_implementation!(EngineStarterValveSignal);
 valve_signal_implementation!(FanAirValveSignal);
 valve_signal_implementation!(PackFlowValveSignal);
 
+pub struct A320WingAntiIceConstants;
+
+impl WingAntiIceConstants for A320WingAntiIceConstants {
+    // Each WAI duct is made of
+    // Flow Trimming Restrictor 47mm diameter
+    // Lagged Supply Duct 50mm diameter
+    // Telescopic Duct 73.66mm to 57.15mm diameter
+    // Piccolo Ducts 63.5mm to 44mm diameter
+    // Flexible Ducts
+    // Slat 2 --> 3.335m
+    // Slat 3 --> 2.8m
+    // Slat 4 --> 2.736m
+    // Slat 5 --> 2.593m
+    // Total length 11.464m
+    // 3.335m * 0.050m diameter
+    // 2.8m   * 0.0635m diameter
+    // 2.736m * 0.055m diameter
+    // 2.593m * 0.044m diameter
+    // 0.458m * 0.05715mm diameter
+    // Total volume of ducts is around 2m^3. Assuming telescopic duct retracted
+    const PIPE_VOLUME_CUBIC_METER: f64 = 2.;
+    const EXHAUST_SPEED: f64 = 0.129;
+    const VALVE_TRANSFER_SPEED: f64 = 1.2;
+    // The tests expect the nominal setpoint
+    const VALVE_SETPOINT_STD_DEV_PSI: f64 = if cfg!(test) { 0. } else { 2.5 };
+    const CONTROLLER_POWERED_BY: ElectricalBusType = ElectricalBusType::DirectCurrentEssentialShed;
+    const GROUND_TEST_DURATION: Duration = Duration::from_secs(30);
+}
+
 pub struct A320Pneumatic {
     physics_updater: MaxStepLoop,
 
@@ -110,11 +139,13 @@ pub struct A320Pneumatic {
 
     apu_compression_chamber: CompressionChamber,
     apu_bleed_air_valve: DefaultValve,
+    apu_bleed_duct_leak: BleedAirDuctLeak,
+    apu_leak_detection_loop: LeakDetectionLoop,
 
     air_starter_unit_compression_chamber: CompressionChamber,
     air_starter_unit_bleed_air_valve: PurelyPneumaticValve,
 
-    wing_anti_ice: WingAntiIceComplex,
+    wing_anti_ice: WingAntiIceComplex<A320WingAntiIceConstants>,
 
     hydraulic_reservoir_bleed_air_valves: [PurelyPneumaticValve; 2],
     hydraulic_reservoir_bleed_air_pipe: PneumaticPipe,
@@ -130,6 +161,7 @@ pub struct A320Pneumatic {
 }
 impl A320Pneumatic {
     const PNEUMATIC_SIM_MAX_TIME_STEP: Duration = Duration::from_millis(10);
+    const APU_LEAK_DETECTION_ALARM_TEMPERATURE_C: f64 = 124.;
 
     pub fn new(context: &mut InitContext) -> Self {
         Self {
@@ -167,6 +199,18 @@ impl A320Pneumatic {
             ],
             apu_compression_chamber: CompressionChamber::new(Volume::new::<cubic_meter>(5.)),
             apu_bleed_air_valve: DefaultValve::new_closed(),
+            apu_bleed_duct_leak: BleedAirDuctLeak::new(
+                LeakDetectionZone::Apu,
+                EngineBleedAirSystem::DUCT_LEAK_SPEED,
+            ),
+            apu_leak_detection_loop: LeakDetectionLoop::new(
+                context,
+                LeakDetectionZone::Apu,
+                ThermodynamicTemperature::new::<degree_celsius>(
+                    Self::APU_LEAK_DETECTION_ALARM_TEMPERATURE_C,
+                ),
+                ElectricalBusType::DirectCurrentEssentialShed,
+            ),
             air_starter_unit_compression_chamber: CompressionChamber::new(
                 Volume::new::<cubic_meter>(5.),
             ),
@@ -264,6 +308,8 @@ impl A320Pneumatic {
         self.air_starter_unit_compression_chamber.update(asu);
 
         for bleed_monitoring_computer in self.bleed_monitoring_computers.iter_mut() {
+            bleed_monitoring_computer
+                .update_leak_detection(&self.engine_systems, &self.apu_leak_detection_loop);
             bleed_monitoring_computer.update(
                 context,
                 &self.engine_systems,
@@ -321,7 +367,7 @@ impl A320Pneumatic {
 
         self.wing_anti_ice.update(
             context,
-            &mut self.engine_systems,
+            self.engine_systems.each_mut(),
             overhead_panel.wing_anti_ice.mode(),
             lgciu,
         );
@@ -331,6 +377,15 @@ impl A320Pneumatic {
             &mut self.apu_compression_chamber,
             left_system,
         );
+        // The APU bleed duct is only pressurised by the APU while the APU bleed valve is open.
+        if self.apu_bleed_air_valve.is_open() {
+            self.apu_bleed_duct_leak
+                .update_move_fluid(context, &mut self.apu_compression_chamber);
+        } else {
+            self.apu_bleed_duct_leak.update_isolated();
+        }
+        self.apu_leak_detection_loop
+            .update(context, &self.apu_bleed_duct_leak);
         self.air_starter_unit_bleed_air_valve.update_move_fluid(
             context,
             &mut self.air_starter_unit_compression_chamber,
@@ -364,6 +419,10 @@ impl A320Pneumatic {
         &mut self.apu_bleed_air_valve
     }
 
+    pub fn wing_anti_ice_systems(&self) -> &[WingAntiIceSystem<A320WingAntiIceConstants>; 2] {
+        self.wing_anti_ice.wai_systems()
+    }
+
     pub fn update_hydraulic_reservoir_spatial_volumes(
         &mut self,
         green_hydraulic_reservoir: &impl PressurizeableReservoir,
@@ -381,6 +440,14 @@ impl A320Pneumatic {
     pub fn packs(&mut self) -> &mut [PackComplex; 2] {
         &mut self.packs
     }
+
+    /// The BMCs signal the APU ECB to close the APU bleed valve when a leak is detected in the APU
+    /// bleed duct or the left wing.
+    pub fn should_close_apu_bleed_valve_because_of_leak(&self) -> bool {
+        self.bleed_monitoring_computers
+            .iter()
+            .any(|bmc| bmc.should_close_apu_bleed_valve_because_of_leak())
+    }
 }
 impl PneumaticBleed for A320Pneumatic {
     fn apu_bleed_is_on(&self) -> bool {
@@ -398,6 +465,15 @@ impl EngineStartState for A320Pneumatic {
         self.fadec.engine_mode_selector()
     }
 }
+impl EnginePneumaticLoads for A320Pneumatic {
+    fn starter_air_pressure(&self, engine_number: usize) -> Pressure {
+        self.engine_systems[engine_number - 1].engine_starter_air_pressure()
+    }
+
+    fn bleed_air_extraction(&self, engine_number: usize) -> MassRate {
+        self.engine_systems[engine_number - 1].bleed_air_extraction()
+    }
+}
 impl PackFlowValveState for A320Pneumatic {
     // pack_id: 1 or 2
     fn pack_flow_valve_is_open(&self, pack_id: usize) -> bool {
@@ -415,6 +491,8 @@ impl SimulationElement for A320Pneumatic {
         self.cross_bleed_valve.accept(visitor);
         self.fadec.accept(visitor);
         self.wing_anti_ice.accept(visitor);
+        self.apu_bleed_duct_leak.accept(visitor);
+        self.apu_leak_detection_loop.accept(visitor);
 
         accept_iterable!(self.bleed_monitoring_computers, visitor);
         accept_iterable!(self.engine_systems, visitor);
@@ -496,6 +574,7 @@ struct BleedMonitoringComputer {
     backup_channel_engine_number: usize,
     main_channel: BleedMonitoringComputerChannel,
     backup_channel: BleedMonitoringComputerChannel,
+    failure: Failure,
     powered_by: ElectricalBusType,
     is_powered: bool,
 }
@@ -519,6 +598,9 @@ impl BleedMonitoringComputer {
                 backup_channel_engine_number,
                 BleedMonitoringComputerChannelOperationMode::Slave,
             ),
+            failure: Failure::new(FailureType::BleedMonitoringComputer(
+                main_channel_engine_number,
+            )),
             powered_by,
             is_powered: true,
         }
@@ -558,6 +640,26 @@ impl BleedMonitoringComputer {
         );
     }
 
+    fn update_leak_detection(
+        &mut self,
+        engine_systems: &[EngineBleedAirSystem; 2],
+        apu_leak_detection_loop: &LeakDetectionLoop,
+    ) {
+        if self.is_operative() {
+            self.main_channel
+                .update_leak_detection(engine_systems, apu_leak_detection_loop);
+            self.backup_channel
+                .update_leak_detection(engine_systems, apu_leak_detection_loop);
+        } else {
+            self.main_channel.reset_leak_detection();
+            self.backup_channel.reset_leak_detection();
+        }
+    }
+
+    fn should_close_apu_bleed_valve_because_of_leak(&self) -> bool {
+        self.is_operative() && self.main_channel.has_apu_bleed_leak()
+    }
+
     fn check_for_failure(&mut self, other: &mut BleedMonitoringComputer) {
         if other.signal().is_none() {
             self.change_backup_channel_operation_mode(
@@ -599,11 +701,16 @@ impl BleedMonitoringComputer {
     fn is_powered(&self) -> bool {
         self.is_powered
     }
+
+    fn is_operative(&self) -> bool {
+        self.is_powered() && !self.failure.is_active()
+    }
 }
 impl SimulationElement for BleedMonitoringComputer {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.main_channel.accept(visitor);
         self.backup_channel.accept(visitor);
+        self.failure.accept(visitor);
 
         visitor.visit(self);
     }
@@ -614,7 +721,7 @@ impl SimulationElement for BleedMonitoringComputer {
 }
 impl ControllerSignal<BleedMonitoringComputerIsAliveSignal> for BleedMonitoringComputer {
     fn signal(&self) -> Option<BleedMonitoringComputerIsAliveSignal> {
-        if self.is_powered() {
+        if self.is_operative() {
             Some(BleedMonitoringComputerIsAliveSignal)
         } else {
             None
@@ -643,11 +750,15 @@ struct BleedMonitoringComputerChannel {
     should_use_ip_vs_hp_valve: bool,
     overheat_monitor: BleedOverheatMonitor,
     overpressure_monitor: BleedOverpressureMonitor,
+    has_bleed_fault: bool,
     has_low_bleed_temperature: bool,
     is_in_dual_bleed_config: bool,
     flight_phase_loop: FlightPhaseLoop,
     low_temperature_regulation_active: DelayedTrueLogicGate,
     should_command_onside_prv_closed: bool,
+    has_engine_bleed_leak: [bool; 2],
+    has_cross_bleed_leak: bool,
+    has_apu_bleed_leak: bool,
 
     low_temperature_id: VariableIdentifier,
     overheat_id: VariableIdentifier,
@@ -696,10 +807,14 @@ impl BleedMonitoringComputerChannel {
             should_use_ip_vs_hp_valve: false,
             overheat_monitor: BleedOverheatMonitor::new(),
             overpressure_monitor: BleedOverpressureMonitor::new(),
+            has_bleed_fault: false,
             has_low_bleed_temperature: false,
             flight_phase_loop: FlightPhaseLoop::new(),
             low_temperature_regulation_active: DelayedTrueLogicGate::new(Duration::from_secs(20)),
             should_command_onside_prv_closed: false,
+            has_engine_bleed_leak: [false; 2],
+            has_cross_bleed_leak: false,
+            has_apu_bleed_leak: false,
             is_in_dual_bleed_config: false,
             low_temperature_id: context
                 .get_identifier(format!("PNEU_ENG_{}_LOW_TEMPERATURE", engine_number)),
@@ -830,6 +945,38 @@ impl BleedMonitoringComputerChannel {
         }
     }
 
+    /// A detected leak isolates the affected ducts until the BMC is reset by a loss of power:
+    /// - a wing or pylon leak closes the bleed valve of the engine,
+    /// - a wing or APU leak closes the crossbleed valve when in automatic mode,
+    /// - a left wing or APU leak closes the APU bleed valve.
+    fn update_leak_detection(
+        &mut self,
+        engine_systems: &[EngineBleedAirSystem; 2],
+        apu_leak_detection_loop: &LeakDetectionLoop,
+    ) {
+        for (has_leak, engine_system) in self.has_engine_bleed_leak.iter_mut().zip(engine_systems) {
+            *has_leak |= engine_system.wing_leak_detected() || engine_system.pylon_leak_detected();
+        }
+
+        self.has_cross_bleed_leak |= apu_leak_detection_loop.leak_detected()
+            || engine_systems
+                .iter()
+                .any(|engine_system| engine_system.wing_leak_detected());
+
+        self.has_apu_bleed_leak |=
+            apu_leak_detection_loop.leak_detected() || engine_systems[0].wing_leak_detected();
+    }
+
+    fn reset_leak_detection(&mut self) {
+        self.has_engine_bleed_leak = [false; 2];
+        self.has_cross_bleed_leak = false;
+        self.has_apu_bleed_leak = false;
+    }
+
+    fn has_apu_bleed_leak(&self) -> bool {
+        self.has_apu_bleed_leak
+    }
+
     fn operation_mode(&self) -> BleedMonitoringComputerChannelOperationMode {
         self.operation_mode
     }
@@ -865,6 +1012,7 @@ impl BleedMonitoringComputerChannel {
     ) -> bool {
         !overhead_panel.engine_bleed_pb_is_auto(engine_number)
             || engine_fire_pushbuttons.is_released(engine_number)
+            || self.has_engine_bleed_leak[engine_number - 1]
             || self.should_close_pressure_regulating_valve_because_apu_bleed_is_on(
                 engine_number,
                 overhead_panel,
@@ -909,14 +1057,20 @@ impl BleedMonitoringComputerChannel {
         cross_bleed_valve: &impl PneumaticValve,
         fadec: &FullAuthorityDigitalEngineControl,
     ) {
+        // An overpressure or overheat keeps the PRV closed until the engine bleed pushbutton is
+        // selected off.
+        self.has_bleed_fault = overhead_panel.engine_bleed_pb_is_auto(self.engine_number)
+            && (self.has_bleed_fault
+                || self.overpressure_monitor.has_overpressure()
+                || self.overheat_monitor.has_overheat());
+
         self.should_command_onside_prv_closed = self.should_command_prv_closed(
             self.engine_number,
             overhead_panel,
             engine_fire_pushbuttons,
             cross_bleed_valve,
         ) || sensors.engine_starter_valve_is_open()
-            || self.overpressure_monitor.has_overpressure()
-            || self.overheat_monitor.has_overheat();
+            || self.has_bleed_fault;
 
         let should_command_offside_prv_closed = self.should_command_prv_closed(
             self.engine_number % 2 + 1,
@@ -998,7 +1152,7 @@ impl ControllerSignal<CrossBleedValveSignal> for BleedMonitoringComputerChannel
                 CrossBleedValveSignalType::Manual,
             )),
             CrossBleedValveSelectorMode::Auto => {
-                if self.is_apu_bleed_valve_open {
+                if self.is_apu_bleed_valve_open && !self.has_cross_bleed_leak {
                     Some(CrossBleedValveSignal::new_open(
                         CrossBleedValveSignalType::Automatic,
                     ))
@@ -1063,6 +1217,7 @@ struct EngineBleedAirSystem {
     engine_starter_exhaust: PneumaticExhaust,
     engine_starter_container: PneumaticPipe,
     engine_starter_pressurized: bool,
+    engine_starter_air_pressure: Pressure,
     engine_starter_valve: DefaultValve,
     fan_air_valve: ElectroPneumaticValve,
     precooler: Precooler,
@@ -1071,11 +1226,27 @@ struct EngineBleedAirSystem {
     regulated_pressure_transducer: PressureTransducer,
     differential_pressure_transducer: DifferentialPressureTransducer,
     bleed_temperature_sensor: BleedTemperatureSensor,
+
+    pylon_duct_leak: BleedAirDuctLeak,
+    wing_duct_leak: BleedAirDuctLeak,
+    pylon_leak_detection_loop: LeakDetectionLoop,
+    wing_leak_detection_loop: LeakDetectionLoop,
+
+    high_pressure_valve_jam: EngineBleedValveJam,
+    pressure_regulating_valve_jam: EngineBleedValveJam,
+    fan_air_valve_jam: EngineBleedValveJam,
+    precooler_blockage_failure: Failure,
+    overpressure_failure: Failure,
+    overtemperature_failure: Failure,
 }
 impl EngineBleedAirSystem {
     const MIN_ENGINE_START_CONTAINER_PRESSURE_PSIG_HIGH: f64 = 10.;
     const MIN_ENGINE_START_CONTAINER_PRESSURE_PSIG_LOW: f64 = 5.;
 
+    const DUCT_LEAK_SPEED: f64 = 0.1;
+    const PYLON_LEAK_DETECTION_ALARM_TEMPERATURE_C: f64 = 204.;
+    const WING_LEAK_DETECTION_ALARM_TEMPERATURE_C: f64 = 124.;
+
     fn new(context: &mut InitContext, number: usize, powered_by: ElectricalBusType) -> Self {
         Self {
             number,
@@ -1183,6 +1354,7 @@ impl EngineBleedAirSystem {
                 ThermodynamicTemperature::new::<degree_celsius>(15.),
             ),
             engine_starter_pressurized: false,
+            engine_starter_air_pressure: Pressure::default(),
             engine_starter_exhaust: PneumaticExhaust::new(10., 10., Pressure::new::<psi>(0.)),
             engine_starter_valve: DefaultValve::new_closed(),
             precooler: Precooler::new(900. * 2.),
@@ -1191,6 +1363,42 @@ impl EngineBleedAirSystem {
             regulated_pressure_transducer: PressureTransducer::new(powered_by),
             differential_pressure_transducer: DifferentialPressureTransducer::new(powered_by),
             bleed_temperature_sensor: BleedTemperatureSensor::new(powered_by),
+            pylon_duct_leak: BleedAirDuctLeak::new(
+                LeakDetectionZone::Pylon(number),
+                Self::DUCT_LEAK_SPEED,
+            ),
+            wing_duct_leak: BleedAirDuctLeak::new(
+                LeakDetectionZone::Wing(number),
+                Self::DUCT_LEAK_SPEED,
+            ),
+            pylon_leak_detection_loop: LeakDetectionLoop::new(
+                context,
+                LeakDetectionZone::Pylon(number),
+                ThermodynamicTemperature::new::<degree_celsius>(
+                    Self::PYLON_LEAK_DETECTION_ALARM_TEMPERATURE_C,
+                ),
+                powered_by,
+            ),
+            wing_leak_detection_loop: LeakDetectionLoop::new(
+                context,
+                LeakDetectionZone::Wing(number),
+                ThermodynamicTemperature::new::<degree_celsius>(
+                    Self::WING_LEAK_DETECTION_ALARM_TEMPERATURE_C,
+                ),
+                powered_by,
+            ),
+            high_pressure_valve_jam: EngineBleedValveJam::new(
+                number,
+                EngineBleedValveId::HighPressure,
+            ),
+            pressure_regulating_valve_jam: EngineBleedValveJam::new(
+                number,
+                EngineBleedValveId::PressureRegulating,
+            ),
+            fan_air_valve_jam: EngineBleedValveJam::new(number, EngineBleedValveId::FanAir),
+            precooler_blockage_failure: Failure::new(FailureType::PrecoolerBlockage(number)),
+            overpressure_failure: Failure::new(FailureType::EngineBleedOverpressure(number)),
+            overtemperature_failure: Failure::new(FailureType::EngineBleedOvertemperature(number)),
         }
     }
 
@@ -1218,6 +1426,8 @@ impl EngineBleedAirSystem {
         self.high_pressure_compression_chamber
             .update(&self.high_pressure_compression_chamber_controller);
 
+        self.update_failures();
+
         self.high_pressure_valve
             .update_solenoid(high_pressure_valve_controller);
         self.pressure_regulating_valve
@@ -1258,6 +1468,10 @@ impl EngineBleedAirSystem {
             &mut self.precooler_supply_pipe,
             &mut self.precooler_outlet_pipe,
         );
+        self.pylon_duct_leak
+            .update_move_fluid(context, &mut self.regulated_pressure_pipe);
+        self.wing_duct_leak
+            .update_move_fluid(context, &mut self.precooler_outlet_pipe);
         self.engine_starter_valve
             .update_move_fluid_with_transfer_speed(
                 context,
@@ -1278,11 +1492,33 @@ impl EngineBleedAirSystem {
 
         self.bleed_temperature_sensor
             .update(&self.precooler_outlet_pipe);
+
+        self.pylon_leak_detection_loop
+            .update(context, &self.pylon_duct_leak);
+        self.wing_leak_detection_loop
+            .update(context, &self.wing_duct_leak);
+    }
+
+    fn update_failures(&mut self) {
+        self.high_pressure_valve
+            .set_jammed(self.high_pressure_valve_jam.open_amount());
+        self.pressure_regulating_valve
+            .set_jammed(self.pressure_regulating_valve_jam.open_amount());
+        self.fan_air_valve
+            .set_jammed(self.fan_air_valve_jam.open_amount());
+
+        self.pressure_regulating_valve
+            .set_regulation_lost(self.overpressure_failure.is_active());
+        self.precooler
+            .set_blocked(self.precooler_blockage_failure.is_active());
+        self.precooler
+            .set_cooling_lost(self.overtemperature_failure.is_active());
     }
 
     fn update_engine_start_pressurization(&mut self, context: &UpdateContext) {
         let starter_container_pressure_psig =
             self.engine_starter_container.pressure() - context.ambient_pressure();
+        self.engine_starter_air_pressure = starter_container_pressure_psig;
 
         self.engine_starter_pressurized = (!self.engine_starter_pressurized
             && starter_container_pressure_psig.get::<psi>()
@@ -1349,6 +1585,16 @@ impl EngineBleedAirSystem {
         self.engine_starter_valve.is_open()
     }
 
+    fn engine_starter_air_pressure(&self) -> Pressure {
+        self.engine_starter_air_pressure
+    }
+
+    fn bleed_air_extraction(&self) -> MassRate {
+        self.pressure_regulating_valve
+            .fluid_flow()
+            .max(MassRate::default())
+    }
+
     fn pressure_regulating_valve_is_open(&self) -> bool {
         self.pressure_regulating_valve.is_open()
     }
@@ -1368,6 +1614,14 @@ impl EngineBleedAirSystem {
     fn bleed_temperature_sensor_temperature(&self) -> Option<ThermodynamicTemperature> {
         self.bleed_temperature_sensor.signal()
     }
+
+    fn pylon_leak_detected(&self) -> bool {
+        self.pylon_leak_detection_loop.leak_detected()
+    }
+
+    fn wing_leak_detected(&self) -> bool {
+        self.wing_leak_detection_loop.leak_detected()
+    }
 }
 impl SimulationElement for EngineBleedAirSystem {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
@@ -1380,6 +1634,18 @@ impl SimulationElement for EngineBleedAirSystem {
         self.differential_pressure_transducer.accept(visitor);
         self.bleed_temperature_sensor.accept(visitor);
 
+        self.pylon_duct_leak.accept(visitor);
+        self.wing_duct_leak.accept(visitor);
+        self.pylon_leak_detection_loop.accept(visitor);
+        self.wing_leak_detection_loop.accept(visitor);
+
+        self.high_pressure_valve_jam.accept(visitor);
+        self.pressure_regulating_valve_jam.accept(visitor);
+        self.fan_air_valve_jam.accept(visitor);
+        self.precooler_blockage_failure.accept(visitor);
+        self.overpressure_failure.accept(visitor);
+        self.overtemperature_failure.accept(visitor);
+
         visitor.visit(self);
     }
 
@@ -1481,62 +1747,6 @@ impl PneumaticContainer for EngineBleedAirSystem {
     }
 }
 
-struct BleedOverheatMonitor {
-    temperature_over_257_for_55s: DelayedTrueLogicGate,
-    temperature_over_270_for_15s: DelayedTrueLogicGate,
-    temperature_over_290_for_5s: DelayedTrueLogicGate,
-}
-impl BleedOverheatMonitor {
-    fn new() -> Self {
-        Self {
-            temperature_over_257_for_55s: DelayedTrueLogicGate::new(Duration::from_secs(55)),
-            temperature_over_270_for_15s: DelayedTrueLogicGate::new(Duration::from_secs(15)),
-            temperature_over_290_for_5s: DelayedTrueLogicGate::new(Duration::from_secs(5)),
-        }
-    }
-
-    fn update(&mut self, context: &UpdateContext, temperature: ThermodynamicTemperature) {
-        self.temperature_over_257_for_55s.update(
-            context,
-            temperature > ThermodynamicTemperature::new::<degree_celsius>(257.),
-        );
-        self.temperature_over_270_for_15s.update(
-            context,
-            temperature > ThermodynamicTemperature::new::<degree_celsius>(270.),
-        );
-        self.temperature_over_290_for_5s.update(
-            context,
-            temperature > ThermodynamicTemperature::new::<degree_celsius>(290.),
-        );
-    }
-
-    fn has_overheat(&self) -> bool {
-        self.temperature_over_257_for_55s.output()
-            || self.temperature_over_270_for_15s.output()
-            || self.temperature_over_290_for_5s.output()
-    }
-}
-
-struct BleedOverpressureMonitor {
-    pressure_over_60_psig_for_15s: DelayedTrueLogicGate,
-}
-impl BleedOverpressureMonitor {
-    fn new() -> Self {
-        Self {
-            pressure_over_60_psig_for_15s: DelayedTrueLogicGate::new(Duration::from_secs(15)),
-        }
-    }
-
-    fn update(&mut self, context: &UpdateContext, pressure: Pressure) {
-        self.pressure_over_60_psig_for_15s
-            .update(context, pressure > Pressure::new::<psi>(60.));
-    }
-
-    fn has_overpressure(&self) -> bool {
-        self.pressure_over_60_psig_for_15s.output()
-    }
-}
-
 pub struct A320PneumaticOverheadPanel {
     apu_bleed: OnOffFaultPushButton,
     cross_bleed: CrossBleedValveSelectorKnob,
@@ -1912,6 +2122,7 @@ pub mod tests {
         electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
         engine::leap_engine::LeapEngine,
         failures::FailureType,
+        icing_state::ActiveDeicingController,
         pneumatic::{
             BleedMonitoringComputerChannelOperationMode, ControllablePneumaticValve,
             CrossBleedValveSelectorMode, EngineState, PneumaticContainer, PneumaticValveSignal,
@@ -1921,9 +2132,10 @@ pub mod tests {
             arinc429::{Arinc429Word, SignStatus},
             interpolation, ApuBleedAirValveSignal, CabinAltitude, CabinSimulation,
             ControllerSignal, ElectricalBusType, ElectricalBuses, EmergencyElectricalState,
-            EngineCorrectedN1, EngineFirePushButtons, EngineStartState, HydraulicColor,
-            InternationalStandardAtmosphere, LgciuWeightOnWheels, MachNumber, PackFlowValveState,
-            PneumaticBleed, PneumaticValve, PotentialOrigin,
+            EngineBleedValveId, EngineCorrectedN1, EngineFirePushButtons, EngineStartState,
+            HydraulicColor, InternationalStandardAtmosphere, LeakDetectionZone,
+            LgciuWeightOnWheels, MachNumber, PackFlowValveState, PneumaticBleed, PneumaticValve,
+            PotentialOrigin,
         },
         simulation::{
             test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
@@ -2747,6 +2959,18 @@ pub mod tests {
             self.query(|a| a.pneumatic.cross_bleed_valve.is_open())
         }
 
+        fn wing_leak_detected(&self, number: usize) -> bool {
+            self.query(|a| a.pneumatic.engine_systems[number - 1].wing_leak_detected())
+        }
+
+        fn pylon_leak_detected(&self, number: usize) -> bool {
+            self.query(|a| a.pneumatic.engine_systems[number - 1].pylon_leak_detected())
+        }
+
+        fn should_close_apu_bleed_valve_because_of_leak(&self) -> bool {
+            self.query(|a| a.pneumatic.should_close_apu_bleed_valve_because_of_leak())
+        }
+
         fn cross_bleed_valve_selector(&self) -> CrossBleedValveSelectorMode {
             self.query(|a| a.pneumatic_overhead_panel.cross_bleed_mode())
         }
@@ -2920,6 +3144,15 @@ pub mod tests {
             self.query(|a| a.pneumatic.wing_anti_ice.wai_consumer_temperature(1))
         }
 
+        fn wai_deicing_rates(&self) -> [Ratio; 2] {
+            self.query(|a| {
+                a.pneumatic
+                    .wing_anti_ice_systems()
+                    .each_ref()
+                    .map(|wai| wai.active_deicing_normalized_rate())
+            })
+        }
+
         fn valve_controller_timer(&self) -> Duration {
             self.query(|a| a.pneumatic.wing_anti_ice.wai_timer())
         }
@@ -4873,6 +5106,20 @@ pub mod tests {
             );
         }
 
+        #[test]
+        fn wing_anti_ice_deices_the_slats_when_on() {
+            let test_bed = test_bed_with()
+                .in_isa_atmosphere(Length::new::<foot>(6000.))
+                .toga_eng1()
+                .toga_eng2()
+                .wing_anti_ice_push_button(WingAntiIcePushButtonMode::On)
+                .and_stabilize();
+
+            for rate in test_bed.wai_deicing_rates() {
+                assert_eq!(rate, Ratio::new::<ratio>(1.));
+            }
+        }
+
         #[test]
         fn wing_anti_ice_valve_close_after_30_seconds_on_ground() {
             let altitude = Length::new::<foot>(10.);
@@ -5219,4 +5466,314 @@ pub mod tests {
             assert!(test_bed.pack_flow_valve_flow(2) < flow_rate_tolerance());
         }
     }
+
+    mod leak_detection {
+        use super::*;
+
+        #[test]
+        fn no_leak_is_detected_without_failure() {
+            let mut test_bed = test_bed_with().idle_eng1().idle_eng2().and_stabilize();
+            test_bed.run_multiple_frames(Duration::from_secs(30));
+
+            assert!(!test_bed.wing_leak_detected(1));
+            assert!(!test_bed.wing_leak_detected(2));
+            assert!(!test_bed.pylon_leak_detected(1));
+            assert!(!test_bed.pylon_leak_detected(2));
+            assert!(test_bed.pr_valve_is_open(1));
+            assert!(test_bed.pr_valve_is_open(2));
+        }
+
+        #[test]
+        fn wing_leak_closes_the_engine_bleed_valve() {
+            let mut test_bed = test_bed_with().idle_eng1().idle_eng2().and_stabilize();
+
+            test_bed.fail(FailureType::BleedAirLeak(LeakDetectionZone::Wing(1)));
+            test_bed.run_multiple_frames(Duration::from_secs(30));
+
+            assert!(!test_bed.pr_valve_is_open(1));
+            assert!(test_bed.pr_valve_is_open(2));
+        }
+
+        #[test]
+        fn wing_leak_detection_is_written() {
+            let mut test_bed = test_bed_with().idle_eng1().idle_eng2().and_stabilize();
+
+            test_bed.fail(FailureType::BleedAirLeak(LeakDetectionZone::Wing(2)));
+
+            let mut leak_detected = false;
+            for _ in 0..40 {
+                test_bed.run_multiple_frames(Duration::from_secs(1));
+
+                let is_detected: bool = test_bed.read_by_name("PNEU_WING_2_LEAK_DETECTED");
+                leak_detected |= is_detected;
+            }
+
+            assert!(leak_detected);
+        }
+
+        #[test]
+        fn pylon_leak_closes_the_engine_bleed_valve() {
+            let mut test_bed = test_bed_with()
+                .eng1_n1(0.9)
+                .and_eng1_n2_based_on_n1()
+                .eng2_n1(0.9)
+                .and_eng2_n2_based_on_n1()
+                .and_stabilize();
+
+            test_bed.fail(FailureType::BleedAirLeak(LeakDetectionZone::Pylon(2)));
+            test_bed.run_multiple_frames(Duration::from_secs(30));
+
+            assert!(test_bed.pr_valve_is_open(1));
+            assert!(!test_bed.pr_valve_is_open(2));
+        }
+
+        #[test]
+        fn engine_bleed_valve_remains_closed_once_leak_is_isolated() {
+            let mut test_bed = test_bed_with().idle_eng1().idle_eng2().and_stabilize();
+
+            test_bed.fail(FailureType::BleedAirLeak(LeakDetectionZone::Wing(1)));
+            test_bed.run_multiple_frames(Duration::from_secs(30));
+            test_bed.unfail(FailureType::BleedAirLeak(LeakDetectionZone::Wing(1)));
+            test_bed.run_multiple_frames(Duration::from_secs(120));
+
+            assert!(!test_bed.wing_leak_detected(1));
+            assert!(!test_bed.pr_valve_is_open(1));
+        }
+
+        #[test]
+        fn wing_leak_closes_the_cross_bleed_valve() {
+            let mut test_bed = test_bed_with()
+                .stop_eng1()
+                .stop_eng2()
+                .cross_bleed_valve_selector_knob(CrossBleedValveSelectorMode::Auto)
+                .set_bleed_air_running()
+                .and_stabilize();
+
+            assert!(test_bed.cross_bleed_valve_is_open());
+
+            test_bed.fail(FailureType::BleedAirLeak(LeakDetectionZone::Wing(2)));
+            test_bed.run_multiple_frames(Duration::from_secs(30));
+
+            assert!(!test_bed.cross_bleed_valve_is_open());
+        }
+
+        #[test]
+        fn apu_leak_closes_the_apu_bleed_valve() {
+            let mut test_bed = test_bed_with()
+                .stop_eng1()
+                .stop_eng2()
+                .set_bleed_air_running()
+                .and_stabilize();
+
+            assert!(!test_bed.should_close_apu_bleed_valve_because_of_leak());
+
+            test_bed.fail(FailureType::BleedAirLeak(LeakDetectionZone::Apu));
+            test_bed.run_multiple_frames(Duration::from_secs(30));
+
+            assert!(test_bed.should_close_apu_bleed_valve_because_of_leak());
+            assert!(!test_bed.cross_bleed_valve_is_open());
+        }
+
+        #[test]
+        fn leak_is_not_detected_by_failed_loop() {
+            let mut test_bed = test_bed_with().idle_eng1().idle_eng2().and_stabilize();
+
+            test_bed.fail(FailureType::LeakDetectionLoop(LeakDetectionZone::Wing(1)));
+            test_bed.fail(FailureType::BleedAirLeak(LeakDetectionZone::Wing(1)));
+            test_bed.run_multiple_frames(Duration::from_secs(30));
+
+            assert!(!test_bed.wing_leak_detected(1));
+            assert!(test_bed.pr_valve_is_open(1));
+        }
+    }
+
+    mod bleed_failures {
+        use super::*;
+
+        fn test_bed_at_high_thrust() -> PneumaticTestBed {
+            test_bed_with()
+                .eng1_n1(0.9)
+                .and_eng1_n2_based_on_n1()
+                .eng2_n1(0.9)
+                .and_eng2_n2_based_on_n1()
+                .and_stabilize()
+        }
+
+        #[test]
+        fn pressure_regulating_valve_jammed_closed_removes_engine_bleed() {
+            let mut test_bed = test_bed_with().idle_eng1().idle_eng2().and_stabilize();
+
+            test_bed.fail(FailureType::EngineBleedValveJammedClosed(
+                1,
+                EngineBleedValveId::PressureRegulating,
+            ));
+            test_bed.run_multiple_frames(Duration::from_secs(30));
+
+            assert!(!test_bed.pr_valve_is_open(1));
+            assert!(test_bed.pr_valve_is_open(2));
+            assert!(
+                test_bed.precooler_outlet_pressure(1).get::<psi>()
+                    < test_bed.precooler_outlet_pressure(2).get::<psi>() - 10.
+            );
+        }
+
+        #[test]
+        fn pressure_regulating_valve_jammed_open_remains_open_with_engine_bleed_push_button_off() {
+            let mut test_bed = test_bed_with().idle_eng1().idle_eng2().and_stabilize();
+
+            test_bed.fail(FailureType::EngineBleedValveJammedOpen(
+                1,
+                EngineBleedValveId::PressureRegulating,
+            ));
+            test_bed = test_bed
+                .set_engine_bleed_push_button_off(1)
+                .set_engine_bleed_push_button_off(2);
+            test_bed.run_multiple_frames(Duration::from_secs(30));
+
+            assert!(test_bed.pr_valve_is_open(1));
+            assert!(!test_bed.pr_valve_is_open(2));
+        }
+
+        #[test]
+        fn high_pressure_valve_jammed_closed_lowers_transfer_pressure_at_idle() {
+            let mut test_bed = test_bed_with().idle_eng1().idle_eng2().and_stabilize();
+
+            test_bed.fail(FailureType::EngineBleedValveJammedClosed(
+                1,
+                EngineBleedValveId::HighPressure,
+            ));
+            test_bed.run_multiple_frames(Duration::from_secs(30));
+
+            assert!(!test_bed.hp_valve_is_open(1));
+            assert!(test_bed.hp_valve_is_open(2));
+            assert!(test_bed.transfer_pressure(1) < test_bed.transfer_pressure(2));
+        }
+
+        #[test]
+        fn fan_air_valve_jammed_closed_raises_precooler_outlet_temperature() {
+            let mut test_bed = test_bed_at_high_thrust();
+
+            test_bed.fail(FailureType::EngineBleedValveJammedClosed(
+                1,
+                EngineBleedValveId::FanAir,
+            ));
+            test_bed.run_multiple_frames(Duration::from_secs(30));
+
+            assert!(
+                test_bed
+                    .precooler_outlet_temperature(1)
+                    .get::<degree_celsius>()
+                    > test_bed
+                        .precooler_outlet_temperature(2)
+                        .get::<degree_celsius>()
+                        + 10.
+            );
+        }
+
+        #[test]
+        fn precooler_blockage_lowers_precooler_outlet_pressure() {
+            let mut test_bed = test_bed_with().idle_eng1().idle_eng2().and_stabilize();
+
+            test_bed.fail(FailureType::PrecoolerBlockage(1));
+            test_bed.run_multiple_frames(Duration::from_secs(30));
+
+            assert!(
+                test_bed.precooler_outlet_pressure(1).get::<psi>()
+                    < test_bed.precooler_outlet_pressure(2).get::<psi>() - 10.
+            );
+        }
+
+        #[test]
+        fn overtemperature_raises_precooler_outlet_temperature() {
+            let mut test_bed = test_bed_at_high_thrust();
+
+            test_bed.fail(FailureType::EngineBleedOvertemperature(1));
+            test_bed.run_multiple_frames(Duration::from_secs(30));
+
+            assert!(
+                test_bed
+                    .precooler_outlet_temperature(1)
+                    .get::<degree_celsius>()
+                    > test_bed
+                        .precooler_outlet_temperature(2)
+                        .get::<degree_celsius>()
+                        + 20.
+            );
+        }
+
+        #[test]
+        fn overheat_closes_the_engine_bleed_valve() {
+            let mut test_bed = test_bed_at_high_thrust();
+
+            test_bed.fail(FailureType::EngineBleedOvertemperature(1));
+            test_bed.fail(FailureType::EngineBleedValveJammedOpen(
+                1,
+                EngineBleedValveId::HighPressure,
+            ));
+
+            let mut overheat = false;
+            for _ in 0..40 {
+                test_bed.run_multiple_frames(Duration::from_secs(1));
+
+                let has_overheat: bool = test_bed.read_by_name("PNEU_ENG_1_OVERHEAT");
+                overheat |= has_overheat;
+            }
+
+            assert!(overheat);
+            assert!(!test_bed.pr_valve_is_open(1));
+            assert!(test_bed.pr_valve_is_open(2));
+        }
+
+        #[test]
+        fn overpressure_closes_the_engine_bleed_valve() {
+            let mut test_bed = test_bed_at_high_thrust();
+
+            test_bed.fail(FailureType::EngineBleedOverpressure(1));
+
+            let mut overpressure = false;
+            for _ in 0..30 {
+                test_bed.run_multiple_frames(Duration::from_secs(1));
+
+                let has_overpressure: bool = test_bed.read_by_name("PNEU_ENG_1_OVERPRESSURE");
+                overpressure |= has_overpressure;
+            }
+
+            assert!(overpressure);
+            assert!(!test_bed.pr_valve_is_open(1));
+            assert!(test_bed.pr_valve_is_open(2));
+        }
+
+        #[test]
+        fn engine_bleed_valve_reopens_after_bleed_fault_once_push_button_is_cycled() {
+            let mut test_bed = test_bed_at_high_thrust();
+
+            test_bed.fail(FailureType::EngineBleedOverpressure(1));
+            test_bed.run_multiple_frames(Duration::from_secs(30));
+            test_bed.unfail(FailureType::EngineBleedOverpressure(1));
+            test_bed.run_multiple_frames(Duration::from_secs(30));
+
+            assert!(!test_bed.pr_valve_is_open(1));
+
+            test_bed = test_bed.set_engine_bleed_push_button_off(1).and_run();
+            test_bed = test_bed.set_engine_bleed_push_button_auto(1);
+            test_bed.run_multiple_frames(Duration::from_secs(10));
+
+            assert!(test_bed.pr_valve_is_open(1));
+        }
+
+        #[test]
+        fn failed_bmc_hands_engine_bleed_over_to_other_bmc() {
+            let mut test_bed = test_bed_with().idle_eng1().idle_eng2().and_stabilize();
+
+            test_bed.fail(FailureType::BleedMonitoringComputer(1));
+            test_bed.run_multiple_frames(Duration::from_secs(10));
+
+            assert_eq!(
+                test_bed.bmc_operation_mode_for_engine(2, 1),
+                BleedMonitoringComputerChannelOperationMode::Master
+            );
+            assert!(test_bed.pr_valve_is_open(1));
+            assert!(test_bed.pr_valve_is_open(2));
+        }
+    }
 }
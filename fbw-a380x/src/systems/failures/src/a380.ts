@@ -79,6 +79,18 @@ export const A380Failure = Object.freeze({
   Generator4: 24023,
   ApuGenerator1: 24030,
   ApuGenerator2: 24031,
+  Generator1VoltageRegulator: 24040,
+  Generator2VoltageRegulator: 24041,
+  Generator3VoltageRegulator: 24042,
+  Generator4VoltageRegulator: 24043,
+  ApuGenerator1VoltageRegulator: 24044,
+  ApuGenerator2VoltageRegulator: 24045,
+  Generator1FeederFault: 24050,
+  Generator2FeederFault: 24051,
+  Generator3FeederFault: 24052,
+  Generator4FeederFault: 24053,
+  ApuGenerator1FeederFault: 24054,
+  ApuGenerator2FeederFault: 24055,
   AlternatingCurrent1: 24100,
   AlternatingCurrent2: 24101,
   AlternatingCurrent3: 24102,
@@ -126,6 +138,48 @@ export const A380Failure = Object.freeze({
   Fcdc1: 27006,
   Fcdc2: 27007,
 
+  Feed1MainPump: 28000,
+  Feed1StbyPump: 28001,
+  Feed2MainPump: 28002,
+  Feed2StbyPump: 28003,
+  Feed3MainPump: 28004,
+  Feed3StbyPump: 28005,
+  Feed4MainPump: 28006,
+  Feed4StbyPump: 28007,
+  LeftOuterPump: 28008,
+  LeftMidFwdPump: 28009,
+  LeftMidAftPump: 28010,
+  LeftInnerFwdPump: 28011,
+  RightInnerFwdPump: 28012,
+  RightOuterPump: 28013,
+  RightMidFwdPump: 28014,
+  RightMidAftPump: 28015,
+  LeftInnerAftPump: 28016,
+  RightInnerAftPump: 28017,
+  TrimLeftPump: 28018,
+  TrimRightPump: 28019,
+  Engine1LpValve: 28030,
+  Engine2LpValve: 28031,
+  Engine3LpValve: 28032,
+  Engine4LpValve: 28033,
+  Crossfeed1Valve: 28034,
+  Crossfeed2Valve: 28035,
+  Crossfeed3Valve: 28036,
+  Crossfeed4Valve: 28037,
+  LeftOuterTankLeak: 28040,
+  FeedTank1Leak: 28041,
+  LeftMidTankLeak: 28042,
+  LeftInnerTankLeak: 28043,
+  FeedTank2Leak: 28044,
+  FeedTank3Leak: 28045,
+  RightInnerTankLeak: 28046,
+  RightMidTankLeak: 28047,
+  FeedTank4Leak: 28048,
+  RightOuterTankLeak: 28049,
+  TrimTankLeak: 28050,
+  Fqms1: 28060,
+  Fqms2: 28061,
+
   GreenReservoirLeak: 29000,
   YellowReservoirLeak: 29001,
   GreenReservoirAirLeak: 29002,
@@ -145,6 +199,31 @@ export const A380Failure = Object.freeze({
   EnginePump4AOHeat: 29016,
   EnginePump4BOHeat: 29017,
 
+  Adr1PitotHeater: 30011,
+  Adr1LeftStaticHeater: 30012,
+  Adr1RightStaticHeater: 30013,
+  Adr1TatHeater: 30014,
+  Adr1AoaHeater: 30015,
+  Adr2PitotHeater: 30021,
+  Adr2LeftStaticHeater: 30022,
+  Adr2RightStaticHeater: 30023,
+  Adr2TatHeater: 30024,
+  Adr2AoaHeater: 30025,
+  Adr3PitotHeater: 30031,
+  Adr3LeftStaticHeater: 30032,
+  Adr3RightStaticHeater: 30033,
+  Adr3AoaHeater: 30035,
+  CaptWindshieldHeater: 30041,
+  CaptWindowHeater: 30042,
+  CaptWindowHeatRegulation: 30043,
+  FoWindshieldHeater: 30051,
+  FoWindowHeater: 30052,
+  FoWindowHeatRegulation: 30053,
+  Eng1AntiIceValve: 30061,
+  Eng2AntiIceValve: 30062,
+  Eng3AntiIceValve: 30063,
+  Eng4AntiIceValve: 30064,
+
   Fws1: 31100,
   Fws2: 31101,
   Fws1AudioFunction: 31102,
@@ -170,6 +249,11 @@ export const A380Failure = Object.freeze({
   RadioAntennaDirectCoupling1: 34020,
   RadioAntennaDirectCoupling2: 34021,
   RadioAntennaDirectCoupling3: 34022,
+  InertialReference1: 34030,
+  InertialReference2: 34031,
+  InertialReference3: 34032,
+  GpsReceiver1: 34040,
+  GpsReceiver2: 34041,
 
   Transponder1: 34003,
   Transponder2: 34004,
@@ -179,6 +263,69 @@ export const A380Failure = Object.freeze({
   Gpws1: 34007,
   Gpws2: 34008,
 
+  CrewOxygenBottleLeak1: 35000,
+  CrewOxygenBottleLeak2: 35001,
+  CrewOxygenRegulatorLowPressure: 35010,
+
+  WingBleedLeak1: 36000,
+  WingBleedLeak2: 36001,
+  WingBleedLeak3: 36002,
+  WingBleedLeak4: 36003,
+  PylonBleedLeak1: 36010,
+  PylonBleedLeak2: 36011,
+  PylonBleedLeak3: 36012,
+  PylonBleedLeak4: 36013,
+  ApuBleedLeak: 36020,
+  WingLeakDetectionLoop1: 36100,
+  WingLeakDetectionLoop2: 36101,
+  WingLeakDetectionLoop3: 36102,
+  WingLeakDetectionLoop4: 36103,
+  PylonLeakDetectionLoop1: 36110,
+  PylonLeakDetectionLoop2: 36111,
+  PylonLeakDetectionLoop3: 36112,
+  PylonLeakDetectionLoop4: 36113,
+  ApuLeakDetectionLoop: 36120,
+  HpValveJammedOpen1: 36200,
+  HpValveJammedOpen2: 36201,
+  HpValveJammedOpen3: 36202,
+  HpValveJammedOpen4: 36203,
+  HpValveJammedClosed1: 36210,
+  HpValveJammedClosed2: 36211,
+  HpValveJammedClosed3: 36212,
+  HpValveJammedClosed4: 36213,
+  PrvValveJammedOpen1: 36220,
+  PrvValveJammedOpen2: 36221,
+  PrvValveJammedOpen3: 36222,
+  PrvValveJammedOpen4: 36223,
+  PrvValveJammedClosed1: 36230,
+  PrvValveJammedClosed2: 36231,
+  PrvValveJammedClosed3: 36232,
+  PrvValveJammedClosed4: 36233,
+  FavValveJammedOpen1: 36240,
+  FavValveJammedOpen2: 36241,
+  FavValveJammedOpen3: 36242,
+  FavValveJammedOpen4: 36243,
+  FavValveJammedClosed1: 36250,
+  FavValveJammedClosed2: 36251,
+  FavValveJammedClosed3: 36252,
+  FavValveJammedClosed4: 36253,
+  PrecoolerBlockage1: 36300,
+  PrecoolerBlockage2: 36301,
+  PrecoolerBlockage3: 36302,
+  PrecoolerBlockage4: 36303,
+  BleedOverpressure1: 36310,
+  BleedOverpressure2: 36311,
+  BleedOverpressure3: 36312,
+  BleedOverpressure4: 36313,
+  BleedOvertemperature1: 36320,
+  BleedOvertemperature2: 36321,
+  BleedOvertemperature3: 36322,
+  BleedOvertemperature4: 36323,
+  CpiomA1Bleed: 36400,
+  CpiomA2Bleed: 36401,
+  CpiomA3Bleed: 36402,
+  CpiomA4Bleed: 36403,
+
   NssAnsu1: 46001,
   NssAnsu2: 46002,
   FltOpsAnsu: 46003,
@@ -186,6 +333,14 @@ export const A380Failure = Object.freeze({
   FirstOfficerLaptop: 46005,
   CaptainOit: 46006,
   FirstOfficerOit: 46007,
+
+  ApuStartMotor: 49000,
+  ApuFuelControlUnit: 49010,
+  ApuAirIntakeFlapJammed: 49020,
+  ApuEgtSensor: 49030,
+  ApuSpeedSensor1: 49040,
+  ApuSpeedSensor2: 49041,
+  ApuInletPressureSensor: 49050,
 });
 
 export const A380FailureDefinitions: FailureDefinition[] = [
@@ -264,6 +419,18 @@ export const A380FailureDefinitions: FailureDefinition[] = [
   [24, A380Failure.Generator4, 'Generator 4'],
   [24, A380Failure.ApuGenerator1, 'APU Generator 1'],
   [24, A380Failure.ApuGenerator2, 'APU Generator 2'],
+  [24, A380Failure.Generator1VoltageRegulator, 'Generator 1 voltage regulator'],
+  [24, A380Failure.Generator2VoltageRegulator, 'Generator 2 voltage regulator'],
+  [24, A380Failure.Generator3VoltageRegulator, 'Generator 3 voltage regulator'],
+  [24, A380Failure.Generator4VoltageRegulator, 'Generator 4 voltage regulator'],
+  [24, A380Failure.ApuGenerator1VoltageRegulator, 'APU Generator 1 voltage regulator'],
+  [24, A380Failure.ApuGenerator2VoltageRegulator, 'APU Generator 2 voltage regulator'],
+  [24, A380Failure.Generator1FeederFault, 'Generator 1 feeder fault'],
+  [24, A380Failure.Generator2FeederFault, 'Generator 2 feeder fault'],
+  [24, A380Failure.Generator3FeederFault, 'Generator 3 feeder fault'],
+  [24, A380Failure.Generator4FeederFault, 'Generator 4 feeder fault'],
+  [24, A380Failure.ApuGenerator1FeederFault, 'APU Generator 1 feeder fault'],
+  [24, A380Failure.ApuGenerator2FeederFault, 'APU Generator 2 feeder fault'],
   [24, A380Failure.AlternatingCurrent1, 'AC 1'],
   [24, A380Failure.AlternatingCurrent2, 'AC 2'],
   [24, A380Failure.AlternatingCurrent3, 'AC 3'],
@@ -311,6 +478,48 @@ export const A380FailureDefinitions: FailureDefinition[] = [
   [27, A380Failure.Fcdc1, 'FCDC 1'],
   [27, A380Failure.Fcdc2, 'FCDC 2'],
 
+  [28, A380Failure.Feed1MainPump, 'Feed tank 1 main pump'],
+  [28, A380Failure.Feed1StbyPump, 'Feed tank 1 standby pump'],
+  [28, A380Failure.Feed2MainPump, 'Feed tank 2 main pump'],
+  [28, A380Failure.Feed2StbyPump, 'Feed tank 2 standby pump'],
+  [28, A380Failure.Feed3MainPump, 'Feed tank 3 main pump'],
+  [28, A380Failure.Feed3StbyPump, 'Feed tank 3 standby pump'],
+  [28, A380Failure.Feed4MainPump, 'Feed tank 4 main pump'],
+  [28, A380Failure.Feed4StbyPump, 'Feed tank 4 standby pump'],
+  [28, A380Failure.LeftOuterPump, 'Left outer tank pump'],
+  [28, A380Failure.LeftMidFwdPump, 'Left mid tank forward pump'],
+  [28, A380Failure.LeftMidAftPump, 'Left mid tank aft pump'],
+  [28, A380Failure.LeftInnerFwdPump, 'Left inner tank forward pump'],
+  [28, A380Failure.RightInnerFwdPump, 'Right inner tank forward pump'],
+  [28, A380Failure.RightOuterPump, 'Right outer tank pump'],
+  [28, A380Failure.RightMidFwdPump, 'Right mid tank forward pump'],
+  [28, A380Failure.RightMidAftPump, 'Right mid tank aft pump'],
+  [28, A380Failure.LeftInnerAftPump, 'Left inner tank aft pump'],
+  [28, A380Failure.RightInnerAftPump, 'Right inner tank aft pump'],
+  [28, A380Failure.TrimLeftPump, 'Trim tank left pump'],
+  [28, A380Failure.TrimRightPump, 'Trim tank right pump'],
+  [28, A380Failure.Engine1LpValve, 'Engine 1 LP valve jammed'],
+  [28, A380Failure.Engine2LpValve, 'Engine 2 LP valve jammed'],
+  [28, A380Failure.Engine3LpValve, 'Engine 3 LP valve jammed'],
+  [28, A380Failure.Engine4LpValve, 'Engine 4 LP valve jammed'],
+  [28, A380Failure.Crossfeed1Valve, 'Crossfeed valve 1 jammed'],
+  [28, A380Failure.Crossfeed2Valve, 'Crossfeed valve 2 jammed'],
+  [28, A380Failure.Crossfeed3Valve, 'Crossfeed valve 3 jammed'],
+  [28, A380Failure.Crossfeed4Valve, 'Crossfeed valve 4 jammed'],
+  [28, A380Failure.LeftOuterTankLeak, 'Left outer tank leak'],
+  [28, A380Failure.FeedTank1Leak, 'Feed tank 1 leak'],
+  [28, A380Failure.LeftMidTankLeak, 'Left mid tank leak'],
+  [28, A380Failure.LeftInnerTankLeak, 'Left inner tank leak'],
+  [28, A380Failure.FeedTank2Leak, 'Feed tank 2 leak'],
+  [28, A380Failure.FeedTank3Leak, 'Feed tank 3 leak'],
+  [28, A380Failure.RightInnerTankLeak, 'Right inner tank leak'],
+  [28, A380Failure.RightMidTankLeak, 'Right mid tank leak'],
+  [28, A380Failure.FeedTank4Leak, 'Feed tank 4 leak'],
+  [28, A380Failure.RightOuterTankLeak, 'Right outer tank leak'],
+  [28, A380Failure.TrimTankLeak, 'Trim tank leak'],
+  [28, A380Failure.Fqms1, 'FQMS 1'],
+  [28, A380Failure.Fqms2, 'FQMS 2'],
+
   [29, A380Failure.GreenReservoirLeak, 'Green reservoir leak'],
   [29, A380Failure.YellowReservoirLeak, 'Yellow reservoir leak'],
   [29, A380Failure.GreenReservoirAirLeak, 'Green reservoir air leak'],
@@ -330,6 +539,31 @@ export const A380FailureDefinitions: FailureDefinition[] = [
   [29, A380Failure.EnginePump4AOHeat, 'Engine 4 pump A overheat'],
   [29, A380Failure.EnginePump4BOHeat, 'Engine 4 pump B overheat'],
 
+  [30, A380Failure.Adr1PitotHeater, 'ADR 1 pitot heater'],
+  [30, A380Failure.Adr1LeftStaticHeater, 'ADR 1 left static heater'],
+  [30, A380Failure.Adr1RightStaticHeater, 'ADR 1 right static heater'],
+  [30, A380Failure.Adr1TatHeater, 'ADR 1 TAT heater'],
+  [30, A380Failure.Adr1AoaHeater, 'ADR 1 AOA heater'],
+  [30, A380Failure.Adr2PitotHeater, 'ADR 2 pitot heater'],
+  [30, A380Failure.Adr2LeftStaticHeater, 'ADR 2 left static heater'],
+  [30, A380Failure.Adr2RightStaticHeater, 'ADR 2 right static heater'],
+  [30, A380Failure.Adr2TatHeater, 'ADR 2 TAT heater'],
+  [30, A380Failure.Adr2AoaHeater, 'ADR 2 AOA heater'],
+  [30, A380Failure.Adr3PitotHeater, 'ADR 3 pitot heater'],
+  [30, A380Failure.Adr3LeftStaticHeater, 'ADR 3 left static heater'],
+  [30, A380Failure.Adr3RightStaticHeater, 'ADR 3 right static heater'],
+  [30, A380Failure.Adr3AoaHeater, 'ADR 3 AOA heater'],
+  [30, A380Failure.CaptWindshieldHeater, 'Capt windshield heater'],
+  [30, A380Failure.CaptWindowHeater, 'Capt side window heater'],
+  [30, A380Failure.CaptWindowHeatRegulation, 'Capt window heat regulation'],
+  [30, A380Failure.FoWindshieldHeater, 'F/O windshield heater'],
+  [30, A380Failure.FoWindowHeater, 'F/O side window heater'],
+  [30, A380Failure.FoWindowHeatRegulation, 'F/O window heat regulation'],
+  [30, A380Failure.Eng1AntiIceValve, 'Engine 1 anti-ice valve'],
+  [30, A380Failure.Eng2AntiIceValve, 'Engine 2 anti-ice valve'],
+  [30, A380Failure.Eng3AntiIceValve, 'Engine 3 anti-ice valve'],
+  [30, A380Failure.Eng4AntiIceValve, 'Engine 4 anti-ice valve'],
+
   [31, A380Failure.Fws1, 'FWS 1'],
   [31, A380Failure.Fws2, 'FWS 2'],
   [31, A380Failure.Fws1AudioFunction, 'FWS 1 Audio Function'],
@@ -352,6 +586,11 @@ export const A380FailureDefinitions: FailureDefinition[] = [
   [34, A380Failure.RadioAntennaDirectCoupling1, 'RA SYS A Direct Coupling'],
   [34, A380Failure.RadioAntennaDirectCoupling2, 'RA SYS B Direct Coupling'],
   [34, A380Failure.RadioAntennaDirectCoupling3, 'RA SYS C Direct Coupling'],
+  [34, A380Failure.InertialReference1, 'IR 1'],
+  [34, A380Failure.InertialReference2, 'IR 2'],
+  [34, A380Failure.InertialReference3, 'IR 3'],
+  [34, A380Failure.GpsReceiver1, 'GPS 1'],
+  [34, A380Failure.GpsReceiver2, 'GPS 2'],
   [34, A380Failure.Terr1, 'TERR 1'],
   [34, A380Failure.Terr2, 'TERR 2'],
   [34, A380Failure.Gpws1, 'GPWS 1'],
@@ -359,6 +598,69 @@ export const A380FailureDefinitions: FailureDefinition[] = [
   [34, A380Failure.Transponder1, 'XPDR 1'],
   [34, A380Failure.Transponder2, 'XPDR 2'],
 
+  [35, A380Failure.CrewOxygenBottleLeak1, 'Crew oxygen bottle 1 leak'],
+  [35, A380Failure.CrewOxygenBottleLeak2, 'Crew oxygen bottle 2 leak'],
+  [35, A380Failure.CrewOxygenRegulatorLowPressure, 'Crew oxygen regulator low pressure'],
+
+  [36, A380Failure.WingBleedLeak1, 'Wing 1 bleed duct leak'],
+  [36, A380Failure.WingBleedLeak2, 'Wing 2 bleed duct leak'],
+  [36, A380Failure.WingBleedLeak3, 'Wing 3 bleed duct leak'],
+  [36, A380Failure.WingBleedLeak4, 'Wing 4 bleed duct leak'],
+  [36, A380Failure.PylonBleedLeak1, 'Pylon 1 bleed duct leak'],
+  [36, A380Failure.PylonBleedLeak2, 'Pylon 2 bleed duct leak'],
+  [36, A380Failure.PylonBleedLeak3, 'Pylon 3 bleed duct leak'],
+  [36, A380Failure.PylonBleedLeak4, 'Pylon 4 bleed duct leak'],
+  [36, A380Failure.ApuBleedLeak, 'APU bleed duct leak'],
+  [36, A380Failure.WingLeakDetectionLoop1, 'Wing 1 leak detection loop'],
+  [36, A380Failure.WingLeakDetectionLoop2, 'Wing 2 leak detection loop'],
+  [36, A380Failure.WingLeakDetectionLoop3, 'Wing 3 leak detection loop'],
+  [36, A380Failure.WingLeakDetectionLoop4, 'Wing 4 leak detection loop'],
+  [36, A380Failure.PylonLeakDetectionLoop1, 'Pylon 1 leak detection loop'],
+  [36, A380Failure.PylonLeakDetectionLoop2, 'Pylon 2 leak detection loop'],
+  [36, A380Failure.PylonLeakDetectionLoop3, 'Pylon 3 leak detection loop'],
+  [36, A380Failure.PylonLeakDetectionLoop4, 'Pylon 4 leak detection loop'],
+  [36, A380Failure.ApuLeakDetectionLoop, 'APU leak detection loop'],
+  [36, A380Failure.HpValveJammedOpen1, 'Engine 1 HP valve jammed open'],
+  [36, A380Failure.HpValveJammedOpen2, 'Engine 2 HP valve jammed open'],
+  [36, A380Failure.HpValveJammedOpen3, 'Engine 3 HP valve jammed open'],
+  [36, A380Failure.HpValveJammedOpen4, 'Engine 4 HP valve jammed open'],
+  [36, A380Failure.HpValveJammedClosed1, 'Engine 1 HP valve jammed closed'],
+  [36, A380Failure.HpValveJammedClosed2, 'Engine 2 HP valve jammed closed'],
+  [36, A380Failure.HpValveJammedClosed3, 'Engine 3 HP valve jammed closed'],
+  [36, A380Failure.HpValveJammedClosed4, 'Engine 4 HP valve jammed closed'],
+  [36, A380Failure.PrvValveJammedOpen1, 'Engine 1 PR valve jammed open'],
+  [36, A380Failure.PrvValveJammedOpen2, 'Engine 2 PR valve jammed open'],
+  [36, A380Failure.PrvValveJammedOpen3, 'Engine 3 PR valve jammed open'],
+  [36, A380Failure.PrvValveJammedOpen4, 'Engine 4 PR valve jammed open'],
+  [36, A380Failure.PrvValveJammedClosed1, 'Engine 1 PR valve jammed closed'],
+  [36, A380Failure.PrvValveJammedClosed2, 'Engine 2 PR valve jammed closed'],
+  [36, A380Failure.PrvValveJammedClosed3, 'Engine 3 PR valve jammed closed'],
+  [36, A380Failure.PrvValveJammedClosed4, 'Engine 4 PR valve jammed closed'],
+  [36, A380Failure.FavValveJammedOpen1, 'Engine 1 fan air valve jammed open'],
+  [36, A380Failure.FavValveJammedOpen2, 'Engine 2 fan air valve jammed open'],
+  [36, A380Failure.FavValveJammedOpen3, 'Engine 3 fan air valve jammed open'],
+  [36, A380Failure.FavValveJammedOpen4, 'Engine 4 fan air valve jammed open'],
+  [36, A380Failure.FavValveJammedClosed1, 'Engine 1 fan air valve jammed closed'],
+  [36, A380Failure.FavValveJammedClosed2, 'Engine 2 fan air valve jammed closed'],
+  [36, A380Failure.FavValveJammedClosed3, 'Engine 3 fan air valve jammed closed'],
+  [36, A380Failure.FavValveJammedClosed4, 'Engine 4 fan air valve jammed closed'],
+  [36, A380Failure.PrecoolerBlockage1, 'Engine 1 precooler blockage'],
+  [36, A380Failure.PrecoolerBlockage2, 'Engine 2 precooler blockage'],
+  [36, A380Failure.PrecoolerBlockage3, 'Engine 3 precooler blockage'],
+  [36, A380Failure.PrecoolerBlockage4, 'Engine 4 precooler blockage'],
+  [36, A380Failure.BleedOverpressure1, 'Engine 1 bleed overpressure'],
+  [36, A380Failure.BleedOverpressure2, 'Engine 2 bleed overpressure'],
+  [36, A380Failure.BleedOverpressure3, 'Engine 3 bleed overpressure'],
+  [36, A380Failure.BleedOverpressure4, 'Engine 4 bleed overpressure'],
+  [36, A380Failure.BleedOvertemperature1, 'Engine 1 bleed overtemperature'],
+  [36, A380Failure.BleedOvertemperature2, 'Engine 2 bleed overtemperature'],
+  [36, A380Failure.BleedOvertemperature3, 'Engine 3 bleed overtemperature'],
+  [36, A380Failure.BleedOvertemperature4, 'Engine 4 bleed overtemperature'],
+  [36, A380Failure.CpiomA1Bleed, 'CPIOM A1 bleed monitoring'],
+  [36, A380Failure.CpiomA2Bleed, 'CPIOM A2 bleed monitoring'],
+  [36, A380Failure.CpiomA3Bleed, 'CPIOM A3 bleed monitoring'],
+  [36, A380Failure.CpiomA4Bleed, 'CPIOM A4 bleed monitoring'],
+
   [46, A380Failure.NssAnsu1, 'NSS AVNCS ANSU 1'],
   [46, A380Failure.NssAnsu2, 'NSS AVNCS ANSU 2'],
   [46, A380Failure.FltOpsAnsu, 'FLT OPS ANSU'],
@@ -366,4 +668,12 @@ export const A380FailureDefinitions: FailureDefinition[] = [
   [46, A380Failure.FirstOfficerLaptop, 'F/O Laptop'],
   [46, A380Failure.CaptainOit, 'Captain OIT'],
   [46, A380Failure.FirstOfficerOit, 'F/O OIT'],
+
+  [49, A380Failure.ApuStartMotor, 'APU start motor'],
+  [49, A380Failure.ApuFuelControlUnit, 'APU fuel control unit'],
+  [49, A380Failure.ApuAirIntakeFlapJammed, 'APU air intake flap jammed'],
+  [49, A380Failure.ApuEgtSensor, 'APU EGT sensor'],
+  [49, A380Failure.ApuSpeedSensor1, 'APU speed sensor 1'],
+  [49, A380Failure.ApuSpeedSensor2, 'APU speed sensor 2'],
+  [49, A380Failure.ApuInletPressureSensor, 'APU inlet pressure sensor'],
 ];
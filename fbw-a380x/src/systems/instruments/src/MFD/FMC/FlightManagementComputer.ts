@@ -1028,6 +1028,7 @@ export class FlightManagementComputer implements FmcInterface {
 
       this.acInterface.checkSpeedLimit();
       this.acInterface.thrustReductionAccelerationChecks();
+      this.acInterface.updateIrsPresentPosition();
       // TODO port over from legacy code
       // this.updatePerfPageAltPredictions();
     }
@@ -90,6 +90,10 @@ export class FmcAircraftInterface {
 
   public arincRemainingFlightTime = FmArinc429OutputWord.emptyFm('REMAINING_FLIGHT_TIME');
 
+  public arincIrsPresentLatitude = FmArinc429OutputWord.emptyFm('IRS_PRESENT_LATITUDE');
+
+  public arincIrsPresentLongitude = FmArinc429OutputWord.emptyFm('IRS_PRESENT_LONGITUDE');
+
   /** contains fm messages (not yet implemented) and nodh bit */
   public arincEisWord2 = FmArinc429OutputWord.emptyFm('EIS_DISCRETE_WORD_2');
 
@@ -114,6 +118,8 @@ export class FmcAircraftInterface {
     this.arincZeroFuelWeight,
     this.arincZeroFuelWeightCg,
     this.arincRemainingFlightTime,
+    this.arincIrsPresentLatitude,
+    this.arincIrsPresentLongitude,
     this.arincEisWord2,
   ];
 
@@ -638,6 +644,15 @@ export class FmcAircraftInterface {
     this.arincEisWord2.ssm = Arinc429SignStatusMatrix.NormalOperation;
   }
 
+  /** Sends the GPS position to the IRs, which need a present position to complete their alignment. */
+  updateIrsPresentPosition() {
+    const latitude = SimVar.GetSimVarValue('GPS POSITION LAT', 'degree latitude');
+    const longitude = SimVar.GetSimVarValue('GPS POSITION LON', 'degree longitude');
+
+    this.arincIrsPresentLatitude.setBnrValue(latitude, Arinc429SignStatusMatrix.NormalOperation, 18, 180, -180);
+    this.arincIrsPresentLongitude.setBnrValue(longitude, Arinc429SignStatusMatrix.NormalOperation, 18, 180, -180);
+  }
+
   shouldTransmitMinimums(distanceToDestination: number) {
     const phase = this.flightPhase.get();
     const isCloseToDestination = Number.isFinite(distanceToDestination) ? distanceToDestination < 250 : true;
@@ -2649,13 +2649,14 @@ export class FwsCore {
 
     this.flapsIndex.set(SimVar.GetSimVarValue('L:A32NX_FLAPS_CONF_INDEX', 'number'));
 
-    this.adr1Cas.setWord(SimVar.GetSimVarValue('L:A32NX_ADIRS_ADR_1_COMPUTED_AIRSPEED', 'number'));
-    this.adr2Cas.setWord(SimVar.GetSimVarValue('L:A32NX_ADIRS_ADR_2_COMPUTED_AIRSPEED', 'number'));
-    this.adr3Cas.setWord(SimVar.GetSimVarValue('L:A32NX_ADIRS_ADR_3_COMPUTED_AIRSPEED', 'number'));
+    // The air data is received from the ADIRUs over the ADCN
+    this.adr1Cas.setWord(SimVar.GetSimVarValue('L:A32NX_ADCN_ADR_1_COMPUTED_AIRSPEED', 'number'));
+    this.adr2Cas.setWord(SimVar.GetSimVarValue('L:A32NX_ADCN_ADR_2_COMPUTED_AIRSPEED', 'number'));
+    this.adr3Cas.setWord(SimVar.GetSimVarValue('L:A32NX_ADCN_ADR_3_COMPUTED_AIRSPEED', 'number'));
 
-    this.adr1Mach.setWord(SimVar.GetSimVarValue('L:A32NX_ADIRS_ADR_1_MACH', 'number'));
-    this.adr2Mach.setWord(SimVar.GetSimVarValue('L:A32NX_ADIRS_ADR_2_MACH', 'number'));
-    this.adr3Mach.setWord(SimVar.GetSimVarValue('L:A32NX_ADIRS_ADR_3_MACH', 'number'));
+    this.adr1Mach.setWord(SimVar.GetSimVarValue('L:A32NX_ADCN_ADR_1_MACH', 'number'));
+    this.adr2Mach.setWord(SimVar.GetSimVarValue('L:A32NX_ADCN_ADR_2_MACH', 'number'));
+    this.adr3Mach.setWord(SimVar.GetSimVarValue('L:A32NX_ADCN_ADR_3_MACH', 'number'));
 
     this.ir1Pitch.setFromSimVar('L:A32NX_ADIRS_IR_1_PITCH');
     this.ir2Pitch.setFromSimVar('L:A32NX_ADIRS_IR_2_PITCH');
@@ -3057,9 +3058,9 @@ export class FwsCore {
     this.ir2Fault.set(!flightPhase112 && (this.ir2Pitch.isFailureWarning() || this.ir2MaintWord.bitValueOr(9, true)));
     this.ir3Fault.set(!flightPhase112 && (this.ir3Pitch.isFailureWarning() || this.ir3MaintWord.bitValueOr(9, true)));
 
-    const adr1PressureAltitude = Arinc429Word.fromSimVarValue('L:A32NX_ADIRS_ADR_1_ALTITUDE');
-    const adr2PressureAltitude = Arinc429Word.fromSimVarValue('L:A32NX_ADIRS_ADR_2_ALTITUDE');
-    const adr3PressureAltitude = Arinc429Word.fromSimVarValue('L:A32NX_ADIRS_ADR_3_ALTITUDE');
+    const adr1PressureAltitude = Arinc429Word.fromSimVarValue('L:A32NX_ADCN_ADR_1_ALTITUDE');
+    const adr2PressureAltitude = Arinc429Word.fromSimVarValue('L:A32NX_ADCN_ADR_2_ALTITUDE');
+    const adr3PressureAltitude = Arinc429Word.fromSimVarValue('L:A32NX_ADCN_ADR_3_ALTITUDE');
 
     this.irExcessMotion.set(
       this.ir1MaintWord.bitValueOr(13, false) ||
@@ -117,7 +117,7 @@ export class LegacySoundManager {
 }
 
 // many lengths are approximate until we can get them accuratly (when boris re-makes them and we have the sources)
-interface LegacySound {
+export interface LegacySound {
   name: string;
   length: number;
 }
@@ -77,6 +77,14 @@ void EngineControl_A380X::update() {
     const double deltaN3       = simN3 - prevSimEngineN3[engineIdx];
     prevSimEngineN3[engineIdx] = simN3;
 
+    // the engine flames out when its feed line is no longer supplied with fuel
+    const bool engineIsFed = simData.engineIsFed[engineIdx]->getAsBool();
+    if (engineStarter && prevEngineIsFed[engineIdx] && !engineIsFed) {
+      simData.setStarterHeldEvent[engineIdx]->trigger(0);
+      simData.setStarterEvent[engineIdx]->trigger(0);
+    }
+    prevEngineIsFed[engineIdx] = engineIsFed;
+
     // Update various engine values based on the current engine state
     switch (static_cast<int>(engineState)) {
       case STARTING:
@@ -786,91 +794,40 @@ void EngineControl_A380X::updateFuel(double deltaTimeSeconds) {
     simData.fuelRightOuterPre->set(rightOuterQty);
     simData.fuelTrimPre->set(trimQty);
   } else {
-    if (uiFuelTamper) {
-      fuelLeftOuterPre  = leftOuterQty;   // in Pounds
-      fuelFeedOnePre    = feedOneQty;     // in Pounds
-      fuelLeftMidPre    = leftMidQty;     // in Pounds
-      fuelLeftInnerPre  = leftInnerQty;   // in Pounds
-      fuelFeedTwoPre    = feedTwoQty;     // in Pounds
-      fuelFeedThreePre  = feedThreeQty;   // in Pounds
-      fuelRightInnerPre = rightInnerQty;  // in Pounds
-      fuelRightMidPre   = rightMidQty;    // in Pounds
-      fuelFeedFourPre   = feedFourQty;    // in Pounds
-      fuelRightOuterPre = rightOuterQty;  // in Pounds
-      fuelTrimPre       = trimQty;        // in Pounds
-    }
-
+    // The engine fuel burn is simulated by the systems, which own the tank quantities.
+    // Only the fuel used by each engine is accumulated here.
     double fuelFlowRateChange   = 0;  // was m in the original code
     double previousFuelFlowRate = 0;  // was b in the original code
-    double fuelBurn1            = 0;  // in kg
-    double fuelBurn2            = 0;  // in kg
-    double fuelBurn3            = 0;  // in kg
-    double fuelBurn4            = 0;  // in kg
-
-    double fuelUsedEngine1 = simData.engineFuelUsed[E1]->get();
-    double fuelUsedEngine2 = simData.engineFuelUsed[E2]->get();
-    double fuelUsedEngine3 = simData.engineFuelUsed[E3]->get();
-    double fuelUsedEngine4 = simData.engineFuelUsed[E4]->get();
 
     // Initialize arrays to avoid code duplication when looping over engines
-    const double* engineFF[4]       = {&engine1FF, &engine2FF, &engine3FF, &engine4FF};
-    const double* enginePreFF[4]    = {&engine1PreFF, &engine2PreFF, &engine3PreFF, &engine4PreFF};
-    double*       fuelFeedPre[4]    = {&fuelFeedOnePre, &fuelFeedTwoPre, &fuelFeedThreePre, &fuelFeedFourPre};
-    double*       fuelBurn[4]       = {&fuelBurn1, &fuelBurn2, &fuelBurn3, &fuelBurn4};
-    double*       fuelUsedEngine[4] = {&fuelUsedEngine1, &fuelUsedEngine2, &fuelUsedEngine3, &fuelUsedEngine4};
+    const double engineFF[4]    = {engine1FF, engine2FF, engine3FF, engine4FF};
+    const double enginePreFF[4] = {engine1PreFF, engine2PreFF, engine3PreFF, engine4PreFF};
 
     // Loop over engines
     for (int i = 0; i < 4; i++) {
-      // Engines fuel burn routine
-      if (*fuelFeedPre[i] > 0) {
-        // Cycle Fuel Burn
-        if (aircraftDevelopmentStateVar != 2) {
-          fuelFlowRateChange   = (*engineFF[i] - *enginePreFF[i]) / deltaTimeHours;
-          previousFuelFlowRate = *enginePreFF[i];
-          *fuelBurn[i]         = (fuelFlowRateChange * std::pow(deltaTimeHours, 2) / 2) + (previousFuelFlowRate * deltaTimeHours);  // KG
-        }
-        // Fuel Used Accumulators
-        *fuelUsedEngine[i] += *fuelBurn[i];
-      } else {
-        fuelBurn[i]    = 0;
-        fuelFeedPre[i] = 0;
+      double fuelBurn = 0;  // in kg
+      if (simData.engineIsFed[i]->getAsBool() && aircraftDevelopmentStateVar != 2) {
+        fuelFlowRateChange   = (engineFF[i] - enginePreFF[i]) / deltaTimeHours;
+        previousFuelFlowRate = enginePreFF[i];
+        fuelBurn             = (fuelFlowRateChange * std::pow(deltaTimeHours, 2) / 2) + (previousFuelFlowRate * deltaTimeHours);  // KG
       }
+      // Fuel Used Accumulators
+      simData.engineFuelUsed[i]->set(simData.engineFuelUsed[i]->get() + fuelBurn);
+      // Setting new pre-cycle conditions
+      simData.enginePreFF[i]->set(engineFF[i]);
     }
 
-    const double fuelFeedOne   = std::max(feedOneQty - (fuelBurn1 * Fadec::KGS_TO_LBS), 0.0);    // Pounds
-    const double fuelFeedTwo   = std::max(feedTwoQty - (fuelBurn2 * Fadec::KGS_TO_LBS), 0.0);    // Pounds
-    const double fuelFeedThree = std::max(feedThreeQty - (fuelBurn3 * Fadec::KGS_TO_LBS), 0.0);  // Pounds
-    const double fuelFeedFour  = std::max(feedFourQty - (fuelBurn4 * Fadec::KGS_TO_LBS), 0.0);   // Pounds
-
-    // Setting new pre-cycle conditions
-    simData.enginePreFF[E1]->set(engine1FF);
-    simData.enginePreFF[E2]->set(engine2FF);
-    simData.enginePreFF[E3]->set(engine3FF);
-    simData.enginePreFF[E4]->set(engine4FF);
-
-    simData.engineFuelUsed[E1]->set(fuelUsedEngine1);
-    simData.engineFuelUsed[E2]->set(fuelUsedEngine2);
-    simData.engineFuelUsed[E3]->set(fuelUsedEngine3);
-    simData.engineFuelUsed[E4]->set(fuelUsedEngine4);
-
-    simData.fuelFeedOnePre->set(fuelFeedOne);
-    simData.fuelFeedTwoPre->set(fuelFeedTwo);
-    simData.fuelFeedThreePre->set(fuelFeedThree);
-    simData.fuelFeedFourPre->set(fuelFeedFour);
-
     simData.fuelLeftOuterPre->set(leftOuterQty);
+    simData.fuelFeedOnePre->set(feedOneQty);
     simData.fuelLeftMidPre->set(leftMidQty);
     simData.fuelLeftInnerPre->set(leftInnerQty);
+    simData.fuelFeedTwoPre->set(feedTwoQty);
+    simData.fuelFeedThreePre->set(feedThreeQty);
     simData.fuelRightInnerPre->set(rightInnerQty);
     simData.fuelRightMidPre->set(rightMidQty);
+    simData.fuelFeedFourPre->set(feedFourQty);
     simData.fuelRightOuterPre->set(rightOuterQty);
     simData.fuelTrimPre->set(trimQty);
-
-    simData.fuelFeedTankDataPtr->data().fuelSystemFeedOne   = (fuelFeedOne / weightLbsPerGallon);
-    simData.fuelFeedTankDataPtr->data().fuelSystemFeedTwo   = (fuelFeedTwo / weightLbsPerGallon);
-    simData.fuelFeedTankDataPtr->data().fuelSystemFeedThree = (fuelFeedThree / weightLbsPerGallon);
-    simData.fuelFeedTankDataPtr->data().fuelSystemFeedFour  = (fuelFeedFour / weightLbsPerGallon);
-    simData.fuelFeedTankDataPtr->writeDataToSim();
   }
 
   // Will save the current fuel quantities if on the ground AND engines being shutdown
@@ -58,6 +58,7 @@ class EngineControl_A380X {
 
   // TODO - might not be required - feeds into stateMachine but really relevant
   double prevSimEngineN3[4] = {0.0, 0.0, 0.0, 0.0};
+  bool   prevEngineIsFed[4] = {false, false, false, false};
 
   // Engine oil state
   double thermalEnergy[4] = {0.0, 0.0, 0.0, 0.0};
@@ -210,6 +210,7 @@ class FadecSimData_A380X {
   NamedVariablePtr engineIdleFF;
   NamedVariablePtr engineIdleN1;  // Percent
   NamedVariablePtr engineIdleN3;  // Percent
+  NamedVariablePtr engineIsFed[4];
   NamedVariablePtr engineN1[4];   // Percent
   NamedVariablePtr engineN2[4];   // Percent
   NamedVariablePtr engineN3[4];   // Percent
@@ -381,6 +382,11 @@ class FadecSimData_A380X {
     engineFuelUsed[E3] = dm->make_named_var("A32NX_FUEL_USED:3", UNITS.Number, AUTO_READ_WRITE);
     engineFuelUsed[E4] = dm->make_named_var("A32NX_FUEL_USED:4", UNITS.Number, AUTO_READ_WRITE);
 
+    engineIsFed[E1] = dm->make_named_var("A32NX_FUEL_ENGINE_1_IS_FED", UNITS.Bool, AUTO_READ);
+    engineIsFed[E2] = dm->make_named_var("A32NX_FUEL_ENGINE_2_IS_FED", UNITS.Bool, AUTO_READ);
+    engineIsFed[E3] = dm->make_named_var("A32NX_FUEL_ENGINE_3_IS_FED", UNITS.Bool, AUTO_READ);
+    engineIsFed[E4] = dm->make_named_var("A32NX_FUEL_ENGINE_4_IS_FED", UNITS.Bool, AUTO_READ);
+
     engineOil[E1] = dm->make_named_var("A32NX_ENGINE_OIL_QTY:1", UNITS.Number, AUTO_READ_WRITE);
     engineOil[E2] = dm->make_named_var("A32NX_ENGINE_OIL_QTY:2", UNITS.Number, AUTO_READ_WRITE);
     engineOil[E3] = dm->make_named_var("A32NX_ENGINE_OIL_QTY:3", UNITS.Number, AUTO_READ_WRITE);
@@ -231,6 +231,20 @@ impl PackFlowControllers for A380AirConditioning {
     }
 }
 
+impl CabinSimulation for A380AirConditioning {
+    fn cabin_temperature(&self) -> Vec<ThermodynamicTemperature> {
+        self.a380_cabin.cabin_temperature()
+    }
+
+    fn exterior_pressure(&self) -> Pressure {
+        self.a380_cabin.exterior_pressure()
+    }
+
+    fn cabin_pressure(&self) -> Pressure {
+        self.a380_cabin.cabin_pressure()
+    }
+}
+
 impl SimulationElement for A380AirConditioning {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.a380_cabin.accept(visitor);
@@ -1,14 +1,17 @@
 use crate::systems::{
+    accept_iterable,
+    fuel::FUEL_GALLONS_TO_KG,
     integrated_modular_avionics::{
         avionics_full_duplex_switch::AvionicsFullDuplexSwitch,
         core_processing_input_output_module::CoreProcessingInputOutputModule,
         input_output_module::InputOutputModule, AvionicsDataCommunicationNetwork,
+        AvionicsDataCommunicationNetworkEndpoint,
         AvionicsDataCommunicationNetworkMessageIdentifier,
     },
-    shared::ElectricalBusType,
+    shared::{arinc429::Arinc429Word, arinc429::SignStatus, ElectricalBusType},
     simulation::{
-        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter,
-        VariableIdentifier, Write,
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
     },
 };
 use fxhash::FxHashMap;
@@ -22,6 +25,7 @@ use systems::integrated_modular_avionics::{
     AvionicsDataCommunicationNetworkMessage,
     AvionicsDataCommunicationNetworkMessageFunctionalDataSetStatus,
 };
+use uom::si::{f64::*, mass::kilogram, pressure::psi};
 
 struct RoutingTableEntry {
     routing_id_1: VariableIdentifier,
@@ -65,6 +69,9 @@ impl RoutingTableEntry {
 //
 // To ensure that the data can be consumed or transmitted is the availability of the own and the other system required.
 //
+// CPIOMs and IOMs send their messages on both networks. When receiving, they select the freshest copy
+// of the two networks and ignore copies which have not been sent again for some time.
+//
 // The routing tables define the upper triangular matrix for the two networks.
 // A breadth-first-search is used to update the routing table per AFDX switch.
 pub struct A380AvionicsDataCommunicationNetwork {
@@ -443,13 +450,13 @@ impl A380AvionicsDataCommunicationNetwork {
         }
     }
 
-    pub fn update(&mut self) {
+    pub fn update(&mut self, context: &UpdateContext) {
         let mut update_network_a = false;
         let mut update_network_b = false;
 
         for (i, afdx) in self.afdx_switches.iter().enumerate() {
             let mut afdx = RefCell::borrow_mut(afdx);
-            afdx.update();
+            afdx.update(context);
             if afdx.routing_update_required() {
                 if i >= 8 {
                     update_network_b = true;
@@ -546,34 +553,652 @@ impl SimulationElement for A380AvionicsDataCommunicationNetwork {
     }
 }
 
-// This struct is intended to translate simvar values to ADCN messages
-// and ADCN messages to simvars.
-pub struct A380AvionicsDataCommunicationNetworkSimvarTranslator {}
+/// A module attached to the ADCN which owns or consumes functional data sets.
+#[derive(Clone, Copy)]
+enum AdcnModule {
+    Cpiom(&'static str),
+    Iom(&'static str),
+}
+impl AdcnModule {
+    fn is_available<'a>(
+        &self,
+        adcn: &impl AvionicsDataCommunicationNetwork<
+            'a,
+            A380AvionicsDataCommunicationNetworkMessageData,
+        >,
+    ) -> bool {
+        match self {
+            AdcnModule::Cpiom(name) => adcn.get_cpiom(name).is_available(),
+            AdcnModule::Iom(name) => adcn.get_iom(name).is_available(),
+        }
+    }
+
+    fn send<'a>(
+        &self,
+        adcn: &impl AvionicsDataCommunicationNetwork<
+            'a,
+            A380AvionicsDataCommunicationNetworkMessageData,
+        >,
+        id: &AvionicsDataCommunicationNetworkMessageIdentifier,
+        message: AvionicsDataCommunicationNetworkMessage<
+            A380AvionicsDataCommunicationNetworkMessageData,
+        >,
+    ) {
+        match self {
+            AdcnModule::Cpiom(name) => adcn.get_cpiom(name).send_value(id, message),
+            AdcnModule::Iom(name) => adcn.get_iom(name).send_value(id, message),
+        }
+    }
+
+    fn recv<'a>(
+        &self,
+        adcn: &impl AvionicsDataCommunicationNetwork<
+            'a,
+            A380AvionicsDataCommunicationNetworkMessageData,
+        >,
+        id: &AvionicsDataCommunicationNetworkMessageIdentifier,
+    ) -> Option<
+        AvionicsDataCommunicationNetworkMessage<A380AvionicsDataCommunicationNetworkMessageData>,
+    > {
+        match self {
+            AdcnModule::Cpiom(name) => adcn.get_cpiom(name).recv_value(id),
+            AdcnModule::Iom(name) => adcn.get_iom(name).recv_value(id),
+        }
+    }
+}
+
+/// A functional data set which is sent by the first available of its owning modules
+/// and received by the first available of the consuming modules.
+struct FunctionalDataSetRoute {
+    message_id: AvionicsDataCommunicationNetworkMessageIdentifier,
+    owners: &'static [AdcnModule],
+}
+impl FunctionalDataSetRoute {
+    // The data sets are published for the flight warning system, which is hosted by the CPIOM C.
+    const CONSUMERS: [AdcnModule; 2] = [AdcnModule::Cpiom("C1"), AdcnModule::Cpiom("C2")];
+
+    fn new<'a>(
+        adcn: &mut impl AvionicsDataCommunicationNetwork<
+            'a,
+            A380AvionicsDataCommunicationNetworkMessageData,
+        >,
+        name: String,
+        owners: &'static [AdcnModule],
+    ) -> Self {
+        Self {
+            message_id: adcn.get_message_identifier(name),
+            owners,
+        }
+    }
+
+    fn send<'a>(
+        &self,
+        adcn: &impl AvionicsDataCommunicationNetwork<
+            'a,
+            A380AvionicsDataCommunicationNetworkMessageData,
+        >,
+        message: AvionicsDataCommunicationNetworkMessage<
+            A380AvionicsDataCommunicationNetworkMessageData,
+        >,
+    ) {
+        if let Some(owner) = self.owners.iter().find(|owner| owner.is_available(adcn)) {
+            owner.send(adcn, &self.message_id, message);
+        }
+    }
+
+    fn recv<'a>(
+        &self,
+        adcn: &impl AvionicsDataCommunicationNetwork<
+            'a,
+            A380AvionicsDataCommunicationNetworkMessageData,
+        >,
+    ) -> Option<
+        AvionicsDataCommunicationNetworkMessage<A380AvionicsDataCommunicationNetworkMessageData>,
+    > {
+        Self::CONSUMERS
+            .iter()
+            .find(|consumer| consumer.is_available(adcn))
+            .and_then(|consumer| consumer.recv(adcn, &self.message_id))
+    }
+}
+
+fn sign_status(
+    status: AvionicsDataCommunicationNetworkMessageFunctionalDataSetStatus,
+) -> SignStatus {
+    match status {
+        AvionicsDataCommunicationNetworkMessageFunctionalDataSetStatus::NoData => {
+            SignStatus::FailureWarning
+        }
+        AvionicsDataCommunicationNetworkMessageFunctionalDataSetStatus::NoComputedData => {
+            SignStatus::NoComputedData
+        }
+        AvionicsDataCommunicationNetworkMessageFunctionalDataSetStatus::FunctionalTest => {
+            SignStatus::FunctionalTest
+        }
+        AvionicsDataCommunicationNetworkMessageFunctionalDataSetStatus::NormalOperation => {
+            SignStatus::NormalOperation
+        }
+    }
+}
+
+fn functional_data_set_status(
+    ssm: SignStatus,
+) -> AvionicsDataCommunicationNetworkMessageFunctionalDataSetStatus {
+    match ssm {
+        SignStatus::FailureWarning => {
+            AvionicsDataCommunicationNetworkMessageFunctionalDataSetStatus::NoData
+        }
+        SignStatus::NoComputedData => {
+            AvionicsDataCommunicationNetworkMessageFunctionalDataSetStatus::NoComputedData
+        }
+        SignStatus::FunctionalTest => {
+            AvionicsDataCommunicationNetworkMessageFunctionalDataSetStatus::FunctionalTest
+        }
+        SignStatus::NormalOperation => {
+            AvionicsDataCommunicationNetworkMessageFunctionalDataSetStatus::NormalOperation
+        }
+    }
+}
+
+struct AirDataTranslation {
+    route: FunctionalDataSetRoute,
+    altitude_id: VariableIdentifier,
+    computed_airspeed_id: VariableIdentifier,
+    mach_id: VariableIdentifier,
+    static_air_temperature_id: VariableIdentifier,
+    altitude: Arinc429Word<Length>,
+    computed_airspeed: Arinc429Word<Velocity>,
+    mach: Arinc429Word<f64>,
+    static_air_temperature: Arinc429Word<ThermodynamicTemperature>,
+    adcn_altitude_id: VariableIdentifier,
+    adcn_computed_airspeed_id: VariableIdentifier,
+    adcn_mach_id: VariableIdentifier,
+    adcn_static_air_temperature_id: VariableIdentifier,
+    received: Option<
+        AvionicsDataCommunicationNetworkMessage<A380AvionicsDataCommunicationNetworkMessageData>,
+    >,
+}
+impl AirDataTranslation {
+    fn new<'a>(
+        context: &mut InitContext,
+        adcn: &mut impl AvionicsDataCommunicationNetwork<
+            'a,
+            A380AvionicsDataCommunicationNetworkMessageData,
+        >,
+        number: usize,
+        owners: &'static [AdcnModule],
+    ) -> Self {
+        Self {
+            route: FunctionalDataSetRoute::new(adcn, format!("ADR_{number}_AIR_DATA"), owners),
+            altitude_id: context.get_identifier(format!("ADIRS_ADR_{number}_ALTITUDE")),
+            computed_airspeed_id: context
+                .get_identifier(format!("ADIRS_ADR_{number}_COMPUTED_AIRSPEED")),
+            mach_id: context.get_identifier(format!("ADIRS_ADR_{number}_MACH")),
+            static_air_temperature_id: context
+                .get_identifier(format!("ADIRS_ADR_{number}_STATIC_AIR_TEMPERATURE")),
+            altitude: Arinc429Word::new(Length::default(), SignStatus::FailureWarning),
+            computed_airspeed: Arinc429Word::new(Velocity::default(), SignStatus::FailureWarning),
+            mach: Arinc429Word::new(0., SignStatus::FailureWarning),
+            static_air_temperature: Arinc429Word::new(
+                ThermodynamicTemperature::default(),
+                SignStatus::FailureWarning,
+            ),
+            adcn_altitude_id: context.get_identifier(format!("ADCN_ADR_{number}_ALTITUDE")),
+            adcn_computed_airspeed_id: context
+                .get_identifier(format!("ADCN_ADR_{number}_COMPUTED_AIRSPEED")),
+            adcn_mach_id: context.get_identifier(format!("ADCN_ADR_{number}_MACH")),
+            adcn_static_air_temperature_id: context
+                .get_identifier(format!("ADCN_ADR_{number}_STATIC_AIR_TEMPERATURE")),
+            received: None,
+        }
+    }
+
+    fn update<'a>(
+        &mut self,
+        adcn: &impl AvionicsDataCommunicationNetwork<
+            'a,
+            A380AvionicsDataCommunicationNetworkMessageData,
+        >,
+    ) {
+        // The data set is only as good as its worst parameter.
+        let ssm = [
+            self.altitude.ssm(),
+            self.computed_airspeed.ssm(),
+            self.mach.ssm(),
+            self.static_air_temperature.ssm(),
+        ]
+        .into_iter()
+        .min_by_key(|ssm| functional_data_set_status(*ssm))
+        .unwrap_or(SignStatus::FailureWarning);
+
+        self.route.send(
+            adcn,
+            A380AvionicsDataCommunicationNetworkMessageData::AirData(AirData {
+                altitude: self.altitude.value(),
+                computed_airspeed: self.computed_airspeed.value(),
+                mach: self.mach.value(),
+                static_air_temperature: self.static_air_temperature.value(),
+            })
+            .into_message(functional_data_set_status(ssm)),
+        );
+        self.received = self.route.recv(adcn);
+    }
+}
+impl SimulationElement for AirDataTranslation {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.altitude = reader.read_arinc429(&self.altitude_id);
+        self.computed_airspeed = reader.read_arinc429(&self.computed_airspeed_id);
+        self.mach = reader.read_arinc429(&self.mach_id);
+        self.static_air_temperature = reader.read_arinc429(&self.static_air_temperature_id);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        let (data, ssm) = match &self.received {
+            Some(message) => match message.data() {
+                A380AvionicsDataCommunicationNetworkMessageData::AirData(data) => {
+                    (*data, sign_status(message.status()))
+                }
+                _ => (AirData::default(), SignStatus::FailureWarning),
+            },
+            None => (AirData::default(), SignStatus::FailureWarning),
+        };
+
+        writer.write_arinc429(&self.adcn_altitude_id, data.altitude, ssm);
+        writer.write_arinc429(&self.adcn_computed_airspeed_id, data.computed_airspeed, ssm);
+        writer.write_arinc429(&self.adcn_mach_id, data.mach, ssm);
+        writer.write_arinc429(
+            &self.adcn_static_air_temperature_id,
+            data.static_air_temperature,
+            ssm,
+        );
+    }
+}
+
+struct FuelQuantityTranslation {
+    route: FunctionalDataSetRoute,
+    total_quantity_id: VariableIdentifier,
+    feed_tank_quantity_ids: [VariableIdentifier; 4],
+    total_quantity: Mass,
+    feed_tank_quantities: [Mass; 4],
+    adcn_total_quantity_id: VariableIdentifier,
+    adcn_feed_tank_quantity_ids: [VariableIdentifier; 4],
+    received: Option<
+        AvionicsDataCommunicationNetworkMessage<A380AvionicsDataCommunicationNetworkMessageData>,
+    >,
+}
+impl FuelQuantityTranslation {
+    // The quantity is computed by the fuel quantity data concentrators of the CPIOM F.
+    const OWNERS: [AdcnModule; 2] = [AdcnModule::Cpiom("F1"), AdcnModule::Cpiom("F2")];
+
+    fn new<'a>(
+        context: &mut InitContext,
+        adcn: &mut impl AvionicsDataCommunicationNetwork<
+            'a,
+            A380AvionicsDataCommunicationNetworkMessageData,
+        >,
+    ) -> Self {
+        Self {
+            route: FunctionalDataSetRoute::new(adcn, "FUEL_QUANTITY".to_owned(), &Self::OWNERS),
+            total_quantity_id: context.get_identifier("FUEL TOTAL QUANTITY WEIGHT".to_owned()),
+            // Feed tanks one to four.
+            feed_tank_quantity_ids: [2, 5, 6, 9]
+                .map(|id| context.get_identifier(format!("FUEL_TANK_QUANTITY_{id}"))),
+            total_quantity: Mass::default(),
+            feed_tank_quantities: [Mass::default(); 4],
+            adcn_total_quantity_id: context.get_identifier("ADCN_FUEL_TOTAL_QUANTITY".to_owned()),
+            adcn_feed_tank_quantity_ids: [1, 2, 3, 4].map(|feed_tank| {
+                context.get_identifier(format!("ADCN_FUEL_FEED_TANK_{feed_tank}_QUANTITY"))
+            }),
+            received: None,
+        }
+    }
+
+    fn update<'a>(
+        &mut self,
+        adcn: &impl AvionicsDataCommunicationNetwork<
+            'a,
+            A380AvionicsDataCommunicationNetworkMessageData,
+        >,
+    ) {
+        self.route.send(
+            adcn,
+            A380AvionicsDataCommunicationNetworkMessageData::FuelQuantity(FuelQuantityData {
+                total_quantity: self.total_quantity,
+                feed_tank_quantities: self.feed_tank_quantities,
+            })
+            .into(),
+        );
+        self.received = self.route.recv(adcn);
+    }
+}
+impl SimulationElement for FuelQuantityTranslation {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.total_quantity = reader.read(&self.total_quantity_id);
+        self.feed_tank_quantities = self.feed_tank_quantity_ids.map(|id| {
+            let gallons: f64 = reader.read(&id);
+            Mass::new::<kilogram>(gallons * FUEL_GALLONS_TO_KG)
+        });
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        let (data, ssm) = match &self.received {
+            Some(message) => match message.data() {
+                A380AvionicsDataCommunicationNetworkMessageData::FuelQuantity(data) => {
+                    (*data, sign_status(message.status()))
+                }
+                _ => (FuelQuantityData::default(), SignStatus::FailureWarning),
+            },
+            None => (FuelQuantityData::default(), SignStatus::FailureWarning),
+        };
+
+        writer.write_arinc429(&self.adcn_total_quantity_id, data.total_quantity, ssm);
+        for (id, quantity) in self
+            .adcn_feed_tank_quantity_ids
+            .iter()
+            .zip(data.feed_tank_quantities)
+        {
+            writer.write_arinc429(id, quantity, ssm);
+        }
+    }
+}
+
+struct HydraulicPressureTranslation {
+    route: FunctionalDataSetRoute,
+    pressure_id: VariableIdentifier,
+    pressure: Pressure,
+    adcn_pressure_id: VariableIdentifier,
+    received: Option<
+        AvionicsDataCommunicationNetworkMessage<A380AvionicsDataCommunicationNetworkMessageData>,
+    >,
+}
+impl HydraulicPressureTranslation {
+    fn new<'a>(
+        context: &mut InitContext,
+        adcn: &mut impl AvionicsDataCommunicationNetwork<
+            'a,
+            A380AvionicsDataCommunicationNetworkMessageData,
+        >,
+        color: &str,
+        owners: &'static [AdcnModule],
+    ) -> Self {
+        Self {
+            route: FunctionalDataSetRoute::new(adcn, format!("HYD_{color}_PRESSURE"), owners),
+            pressure_id: context.get_identifier(format!("HYD_{color}_SYSTEM_1_SECTION_PRESSURE")),
+            pressure: Pressure::default(),
+            adcn_pressure_id: context.get_identifier(format!("ADCN_HYD_{color}_PRESSURE")),
+            received: None,
+        }
+    }
+
+    fn update<'a>(
+        &mut self,
+        adcn: &impl AvionicsDataCommunicationNetwork<
+            'a,
+            A380AvionicsDataCommunicationNetworkMessageData,
+        >,
+    ) {
+        self.route.send(
+            adcn,
+            A380AvionicsDataCommunicationNetworkMessageData::HydraulicPressure(
+                HydraulicPressureData {
+                    pressure: self.pressure,
+                },
+            )
+            .into(),
+        );
+        self.received = self.route.recv(adcn);
+    }
+}
+impl SimulationElement for HydraulicPressureTranslation {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.pressure = reader.read(&self.pressure_id);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        let (data, ssm) = match &self.received {
+            Some(message) => match message.data() {
+                A380AvionicsDataCommunicationNetworkMessageData::HydraulicPressure(data) => {
+                    (*data, sign_status(message.status()))
+                }
+                _ => (HydraulicPressureData::default(), SignStatus::FailureWarning),
+            },
+            None => (HydraulicPressureData::default(), SignStatus::FailureWarning),
+        };
+
+        writer.write_arinc429(&self.adcn_pressure_id, data.pressure, ssm);
+    }
+}
+
+struct EngineBleedTranslation {
+    route: FunctionalDataSetRoute,
+    regulated_pressure_id: VariableIdentifier,
+    precooler_outlet_temperature_id: VariableIdentifier,
+    pressure_regulating_valve_open_id: VariableIdentifier,
+    regulated_pressure: Option<Pressure>,
+    precooler_outlet_temperature: ThermodynamicTemperature,
+    pressure_regulating_valve_is_open: bool,
+    adcn_regulated_pressure_id: VariableIdentifier,
+    adcn_precooler_outlet_temperature_id: VariableIdentifier,
+    adcn_pressure_regulating_valve_open_id: VariableIdentifier,
+    received: Option<
+        AvionicsDataCommunicationNetworkMessage<A380AvionicsDataCommunicationNetworkMessageData>,
+    >,
+}
+impl EngineBleedTranslation {
+    fn new<'a>(
+        context: &mut InitContext,
+        adcn: &mut impl AvionicsDataCommunicationNetwork<
+            'a,
+            A380AvionicsDataCommunicationNetworkMessageData,
+        >,
+        number: usize,
+        owners: &'static [AdcnModule],
+    ) -> Self {
+        Self {
+            route: FunctionalDataSetRoute::new(adcn, format!("ENG_{number}_BLEED"), owners),
+            regulated_pressure_id: context
+                .get_identifier(format!("PNEU_ENG_{number}_REGULATED_TRANSDUCER_PRESSURE")),
+            precooler_outlet_temperature_id: context
+                .get_identifier(format!("PNEU_ENG_{number}_PRECOOLER_OUTLET_TEMPERATURE")),
+            pressure_regulating_valve_open_id: context
+                .get_identifier(format!("PNEU_ENG_{number}_PR_VALVE_OPEN")),
+            regulated_pressure: None,
+            precooler_outlet_temperature: ThermodynamicTemperature::default(),
+            pressure_regulating_valve_is_open: false,
+            adcn_regulated_pressure_id: context
+                .get_identifier(format!("ADCN_ENG_{number}_BLEED_REGULATED_PRESSURE")),
+            adcn_precooler_outlet_temperature_id: context.get_identifier(format!(
+                "ADCN_ENG_{number}_BLEED_PRECOOLER_OUTLET_TEMPERATURE"
+            )),
+            adcn_pressure_regulating_valve_open_id: context
+                .get_identifier(format!("ADCN_ENG_{number}_BLEED_PR_VALVE_OPEN")),
+            received: None,
+        }
+    }
+
+    fn update<'a>(
+        &mut self,
+        adcn: &impl AvionicsDataCommunicationNetwork<
+            'a,
+            A380AvionicsDataCommunicationNetworkMessageData,
+        >,
+    ) {
+        // A failed pressure transducer makes the data set invalid.
+        let status = if self.regulated_pressure.is_some() {
+            AvionicsDataCommunicationNetworkMessageFunctionalDataSetStatus::NormalOperation
+        } else {
+            AvionicsDataCommunicationNetworkMessageFunctionalDataSetStatus::NoComputedData
+        };
+
+        self.route.send(
+            adcn,
+            A380AvionicsDataCommunicationNetworkMessageData::EngineBleed(EngineBleedData {
+                regulated_pressure: self.regulated_pressure.unwrap_or_default(),
+                precooler_outlet_temperature: self.precooler_outlet_temperature,
+                pressure_regulating_valve_is_open: self.pressure_regulating_valve_is_open,
+            })
+            .into_message(status),
+        );
+        self.received = self.route.recv(adcn);
+    }
+}
+impl SimulationElement for EngineBleedTranslation {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        let regulated_pressure_psi: f64 = reader.read(&self.regulated_pressure_id);
+        self.regulated_pressure =
+            (regulated_pressure_psi >= 0.).then(|| Pressure::new::<psi>(regulated_pressure_psi));
+        self.precooler_outlet_temperature = reader.read(&self.precooler_outlet_temperature_id);
+        self.pressure_regulating_valve_is_open =
+            reader.read(&self.pressure_regulating_valve_open_id);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        let (data, ssm) = match &self.received {
+            Some(message) => match message.data() {
+                A380AvionicsDataCommunicationNetworkMessageData::EngineBleed(data) => {
+                    (*data, sign_status(message.status()))
+                }
+                _ => (EngineBleedData::default(), SignStatus::FailureWarning),
+            },
+            None => (EngineBleedData::default(), SignStatus::FailureWarning),
+        };
+
+        writer.write_arinc429(
+            &self.adcn_regulated_pressure_id,
+            data.regulated_pressure,
+            ssm,
+        );
+        writer.write_arinc429(
+            &self.adcn_precooler_outlet_temperature_id,
+            data.precooler_outlet_temperature,
+            ssm,
+        );
+        writer.write_arinc429(
+            &self.adcn_pressure_regulating_valve_open_id,
+            data.pressure_regulating_valve_is_open as u32 as f64,
+            ssm,
+        );
+    }
+}
+
+// This struct translates simvar values to ADCN messages and ADCN messages to simvars.
+// The functional data sets are read from the simvars of the owning systems and sent over
+// the ADCN by their owning CPIOM or IOM. What the consuming CPIOM receives is published
+// as ARINC 429 words prefixed with ADCN_. Without a route through the network, the words
+// are published with a failure warning.
+pub struct A380AvionicsDataCommunicationNetworkSimvarTranslator {
+    air_data: [AirDataTranslation; 3],
+    fuel_quantity: FuelQuantityTranslation,
+    hydraulic_pressures: [HydraulicPressureTranslation; 2],
+    engine_bleeds: [EngineBleedTranslation; 4],
+}
 impl A380AvionicsDataCommunicationNetworkSimvarTranslator {
+    // The ADIRUs are connected to the network through the IOMs.
+    const AIR_DATA_OWNERS: [&'static [AdcnModule]; 3] = [
+        &[AdcnModule::Iom("A1")],
+        &[AdcnModule::Iom("A2")],
+        &[AdcnModule::Iom("A3")],
+    ];
+    const HYDRAULIC_PRESSURE_OWNERS: [(&'static str, &'static [AdcnModule]); 2] = [
+        ("GREEN", &[AdcnModule::Iom("A5")]),
+        ("YELLOW", &[AdcnModule::Iom("A6")]),
+    ];
+    // Each engine bleed is monitored by the bleed application of its CPIOM A.
+    const ENGINE_BLEED_OWNERS: [&'static [AdcnModule]; 4] = [
+        &[AdcnModule::Cpiom("A1")],
+        &[AdcnModule::Cpiom("A2")],
+        &[AdcnModule::Cpiom("A3")],
+        &[AdcnModule::Cpiom("A4")],
+    ];
+
     pub fn new<'a>(
-        _context: &mut InitContext,
-        _adcn: &mut impl AvionicsDataCommunicationNetwork<
+        context: &mut InitContext,
+        adcn: &mut impl AvionicsDataCommunicationNetwork<
             'a,
             A380AvionicsDataCommunicationNetworkMessageData,
         >,
     ) -> Self {
-        Self {}
+        Self {
+            air_data: [1, 2, 3].map(|number| {
+                AirDataTranslation::new(context, adcn, number, Self::AIR_DATA_OWNERS[number - 1])
+            }),
+            fuel_quantity: FuelQuantityTranslation::new(context, adcn),
+            hydraulic_pressures: Self::HYDRAULIC_PRESSURE_OWNERS.map(|(color, owners)| {
+                HydraulicPressureTranslation::new(context, adcn, color, owners)
+            }),
+            engine_bleeds: [1, 2, 3, 4].map(|number| {
+                EngineBleedTranslation::new(
+                    context,
+                    adcn,
+                    number,
+                    Self::ENGINE_BLEED_OWNERS[number - 1],
+                )
+            }),
+        }
     }
 
     pub fn update<'a>(
         &mut self,
-        _adcn: &impl AvionicsDataCommunicationNetwork<
+        adcn: &impl AvionicsDataCommunicationNetwork<
             'a,
             A380AvionicsDataCommunicationNetworkMessageData,
         >,
     ) {
+        for air_data in &mut self.air_data {
+            air_data.update(adcn);
+        }
+        self.fuel_quantity.update(adcn);
+        for hydraulic_pressure in &mut self.hydraulic_pressures {
+            hydraulic_pressure.update(adcn);
+        }
+        for engine_bleed in &mut self.engine_bleeds {
+            engine_bleed.update(adcn);
+        }
+    }
+}
+impl SimulationElement for A380AvionicsDataCommunicationNetworkSimvarTranslator {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.air_data, visitor);
+        self.fuel_quantity.accept(visitor);
+        accept_iterable!(self.hydraulic_pressures, visitor);
+        accept_iterable!(self.engine_bleeds, visitor);
+        visitor.visit(self);
     }
 }
-impl SimulationElement for A380AvionicsDataCommunicationNetworkSimvarTranslator {}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct AirData {
+    pub altitude: Length,
+    pub computed_airspeed: Velocity,
+    pub mach: f64,
+    pub static_air_temperature: ThermodynamicTemperature,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct FuelQuantityData {
+    pub total_quantity: Mass,
+    pub feed_tank_quantities: [Mass; 4],
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct HydraulicPressureData {
+    pub pressure: Pressure,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct EngineBleedData {
+    pub regulated_pressure: Pressure,
+    pub precooler_outlet_temperature: ThermodynamicTemperature,
+    pub pressure_regulating_valve_is_open: bool,
+}
 
 /// This type represents all the messages which can be send over AFDX
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum A380AvionicsDataCommunicationNetworkMessageData {
+    AirData(AirData),
+    FuelQuantity(FuelQuantityData),
+    HydraulicPressure(HydraulicPressureData),
+    EngineBleed(EngineBleedData),
     #[cfg(test)]
     TestValue(&'static str),
 }
@@ -607,22 +1232,34 @@ mod tests {
             Aircraft, InitContext, SimulationElement, SimulationElementVisitor, UpdateContext,
         },
     };
-    use systems::integrated_modular_avionics::AvionicsDataCommunicationNetworkMessage;
-    use uom::si::{electric_potential::volt, f64::*};
+    use std::time::Duration;
+    use systems::integrated_modular_avionics::{
+        AvionicsDataCommunicationNetworkMessage, AvionicsDataCommunicationNetworkMessageSource,
+    };
+    use uom::si::{
+        electric_potential::volt, length::foot, mass::pound,
+        thermodynamic_temperature::degree_celsius, velocity::knot,
+    };
 
     struct AdcnTestAircraft {
         adcn: A380AvionicsDataCommunicationNetwork,
+        adcn_simvar_translation: A380AvionicsDataCommunicationNetworkSimvarTranslator,
         powered_source_dc: TestElectricitySource,
         dc_1_bus: ElectricalBus,
         dc_2_bus: ElectricalBus,
         dc_ess_bus: ElectricalBus,
         dc_ess_in_flight_bus: ElectricalBus,
+        dc_gnd_flt_service_bus: ElectricalBus,
         is_elec_powered: bool,
     }
     impl AdcnTestAircraft {
         fn new(context: &mut InitContext) -> Self {
+            let mut adcn = A380AvionicsDataCommunicationNetwork::new(context);
             Self {
-                adcn: A380AvionicsDataCommunicationNetwork::new(context),
+                adcn_simvar_translation: A380AvionicsDataCommunicationNetworkSimvarTranslator::new(
+                    context, &mut adcn,
+                ),
+                adcn,
                 powered_source_dc: TestElectricitySource::powered(
                     context,
                     PotentialOrigin::Battery(2),
@@ -634,12 +1271,17 @@ mod tests {
                     context,
                     ElectricalBusType::DirectCurrentNamed("108PH"),
                 ),
+                dc_gnd_flt_service_bus: ElectricalBus::new(
+                    context,
+                    ElectricalBusType::DirectCurrentGndFltService,
+                ),
                 is_elec_powered: false,
             }
         }
 
-        fn update(&mut self) {
-            self.adcn.update();
+        fn update(&mut self, context: &UpdateContext) {
+            self.adcn.update(context);
+            self.adcn_simvar_translation.update(&self.adcn);
         }
 
         fn set_elec_powered(&mut self, is_powered: bool) {
@@ -675,6 +1317,26 @@ mod tests {
         > {
             self.adcn.get_endpoint(switch_id).recv_value(id)
         }
+
+        fn cpiom_recv_message(
+            &self,
+            name: &str,
+            id: &AvionicsDataCommunicationNetworkMessageIdentifier,
+        ) -> Option<
+            AvionicsDataCommunicationNetworkMessage<
+                A380AvionicsDataCommunicationNetworkMessageData,
+            >,
+        > {
+            self.adcn.get_cpiom(name).recv_value(id)
+        }
+
+        fn cpiom_message_source(
+            &self,
+            name: &str,
+            id: &AvionicsDataCommunicationNetworkMessageIdentifier,
+        ) -> AvionicsDataCommunicationNetworkMessageSource {
+            self.adcn.get_cpiom(name).message_source(id)
+        }
     }
     impl Aircraft for AdcnTestAircraft {
         fn update_before_power_distribution(
@@ -691,16 +1353,18 @@ mod tests {
                 electricity.flow(&self.powered_source_dc, &self.dc_2_bus);
                 electricity.flow(&self.powered_source_dc, &self.dc_ess_bus);
                 electricity.flow(&self.powered_source_dc, &self.dc_ess_in_flight_bus);
+                electricity.flow(&self.powered_source_dc, &self.dc_gnd_flt_service_bus);
             }
         }
 
-        fn update_after_power_distribution(&mut self, _: &UpdateContext) {
-            self.update();
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.update(context);
         }
     }
     impl SimulationElement for AdcnTestAircraft {
         fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
             self.adcn.accept(visitor);
+            self.adcn_simvar_translation.accept(visitor);
             visitor.visit(self);
         }
     }
@@ -1002,4 +1666,290 @@ mod tests {
             assert_eq!(message, Some(message2.clone()));
         }
     }
+
+    #[test]
+    fn air_data_is_published_over_the_network() {
+        let mut test_bed = SimulationTestBed::new(AdcnTestAircraft::new);
+
+        test_bed.command(|a| a.set_elec_powered(true));
+        test_bed.write_arinc429_by_name(
+            "ADIRS_ADR_1_ALTITUDE",
+            Length::new::<foot>(10000.),
+            SignStatus::NormalOperation,
+        );
+        test_bed.write_arinc429_by_name(
+            "ADIRS_ADR_1_COMPUTED_AIRSPEED",
+            Velocity::new::<knot>(250.),
+            SignStatus::NormalOperation,
+        );
+        test_bed.write_arinc429_by_name("ADIRS_ADR_1_MACH", 0.5, SignStatus::NormalOperation);
+        test_bed.write_arinc429_by_name(
+            "ADIRS_ADR_1_STATIC_AIR_TEMPERATURE",
+            ThermodynamicTemperature::new::<degree_celsius>(-5.),
+            SignStatus::NormalOperation,
+        );
+        test_bed.run();
+
+        let altitude: Arinc429Word<Length> = test_bed.read_arinc429_by_name("ADCN_ADR_1_ALTITUDE");
+        let computed_airspeed: Arinc429Word<Velocity> =
+            test_bed.read_arinc429_by_name("ADCN_ADR_1_COMPUTED_AIRSPEED");
+        let mach: Arinc429Word<f64> = test_bed.read_arinc429_by_name("ADCN_ADR_1_MACH");
+        let static_air_temperature: Arinc429Word<ThermodynamicTemperature> =
+            test_bed.read_arinc429_by_name("ADCN_ADR_1_STATIC_AIR_TEMPERATURE");
+
+        assert_eq!(altitude.ssm(), SignStatus::NormalOperation);
+        assert!((altitude.value().get::<foot>() - 10000.).abs() < 1.);
+        assert!((computed_airspeed.value().get::<knot>() - 250.).abs() < 0.1);
+        assert!((mach.value() - 0.5).abs() < 0.001);
+        assert!((static_air_temperature.value().get::<degree_celsius>() + 5.).abs() < 0.1);
+    }
+
+    #[test]
+    fn invalid_air_data_is_published_as_no_computed_data() {
+        let mut test_bed = SimulationTestBed::new(AdcnTestAircraft::new);
+
+        test_bed.command(|a| a.set_elec_powered(true));
+        test_bed.write_arinc429_by_name(
+            "ADIRS_ADR_2_ALTITUDE",
+            Length::new::<foot>(10000.),
+            SignStatus::NoComputedData,
+        );
+        for name in [
+            "ADIRS_ADR_2_COMPUTED_AIRSPEED",
+            "ADIRS_ADR_2_MACH",
+            "ADIRS_ADR_2_STATIC_AIR_TEMPERATURE",
+        ] {
+            test_bed.write_arinc429_by_name(name, 0., SignStatus::NormalOperation);
+        }
+        test_bed.run();
+
+        let altitude: Arinc429Word<Length> = test_bed.read_arinc429_by_name("ADCN_ADR_2_ALTITUDE");
+        assert_eq!(altitude.ssm(), SignStatus::NoComputedData);
+    }
+
+    #[test]
+    fn data_is_not_published_without_power() {
+        let mut test_bed = SimulationTestBed::new(AdcnTestAircraft::new);
+
+        test_bed.write_by_name("HYD_GREEN_SYSTEM_1_SECTION_PRESSURE", 5000.);
+        test_bed.run();
+
+        let pressure: Arinc429Word<Pressure> =
+            test_bed.read_arinc429_by_name("ADCN_HYD_GREEN_PRESSURE");
+        assert_eq!(pressure.ssm(), SignStatus::FailureWarning);
+    }
+
+    #[test]
+    fn hydraulic_pressure_is_published_over_the_network() {
+        let mut test_bed = SimulationTestBed::new(AdcnTestAircraft::new);
+
+        test_bed.command(|a| a.set_elec_powered(true));
+        test_bed.write_by_name("HYD_YELLOW_SYSTEM_1_SECTION_PRESSURE", 5000.);
+        test_bed.run();
+
+        let pressure: Arinc429Word<Pressure> =
+            test_bed.read_arinc429_by_name("ADCN_HYD_YELLOW_PRESSURE");
+        assert_eq!(pressure.ssm(), SignStatus::NormalOperation);
+        assert!((pressure.value().get::<psi>() - 5000.).abs() < 1.);
+    }
+
+    #[test]
+    fn data_is_not_published_when_owning_module_failed() {
+        let mut test_bed = SimulationTestBed::new(AdcnTestAircraft::new);
+
+        test_bed.command(|a| a.set_elec_powered(true));
+        test_bed.write_by_name("IOM_A5_FAILURE", true);
+        test_bed.write_by_name("HYD_GREEN_SYSTEM_1_SECTION_PRESSURE", 5000.);
+        test_bed.run();
+
+        let pressure: Arinc429Word<Pressure> =
+            test_bed.read_arinc429_by_name("ADCN_HYD_GREEN_PRESSURE");
+        assert_eq!(pressure.ssm(), SignStatus::FailureWarning);
+    }
+
+    #[test]
+    fn fuel_quantity_is_sent_by_the_second_cpiom_f_when_the_first_failed() {
+        let mut test_bed = SimulationTestBed::new(AdcnTestAircraft::new);
+
+        test_bed.command(|a| a.set_elec_powered(true));
+        test_bed.write_by_name("CPIOM_F1_FAILURE", true);
+        test_bed.write_by_name("FUEL TOTAL QUANTITY WEIGHT", 100000.);
+        test_bed.run();
+
+        let quantity: Arinc429Word<Mass> =
+            test_bed.read_arinc429_by_name("ADCN_FUEL_TOTAL_QUANTITY");
+        assert_eq!(quantity.ssm(), SignStatus::NormalOperation);
+        assert!((quantity.value().get::<pound>() - 100000.).abs() < 10.);
+    }
+
+    #[test]
+    fn data_is_received_by_the_second_cpiom_c_when_the_first_failed() {
+        let mut test_bed = SimulationTestBed::new(AdcnTestAircraft::new);
+
+        test_bed.command(|a| a.set_elec_powered(true));
+        test_bed.write_by_name("CPIOM_C1_FAILURE", true);
+        test_bed.write_by_name("PNEU_ENG_2_REGULATED_TRANSDUCER_PRESSURE", 40.);
+        test_bed.write_by_name("PNEU_ENG_2_PR_VALVE_OPEN", true);
+        test_bed.run();
+
+        let pressure: Arinc429Word<Pressure> =
+            test_bed.read_arinc429_by_name("ADCN_ENG_2_BLEED_REGULATED_PRESSURE");
+        let valve_open: Arinc429Word<f64> =
+            test_bed.read_arinc429_by_name("ADCN_ENG_2_BLEED_PR_VALVE_OPEN");
+        assert_eq!(pressure.ssm(), SignStatus::NormalOperation);
+        assert!((pressure.value().get::<psi>() - 40.).abs() < 0.1);
+        assert_eq!(valve_open.value(), 1.);
+    }
+
+    #[test]
+    fn failed_bleed_pressure_transducer_is_published_as_no_computed_data() {
+        let mut test_bed = SimulationTestBed::new(AdcnTestAircraft::new);
+
+        test_bed.command(|a| a.set_elec_powered(true));
+        test_bed.write_by_name("PNEU_ENG_3_REGULATED_TRANSDUCER_PRESSURE", -1.);
+        test_bed.run();
+
+        let pressure: Arinc429Word<Pressure> =
+            test_bed.read_arinc429_by_name("ADCN_ENG_3_BLEED_REGULATED_PRESSURE");
+        assert_eq!(pressure.ssm(), SignStatus::NoComputedData);
+    }
+
+    #[test]
+    fn data_is_not_published_when_both_cpiom_c_failed() {
+        let mut test_bed = SimulationTestBed::new(AdcnTestAircraft::new);
+
+        test_bed.command(|a| a.set_elec_powered(true));
+        test_bed.write_by_name("CPIOM_C1_FAILURE", true);
+        test_bed.write_by_name("CPIOM_C2_FAILURE", true);
+        test_bed.write_by_name("PNEU_ENG_4_REGULATED_TRANSDUCER_PRESSURE", 40.);
+        test_bed.run();
+
+        let pressure: Arinc429Word<Pressure> =
+            test_bed.read_arinc429_by_name("ADCN_ENG_4_BLEED_REGULATED_PRESSURE");
+        assert_eq!(pressure.ssm(), SignStatus::FailureWarning);
+    }
+
+    #[test]
+    fn cpiom_prefers_network_a_when_both_networks_are_fresh() {
+        let mut test_bed = SimulationTestBed::new(AdcnTestAircraft::new);
+
+        let mut message_id = Default::default();
+        test_bed.command(|a| message_id = a.get_message_identifier("test_value".to_owned()));
+        let message1: AvionicsDataCommunicationNetworkMessage<_> =
+            A380AvionicsDataCommunicationNetworkMessageData::TestValue("message1").into();
+        let message2: AvionicsDataCommunicationNetworkMessage<_> =
+            A380AvionicsDataCommunicationNetworkMessageData::TestValue("message2").into();
+        test_bed.command(|a| a.set_elec_powered(true));
+        test_bed.run();
+        test_bed.command(|a| a.send_message(3, &message_id, message1.clone()));
+        test_bed.command(|a| a.send_message(13, &message_id, message2.clone()));
+
+        assert_eq!(
+            test_bed.query(|a| a.cpiom_recv_message("C1", &message_id)),
+            Some(message1)
+        );
+        assert_eq!(
+            test_bed.query(|a| a.cpiom_message_source("C1", &message_id)),
+            AvionicsDataCommunicationNetworkMessageSource::NetworkA
+        );
+    }
+
+    #[test]
+    fn cpiom_receives_freshest_copy_of_message() {
+        let mut test_bed = SimulationTestBed::new(AdcnTestAircraft::new);
+
+        let mut message_id = Default::default();
+        test_bed.command(|a| message_id = a.get_message_identifier("test_value".to_owned()));
+        let message1: AvionicsDataCommunicationNetworkMessage<_> =
+            A380AvionicsDataCommunicationNetworkMessageData::TestValue("message1").into();
+        let message2: AvionicsDataCommunicationNetworkMessage<_> =
+            A380AvionicsDataCommunicationNetworkMessageData::TestValue("message2").into();
+        test_bed.command(|a| a.set_elec_powered(true));
+        test_bed.run();
+        test_bed.command(|a| a.send_message(3, &message_id, message1.clone()));
+        test_bed.run_with_delta(Duration::from_millis(100));
+        test_bed.command(|a| a.send_message(13, &message_id, message2.clone()));
+
+        assert_eq!(
+            test_bed.query(|a| a.cpiom_recv_message("C1", &message_id)),
+            Some(message2)
+        );
+        assert_eq!(
+            test_bed.query(|a| a.cpiom_message_source("C1", &message_id)),
+            AvionicsDataCommunicationNetworkMessageSource::NetworkB
+        );
+    }
+
+    #[test]
+    fn cpiom_receives_from_network_b_when_network_a_switch_failed() {
+        let mut test_bed = SimulationTestBed::new(AdcnTestAircraft::new);
+
+        let mut message_id = Default::default();
+        test_bed.command(|a| message_id = a.get_message_identifier("test_value".to_owned()));
+        let message: AvionicsDataCommunicationNetworkMessage<_> =
+            A380AvionicsDataCommunicationNetworkMessageData::TestValue("testval").into();
+        test_bed.command(|a| a.set_elec_powered(true));
+        test_bed.write_by_name("AFDX_SWITCH_3_FAILURE", true);
+        test_bed.run();
+        test_bed.command(|a| a.send_message(1, &message_id, message.clone()));
+        test_bed.command(|a| a.send_message(11, &message_id, message.clone()));
+
+        assert_eq!(
+            test_bed.query(|a| a.cpiom_recv_message("C1", &message_id)),
+            Some(message)
+        );
+        assert_eq!(
+            test_bed.query(|a| a.cpiom_message_source("C1", &message_id)),
+            AvionicsDataCommunicationNetworkMessageSource::NetworkB
+        );
+    }
+
+    #[test]
+    fn cpiom_receives_nothing_when_both_networks_failed() {
+        let mut test_bed = SimulationTestBed::new(AdcnTestAircraft::new);
+
+        let mut message_id = Default::default();
+        test_bed.command(|a| message_id = a.get_message_identifier("test_value".to_owned()));
+        let message: AvionicsDataCommunicationNetworkMessage<_> =
+            A380AvionicsDataCommunicationNetworkMessageData::TestValue("testval").into();
+        test_bed.command(|a| a.set_elec_powered(true));
+        test_bed.write_by_name("AFDX_SWITCH_3_FAILURE", true);
+        test_bed.write_by_name("AFDX_SWITCH_13_FAILURE", true);
+        test_bed.run();
+        test_bed.command(|a| a.send_message(1, &message_id, message.clone()));
+        test_bed.command(|a| a.send_message(11, &message_id, message));
+
+        assert_eq!(
+            test_bed.query(|a| a.cpiom_recv_message("C1", &message_id)),
+            None
+        );
+        assert_eq!(
+            test_bed.query(|a| a.cpiom_message_source("C1", &message_id)),
+            AvionicsDataCommunicationNetworkMessageSource::NoNetwork
+        );
+    }
+
+    #[test]
+    fn cpiom_ignores_stale_messages() {
+        let mut test_bed = SimulationTestBed::new(AdcnTestAircraft::new);
+
+        let mut message_id = Default::default();
+        test_bed.command(|a| message_id = a.get_message_identifier("test_value".to_owned()));
+        let message: AvionicsDataCommunicationNetworkMessage<_> =
+            A380AvionicsDataCommunicationNetworkMessageData::TestValue("testval").into();
+        test_bed.command(|a| a.set_elec_powered(true));
+        test_bed.run();
+        test_bed.command(|a| a.send_message(3, &message_id, message.clone()));
+        test_bed.command(|a| a.send_message(13, &message_id, message));
+        test_bed.run();
+
+        assert_eq!(
+            test_bed.query(|a| a.cpiom_recv_message("C1", &message_id)),
+            None
+        );
+        assert_eq!(
+            test_bed.query(|a| a.cpiom_message_source("C1", &message_id)),
+            AvionicsDataCommunicationNetworkMessageSource::NoNetwork
+        );
+    }
 }
@@ -213,6 +213,11 @@ impl A380AlternatingCurrentElectrical {
         self.main_power_sources.gen_drive_connected(number)
     }
 
+    pub fn engine_generator_output_power(&self, number: usize) -> Power {
+        self.main_power_sources
+            .engine_generator_output_power(number)
+    }
+
     pub fn emergency_generator_contactor_is_closed(&self) -> bool {
         self.emergency_gen_contactor.is_closed()
     }
@@ -640,6 +645,10 @@ impl A380MainPowerSources {
     fn gen_drive_connected(&self, number: usize) -> bool {
         self.engine_gens[number - 1].is_drive_connected()
     }
+
+    fn engine_generator_output_power(&self, number: usize) -> Power {
+        self.engine_gens[number - 1].output_power()
+    }
 }
 impl SimulationElement for A380MainPowerSources {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
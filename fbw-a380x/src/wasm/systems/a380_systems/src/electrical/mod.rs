@@ -18,9 +18,10 @@ use std::time::Duration;
 use systems::{
     accept_iterable,
     electrical::{
-        AlternatingCurrentElectricalSystem, BatteryPushButtons, ElectricalElement, Electricity,
-        EmergencyElectrical, EmergencyGenerator, EngineGeneratorPushButtons, ExternalPowerSource,
-        GeneratorControlUnit, RamAirTurbine, StaticInverter, TransformerRectifier,
+        AlternatingCurrentElectricalSystem, ApuGeneratorPushButtons, BatteryPushButtons,
+        ElectricalElement, Electricity, EmergencyElectrical, EmergencyGenerator,
+        EngineGeneratorPushButtons, ExternalPowerSource, RamAirTurbine,
+        RamAirTurbineGeneratorControlUnit, StaticInverter, TransformerRectifier,
     },
     engine::Engine,
     overhead::{
@@ -51,7 +52,7 @@ pub(super) struct A380Electrical {
     emergency_gen: EmergencyGenerator,
 
     rat_physics_updater: MaxStepLoop,
-    gcu: GeneratorControlUnit,
+    gcu: RamAirTurbineGeneratorControlUnit,
     ram_air_turbine: RamAirTurbine,
     rat_controller: A380RamAirTurbineController,
     tefo_condition: LatchedTrueLogicGate,
@@ -84,7 +85,7 @@ impl A380Electrical {
             ),
 
             rat_physics_updater: MaxStepLoop::new(Self::RAT_SIM_TIME_STEP),
-            gcu: GeneratorControlUnit::default(),
+            gcu: RamAirTurbineGeneratorControlUnit::default(),
             ram_air_turbine: RamAirTurbine::new(context),
             rat_controller: A380RamAirTurbineController::new(
                 Self::RAT_CONTROL_SOLENOID1_POWER_BUS,
@@ -296,6 +297,11 @@ impl A380Electrical {
         self.alternating_current.gen_drive_connected(number)
     }
 
+    pub fn engine_generator_output_power(&self, number: usize) -> Power {
+        self.alternating_current
+            .engine_generator_output_power(number)
+    }
+
     pub fn in_emergency_elec(&self) -> bool {
         self.emergency_elec.is_in_emergency_elec()
     }
@@ -419,6 +425,11 @@ impl A380ElectricalOverheadPanel {
         self.galy_and_cab.is_off()
     }
 }
+impl ApuGeneratorPushButtons for A380ElectricalOverheadPanel {
+    fn apu_gen_push_button_is_on(&self, number: usize) -> bool {
+        self.apu_gens[number - 1].is_on()
+    }
+}
 impl EngineGeneratorPushButtons for A380ElectricalOverheadPanel {
     fn engine_gen_push_button_is_on(&self, number: usize) -> bool {
         self.generators[number - 1].is_on()
@@ -3213,7 +3224,14 @@ mod a380_electrical_circuit_tests {
         }
     }
     impl ApuGenerator for TestApuGenerator {
-        fn update(&mut self, _n: Ratio, _is_emergency_shutdown: bool) {}
+        fn update(
+            &mut self,
+            _context: &UpdateContext,
+            _n: Ratio,
+            _is_emergency_shutdown: bool,
+            _push_button_is_on: bool,
+        ) {
+        }
 
         fn output_within_normal_parameters(&self) -> bool {
             self.is_available
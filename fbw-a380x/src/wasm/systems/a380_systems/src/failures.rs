@@ -0,0 +1,680 @@
+use systems::{
+    air_conditioning::{Channel, FdacId, OcsmId, VcmId},
+    failures::FailureType,
+    integrated_modular_avionics::core_processing_input_output_module::CpiomId,
+    shared::{
+        AirbusElectricPumpId, AirbusEngineDrivenPumpId, ElectricalBusType, EngineBleedValveId,
+        FireDetectionLoopID, FireDetectionZone, GearActuatorId, HydraulicColor, LeakDetectionZone,
+        LgciuId, PotentialOrigin, ProbeType, ProximityDetectorId, WindowType,
+    },
+};
+
+/// The failures which can be activated on the A380, by the identifier which the failure
+/// manager uses to refer to them.
+pub const A380_FAILURES: &[(u64, FailureType)] = &[
+    (21_000, FailureType::RapidDecompression),
+    (21_001, FailureType::CabinFan(1)),
+    (21_002, FailureType::CabinFan(2)),
+    (21_003, FailureType::CabinFan(3)),
+    (21_004, FailureType::CabinFan(4)),
+    (21_005, FailureType::HotAir(1)),
+    (21_006, FailureType::HotAir(2)),
+    (21_007, FailureType::FwdIsolValve),
+    (21_008, FailureType::FwdExtractFan),
+    (21_009, FailureType::BulkIsolValve),
+    (21_010, FailureType::BulkExtractFan),
+    (21_011, FailureType::CargoHeater),
+    (21_012, FailureType::Fdac(FdacId::One, Channel::ChannelOne)),
+    (21_013, FailureType::Fdac(FdacId::One, Channel::ChannelTwo)),
+    (21_014, FailureType::Fdac(FdacId::Two, Channel::ChannelOne)),
+    (21_015, FailureType::Fdac(FdacId::Two, Channel::ChannelTwo)),
+    (21_016, FailureType::Tadd(Channel::ChannelOne)),
+    (21_017, FailureType::Tadd(Channel::ChannelTwo)),
+    (21_018, FailureType::Vcm(VcmId::Fwd, Channel::ChannelOne)),
+    (21_019, FailureType::Vcm(VcmId::Fwd, Channel::ChannelTwo)),
+    (21_020, FailureType::Vcm(VcmId::Aft, Channel::ChannelOne)),
+    (21_021, FailureType::Vcm(VcmId::Aft, Channel::ChannelTwo)),
+    (21_022, FailureType::OcsmAutoPartition(OcsmId::One)),
+    (21_023, FailureType::OcsmAutoPartition(OcsmId::Two)),
+    (21_024, FailureType::OcsmAutoPartition(OcsmId::Three)),
+    (21_025, FailureType::OcsmAutoPartition(OcsmId::Four)),
+    (21_026, FailureType::Ocsm(OcsmId::One, Channel::ChannelOne)),
+    (21_027, FailureType::Ocsm(OcsmId::One, Channel::ChannelTwo)),
+    (21_028, FailureType::Ocsm(OcsmId::Two, Channel::ChannelOne)),
+    (21_029, FailureType::Ocsm(OcsmId::Two, Channel::ChannelTwo)),
+    (
+        21_030,
+        FailureType::Ocsm(OcsmId::Three, Channel::ChannelOne),
+    ),
+    (
+        21_031,
+        FailureType::Ocsm(OcsmId::Three, Channel::ChannelTwo),
+    ),
+    (21_032, FailureType::Ocsm(OcsmId::Four, Channel::ChannelOne)),
+    (21_033, FailureType::Ocsm(OcsmId::Four, Channel::ChannelTwo)),
+    (21_034, FailureType::AgsApp(CpiomId::B1)),
+    (21_035, FailureType::AgsApp(CpiomId::B2)),
+    (21_036, FailureType::AgsApp(CpiomId::B3)),
+    (21_037, FailureType::AgsApp(CpiomId::B4)),
+    (21_038, FailureType::TcsApp(CpiomId::B1)),
+    (21_039, FailureType::TcsApp(CpiomId::B2)),
+    (21_040, FailureType::TcsApp(CpiomId::B3)),
+    (21_041, FailureType::TcsApp(CpiomId::B4)),
+    (21_042, FailureType::VcsApp(CpiomId::B1)),
+    (21_043, FailureType::VcsApp(CpiomId::B2)),
+    (21_044, FailureType::VcsApp(CpiomId::B3)),
+    (21_045, FailureType::VcsApp(CpiomId::B4)),
+    (21_046, FailureType::CpcsApp(CpiomId::B1)),
+    (21_047, FailureType::CpcsApp(CpiomId::B2)),
+    (21_048, FailureType::CpcsApp(CpiomId::B3)),
+    (21_049, FailureType::CpcsApp(CpiomId::B4)),
+    (24_000, FailureType::TransformerRectifier(1)),
+    (24_001, FailureType::TransformerRectifier(2)),
+    (24_002, FailureType::TransformerRectifier(3)),
+    (24_003, FailureType::TransformerRectifier(4)),
+    (24_004, FailureType::StaticInverter),
+    (24_020, FailureType::Generator(1)),
+    (24_021, FailureType::Generator(2)),
+    (24_022, FailureType::Generator(3)),
+    (24_023, FailureType::Generator(4)),
+    (24_030, FailureType::ApuGenerator(1)),
+    (24_031, FailureType::ApuGenerator(2)),
+    (
+        24_040,
+        FailureType::GeneratorVoltageRegulator(PotentialOrigin::EngineGenerator(1)),
+    ),
+    (
+        24_041,
+        FailureType::GeneratorVoltageRegulator(PotentialOrigin::EngineGenerator(2)),
+    ),
+    (
+        24_042,
+        FailureType::GeneratorVoltageRegulator(PotentialOrigin::EngineGenerator(3)),
+    ),
+    (
+        24_043,
+        FailureType::GeneratorVoltageRegulator(PotentialOrigin::EngineGenerator(4)),
+    ),
+    (
+        24_044,
+        FailureType::GeneratorVoltageRegulator(PotentialOrigin::ApuGenerator(1)),
+    ),
+    (
+        24_045,
+        FailureType::GeneratorVoltageRegulator(PotentialOrigin::ApuGenerator(2)),
+    ),
+    (
+        24_050,
+        FailureType::GeneratorFeederFault(PotentialOrigin::EngineGenerator(1)),
+    ),
+    (
+        24_051,
+        FailureType::GeneratorFeederFault(PotentialOrigin::EngineGenerator(2)),
+    ),
+    (
+        24_052,
+        FailureType::GeneratorFeederFault(PotentialOrigin::EngineGenerator(3)),
+    ),
+    (
+        24_053,
+        FailureType::GeneratorFeederFault(PotentialOrigin::EngineGenerator(4)),
+    ),
+    (
+        24_054,
+        FailureType::GeneratorFeederFault(PotentialOrigin::ApuGenerator(1)),
+    ),
+    (
+        24_055,
+        FailureType::GeneratorFeederFault(PotentialOrigin::ApuGenerator(2)),
+    ),
+    (
+        24_100,
+        FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrent(1)),
+    ),
+    (
+        24_101,
+        FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrent(2)),
+    ),
+    (
+        24_102,
+        FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrent(3)),
+    ),
+    (
+        24_103,
+        FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrent(4)),
+    ),
+    (
+        24_104,
+        FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrentEssential),
+    ),
+    (
+        24_105,
+        FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrentEssentialShed),
+    ),
+    (
+        24_106,
+        FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrentNamed("247XP")),
+    ),
+    (
+        24_107,
+        FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrentGndFltService),
+    ),
+    (
+        24_108,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrent(1)),
+    ),
+    (
+        24_109,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrent(2)),
+    ),
+    (
+        24_110,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrentEssential),
+    ),
+    (
+        24_111,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrentNamed("247PP")),
+    ),
+    (
+        24_112,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrentNamed("309PP")),
+    ),
+    (
+        24_113,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrentHot(1)),
+    ),
+    (
+        24_114,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrentHot(2)),
+    ),
+    (
+        24_115,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrentHot(3)),
+    ),
+    (
+        24_116,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrentHot(4)),
+    ),
+    (
+        24_117,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrentGndFltService),
+    ),
+    (26_001, FailureType::SetOnFire(FireDetectionZone::Engine(1))),
+    (26_002, FailureType::SetOnFire(FireDetectionZone::Engine(2))),
+    (26_003, FailureType::SetOnFire(FireDetectionZone::Engine(3))),
+    (26_004, FailureType::SetOnFire(FireDetectionZone::Engine(4))),
+    (26_005, FailureType::SetOnFire(FireDetectionZone::Apu)),
+    (26_006, FailureType::SetOnFire(FireDetectionZone::Mlg)),
+    (
+        26_007,
+        FailureType::FireDetectionLoop(FireDetectionLoopID::A, FireDetectionZone::Engine(1)),
+    ),
+    (
+        26_008,
+        FailureType::FireDetectionLoop(FireDetectionLoopID::B, FireDetectionZone::Engine(1)),
+    ),
+    (
+        26_009,
+        FailureType::FireDetectionLoop(FireDetectionLoopID::A, FireDetectionZone::Engine(2)),
+    ),
+    (
+        26_010,
+        FailureType::FireDetectionLoop(FireDetectionLoopID::B, FireDetectionZone::Engine(2)),
+    ),
+    (
+        26_011,
+        FailureType::FireDetectionLoop(FireDetectionLoopID::A, FireDetectionZone::Engine(3)),
+    ),
+    (
+        26_012,
+        FailureType::FireDetectionLoop(FireDetectionLoopID::B, FireDetectionZone::Engine(3)),
+    ),
+    (
+        26_013,
+        FailureType::FireDetectionLoop(FireDetectionLoopID::A, FireDetectionZone::Engine(4)),
+    ),
+    (
+        26_014,
+        FailureType::FireDetectionLoop(FireDetectionLoopID::B, FireDetectionZone::Engine(4)),
+    ),
+    (
+        26_015,
+        FailureType::FireDetectionLoop(FireDetectionLoopID::A, FireDetectionZone::Apu),
+    ),
+    (
+        26_016,
+        FailureType::FireDetectionLoop(FireDetectionLoopID::B, FireDetectionZone::Apu),
+    ),
+    (
+        26_017,
+        FailureType::FireDetectionLoop(FireDetectionLoopID::A, FireDetectionZone::Mlg),
+    ),
+    (
+        26_018,
+        FailureType::FireDetectionLoop(FireDetectionLoopID::B, FireDetectionZone::Mlg),
+    ),
+    (28_000, FailureType::FuelPump(1)),
+    (28_001, FailureType::FuelPump(2)),
+    (28_002, FailureType::FuelPump(3)),
+    (28_003, FailureType::FuelPump(4)),
+    (28_004, FailureType::FuelPump(5)),
+    (28_005, FailureType::FuelPump(6)),
+    (28_006, FailureType::FuelPump(7)),
+    (28_007, FailureType::FuelPump(8)),
+    (28_008, FailureType::FuelPump(9)),
+    (28_009, FailureType::FuelPump(10)),
+    (28_010, FailureType::FuelPump(11)),
+    (28_011, FailureType::FuelPump(12)),
+    (28_012, FailureType::FuelPump(13)),
+    (28_013, FailureType::FuelPump(14)),
+    (28_014, FailureType::FuelPump(15)),
+    (28_015, FailureType::FuelPump(16)),
+    (28_016, FailureType::FuelPump(17)),
+    (28_017, FailureType::FuelPump(18)),
+    (28_018, FailureType::FuelPump(19)),
+    (28_019, FailureType::FuelPump(20)),
+    (28_030, FailureType::FuelValve(1)),
+    (28_031, FailureType::FuelValve(2)),
+    (28_032, FailureType::FuelValve(3)),
+    (28_033, FailureType::FuelValve(4)),
+    (28_034, FailureType::FuelValve(46)),
+    (28_035, FailureType::FuelValve(47)),
+    (28_036, FailureType::FuelValve(48)),
+    (28_037, FailureType::FuelValve(49)),
+    (28_040, FailureType::FuelTankLeak(1)),
+    (28_041, FailureType::FuelTankLeak(2)),
+    (28_042, FailureType::FuelTankLeak(3)),
+    (28_043, FailureType::FuelTankLeak(4)),
+    (28_044, FailureType::FuelTankLeak(5)),
+    (28_045, FailureType::FuelTankLeak(6)),
+    (28_046, FailureType::FuelTankLeak(7)),
+    (28_047, FailureType::FuelTankLeak(8)),
+    (28_048, FailureType::FuelTankLeak(9)),
+    (28_049, FailureType::FuelTankLeak(10)),
+    (28_050, FailureType::FuelTankLeak(11)),
+    (28_060, FailureType::FuelQuantityManagementSystem(1)),
+    (28_061, FailureType::FuelQuantityManagementSystem(2)),
+    (29_000, FailureType::ReservoirLeak(HydraulicColor::Green)),
+    (29_001, FailureType::ReservoirLeak(HydraulicColor::Yellow)),
+    (29_002, FailureType::ReservoirAirLeak(HydraulicColor::Green)),
+    (
+        29_003,
+        FailureType::ReservoirAirLeak(HydraulicColor::Yellow),
+    ),
+    (
+        29_004,
+        FailureType::ReservoirReturnLeak(HydraulicColor::Green),
+    ),
+    (
+        29_005,
+        FailureType::ReservoirReturnLeak(HydraulicColor::Yellow),
+    ),
+    (
+        29_006,
+        FailureType::ElecPumpOverheat(AirbusElectricPumpId::GreenA),
+    ),
+    (
+        29_007,
+        FailureType::ElecPumpOverheat(AirbusElectricPumpId::GreenB),
+    ),
+    (
+        29_008,
+        FailureType::ElecPumpOverheat(AirbusElectricPumpId::YellowA),
+    ),
+    (
+        29_009,
+        FailureType::ElecPumpOverheat(AirbusElectricPumpId::YellowB),
+    ),
+    (
+        29_010,
+        FailureType::EnginePumpOverheat(AirbusEngineDrivenPumpId::Edp1a),
+    ),
+    (
+        29_011,
+        FailureType::EnginePumpOverheat(AirbusEngineDrivenPumpId::Edp1b),
+    ),
+    (
+        29_012,
+        FailureType::EnginePumpOverheat(AirbusEngineDrivenPumpId::Edp2a),
+    ),
+    (
+        29_013,
+        FailureType::EnginePumpOverheat(AirbusEngineDrivenPumpId::Edp2b),
+    ),
+    (
+        29_014,
+        FailureType::EnginePumpOverheat(AirbusEngineDrivenPumpId::Edp3a),
+    ),
+    (
+        29_015,
+        FailureType::EnginePumpOverheat(AirbusEngineDrivenPumpId::Edp3b),
+    ),
+    (
+        29_016,
+        FailureType::EnginePumpOverheat(AirbusEngineDrivenPumpId::Edp4a),
+    ),
+    (
+        29_017,
+        FailureType::EnginePumpOverheat(AirbusEngineDrivenPumpId::Edp4b),
+    ),
+    (30_011, FailureType::ProbeHeater(1, ProbeType::Pitot)),
+    (30_012, FailureType::ProbeHeater(1, ProbeType::LeftStatic)),
+    (30_013, FailureType::ProbeHeater(1, ProbeType::RightStatic)),
+    (
+        30_014,
+        FailureType::ProbeHeater(1, ProbeType::TotalAirTemperature),
+    ),
+    (
+        30_015,
+        FailureType::ProbeHeater(1, ProbeType::AngleOfAttack),
+    ),
+    (30_021, FailureType::ProbeHeater(2, ProbeType::Pitot)),
+    (30_022, FailureType::ProbeHeater(2, ProbeType::LeftStatic)),
+    (30_023, FailureType::ProbeHeater(2, ProbeType::RightStatic)),
+    (
+        30_024,
+        FailureType::ProbeHeater(2, ProbeType::TotalAirTemperature),
+    ),
+    (
+        30_025,
+        FailureType::ProbeHeater(2, ProbeType::AngleOfAttack),
+    ),
+    (30_031, FailureType::ProbeHeater(3, ProbeType::Pitot)),
+    (30_032, FailureType::ProbeHeater(3, ProbeType::LeftStatic)),
+    (30_033, FailureType::ProbeHeater(3, ProbeType::RightStatic)),
+    (
+        30_035,
+        FailureType::ProbeHeater(3, ProbeType::AngleOfAttack),
+    ),
+    (30_041, FailureType::WindowHeater(1, WindowType::Windshield)),
+    (30_042, FailureType::WindowHeater(1, WindowType::SideWindow)),
+    (30_043, FailureType::WindowHeatRegulation(1)),
+    (30_051, FailureType::WindowHeater(2, WindowType::Windshield)),
+    (30_052, FailureType::WindowHeater(2, WindowType::SideWindow)),
+    (30_053, FailureType::WindowHeatRegulation(2)),
+    (30_061, FailureType::EngineAntiIceValve(1)),
+    (30_062, FailureType::EngineAntiIceValve(2)),
+    (30_063, FailureType::EngineAntiIceValve(3)),
+    (30_064, FailureType::EngineAntiIceValve(4)),
+    (32_000, FailureType::LgciuPowerSupply(LgciuId::Lgciu1)),
+    (32_001, FailureType::LgciuPowerSupply(LgciuId::Lgciu2)),
+    (32_002, FailureType::LgciuInternalError(LgciuId::Lgciu1)),
+    (32_003, FailureType::LgciuInternalError(LgciuId::Lgciu2)),
+    (
+        32_004,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::UplockGearNose1),
+    ),
+    (
+        32_005,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockGearNose2),
+    ),
+    (
+        32_006,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::UplockGearRight1),
+    ),
+    (
+        32_007,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockGearRight2),
+    ),
+    (
+        32_008,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::UplockGearLeft2),
+    ),
+    (
+        32_009,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockGearLeft1),
+    ),
+    (
+        32_010,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::UplockDoorNose1),
+    ),
+    (
+        32_011,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockDoorNose2),
+    ),
+    (
+        32_012,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::UplockDoorRight2),
+    ),
+    (
+        32_013,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockDoorRight1),
+    ),
+    (
+        32_014,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::UplockDoorLeft2),
+    ),
+    (
+        32_015,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockDoorLeft1),
+    ),
+    (
+        32_020,
+        FailureType::GearActuatorJammed(GearActuatorId::GearNose),
+    ),
+    (
+        32_021,
+        FailureType::GearActuatorJammed(GearActuatorId::GearLeft),
+    ),
+    (
+        32_022,
+        FailureType::GearActuatorJammed(GearActuatorId::GearRight),
+    ),
+    (
+        32_023,
+        FailureType::GearActuatorJammed(GearActuatorId::GearDoorNose),
+    ),
+    (
+        32_024,
+        FailureType::GearActuatorJammed(GearActuatorId::GearDoorLeft),
+    ),
+    (
+        32_025,
+        FailureType::GearActuatorJammed(GearActuatorId::GearDoorRight),
+    ),
+    (34_000, FailureType::RadioAltimeter(1)),
+    (34_001, FailureType::RadioAltimeter(2)),
+    (34_002, FailureType::RadioAltimeter(3)),
+    (34_010, FailureType::RadioAntennaInterrupted(1)),
+    (34_011, FailureType::RadioAntennaInterrupted(2)),
+    (34_012, FailureType::RadioAntennaInterrupted(3)),
+    (34_020, FailureType::RadioAntennaDirectCoupling(1)),
+    (34_021, FailureType::RadioAntennaDirectCoupling(2)),
+    (34_022, FailureType::RadioAntennaDirectCoupling(3)),
+    (34_030, FailureType::InertialReference(1)),
+    (34_031, FailureType::InertialReference(2)),
+    (34_032, FailureType::InertialReference(3)),
+    (34_040, FailureType::GpsReceiver(1)),
+    (34_041, FailureType::GpsReceiver(2)),
+    (35_000, FailureType::CrewOxygenBottleLeak(1)),
+    (35_001, FailureType::CrewOxygenBottleLeak(2)),
+    (35_010, FailureType::CrewOxygenRegulatorLowPressure),
+    (
+        36_000,
+        FailureType::BleedAirLeak(LeakDetectionZone::Wing(1)),
+    ),
+    (
+        36_001,
+        FailureType::BleedAirLeak(LeakDetectionZone::Wing(2)),
+    ),
+    (
+        36_002,
+        FailureType::BleedAirLeak(LeakDetectionZone::Wing(3)),
+    ),
+    (
+        36_003,
+        FailureType::BleedAirLeak(LeakDetectionZone::Wing(4)),
+    ),
+    (
+        36_010,
+        FailureType::BleedAirLeak(LeakDetectionZone::Pylon(1)),
+    ),
+    (
+        36_011,
+        FailureType::BleedAirLeak(LeakDetectionZone::Pylon(2)),
+    ),
+    (
+        36_012,
+        FailureType::BleedAirLeak(LeakDetectionZone::Pylon(3)),
+    ),
+    (
+        36_013,
+        FailureType::BleedAirLeak(LeakDetectionZone::Pylon(4)),
+    ),
+    (36_020, FailureType::BleedAirLeak(LeakDetectionZone::Apu)),
+    (
+        36_100,
+        FailureType::LeakDetectionLoop(LeakDetectionZone::Wing(1)),
+    ),
+    (
+        36_101,
+        FailureType::LeakDetectionLoop(LeakDetectionZone::Wing(2)),
+    ),
+    (
+        36_102,
+        FailureType::LeakDetectionLoop(LeakDetectionZone::Wing(3)),
+    ),
+    (
+        36_103,
+        FailureType::LeakDetectionLoop(LeakDetectionZone::Wing(4)),
+    ),
+    (
+        36_110,
+        FailureType::LeakDetectionLoop(LeakDetectionZone::Pylon(1)),
+    ),
+    (
+        36_111,
+        FailureType::LeakDetectionLoop(LeakDetectionZone::Pylon(2)),
+    ),
+    (
+        36_112,
+        FailureType::LeakDetectionLoop(LeakDetectionZone::Pylon(3)),
+    ),
+    (
+        36_113,
+        FailureType::LeakDetectionLoop(LeakDetectionZone::Pylon(4)),
+    ),
+    (
+        36_120,
+        FailureType::LeakDetectionLoop(LeakDetectionZone::Apu),
+    ),
+    (
+        36_200,
+        FailureType::EngineBleedValveJammedOpen(1, EngineBleedValveId::HighPressure),
+    ),
+    (
+        36_201,
+        FailureType::EngineBleedValveJammedOpen(2, EngineBleedValveId::HighPressure),
+    ),
+    (
+        36_202,
+        FailureType::EngineBleedValveJammedOpen(3, EngineBleedValveId::HighPressure),
+    ),
+    (
+        36_203,
+        FailureType::EngineBleedValveJammedOpen(4, EngineBleedValveId::HighPressure),
+    ),
+    (
+        36_210,
+        FailureType::EngineBleedValveJammedClosed(1, EngineBleedValveId::HighPressure),
+    ),
+    (
+        36_211,
+        FailureType::EngineBleedValveJammedClosed(2, EngineBleedValveId::HighPressure),
+    ),
+    (
+        36_212,
+        FailureType::EngineBleedValveJammedClosed(3, EngineBleedValveId::HighPressure),
+    ),
+    (
+        36_213,
+        FailureType::EngineBleedValveJammedClosed(4, EngineBleedValveId::HighPressure),
+    ),
+    (
+        36_220,
+        FailureType::EngineBleedValveJammedOpen(1, EngineBleedValveId::PressureRegulating),
+    ),
+    (
+        36_221,
+        FailureType::EngineBleedValveJammedOpen(2, EngineBleedValveId::PressureRegulating),
+    ),
+    (
+        36_222,
+        FailureType::EngineBleedValveJammedOpen(3, EngineBleedValveId::PressureRegulating),
+    ),
+    (
+        36_223,
+        FailureType::EngineBleedValveJammedOpen(4, EngineBleedValveId::PressureRegulating),
+    ),
+    (
+        36_230,
+        FailureType::EngineBleedValveJammedClosed(1, EngineBleedValveId::PressureRegulating),
+    ),
+    (
+        36_231,
+        FailureType::EngineBleedValveJammedClosed(2, EngineBleedValveId::PressureRegulating),
+    ),
+    (
+        36_232,
+        FailureType::EngineBleedValveJammedClosed(3, EngineBleedValveId::PressureRegulating),
+    ),
+    (
+        36_233,
+        FailureType::EngineBleedValveJammedClosed(4, EngineBleedValveId::PressureRegulating),
+    ),
+    (
+        36_240,
+        FailureType::EngineBleedValveJammedOpen(1, EngineBleedValveId::FanAir),
+    ),
+    (
+        36_241,
+        FailureType::EngineBleedValveJammedOpen(2, EngineBleedValveId::FanAir),
+    ),
+    (
+        36_242,
+        FailureType::EngineBleedValveJammedOpen(3, EngineBleedValveId::FanAir),
+    ),
+    (
+        36_243,
+        FailureType::EngineBleedValveJammedOpen(4, EngineBleedValveId::FanAir),
+    ),
+    (
+        36_250,
+        FailureType::EngineBleedValveJammedClosed(1, EngineBleedValveId::FanAir),
+    ),
+    (
+        36_251,
+        FailureType::EngineBleedValveJammedClosed(2, EngineBleedValveId::FanAir),
+    ),
+    (
+        36_252,
+        FailureType::EngineBleedValveJammedClosed(3, EngineBleedValveId::FanAir),
+    ),
+    (
+        36_253,
+        FailureType::EngineBleedValveJammedClosed(4, EngineBleedValveId::FanAir),
+    ),
+    (36_300, FailureType::PrecoolerBlockage(1)),
+    (36_301, FailureType::PrecoolerBlockage(2)),
+    (36_302, FailureType::PrecoolerBlockage(3)),
+    (36_303, FailureType::PrecoolerBlockage(4)),
+    (36_310, FailureType::EngineBleedOverpressure(1)),
+    (36_311, FailureType::EngineBleedOverpressure(2)),
+    (36_312, FailureType::EngineBleedOverpressure(3)),
+    (36_313, FailureType::EngineBleedOverpressure(4)),
+    (36_320, FailureType::EngineBleedOvertemperature(1)),
+    (36_321, FailureType::EngineBleedOvertemperature(2)),
+    (36_322, FailureType::EngineBleedOvertemperature(3)),
+    (36_323, FailureType::EngineBleedOvertemperature(4)),
+    (36_400, FailureType::BleedMonitoringComputer(1)),
+    (36_401, FailureType::BleedMonitoringComputer(2)),
+    (36_402, FailureType::BleedMonitoringComputer(3)),
+    (36_403, FailureType::BleedMonitoringComputer(4)),
+    (49_000, FailureType::ApuStartMotor),
+    (49_010, FailureType::ApuFuelControlUnit),
+    (49_020, FailureType::ApuAirIntakeFlapJammed),
+    (49_030, FailureType::ApuEgtSensor),
+    (49_040, FailureType::ApuSpeedSensor(1)),
+    (49_041, FailureType::ApuSpeedSensor(2)),
+    (49_050, FailureType::ApuInletPressureSensor),
+];
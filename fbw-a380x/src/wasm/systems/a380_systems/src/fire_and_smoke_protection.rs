@@ -1,26 +1,26 @@
-use std::time::Duration;
-
 use systems::{
-    accept_iterable,
-    failures::{Failure, FailureType},
-    overhead::{FirePushButton, MomentaryPushButton},
-    shared::{
-        arinc429::{Arinc429Word, SignStatus},
-        DelayedTrueLogicGate, ElectricalBusType, ElectricalBuses, EngineFirePushButtons,
-        FireDetectionLoopID, FireDetectionZone, LgciuWeightOnWheels,
-    },
-    simulation::{
-        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
-        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+    apu::AuxiliaryPowerUnitFireOverheadPanel,
+    fire_protection::{
+        ExtinguishingAgentBottle, FireDetectionUnit, FireExtinguishingSystem, FireTestPushButton,
+        SetOnFireModule,
     },
+    shared::{ElectricalBusType, EngineFirePushButtons, FireDetectionZone, LgciuWeightOnWheels},
+    simulation::{InitContext, SimulationElement, SimulationElementVisitor, UpdateContext},
 };
 
-use std::iter::zip;
+const FIRE_DETECTION_ZONES: [FireDetectionZone; 6] = [
+    FireDetectionZone::Engine(1),
+    FireDetectionZone::Engine(2),
+    FireDetectionZone::Engine(3),
+    FireDetectionZone::Engine(4),
+    FireDetectionZone::Apu,
+    FireDetectionZone::Mlg,
+];
 
 pub(super) struct A380FireAndSmokeProtection {
     a380_fire_protection_system: FireProtectionSystem,
     // a380_smoke_detection_function
-    set_zone_on_fire: SetOnFireModule,
+    set_zone_on_fire: SetOnFireModule<6>,
 }
 
 impl A380FireAndSmokeProtection {
@@ -28,7 +28,7 @@ impl A380FireAndSmokeProtection {
         Self {
             a380_fire_protection_system: FireProtectionSystem::new(context),
 
-            set_zone_on_fire: SetOnFireModule::new(context),
+            set_zone_on_fire: SetOnFireModule::new(context, FIRE_DETECTION_ZONES),
         }
     }
 
@@ -36,13 +36,18 @@ impl A380FireAndSmokeProtection {
         &mut self,
         context: &UpdateContext,
         engine_fire_push_buttons: &impl EngineFirePushButtons,
+        apu_fire_overhead: &AuxiliaryPowerUnitFireOverheadPanel,
         lgciu: [&impl LgciuWeightOnWheels; 2],
     ) {
-        self.a380_fire_protection_system
-            .update(context, engine_fire_push_buttons, lgciu);
+        self.a380_fire_protection_system.update(
+            context,
+            engine_fire_push_buttons,
+            apu_fire_overhead,
+            lgciu,
+        );
 
         self.set_zone_on_fire
-            .update(self.a380_fire_protection_system.bottle_discharge());
+            .update(&self.a380_fire_protection_system.fire_extinguishing_system);
     }
 
     pub fn apu_fire_on_ground(&self) -> bool {
@@ -60,44 +65,72 @@ impl SimulationElement for A380FireAndSmokeProtection {
 }
 
 struct FireProtectionSystem {
-    fire_detection_unit: FireDetectionUnit,
-    fire_extinguishing_system: FireExtinguishingSystem,
+    fire_detection_unit: FireDetectionUnit<6>,
+    fire_extinguishing_system: FireExtinguishingSystem<9>,
 
-    fire_test_pushbutton_id: VariableIdentifier,
-    fire_test_pushbutton_is_pressed: bool,
-    fire_test_pushbutton_signal: DelayedTrueLogicGate,
+    fire_test_pushbutton: FireTestPushButton,
 }
 
 impl FireProtectionSystem {
-    const DELAY_FIRE_TEST_MILLIS: Duration = Duration::from_millis(500);
-
     fn new(context: &mut InitContext) -> Self {
         Self {
-            fire_detection_unit: FireDetectionUnit::new(context),
-            fire_extinguishing_system: FireExtinguishingSystem::new(context),
+            fire_detection_unit: FireDetectionUnit::new(
+                context,
+                FIRE_DETECTION_ZONES,
+                [
+                    ElectricalBusType::DirectCurrentEssential,
+                    ElectricalBusType::DirectCurrent(2),
+                ],
+            ),
+            fire_extinguishing_system: Self::new_fire_extinguishing_system(context),
 
-            fire_test_pushbutton_id: context
-                .get_identifier("OVHD_FIRE_TEST_PB_IS_PRESSED".to_owned()),
-            fire_test_pushbutton_is_pressed: false,
-            fire_test_pushbutton_signal: DelayedTrueLogicGate::new(Self::DELAY_FIRE_TEST_MILLIS),
+            fire_test_pushbutton: FireTestPushButton::new(context, "OVHD_FIRE_TEST_PB_IS_PRESSED"),
         }
     }
 
+    /// Two bottles for ENG1-4 and one for the APU
+    fn new_fire_extinguishing_system(context: &mut InitContext) -> FireExtinguishingSystem<9> {
+        let powered_by = [
+            ElectricalBusType::DirectCurrentHot(1),
+            ElectricalBusType::DirectCurrentEssential,
+        ];
+        let engine_bottle = |context: &mut InitContext, bottle_number: usize, engine_number| {
+            ExtinguishingAgentBottle::new(
+                context,
+                &format!("{}_ENG_{}", bottle_number, engine_number),
+                FireDetectionZone::Engine(engine_number),
+                powered_by,
+            )
+        };
+
+        FireExtinguishingSystem::new([
+            engine_bottle(context, 1, 1),
+            engine_bottle(context, 2, 1),
+            engine_bottle(context, 1, 2),
+            engine_bottle(context, 2, 2),
+            engine_bottle(context, 1, 3),
+            engine_bottle(context, 2, 3),
+            engine_bottle(context, 1, 4),
+            engine_bottle(context, 2, 4),
+            ExtinguishingAgentBottle::new(context, "1_APU_1", FireDetectionZone::Apu, powered_by),
+        ])
+    }
+
     fn update(
         &mut self,
         context: &UpdateContext,
         engine_fire_push_buttons: &impl EngineFirePushButtons,
+        apu_fire_overhead: &AuxiliaryPowerUnitFireOverheadPanel,
         lgciu: [&impl LgciuWeightOnWheels; 2],
     ) {
-        // We add a delay between button press and response based on references
-        self.fire_test_pushbutton_signal
-            .update(context, self.fire_test_pushbutton_is_pressed);
+        self.fire_test_pushbutton.update(context);
         self.fire_detection_unit
-            .update(context, self.fire_test_pushbutton_signal.output(), lgciu);
+            .update(context, &self.fire_test_pushbutton, lgciu);
         self.fire_extinguishing_system.update(
             context,
             engine_fire_push_buttons,
-            self.fire_test_pushbutton_signal.output(),
+            apu_fire_overhead,
+            &self.fire_test_pushbutton,
             self.fire_detection_unit.should_extinguish_apu_fire(),
         )
     }
@@ -105,18 +138,11 @@ impl FireProtectionSystem {
     fn apu_fire_on_ground(&self) -> bool {
         self.fire_detection_unit.apu_fire_on_ground()
     }
-
-    fn bottle_discharge(&self) -> [bool; 9] {
-        self.fire_extinguishing_system.bottle_discharge()
-    }
 }
 
 impl SimulationElement for FireProtectionSystem {
-    fn read(&mut self, reader: &mut SimulatorReader) {
-        self.fire_test_pushbutton_is_pressed = reader.read(&self.fire_test_pushbutton_id);
-    }
-
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.fire_test_pushbutton.accept(visitor);
         self.fire_detection_unit.accept(visitor);
         self.fire_extinguishing_system.accept(visitor);
 
@@ -124,694 +150,14 @@ impl SimulationElement for FireProtectionSystem {
     }
 }
 
-struct FireDetectionUnit {
-    fire_detection_loop: [FireDetectionLoop; 2],
-
-    fire_detected_id: [VariableIdentifier; 6],
-
-    fire_detected: [bool; 6],
-    fire_detection_zones: [FireDetectionZone; 6],
-    interval_between_loop_failures: [Duration; 6],
-    apu_fire_on_ground: bool,
-    should_extinguish_apu_fire: DelayedTrueLogicGate,
-
-    // The FDU sends discrete signals to the overhead panel and arinc signals to the FWS
-    // Fixme: We assume a discrete word is sent, validate with references
-    discrete_word_id: VariableIdentifier,
-    discrete_word: Arinc429Word<u32>,
-}
-
-impl FireDetectionUnit {
-    const DELAY_APU_FIRE_EXTINGUISHING: Duration = Duration::from_secs(10);
-
-    fn new(context: &mut InitContext) -> Self {
-        let fire_detection_zones = [
-            FireDetectionZone::Engine(1),
-            FireDetectionZone::Engine(2),
-            FireDetectionZone::Engine(3),
-            FireDetectionZone::Engine(4),
-            FireDetectionZone::Apu,
-            FireDetectionZone::Mlg,
-        ];
-
-        Self {
-            fire_detection_loop: [
-                FireDetectionLoop::new(
-                    context,
-                    FireDetectionLoopID::A,
-                    &fire_detection_zones,
-                    ElectricalBusType::DirectCurrentEssential,
-                ),
-                FireDetectionLoop::new(
-                    context,
-                    FireDetectionLoopID::B,
-                    &fire_detection_zones,
-                    ElectricalBusType::DirectCurrent(2),
-                ),
-            ],
-
-            fire_detected_id: fire_detection_zones.map(|zone| Self::init_identifier(context, zone)),
-
-            fire_detected: [false; 6],
-            fire_detection_zones,
-            interval_between_loop_failures: [Duration::ZERO; 6],
-            apu_fire_on_ground: false,
-            should_extinguish_apu_fire: DelayedTrueLogicGate::new(
-                Self::DELAY_APU_FIRE_EXTINGUISHING,
-            ),
-
-            discrete_word_id: context.get_identifier("FIRE_FDU_DISCRETE_WORD".to_owned()),
-            discrete_word: Arinc429Word::new(0, SignStatus::NoComputedData),
-        }
-    }
-
-    fn init_identifier(
-        context: &mut InitContext,
-        zone_id: FireDetectionZone,
-    ) -> VariableIdentifier {
-        if matches!(zone_id, FireDetectionZone::Engine(_)) {
-            context.get_identifier(format!("FIRE_DETECTED_ENG{}", zone_id))
-        } else {
-            context.get_identifier(format!("FIRE_DETECTED_{}", zone_id))
-        }
-    }
-
-    fn update(
-        &mut self,
-        context: &UpdateContext,
-        fire_test_pushbutton_is_pressed: bool,
-        lgciu: [&impl LgciuWeightOnWheels; 2],
-    ) {
-        self.interval_between_loop_failures = self.calculate_interval_between_failures(context);
-
-        self.fire_detected = self.fire_detection_determination(fire_test_pushbutton_is_pressed);
-
-        self.fire_detection_loop
-            .iter_mut()
-            .for_each(|l| l.update_was_powered());
-
-        // If a fire is detected in the APU while the aircraft is on the ground, the extinguishim system is automatically activated after a delay
-        self.apu_fire_on_ground = self.fire_detected[4]
-            && !fire_test_pushbutton_is_pressed
-            && lgciu.iter().all(|a| a.left_and_right_gear_compressed(true));
-        self.should_extinguish_apu_fire
-            .update(context, self.apu_fire_on_ground);
-
-        self.update_discrete_word();
-    }
-
-    fn fire_detection_determination(&self, fire_test_pb: bool) -> [bool; 6] {
-        let mut fire_detected = [false; 6];
-        for ((&zone, &interval_between_loop_failures), fire_detected) in self
-            .fire_detection_zones
-            .iter()
-            .zip(&self.interval_between_loop_failures)
-            .zip(&mut fire_detected)
-        {
-            *fire_detected = (self.fire_detection_loop[0]
-                .fire_detected_in_loop(zone, fire_test_pb)
-                && self.fire_detection_loop[1].fire_detected_in_loop(zone, fire_test_pb))
-                || (self
-                    .fire_detection_loop
-                    .iter()
-                    .any(|l| l.fire_detected_in_loop(zone, fire_test_pb))
-                    && self
-                        .fire_detection_loop
-                        .iter()
-                        .any(|l| l.loop_has_failed(zone)))
-                || (self
-                    .fire_detection_loop
-                    .iter()
-                    .all(|l| l.loop_has_failed(zone))
-                    && interval_between_loop_failures < Duration::from_secs(5)
-                    && zone != FireDetectionZone::Mlg);
-        }
-        fire_detected
-    }
-
-    fn calculate_interval_between_failures(&self, context: &UpdateContext) -> [Duration; 6] {
-        let mut interval = [Duration::ZERO; 6];
-        for ((&zone, &interval_between_loop_failures), interval) in self
-            .fire_detection_zones
-            .iter()
-            .zip(&self.interval_between_loop_failures)
-            .zip(&mut interval)
-        {
-            *interval = if self
-                .fire_detection_loop
-                .iter()
-                .all(|l| !l.loop_has_failed(zone))
-            {
-                Duration::ZERO
-            } else if self
-                .fire_detection_loop
-                .iter()
-                .all(|l| l.loop_has_failed(zone))
-            {
-                interval_between_loop_failures
-            } else {
-                interval_between_loop_failures + context.delta()
-            }
-        }
-        interval
-    }
-
-    fn should_extinguish_apu_fire(&self) -> bool {
-        self.should_extinguish_apu_fire.output()
-    }
-
-    fn apu_fire_on_ground(&self) -> bool {
-        self.apu_fire_on_ground
-    }
-
-    fn update_discrete_word(&mut self) {
-        // TODO: Add electrical supply for FDU, when not powered it should return NCD
-        self.discrete_word = Arinc429Word::new(0, SignStatus::NormalOperation);
-
-        // Fixme: The bit order is assumed as no references
-        self.discrete_word.set_bit(11, self.fire_detected[0]); // FIRE ENG 1
-        self.discrete_word.set_bit(12, self.fire_detected[1]); // FIRE ENG 2
-        self.discrete_word.set_bit(13, self.fire_detected[2]); // FIRE ENG 3
-        self.discrete_word.set_bit(14, self.fire_detected[3]); // FIRE ENG 4
-        self.discrete_word.set_bit(15, self.fire_detected[4]); // FIRE APU
-        self.discrete_word.set_bit(16, self.fire_detected[5]); // FIRE MLG
-        self.discrete_word.set_bit(
-            18,
-            self.fire_detection_loop[0].loop_has_failed(FireDetectionZone::Engine(1)),
-        ); // ENG 1 LOOP A has failed
-        self.discrete_word.set_bit(
-            19,
-            self.fire_detection_loop[1].loop_has_failed(FireDetectionZone::Engine(1)),
-        ); // ENG 1 LOOP B has failed
-        self.discrete_word.set_bit(
-            20,
-            self.fire_detection_loop[0].loop_has_failed(FireDetectionZone::Engine(2)),
-        ); // ENG 2 LOOP A has failed
-        self.discrete_word.set_bit(
-            21,
-            self.fire_detection_loop[1].loop_has_failed(FireDetectionZone::Engine(2)),
-        ); // ENG 2 LOOP B has failed
-        self.discrete_word.set_bit(
-            22,
-            self.fire_detection_loop[0].loop_has_failed(FireDetectionZone::Engine(3)),
-        ); // ENG 3 LOOP A has failed
-        self.discrete_word.set_bit(
-            23,
-            self.fire_detection_loop[1].loop_has_failed(FireDetectionZone::Engine(3)),
-        ); // ENG 3 LOOP B has failed
-        self.discrete_word.set_bit(
-            24,
-            self.fire_detection_loop[0].loop_has_failed(FireDetectionZone::Engine(4)),
-        ); // ENG 4 LOOP A has failed
-        self.discrete_word.set_bit(
-            25,
-            self.fire_detection_loop[1].loop_has_failed(FireDetectionZone::Engine(4)),
-        ); // ENG 4 LOOP B has failed
-        self.discrete_word.set_bit(
-            26,
-            self.fire_detection_loop[0].loop_has_failed(FireDetectionZone::Apu),
-        ); // APU LOOP A has failed
-        self.discrete_word.set_bit(
-            27,
-            self.fire_detection_loop[1].loop_has_failed(FireDetectionZone::Apu),
-        ); // APU LOOP B has failed
-        self.discrete_word.set_bit(
-            28,
-            self.fire_detection_loop[0].loop_has_failed(FireDetectionZone::Mlg),
-        ); // MLG LOOP A has failed
-        self.discrete_word.set_bit(
-            29,
-            self.fire_detection_loop[1].loop_has_failed(FireDetectionZone::Mlg),
-        ); // MLG LOOP B has failed
-    }
-}
-
-impl SimulationElement for FireDetectionUnit {
-    fn write(&self, writer: &mut SimulatorWriter) {
-        for (id, fire_detected) in self.fire_detected_id.iter().zip(self.fire_detected) {
-            writer.write(id, fire_detected);
-        }
-        writer.write(&self.discrete_word_id, self.discrete_word);
-    }
-
-    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
-        accept_iterable!(self.fire_detection_loop, visitor);
-
-        visitor.visit(self);
-    }
-}
-
-struct FireDetectionLoop {
-    loop_id: FireDetectionLoopID,
-    powered_by: ElectricalBusType,
-    is_powered: bool,
-    was_powered_before: bool,
-    failures: [Failure; 6],
-
-    fire_detectors: [FireDetector; 6],
-}
-
-impl FireDetectionLoop {
-    fn new(
-        context: &mut InitContext,
-        loop_id: FireDetectionLoopID,
-        fire_detection_zones: &[FireDetectionZone; 6],
-        powered_by: ElectricalBusType,
-    ) -> Self {
-        Self {
-            loop_id,
-            powered_by,
-            is_powered: false,
-            was_powered_before: false,
-            failures: fire_detection_zones
-                .map(|zone| Failure::new(FailureType::FireDetectionLoop(loop_id, zone))),
-
-            fire_detectors: fire_detection_zones.map(|zone| FireDetector::new(context, zone)),
-        }
-    }
-
-    fn fire_detected_in_loop(
-        &self,
-        fire_detection_zone: FireDetectionZone,
-        fire_test_pushbutton_is_pressed: bool,
-    ) -> bool {
-        let failure = self
-            .failures
-            .iter()
-            .find(|&f| {
-                f.failure_type()
-                    == FailureType::FireDetectionLoop(self.loop_id, fire_detection_zone)
-            })
-            .unwrap();
-
-        !failure.is_active()
-            && self.is_powered
-            && (self
-                .fire_detectors
-                .iter()
-                .find(|detector| fire_detection_zone == detector.zone_id())
-                .unwrap()
-                .fire_detected()
-                || fire_test_pushbutton_is_pressed)
-    }
-
-    fn loop_has_failed(&self, fire_detection_zone: FireDetectionZone) -> bool {
-        let failure = self
-            .failures
-            .iter()
-            .find(|&f| {
-                f.failure_type()
-                    == FailureType::FireDetectionLoop(self.loop_id, fire_detection_zone)
-            })
-            .unwrap();
-
-        failure.is_active() || (!self.is_powered && self.was_powered_before)
-    }
-
-    /// This is to avoid a fire detection on initial load
-    fn update_was_powered(&mut self) {
-        self.was_powered_before = self.is_powered
-    }
-}
-
-impl SimulationElement for FireDetectionLoop {
-    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
-        accept_iterable!(self.failures, visitor);
-        accept_iterable!(self.fire_detectors, visitor);
-        visitor.visit(self);
-    }
-
-    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
-        self.is_powered = buses.is_powered(self.powered_by);
-    }
-}
-
-// Electro-pneumatic fire detectors. There are multiple detectors in 3 fire zones per engine, one per pylon, one in the APU and one in the MLG
-// For simplicity here we simulate just one detection zone per engine, when we have deep engine simulation we can modify this accordingly
-struct FireDetector {
-    zone_id: FireDetectionZone,
-
-    fire_detection_id: VariableIdentifier,
-    fire_detected: bool,
-}
-
-impl FireDetector {
-    const ENGINE_ON_FIRE: &'static str = "ENG ON FIRE:";
-
-    fn new(context: &mut InitContext, fire_zone_id: FireDetectionZone) -> Self {
-        Self {
-            zone_id: fire_zone_id,
-
-            fire_detection_id: Self::init_identifier(context, fire_zone_id),
-            fire_detected: false,
-        }
-    }
-
-    fn init_identifier(
-        context: &mut InitContext,
-        zone_id: FireDetectionZone,
-    ) -> VariableIdentifier {
-        if matches!(zone_id, FireDetectionZone::Engine(_)) {
-            context.get_identifier(format!("{}{}", Self::ENGINE_ON_FIRE, zone_id))
-        } else {
-            context.get_identifier(format!("{}_ON_FIRE", zone_id))
-        }
-    }
-
-    fn zone_id(&self) -> FireDetectionZone {
-        self.zone_id
-    }
-
-    fn fire_detected(&self) -> bool {
-        self.fire_detected
-    }
-}
-
-impl SimulationElement for FireDetector {
-    fn read(&mut self, reader: &mut SimulatorReader) {
-        self.fire_detected = reader.read(&self.fire_detection_id);
-    }
-}
-
-struct FireExtinguishingSystem {
-    fire_extinguishing_bottles: [ExtinguishingAgentBottle; 9],
-
-    apu_fire_push_button: FirePushButton,
-}
-
-impl FireExtinguishingSystem {
-    fn new(context: &mut InitContext) -> Self {
-        let powered_by = [
-            ElectricalBusType::DirectCurrentHot(1),
-            ElectricalBusType::DirectCurrentEssential,
-        ];
-        Self {
-            fire_extinguishing_bottles: [
-                ExtinguishingAgentBottle::new(context, "1_ENG_1", powered_by),
-                ExtinguishingAgentBottle::new(context, "2_ENG_1", powered_by),
-                ExtinguishingAgentBottle::new(context, "1_ENG_2", powered_by),
-                ExtinguishingAgentBottle::new(context, "2_ENG_2", powered_by),
-                ExtinguishingAgentBottle::new(context, "1_ENG_3", powered_by),
-                ExtinguishingAgentBottle::new(context, "2_ENG_3", powered_by),
-                ExtinguishingAgentBottle::new(context, "1_ENG_4", powered_by),
-                ExtinguishingAgentBottle::new(context, "2_ENG_4", powered_by),
-                ExtinguishingAgentBottle::new(context, "1_APU_1", powered_by),
-            ],
-
-            apu_fire_push_button: FirePushButton::new(context, "APU"),
-        }
-    }
-
-    fn update(
-        &mut self,
-        context: &UpdateContext,
-        engine_fire_push_buttons: &impl EngineFirePushButtons,
-        fire_test_pushbutton_is_pressed: bool,
-        should_extinguish_apu_fire: bool,
-    ) {
-        self.fire_extinguishing_bottles[..2]
-            .iter_mut()
-            .for_each(|bottle| {
-                bottle.update(
-                    context,
-                    engine_fire_push_buttons.is_released(1),
-                    fire_test_pushbutton_is_pressed,
-                    None,
-                )
-            });
-        self.fire_extinguishing_bottles[2..4]
-            .iter_mut()
-            .for_each(|bottle| {
-                bottle.update(
-                    context,
-                    engine_fire_push_buttons.is_released(2),
-                    fire_test_pushbutton_is_pressed,
-                    None,
-                )
-            });
-        self.fire_extinguishing_bottles[4..6]
-            .iter_mut()
-            .for_each(|bottle| {
-                bottle.update(
-                    context,
-                    engine_fire_push_buttons.is_released(3),
-                    fire_test_pushbutton_is_pressed,
-                    None,
-                )
-            });
-        self.fire_extinguishing_bottles[6..8]
-            .iter_mut()
-            .for_each(|bottle| {
-                bottle.update(
-                    context,
-                    engine_fire_push_buttons.is_released(4),
-                    fire_test_pushbutton_is_pressed,
-                    None,
-                )
-            });
-        self.fire_extinguishing_bottles[8].update(
-            context,
-            self.apu_fire_push_button.is_released(),
-            fire_test_pushbutton_is_pressed,
-            Some(should_extinguish_apu_fire),
-        );
-    }
-
-    /// The array of 9 bottles represents two bottles for ENG1-4 and one for the APU
-    fn bottle_discharge(&self) -> [bool; 9] {
-        self.fire_extinguishing_bottles
-            .iter()
-            .map(|bottle| bottle.bottle_discharge())
-            .collect::<Vec<bool>>()
-            .try_into()
-            .unwrap_or_else(|v: Vec<bool>| {
-                panic!("Expected a Vec of length {} but it was {}", 9, v.len())
-            })
-    }
-}
-
-impl SimulationElement for FireExtinguishingSystem {
-    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
-        self.apu_fire_push_button.accept(visitor);
-        accept_iterable!(self.fire_extinguishing_bottles, visitor);
-
-        visitor.visit(self);
-    }
-}
-
-/// This struct represents the physical bottle of Halon 1301, and its possible states of armed, disarmed, full and empty
-/// There are two squibs per bottle, but for simplicity we simulate a "single" squib with two possible power sources
-struct ExtinguishingAgentBottle {
-    squib_armed_id: VariableIdentifier,
-    bottle_discharged_id: VariableIdentifier,
-    agent_pb: MomentaryPushButton,
-
-    squib_is_armed: bool,
-    bottle_is_discharged: bool,
-    system_test: bool,
-
-    timer: Duration,
-    powered_by: [ElectricalBusType; 2],
-    is_powered: bool,
-}
-
-impl ExtinguishingAgentBottle {
-    fn new(context: &mut InitContext, id: &str, powered_by: [ElectricalBusType; 2]) -> Self {
-        Self {
-            squib_armed_id: context.get_identifier(format!("FIRE_SQUIB_{}_IS_ARMED", id)),
-            bottle_discharged_id: context
-                .get_identifier(format!("FIRE_SQUIB_{}_IS_DISCHARGED", id)),
-            agent_pb: MomentaryPushButton::new(context, &format!("FIRE_AGENT_{}", id)),
-
-            squib_is_armed: false,
-            bottle_is_discharged: false,
-            system_test: false,
-
-            timer: Duration::ZERO,
-            powered_by,
-            is_powered: false,
-        }
-    }
-
-    fn update(
-        &mut self,
-        context: &UpdateContext,
-        engine_fire_push_button_is_pressed: bool,
-        fire_test_pushbutton_is_pressed: bool,
-        should_extinguish_fire: Option<bool>,
-    ) {
-        self.system_test = fire_test_pushbutton_is_pressed && self.is_powered;
-        self.squib_is_armed = self.is_powered && engine_fire_push_button_is_pressed;
-        if self.is_powered
-            && ((self.squib_is_armed || should_extinguish_fire.unwrap_or(false))
-                && self.timer >= Duration::from_secs(1))
-        {
-            // Once the bottle is discharged, it can't be recharged
-            self.bottle_is_discharged = true
-        } else if self.is_powered
-            && (((self.squib_is_armed)
-                && (self.agent_pb.is_pressed() || self.timer > Duration::ZERO))
-                || should_extinguish_fire.unwrap_or(false))
-            && self.timer <= Duration::from_secs(1)
-        {
-            self.timer += context.delta()
-        } else {
-            self.timer = Duration::ZERO
-        };
-    }
-
-    fn bottle_discharge(&self) -> bool {
-        self.bottle_is_discharged
-    }
-}
-
-impl SimulationElement for ExtinguishingAgentBottle {
-    fn write(&self, writer: &mut SimulatorWriter) {
-        writer.write(
-            &self.squib_armed_id,
-            self.squib_is_armed || self.system_test,
-        );
-        writer.write(
-            &self.bottle_discharged_id,
-            self.bottle_is_discharged || self.system_test,
-        );
-    }
-
-    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
-        self.agent_pb.accept(visitor);
-
-        visitor.visit(self);
-    }
-
-    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
-        self.is_powered = self.powered_by.iter().any(|&p| buses.is_powered(p));
-    }
-}
-
-/// Small module that sets each zone on fire when the failure is triggered. This is independent to the system implementation.
-struct SetOnFireModule {
-    fire_id: [VariableIdentifier; 6],
-
-    fire: [Failure; 6],
-    should_set_zone_on_fire: [bool; 6],
-    should_extinguish_zone: [bool; 6],
-    // We use this to avoid having a previously discharged bottle extinguish a fire
-    bottle_already_discharged: [bool; 9],
-    // We use this to know when to cancel the fire command when the failure is resolved
-    was_on_fire: [bool; 6],
-}
-
-impl SetOnFireModule {
-    fn new(context: &mut InitContext) -> Self {
-        Self {
-            fire_id: [
-                context.get_identifier(format!("ENG_{}_ON_FIRE", 1)),
-                context.get_identifier(format!("ENG_{}_ON_FIRE", 2)),
-                context.get_identifier(format!("ENG_{}_ON_FIRE", 3)),
-                context.get_identifier(format!("ENG_{}_ON_FIRE", 4)),
-                context.get_identifier("APU_ON_FIRE".to_owned()),
-                context.get_identifier("MLG_ON_FIRE".to_owned()),
-            ],
-
-            fire: [
-                Failure::new(FailureType::SetOnFire(FireDetectionZone::Engine(1))),
-                Failure::new(FailureType::SetOnFire(FireDetectionZone::Engine(2))),
-                Failure::new(FailureType::SetOnFire(FireDetectionZone::Engine(3))),
-                Failure::new(FailureType::SetOnFire(FireDetectionZone::Engine(4))),
-                Failure::new(FailureType::SetOnFire(FireDetectionZone::Apu)),
-                Failure::new(FailureType::SetOnFire(FireDetectionZone::Mlg)),
-            ],
-            should_set_zone_on_fire: [false; 6],
-            should_extinguish_zone: [false; 6],
-            bottle_already_discharged: [false; 9],
-            was_on_fire: [false; 6],
-        }
-    }
-
-    fn update(&mut self, bottle_discharge: [bool; 9]) {
-        for id in 0..6 {
-            self.should_set_zone_on_fire[id] = self.fire[id].is_active()
-                && !self.should_set_zone_on_fire[id]
-                && !self.was_on_fire[id]
-        }
-
-        self.should_extinguish_zone = self.zone_extinguishing_determination(bottle_discharge);
-        self.bottle_already_discharged = bottle_discharge;
-        self.was_on_fire = self
-            .fire
-            .iter()
-            .map(|f| f.is_active())
-            .collect::<Vec<bool>>()
-            .try_into()
-            .unwrap_or_else(|v: Vec<bool>| {
-                panic!("Expected a Vec of length {} but it was {}", 6, v.len())
-            });
-    }
-
-    /// We check any "new" bottle discharges and then add a random factor on whether it should extinguish a fire
-    /// We also use this function to "extinguish" a fire if the user deselects the failure
-    fn zone_extinguishing_determination(&self, bottle_discharge: [bool; 9]) -> [bool; 6] {
-        [
-            (zip(
-                bottle_discharge[..2].iter(),
-                self.bottle_already_discharged[..2].iter(),
-            )
-            .any(|(discharge, already_discharged)| *discharge && !already_discharged)
-                && rand::random())
-                || (self.was_on_fire[0] && !self.fire[0].is_active()),
-            (zip(
-                bottle_discharge[2..4].iter(),
-                self.bottle_already_discharged[2..4].iter(),
-            )
-            .any(|(discharge, already_discharged)| *discharge && !already_discharged)
-                && rand::random())
-                || (self.was_on_fire[1] && !self.fire[1].is_active()),
-            (zip(
-                bottle_discharge[4..6].iter(),
-                self.bottle_already_discharged[4..6].iter(),
-            )
-            .any(|(discharge, already_discharged)| *discharge && !already_discharged)
-                && rand::random())
-                || (self.was_on_fire[2] && !self.fire[2].is_active()),
-            (zip(
-                bottle_discharge[6..8].iter(),
-                self.bottle_already_discharged[6..8].iter(),
-            )
-            .any(|(discharge, already_discharged)| *discharge && !already_discharged)
-                && rand::random())
-                || (self.was_on_fire[3] && !self.fire[3].is_active()),
-            (bottle_discharge[8] && !self.bottle_already_discharged[8] && rand::random())
-                || (self.was_on_fire[4] && !self.fire[4].is_active()),
-            // MLG does not have a fire extinguishing system
-            false,
-        ]
-    }
-}
-
-impl SimulationElement for SetOnFireModule {
-    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
-        accept_iterable!(self.fire, visitor);
-
-        visitor.visit(self);
-    }
-
-    fn write(&self, writer: &mut SimulatorWriter) {
-        for (id, zone) in self.fire_id.iter().enumerate() {
-            if self.should_set_zone_on_fire[id] {
-                writer.write(zone, true)
-            } else if self.should_extinguish_zone[id] {
-                writer.write(zone, false)
-            }
-        }
-    }
-}
-
 #[cfg(test)]
 mod a380_fire_and_smoke_protection_tests {
+    use std::time::Duration;
     use systems::{
         electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
         engine::EngineFireOverheadPanel,
-        shared::PotentialOrigin,
+        failures::FailureType,
+        shared::{FireDetectionLoopID, PotentialOrigin},
         simulation::{
             test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
             Aircraft, SimulationElement, SimulationElementVisitor, UpdateContext,
@@ -862,6 +208,7 @@ mod a380_fire_and_smoke_protection_tests {
     struct TestAircraft {
         a380_fire_and_smoke_protection: A380FireAndSmokeProtection,
         engine_fire_overhead_panel: EngineFireOverheadPanel<4>,
+        apu_fire_overhead_panel: AuxiliaryPowerUnitFireOverheadPanel,
         lgciu1: TestLgciu,
         lgciu2: TestLgciu,
 
@@ -878,6 +225,7 @@ mod a380_fire_and_smoke_protection_tests {
             Self {
                 a380_fire_and_smoke_protection: A380FireAndSmokeProtection::new(context),
                 engine_fire_overhead_panel: EngineFireOverheadPanel::new(context),
+                apu_fire_overhead_panel: AuxiliaryPowerUnitFireOverheadPanel::new(context),
                 lgciu1: TestLgciu::new(false),
                 lgciu2: TestLgciu::new(false),
 
@@ -942,6 +290,7 @@ mod a380_fire_and_smoke_protection_tests {
             self.a380_fire_and_smoke_protection.update(
                 context,
                 &self.engine_fire_overhead_panel,
+                &self.apu_fire_overhead_panel,
                 [&self.lgciu1, &self.lgciu2],
             )
         }
@@ -950,6 +299,7 @@ mod a380_fire_and_smoke_protection_tests {
         fn accept<V: SimulationElementVisitor>(&mut self, visitor: &mut V) {
             self.a380_fire_and_smoke_protection.accept(visitor);
             self.engine_fire_overhead_panel.accept(visitor);
+            self.apu_fire_overhead_panel.accept(visitor);
 
             visitor.visit(self);
         }
@@ -3,6 +3,8 @@ use std::{collections::HashMap, time::Duration};
 use crate::systems::simulation::SimulationElement;
 use serde::Deserialize;
 use systems::{
+    accept_iterable,
+    failures::{Failure, FailureType},
     fuel::{self, FuelInfo, FuelPump, FuelPumpProperties, FuelSystem, RefuelRate},
     pneumatic::EngineState,
     shared::{ElectricalBusType, ElectricalBuses},
@@ -600,6 +602,8 @@ pub struct A380FuelQuantityManagementSystem {
     fuel_system: FuelSystem<11, 20>,
     refuel_application: RefuelApplication,
     integrated_refuel_panel: IntegratedRefuelPanel,
+    channel_fault_ids: [VariableIdentifier; 2],
+    channel_failures: [Failure; 2],
 }
 impl A380FuelQuantityManagementSystem {
     pub fn new(
@@ -625,15 +629,28 @@ impl A380FuelQuantityManagementSystem {
                 context,
                 ElectricalBusType::DirectCurrentEssential, // 501PP
             ),
+            channel_fault_ids: [1, 2]
+                .map(|channel| context.get_identifier(format!("FQMS_{channel}_FAULT"))),
+            channel_failures: [1, 2]
+                .map(|channel| Failure::new(FailureType::FuelQuantityManagementSystem(channel))),
         }
     }
 
     pub fn update(&mut self, context: &UpdateContext) {
-        self.refuel_application.update(
-            context,
-            &mut self.fuel_system,
-            &mut self.integrated_refuel_panel,
-        );
+        // Refuelling is controlled by the FQMS and is lost when both of its channels have failed.
+        if self.is_operative() {
+            self.refuel_application.update(
+                context,
+                &mut self.fuel_system,
+                &mut self.integrated_refuel_panel,
+            );
+        }
+    }
+
+    fn is_operative(&self) -> bool {
+        self.channel_failures
+            .iter()
+            .any(|failure| !failure.is_active())
     }
 
     #[allow(dead_code)]
@@ -644,12 +661,23 @@ impl A380FuelQuantityManagementSystem {
     pub fn fuel_system(&self) -> &FuelSystem<11, 20> {
         &self.fuel_system
     }
+
+    pub fn fuel_system_mut(&mut self) -> &mut FuelSystem<11, 20> {
+        &mut self.fuel_system
+    }
 }
 impl SimulationElement for A380FuelQuantityManagementSystem {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.fuel_system.accept(visitor);
         self.refuel_application.accept(visitor);
         self.integrated_refuel_panel.accept(visitor);
+        accept_iterable!(self.channel_failures, visitor);
         visitor.visit(self);
     }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        for (id, failure) in self.channel_fault_ids.iter().zip(&self.channel_failures) {
+            writer.write(id, failure.is_active());
+        }
+    }
 }
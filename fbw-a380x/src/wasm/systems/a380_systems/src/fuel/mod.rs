@@ -1,14 +1,19 @@
-// Note: Fuel system for now is still handled in MSFS. This is used for calculating fuel-related factors.
-
 mod fuel_quantity_management_system;
 use fuel_quantity_management_system::A380FuelQuantityManagementSystem;
 use nalgebra::Vector3;
 use systems::{
-    fuel::{FuelCG, FuelInfo, FuelPayload, FuelPumpProperties, FuelSystem},
+    accept_iterable,
+    fuel::{
+        EngineFeed, FeedPump, FuelCG, FuelInfo, FuelPayload, FuelPumpProperties, FuelSystem,
+        FuelValve,
+    },
     shared::ElectricalBusType,
-    simulation::{InitContext, SimulationElement, SimulationElementVisitor, UpdateContext},
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+    },
 };
-use uom::si::f64::*;
+use uom::si::{f64::*, mass_rate::kilogram_per_hour};
 
 #[cfg(test)]
 mod test;
@@ -64,6 +69,20 @@ impl A380FuelTankType {
 
 pub struct A380Fuel {
     fuel_quantity_management_system: A380FuelQuantityManagementSystem,
+
+    engine_fuel_flow_ids: [VariableIdentifier; 4],
+    engine_fuel_flows: [MassRate; 4],
+
+    lp_valve_switch_ids: [VariableIdentifier; 4],
+    lp_valve_switches: [bool; 4],
+    lp_valves: [FuelValve; 4],
+    crossfeed_valve_switch_ids: [VariableIdentifier; 4],
+    crossfeed_valve_switches: [bool; 4],
+    crossfeed_valves: [FuelValve; 4],
+
+    engine_is_fed_ids: [VariableIdentifier; 4],
+    engine_is_fed: [bool; 4],
+    apu_fuel_feed_pressure: Pressure,
 }
 
 impl A380Fuel {
@@ -300,6 +319,70 @@ impl A380Fuel {
         ),
     ];
 
+    // Each feed tank has a main and a standby pump, which deliver the same pressure.
+    const ENGINE_FEEDS: [EngineFeed; 4] = [
+        EngineFeed {
+            pumps: &[
+                FeedPump {
+                    pump: 0,
+                    tank: A380FuelTankType::FeedOne as usize,
+                    priority: 0,
+                },
+                FeedPump {
+                    pump: 1,
+                    tank: A380FuelTankType::FeedOne as usize,
+                    priority: 0,
+                },
+            ],
+            suction_tank: A380FuelTankType::FeedOne as usize,
+        },
+        EngineFeed {
+            pumps: &[
+                FeedPump {
+                    pump: 2,
+                    tank: A380FuelTankType::FeedTwo as usize,
+                    priority: 0,
+                },
+                FeedPump {
+                    pump: 3,
+                    tank: A380FuelTankType::FeedTwo as usize,
+                    priority: 0,
+                },
+            ],
+            suction_tank: A380FuelTankType::FeedTwo as usize,
+        },
+        EngineFeed {
+            pumps: &[
+                FeedPump {
+                    pump: 4,
+                    tank: A380FuelTankType::FeedThree as usize,
+                    priority: 0,
+                },
+                FeedPump {
+                    pump: 5,
+                    tank: A380FuelTankType::FeedThree as usize,
+                    priority: 0,
+                },
+            ],
+            suction_tank: A380FuelTankType::FeedThree as usize,
+        },
+        EngineFeed {
+            pumps: &[
+                FeedPump {
+                    pump: 6,
+                    tank: A380FuelTankType::FeedFour as usize,
+                    priority: 0,
+                },
+                FeedPump {
+                    pump: 7,
+                    tank: A380FuelTankType::FeedFour as usize,
+                    priority: 0,
+                },
+            ],
+            suction_tank: A380FuelTankType::FeedFour as usize,
+        },
+    ];
+
     pub fn new(context: &mut InitContext) -> Self {
         A380Fuel {
             fuel_quantity_management_system: A380FuelQuantityManagementSystem::new(
@@ -307,11 +390,78 @@ impl A380Fuel {
                 Self::A380_FUEL,
                 Self::FUEL_PUMPS,
             ),
+
+            engine_fuel_flow_ids: [1, 2, 3, 4]
+                .map(|id| context.get_identifier(format!("ENGINE_FF:{id}"))),
+            engine_fuel_flows: [MassRate::default(); 4],
+
+            lp_valve_switch_ids: [1, 2, 3, 4]
+                .map(|id| context.get_identifier(format!("FUELSYSTEM VALVE SWITCH:{id}"))),
+            lp_valve_switches: [false; 4],
+            lp_valves: [
+                FuelValve::new(context, 1, ElectricalBusType::DirectCurrentEssential),
+                FuelValve::new(context, 2, ElectricalBusType::DirectCurrent(1)),
+                FuelValve::new(context, 3, ElectricalBusType::DirectCurrent(2)),
+                FuelValve::new(context, 4, ElectricalBusType::DirectCurrentEssential),
+            ],
+            crossfeed_valve_switch_ids: [46, 47, 48, 49]
+                .map(|id| context.get_identifier(format!("FUELSYSTEM VALVE SWITCH:{id}"))),
+            crossfeed_valve_switches: [false; 4],
+            crossfeed_valves: [
+                FuelValve::new(context, 46, ElectricalBusType::DirectCurrent(1)),
+                FuelValve::new(context, 47, ElectricalBusType::DirectCurrentEssential),
+                FuelValve::new(context, 48, ElectricalBusType::DirectCurrentEssential),
+                FuelValve::new(context, 49, ElectricalBusType::DirectCurrent(2)),
+            ],
+
+            engine_is_fed_ids: [1, 2, 3, 4]
+                .map(|id| context.get_identifier(format!("FUEL_ENGINE_{id}_IS_FED"))),
+            engine_is_fed: [false; 4],
+            apu_fuel_feed_pressure: Pressure::default(),
         }
     }
 
     pub(crate) fn update(&mut self, context: &UpdateContext) {
         self.fuel_quantity_management_system.update(context);
+        self.fuel_quantity_management_system
+            .fuel_system_mut()
+            .update(context);
+
+        for (valve, should_open) in self.lp_valves.iter_mut().zip(self.lp_valve_switches) {
+            valve.update(context, should_open);
+        }
+        for (valve, should_open) in self
+            .crossfeed_valves
+            .iter_mut()
+            .zip(self.crossfeed_valve_switches)
+        {
+            valve.update(context, should_open);
+        }
+
+        // A closed LP valve isolates the engine from its feed line.
+        let demands: [MassRate; 4] = [0, 1, 2, 3].map(|index| {
+            if self.lp_valves[index].is_open() {
+                self.engine_fuel_flows[index]
+            } else {
+                MassRate::default()
+            }
+        });
+        let line_pressure = self
+            .fuel_quantity_management_system
+            .fuel_system_mut()
+            .feed_engines(
+                context,
+                &Self::ENGINE_FEEDS,
+                self.crossfeed_valves
+                    .each_ref()
+                    .map(|valve| valve.is_open()),
+                demands,
+            );
+        for (index, is_fed) in self.engine_is_fed.iter_mut().enumerate() {
+            *is_fed = line_pressure[index] > Pressure::default() && self.lp_valves[index].is_open();
+        }
+        // The APU is supplied from the engine 1 feed line.
+        self.apu_fuel_feed_pressure = line_pressure[0];
     }
 
     fn fuel_system(&self) -> &FuelSystem<11, 20> {
@@ -358,6 +508,14 @@ impl A380Fuel {
             .tank_has_fuel(A380FuelTankType::RightMid as usize)
     }
 
+    pub fn apu_fuel_feed_pressure(&self) -> Pressure {
+        self.apu_fuel_feed_pressure
+    }
+
+    pub fn engine_is_fed(&self, engine_number: usize) -> bool {
+        self.engine_is_fed[engine_number - 1]
+    }
+
     pub fn feed_four_tank_has_fuel(&self) -> bool {
         self.fuel_system()
             .tank_has_fuel(A380FuelTankType::FeedFour as usize)
@@ -441,6 +599,24 @@ impl FuelCG for A380Fuel {
 impl SimulationElement for A380Fuel {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.fuel_quantity_management_system.accept(visitor);
+        accept_iterable!(self.lp_valves, visitor);
+        accept_iterable!(self.crossfeed_valves, visitor);
         visitor.visit(self);
     }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.engine_fuel_flows = self.engine_fuel_flow_ids.map(|id| {
+            let kilograms_per_hour: f64 = reader.read(&id);
+            MassRate::new::<kilogram_per_hour>(kilograms_per_hour)
+        });
+
+        self.lp_valve_switches = self.lp_valve_switch_ids.map(|id| reader.read(&id));
+        self.crossfeed_valve_switches = self.crossfeed_valve_switch_ids.map(|id| reader.read(&id));
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        for (id, is_fed) in self.engine_is_fed_ids.iter().zip(self.engine_is_fed) {
+            writer.write(id, is_fed);
+        }
+    }
 }
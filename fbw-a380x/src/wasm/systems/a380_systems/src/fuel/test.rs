@@ -1,7 +1,9 @@
 use std::{collections::HashMap, time::Duration};
 
 use ntest::{assert_false, assert_true};
-use systems::{electrical::Electricity, fuel::RefuelRate, simulation::test::ReadByName};
+use systems::{
+    electrical::Electricity, failures::FailureType, fuel::RefuelRate, simulation::test::ReadByName,
+};
 use uom::si::mass::kilogram;
 
 use super::*;
@@ -395,6 +397,44 @@ fn spawn_no_fuel_load_desired_instant_50000() {
     assert_fuel_quantity_50000(&test_bed);
 }
 
+#[test]
+fn refuel_is_not_possible_with_both_fqms_channels_failed() {
+    let mut test_bed = test_bed_with()
+        .desired_fuel_50000()
+        .trigger_instant_refuel();
+    test_bed.fail(FailureType::FuelQuantityManagementSystem(1));
+    test_bed.fail(FailureType::FuelQuantityManagementSystem(2));
+
+    test_bed = test_bed.and_run().and_stabilize();
+
+    assert_fuel_quantity_0(&test_bed);
+    let fqms_1_fault: bool = test_bed.read_by_name("FQMS_1_FAULT");
+    assert_true!(fqms_1_fault);
+}
+
+#[test]
+fn refuel_is_possible_with_one_fqms_channel_failed() {
+    let mut test_bed = test_bed_with()
+        .desired_fuel_50000()
+        .trigger_instant_refuel();
+    test_bed.fail(FailureType::FuelQuantityManagementSystem(1));
+
+    test_bed = test_bed.and_run().and_stabilize();
+
+    assert_fuel_quantity_50000(&test_bed);
+}
+
+#[test]
+fn leaking_tank_loses_fuel() {
+    let mut test_bed = test_bed_with().fuel_high().and_run();
+    let trim_tank_before = test_bed.tank_mass(A380FuelTankType::Trim as usize);
+    test_bed.fail(FailureType::FuelTankLeak(11));
+
+    test_bed = test_bed.run_multiple_frames(Duration::from_secs(60));
+
+    assert!(test_bed.tank_mass(A380FuelTankType::Trim as usize) < trim_tank_before);
+}
+
 #[test]
 fn spawn_high_fuel_load_desired_instant_50000() {
     let test_bed = test_bed_with()
@@ -54,8 +54,8 @@ use systems::{
         AdirsMeasurementOutputs, AirbusElectricPumpId, AirbusEngineDrivenPumpId, CargoDoorLocked,
         ControllerSignal, DelayedFalseLogicGate, DelayedTrueLogicGate, ElectricalBusType,
         ElectricalBuses, EngineFirePushButtons, GearWheel, HydraulicColor, LandingGearHandle,
-        LgciuInterface, LgciuWeightOnWheels, ReservoirAirPressure, SectionPressure,
-        SurfacesPositions,
+        LgciuInterface, LgciuWeightOnWheels, PositionPickoffUnit, ReservoirAirPressure,
+        SectionPressure, SurfacesPositions,
     },
     simulation::{
         InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
@@ -3121,6 +3121,10 @@ impl A380Hydraulic {
     pub fn gear_system(&self) -> &impl GearSystemSensors {
         &self.gear_system
     }
+
+    pub fn flaps_position_pickoff_unit(&self) -> &impl PositionPickoffUnit {
+        &self.flap_system
+    }
 }
 
 impl SurfacesPositions for A380Hydraulic {
@@ -7178,6 +7182,12 @@ mod tests {
             fn longitude(&self, _adiru_number: usize) -> Arinc429Word<Angle> {
                 Arinc429Word::new(Angle::default(), SignStatus::NormalOperation)
             }
+            fn hybrid_latitude(&self, _adiru_number: usize) -> Arinc429Word<Angle> {
+                Arinc429Word::new(Angle::default(), SignStatus::NormalOperation)
+            }
+            fn hybrid_longitude(&self, _adiru_number: usize) -> Arinc429Word<Angle> {
+                Arinc429Word::new(Angle::default(), SignStatus::NormalOperation)
+            }
             fn heading(&self, _adiru_number: usize) -> Arinc429Word<Angle> {
                 Arinc429Word::new(Angle::default(), SignStatus::NormalOperation)
             }
@@ -7190,6 +7200,9 @@ mod tests {
             fn altitude(&self, _adiru_number: usize) -> Arinc429Word<Length> {
                 Arinc429Word::new(Length::default(), SignStatus::NormalOperation)
             }
+            fn computed_airspeed(&self, _adiru_number: usize) -> Arinc429Word<Velocity> {
+                Arinc429Word::new(self.airspeed, SignStatus::NormalOperation)
+            }
             fn angle_of_attack(&self, _adiru_number: usize) -> Arinc429Word<Angle> {
                 Arinc429Word::new(Angle::default(), SignStatus::NormalOperation)
             }
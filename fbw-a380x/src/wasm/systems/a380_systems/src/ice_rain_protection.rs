@@ -0,0 +1,149 @@
+use systems::{
+    accept_iterable,
+    ice_rain_protection::{
+        engine_anti_ice::{EngineAntiIcePushButton, EngineAntiIceValve},
+        probe_heat::ProbeHeatComputer,
+        window_heat::WindowHeatComputer,
+        ProbeWindowHeatPushButton,
+    },
+    shared::{ElectricalBusType, EngineCorrectedN2, LgciuWeightOnWheels, ProbeType},
+    simulation::{InitContext, SimulationElement, SimulationElementVisitor, UpdateContext},
+};
+
+pub struct A380IceRainProtection {
+    probe_window_heat_push_button: ProbeWindowHeatPushButton,
+    probe_heat_computers: [ProbeHeatComputer; 3],
+    window_heat_computers: [WindowHeatComputer; 2],
+    engine_anti_ice_push_buttons: [EngineAntiIcePushButton; 4],
+    engine_anti_ice_valves: [EngineAntiIceValve; 4],
+}
+impl A380IceRainProtection {
+    const ADR_1_AND_2_PROBES: [ProbeType; 5] = [
+        ProbeType::Pitot,
+        ProbeType::LeftStatic,
+        ProbeType::RightStatic,
+        ProbeType::TotalAirTemperature,
+        ProbeType::AngleOfAttack,
+    ];
+    // ADR 3 doesn't have its own TAT probe.
+    const ADR_3_PROBES: [ProbeType; 4] = [
+        ProbeType::Pitot,
+        ProbeType::LeftStatic,
+        ProbeType::RightStatic,
+        ProbeType::AngleOfAttack,
+    ];
+
+    pub fn new(context: &mut InitContext) -> Self {
+        Self {
+            probe_window_heat_push_button: ProbeWindowHeatPushButton::new(context),
+            probe_heat_computers: [
+                ProbeHeatComputer::new(
+                    context,
+                    1,
+                    &Self::ADR_1_AND_2_PROBES,
+                    ElectricalBusType::DirectCurrentEssential,
+                    ElectricalBusType::AlternatingCurrentEssential,
+                ),
+                ProbeHeatComputer::new(
+                    context,
+                    2,
+                    &Self::ADR_1_AND_2_PROBES,
+                    ElectricalBusType::DirectCurrent(2),
+                    ElectricalBusType::AlternatingCurrent(4),
+                ),
+                ProbeHeatComputer::new(
+                    context,
+                    3,
+                    &Self::ADR_3_PROBES,
+                    ElectricalBusType::DirectCurrent(1),
+                    ElectricalBusType::AlternatingCurrent(2),
+                ),
+            ],
+            window_heat_computers: [
+                WindowHeatComputer::new(
+                    context,
+                    1,
+                    ElectricalBusType::DirectCurrent(1),
+                    ElectricalBusType::AlternatingCurrent(1),
+                    ElectricalBusType::AlternatingCurrent(2),
+                ),
+                WindowHeatComputer::new(
+                    context,
+                    2,
+                    ElectricalBusType::DirectCurrent(2),
+                    ElectricalBusType::AlternatingCurrent(4),
+                    ElectricalBusType::AlternatingCurrent(3),
+                ),
+            ],
+            engine_anti_ice_push_buttons: [1, 2, 3, 4]
+                .map(|number| EngineAntiIcePushButton::new(context, number)),
+            // Engines 1 and 2 are controlled from DC 1, engines 3 and 4 from DC 2.
+            engine_anti_ice_valves: [1, 2, 3, 4].map(|number| {
+                EngineAntiIceValve::new(
+                    context,
+                    number,
+                    ElectricalBusType::DirectCurrent(if number <= 2 { 1 } else { 2 }),
+                )
+            }),
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        engines: &[&impl EngineCorrectedN2],
+        lgcius: [&impl LgciuWeightOnWheels; 2],
+    ) {
+        let [phc_1, phc_2, phc_3] = &mut self.probe_heat_computers;
+        phc_1.update(
+            context,
+            &self.probe_window_heat_push_button,
+            engines,
+            lgcius[0],
+        );
+        phc_2.update(
+            context,
+            &self.probe_window_heat_push_button,
+            engines,
+            lgcius[1],
+        );
+        phc_3.update(
+            context,
+            &self.probe_window_heat_push_button,
+            engines,
+            lgcius[0],
+        );
+
+        for (whc, lgciu) in self.window_heat_computers.iter_mut().zip(lgcius) {
+            whc.update(context, &self.probe_window_heat_push_button, engines, lgciu);
+        }
+
+        for ((valve, push_button), engine) in self
+            .engine_anti_ice_valves
+            .iter_mut()
+            .zip(&self.engine_anti_ice_push_buttons)
+            .zip(engines)
+        {
+            valve.update(context, push_button, *engine);
+        }
+    }
+
+    pub fn probe_heat_computers(&self) -> &[ProbeHeatComputer; 3] {
+        &self.probe_heat_computers
+    }
+
+    pub fn engine_anti_ice_valves(&self) -> &[EngineAntiIceValve; 4] {
+        &self.engine_anti_ice_valves
+    }
+}
+impl SimulationElement for A380IceRainProtection {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.probe_window_heat_push_button.accept(visitor);
+        accept_iterable!(self.probe_heat_computers, visitor);
+        accept_iterable!(self.window_heat_computers, visitor);
+        accept_iterable!(self.engine_anti_ice_push_buttons, visitor);
+        accept_iterable!(self.engine_anti_ice_valves, visitor);
+
+        visitor.visit(self);
+    }
+}
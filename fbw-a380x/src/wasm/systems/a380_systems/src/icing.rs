@@ -1,5 +1,6 @@
 use systems::{
-    icing_state::{IcingState, PassiveIcingElement},
+    ice_rain_protection::airframe_icing::AirframeIcing,
+    icing_state::{ActiveDeicingController, IcingState, PassiveIcingElement},
     simulation::{InitContext, SimulationElement, SimulationElementVisitor, UpdateContext},
 };
 
@@ -7,6 +8,7 @@ use std::time::Duration;
 
 pub struct Icing {
     cockpit_icing_stick: IcingState,
+    airframe_icing: AirframeIcing<4>,
 }
 impl Icing {
     pub fn new(context: &mut InitContext) -> Self {
@@ -18,16 +20,26 @@ impl Icing {
                 Duration::from_secs(200),
                 None,
             ),
+            airframe_icing: AirframeIcing::new(context),
         }
     }
 
-    pub fn update(&mut self, context: &UpdateContext) {
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        wing_anti_ice: &[impl ActiveDeicingController; 2],
+        engine_anti_ice: &[impl ActiveDeicingController; 4],
+    ) {
         self.cockpit_icing_stick
             .update(context, None::<&PassiveIcingElement>);
+
+        self.airframe_icing
+            .update(context, wing_anti_ice, engine_anti_ice);
     }
 }
 impl SimulationElement for Icing {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.cockpit_icing_stick.accept(visitor);
+        self.airframe_icing.accept(visitor);
     }
 }
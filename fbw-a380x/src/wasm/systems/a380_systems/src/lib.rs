@@ -5,17 +5,22 @@ mod airframe;
 mod avionics_data_communication_network;
 mod control_display_system;
 mod electrical;
+mod failures;
 mod fire_and_smoke_protection;
 mod fuel;
 pub mod hydraulic;
+mod ice_rain_protection;
 mod icing;
 mod navigation;
+mod oxygen;
 mod payload;
 mod pneumatic;
 mod power_consumption;
 mod reverser;
 mod structural_flex;
 
+pub use failures::A380_FAILURES;
+
 use self::{
     air_conditioning::{A380AirConditioning, A380PressurizationOverheadPanel},
     avionics_data_communication_network::A380AvionicsDataCommunicationNetwork,
@@ -31,14 +36,21 @@ use electrical::{
     APU_START_MOTOR_BUS_TYPE,
 };
 use fire_and_smoke_protection::A380FireAndSmokeProtection;
-use fuel::FuelLevel;
 use hydraulic::{autobrakes::A380AutobrakePanel, A380Hydraulic, A380HydraulicOverheadPanel};
+use ice_rain_protection::A380IceRainProtection;
 use icing::Icing;
-use navigation::{A380AirDataInertialReferenceSystemBuilder, A380RadioAltimeters};
+use navigation::{
+    A380AirDataInertialReferenceSystemBuilder, A380GpsReceivers, A380RadioAltimeters,
+};
+use oxygen::A380Oxygen;
 use payload::A380Payload;
 use power_consumption::A380PowerConsumption;
 use reverser::{A380ReverserController, A380Reversers};
-use uom::si::{f64::Length, length::nautical_mile};
+use uom::si::{
+    angle::degree,
+    f64::{Angle, Length},
+    length::nautical_mile,
+};
 
 use systems::{
     accept_iterable,
@@ -47,7 +59,10 @@ use systems::{
         AuxiliaryPowerUnitOverheadPanel, Pw980ApuGenerator, Pw980Constants, Pw980StartMotor,
     },
     electrical::{Electricity, ElectricitySource, ExternalPowerSource},
-    engine::{reverser_thrust::ReverserForce, trent_engine::TrentEngine, EngineFireOverheadPanel},
+    engine::{
+        physical_model::EngineSimulationMode, reverser_thrust::ReverserForce,
+        trent_engine::TrentEngine, EngineFireOverheadPanel,
+    },
     enhanced_gpwc::EnhancedGroundProximityWarningComputer,
     landing_gear::{LandingGear, LandingGearControlInterfaceUnitSet},
     navigation::adirs::{
@@ -87,10 +102,13 @@ pub struct A380 {
     lgcius: LandingGearControlInterfaceUnitSet,
     hydraulic: A380Hydraulic,
     hydraulic_overhead: A380HydraulicOverheadPanel,
+    ice_rain_protection: A380IceRainProtection,
+    oxygen: A380Oxygen,
     autobrake_panel: A380AutobrakePanel,
     landing_gear: LandingGear,
     pneumatic: A380Pneumatic,
     radio_altimeters: A380RadioAltimeters,
+    gps_receivers: A380GpsReceivers,
     cds: A380ControlDisplaySystem,
     egpwc: EnhancedGroundProximityWarningComputer,
     icing_simulation: Icing,
@@ -102,6 +120,10 @@ pub struct A380 {
 }
 impl A380 {
     pub fn new(context: &mut InitContext) -> A380 {
+        Self::with_engine_mode(context, EngineSimulationMode::Simulator)
+    }
+
+    pub fn with_engine_mode(context: &mut InitContext, engine_mode: EngineSimulationMode) -> A380 {
         let mut adcn = A380AvionicsDataCommunicationNetwork::new(context);
         let adcn_simvar_translation =
             A380AvionicsDataCommunicationNetworkSimvarTranslator::new(context, &mut adcn);
@@ -127,10 +149,10 @@ impl A380 {
             airframe: A380Airframe::new(context),
             fire_and_smoke_protection: A380FireAndSmokeProtection::new(context),
             fuel: A380Fuel::new(context),
-            engine_1: TrentEngine::new(context, 1),
-            engine_2: TrentEngine::new(context, 2),
-            engine_3: TrentEngine::new(context, 3),
-            engine_4: TrentEngine::new(context, 4),
+            engine_1: TrentEngine::with_mode(context, 1, engine_mode),
+            engine_2: TrentEngine::with_mode(context, 2, engine_mode),
+            engine_3: TrentEngine::with_mode(context, 3, engine_mode),
+            engine_4: TrentEngine::with_mode(context, 4, engine_mode),
             engine_fire_overhead: EngineFireOverheadPanel::new(context),
             electrical: A380Electrical::new(context),
             power_consumption: A380PowerConsumption::new(context),
@@ -142,10 +164,13 @@ impl A380 {
             ),
             hydraulic: A380Hydraulic::new(context),
             hydraulic_overhead: A380HydraulicOverheadPanel::new(context),
+            ice_rain_protection: A380IceRainProtection::new(context),
+            oxygen: A380Oxygen::new(context),
             autobrake_panel: A380AutobrakePanel::new(context),
             landing_gear: LandingGear::new(context, true),
             pneumatic: A380Pneumatic::new(context),
             radio_altimeters: A380RadioAltimeters::new(context),
+            gps_receivers: A380GpsReceivers::new(context),
             cds: A380ControlDisplaySystem::new(context),
             egpwc: EnhancedGroundProximityWarningComputer::new(
                 context,
@@ -161,6 +186,8 @@ impl A380 {
                     Length::new::<nautical_mile>(640.0),
                 ],
                 3,
+                Angle::new::<degree>(190.),
+                Angle::new::<degree>(215.),
             ),
 
             icing_simulation: Icing::new(context),
@@ -185,7 +212,10 @@ impl Aircraft for A380 {
             &self.apu_overhead,
             self.fire_and_smoke_protection.apu_fire_on_ground(),
             &self.apu_fire_overhead,
-            self.pneumatic_overhead.apu_bleed_is_on(),
+            self.pneumatic_overhead.apu_bleed_is_on()
+                && !self
+                    .pneumatic
+                    .should_close_apu_bleed_valve_because_of_leak(),
             // This will be replaced when integrating the whole electrical system.
             // For now we use the same logic as found in the JavaScript code; ignoring whether or not
             // the engine generators are supplying electricity.
@@ -193,8 +223,9 @@ impl Aircraft for A380 {
                 || self.electrical_overhead.apu_generator_is_on(2))
                 && !(self.electrical_overhead.external_power_is_on(1)
                     && self.electrical_overhead.external_power_is_available(1)),
+            &self.electrical_overhead,
             self.pneumatic.apu_bleed_air_valve(),
-            self.fuel.feed_one_tank_has_fuel(),
+            self.fuel.apu_fuel_feed_pressure(),
         );
 
         self.electrical.update(
@@ -225,6 +256,24 @@ impl Aircraft for A380 {
     }
 
     fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+        // The fuel feed, bleed and generator loads are those of the previous update.
+        for (number, engine) in [
+            &mut self.engine_1,
+            &mut self.engine_2,
+            &mut self.engine_3,
+            &mut self.engine_4,
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            engine.update(
+                context,
+                self.fuel.engine_is_fed(number + 1),
+                &self.pneumatic,
+                self.electrical.engine_generator_output_power(number + 1),
+            );
+        }
+
         self.apu.update_after_power_distribution(
             &[
                 &self.engine_1,
@@ -236,7 +285,7 @@ impl Aircraft for A380 {
         );
         self.apu_overhead.update_after_apu(&self.apu);
 
-        self.adcn.update();
+        self.adcn.update(context);
         self.adcn_simvar_translation.update(&self.adcn);
         self.lgcius.update(
             context,
@@ -248,10 +297,12 @@ impl Aircraft for A380 {
         self.fire_and_smoke_protection.update(
             context,
             &self.engine_fire_overhead,
+            &self.apu_fire_overhead,
             [self.lgcius.lgciu1(), self.lgcius.lgciu2()],
         );
 
         self.radio_altimeters.update(context);
+        self.gps_receivers.update(context);
 
         self.hydraulic.update(
             context,
@@ -276,8 +327,28 @@ impl Aircraft for A380 {
 
         self.hydraulic_overhead.update(&self.hydraulic);
 
-        self.adirs.update(context, &self.adirs_overhead);
-        self.adirs_overhead.update(context, &self.adirs);
+        self.ice_rain_protection.update(
+            context,
+            &[
+                &self.engine_1,
+                &self.engine_2,
+                &self.engine_3,
+                &self.engine_4,
+            ],
+            [self.lgcius.lgciu1(), self.lgcius.lgciu2()],
+        );
+
+        self.adirs.update(
+            context,
+            &self.adirs_overhead,
+            self.ice_rain_protection.probe_heat_computers(),
+            [
+                self.gps_receivers.gps_receiver_1(),
+                self.gps_receivers.gps_receiver_2(),
+                self.gps_receivers.gps_receiver_1(),
+            ],
+        );
+        self.adirs_overhead.update(&self.adirs);
 
         self.power_consumption.update(context);
 
@@ -293,6 +364,7 @@ impl Aircraft for A380 {
             &self.engine_fire_overhead,
             &self.apu,
             &self.air_conditioning,
+            [self.lgcius.lgciu1(), self.lgcius.lgciu2()],
         );
         self.air_conditioning
             .mix_packs_air_update(self.pneumatic.packs());
@@ -314,10 +386,21 @@ impl Aircraft for A380 {
             &self.pressurization_overhead,
             [self.lgcius.lgciu1(), self.lgcius.lgciu2()],
         );
+        self.oxygen.update(context, &self.air_conditioning);
 
         self.cds.update();
 
-        self.egpwc.update(&self.adirs, self.lgcius.lgciu1());
+        self.egpwc.update(
+            context,
+            &self.adirs,
+            self.gps_receivers.gps_receiver_1(),
+            [
+                self.radio_altimeters.radio_altimeter_1(),
+                self.radio_altimeters.radio_altimeter_2(),
+            ],
+            self.lgcius.lgciu1(),
+            self.hydraulic.flaps_position_pickoff_unit(),
+        );
 
         self.structural_flex.update(
             context,
@@ -331,9 +414,12 @@ impl Aircraft for A380 {
         );
         self.cds.update();
 
-        self.icing_simulation.update(context);
+        self.icing_simulation.update(
+            context,
+            self.pneumatic.wing_anti_ice_systems(),
+            self.ice_rain_protection.engine_anti_ice_valves(),
+        );
 
-        self.egpwc.update(&self.adirs, self.lgcius.lgciu1());
         self.fuel.update(context);
 
         self.engine_reverser_control[0].update(
@@ -385,9 +471,12 @@ impl SimulationElement for A380 {
         accept_iterable!(self.ext_pwrs, visitor);
         self.lgcius.accept(visitor);
         self.radio_altimeters.accept(visitor);
+        self.gps_receivers.accept(visitor);
         self.autobrake_panel.accept(visitor);
         self.hydraulic.accept(visitor);
         self.hydraulic_overhead.accept(visitor);
+        self.ice_rain_protection.accept(visitor);
+        self.oxygen.accept(visitor);
         self.landing_gear.accept(visitor);
         self.pneumatic.accept(visitor);
         self.cds.accept(visitor);
@@ -12,20 +12,24 @@ use uom::si::{
 use systems::{
     accept_iterable,
     air_conditioning::PackFlowControllers,
+    failures::{Failure, FailureType},
     overhead::{AutoOffFaultPushButton, OnOffFaultPushButton},
+    pneumatic::wing_anti_ice::{WingAntiIceComplex, WingAntiIceConstants, WingAntiIceSystem},
     pneumatic::{
-        valve::*, BleedMonitoringComputerIsAliveSignal, CompressionChamber,
-        ControllablePneumaticValve, CrossBleedValveSelectorKnob, CrossBleedValveSelectorMode,
-        DifferentialPressureTransducer, EngineCompressionChamberController, EngineModeSelector,
-        EngineState, PneumaticContainer, PneumaticPipe, PneumaticValveSignal, Precooler,
+        valve::*, BleedAirDuctLeak, BleedMonitoringComputerIsAliveSignal, BleedOverheatMonitor,
+        BleedOverpressureMonitor, CompressionChamber, ControllablePneumaticValve,
+        CrossBleedValveSelectorKnob, CrossBleedValveSelectorMode, DifferentialPressureTransducer,
+        EngineBleedValveJam, EngineCompressionChamberController, EngineModeSelector, EngineState,
+        LeakDetectionLoop, PneumaticContainer, PneumaticPipe, PneumaticValveSignal, Precooler,
         PressureTransducer, PressurisedReservoirWithExhaustValve, PressurizeableReservoir,
-        TargetPressureTemperatureSignal, VariableVolumeContainer,
+        TargetPressureTemperatureSignal, VariableVolumeContainer, WingAntiIcePushButton,
     },
     shared::{
         pid::PidController, update_iterator::MaxStepLoop, ControllerSignal, ElectricalBusType,
-        ElectricalBuses, EngineBleedPushbutton, EngineCorrectedN1, EngineCorrectedN2,
-        EngineFirePushButtons, EngineStartState, HydraulicColor, PackFlowValveState,
-        PneumaticBleed, PneumaticValve, ReservoirAirPressure,
+        ElectricalBuses, EngineBleedPushbutton, EngineBleedValveId, EngineCorrectedN1,
+        EngineCorrectedN2, EngineFirePushButtons, EnginePneumaticLoads, EngineStartState,
+        HydraulicColor, LeakDetectionZone, LgciuWeightOnWheels, PackFlowValveState, PneumaticBleed,
+        PneumaticValve, ReservoirAirPressure,
     },
     simulation::{
         InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
@@ -106,6 +110,21 @@ valve_signal_implementation!(EngineStarterValveSignal);
 valve_signal_implementation!(FanAirValveSignal);
 valve_signal_implementation!(PackFlowValveSignal);
 
+pub struct A380WingAntiIceConstants;
+
+impl WingAntiIceConstants for A380WingAntiIceConstants {
+    // Only the outboard slats are heated. Their piccolo ducts are about twice
+    // as long as the A320 ones, so the volume and the flow through the
+    // exhaust are scaled accordingly.
+    const PIPE_VOLUME_CUBIC_METER: f64 = 4.;
+    const EXHAUST_SPEED: f64 = 0.25;
+    const VALVE_TRANSFER_SPEED: f64 = 2.4;
+    const VALVE_SETPOINT_STD_DEV_PSI: f64 = 2.5;
+    // The valves are commanded by the PADS application of the CPIOM-A
+    const CONTROLLER_POWERED_BY: ElectricalBusType = ElectricalBusType::DirectCurrentEssential;
+    const GROUND_TEST_DURATION: Duration = Duration::from_secs(10);
+}
+
 pub struct A380Pneumatic {
     physics_updater: MaxStepLoop,
 
@@ -122,6 +141,8 @@ pub struct A380Pneumatic {
 
     apu_compression_chamber: CompressionChamber,
     apu_bleed_air_valve: DefaultValve,
+    apu_bleed_duct_leak: BleedAirDuctLeak,
+    apu_leak_detection_loop: LeakDetectionLoop,
 
     hydraulic_reservoir_bleed_air_valves: [PurelyPneumaticValve; 2],
     hydraulic_reservoir_bleed_air_pipe: PneumaticPipe,
@@ -132,9 +153,12 @@ pub struct A380Pneumatic {
         PressurisedReservoirWithExhaustValve<VariableVolumeContainer>,
 
     packs: [PackComplex; 2],
+
+    wing_anti_ice: WingAntiIceComplex<A380WingAntiIceConstants>,
 }
 impl A380Pneumatic {
     const PNEUMATIC_SIM_MAX_TIME_STEP: Duration = Duration::from_millis(100);
+    const APU_LEAK_DETECTION_ALARM_TEMPERATURE_C: f64 = 124.;
 
     pub fn new(context: &mut InitContext) -> Self {
         Self {
@@ -144,6 +168,7 @@ impl A380Pneumatic {
             apu_bleed_air_pressure_id: context
                 .get_identifier("PNEU_APU_BLEED_CONTAINER_PRESSURE".to_owned()),
             core_processing_input_output_module_a: CoreProcessingInputOutputModuleA::new(
+                context,
                 ElectricalBusType::DirectCurrentEssential, // TTM 2
             ),
             engine_systems: [
@@ -166,6 +191,18 @@ impl A380Pneumatic {
             ],
             apu_compression_chamber: CompressionChamber::new(Volume::new::<cubic_meter>(5.)),
             apu_bleed_air_valve: DefaultValve::new_closed(),
+            apu_bleed_duct_leak: BleedAirDuctLeak::new(
+                LeakDetectionZone::Apu,
+                EngineBleedAirSystem::DUCT_LEAK_SPEED,
+            ),
+            apu_leak_detection_loop: LeakDetectionLoop::new(
+                context,
+                LeakDetectionZone::Apu,
+                ThermodynamicTemperature::new::<degree_celsius>(
+                    Self::APU_LEAK_DETECTION_ALARM_TEMPERATURE_C,
+                ),
+                ElectricalBusType::DirectCurrentEssential,
+            ),
             hydraulic_reservoir_bleed_air_valves: [
                 PurelyPneumaticValve::new(),
                 PurelyPneumaticValve::new(),
@@ -198,6 +235,7 @@ impl A380Pneumatic {
                 6e-2,
             ),
             packs: [PackComplex::new(context, 1), PackComplex::new(context, 2)],
+            wing_anti_ice: WingAntiIceComplex::new(context),
         }
     }
 
@@ -209,6 +247,7 @@ impl A380Pneumatic {
         engine_fire_push_buttons: &impl EngineFirePushButtons,
         apu: &impl ControllerSignal<TargetPressureTemperatureSignal>,
         pack_flow_valve_signals: &impl PackFlowControllers,
+        lgciu: [&impl LgciuWeightOnWheels; 2],
     ) {
         self.physics_updater.update(context);
 
@@ -220,6 +259,7 @@ impl A380Pneumatic {
                 engine_fire_push_buttons,
                 apu,
                 pack_flow_valve_signals,
+                lgciu,
             );
         }
     }
@@ -232,6 +272,7 @@ impl A380Pneumatic {
         engine_fire_push_buttons: &impl EngineFirePushButtons,
         apu: &impl ControllerSignal<TargetPressureTemperatureSignal>,
         pack_flow_valve_signals: &impl PackFlowControllers,
+        lgciu: [&impl LgciuWeightOnWheels; 2],
     ) {
         self.apu_compression_chamber.update(apu);
 
@@ -239,16 +280,19 @@ impl A380Pneumatic {
             context,
             &self.engine_systems,
             &self.apu_bleed_air_valve,
+            &self.apu_leak_detection_loop,
             overhead_panel,
             engine_fire_push_buttons,
         );
 
-        for cross_bleed_valve in self.cross_bleed_valves.iter_mut() {
-            // TODO: See TTM 3. There is a relay system that connects the 4 CPIOM units (PADS application) to the 3 crossbleed valves and the APU isolation valve
-            // TLDR: CPIOM-A1+A2 control left xbleed and APU isolation valve, CPIOM-A3+A4 control center and right xbleed valve
-            cross_bleed_valve
-                .update_open_amount(&self.core_processing_input_output_module_a.units[0])
-        }
+        // See TTM 3. There is a relay system that connects the 4 CPIOM units (PADS application) to the 3 crossbleed valves and the APU isolation valve
+        // TLDR: CPIOM-A1+A2 control left xbleed and APU isolation valve, CPIOM-A3+A4 control center and right xbleed valve
+        let [left_cross_bleed_valve, center_cross_bleed_valve, right_cross_bleed_valve] =
+            &mut self.cross_bleed_valves;
+        let cpiom_a = &self.core_processing_input_output_module_a;
+        left_cross_bleed_valve.update_open_amount(cpiom_a.left_cross_bleed_valve_controller());
+        center_cross_bleed_valve.update_open_amount(cpiom_a.right_cross_bleed_valve_controller());
+        right_cross_bleed_valve.update_open_amount(cpiom_a.right_cross_bleed_valve_controller());
 
         for controller in self.engine_starter_valve_controllers.iter_mut() {
             controller.update(&self.fadec);
@@ -277,6 +321,15 @@ impl A380Pneumatic {
             &mut self.apu_compression_chamber,
             engine_1_system,
         );
+        // The APU bleed duct is only pressurised by the APU while the APU bleed valve is open.
+        if self.apu_bleed_air_valve.is_open() {
+            self.apu_bleed_duct_leak
+                .update_move_fluid(context, &mut self.apu_compression_chamber);
+        } else {
+            self.apu_bleed_duct_leak.update_isolated();
+        }
+        self.apu_leak_detection_loop
+            .update(context, &self.apu_bleed_duct_leak);
 
         // Hydraulic reservoir pressurization.
         // G+Y are pressurized through a pipe that is shared between engine 1 and engine 4 systems (downstream the bleed valve)
@@ -331,6 +384,14 @@ impl A380Pneumatic {
             engine_4_system,
             pack_flow_valve_signals,
         );
+
+        // Each wing is supplied downstream of the bleed valve of its inner engine
+        self.wing_anti_ice.update(
+            context,
+            [engine_2_system, engine_3_system],
+            overhead_panel.wing_anti_ice.mode(),
+            lgciu,
+        );
     }
 
     // TODO: Returning a mutable reference here is not great. I was running into an issue with the update order:
@@ -356,6 +417,17 @@ impl A380Pneumatic {
     pub fn packs(&mut self) -> &mut [PackComplex; 2] {
         &mut self.packs
     }
+
+    pub fn wing_anti_ice_systems(&self) -> &[WingAntiIceSystem<A380WingAntiIceConstants>; 2] {
+        self.wing_anti_ice.wai_systems()
+    }
+
+    /// The CPIOM signals the APU ECB to close the APU bleed valve when a leak is detected in the
+    /// APU bleed duct or the wing it is connected to.
+    pub fn should_close_apu_bleed_valve_because_of_leak(&self) -> bool {
+        self.core_processing_input_output_module_a
+            .should_close_apu_bleed_valve_because_of_leak()
+    }
 }
 impl PneumaticBleed for A380Pneumatic {
     fn apu_bleed_is_on(&self) -> bool {
@@ -374,6 +446,15 @@ impl EngineStartState for A380Pneumatic {
         self.fadec.engine_mode_selector()
     }
 }
+impl EnginePneumaticLoads for A380Pneumatic {
+    fn starter_air_pressure(&self, engine_number: usize) -> Pressure {
+        self.engine_systems[engine_number - 1].engine_starter_air_pressure()
+    }
+
+    fn bleed_air_extraction(&self, engine_number: usize) -> MassRate {
+        self.engine_systems[engine_number - 1].bleed_air_extraction()
+    }
+}
 impl PackFlowValveState for A380Pneumatic {
     // fcv_id: 1, 2, 3 or 4
     fn pack_flow_valve_is_open(&self, fcv_id: usize) -> bool {
@@ -404,11 +485,17 @@ impl PackFlowValveState for A380Pneumatic {
 impl SimulationElement for A380Pneumatic {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.fadec.accept(visitor);
+        self.core_processing_input_output_module_a.accept(visitor);
 
         accept_iterable!(self.cross_bleed_valves, visitor);
         accept_iterable!(self.engine_systems, visitor);
         accept_iterable!(self.packs, visitor);
 
+        self.wing_anti_ice.accept(visitor);
+
+        self.apu_bleed_duct_leak.accept(visitor);
+        self.apu_leak_detection_loop.accept(visitor);
+
         self.yellow_hydraulic_reservoir_with_valve.accept(visitor);
         self.green_hydraulic_reservoir_with_valve.accept(visitor);
 
@@ -474,13 +561,13 @@ struct CoreProcessingInputOutputModuleA {
     is_powered: bool,
 }
 impl CoreProcessingInputOutputModuleA {
-    fn new(powered_by: ElectricalBusType) -> Self {
+    fn new(context: &mut InitContext, powered_by: ElectricalBusType) -> Self {
         Self {
             units: [
-                CoreProcessingInputOutputModuleAUnit::new(1),
-                CoreProcessingInputOutputModuleAUnit::new(2),
-                CoreProcessingInputOutputModuleAUnit::new(3),
-                CoreProcessingInputOutputModuleAUnit::new(4),
+                CoreProcessingInputOutputModuleAUnit::new(context, 1),
+                CoreProcessingInputOutputModuleAUnit::new(context, 2),
+                CoreProcessingInputOutputModuleAUnit::new(context, 3),
+                CoreProcessingInputOutputModuleAUnit::new(context, 4),
             ],
             powered_by,
             is_powered: true,
@@ -492,10 +579,17 @@ impl CoreProcessingInputOutputModuleA {
         context: &UpdateContext,
         sensors: &[EngineBleedAirSystem; 4],
         apu_bleed_valve: &impl PneumaticValve,
+        apu_leak_detection_loop: &LeakDetectionLoop,
         pneumatic_overhead_panel: &A380PneumaticOverheadPanel,
         engine_fire_push_buttons: &impl EngineFirePushButtons,
     ) {
         for (unit, sensor) in self.units.iter_mut().zip(sensors) {
+            if self.is_powered {
+                unit.update_leak_detection(sensors, apu_leak_detection_loop);
+            } else {
+                unit.reset_leak_detection();
+            }
+
             unit.update(
                 context,
                 sensor,
@@ -509,8 +603,39 @@ impl CoreProcessingInputOutputModuleA {
     fn is_powered(&self) -> bool {
         self.is_powered
     }
+
+    fn should_close_apu_bleed_valve_because_of_leak(&self) -> bool {
+        self.is_powered && self.left_cross_bleed_valve_controller().has_apu_bleed_leak
+    }
+
+    /// CPIOM-A2 takes over the left crossbleed and APU bleed valves when CPIOM-A1 has failed.
+    fn left_cross_bleed_valve_controller(&self) -> &CoreProcessingInputOutputModuleAUnit {
+        Self::operative_unit_of(&self.units[0], &self.units[1])
+    }
+
+    /// CPIOM-A4 takes over the center and right crossbleed valves when CPIOM-A3 has failed.
+    fn right_cross_bleed_valve_controller(&self) -> &CoreProcessingInputOutputModuleAUnit {
+        Self::operative_unit_of(&self.units[2], &self.units[3])
+    }
+
+    fn operative_unit_of<'a>(
+        main: &'a CoreProcessingInputOutputModuleAUnit,
+        backup: &'a CoreProcessingInputOutputModuleAUnit,
+    ) -> &'a CoreProcessingInputOutputModuleAUnit {
+        if main.has_failed() && !backup.has_failed() {
+            backup
+        } else {
+            main
+        }
+    }
 }
 impl SimulationElement for CoreProcessingInputOutputModuleA {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.units, visitor);
+
+        visitor.visit(self);
+    }
+
     fn receive_power(&mut self, buses: &impl ElectricalBuses) {
         self.is_powered = buses.is_powered(self.powered_by)
     }
@@ -526,6 +651,9 @@ impl ControllerSignal<BleedMonitoringComputerIsAliveSignal> for CoreProcessingIn
 }
 
 struct CoreProcessingInputOutputModuleAUnit {
+    overheat_id: VariableIdentifier,
+    overpressure_id: VariableIdentifier,
+
     engine_number: usize,
     pressure_regulating_valve_is_closed: bool,
     intermediate_pressure_compressor_pressure: Pressure,
@@ -541,12 +669,22 @@ struct CoreProcessingInputOutputModuleAUnit {
     pressure_regulating_valve_pid: PidController,
     fan_air_valve_pid: PidController,
     cross_bleed_valve_selector: CrossBleedValveSelectorMode,
+    has_engine_bleed_leak: bool,
+    has_cross_bleed_leak: bool,
+    has_apu_bleed_leak: bool,
+    overheat_monitor: BleedOverheatMonitor,
+    overpressure_monitor: BleedOverpressureMonitor,
+    has_bleed_fault: bool,
+    failure: Failure,
 }
 impl CoreProcessingInputOutputModuleAUnit {
     const PRESSURE_REGULATING_VALVE_TARGET_PSI: f64 = 40.; // FCOM
 
-    fn new(engine_number: usize) -> Self {
+    fn new(context: &mut InitContext, engine_number: usize) -> Self {
         Self {
+            overheat_id: context.get_identifier(format!("PNEU_ENG_{}_OVERHEAT", engine_number)),
+            overpressure_id: context
+                .get_identifier(format!("PNEU_ENG_{}_OVERPRESSURE", engine_number)),
             engine_number,
             pressure_regulating_valve_is_closed: false,
             intermediate_pressure_compressor_pressure: Pressure::new::<psi>(0.),
@@ -570,6 +708,13 @@ impl CoreProcessingInputOutputModuleAUnit {
             ),
             fan_air_valve_pid: PidController::new(-0.005, -0.001, 0., 0., 1., 200., 1.),
             cross_bleed_valve_selector: CrossBleedValveSelectorMode::Auto,
+            has_engine_bleed_leak: false,
+            has_cross_bleed_leak: false,
+            has_apu_bleed_leak: false,
+            overheat_monitor: BleedOverheatMonitor::new(),
+            overpressure_monitor: BleedOverpressureMonitor::new(),
+            has_bleed_fault: false,
+            failure: Failure::new(FailureType::BleedMonitoringComputer(engine_number)),
         }
     }
 
@@ -597,6 +742,9 @@ impl CoreProcessingInputOutputModuleAUnit {
                 regulated_pressure_signal.get::<psi>(),
                 Some(context.delta()),
             );
+
+            self.overpressure_monitor
+                .update(context, regulated_pressure_signal);
         }
 
         self.fan_air_valve_pid.next_control_output(
@@ -606,10 +754,18 @@ impl CoreProcessingInputOutputModuleAUnit {
             Some(context.delta()),
         );
 
+        self.overheat_monitor
+            .update(context, sensors.precooler_outlet_temperature());
+
         self.engine_starter_valve_is_open = sensors.engine_starter_valve_is_open();
 
         self.is_engine_bleed_pushbutton_auto =
             pneumatic_overhead_panel.engine_bleed_pb_is_auto(self.engine_number);
+
+        // An overpressure or overheat keeps the PRV closed until the engine bleed pushbutton is
+        // selected off.
+        self.has_bleed_fault = self.is_engine_bleed_pushbutton_auto
+            && (self.has_bleed_fault || self.has_overheat() || self.has_overpressure());
         self.is_engine_fire_pushbutton_released = is_engine_fire_pushbutton_released;
 
         self.is_any_bleed_pushbutton_off =
@@ -621,14 +777,67 @@ impl CoreProcessingInputOutputModuleAUnit {
         self.cross_bleed_valve_selector = pneumatic_overhead_panel.cross_bleed_mode();
     }
 
+    /// A detected leak isolates the affected ducts until the CPIOM is reset by a loss of power:
+    /// - a wing or pylon leak closes the bleed valve of the engine,
+    /// - a wing or APU leak closes the crossbleed valves when in automatic mode,
+    /// - a wing 1 or APU leak closes the APU bleed valve.
+    fn update_leak_detection(
+        &mut self,
+        engine_systems: &[EngineBleedAirSystem; 4],
+        apu_leak_detection_loop: &LeakDetectionLoop,
+    ) {
+        let engine_system = &engine_systems[self.engine_number - 1];
+        self.has_engine_bleed_leak |=
+            engine_system.wing_leak_detected() || engine_system.pylon_leak_detected();
+
+        self.has_cross_bleed_leak |= apu_leak_detection_loop.leak_detected()
+            || engine_systems
+                .iter()
+                .any(|engine_system| engine_system.wing_leak_detected());
+
+        self.has_apu_bleed_leak |=
+            apu_leak_detection_loop.leak_detected() || engine_systems[0].wing_leak_detected();
+    }
+
+    fn reset_leak_detection(&mut self) {
+        self.has_engine_bleed_leak = false;
+        self.has_cross_bleed_leak = false;
+        self.has_apu_bleed_leak = false;
+    }
+
     fn should_close_pressure_regulating_valve_because_apu_bleed_is_on(&self) -> bool {
         self.is_apu_bleed_on && self.is_apu_bleed_valve_open
     }
+
+    fn has_overheat(&self) -> bool {
+        self.overheat_monitor.has_overheat()
+    }
+
+    fn has_overpressure(&self) -> bool {
+        self.overpressure_monitor.has_overpressure()
+    }
+
+    fn has_failed(&self) -> bool {
+        self.failure.is_active()
+    }
+}
+impl SimulationElement for CoreProcessingInputOutputModuleAUnit {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.overheat_id, self.has_overheat());
+        writer.write(&self.overpressure_id, self.has_overpressure());
+    }
 }
 impl ControllerSignal<HighPressureValveSignal> for CoreProcessingInputOutputModuleAUnit {
     fn signal(&self) -> Option<HighPressureValveSignal> {
-        // TODO: Add overtemperature condition here
-        if self.pressure_regulating_valve_is_closed
+        // A failed unit no longer energises the valves, which close.
+        if self.has_failed()
+            || self.pressure_regulating_valve_is_closed
             || self.high_pressure_compressor_pressure < Pressure::new::<psi>(15.)
             || self.intermediate_pressure_compressor_pressure > Pressure::new::<psi>(33.5)
         {
@@ -642,8 +851,11 @@ impl ControllerSignal<HighPressureValveSignal> for CoreProcessingInputOutputModu
 }
 impl ControllerSignal<PressureRegulatingValveSignal> for CoreProcessingInputOutputModuleAUnit {
     fn signal(&self) -> Option<PressureRegulatingValveSignal> {
-        if self.transfer_pressure < Pressure::new::<psi>(15.)
+        if self.has_failed()
+            || self.transfer_pressure < Pressure::new::<psi>(15.)
             || (!self.is_engine_bleed_pushbutton_auto || self.is_engine_fire_pushbutton_released)
+            || self.has_engine_bleed_leak
+            || self.has_bleed_fault
             || self.should_close_pressure_regulating_valve_because_apu_bleed_is_on()
             || self.engine_starter_valve_is_open
         {
@@ -657,13 +869,21 @@ impl ControllerSignal<PressureRegulatingValveSignal> for CoreProcessingInputOutp
 }
 impl ControllerSignal<FanAirValveSignal> for CoreProcessingInputOutputModuleAUnit {
     fn signal(&self) -> Option<FanAirValveSignal> {
-        Some(FanAirValveSignal::new(Ratio::new::<ratio>(
-            self.fan_air_valve_pid.output(),
-        )))
+        if self.has_failed() {
+            Some(FanAirValveSignal::new_closed())
+        } else {
+            Some(FanAirValveSignal::new(Ratio::new::<ratio>(
+                self.fan_air_valve_pid.output(),
+            )))
+        }
     }
 }
 impl ControllerSignal<CrossBleedValveSignal> for CoreProcessingInputOutputModuleAUnit {
     fn signal(&self) -> Option<CrossBleedValveSignal> {
+        if self.has_failed() {
+            return None;
+        }
+
         match self.cross_bleed_valve_selector {
             CrossBleedValveSelectorMode::Shut => Some(CrossBleedValveSignal::new_closed(
                 CrossBleedValveSignalType::Manual,
@@ -672,7 +892,12 @@ impl ControllerSignal<CrossBleedValveSignal> for CoreProcessingInputOutputModule
                 CrossBleedValveSignalType::Manual,
             )),
             CrossBleedValveSelectorMode::Auto => {
-                if self.is_apu_bleed_valve_open || self.is_any_bleed_pushbutton_off {
+                // A leak isolates all engines from each other.
+                if self.has_cross_bleed_leak {
+                    Some(CrossBleedValveSignal::new_closed(
+                        CrossBleedValveSignalType::Automatic,
+                    ))
+                } else if self.is_apu_bleed_valve_open || self.is_any_bleed_pushbutton_off {
                     Some(CrossBleedValveSignal::new_open(
                         CrossBleedValveSignalType::Automatic,
                     ))
@@ -720,6 +945,7 @@ struct EngineBleedAirSystem {
     engine_starter_exhaust: PneumaticExhaust,
     engine_starter_container: PneumaticPipe,
     engine_starter_valve: DefaultValve,
+    engine_starter_air_pressure: Pressure,
     fan_air_valve: ElectroPneumaticValve,
     precooler: Precooler,
 
@@ -727,8 +953,24 @@ struct EngineBleedAirSystem {
     transfer_pressure_transducer: PressureTransducer,
     regulated_pressure_transducer: PressureTransducer,
     differential_pressure_transducer: DifferentialPressureTransducer,
+
+    pylon_duct_leak: BleedAirDuctLeak,
+    wing_duct_leak: BleedAirDuctLeak,
+    pylon_leak_detection_loop: LeakDetectionLoop,
+    wing_leak_detection_loop: LeakDetectionLoop,
+
+    high_pressure_valve_jam: EngineBleedValveJam,
+    pressure_regulating_valve_jam: EngineBleedValveJam,
+    fan_air_valve_jam: EngineBleedValveJam,
+    precooler_blockage_failure: Failure,
+    overpressure_failure: Failure,
+    overtemperature_failure: Failure,
 }
 impl EngineBleedAirSystem {
+    const DUCT_LEAK_SPEED: f64 = 0.1;
+    const PYLON_LEAK_DETECTION_ALARM_TEMPERATURE_C: f64 = 204.;
+    const WING_LEAK_DETECTION_ALARM_TEMPERATURE_C: f64 = 124.;
+
     fn new(context: &mut InitContext, number: usize, powered_by: ElectricalBusType) -> Self {
         Self {
             high_pressure_id: context.get_identifier(format!("PNEU_ENG_{}_HP_PRESSURE", number)),
@@ -811,11 +1053,48 @@ impl EngineBleedAirSystem {
             ),
             engine_starter_exhaust: PneumaticExhaust::new(3e-2, 3e-2, Pressure::new::<psi>(0.)),
             engine_starter_valve: DefaultValve::new_closed(),
+            engine_starter_air_pressure: Pressure::default(),
             precooler: Precooler::new(180. * 2.),
             intermediate_pressure_transducer: PressureTransducer::new(powered_by),
             transfer_pressure_transducer: PressureTransducer::new(powered_by),
             regulated_pressure_transducer: PressureTransducer::new(powered_by),
             differential_pressure_transducer: DifferentialPressureTransducer::new(powered_by),
+            pylon_duct_leak: BleedAirDuctLeak::new(
+                LeakDetectionZone::Pylon(number),
+                Self::DUCT_LEAK_SPEED,
+            ),
+            wing_duct_leak: BleedAirDuctLeak::new(
+                LeakDetectionZone::Wing(number),
+                Self::DUCT_LEAK_SPEED,
+            ),
+            pylon_leak_detection_loop: LeakDetectionLoop::new(
+                context,
+                LeakDetectionZone::Pylon(number),
+                ThermodynamicTemperature::new::<degree_celsius>(
+                    Self::PYLON_LEAK_DETECTION_ALARM_TEMPERATURE_C,
+                ),
+                powered_by,
+            ),
+            wing_leak_detection_loop: LeakDetectionLoop::new(
+                context,
+                LeakDetectionZone::Wing(number),
+                ThermodynamicTemperature::new::<degree_celsius>(
+                    Self::WING_LEAK_DETECTION_ALARM_TEMPERATURE_C,
+                ),
+                powered_by,
+            ),
+            high_pressure_valve_jam: EngineBleedValveJam::new(
+                number,
+                EngineBleedValveId::HighPressure,
+            ),
+            pressure_regulating_valve_jam: EngineBleedValveJam::new(
+                number,
+                EngineBleedValveId::PressureRegulating,
+            ),
+            fan_air_valve_jam: EngineBleedValveJam::new(number, EngineBleedValveId::FanAir),
+            precooler_blockage_failure: Failure::new(FailureType::PrecoolerBlockage(number)),
+            overpressure_failure: Failure::new(FailureType::EngineBleedOverpressure(number)),
+            overtemperature_failure: Failure::new(FailureType::EngineBleedOvertemperature(number)),
         }
     }
 
@@ -843,6 +1122,8 @@ impl EngineBleedAirSystem {
         self.high_pressure_compression_chamber
             .update(&self.high_pressure_compression_chamber_controller);
 
+        self.update_failures();
+
         self.high_pressure_valve
             .update_open_amount(high_pressure_valve_controller);
         self.pressure_regulating_valve
@@ -878,6 +1159,10 @@ impl EngineBleedAirSystem {
             &mut self.precooler_supply_pipe,
             &mut self.precooler_outlet_pipe,
         );
+        self.pylon_duct_leak
+            .update_move_fluid(context, &mut self.precooler_inlet_pipe);
+        self.wing_duct_leak
+            .update_move_fluid(context, &mut self.precooler_outlet_pipe);
         self.engine_starter_valve.update_move_fluid(
             context,
             &mut self.precooler_inlet_pipe,
@@ -885,6 +1170,8 @@ impl EngineBleedAirSystem {
         );
         self.engine_starter_exhaust
             .update_move_fluid(context, &mut self.engine_starter_container);
+        self.engine_starter_air_pressure =
+            self.engine_starter_container.pressure() - context.ambient_pressure();
 
         self.intermediate_pressure_transducer
             .update(context, &self.intermediate_pressure_compression_chamber);
@@ -894,6 +1181,27 @@ impl EngineBleedAirSystem {
             .update(context, &self.precooler_inlet_pipe);
         self.differential_pressure_transducer
             .update(&self.precooler_inlet_pipe, &self.precooler_outlet_pipe);
+
+        self.pylon_leak_detection_loop
+            .update(context, &self.pylon_duct_leak);
+        self.wing_leak_detection_loop
+            .update(context, &self.wing_duct_leak);
+    }
+
+    fn update_failures(&mut self) {
+        self.high_pressure_valve
+            .set_jammed(self.high_pressure_valve_jam.open_amount());
+        self.pressure_regulating_valve
+            .set_jammed(self.pressure_regulating_valve_jam.open_amount());
+        self.fan_air_valve
+            .set_jammed(self.fan_air_valve_jam.open_amount());
+
+        self.pressure_regulating_valve
+            .set_regulation_lost(self.overpressure_failure.is_active());
+        self.precooler
+            .set_blocked(self.precooler_blockage_failure.is_active());
+        self.precooler
+            .set_cooling_lost(self.overtemperature_failure.is_active());
     }
 
     fn intermediate_pressure(&self) -> Pressure {
@@ -942,6 +1250,16 @@ impl EngineBleedAirSystem {
         self.engine_starter_valve.is_open()
     }
 
+    fn engine_starter_air_pressure(&self) -> Pressure {
+        self.engine_starter_air_pressure
+    }
+
+    fn bleed_air_extraction(&self) -> MassRate {
+        self.pressure_regulating_valve
+            .fluid_flow()
+            .max(MassRate::default())
+    }
+
     fn pressure_regulating_valve_is_open(&self) -> bool {
         self.pressure_regulating_valve.is_open()
     }
@@ -961,6 +1279,14 @@ impl EngineBleedAirSystem {
     fn differential_pressure_transducer_pressure(&self) -> Option<Pressure> {
         self.differential_pressure_transducer.signal()
     }
+
+    fn pylon_leak_detected(&self) -> bool {
+        self.pylon_leak_detection_loop.leak_detected()
+    }
+
+    fn wing_leak_detected(&self) -> bool {
+        self.wing_leak_detection_loop.leak_detected()
+    }
 }
 impl SimulationElement for EngineBleedAirSystem {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
@@ -973,6 +1299,18 @@ impl SimulationElement for EngineBleedAirSystem {
         self.regulated_pressure_transducer.accept(visitor);
         self.differential_pressure_transducer.accept(visitor);
 
+        self.pylon_duct_leak.accept(visitor);
+        self.wing_duct_leak.accept(visitor);
+        self.pylon_leak_detection_loop.accept(visitor);
+        self.wing_leak_detection_loop.accept(visitor);
+
+        self.high_pressure_valve_jam.accept(visitor);
+        self.pressure_regulating_valve_jam.accept(visitor);
+        self.fan_air_valve_jam.accept(visitor);
+        self.precooler_blockage_failure.accept(visitor);
+        self.overpressure_failure.accept(visitor);
+        self.overtemperature_failure.accept(visitor);
+
         visitor.visit(self);
     }
 
@@ -1080,6 +1418,7 @@ pub struct A380PneumaticOverheadPanel {
     engine_2_bleed: AutoOffFaultPushButton,
     engine_3_bleed: AutoOffFaultPushButton,
     engine_4_bleed: AutoOffFaultPushButton,
+    wing_anti_ice: WingAntiIcePushButton,
 }
 impl A380PneumaticOverheadPanel {
     pub fn new(context: &mut InitContext) -> Self {
@@ -1090,6 +1429,7 @@ impl A380PneumaticOverheadPanel {
             engine_2_bleed: AutoOffFaultPushButton::new_auto(context, "PNEU_ENG_2_BLEED"),
             engine_3_bleed: AutoOffFaultPushButton::new_auto(context, "PNEU_ENG_3_BLEED"),
             engine_4_bleed: AutoOffFaultPushButton::new_auto(context, "PNEU_ENG_4_BLEED"),
+            wing_anti_ice: WingAntiIcePushButton::new_off(context),
         }
     }
 
@@ -1129,6 +1469,7 @@ impl SimulationElement for A380PneumaticOverheadPanel {
         self.engine_2_bleed.accept(visitor);
         self.engine_3_bleed.accept(visitor);
         self.engine_4_bleed.accept(visitor);
+        self.wing_anti_ice.accept(visitor);
 
         visitor.visit(self);
     }
@@ -1453,13 +1794,13 @@ mod tests {
         shared::{
             arinc429::{Arinc429Word, SignStatus},
             ApuBleedAirValveSignal, CargoDoorLocked, ControllerSignal, ElectricalBusType,
-            ElectricalBuses, EmergencyElectricalState, EngineBleedPushbutton, EngineCorrectedN1,
-            EngineFirePushButtons, EngineStartState, HydraulicColor,
-            InternationalStandardAtmosphere, LgciuWeightOnWheels, MachNumber, PackFlowValveState,
-            PneumaticBleed, PneumaticValve, PotentialOrigin,
+            ElectricalBuses, EmergencyElectricalState, EngineBleedPushbutton, EngineBleedValveId,
+            EngineCorrectedN1, EngineFirePushButtons, EngineStartState, HydraulicColor,
+            InternationalStandardAtmosphere, LeakDetectionZone, LgciuWeightOnWheels, MachNumber,
+            PackFlowValveState, PneumaticBleed, PneumaticValve, PotentialOrigin,
         },
         simulation::{
-            test::{SimulationTestBed, TestBed, WriteByName},
+            test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
             Aircraft, InitContext, SimulationElement, SimulationElementVisitor, UpdateContext,
         },
     };
@@ -1594,25 +1935,25 @@ mod tests {
             self.compressed
         }
         fn right_gear_compressed(&self, _treat_ext_pwr_as_ground: bool) -> bool {
-            true
+            self.compressed
         }
         fn right_gear_extended(&self, _treat_ext_pwr_as_ground: bool) -> bool {
-            false
+            !self.compressed
         }
         fn left_gear_compressed(&self, _treat_ext_pwr_as_ground: bool) -> bool {
-            true
+            self.compressed
         }
         fn left_gear_extended(&self, _treat_ext_pwr_as_ground: bool) -> bool {
-            false
+            !self.compressed
         }
         fn left_and_right_gear_extended(&self, _treat_ext_pwr_as_ground: bool) -> bool {
-            false
+            !self.compressed
         }
         fn nose_gear_compressed(&self, _treat_ext_pwr_as_ground: bool) -> bool {
-            true
+            self.compressed
         }
         fn nose_gear_extended(&self, _treat_ext_pwr_as_ground: bool) -> bool {
-            false
+            !self.compressed
         }
     }
 
@@ -1776,6 +2117,14 @@ mod tests {
         fn set_dc_ess_shed_bus_power(&mut self, is_powered: bool) {
             self.is_dc_ess_shed_powered = is_powered;
         }
+
+        fn set_dc_ess_bus_power(&mut self, is_powered: bool) {
+            self.is_dc_ess_powered = is_powered;
+        }
+
+        fn set_on_ground(&mut self, is_on_ground: bool) {
+            self.lgciu.compressed = is_on_ground;
+        }
     }
     impl Aircraft for PneumaticTestAircraft {
         fn update_before_power_distribution(
@@ -1827,6 +2176,7 @@ mod tests {
                 &self.fire_pushbuttons,
                 &self.apu,
                 &self.air_conditioning,
+                [&self.lgciu; 2],
             );
             self.air_conditioning.update(
                 context,
@@ -1895,6 +2245,10 @@ mod tests {
             self
         }
 
+        fn run_multiple_frames(&mut self, duration: Duration) {
+            self.test_bed.run_multiple_frames(duration);
+        }
+
         fn mach_number(mut self, mach: MachNumber) -> Self {
             self.write_by_name("AIRSPEED MACH", mach);
 
@@ -2311,6 +2665,18 @@ mod tests {
             })
         }
 
+        fn wing_leak_detected(&self, number: usize) -> bool {
+            self.query(|a| a.pneumatic.engine_systems[number - 1].wing_leak_detected())
+        }
+
+        fn pylon_leak_detected(&self, number: usize) -> bool {
+            self.query(|a| a.pneumatic.engine_systems[number - 1].pylon_leak_detected())
+        }
+
+        fn should_close_apu_bleed_valve_because_of_leak(&self) -> bool {
+            self.query(|a| a.pneumatic.should_close_apu_bleed_valve_because_of_leak())
+        }
+
         fn cross_bleed_valve_selector(&self) -> CrossBleedValveSelectorMode {
             self.query(|a| a.pneumatic_overhead_panel.cross_bleed_mode())
         }
@@ -2360,6 +2726,48 @@ mod tests {
             self
         }
 
+        fn set_dc_ess_bus_power(mut self, is_powered: bool) -> Self {
+            self.command(|a| a.set_dc_ess_bus_power(is_powered));
+
+            self
+        }
+
+        fn on_ground(mut self, is_on_ground: bool) -> Self {
+            self.command(|a| a.set_on_ground(is_on_ground));
+
+            self
+        }
+
+        fn wing_anti_ice_push_button(mut self, is_on: bool) -> Self {
+            self.write_by_name("BUTTON_OVHD_ANTI_ICE_WING_POSITION", is_on);
+
+            self
+        }
+
+        fn wing_anti_ice_system_on(&mut self) -> bool {
+            self.read_by_name("PNEU_WING_ANTI_ICE_SYSTEM_ON")
+        }
+
+        fn wing_anti_ice_system_selected(&mut self) -> bool {
+            self.read_by_name("PNEU_WING_ANTI_ICE_SYSTEM_SELECTED")
+        }
+
+        fn wing_anti_ice_has_fault(&mut self) -> bool {
+            self.read_by_name("PNEU_WING_ANTI_ICE_HAS_FAULT")
+        }
+
+        fn wing_anti_ice_valve_closed(&self, number: usize) -> bool {
+            self.query(|a| a.pneumatic.wing_anti_ice.is_wai_valve_closed(number - 1))
+        }
+
+        fn wing_anti_ice_consumer_pressure(&self, number: usize) -> Pressure {
+            self.query(|a| a.pneumatic.wing_anti_ice.wai_consumer_pressure(number - 1))
+        }
+
+        fn wing_anti_ice_mass_flow(&self, number: usize) -> MassRate {
+            self.query(|a| a.pneumatic.wing_anti_ice.wai_mass_flow(number - 1))
+        }
+
         fn left_pack_flow_valve_flow(&self, pack_number: usize) -> MassRate {
             self.query(|a| a.pneumatic.packs[pack_number - 1].left_pack_flow_valve_air_flow())
         }
@@ -3486,4 +3894,403 @@ mod tests {
             assert!(test_bed.right_pack_flow_valve_flow(2) < flow_rate_tolerance());
         }
     }
+
+    mod leak_detection {
+        use super::*;
+
+        fn test_bed_with_engines_at_idle() -> PneumaticTestBed {
+            test_bed_with()
+                .idle_eng1()
+                .idle_eng2()
+                .idle_eng3()
+                .idle_eng4()
+                .and_stabilize()
+        }
+
+        #[test]
+        fn no_leak_is_detected_without_failure() {
+            let mut test_bed = test_bed_with_engines_at_idle();
+            test_bed.run_multiple_frames(Duration::from_secs(30));
+
+            for engine_number in 1..=4 {
+                assert!(!test_bed.wing_leak_detected(engine_number));
+                assert!(!test_bed.pylon_leak_detected(engine_number));
+                assert!(test_bed.pr_valve_is_open(engine_number));
+            }
+        }
+
+        #[rstest]
+        fn wing_leak_closes_the_engine_bleed_valve(#[values(1, 2, 3, 4)] engine_number: usize) {
+            let mut test_bed = test_bed_with_engines_at_idle();
+
+            test_bed.fail(FailureType::BleedAirLeak(LeakDetectionZone::Wing(
+                engine_number,
+            )));
+            test_bed.run_multiple_frames(Duration::from_secs(30));
+
+            for number in 1..=4 {
+                assert_eq!(test_bed.pr_valve_is_open(number), number != engine_number);
+            }
+        }
+
+        #[test]
+        fn pylon_leak_closes_the_engine_bleed_valve() {
+            let mut test_bed = test_bed_with()
+                .eng1_n1(0.9)
+                .eng1_n2(0.95)
+                .idle_eng2()
+                .idle_eng3()
+                .idle_eng4()
+                .and_stabilize();
+
+            test_bed.fail(FailureType::BleedAirLeak(LeakDetectionZone::Pylon(1)));
+            test_bed.run_multiple_frames(Duration::from_secs(30));
+
+            assert!(!test_bed.pr_valve_is_open(1));
+            assert!(test_bed.pr_valve_is_open(2));
+        }
+
+        #[test]
+        fn wing_leak_closes_the_cross_bleed_valves() {
+            let mut test_bed = test_bed_with()
+                .stop_eng1()
+                .stop_eng2()
+                .stop_eng3()
+                .stop_eng4()
+                .cross_bleed_valve_selector_knob(CrossBleedValveSelectorMode::Auto)
+                .set_bleed_air_running()
+                .and_stabilize();
+
+            assert!(test_bed.cross_bleed_valves_are_open());
+
+            test_bed.fail(FailureType::BleedAirLeak(LeakDetectionZone::Wing(2)));
+            test_bed.run_multiple_frames(Duration::from_secs(30));
+
+            assert!(!test_bed.cross_bleed_valves_are_open());
+        }
+
+        #[test]
+        fn apu_leak_closes_the_apu_bleed_valve() {
+            let mut test_bed = test_bed_with()
+                .stop_eng1()
+                .stop_eng2()
+                .stop_eng3()
+                .stop_eng4()
+                .set_bleed_air_running()
+                .and_stabilize();
+
+            assert!(!test_bed.should_close_apu_bleed_valve_because_of_leak());
+
+            test_bed.fail(FailureType::BleedAirLeak(LeakDetectionZone::Apu));
+            test_bed.run_multiple_frames(Duration::from_secs(30));
+
+            assert!(test_bed.should_close_apu_bleed_valve_because_of_leak());
+        }
+
+        #[test]
+        fn leak_is_not_detected_by_failed_loop() {
+            let mut test_bed = test_bed_with_engines_at_idle();
+
+            test_bed.fail(FailureType::LeakDetectionLoop(LeakDetectionZone::Wing(3)));
+            test_bed.fail(FailureType::BleedAirLeak(LeakDetectionZone::Wing(3)));
+            test_bed.run_multiple_frames(Duration::from_secs(30));
+
+            assert!(!test_bed.wing_leak_detected(3));
+            assert!(test_bed.pr_valve_is_open(3));
+        }
+    }
+
+    mod bleed_failures {
+        use super::*;
+
+        fn test_bed_with_engines_at_idle() -> PneumaticTestBed {
+            test_bed_with()
+                .idle_eng1()
+                .idle_eng2()
+                .idle_eng3()
+                .idle_eng4()
+                .and_stabilize()
+        }
+
+        fn test_bed_with_engines_at_climb_thrust() -> PneumaticTestBed {
+            test_bed_with()
+                .eng1_n1(0.75)
+                .eng1_n2(0.9)
+                .eng2_n1(0.75)
+                .eng2_n2(0.9)
+                .eng3_n1(0.75)
+                .eng3_n2(0.9)
+                .eng4_n1(0.75)
+                .eng4_n2(0.9)
+                .and_stabilize()
+        }
+
+        #[rstest]
+        fn pressure_regulating_valve_jammed_closed_removes_engine_bleed(
+            #[values(1, 2, 3, 4)] engine_number: usize,
+        ) {
+            let mut test_bed = test_bed_with_engines_at_idle();
+
+            test_bed.fail(FailureType::EngineBleedValveJammedClosed(
+                engine_number,
+                EngineBleedValveId::PressureRegulating,
+            ));
+            test_bed.run_multiple_frames(Duration::from_secs(30));
+
+            for number in 1..=4 {
+                assert_eq!(test_bed.pr_valve_is_open(number), number != engine_number);
+            }
+        }
+
+        #[test]
+        fn pressure_regulating_valve_jammed_open_remains_open_with_engine_bleed_push_button_off() {
+            let mut test_bed = test_bed_with()
+                .idle_eng1()
+                .idle_eng2()
+                .idle_eng3()
+                .idle_eng4()
+                .set_engine_bleed_push_button_off(1)
+                .set_engine_bleed_push_button_off(2)
+                .and_stabilize();
+
+            test_bed.fail(FailureType::EngineBleedValveJammedOpen(
+                1,
+                EngineBleedValveId::PressureRegulating,
+            ));
+            test_bed.run_multiple_frames(Duration::from_secs(30));
+
+            assert!(test_bed.pr_valve_is_open(1));
+            assert!(!test_bed.pr_valve_is_open(2));
+        }
+
+        #[test]
+        fn high_pressure_valve_jammed_closed_lowers_transfer_pressure_at_idle() {
+            let mut test_bed = test_bed_with_engines_at_idle();
+
+            test_bed.fail(FailureType::EngineBleedValveJammedClosed(
+                1,
+                EngineBleedValveId::HighPressure,
+            ));
+            test_bed.run_multiple_frames(Duration::from_secs(30));
+
+            assert!(!test_bed.hp_valve_is_open(1));
+            assert!(
+                test_bed.transfer_pressure(1)
+                    < test_bed.transfer_pressure(2) - Pressure::new::<psi>(10.)
+            );
+        }
+
+        #[test]
+        fn fan_air_valve_jammed_closed_raises_precooler_outlet_temperature() {
+            let mut test_bed = test_bed_with_engines_at_climb_thrust();
+
+            test_bed.fail(FailureType::EngineBleedValveJammedClosed(
+                1,
+                EngineBleedValveId::FanAir,
+            ));
+            test_bed.run_multiple_frames(Duration::from_secs(30));
+
+            assert!(
+                test_bed
+                    .precooler_outlet_temperature(1)
+                    .get::<degree_celsius>()
+                    > test_bed
+                        .precooler_outlet_temperature(2)
+                        .get::<degree_celsius>()
+                        + 5.
+            );
+        }
+
+        #[test]
+        fn precooler_blockage_lowers_precooler_outlet_pressure() {
+            let mut test_bed = test_bed_with_engines_at_idle();
+
+            test_bed.fail(FailureType::PrecoolerBlockage(1));
+            test_bed.run_multiple_frames(Duration::from_secs(30));
+
+            assert!(
+                test_bed.precooler_outlet_pressure(1)
+                    < test_bed.precooler_outlet_pressure(2) - Pressure::new::<psi>(2.)
+            );
+        }
+
+        #[test]
+        fn overtemperature_raises_precooler_outlet_temperature() {
+            let mut test_bed = test_bed_with_engines_at_climb_thrust();
+
+            test_bed.fail(FailureType::EngineBleedOvertemperature(1));
+            test_bed.run_multiple_frames(Duration::from_secs(30));
+
+            assert!(
+                test_bed
+                    .precooler_outlet_temperature(1)
+                    .get::<degree_celsius>()
+                    > test_bed
+                        .precooler_outlet_temperature(2)
+                        .get::<degree_celsius>()
+                        + 5.
+            );
+        }
+
+        #[test]
+        fn overheat_closes_the_engine_bleed_valve() {
+            let mut test_bed = test_bed_with_engines_at_climb_thrust();
+
+            test_bed.fail(FailureType::EngineBleedOvertemperature(1));
+            test_bed.fail(FailureType::EngineBleedValveJammedOpen(
+                1,
+                EngineBleedValveId::HighPressure,
+            ));
+
+            let mut overheat_detected = false;
+            for _ in 0..60 {
+                test_bed.run_multiple_frames(Duration::from_secs(1));
+                overheat_detected |= test_bed.query(|a| {
+                    a.pneumatic.core_processing_input_output_module_a.units[0].has_overheat()
+                });
+            }
+
+            assert!(overheat_detected);
+            assert!(!test_bed.pr_valve_is_open(1));
+            assert!(test_bed.pr_valve_is_open(2));
+        }
+
+        #[test]
+        fn overpressure_closes_the_engine_bleed_valve() {
+            let mut test_bed = test_bed_with_engines_at_climb_thrust();
+
+            test_bed.fail(FailureType::EngineBleedOverpressure(1));
+
+            let mut overpressure_detected = false;
+            for _ in 0..60 {
+                test_bed.run_multiple_frames(Duration::from_secs(1));
+                overpressure_detected |= test_bed.query(|a| {
+                    a.pneumatic.core_processing_input_output_module_a.units[0].has_overpressure()
+                });
+            }
+
+            assert!(overpressure_detected);
+            assert!(!test_bed.pr_valve_is_open(1));
+            assert!(test_bed.pr_valve_is_open(2));
+        }
+
+        #[rstest]
+        fn failed_cpiom_closes_its_engine_bleed_valve(#[values(1, 2, 3, 4)] engine_number: usize) {
+            let mut test_bed = test_bed_with_engines_at_idle();
+
+            test_bed.fail(FailureType::BleedMonitoringComputer(engine_number));
+            test_bed.run_multiple_frames(Duration::from_secs(30));
+
+            for number in 1..=4 {
+                assert_eq!(test_bed.pr_valve_is_open(number), number != engine_number);
+            }
+        }
+
+        #[test]
+        fn failed_cpioms_hand_cross_bleed_valve_control_over_to_backup_cpioms() {
+            let mut test_bed = test_bed_with()
+                .stop_eng1()
+                .stop_eng2()
+                .stop_eng3()
+                .stop_eng4()
+                .cross_bleed_valve_selector_knob(CrossBleedValveSelectorMode::Shut)
+                .set_bleed_air_running()
+                .and_stabilize();
+
+            assert!(!test_bed.cross_bleed_valves_are_open());
+
+            test_bed.fail(FailureType::BleedMonitoringComputer(1));
+            test_bed.fail(FailureType::BleedMonitoringComputer(3));
+            test_bed = test_bed.cross_bleed_valve_selector_knob(CrossBleedValveSelectorMode::Auto);
+            test_bed.run_multiple_frames(Duration::from_secs(30));
+
+            assert!(test_bed.cross_bleed_valves_are_open());
+        }
+    }
+
+    mod wing_anti_ice {
+        use super::*;
+
+        fn test_bed_in_flight() -> PneumaticTestBed {
+            test_bed_with()
+                .idle_eng1()
+                .idle_eng2()
+                .idle_eng3()
+                .idle_eng4()
+                .mach_number(MachNumber(0.35))
+                .in_isa_atmosphere(Length::new::<foot>(2000.))
+                .on_ground(false)
+                .and_stabilize()
+        }
+
+        #[test]
+        fn wing_anti_ice_is_off_by_default() {
+            let mut test_bed = test_bed_in_flight();
+
+            assert!(test_bed.wing_anti_ice_valve_closed(1));
+            assert!(test_bed.wing_anti_ice_valve_closed(2));
+            assert!(!test_bed.wing_anti_ice_system_on());
+            assert!(!test_bed.wing_anti_ice_system_selected());
+            assert!(!test_bed.wing_anti_ice_has_fault());
+        }
+
+        #[test]
+        fn wing_anti_ice_opens_in_flight_and_consumes_bleed_air() {
+            let mut test_bed = test_bed_in_flight()
+                .wing_anti_ice_push_button(true)
+                .and_stabilize();
+
+            let ambient_pressure =
+                InternationalStandardAtmosphere::pressure_at_altitude(Length::new::<foot>(2000.));
+            for number in 1..=2 {
+                assert!(!test_bed.wing_anti_ice_valve_closed(number));
+                assert!(
+                    test_bed.wing_anti_ice_consumer_pressure(number) - ambient_pressure
+                        > Pressure::new::<psi>(10.)
+                );
+                assert!(test_bed.wing_anti_ice_mass_flow(number) > flow_rate_tolerance());
+            }
+            assert!(test_bed.wing_anti_ice_system_on());
+            assert!(test_bed.wing_anti_ice_system_selected());
+            assert!(!test_bed.wing_anti_ice_has_fault());
+        }
+
+        #[test]
+        fn wing_anti_ice_closes_at_the_end_of_the_ground_test() {
+            let mut test_bed = test_bed_with()
+                .idle_eng1()
+                .idle_eng2()
+                .idle_eng3()
+                .idle_eng4()
+                .and_stabilize()
+                .wing_anti_ice_push_button(true);
+            test_bed.run_multiple_frames(Duration::from_secs(5));
+
+            assert!(test_bed.wing_anti_ice_system_on());
+
+            test_bed.run_multiple_frames(Duration::from_secs(10));
+
+            assert!(!test_bed.wing_anti_ice_system_on());
+            assert!(test_bed.wing_anti_ice_system_selected());
+
+            test_bed = test_bed.and_stabilize();
+
+            assert!(test_bed.wing_anti_ice_valve_closed(1));
+            assert!(test_bed.wing_anti_ice_valve_closed(2));
+            assert!(!test_bed.wing_anti_ice_has_fault());
+        }
+
+        #[test]
+        fn wing_anti_ice_does_not_work_without_dc_ess_bus() {
+            let mut test_bed = test_bed_in_flight()
+                .set_dc_ess_bus_power(false)
+                .wing_anti_ice_push_button(true)
+                .and_stabilize();
+
+            assert!(test_bed.wing_anti_ice_valve_closed(1));
+            assert!(test_bed.wing_anti_ice_valve_closed(2));
+            assert!(!test_bed.wing_anti_ice_system_on());
+            assert!(test_bed.wing_anti_ice_system_selected());
+        }
+    }
 }
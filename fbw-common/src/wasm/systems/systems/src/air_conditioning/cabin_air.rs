@@ -3,8 +3,8 @@ use crate::{
     failures::{Failure, FailureType},
     shared::{AverageExt, CabinSimulation},
     simulation::{
-        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
-        VariableIdentifier, Write,
+        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, SnapshotReader,
+        SnapshotWriter, UpdateContext, VariableIdentifier, Write,
     },
 };
 use num_traits::Pow;
@@ -26,6 +26,8 @@ use bounded_vec_deque::BoundedVecDeque;
 use std::{convert::TryInto, marker::PhantomData};
 
 pub struct CabinAirSimulation<C, const ZONES: usize> {
+    cabin_pressure_id: VariableIdentifier,
+
     is_initialised: bool,
     previous_exterior_pressure: BoundedVecDeque<Pressure>,
     filtered_exterior_pressure: Pressure,
@@ -46,6 +48,8 @@ pub struct CabinAirSimulation<C, const ZONES: usize> {
 impl<C: PressurizationConstants, const ZONES: usize> CabinAirSimulation<C, ZONES> {
     pub fn new(context: &mut InitContext, cabin_zone_ids: &[ZoneType; ZONES]) -> Self {
         Self {
+            cabin_pressure_id: context.get_identifier("CABIN_AIR_PRESSURE".to_owned()),
+
             is_initialised: false,
             previous_exterior_pressure: BoundedVecDeque::from_iter(
                 [Pressure::new::<hectopascal>(1013.25); 20],
@@ -384,6 +388,27 @@ impl<C: PressurizationConstants, const ZONES: usize> SimulationElement
 
         visitor.visit(self);
     }
+
+    fn save_state(&self, writer: &mut SnapshotWriter) {
+        if self.is_initialised {
+            writer.write(&self.cabin_pressure_id, self.internal_air.pressure());
+        }
+    }
+
+    fn restore_state(&mut self, reader: &mut SnapshotReader) {
+        if let Some(pressure) = reader.restore(&self.cabin_pressure_id) {
+            // The zones have been restored before the cabin itself.
+            let average_temperature: ThermodynamicTemperature = self
+                .cabin_zones
+                .iter()
+                .map(|zone| zone.zone_air_temperature())
+                .average();
+
+            self.internal_air.set_pressure(pressure);
+            self.internal_air.set_temperature(average_temperature);
+            self.is_initialised = true;
+        }
+    }
 }
 
 pub struct CabinZone<C> {
@@ -475,6 +500,16 @@ impl<C: PressurizationConstants> SimulationElement for CabinZone<C> {
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.zone_identifier, self.zone_air_temperature());
     }
+
+    fn save_state(&self, writer: &mut SnapshotWriter) {
+        writer.write(&self.zone_identifier, self.zone_air_temperature());
+    }
+
+    fn restore_state(&mut self, reader: &mut SnapshotReader) {
+        if let Some(temperature) = reader.restore(&self.zone_identifier) {
+            self.set_zone_air_temperature(temperature);
+        }
+    }
 }
 
 struct ZoneAir {
@@ -8,8 +8,8 @@ use uom::si::{
 use crate::{
     electrical::{
         ElectricalElement, ElectricalElementIdentifier, ElectricalElementIdentifierProvider,
-        ElectricalStateWriter, ElectricitySource, Potential, ProvideFrequency, ProvideLoad,
-        ProvidePotential,
+        ElectricalStateWriter, ElectricitySource, GeneratorControlUnit, Potential,
+        ProvideFrequency, ProvideLoad, ProvidePotential,
     },
     failures::{Failure, FailureType},
     shared::{
@@ -19,7 +19,10 @@ use crate::{
     simulation::{InitContext, SimulationElement, SimulatorWriter, UpdateContext},
 };
 
-use super::{ApuConstants, ApuGenerator, ApuStartMotor, Turbine, TurbineSignal, TurbineState};
+use super::{
+    ApuConstants, ApuGenerator, ApuStartMotor, FuelControlUnitRunaway, IntakeConditions, Turbine,
+    TurbineSignal, TurbineState,
+};
 
 pub struct Aps3200Constants;
 
@@ -52,6 +55,7 @@ impl Turbine for ShutdownAps3200Turbine {
         context: &UpdateContext,
         _: bool,
         _: bool,
+        _: bool,
         controller: &dyn ControllerSignal<TurbineSignal>,
     ) -> Box<dyn Turbine> {
         self.egt = calculate_towards_ambient_egt(self.egt, context);
@@ -70,6 +74,10 @@ impl Turbine for ShutdownAps3200Turbine {
         self.egt
     }
 
+    fn restore_egt(&mut self, egt: ThermodynamicTemperature) {
+        self.egt = egt;
+    }
+
     fn state(&self) -> TurbineState {
         TurbineState::Shutdown
     }
@@ -95,7 +103,14 @@ impl Starting {
         }
     }
 
-    fn calculate_egt(&mut self, context: &UpdateContext) -> ThermodynamicTemperature {
+    fn calculate_egt(
+        &mut self,
+        context: &UpdateContext,
+        fuel_control_unit_has_failed: bool,
+    ) -> ThermodynamicTemperature {
+        // The additional EGT rise of a start which only just has enough power to complete.
+        const SLOW_START_FACTOR: f64 = 0.6;
+
         // Refer to APS3200.md for details on the values below and source data.
         const APU_N_TEMP_CONST: f64 = -92.3417137705543;
         const APU_N_TEMP_X: f64 = -14.36417426895237;
@@ -114,7 +129,7 @@ impl Starting {
 
         let n = self.n.get::<percent>();
 
-        let temperature = ThermodynamicTemperature::new::<degree_celsius>(
+        let reference_temperature = ThermodynamicTemperature::new::<degree_celsius>(
             APU_N_TEMP_CONST
                 + (APU_N_TEMP_X * n)
                 + (APU_N_TEMP_X2 * n.powi(2))
@@ -130,6 +145,11 @@ impl Starting {
                 + (APU_N_TEMP_X12 * n.powi(12))
                 + (APU_N_TEMP_X13 * n.powi(13)),
         );
+        let mut temperature =
+            IntakeConditions::new(context).starting_egt(reference_temperature, SLOW_START_FACTOR);
+        if fuel_control_unit_has_failed {
+            temperature = FuelControlUnitRunaway::starting_egt(context, temperature);
+        }
 
         // The above calculated EGT can be lower than the ambient temperature,
         // or the current APU EGT (when cooling down). To prevent sudden changes
@@ -198,16 +218,19 @@ impl Turbine for Starting {
         context: &UpdateContext,
         _: bool,
         _: bool,
+        fuel_control_unit_has_failed: bool,
         controller: &dyn ControllerSignal<TurbineSignal>,
     ) -> Box<dyn Turbine> {
-        self.since += context.delta();
+        self.since += context
+            .delta()
+            .mul_f64(IntakeConditions::new(context).start_acceleration_factor());
         if context.aircraft_preset_quick_mode() {
             self.n = Ratio::new::<percent>(100.);
             println!("apu/apu3200.rs: Aircraft Preset Quick Mode is active, setting N to 100%.");
         } else {
             self.n = self.calculate_n();
         };
-        self.egt = self.calculate_egt(context);
+        self.egt = self.calculate_egt(context, fuel_control_unit_has_failed);
 
         match controller.signal() {
             Some(TurbineSignal::Stop) | None => Box::new(Stopping::new(self.egt, self.n)),
@@ -344,49 +367,61 @@ impl ApuGenUsageEgtDelta {
 struct Running {
     egt: ThermodynamicTemperature,
     base_egt: ThermodynamicTemperature,
-    base_egt_deviation: TemperatureInterval,
+    base_egt_deviation: Option<TemperatureInterval>,
     bleed_air_usage: BleedAirUsageEgtDelta,
     apu_gen_usage: ApuGenUsageEgtDelta,
+    bleed_air_pressure: Pressure,
+    n: Ratio,
 }
 impl Running {
+    // TODO: Figure out what value this is supposed to be.
+    const REFERENCE_BLEED_AIR_PRESSURE_PSI: f64 = 50.;
+
     fn new(egt: ThermodynamicTemperature) -> Running {
         let base_egt = 340. + ((random_number() % 11) as f64);
         Running {
             egt,
             base_egt: ThermodynamicTemperature::new::<degree_celsius>(base_egt),
-            // This contains the deviation from the base EGT at the moment of entering the running state.
-            // This code assumes the base EGT is lower than the EGT at this point in time, which is always the case.
-            // Should this change in the future, then changes have to be made here.
-            base_egt_deviation: TemperatureInterval::new::<temperature_interval::degree_celsius>(
-                egt.get::<degree_celsius>() - base_egt,
-            ),
+            base_egt_deviation: None,
             bleed_air_usage: BleedAirUsageEgtDelta::new(),
             apu_gen_usage: ApuGenUsageEgtDelta::new(),
+            bleed_air_pressure: Pressure::new::<psi>(Self::REFERENCE_BLEED_AIR_PRESSURE_PSI),
+            n: Ratio::new::<percent>(100.),
         }
     }
 
     fn calculate_egt(
         &mut self,
         context: &UpdateContext,
+        conditions: &IntakeConditions,
         apu_gen_is_used: bool,
         apu_bleed_is_used: bool,
     ) -> ThermodynamicTemperature {
-        // Reduce the deviation by 1 per second to slowly creep back to normal temperatures
-        self.base_egt_deviation -= TemperatureInterval::new::<temperature_interval::degree_celsius>(
-            (context.delta_as_secs_f64() * 1.).min(
-                self.base_egt_deviation
-                    .get::<temperature_interval::degree_celsius>(),
-            ),
+        self.apu_gen_usage.update(context, apu_gen_is_used);
+        self.bleed_air_usage.update(context, apu_bleed_is_used);
+        let target = conditions.running_egt(
+            self.base_egt,
+            self.apu_gen_usage.egt_delta() + self.bleed_air_usage.egt_delta(),
         );
 
-        let mut target = self.base_egt + self.base_egt_deviation;
-        self.apu_gen_usage.update(context, apu_gen_is_used);
-        target += self.apu_gen_usage.egt_delta();
+        // This contains the deviation from the base EGT at the moment of entering the running state.
+        let deviation = self
+            .base_egt_deviation
+            .get_or_insert(TemperatureInterval::new::<
+                temperature_interval::degree_celsius,
+            >(
+                self.egt.get::<degree_celsius>() - target.get::<degree_celsius>(),
+            ))
+            .get::<temperature_interval::degree_celsius>();
 
-        self.bleed_air_usage.update(context, apu_bleed_is_used);
-        target += self.bleed_air_usage.egt_delta();
+        // Reduce the deviation by 1 per second to slowly creep back to normal temperatures
+        let reduction = (context.delta_as_secs_f64() * 1.).min(deviation.abs());
+        let deviation = deviation - reduction * deviation.signum();
+        self.base_egt_deviation = Some(TemperatureInterval::new::<
+            temperature_interval::degree_celsius,
+        >(deviation));
 
-        target
+        target + TemperatureInterval::new::<temperature_interval::degree_celsius>(deviation)
     }
 }
 impl Turbine for Running {
@@ -395,20 +430,28 @@ impl Turbine for Running {
         context: &UpdateContext,
         apu_bleed_is_used: bool,
         apu_gen_is_used: bool,
+        fuel_control_unit_has_failed: bool,
         controller: &dyn ControllerSignal<TurbineSignal>,
     ) -> Box<dyn Turbine> {
-        self.egt = self.calculate_egt(context, apu_gen_is_used, apu_bleed_is_used);
+        let conditions = IntakeConditions::new(context);
+        self.egt = self.calculate_egt(context, &conditions, apu_gen_is_used, apu_bleed_is_used);
+        self.bleed_air_pressure = conditions.compressor_delivery_pressure(Pressure::new::<psi>(
+            Self::REFERENCE_BLEED_AIR_PRESSURE_PSI,
+        ));
+        self.n = if fuel_control_unit_has_failed {
+            FuelControlUnitRunaway::running_n(context, self.n)
+        } else {
+            Ratio::new::<percent>(100.)
+        };
 
         match controller.signal() {
             Some(TurbineSignal::StartOrContinue) => self,
-            Some(TurbineSignal::Stop) | None => {
-                Box::new(Stopping::new(self.egt, Ratio::new::<percent>(100.)))
-            }
+            Some(TurbineSignal::Stop) | None => Box::new(Stopping::new(self.egt, self.n)),
         }
     }
 
     fn n(&self) -> Ratio {
-        Ratio::new::<percent>(100.)
+        self.n
     }
 
     fn egt(&self) -> ThermodynamicTemperature {
@@ -420,8 +463,7 @@ impl Turbine for Running {
     }
 
     fn bleed_air_pressure(&self) -> Pressure {
-        // TODO: Figure out what value this is supposed to be.
-        Pressure::new::<psi>(50.)
+        self.bleed_air_pressure
     }
 }
 
@@ -529,6 +571,7 @@ impl Turbine for Stopping {
         context: &UpdateContext,
         _: bool,
         _: bool,
+        _: bool,
         _: &dyn ControllerSignal<TurbineSignal>,
     ) -> Box<dyn Turbine> {
         self.since += context.delta();
@@ -588,10 +631,13 @@ pub struct Aps3200ApuGenerator {
     output_potential: ElectricPotential,
     load: Ratio,
     is_emergency_shutdown: bool,
+    gcu: GeneratorControlUnit,
     failure: Failure,
 }
 impl Aps3200ApuGenerator {
     pub(super) const APU_GEN_POWERED_N: f64 = 84.;
+    // Just below the frequency the generator reaches at APU_GEN_POWERED_N.
+    const APU_GEN_EXCITATION_FREQUENCY: f64 = 330.;
 
     pub fn new(context: &mut InitContext, number: usize) -> Aps3200ApuGenerator {
         Aps3200ApuGenerator {
@@ -603,6 +649,12 @@ impl Aps3200ApuGenerator {
             output_frequency: Frequency::new::<hertz>(0.),
             load: Ratio::new::<percent>(0.),
             is_emergency_shutdown: false,
+            gcu: GeneratorControlUnit::new(
+                context,
+                PotentialOrigin::ApuGenerator(number),
+                390.0..=410.0,
+                Self::APU_GEN_EXCITATION_FREQUENCY,
+            ),
             failure: Failure::new(FailureType::ApuGenerator(number)),
         }
     }
@@ -648,16 +700,39 @@ impl Aps3200ApuGenerator {
         }
     }
 
+    fn is_spinning_fast_enough(&self) -> bool {
+        self.n.get::<percent>() >= Aps3200ApuGenerator::APU_GEN_POWERED_N
+    }
+
+    fn can_provide_output(&self) -> bool {
+        !self.failure.is_active() && !self.is_emergency_shutdown && self.is_spinning_fast_enough()
+    }
+
     fn should_provide_output(&self) -> bool {
-        !self.failure.is_active()
-            && !self.is_emergency_shutdown
-            && self.n.get::<percent>() >= Aps3200ApuGenerator::APU_GEN_POWERED_N
+        self.can_provide_output() && self.gcu.generator_is_excited()
     }
 }
 impl ApuGenerator for Aps3200ApuGenerator {
-    fn update(&mut self, n: Ratio, is_emergency_shutdown: bool) {
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        n: Ratio,
+        is_emergency_shutdown: bool,
+        push_button_is_on: bool,
+    ) {
         self.n = n;
         self.is_emergency_shutdown = is_emergency_shutdown;
+
+        let frequency = if self.is_spinning_fast_enough() {
+            self.calculate_frequency(n)
+        } else {
+            Frequency::new::<hertz>(0.)
+        };
+        self.gcu.update(
+            context,
+            push_button_is_on && !is_emergency_shutdown,
+            frequency,
+        );
     }
 
     /// Indicates if the provided electricity's potential and frequency
@@ -667,7 +742,7 @@ impl ApuGenerator for Aps3200ApuGenerator {
     /// overtemperature which over time will trigger a mechanical
     /// disconnect of the generator.
     fn output_within_normal_parameters(&self) -> bool {
-        self.should_provide_output() && self.potential_normal() && self.frequency_normal()
+        self.should_provide_output() && self.gcu.line_contactor_should_close()
     }
 }
 provide_potential!(Aps3200ApuGenerator, (110.0..=120.0));
@@ -700,6 +775,7 @@ impl ElectricitySource for Aps3200ApuGenerator {
 }
 impl SimulationElement for Aps3200ApuGenerator {
     fn accept<T: crate::simulation::SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.gcu.accept(visitor);
         self.failure.accept(visitor);
         visitor.visit(self);
     }
@@ -713,11 +789,11 @@ impl SimulationElement for Aps3200ApuGenerator {
         _: &UpdateContext,
         report: &T,
     ) {
-        self.output_potential = if self.should_provide_output() {
+        self.output_potential = self.gcu.regulate(if self.can_provide_output() {
             self.calculate_potential(self.n)
         } else {
             ElectricPotential::new::<volt>(0.)
-        };
+        });
 
         self.output_frequency = if self.should_provide_output() {
             self.calculate_frequency(self.n)
@@ -745,6 +821,7 @@ pub struct Aps3200StartMotor {
     powered_by: ElectricalBusType,
     is_powered: bool,
     powered_since: Duration,
+    failure: Failure,
 }
 impl Aps3200StartMotor {
     pub fn new(powered_by: ElectricalBusType) -> Self {
@@ -752,6 +829,7 @@ impl Aps3200StartMotor {
             powered_by,
             is_powered: false,
             powered_since: Duration::from_secs(0),
+            failure: Failure::new(FailureType::ApuStartMotor),
         }
     }
 }
@@ -759,14 +837,23 @@ impl ApuStartMotor for Aps3200StartMotor {
     fn is_powered(&self) -> bool {
         self.is_powered
     }
+
+    fn is_turning(&self) -> bool {
+        self.is_powered && !self.failure.is_active()
+    }
 }
 impl SimulationElement for Aps3200StartMotor {
+    fn accept<T: crate::simulation::SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+        visitor.visit(self);
+    }
+
     fn receive_power(&mut self, buses: &impl ElectricalBuses) {
         self.is_powered = buses.is_powered(self.powered_by);
     }
 
     fn consume_power<T: ConsumePower>(&mut self, context: &UpdateContext, consumption: &mut T) {
-        if !self.is_powered {
+        if !self.is_turning() {
             self.powered_since = Duration::from_secs(0);
         } else {
             self.powered_since += context.delta();
@@ -980,6 +1067,17 @@ mod apu_generator_tests {
         assert!(!test_bed.load_within_normal_range());
     }
 
+    #[test]
+    fn when_apu_gen_push_button_off_provides_no_output() {
+        let test_bed = test_bed_with()
+            .running_apu()
+            .and()
+            .apu_gen_push_button_off()
+            .run(Duration::from_secs(1));
+
+        assert!(test_bed.generator_is_unpowered());
+    }
+
     #[test]
     fn when_apu_emergency_shutdown_provides_no_output() {
         let test_bed = test_bed_with()
@@ -1010,16 +1108,16 @@ mod apu_generator_tests {
     }
 
     fn update_above_threshold(test_bed: &mut SimulationTestBed<TestAircraft<Aps3200ApuGenerator>>) {
-        test_bed.set_update_before_power_distribution(|generator, _, electricity| {
-            generator.update(Ratio::new::<percent>(100.), false);
+        test_bed.set_update_before_power_distribution(|generator, context, electricity| {
+            generator.update(context, Ratio::new::<percent>(100.), false, true);
             electricity.supplied_by(generator);
         });
         test_bed.run();
     }
 
     fn update_below_threshold(test_bed: &mut SimulationTestBed<TestAircraft<Aps3200ApuGenerator>>) {
-        test_bed.set_update_before_power_distribution(|generator, _, electricity| {
-            generator.update(Ratio::new::<percent>(0.), false);
+        test_bed.set_update_before_power_distribution(|generator, context, electricity| {
+            generator.update(context, Ratio::new::<percent>(0.), false, true);
             electricity.supplied_by(generator);
         });
         test_bed.run();
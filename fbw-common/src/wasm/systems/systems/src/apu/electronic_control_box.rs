@@ -9,12 +9,16 @@ use uom::si::{
 use crate::shared::{EngineCorrectedN1, LgciuWeightOnWheels};
 use crate::simulation::{InitContext, SimulatorReader, VariableIdentifier};
 use crate::{
+    failures::{Failure, FailureType},
     pneumatic::PneumaticValveSignal,
     shared::{
         arinc429::SignStatus, ApuBleedAirValveSignal, ApuMaster, ApuStart, ConsumePower,
-        ContactorSignal, ControllerSignal, ElectricalBusType, ElectricalBuses, PneumaticValve,
+        ContactorSignal, ControllerSignal, ElectricalBusType, ElectricalBuses,
+        InternationalStandardAtmosphere, PneumaticValve,
+    },
+    simulation::{
+        Read, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext, Write,
     },
-    simulation::{Read, SimulationElement, SimulatorWriter, UpdateContext, Write},
 };
 
 use super::ApuConstants;
@@ -59,6 +63,8 @@ pub(super) struct ElectronicControlBox<C: ApuConstants> {
     egt: ThermodynamicTemperature,
     egt_warning_temperature: ThermodynamicTemperature,
     n_above_95_duration: Duration,
+    starting_duration: Duration,
+    air_intake_flap_opening_duration: Duration,
     fire_button_is_released: bool,
     engines_on: bool,
     on_ground: bool,
@@ -68,11 +74,19 @@ pub(super) struct ElectronicControlBox<C: ApuConstants> {
     /// of the aircraft.
     /// In the context of the ecb this means that the APU cooldown is skipped.
     aircraft_preset_quick_mode: bool,
+    speed_sensor_failures: [Failure; 2],
+    egt_sensor_failure: Failure,
+    inlet_pressure_sensor_failure: Failure,
 
     constants: PhantomData<C>,
 }
 impl<C: ApuConstants> ElectronicControlBox<C> {
     const START_MOTOR_POWERED_UNTIL_N: f64 = 55.;
+    // A start which doesn't complete within this time is considered hung.
+    const START_TIME_LIMIT: Duration = Duration::from_secs(180);
+    // An air intake flap which doesn't open within this time is considered failed.
+    const AIR_INTAKE_FLAP_OPENING_TIME_LIMIT: Duration = Duration::from_secs(30);
+    const OVERSPEED_N: f64 = 107.;
     const JET_A_1_DENSITY: f64 = 0.804; // Kilograms per Liter
 
     pub fn new(context: &mut InitContext, powered_by: ElectricalBusType) -> Self {
@@ -116,11 +130,16 @@ impl<C: ApuConstants> ElectronicControlBox<C> {
                 C::RUNNING_WARNING_EGT,
             ),
             n_above_95_duration: Duration::from_secs(0),
+            starting_duration: Duration::ZERO,
+            air_intake_flap_opening_duration: Duration::ZERO,
             fire_button_is_released: false,
             engines_on: false,
             on_ground: false,
             inlet_pressure: Pressure::new::<bar>(0.94),
             aircraft_preset_quick_mode: false,
+            speed_sensor_failures: [1, 2].map(|i| Failure::new(FailureType::ApuSpeedSensor(i))),
+            egt_sensor_failure: Failure::new(FailureType::ApuEgtSensor),
+            inlet_pressure_sensor_failure: Failure::new(FailureType::ApuInletPressureSensor),
 
             constants: PhantomData,
         }
@@ -163,8 +182,13 @@ impl<C: ApuConstants> ElectronicControlBox<C> {
     }
 
     pub fn update_air_intake_state(&mut self, context: &UpdateContext) {
-        // FIXME simulate sensor failure (with fallback value logic)
-        self.inlet_pressure = context.ambient_pressure();
+        // Without a measured inlet pressure the ECB falls back to sea level pressure,
+        // which results in the more restrictive starting EGT limit.
+        self.inlet_pressure = if self.inlet_pressure_sensor_failure.is_active() {
+            InternationalStandardAtmosphere::pressure_at_altitude(Length::default())
+        } else {
+            context.ambient_pressure()
+        };
     }
 
     pub fn update_start_motor_state(&mut self, start_motor: &impl ApuStartMotor) {
@@ -173,9 +197,12 @@ impl<C: ApuConstants> ElectronicControlBox<C> {
         if matches!(
             <ElectronicControlBox<C> as ControllerSignal<ContactorSignal>>::signal(self),
             Some(ContactorSignal::Close)
-        ) && !self.start_motor_is_powered
-        {
-            self.fault = Some(ApuFault::DcPowerLoss);
+        ) {
+            if !self.start_motor_is_powered {
+                self.fault = Some(ApuFault::DcPowerLoss);
+            } else if self.fault.is_none() && !start_motor.is_turning() {
+                self.fault = Some(ApuFault::StartMotor);
+            }
         }
     }
 
@@ -198,6 +225,24 @@ impl<C: ApuConstants> ElectronicControlBox<C> {
             self.n_above_95_duration = Duration::from_secs(0);
         }
 
+        if self.turbine_state == TurbineState::Starting {
+            self.starting_duration += context.delta();
+        } else {
+            self.starting_duration = Duration::ZERO;
+        }
+
+        if matches!(
+            <ElectronicControlBox<C> as ControllerSignal<AirIntakeFlapSignal>>::signal(self),
+            Some(AirIntakeFlapSignal::Open)
+        ) && !self.air_intake_flap_is_fully_open()
+        {
+            self.air_intake_flap_opening_duration += context.delta();
+        } else {
+            self.air_intake_flap_opening_duration = Duration::ZERO;
+        }
+
+        self.update_protective_shutdown();
+
         if !self.is_on() {
             self.fault = None;
         }
@@ -209,6 +254,50 @@ impl<C: ApuConstants> ElectronicControlBox<C> {
         }
     }
 
+    fn update_protective_shutdown(&mut self) {
+        if self.fault.is_some() {
+            return;
+        }
+
+        let is_starting_or_running = matches!(
+            self.turbine_state,
+            TurbineState::Starting | TurbineState::Running
+        );
+        self.fault = if is_starting_or_running && !self.speed_is_measured() {
+            // The turbine cannot be protected against overspeed without a speed measurement.
+            Some(ApuFault::SpeedSensor)
+        } else if self.n.get::<percent>() > Self::OVERSPEED_N {
+            Some(ApuFault::Overspeed)
+        } else if self.turbine_state == TurbineState::Starting && !self.egt_is_measured() {
+            // A start cannot be protected against overtemperature without an EGT measurement.
+            Some(ApuFault::EgtSensor)
+        } else if is_starting_or_running
+            && self.egt_is_measured()
+            && self.egt > self.egt_warning_temperature
+        {
+            Some(ApuFault::EgtOvertemperature)
+        } else if self.turbine_state == TurbineState::Starting
+            && self.starting_duration > Self::START_TIME_LIMIT
+        {
+            Some(ApuFault::HungStart)
+        } else if self.air_intake_flap_opening_duration > Self::AIR_INTAKE_FLAP_OPENING_TIME_LIMIT {
+            Some(ApuFault::AirIntakeFlap)
+        } else {
+            None
+        };
+    }
+
+    /// The ECB measures N using two speed sensors. Either sensor suffices.
+    fn speed_is_measured(&self) -> bool {
+        self.speed_sensor_failures
+            .iter()
+            .any(|failure| !failure.is_active())
+    }
+
+    fn egt_is_measured(&self) -> bool {
+        !self.egt_sensor_failure.is_active()
+    }
+
     pub fn update_bleed_air_valve_state(
         &mut self,
         context: &UpdateContext,
@@ -474,6 +563,8 @@ impl<C: ApuConstants> ControllerSignal<ApuBleedAirValveSignal> for ElectronicCon
             && self.master_is_on
             && self.n.get::<percent>() > 95.
             && self.bleed_is_on
+            // Without an EGT measurement the bleed load is shed to protect the turbine.
+            && self.egt_is_measured()
         {
             Some(ApuBleedAirValveSignal::new_open())
         } else {
@@ -482,19 +573,37 @@ impl<C: ApuConstants> ControllerSignal<ApuBleedAirValveSignal> for ElectronicCon
     }
 }
 impl<C: ApuConstants> SimulationElement for ElectronicControlBox<C> {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.speed_sensor_failures, visitor);
+        self.egt_sensor_failure.accept(visitor);
+        self.inlet_pressure_sensor_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         let ssm = if self.is_on() {
             SignStatus::NormalOperation
         } else {
             SignStatus::FailureWarning
         };
+        let speed_ssm = if self.speed_is_measured() {
+            ssm
+        } else {
+            SignStatus::FailureWarning
+        };
+        let egt_ssm = if self.egt_is_measured() {
+            ssm
+        } else {
+            SignStatus::FailureWarning
+        };
 
         // For sound and effects.
         writer.write(&self.apu_n_raw_id, self.n());
 
-        writer.write_arinc429(&self.apu_n_id, self.n(), ssm);
-        writer.write_arinc429(&self.apu_n2_id, self.n2(), ssm);
-        writer.write_arinc429(&self.apu_egt_id, self.egt, ssm);
+        writer.write_arinc429(&self.apu_n_id, self.n(), speed_ssm);
+        writer.write_arinc429(&self.apu_n2_id, self.n2(), speed_ssm);
+        writer.write_arinc429(&self.apu_egt_id, self.egt, egt_ssm);
         writer.write_arinc429(
             &self.apu_egt_caution_id,
             self.egt_caution_temperature(),
@@ -548,4 +657,11 @@ enum ApuFault {
     ApuFire,
     FuelLowPressure,
     DcPowerLoss,
+    EgtOvertemperature,
+    HungStart,
+    Overspeed,
+    StartMotor,
+    SpeedSensor,
+    EgtSensor,
+    AirIntakeFlap,
 }
@@ -3,7 +3,11 @@ use self::{
     electronic_control_box::ElectronicControlBox, pw980::ShutdownPw980Turbine,
 };
 use crate::{
-    electrical::{ElectricalElement, ElectricitySource, ProvideFrequency, ProvidePotential},
+    electrical::{
+        ApuGeneratorPushButtons, ElectricalElement, ElectricitySource, ProvideFrequency,
+        ProvidePotential,
+    },
+    failures::{Failure, FailureType},
     overhead::{FirePushButton, OnOffAvailablePushButton, OnOffFaultPushButton},
     pneumatic::{ControllablePneumaticValve, TargetPressureTemperatureSignal},
     shared::{
@@ -12,12 +16,17 @@ use crate::{
         LgciuWeightOnWheels,
     },
     simulation::{
-        SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext, Write,
+        SimulationElement, SimulationElementVisitor, SimulatorWriter, SnapshotReader,
+        SnapshotWriter, UpdateContext, Write,
     },
 };
 use std::time::Duration;
-use uom::si::f64::*;
-use uom::si::thermodynamic_temperature::degree_celsius;
+use uom::si::{
+    f64::*,
+    pressure::{inch_of_mercury, psi},
+    ratio::percent,
+    thermodynamic_temperature::{degree_celsius, kelvin},
+};
 
 mod air_intake_flap;
 mod aps3200;
@@ -68,29 +77,37 @@ impl AuxiliaryPowerUnitFactory {
 
 pub trait ApuStartMotor: SimulationElement {
     fn is_powered(&self) -> bool;
+    /// Indicates if the start motor turns the turbine. A failed start motor
+    /// doesn't turn, even when it is powered.
+    fn is_turning(&self) -> bool;
 }
 
 /// Komp: There is a pressure switch between the fuel valve and the APU.
 /// It switches from 0 to 1 when the pressure is >=17 PSI and the signal is received by the ECB
 /// And there is a small hysteresis, means it switches back to 0 when <=16 PSI
-/// This type exists because we don't have a full fuel implementation yet. Once we do, we will
-/// probably move this type and the logic to there.
 pub struct FuelPressureSwitch {
-    has_fuel_remaining: bool,
+    has_pressure: bool,
 }
 impl FuelPressureSwitch {
+    const SWITCH_ON_PRESSURE_PSI: f64 = 17.;
+    const SWITCH_OFF_PRESSURE_PSI: f64 = 16.;
+
     fn new() -> Self {
         FuelPressureSwitch {
-            has_fuel_remaining: false,
+            has_pressure: false,
         }
     }
 
-    fn update(&mut self, has_fuel_remaining: bool) {
-        self.has_fuel_remaining = has_fuel_remaining;
+    fn update(&mut self, fuel_pressure: Pressure) {
+        if fuel_pressure >= Pressure::new::<psi>(Self::SWITCH_ON_PRESSURE_PSI) {
+            self.has_pressure = true;
+        } else if fuel_pressure <= Pressure::new::<psi>(Self::SWITCH_OFF_PRESSURE_PSI) {
+            self.has_pressure = false;
+        }
     }
 
     fn has_pressure(&self) -> bool {
-        self.has_fuel_remaining
+        self.has_pressure
     }
 }
 
@@ -102,6 +119,7 @@ pub enum TurbineSignal {
 
 pub struct AuxiliaryPowerUnit<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize> {
     apu_flap_open_percentage_id: VariableIdentifier,
+    egt_id: VariableIdentifier,
 
     turbine: Option<Box<dyn Turbine>>,
     generators: [T; N],
@@ -109,10 +127,16 @@ pub struct AuxiliaryPowerUnit<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants
     start_motor: U,
     air_intake_flap: AirIntakeFlap,
     fuel_pressure_switch: FuelPressureSwitch,
+    fuel_control_unit_failure: Failure,
+    bleed_air_temperature: ThermodynamicTemperature,
 }
 impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize>
     AuxiliaryPowerUnit<T, U, C, N>
 {
+    // The APU fuel pump is switched on by the ECB and pressurises the APU fuel line
+    // whenever there is fuel in the feed line.
+    const FUEL_PUMP_DELIVERY_PRESSURE_PSI: f64 = 20.;
+
     pub fn new(
         context: &mut InitContext,
         turbine: Box<dyn Turbine>,
@@ -124,6 +148,7 @@ impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize>
         AuxiliaryPowerUnit {
             apu_flap_open_percentage_id: context
                 .get_identifier("APU_FLAP_OPEN_PERCENTAGE".to_owned()),
+            egt_id: context.get_identifier("APU_EGT".to_owned()),
 
             turbine: Some(turbine),
             generators,
@@ -131,6 +156,8 @@ impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize>
             start_motor,
             air_intake_flap: AirIntakeFlap::new(air_intake_flap_powered_by),
             fuel_pressure_switch: FuelPressureSwitch::new(),
+            fuel_control_unit_failure: Failure::new(FailureType::ApuFuelControlUnit),
+            bleed_air_temperature: ThermodynamicTemperature::new::<degree_celsius>(15.),
         }
     }
 
@@ -142,13 +169,15 @@ impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize>
         fire_overhead: &AuxiliaryPowerUnitFireOverheadPanel,
         apu_bleed_is_on: bool,
         apu_gen_is_used: bool,
+        generator_buttons: &impl ApuGeneratorPushButtons,
         bleed_air_valve: &mut impl ControllablePneumaticValve,
-        has_fuel_remaining: bool,
+        fuel_feed_pressure: Pressure,
     ) {
         self.ecb
             .update_overhead_panel_state(overhead, fire_overhead, apu_bleed_is_on);
         self.ecb.update_apu_fire(fire_on_ground);
-        self.fuel_pressure_switch.update(has_fuel_remaining);
+        self.fuel_pressure_switch
+            .update(self.fuel_line_pressure(fuel_feed_pressure));
         self.ecb
             .update_fuel_pressure_switch_state(&self.fuel_pressure_switch);
         bleed_air_valve.update_open_amount::<ApuBleedAirValveSignal, Self>(self);
@@ -162,17 +191,25 @@ impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize>
                 context,
                 bleed_air_valve.is_open(),
                 apu_gen_is_used,
+                self.fuel_control_unit_failure.is_active(),
                 &self.ecb,
             );
 
             self.ecb.update(context, updated_turbine.as_ref());
+            self.bleed_air_temperature = IntakeConditions::new(context)
+                .compressor_delivery_temperature(updated_turbine.bleed_air_pressure());
 
             self.turbine = Some(updated_turbine);
         }
 
         let emergency_shutdown = self.is_emergency_shutdown();
-        for gen in &mut self.generators {
-            gen.update(self.ecb.n(), emergency_shutdown);
+        for (i, gen) in self.generators.iter_mut().enumerate() {
+            gen.update(
+                context,
+                self.ecb.n(),
+                emergency_shutdown,
+                generator_buttons.apu_gen_push_button_is_on(i + 1),
+            );
         }
     }
 
@@ -185,6 +222,14 @@ impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize>
         self.ecb.update_fuel_used_reset(engines, lgciu);
     }
 
+    fn fuel_line_pressure(&self, fuel_feed_pressure: Pressure) -> Pressure {
+        if self.ecb.is_on() && fuel_feed_pressure > Pressure::default() {
+            fuel_feed_pressure.max(Pressure::new::<psi>(Self::FUEL_PUMP_DELIVERY_PRESSURE_PSI))
+        } else {
+            fuel_feed_pressure
+        }
+    }
+
     fn is_available(&self) -> bool {
         self.ecb.is_available()
     }
@@ -249,13 +294,8 @@ impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize>
     ControllerSignal<TargetPressureTemperatureSignal> for AuxiliaryPowerUnit<T, U, C, N>
 {
     fn signal(&self) -> Option<TargetPressureTemperatureSignal> {
-        // TODO: Calculate the temperature depending on environmental conditions.
-        // Currently the temperature is precalculated for a bleed pressure of 42 psi.
         self.turbine.as_ref().map(|s| {
-            TargetPressureTemperatureSignal::new(
-                s.bleed_air_pressure(),
-                ThermodynamicTemperature::new::<degree_celsius>(165.),
-            )
+            TargetPressureTemperatureSignal::new(s.bleed_air_pressure(), self.bleed_air_temperature)
         })
     }
 }
@@ -266,6 +306,7 @@ impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize> Simulat
         accept_iterable!(self.generators, visitor);
         self.start_motor.accept(visitor);
         self.air_intake_flap.accept(visitor);
+        self.fuel_control_unit_failure.accept(visitor);
         self.ecb.accept(visitor);
 
         visitor.visit(self);
@@ -277,6 +318,18 @@ impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize> Simulat
             self.air_intake_flap.open_amount(),
         );
     }
+
+    fn save_state(&self, writer: &mut SnapshotWriter) {
+        if let Some(turbine) = &self.turbine {
+            writer.write(&self.egt_id, turbine.egt());
+        }
+    }
+
+    fn restore_state(&mut self, reader: &mut SnapshotReader) {
+        if let (Some(turbine), Some(egt)) = (&mut self.turbine, reader.restore(&self.egt_id)) {
+            turbine.restore_egt(egt);
+        }
+    }
 }
 
 pub trait Turbine {
@@ -285,6 +338,7 @@ pub trait Turbine {
         context: &UpdateContext,
         apu_bleed_is_used: bool,
         apu_gen_is_used: bool,
+        fuel_control_unit_has_failed: bool,
         controller: &dyn ControllerSignal<TurbineSignal>,
     ) -> Box<dyn Turbine>;
     fn n(&self) -> Ratio;
@@ -292,10 +346,153 @@ pub trait Turbine {
         Ratio::default()
     }
     fn egt(&self) -> ThermodynamicTemperature;
+    /// Restores the EGT of a turbine from a snapshot. Only a turbine which is
+    /// shut down can be restored, as a turbine always starts out shut down.
+    fn restore_egt(&mut self, _egt: ThermodynamicTemperature) {}
     fn state(&self) -> TurbineState;
     fn bleed_air_pressure(&self) -> Pressure;
 }
 
+/// The air entering the APU, relative to the reference conditions of the turbine curves:
+/// sea level pressure at an OAT of 0 degrees celsius. A turbine operating at the same corrected
+/// speed and load behaves alike under any conditions, which lets us scale the reference curves
+/// by the temperature ratio (theta) and pressure ratio (delta) of the intake air.
+#[derive(Clone, Copy)]
+struct IntakeConditions {
+    ambient_temperature: ThermodynamicTemperature,
+    ambient_pressure: Pressure,
+}
+impl IntakeConditions {
+    const REFERENCE_TEMPERATURE_KELVIN: f64 = 273.15;
+    const REFERENCE_PRESSURE_INCHES_OF_MERCURY: f64 = 29.92;
+
+    // Below this power ratio the turbine no longer accelerates on its own once the
+    // start motor disengages.
+    const SELF_SUSTAINING_POWER_RATIO: f64 = 0.25;
+    // Above this power ratio the acceleration schedule of the ECB, rather than the power
+    // available, limits how fast the turbine accelerates during start.
+    const SCHEDULED_START_POWER_RATIO: f64 = 0.85;
+
+    const HEAT_CAPACITY_RATIO: f64 = 1.4;
+    // Chosen such that bleed air compressed to 42 psi leaves the load compressor
+    // at 165 degrees celsius on a standard day at sea level.
+    const LOAD_COMPRESSOR_EFFICIENCY: f64 = 0.716;
+
+    fn new(context: &UpdateContext) -> Self {
+        Self {
+            ambient_temperature: context.ambient_temperature(),
+            ambient_pressure: context.ambient_pressure(),
+        }
+    }
+
+    fn temperature_ratio(&self) -> f64 {
+        self.ambient_temperature.get::<kelvin>() / Self::REFERENCE_TEMPERATURE_KELVIN
+    }
+
+    fn pressure_ratio(&self) -> f64 {
+        self.ambient_pressure.get::<inch_of_mercury>() / Self::REFERENCE_PRESSURE_INCHES_OF_MERCURY
+    }
+
+    /// The power the turbine delivers at a given speed, relative to the power it
+    /// delivers under reference conditions.
+    fn power_ratio(&self) -> f64 {
+        self.pressure_ratio() / self.temperature_ratio().sqrt()
+    }
+
+    /// How fast the turbine accelerates during start, relative to how fast it accelerates
+    /// under reference conditions. Thin or hot air leaves the turbine with less surplus power,
+    /// up to the point where it hangs once the start motor disengages.
+    fn start_acceleration_factor(&self) -> f64 {
+        ((self.power_ratio() - Self::SELF_SUSTAINING_POWER_RATIO)
+            / (Self::SCHEDULED_START_POWER_RATIO - Self::SELF_SUSTAINING_POWER_RATIO))
+            .clamp(0., 1.)
+            .sqrt()
+    }
+
+    /// The EGT during start. A turbine which accelerates slowly runs hotter, as there is less
+    /// air flowing through it to cool the combustion gases. The slow start factor is the
+    /// additional EGT rise, relative to the reference EGT rise, of a start which only just
+    /// has enough power to complete.
+    fn starting_egt(
+        &self,
+        reference_egt: ThermodynamicTemperature,
+        slow_start_factor: f64,
+    ) -> ThermodynamicTemperature {
+        let reference_egt_rise =
+            (reference_egt.get::<kelvin>() - Self::REFERENCE_TEMPERATURE_KELVIN).max(0.);
+
+        ThermodynamicTemperature::new::<kelvin>(
+            reference_egt.get::<kelvin>() * self.temperature_ratio()
+                + reference_egt_rise * slow_start_factor * (1. - self.start_acceleration_factor()),
+        )
+    }
+
+    /// The EGT of a turbine governed at its rated speed. The fuel needed to carry a load,
+    /// and thus the EGT rise caused by it, grows as the power available to the turbine falls.
+    fn running_egt(
+        &self,
+        reference_egt: ThermodynamicTemperature,
+        load_egt_rise: TemperatureInterval,
+    ) -> ThermodynamicTemperature {
+        ThermodynamicTemperature::new::<kelvin>(
+            reference_egt.get::<kelvin>() * self.temperature_ratio(),
+        ) + load_egt_rise / self.power_ratio()
+    }
+
+    /// The pressure delivered by a load compressor which delivers the given pressure under
+    /// reference conditions. At a given speed, the compressor's pressure rise falls as the
+    /// temperature of the air it takes in rises.
+    fn compressor_delivery_pressure(&self, reference_delivery_pressure: Pressure) -> Pressure {
+        let reference_pressure_rise = reference_delivery_pressure.get::<inch_of_mercury>()
+            / Self::REFERENCE_PRESSURE_INCHES_OF_MERCURY
+            - 1.;
+
+        self.ambient_pressure * (1. + reference_pressure_rise / self.temperature_ratio())
+    }
+
+    /// The temperature of the air leaving a compressor which compresses ambient air
+    /// to the given pressure.
+    fn compressor_delivery_temperature(
+        &self,
+        delivery_pressure: Pressure,
+    ) -> ThermodynamicTemperature {
+        let pressure_ratio =
+            (delivery_pressure.get::<psi>() / self.ambient_pressure.get::<psi>()).max(1.);
+
+        ThermodynamicTemperature::new::<kelvin>(
+            self.ambient_temperature.get::<kelvin>()
+                * pressure_ratio.powf(
+                    (Self::HEAT_CAPACITY_RATIO - 1.)
+                        / (Self::HEAT_CAPACITY_RATIO * Self::LOAD_COMPRESSOR_EFFICIENCY),
+                ),
+        )
+    }
+}
+
+/// A failed fuel control unit no longer meters fuel to the schedule of the ECB, but delivers
+/// more fuel than the turbine needs. The turbine runs hotter during start, and once running
+/// it accelerates beyond its governed speed.
+struct FuelControlUnitRunaway;
+impl FuelControlUnitRunaway {
+    const EGT_RISE_FACTOR: f64 = 1.5;
+    const N_INCREASE_PERCENT_PER_SECOND: f64 = 2.;
+
+    fn starting_egt(
+        context: &UpdateContext,
+        egt: ThermodynamicTemperature,
+    ) -> ThermodynamicTemperature {
+        let ambient = context.ambient_temperature().get::<kelvin>();
+
+        ThermodynamicTemperature::new::<kelvin>(
+            ambient + (egt.get::<kelvin>() - ambient).max(0.) * Self::EGT_RISE_FACTOR,
+        )
+    }
+
+    fn running_n(context: &UpdateContext, n: Ratio) -> Ratio {
+        n + Ratio::new::<percent>(Self::N_INCREASE_PERCENT_PER_SECOND * context.delta_as_secs_f64())
+    }
+}
+
 #[derive(PartialEq, Eq)]
 pub enum TurbineState {
     Shutdown,
@@ -307,7 +504,13 @@ pub enum TurbineState {
 pub trait ApuGenerator:
     SimulationElement + ProvidePotential + ProvideFrequency + ElectricalElement + ElectricitySource
 {
-    fn update(&mut self, n: Ratio, is_emergency_shutdown: bool);
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        n: Ratio,
+        is_emergency_shutdown: bool,
+        push_button_is_on: bool,
+    );
     fn output_within_normal_parameters(&self) -> bool;
 }
 
@@ -330,7 +533,7 @@ impl AuxiliaryPowerUnitFireOverheadPanel {
         }
     }
 
-    fn fire_button_is_released(&self) -> bool {
+    pub fn fire_button_is_released(&self) -> bool {
         self.apu_fire_button.is_released()
     }
 }
@@ -398,7 +601,8 @@ mod tests {
         },
         pneumatic::valve::*,
         shared::{
-            arinc429::Arinc429Word, ElectricalBusType, PotentialOrigin, PowerConsumptionReport,
+            arinc429::Arinc429Word, ElectricalBusType, PneumaticValve, PotentialOrigin,
+            PowerConsumptionReport,
         },
         simulation::{
             test::{SimulationTestBed, TestBed},
@@ -455,6 +659,7 @@ mod tests {
             _: &UpdateContext,
             _: bool,
             _: bool,
+            _: bool,
             _: &dyn ControllerSignal<TurbineSignal>,
         ) -> Box<dyn Turbine> {
             self
@@ -490,6 +695,10 @@ mod tests {
         fn bleed_air_valve(&mut self) -> &mut impl ControllablePneumaticValve {
             &mut self.apu_bleed_air_valve
         }
+
+        fn bleed_air_valve_is_open(&self) -> bool {
+            self.apu_bleed_air_valve.is_open()
+        }
     }
 
     struct TestEngine {
@@ -550,6 +759,20 @@ mod tests {
         }
     }
 
+    struct TestApuGeneratorPushButtons {
+        is_on: bool,
+    }
+    impl TestApuGeneratorPushButtons {
+        fn new(is_on: bool) -> Self {
+            Self { is_on }
+        }
+    }
+    impl ApuGeneratorPushButtons for TestApuGeneratorPushButtons {
+        fn apu_gen_push_button_is_on(&self, _number: usize) -> bool {
+            self.is_on
+        }
+    }
+
     pub struct AuxiliaryPowerUnitTestAircraft<
         T: ApuGenerator,
         U: ApuStartMotor,
@@ -565,12 +788,13 @@ mod tests {
         apu_overhead: AuxiliaryPowerUnitOverheadPanel,
         apu_bleed: OnOffFaultPushButton,
         apu_gen_is_used: bool,
+        apu_gen_push_buttons: TestApuGeneratorPushButtons,
         engine_1: TestEngine,
         engine_2: TestEngine,
         fire_detected_on_ground: bool,
         lgciu1: TestLgciu,
         lgciu2: TestLgciu,
-        has_fuel_remaining: bool,
+        fuel_feed_pressure: Pressure,
         power_consumer: PowerConsumer,
         cut_start_motor_power: bool,
         power_consumption: Power,
@@ -604,12 +828,13 @@ mod tests {
                 apu_overhead: AuxiliaryPowerUnitOverheadPanel::new(context),
                 apu_bleed: OnOffFaultPushButton::new_on(context, "APU_BLEED"),
                 apu_gen_is_used: true,
+                apu_gen_push_buttons: TestApuGeneratorPushButtons::new(true),
                 engine_1: TestEngine::new(Ratio::default()),
                 engine_2: TestEngine::new(Ratio::default()),
                 fire_detected_on_ground: false,
                 lgciu1: TestLgciu::new(false),
                 lgciu2: TestLgciu::new(false),
-                has_fuel_remaining: true,
+                fuel_feed_pressure: Pressure::new::<psi>(25.),
                 cut_start_motor_power: false,
                 power_consumption: Power::new::<watt>(0.),
                 apu_generator_output_within_normal_parameters_before_processing_power_consumption_report: false,
@@ -632,12 +857,13 @@ mod tests {
                 apu_overhead: AuxiliaryPowerUnitOverheadPanel::new(context),
                 apu_bleed: OnOffFaultPushButton::new_on(context, "APU_BLEED"),
                 apu_gen_is_used: true,
+                apu_gen_push_buttons: TestApuGeneratorPushButtons::new(true),
                 engine_1: TestEngine::new(Ratio::default()),
                 engine_2: TestEngine::new(Ratio::default()),
                 fire_detected_on_ground: false,
                 lgciu1: TestLgciu::new(false),
                 lgciu2: TestLgciu::new(false),
-                has_fuel_remaining: true,
+                fuel_feed_pressure: Pressure::new::<psi>(25.),
                 cut_start_motor_power: false,
                 power_consumption: Power::new::<watt>(0.),
                 apu_generator_output_within_normal_parameters_before_processing_power_consumption_report: false,
@@ -653,8 +879,12 @@ mod tests {
             self.apu_gen_is_used = value;
         }
 
-        fn set_has_fuel_remaining(&mut self, value: bool) {
-            self.has_fuel_remaining = value;
+        fn set_apu_gen_push_button_on(&mut self, value: bool) {
+            self.apu_gen_push_buttons.is_on = value;
+        }
+
+        fn set_fuel_feed_pressure(&mut self, pressure: Pressure) {
+            self.fuel_feed_pressure = pressure;
         }
 
         fn set_turbine_infinitely_running_at(&mut self, n: Ratio) {
@@ -706,6 +936,10 @@ mod tests {
             self.power_consumption
         }
 
+        fn bleed_air_valve_is_open(&self) -> bool {
+            self.pneumatic.bleed_air_valve_is_open()
+        }
+
         fn set_on_ground(&mut self, on_ground: bool) {
             self.lgciu1.set_on_ground(on_ground);
             self.lgciu2.set_on_ground(on_ground);
@@ -731,8 +965,9 @@ mod tests {
                 &self.apu_fire_overhead,
                 self.apu_bleed.is_on(),
                 self.apu_gen_is_used,
+                &self.apu_gen_push_buttons,
                 self.pneumatic.bleed_air_valve(),
-                self.has_fuel_remaining,
+                self.fuel_feed_pressure,
             );
 
             self.apu_generator_output_within_normal_parameters_before_processing_power_consumption_report = self.apu.generator(1).output_within_normal_parameters();
@@ -898,8 +1133,23 @@ mod tests {
             self
         }
 
+        pub fn apu_gen_push_button_off(mut self) -> Self {
+            self.command(|a| a.set_apu_gen_push_button_on(false));
+            self
+        }
+
         fn no_fuel_available(mut self) -> Self {
-            self.command(|a| a.set_has_fuel_remaining(false));
+            self.command(|a| a.set_fuel_feed_pressure(Pressure::default()));
+            self
+        }
+
+        fn failed(mut self, failure_type: FailureType) -> Self {
+            self.fail(failure_type);
+            self
+        }
+
+        fn fuel_feed_pressure(mut self, pressure: Pressure) -> Self {
+            self.command(|a| a.set_fuel_feed_pressure(pressure));
             self
         }
 
@@ -1029,6 +1279,17 @@ mod tests {
             self
         }
 
+        fn run_in_steps(mut self, duration: Duration) -> Self {
+            let step = Duration::from_millis(100);
+            let mut elapsed = Duration::ZERO;
+            while elapsed < duration {
+                self = self.run(step);
+                elapsed += step;
+            }
+
+            self
+        }
+
         fn unpower_start_motor_between(mut self, start: Ratio, end: Ratio) -> Self {
             loop {
                 self = self.run(Duration::from_millis(50));
@@ -1183,8 +1444,8 @@ mod tests {
             self.read_by_name("ECAM_INOP_SYS_APU")
         }
 
-        fn bleed_air_valve_is_open(&mut self) -> bool {
-            self.read_by_name("APU_BLEED_AIR_VALVE_OPEN")
+        fn bleed_air_valve_is_open(&self) -> bool {
+            self.query(|a| a.bleed_air_valve_is_open())
         }
 
         fn apu_generator_output_within_normal_parameters(&self) -> bool {
@@ -2417,6 +2678,196 @@ mod tests {
             assert!(!test_bed.start_is_on());
         }
 
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn apu_fuel_pump_allows_start_with_suction_fed_fuel<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .fuel_feed_pressure(Pressure::new::<psi>(2.))
+                .and()
+                .starting_apu()
+                .run_in_steps(Duration::from_secs(APPROXIMATE_STARTUP_TIME + 10));
+
+            assert!(test_bed.apu_is_available());
+            assert!(!test_bed
+                .has_fuel_low_pressure_fault()
+                .normal_value()
+                .unwrap());
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn running_apu_egt_is_higher_on_a_hot_day<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .running_apu_without_bleed_air()
+                .and()
+                .apu_gen_not_used()
+                .run(Duration::from_secs(1_000));
+            let standard_day_egt = test_bed.egt().normal_value().unwrap();
+
+            test_bed = test_bed
+                .ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(40.))
+                .run(Duration::from_secs(1_000));
+
+            assert!(
+                test_bed
+                    .egt()
+                    .normal_value()
+                    .unwrap()
+                    .get::<degree_celsius>()
+                    > standard_day_egt.get::<degree_celsius>() + 50.
+            );
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200(), 85.)]
+        #[case::pw980(test_bed_pw980(), 40.)]
+        fn bleed_air_usage_increases_egt_more_at_altitude<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+            #[case] sea_level_egt_rise: f64,
+        ) {
+            let mut test_bed = bed_with
+                .running_apu_without_bleed_air()
+                .and()
+                .apu_gen_not_used()
+                .and()
+                .ambient_pressure(Pressure::new::<psi>(7.))
+                .run(Duration::from_secs(1_000));
+            let egt_without_bleed = test_bed.egt().normal_value().unwrap();
+
+            test_bed = test_bed
+                .running_apu_with_bleed_air()
+                .run(Duration::from_secs(1_000));
+            let egt_rise = test_bed
+                .egt()
+                .normal_value()
+                .unwrap()
+                .get::<degree_celsius>()
+                - egt_without_bleed.get::<degree_celsius>();
+
+            assert!(egt_rise > 1.5 * sea_level_egt_rise);
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn bleed_air_pressure_is_lower_at_altitude<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .running_apu_with_bleed_air()
+                .run(Duration::from_secs(10));
+            let sea_level_pressure = test_bed.bleed_air_pressure().normal_value().unwrap();
+
+            test_bed = test_bed
+                .ambient_pressure(Pressure::new::<psi>(7.))
+                .run(Duration::from_secs(10));
+
+            assert!(test_bed.bleed_air_pressure().normal_value().unwrap() < sea_level_pressure);
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn start_at_hot_and_high_airfield_within_envelope_succeeds<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            // 10000 feet at ISA+30.
+            let mut test_bed = bed_with
+                .ambient_pressure(Pressure::new::<psi>(10.11))
+                .and()
+                .ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(25.))
+                .and()
+                .starting_apu()
+                .run_in_steps(Duration::from_secs(120));
+
+            assert!(test_bed.apu_is_available());
+            assert!(!test_bed.master_has_fault());
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn start_when_too_hot_and_high_fails_with_fault<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            // 14000 feet at an OAT of 30 degrees.
+            let mut test_bed = bed_with
+                .ambient_pressure(Pressure::new::<psi>(8.63))
+                .and()
+                .ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(30.))
+                .and()
+                .starting_apu()
+                .run_in_steps(Duration::from_secs(120));
+
+            assert!(!test_bed.apu_is_available());
+            assert!(test_bed.master_has_fault());
+            assert!(!test_bed.start_is_on());
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn start_which_does_not_complete_within_three_minutes_fails_with_fault<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            // The air at 36000 feet is too thin for the turbine to accelerate.
+            let mut test_bed = bed_with
+                .ambient_pressure(Pressure::new::<psi>(3.2))
+                .and()
+                .ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(-54.))
+                .and()
+                .starting_apu()
+                .run_in_steps(Duration::from_secs(170));
+
+            assert!(!test_bed.master_has_fault());
+
+            test_bed = test_bed.run_in_steps(Duration::from_secs(20));
+
+            assert!(!test_bed.apu_is_available());
+            assert!(test_bed.master_has_fault());
+        }
+
         #[rstest]
         #[case::aps3200(test_bed_aps3200())]
         #[case::pw980(test_bed_pw980())]
@@ -2709,7 +3160,7 @@ mod tests {
         >(
             #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
         ) {
-            let mut test_bed = bed_with
+            let test_bed = bed_with
                 .running_apu_going_in_emergency_shutdown()
                 .run(Duration::from_secs(1));
 
@@ -3408,5 +3859,228 @@ mod tests {
                     < 1.
             );
         }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn failed_start_motor_aborts_start_with_fault<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .failed(FailureType::ApuStartMotor)
+                .and()
+                .starting_apu()
+                .run(Duration::from_secs(5));
+
+            assert!(test_bed.master_has_fault());
+            assert!(!test_bed.start_is_on());
+            assert!(!test_bed.should_close_start_contactors_commanded());
+            assert_about_eq!(test_bed.n().normal_value().unwrap().get::<percent>(), 0.);
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn fuel_control_unit_failure_on_running_apu_results_in_overspeed_shutdown<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .running_apu()
+                .and()
+                .failed(FailureType::ApuFuelControlUnit)
+                .run_in_steps(Duration::from_secs(2));
+
+            assert!(test_bed.n().normal_value().unwrap().get::<percent>() > 100.);
+            assert!(!test_bed.master_has_fault());
+
+            test_bed = test_bed.run_in_steps(Duration::from_secs(5));
+
+            assert!(test_bed.master_has_fault());
+            assert!(test_bed.is_auto_shutdown());
+            assert!(!test_bed.apu_is_available());
+            assert!(test_bed.n().normal_value().unwrap().get::<percent>() <= 107.5);
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn fuel_control_unit_failure_during_start_results_in_overtemperature_shutdown<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .failed(FailureType::ApuFuelControlUnit)
+                .and()
+                .starting_apu()
+                .run_in_steps(Duration::from_secs(APPROXIMATE_STARTUP_TIME));
+
+            assert!(test_bed.master_has_fault());
+            assert!(test_bed.is_auto_shutdown());
+            assert!(!test_bed.apu_is_available());
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn jammed_air_intake_flap_prevents_start_and_results_in_fault<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .failed(FailureType::ApuAirIntakeFlapJammed)
+                .and()
+                .master_on()
+                .run(Duration::from_millis(1))
+                .then_continue_with()
+                .start_on()
+                .run_in_steps(Duration::from_secs(25));
+
+            assert!(!test_bed.master_has_fault());
+
+            test_bed = test_bed.run_in_steps(Duration::from_secs(10));
+
+            assert!(test_bed.master_has_fault());
+            assert!(test_bed.is_air_intake_flap_fully_closed());
+            assert_about_eq!(test_bed.n().normal_value().unwrap().get::<percent>(), 0.);
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn single_speed_sensor_failure_does_not_affect_running_apu<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .running_apu()
+                .and()
+                .failed(FailureType::ApuSpeedSensor(1))
+                .run(Duration::from_secs(10));
+
+            assert!(test_bed.apu_is_available());
+            assert!(!test_bed.master_has_fault());
+            assert!(test_bed.n().is_normal_operation());
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn dual_speed_sensor_failure_shuts_down_running_apu<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .running_apu()
+                .and()
+                .failed(FailureType::ApuSpeedSensor(1))
+                .and()
+                .failed(FailureType::ApuSpeedSensor(2))
+                .run(Duration::from_secs(1));
+
+            assert!(!test_bed.apu_is_available());
+            assert!(test_bed.is_auto_shutdown());
+            assert!(test_bed.n().is_failure_warning());
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn egt_sensor_failure_on_running_apu_sheds_bleed_air_load<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with.running_apu_with_bleed_air();
+
+            assert!(test_bed.bleed_air_valve_is_open());
+
+            test_bed = test_bed
+                .failed(FailureType::ApuEgtSensor)
+                .run(Duration::from_secs(1));
+
+            assert!(test_bed.apu_is_available());
+            assert!(!test_bed.master_has_fault());
+            assert!(!test_bed.bleed_air_valve_is_open());
+            assert!(test_bed.egt().is_failure_warning());
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn egt_sensor_failure_aborts_start_with_fault<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .failed(FailureType::ApuEgtSensor)
+                .and()
+                .starting_apu()
+                .run(Duration::from_secs(1));
+
+            assert!(test_bed.master_has_fault());
+            assert!(!test_bed.start_is_on());
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn inlet_pressure_sensor_failure_falls_back_to_900_degrees_max_starting_egt<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .failed(FailureType::ApuInletPressureSensor)
+                .and()
+                .starting_apu()
+                .and()
+                .ambient_pressure(Pressure::new::<psi>(5.44))
+                .run(Duration::from_secs(1));
+
+            assert_about_eq!(
+                test_bed
+                    .egt_warning_temperature()
+                    .normal_value()
+                    .unwrap()
+                    .get::<degree_celsius>(),
+                900.
+            );
+        }
     }
 }
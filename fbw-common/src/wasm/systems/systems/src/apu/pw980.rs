@@ -1,29 +1,28 @@
 use std::time::Duration;
 
-use uom::{
-    si::{
-        electric_potential::volt, f64::*, frequency::hertz, power::watt, pressure::psi,
-        ratio::percent, temperature_interval, thermodynamic_temperature::degree_celsius,
-    },
-    ConstZero,
+use uom::si::{
+    electric_potential::volt, f64::*, frequency::hertz, power::watt, pressure::psi, ratio::percent,
+    temperature_interval, thermodynamic_temperature::degree_celsius,
 };
 
 use crate::{
     electrical::{
         ElectricalElement, ElectricalElementIdentifier, ElectricalElementIdentifierProvider,
-        ElectricalStateWriter, ElectricitySource, Potential, ProvideFrequency, ProvideLoad,
-        ProvidePotential,
+        ElectricalStateWriter, ElectricitySource, GeneratorControlUnit, Potential,
+        ProvideFrequency, ProvideLoad, ProvidePotential,
     },
     failures::{Failure, FailureType},
     shared::{
         calculate_towards_target_temperature, random_number, ConsumePower, ControllerSignal,
-        ElectricalBusType, ElectricalBuses, InternationalStandardAtmosphere, PotentialOrigin,
-        PowerConsumptionReport,
+        ElectricalBusType, ElectricalBuses, PotentialOrigin, PowerConsumptionReport,
     },
     simulation::{InitContext, SimulationElement, SimulatorWriter, UpdateContext},
 };
 
-use super::{ApuConstants, ApuGenerator, ApuStartMotor, Turbine, TurbineSignal, TurbineState};
+use super::{
+    ApuConstants, ApuGenerator, ApuStartMotor, FuelControlUnitRunaway, IntakeConditions, Turbine,
+    TurbineSignal, TurbineState,
+};
 
 pub struct Pw980Constants;
 
@@ -56,6 +55,7 @@ impl Turbine for ShutdownPw980Turbine {
         context: &UpdateContext,
         _: bool,
         _: bool,
+        _: bool,
         controller: &dyn ControllerSignal<TurbineSignal>,
     ) -> Box<dyn Turbine> {
         self.egt = calculate_towards_ambient_egt(self.egt, context);
@@ -78,6 +78,10 @@ impl Turbine for ShutdownPw980Turbine {
         self.egt
     }
 
+    fn restore_egt(&mut self, egt: ThermodynamicTemperature) {
+        self.egt = egt;
+    }
+
     fn state(&self) -> TurbineState {
         TurbineState::Shutdown
     }
@@ -105,7 +109,14 @@ impl Starting {
         }
     }
 
-    fn calculate_egt(&mut self, context: &UpdateContext) -> ThermodynamicTemperature {
+    fn calculate_egt(
+        &mut self,
+        context: &UpdateContext,
+        fuel_control_unit_has_failed: bool,
+    ) -> ThermodynamicTemperature {
+        // The additional EGT rise of a start which only just has enough power to complete.
+        const SLOW_START_FACTOR: f64 = 1.7;
+
         // Refer to PW980.md for details on the values below and source data.
         const APU_N_TEMP_CONST: f64 = -67.85561068313169;
         const APU_N_TEMP_X: f64 = -19.73523738237853;
@@ -125,7 +136,7 @@ impl Starting {
         // We use N2 for this calculation
         let n = self.n2.get::<percent>();
 
-        let temperature = ThermodynamicTemperature::new::<degree_celsius>(
+        let reference_temperature = ThermodynamicTemperature::new::<degree_celsius>(
             APU_N_TEMP_CONST
                 + (APU_N_TEMP_X * n)
                 + (APU_N_TEMP_X2 * n.powi(2))
@@ -141,6 +152,11 @@ impl Starting {
                 + (APU_N_TEMP_X12 * n.powi(12))
                 + (APU_N_TEMP_X13 * n.powi(13)),
         );
+        let mut temperature =
+            IntakeConditions::new(context).starting_egt(reference_temperature, SLOW_START_FACTOR);
+        if fuel_control_unit_has_failed {
+            temperature = FuelControlUnitRunaway::starting_egt(context, temperature);
+        }
 
         // The above calculated EGT can be lower than the ambient temperature,
         // or the current APU EGT (when cooling down). To prevent sudden changes
@@ -286,9 +302,12 @@ impl Turbine for Starting {
         context: &UpdateContext,
         _: bool,
         _: bool,
+        fuel_control_unit_has_failed: bool,
         controller: &dyn ControllerSignal<TurbineSignal>,
     ) -> Box<dyn Turbine> {
-        self.since += context.delta();
+        self.since += context
+            .delta()
+            .mul_f64(IntakeConditions::new(context).start_acceleration_factor());
         self.n2 = self.calculate_n2();
         if context.aircraft_preset_quick_mode() {
             self.n = Ratio::new::<percent>(100.);
@@ -296,7 +315,7 @@ impl Turbine for Starting {
         } else {
             self.n = self.calculate_n();
         };
-        self.egt = self.calculate_egt(context);
+        self.egt = self.calculate_egt(context, fuel_control_unit_has_failed);
 
         match controller.signal() {
             Some(TurbineSignal::Stop) | None => Box::new(Stopping::new(self.egt, self.n, self.n2)),
@@ -468,59 +487,79 @@ impl ApuBleedUsageN2Delta {
 struct Running {
     egt: ThermodynamicTemperature,
     base_egt: ThermodynamicTemperature,
-    base_egt_deviation: TemperatureInterval,
+    base_egt_deviation: Option<TemperatureInterval>,
     bleed_air_usage: BleedAirUsageEgtDelta,
     apu_gen_usage: ApuGenUsageEgtDelta,
     n2: Ratio,
     bleed_air_n2_delta: ApuBleedUsageN2Delta,
+    bleed_air_pressure: Pressure,
+    n: Ratio,
 }
 impl Running {
+    // Value from refs, relative to standard pressure at sea level.
+    const REFERENCE_BLEED_AIR_PRESSURE_PSI: f64 = 40. + 14.696;
+
     fn new(egt: ThermodynamicTemperature) -> Running {
         let base_egt = 480. + ((random_number() % 11) as f64);
         Running {
             egt,
             base_egt: ThermodynamicTemperature::new::<degree_celsius>(base_egt),
-            // This contains the deviation from the base EGT at the moment of entering the running state.
-            base_egt_deviation: TemperatureInterval::new::<temperature_interval::degree_celsius>(
-                egt.get::<degree_celsius>() - base_egt,
-            ),
+            base_egt_deviation: None,
             bleed_air_usage: BleedAirUsageEgtDelta::new(),
             apu_gen_usage: ApuGenUsageEgtDelta::new(),
             n2: Ratio::default(),
             bleed_air_n2_delta: ApuBleedUsageN2Delta::new(),
+            bleed_air_pressure: Pressure::new::<psi>(Self::REFERENCE_BLEED_AIR_PRESSURE_PSI),
+            n: Ratio::new::<percent>(100.),
         }
     }
 
     fn calculate_egt(
         &mut self,
         context: &UpdateContext,
+        conditions: &IntakeConditions,
         apu_gen_is_used: bool,
         apu_bleed_is_used: bool,
     ) -> ThermodynamicTemperature {
-        // Reduce the deviation by 1 per second to slowly creep back to normal temperatures
-        self.base_egt_deviation -= TemperatureInterval::new::<temperature_interval::degree_celsius>(
-            (context.delta_as_secs_f64() * 1.).min(
-                self.base_egt_deviation
-                    .get::<temperature_interval::degree_celsius>(),
-            ),
+        self.apu_gen_usage.update(context, apu_gen_is_used);
+        self.bleed_air_usage.update(context, apu_bleed_is_used);
+        let target = conditions.running_egt(
+            self.base_egt,
+            self.apu_gen_usage.egt_delta() + self.bleed_air_usage.egt_delta(),
         );
 
-        let mut target = self.base_egt + self.base_egt_deviation;
-        self.apu_gen_usage.update(context, apu_gen_is_used);
-        target += self.apu_gen_usage.egt_delta();
+        // This contains the deviation from the base EGT at the moment of entering the running state.
+        let deviation = self
+            .base_egt_deviation
+            .get_or_insert(TemperatureInterval::new::<
+                temperature_interval::degree_celsius,
+            >(
+                self.egt.get::<degree_celsius>() - target.get::<degree_celsius>(),
+            ))
+            .get::<temperature_interval::degree_celsius>();
 
-        self.bleed_air_usage.update(context, apu_bleed_is_used);
-        target += self.bleed_air_usage.egt_delta();
+        // Reduce the deviation by 1 per second to slowly creep back to normal temperatures
+        let reduction = (context.delta_as_secs_f64() * 1.).min(deviation.abs());
+        let deviation = deviation - reduction * deviation.signum();
+        self.base_egt_deviation = Some(TemperatureInterval::new::<
+            temperature_interval::degree_celsius,
+        >(deviation));
 
-        target
+        target + TemperatureInterval::new::<temperature_interval::degree_celsius>(deviation)
     }
 
-    fn calculate_n2(&mut self, context: &UpdateContext, apu_bleed_is_used: bool) -> Ratio {
+    fn calculate_n2(
+        &mut self,
+        context: &UpdateContext,
+        conditions: &IntakeConditions,
+        apu_bleed_is_used: bool,
+    ) -> Ratio {
         // Base N2 is 85%
         let mut target = Ratio::new::<percent>(85.);
 
+        // Thinner or hotter air requires the gas generator to spin up further to supply bleed air.
         self.bleed_air_n2_delta.update(context, apu_bleed_is_used);
-        target += self.bleed_air_n2_delta.n2_delta();
+        target += self.bleed_air_n2_delta.n2_delta() / conditions.power_ratio();
 
         target
     }
@@ -531,23 +570,31 @@ impl Turbine for Running {
         context: &UpdateContext,
         apu_bleed_is_used: bool,
         apu_gen_is_used: bool,
+        fuel_control_unit_has_failed: bool,
         controller: &dyn ControllerSignal<TurbineSignal>,
     ) -> Box<dyn Turbine> {
-        self.egt = self.calculate_egt(context, apu_gen_is_used, apu_bleed_is_used);
-        self.n2 = self.calculate_n2(context, apu_bleed_is_used);
+        let conditions = IntakeConditions::new(context);
+        self.egt = self.calculate_egt(context, &conditions, apu_gen_is_used, apu_bleed_is_used);
+        self.n2 = self.calculate_n2(context, &conditions, apu_bleed_is_used);
+        self.bleed_air_pressure = conditions.compressor_delivery_pressure(Pressure::new::<psi>(
+            Self::REFERENCE_BLEED_AIR_PRESSURE_PSI,
+        ));
+        self.n = if fuel_control_unit_has_failed {
+            FuelControlUnitRunaway::running_n(context, self.n)
+        } else {
+            Ratio::new::<percent>(100.)
+        };
 
         match controller.signal() {
             Some(TurbineSignal::StartOrContinue) => self,
-            Some(TurbineSignal::Stop) | None => Box::new(Stopping::new(
-                self.egt,
-                Ratio::new::<percent>(100.),
-                self.n2(),
-            )),
+            Some(TurbineSignal::Stop) | None => {
+                Box::new(Stopping::new(self.egt, self.n, self.n2()))
+            }
         }
     }
 
     fn n(&self) -> Ratio {
-        Ratio::new::<percent>(100.)
+        self.n
     }
 
     fn n2(&self) -> Ratio {
@@ -563,9 +610,7 @@ impl Turbine for Running {
     }
 
     fn bleed_air_pressure(&self) -> Pressure {
-        // Value from refs, we add standard pressure at sea level as state of unpressurized system
-        Pressure::new::<psi>(40.)
-            + InternationalStandardAtmosphere::pressure_at_altitude(Length::ZERO)
+        self.bleed_air_pressure
     }
 }
 
@@ -749,6 +794,7 @@ impl Turbine for Stopping {
         context: &UpdateContext,
         _: bool,
         _: bool,
+        _: bool,
         _: &dyn ControllerSignal<TurbineSignal>,
     ) -> Box<dyn Turbine> {
         self.since += context.delta();
@@ -813,10 +859,13 @@ pub struct Pw980ApuGenerator {
     output_potential: ElectricPotential,
     load: Ratio,
     is_emergency_shutdown: bool,
+    gcu: GeneratorControlUnit,
     failure: Failure,
 }
 impl Pw980ApuGenerator {
     pub(super) const APU_GEN_POWERED_N: f64 = 77.;
+    // Just below the frequency the generator reaches at APU_GEN_POWERED_N.
+    const APU_GEN_EXCITATION_FREQUENCY: f64 = 320.;
 
     pub fn new(context: &mut InitContext, number: usize) -> Pw980ApuGenerator {
         Pw980ApuGenerator {
@@ -828,6 +877,12 @@ impl Pw980ApuGenerator {
             output_frequency: Frequency::default(),
             load: Ratio::default(),
             is_emergency_shutdown: false,
+            gcu: GeneratorControlUnit::new(
+                context,
+                PotentialOrigin::ApuGenerator(number),
+                390.0..=410.0,
+                Self::APU_GEN_EXCITATION_FREQUENCY,
+            ),
             failure: Failure::new(FailureType::ApuGenerator(number)),
         }
     }
@@ -872,16 +927,39 @@ impl Pw980ApuGenerator {
         }
     }
 
+    fn is_spinning_fast_enough(&self) -> bool {
+        self.n.get::<percent>() >= Pw980ApuGenerator::APU_GEN_POWERED_N
+    }
+
+    fn can_provide_output(&self) -> bool {
+        !self.failure.is_active() && !self.is_emergency_shutdown && self.is_spinning_fast_enough()
+    }
+
     fn should_provide_output(&self) -> bool {
-        !self.failure.is_active()
-            && !self.is_emergency_shutdown
-            && self.n.get::<percent>() >= Pw980ApuGenerator::APU_GEN_POWERED_N
+        self.can_provide_output() && self.gcu.generator_is_excited()
     }
 }
 impl ApuGenerator for Pw980ApuGenerator {
-    fn update(&mut self, n: Ratio, is_emergency_shutdown: bool) {
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        n: Ratio,
+        is_emergency_shutdown: bool,
+        push_button_is_on: bool,
+    ) {
         self.n = n;
         self.is_emergency_shutdown = is_emergency_shutdown;
+
+        let frequency = if self.is_spinning_fast_enough() {
+            self.calculate_frequency(n)
+        } else {
+            Frequency::default()
+        };
+        self.gcu.update(
+            context,
+            push_button_is_on && !is_emergency_shutdown,
+            frequency,
+        );
     }
 
     /// Indicates if the provided electricity's potential and frequency
@@ -891,7 +969,7 @@ impl ApuGenerator for Pw980ApuGenerator {
     /// overtemperature which over time will trigger a mechanical
     /// disconnect of the generator.
     fn output_within_normal_parameters(&self) -> bool {
-        self.should_provide_output() && self.potential_normal() && self.frequency_normal()
+        self.should_provide_output() && self.gcu.line_contactor_should_close()
     }
 }
 provide_potential!(Pw980ApuGenerator, (110.0..=120.0));
@@ -924,6 +1002,7 @@ impl ElectricitySource for Pw980ApuGenerator {
 }
 impl SimulationElement for Pw980ApuGenerator {
     fn accept<T: crate::simulation::SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.gcu.accept(visitor);
         self.failure.accept(visitor);
         visitor.visit(self);
     }
@@ -937,11 +1016,11 @@ impl SimulationElement for Pw980ApuGenerator {
         _: &UpdateContext,
         report: &T,
     ) {
-        self.output_potential = if self.should_provide_output() {
+        self.output_potential = self.gcu.regulate(if self.can_provide_output() {
             self.calculate_potential(self.n)
         } else {
             ElectricPotential::default()
-        };
+        });
 
         self.output_frequency = if self.should_provide_output() {
             self.calculate_frequency(self.n)
@@ -969,6 +1048,7 @@ pub struct Pw980StartMotor {
     powered_by: ElectricalBusType,
     is_powered: bool,
     powered_since: Duration,
+    failure: Failure,
 }
 impl Pw980StartMotor {
     pub fn new(powered_by: ElectricalBusType) -> Self {
@@ -976,6 +1056,7 @@ impl Pw980StartMotor {
             powered_by,
             is_powered: false,
             powered_since: Duration::ZERO,
+            failure: Failure::new(FailureType::ApuStartMotor),
         }
     }
 }
@@ -983,14 +1064,23 @@ impl ApuStartMotor for Pw980StartMotor {
     fn is_powered(&self) -> bool {
         self.is_powered
     }
+
+    fn is_turning(&self) -> bool {
+        self.is_powered && !self.failure.is_active()
+    }
 }
 impl SimulationElement for Pw980StartMotor {
+    fn accept<T: crate::simulation::SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+        visitor.visit(self);
+    }
+
     fn receive_power(&mut self, buses: &impl ElectricalBuses) {
         self.is_powered = buses.is_powered(self.powered_by);
     }
 
     fn consume_power<T: ConsumePower>(&mut self, context: &UpdateContext, consumption: &mut T) {
-        if !self.is_powered {
+        if !self.is_turning() {
             self.powered_since = Duration::ZERO;
         } else {
             self.powered_since += context.delta();
@@ -1204,6 +1294,17 @@ mod apu_generator_tests {
         assert!(!test_bed.load_within_normal_range());
     }
 
+    #[test]
+    fn when_apu_gen_push_button_off_provides_no_output() {
+        let test_bed = test_bed_with()
+            .running_apu()
+            .and()
+            .apu_gen_push_button_off()
+            .run(Duration::from_secs(1));
+
+        assert!(test_bed.generator_is_unpowered());
+    }
+
     #[test]
     fn when_apu_emergency_shutdown_provides_no_output() {
         let test_bed = test_bed_with()
@@ -1234,16 +1335,16 @@ mod apu_generator_tests {
     }
 
     fn update_above_threshold(test_bed: &mut SimulationTestBed<TestAircraft<Pw980ApuGenerator>>) {
-        test_bed.set_update_before_power_distribution(|generator, _, electricity| {
-            generator.update(Ratio::new::<percent>(100.), false);
+        test_bed.set_update_before_power_distribution(|generator, context, electricity| {
+            generator.update(context, Ratio::new::<percent>(100.), false, true);
             electricity.supplied_by(generator);
         });
         test_bed.run();
     }
 
     fn update_below_threshold(test_bed: &mut SimulationTestBed<TestAircraft<Pw980ApuGenerator>>) {
-        test_bed.set_update_before_power_distribution(|generator, _, electricity| {
-            generator.update(Ratio::default(), false);
+        test_bed.set_update_before_power_distribution(|generator, context, electricity| {
+            generator.update(context, Ratio::default(), false, true);
             electricity.supplied_by(generator);
         });
         test_bed.run();
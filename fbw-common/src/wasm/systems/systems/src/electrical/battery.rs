@@ -5,7 +5,10 @@ use uom::si::{
 
 use crate::{
     shared::{ConsumePower, PowerConsumptionReport},
-    simulation::{InitContext, SimulationElement, SimulatorWriter, UpdateContext},
+    simulation::{
+        InitContext, SimulationElement, SimulatorWriter, SnapshotReader, SnapshotWriter,
+        UpdateContext, VariableIdentifier, Write,
+    },
 };
 
 use super::{
@@ -18,6 +21,7 @@ pub struct Battery {
     number: usize,
     identifier: ElectricalElementIdentifier,
     writer: ElectricalStateWriter,
+    charge_id: VariableIdentifier,
     charge: ElectricCharge,
     input_potential: ElectricPotential,
     output_potential: ElectricPotential,
@@ -51,6 +55,7 @@ impl Battery {
             number,
             identifier: context.next_electrical_identifier(),
             writer: ElectricalStateWriter::new(context, &format!("BAT_{}", number)),
+            charge_id: context.get_identifier(format!("ELEC_BAT_{}_CHARGE", number)),
             charge,
             input_potential: ElectricPotential::new::<volt>(0.),
             output_potential: Battery::calculate_output_potential_for_charge(charge),
@@ -71,7 +76,7 @@ impl Battery {
         self.charge
     }
 
-    /// Function for testing purposes.
+    /// Sets the charge, as done by tests and when restoring a snapshot.
     fn set_charge(&mut self, charge: ElectricCharge) {
         self.charge = charge;
         self.input_potential = ElectricPotential::new::<volt>(0.);
@@ -180,6 +185,16 @@ impl SimulationElement for Battery {
         self.writer.write_direct(self, writer);
     }
 
+    fn save_state(&self, writer: &mut SnapshotWriter) {
+        writer.write(&self.charge_id, self.charge.get::<ampere_hour>());
+    }
+
+    fn restore_state(&mut self, reader: &mut SnapshotReader) {
+        if let Some(charge) = reader.restore(&self.charge_id) {
+            self.set_charge(ElectricCharge::new::<ampere_hour>(charge));
+        }
+    }
+
     fn consume_power<T: ConsumePower>(&mut self, context: &UpdateContext, consumption: &mut T) {
         self.input_potential = consumption.input_of(self).raw();
 
@@ -235,7 +250,7 @@ mod tests {
             },
             simulation::{
                 test::{SimulationTestBed, TestBed},
-                Aircraft, SimulationElementVisitor, UpdateContext,
+                Aircraft, SimulationElementVisitor, StartState, UpdateContext,
             },
         };
         use std::time::Duration;
@@ -317,6 +332,23 @@ mod tests {
                 }
             }
 
+            fn from_snapshot_of(test_bed: &mut BatteryTestBed) -> Self {
+                let snapshot = test_bed.snapshot();
+                Self {
+                    test_bed: SimulationTestBed::new_from_snapshot(
+                        StartState::Cruise,
+                        |context| {
+                            TestAircraft::new(
+                                Battery::full(context, 1),
+                                Battery::full(context, 2),
+                                context,
+                            )
+                        },
+                        &snapshot,
+                    ),
+                }
+            }
+
             fn current_is_normal(&mut self, number: usize) -> bool {
                 self.read_by_name(&format!("ELEC_BAT_{}_CURRENT_NORMAL", number))
             }
@@ -695,5 +727,16 @@ mod tests {
                     < ElectricCharge::new::<ampere_hour>(0.001)
             );
         }
+
+        #[test]
+        fn charge_is_restored_from_snapshot() {
+            let mut test_bed = BatteryTestBed::with_half_charged_batteries();
+            test_bed.run();
+            let charge = test_bed.query(|a| a.battery_1_charge());
+
+            let test_bed = BatteryTestBed::from_snapshot_of(&mut test_bed);
+
+            assert_eq!(test_bed.query(|a| a.battery_1_charge()), charge);
+        }
     }
 }
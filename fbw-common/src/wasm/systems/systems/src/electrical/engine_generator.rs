@@ -1,7 +1,7 @@
 use super::{
     ElectricalElement, ElectricalElementIdentifier, ElectricalElementIdentifierProvider,
-    ElectricalStateWriter, ElectricitySource, EngineGeneratorPushButtons, Potential,
-    PotentialOrigin, ProvideFrequency, ProvideLoad, ProvidePotential,
+    ElectricalStateWriter, ElectricitySource, EngineGeneratorPushButtons, GeneratorControlUnit,
+    Potential, PotentialOrigin, ProvideFrequency, ProvideLoad, ProvidePotential,
 };
 use crate::{
     engine::Engine,
@@ -12,7 +12,7 @@ use crate::{
         VariableIdentifier, Write,
     },
 };
-use std::{ops::RangeInclusive, time::Duration};
+use std::ops::RangeInclusive;
 use uom::si::{
     angular_velocity::revolution_per_minute,
     electric_potential::volt,
@@ -22,8 +22,6 @@ use uom::si::{
     thermodynamic_temperature::degree_celsius,
 };
 
-pub const INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME: Duration = Duration::from_millis(500);
-
 pub type IntegratedDriveGenerator = EngineGenerator<ConstantSpeedDrive>;
 pub type VariableFrequencyGenerator = EngineGenerator<DirectDrive>;
 
@@ -43,10 +41,9 @@ pub struct EngineGenerator<Drive: EngineGeneratorDrive> {
     drive: Drive,
     activated: bool,
     output_frequency: Frequency,
-    normal_frequency: RangeInclusive<f64>,
     output_potential: ElectricPotential,
     load: Ratio,
-    time_above_threshold: Duration,
+    gcu: GeneratorControlUnit,
     failure: Failure,
 }
 impl<Drive: EngineGeneratorDrive> EngineGenerator<Drive> {
@@ -64,10 +61,14 @@ impl<Drive: EngineGeneratorDrive> EngineGenerator<Drive> {
             drive: Drive::new_drive(context, number),
             activated: true,
             output_frequency: Frequency::new::<hertz>(0.),
-            normal_frequency,
             output_potential: ElectricPotential::new::<volt>(0.),
             load: Ratio::new::<percent>(0.),
-            time_above_threshold: INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME,
+            gcu: GeneratorControlUnit::new(
+                context,
+                PotentialOrigin::EngineGenerator(number),
+                normal_frequency.clone(),
+                *normal_frequency.start(),
+            ),
             failure: Failure::new(FailureType::Generator(number)),
         }
     }
@@ -93,30 +94,8 @@ impl<Drive: EngineGeneratorDrive> EngineGenerator<Drive> {
         } else {
             Frequency::default()
         };
-        self.update_stable_time(context);
-    }
-
-    // TODO: move to GCU when implemented
-    fn update_stable_time(&mut self, context: &UpdateContext) {
-        if !self.activated {
-            self.time_above_threshold = Duration::ZERO;
-            return;
-        }
-
-        let new_time = if self.frequency_normal() {
-            self.time_above_threshold + context.delta()
-        } else {
-            Duration::ZERO
-        };
-
-        self.time_above_threshold = new_time.clamp(
-            Duration::ZERO,
-            INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME,
-        );
-    }
-
-    fn provides_stable_power_output(&self) -> bool {
-        self.time_above_threshold == INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME
+        self.gcu
+            .update(context, self.activated, self.output_frequency);
     }
 
     /// Indicates if the provided electricity's potential and frequency
@@ -126,19 +105,21 @@ impl<Drive: EngineGeneratorDrive> EngineGenerator<Drive> {
     /// overtemperature which over time will trigger a mechanical
     /// disconnect of the generator.
     pub fn output_within_normal_parameters(&self) -> bool {
-        self.should_provide_output() && self.potential_normal()
+        self.should_provide_output() && self.gcu.line_contactor_should_close()
     }
 
     fn should_provide_output(&self) -> bool {
-        self.provides_stable_power_output()
-            && self.activated
-            && self.frequency_normal()
-            && !self.failure.is_active()
+        self.gcu.generator_is_excited() && !self.failure.is_active()
     }
 
     pub fn is_drive_connected(&self) -> bool {
         self.drive.is_connected()
     }
+
+    /// The true power drawn from the generator, which is extracted from the engine driving it.
+    pub fn output_power(&self) -> Power {
+        self.max_true_power * self.load
+    }
 }
 impl<Drive: EngineGeneratorDrive> ElectricitySource for EngineGenerator<Drive> {
     fn output_potential(&self) -> Potential {
@@ -152,24 +133,20 @@ impl<Drive: EngineGeneratorDrive> ElectricitySource for EngineGenerator<Drive> {
         }
     }
 }
-// TODO: Move to GCU
 impl<Drive: EngineGeneratorDrive> ProvidePotential for EngineGenerator<Drive> {
     fn potential(&self) -> ElectricPotential {
         self.output_potential
     }
     fn potential_normal(&self) -> bool {
-        let volts = self.output_potential.get::<volt>();
-        (110.0..=120.0).contains(&volts)
+        self.gcu.potential_is_normal(self.output_potential)
     }
 }
-// TODO: Move to GCU
 impl<Drive: EngineGeneratorDrive> ProvideFrequency for EngineGenerator<Drive> {
     fn frequency(&self) -> Frequency {
         self.output_frequency
     }
     fn frequency_normal(&self) -> bool {
-        let hz = self.output_frequency.get::<hertz>();
-        self.normal_frequency.contains(&hz)
+        self.gcu.frequency_is_normal(self.output_frequency)
     }
 }
 // TODO: Move to GCU
@@ -197,6 +174,7 @@ impl<Drive: EngineGeneratorDrive> ElectricalElement for EngineGenerator<Drive> {
 impl<Drive: EngineGeneratorDrive> SimulationElement for EngineGenerator<Drive> {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.drive.accept(visitor);
+        self.gcu.accept(visitor);
         self.failure.accept(visitor);
 
         visitor.visit(self);
@@ -209,11 +187,11 @@ impl<Drive: EngineGeneratorDrive> SimulationElement for EngineGenerator<Drive> {
     ) {
         const POWERFACTOR: f64 = 0.8;
 
-        self.output_potential = if self.should_provide_output() {
-            ElectricPotential::new::<volt>(115.)
-        } else {
+        self.output_potential = self.gcu.regulate(if self.failure.is_active() {
             ElectricPotential::new::<volt>(0.)
-        };
+        } else {
+            ElectricPotential::new::<volt>(115.)
+        });
 
         let power_consumption =
             report.total_consumption_of(PotentialOrigin::EngineGenerator(self.number));
@@ -531,6 +509,7 @@ mod tests {
                 Aircraft, InitContext,
             },
         };
+        use std::time::Duration;
         use uom::si::power::{kilowatt, watt};
 
         struct EngineGeneratorTestBed {
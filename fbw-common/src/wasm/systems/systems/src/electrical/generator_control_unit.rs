@@ -0,0 +1,463 @@
+use super::PotentialOrigin;
+use crate::{
+    failures::{Failure, FailureType},
+    simulation::{
+        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
+        VariableIdentifier, Write,
+    },
+};
+use std::{ops::RangeInclusive, time::Duration};
+use uom::si::{electric_potential::volt, f64::*, frequency::hertz};
+
+pub const INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME: Duration = Duration::from_millis(500);
+
+/// The protection which caused a generator control unit to trip.
+/// Written as its number, where zero indicates no fault.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GeneratorControlUnitFault {
+    OverVoltage = 1,
+    UnderVoltage = 2,
+    OverFrequency = 3,
+    Differential = 4,
+}
+
+/// The generator control unit (GCU) regulates the voltage of an engine or APU generator
+/// by controlling its excitation and protects the network against a faulty generator.
+///
+/// A tripped protection de-excites the generator and opens its line contactor. The fault
+/// remains latched until the GEN push button is switched off. Under frequency is not
+/// considered a fault, as it occurs during every start and shutdown. The generator is only
+/// excited above its excitation frequency and the line contactor only closes once the
+/// frequency has been stable within its normal range.
+pub struct GeneratorControlUnit {
+    fault_id: VariableIdentifier,
+    is_excited_id: VariableIdentifier,
+
+    normal_frequency: RangeInclusive<f64>,
+    excitation_frequency: f64,
+    excitation_is_commanded: bool,
+    frequency: Frequency,
+    potential: ElectricPotential,
+    time_frequency_stable: Duration,
+    time_over_voltage: Duration,
+    time_under_voltage: Duration,
+    time_over_frequency: Duration,
+    latched_fault: Option<GeneratorControlUnitFault>,
+    voltage_regulator_failure: Failure,
+    feeder_failure: Failure,
+}
+impl GeneratorControlUnit {
+    const NORMAL_POTENTIAL_VOLTS: RangeInclusive<f64> = 110.0..=120.0;
+    // Without regulation the excitation runs away to its ceiling.
+    const UNREGULATED_POTENTIAL_VOLTS: f64 = 150.;
+    const OVER_VOLTAGE_THRESHOLD_VOLTS: f64 = 130.;
+    const UNDER_VOLTAGE_THRESHOLD_VOLTS: f64 = 100.;
+    const OVER_FREQUENCY_MARGIN_HZ: f64 = 20.;
+    const OVER_VOLTAGE_DELAY: Duration = Duration::from_millis(100);
+    const UNDER_VOLTAGE_DELAY: Duration = Duration::from_secs(3);
+    const OVER_FREQUENCY_DELAY: Duration = Duration::from_secs(1);
+
+    pub fn new(
+        context: &mut InitContext,
+        origin: PotentialOrigin,
+        normal_frequency: RangeInclusive<f64>,
+        excitation_frequency: f64,
+    ) -> Self {
+        let element_id = match origin {
+            PotentialOrigin::EngineGenerator(number) => format!("ENG_GEN_{}", number),
+            PotentialOrigin::ApuGenerator(number) => format!("APU_GEN_{}", number),
+            _ => panic!("A GCU only controls engine and APU generators."),
+        };
+
+        Self {
+            fault_id: context.get_identifier(format!("ELEC_{}_GCU_FAULT", element_id)),
+            is_excited_id: context.get_identifier(format!("ELEC_{}_GCU_IS_EXCITED", element_id)),
+
+            normal_frequency,
+            excitation_frequency,
+            excitation_is_commanded: false,
+            frequency: Frequency::default(),
+            potential: ElectricPotential::default(),
+            // Allows starting the simulation with running generators.
+            time_frequency_stable: INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME,
+            time_over_voltage: Duration::ZERO,
+            time_under_voltage: Duration::ZERO,
+            time_over_frequency: Duration::ZERO,
+            latched_fault: None,
+            voltage_regulator_failure: Failure::new(FailureType::GeneratorVoltageRegulator(origin)),
+            feeder_failure: Failure::new(FailureType::GeneratorFeederFault(origin)),
+        }
+    }
+
+    /// Updates the GCU with the generator's output frequency.
+    /// The excitation is commanded by the GEN push button. Switching it off resets a latched fault.
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        excitation_is_commanded: bool,
+        frequency: Frequency,
+    ) {
+        self.excitation_is_commanded = excitation_is_commanded;
+        self.frequency = frequency;
+
+        if !excitation_is_commanded {
+            self.latched_fault = None;
+        }
+
+        self.update_stable_time(context);
+        self.update_protections(context);
+    }
+
+    fn update_stable_time(&mut self, context: &UpdateContext) {
+        let new_time = if self.generator_is_excited() && self.frequency_is_normal(self.frequency) {
+            self.time_frequency_stable + context.delta()
+        } else {
+            Duration::ZERO
+        };
+
+        self.time_frequency_stable = new_time.clamp(
+            Duration::ZERO,
+            INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME,
+        );
+    }
+
+    fn update_protections(&mut self, context: &UpdateContext) {
+        if !self.generator_is_excited() {
+            self.time_over_voltage = Duration::ZERO;
+            self.time_under_voltage = Duration::ZERO;
+            self.time_over_frequency = Duration::ZERO;
+            return;
+        }
+
+        let volts = self.potential.get::<volt>();
+        Self::accumulate(
+            &mut self.time_over_voltage,
+            volts > Self::OVER_VOLTAGE_THRESHOLD_VOLTS,
+            context,
+        );
+        Self::accumulate(
+            &mut self.time_under_voltage,
+            volts < Self::UNDER_VOLTAGE_THRESHOLD_VOLTS,
+            context,
+        );
+        Self::accumulate(
+            &mut self.time_over_frequency,
+            self.frequency.get::<hertz>()
+                > self.normal_frequency.end() + Self::OVER_FREQUENCY_MARGIN_HZ,
+            context,
+        );
+
+        self.latched_fault = if self.feeder_failure.is_active() {
+            Some(GeneratorControlUnitFault::Differential)
+        } else if self.time_over_voltage > Self::OVER_VOLTAGE_DELAY {
+            Some(GeneratorControlUnitFault::OverVoltage)
+        } else if self.time_under_voltage > Self::UNDER_VOLTAGE_DELAY {
+            Some(GeneratorControlUnitFault::UnderVoltage)
+        } else if self.time_over_frequency > Self::OVER_FREQUENCY_DELAY {
+            Some(GeneratorControlUnitFault::OverFrequency)
+        } else {
+            None
+        };
+    }
+
+    fn is_under_frequency(&self) -> bool {
+        self.frequency.get::<hertz>() < self.excitation_frequency
+    }
+
+    fn accumulate(time: &mut Duration, condition: bool, context: &UpdateContext) {
+        *time = if condition {
+            *time + context.delta()
+        } else {
+            Duration::ZERO
+        };
+    }
+
+    /// Indicates if the generator field is excited, i.e. the generator supplies a potential.
+    pub fn generator_is_excited(&self) -> bool {
+        self.excitation_is_commanded && self.latched_fault.is_none() && !self.is_under_frequency()
+    }
+
+    /// Regulates the generator's potential and returns it. The nominal potential is the potential
+    /// the generator supplies at its current speed when excited, or zero if it cannot supply any.
+    pub fn regulate(&mut self, nominal_potential: ElectricPotential) -> ElectricPotential {
+        self.potential =
+            if !self.generator_is_excited() || nominal_potential <= ElectricPotential::default() {
+                ElectricPotential::default()
+            } else if self.voltage_regulator_failure.is_active() {
+                ElectricPotential::new::<volt>(Self::UNREGULATED_POTENTIAL_VOLTS)
+            } else {
+                nominal_potential
+            };
+
+        self.potential
+    }
+
+    /// Indicates if the generator line contactor should close.
+    pub fn line_contactor_should_close(&self) -> bool {
+        self.generator_is_excited()
+            && self.time_frequency_stable == INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME
+            && self.potential_is_normal(self.potential)
+            && self.frequency_is_normal(self.frequency)
+    }
+
+    pub fn potential_is_normal(&self, potential: ElectricPotential) -> bool {
+        Self::NORMAL_POTENTIAL_VOLTS.contains(&potential.get::<volt>())
+    }
+
+    pub fn frequency_is_normal(&self, frequency: Frequency) -> bool {
+        self.normal_frequency.contains(&frequency.get::<hertz>())
+    }
+
+    pub fn fault(&self) -> Option<GeneratorControlUnitFault> {
+        self.latched_fault
+    }
+}
+impl SimulationElement for GeneratorControlUnit {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.voltage_regulator_failure.accept(visitor);
+        self.feeder_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(
+            &self.fault_id,
+            self.latched_fault.map_or(0., |fault| fault as u8 as f64),
+        );
+        writer.write(&self.is_excited_id, self.generator_is_excited());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::{
+        test::{ReadByName, SimulationTestBed, TestBed},
+        Aircraft,
+    };
+
+    struct TestAircraft {
+        gcu: GeneratorControlUnit,
+        push_button_is_on: bool,
+        frequency: Frequency,
+        nominal_potential: ElectricPotential,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                gcu: GeneratorControlUnit::new(
+                    context,
+                    PotentialOrigin::EngineGenerator(1),
+                    390.0..=410.0,
+                    390.,
+                ),
+                push_button_is_on: true,
+                frequency: Frequency::new::<hertz>(400.),
+                nominal_potential: ElectricPotential::new::<volt>(115.),
+            }
+        }
+
+        fn set_push_button(&mut self, is_on: bool) {
+            self.push_button_is_on = is_on;
+        }
+
+        fn set_frequency(&mut self, hz: f64) {
+            self.frequency = Frequency::new::<hertz>(hz);
+        }
+
+        fn set_nominal_potential(&mut self, volts: f64) {
+            self.nominal_potential = ElectricPotential::new::<volt>(volts);
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.gcu
+                .update(context, self.push_button_is_on, self.frequency);
+            self.gcu.regulate(self.nominal_potential);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.gcu.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed() -> SimulationTestBed<TestAircraft> {
+        SimulationTestBed::new(TestAircraft::new)
+    }
+
+    #[test]
+    fn closes_line_contactor_once_frequency_is_stable() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_frequency(300.));
+        test_bed.run();
+        test_bed.command(|a| a.set_frequency(400.));
+        test_bed.run_with_delta(Duration::from_millis(100));
+        assert!(test_bed.query(|a| a.gcu.generator_is_excited()));
+        assert!(!test_bed.query(|a| a.gcu.line_contactor_should_close()));
+
+        test_bed.run_with_delta(INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME);
+        assert!(test_bed.query(|a| a.gcu.line_contactor_should_close()));
+    }
+
+    #[test]
+    fn does_not_excite_generator_below_excitation_frequency() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_frequency(300.));
+        test_bed.run();
+
+        assert!(!test_bed.query(|a| a.gcu.generator_is_excited()));
+        assert_eq!(test_bed.query(|a| a.gcu.fault()), None);
+    }
+
+    #[test]
+    fn does_not_excite_generator_when_push_button_off() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_push_button(false));
+        test_bed.run();
+
+        assert!(!test_bed.query(|a| a.gcu.generator_is_excited()));
+        assert!(!test_bed.query(|a| a.gcu.line_contactor_should_close()));
+    }
+
+    #[test]
+    fn trips_on_over_voltage() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_nominal_potential(140.));
+        test_bed.run();
+        assert!(!test_bed.query(|a| a.gcu.line_contactor_should_close()));
+
+        test_bed.run();
+        assert_eq!(
+            test_bed.query(|a| a.gcu.fault()),
+            Some(GeneratorControlUnitFault::OverVoltage)
+        );
+        assert!(!test_bed.query(|a| a.gcu.generator_is_excited()));
+    }
+
+    #[test]
+    fn trips_on_under_voltage_after_delay() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_nominal_potential(90.));
+        test_bed.run();
+        test_bed.run_with_delta(Duration::from_secs(2));
+        assert_eq!(test_bed.query(|a| a.gcu.fault()), None);
+
+        test_bed.run_with_delta(Duration::from_secs(2));
+        assert_eq!(
+            test_bed.query(|a| a.gcu.fault()),
+            Some(GeneratorControlUnitFault::UnderVoltage)
+        );
+    }
+
+    #[test]
+    fn trips_on_over_frequency() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+        test_bed.command(|a| a.set_frequency(450.));
+        test_bed.run_with_delta(Duration::from_millis(100));
+        assert!(!test_bed.query(|a| a.gcu.line_contactor_should_close()));
+
+        test_bed.run_with_delta(Duration::from_millis(1500));
+        assert_eq!(
+            test_bed.query(|a| a.gcu.fault()),
+            Some(GeneratorControlUnitFault::OverFrequency)
+        );
+    }
+
+    #[test]
+    fn trips_on_voltage_regulator_failure() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+        test_bed.fail(FailureType::GeneratorVoltageRegulator(
+            PotentialOrigin::EngineGenerator(1),
+        ));
+        test_bed.run();
+        test_bed.run();
+
+        assert_eq!(
+            test_bed.query(|a| a.gcu.fault()),
+            Some(GeneratorControlUnitFault::OverVoltage)
+        );
+    }
+
+    #[test]
+    fn trips_on_differential_fault() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+        test_bed.fail(FailureType::GeneratorFeederFault(
+            PotentialOrigin::EngineGenerator(1),
+        ));
+        test_bed.run();
+
+        assert_eq!(
+            test_bed.query(|a| a.gcu.fault()),
+            Some(GeneratorControlUnitFault::Differential)
+        );
+        assert!(!test_bed.query(|a| a.gcu.generator_is_excited()));
+    }
+
+    #[test]
+    fn fault_is_reset_by_push_button() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_nominal_potential(140.));
+        test_bed.run();
+        test_bed.run();
+        assert!(test_bed.query(|a| a.gcu.fault()).is_some());
+
+        test_bed.command(|a| a.set_nominal_potential(115.));
+        test_bed.run();
+        assert!(test_bed.query(|a| a.gcu.fault()).is_some());
+
+        test_bed.command(|a| a.set_push_button(false));
+        test_bed.run();
+        test_bed.command(|a| a.set_push_button(true));
+        test_bed.run();
+
+        assert_eq!(test_bed.query(|a| a.gcu.fault()), None);
+        assert!(test_bed.query(|a| a.gcu.line_contactor_should_close()));
+    }
+
+    #[test]
+    fn writes_fault_and_excitation() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+
+        let fault: f64 = test_bed.read_by_name("ELEC_ENG_GEN_1_GCU_FAULT");
+        let is_excited: bool = test_bed.read_by_name("ELEC_ENG_GEN_1_GCU_IS_EXCITED");
+        assert_eq!(fault, 0.);
+        assert!(is_excited);
+
+        test_bed.fail(FailureType::GeneratorFeederFault(
+            PotentialOrigin::EngineGenerator(1),
+        ));
+        test_bed.run();
+
+        let fault: f64 = test_bed.read_by_name("ELEC_ENG_GEN_1_GCU_FAULT");
+        let is_excited: bool = test_bed.read_by_name("ELEC_ENG_GEN_1_GCU_IS_EXCITED");
+        assert_eq!(fault, 4.);
+        assert!(!is_excited);
+    }
+
+    #[test]
+    fn differential_fault_trips_again_after_reset() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+        test_bed.fail(FailureType::GeneratorFeederFault(
+            PotentialOrigin::EngineGenerator(1),
+        ));
+        test_bed.run();
+        test_bed.command(|a| a.set_push_button(false));
+        test_bed.run();
+        test_bed.command(|a| a.set_push_button(true));
+        test_bed.run();
+
+        assert_eq!(
+            test_bed.query(|a| a.gcu.fault()),
+            Some(GeneratorControlUnitFault::Differential)
+        );
+    }
+}
@@ -7,6 +7,7 @@ pub mod consumption;
 mod emergency_generator;
 mod engine_generator;
 mod external_power_source;
+mod generator_control_unit;
 mod ram_air_turbine;
 mod static_inverter;
 mod transformer_rectifier;
@@ -34,17 +35,17 @@ pub use battery::Battery;
 pub use battery_charge_limiter::BatteryChargeLimiter;
 pub use battery_charge_rectifier_unit::BatteryChargeRectifierUnit;
 pub use emergency_generator::EmergencyGenerator;
-pub use engine_generator::{
-    EngineGenerator, IntegratedDriveGenerator, VariableFrequencyGenerator,
-    INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME,
-};
+pub use engine_generator::{EngineGenerator, IntegratedDriveGenerator, VariableFrequencyGenerator};
 pub use external_power_source::ExternalPowerSource;
 use fxhash::{FxHashMap, FxHashSet};
+pub use generator_control_unit::{
+    GeneratorControlUnit, GeneratorControlUnitFault, INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME,
+};
 pub use static_inverter::StaticInverter;
 pub use transformer_rectifier::TransformerRectifier;
 use uom::si::{electric_potential::volt, f64::*, power::watt, velocity::knot};
 
-pub use ram_air_turbine::{GeneratorControlUnit, RamAirTurbine};
+pub use ram_air_turbine::{RamAirTurbine, RamAirTurbineGeneratorControlUnit};
 
 pub mod test;
 
@@ -57,6 +58,10 @@ pub trait EngineGeneratorPushButtons {
     fn idg_push_button_is_released(&self, number: usize) -> bool;
 }
 
+pub trait ApuGeneratorPushButtons {
+    fn apu_gen_push_button_is_on(&self, number: usize) -> bool;
+}
+
 pub trait BatteryPushButtons {
     fn bat_is_auto(&self, number: usize) -> bool;
 }
@@ -132,7 +132,7 @@ impl AngularSpeedSensor for RamAirTurbine {
     }
 }
 
-pub struct GeneratorControlUnit {
+pub struct RamAirTurbineGeneratorControlUnit {
     is_active: bool,
     max_allowed_power_rpm_breakpoints: [f64; 9],
     max_allowed_power_vs_rpm: [f64; 9],
@@ -140,7 +140,7 @@ pub struct GeneratorControlUnit {
 
     manual_generator_on_was_pressed: bool,
 }
-impl GeneratorControlUnit {
+impl RamAirTurbineGeneratorControlUnit {
     const MAX_ALLOWED_POWER_RPM_BREAKPOINTS: [f64; 9] = [
         0., 1000., 6000., 6900., 7500., 8000., 10000., 11000., 12000.,
     ];
@@ -195,7 +195,7 @@ impl GeneratorControlUnit {
         }
     }
 }
-impl EmergencyGeneratorControlUnit for GeneratorControlUnit {
+impl EmergencyGeneratorControlUnit for RamAirTurbineGeneratorControlUnit {
     fn max_allowed_power(&self) -> Power {
         self.max_allowed_power()
     }
@@ -204,7 +204,7 @@ impl EmergencyGeneratorControlUnit for GeneratorControlUnit {
         self.current_speed
     }
 }
-impl Default for GeneratorControlUnit {
+impl Default for RamAirTurbineGeneratorControlUnit {
     fn default() -> Self {
         Self::new()
     }
@@ -307,7 +307,7 @@ mod tests {
         }
     }
 
-    impl EmergencyGeneratorPower for GeneratorControlUnit {
+    impl EmergencyGeneratorPower for RamAirTurbineGeneratorControlUnit {
         fn generated_power(&self) -> Power {
             self.max_allowed_power()
         }
@@ -318,7 +318,7 @@ mod tests {
 
         rat_controller: TestRamAirTurbineController,
         rat: RamAirTurbine,
-        gcu: GeneratorControlUnit,
+        gcu: RamAirTurbineGeneratorControlUnit,
 
         rat_man_on: TestRatManOn,
         emergency_state: TestEmergencyState,
@@ -331,7 +331,7 @@ mod tests {
 
                 rat_controller: TestRamAirTurbineController::deploying(),
                 rat: RamAirTurbine::new(context),
-                gcu: GeneratorControlUnit::new(),
+                gcu: RamAirTurbineGeneratorControlUnit::new(),
 
                 rat_man_on: TestRatManOn::not_pressed(),
                 emergency_state: TestEmergencyState::in_emergency(),
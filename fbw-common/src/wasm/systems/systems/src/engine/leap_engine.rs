@@ -1,14 +1,32 @@
 use uom::si::{angular_velocity::revolution_per_minute, f64::*, pressure::psi, ratio::percent};
 
 use crate::{
-    shared::{EngineCorrectedN1, EngineCorrectedN2, EngineUncorrectedN2},
-    simulation::{Read, SimulationElement, SimulatorReader, UpdateContext},
+    shared::{EngineCorrectedN1, EngineCorrectedN2, EnginePneumaticLoads, EngineUncorrectedN2},
+    simulation::{Read, SimulationElement, SimulatorReader, SimulatorWriter, UpdateContext, Write},
 };
 
-use super::Engine;
+use super::{
+    physical_model::{EngineSimulationMode, PhysicalEngineConstants, PhysicalEngineModel},
+    Engine,
+};
 use crate::simulation::{InitContext, VariableIdentifier};
 
+struct LeapEngineConstants;
+impl PhysicalEngineConstants for LeapEngineConstants {
+    const MIN_IDLE_CORE_SPEED_PERCENT: f64 = 58.;
+    const MAX_CORE_SPEED_PERCENT: f64 = 100.;
+    const IDLE_N1_PERCENT: f64 = 19.;
+    const MAX_N1_PERCENT: f64 = 101.;
+    const LIGHT_OFF_CORE_SPEED_PERCENT: f64 = 20.;
+    const STARTER_FREE_RUNNING_CORE_SPEED_PERCENT: f64 = 60.;
+    const IDLE_EGT_DEGREE_CELSIUS: f64 = 500.;
+    const MAX_EGT_DEGREE_CELSIUS: f64 = 950.;
+}
+
 pub struct LeapEngine {
+    number: usize,
+    mode: EngineSimulationMode,
+
     corrected_n1_id: VariableIdentifier,
     corrected_n2_id: VariableIdentifier,
     thrust_id: VariableIdentifier,
@@ -24,6 +42,13 @@ pub struct LeapEngine {
     oil_pressure: Pressure,
 
     net_thrust: Mass,
+
+    commanded_n1_id: VariableIdentifier,
+    commanded_n1: Ratio,
+    model_n1_id: VariableIdentifier,
+    model_n2_id: VariableIdentifier,
+    model_egt_id: VariableIdentifier,
+    physical_model: PhysicalEngineModel<LeapEngineConstants>,
 }
 impl LeapEngine {
     // According to the Type Certificate Data Sheet of LEAP 1A26
@@ -38,7 +63,18 @@ impl LeapEngine {
     const LOW_OIL_PRESSURE_THRESHOLD_PSI: f64 = 18.;
 
     pub fn new(context: &mut InitContext, number: usize) -> LeapEngine {
+        Self::with_mode(context, number, EngineSimulationMode::Simulator)
+    }
+
+    pub fn with_mode(
+        context: &mut InitContext,
+        number: usize,
+        mode: EngineSimulationMode,
+    ) -> LeapEngine {
         LeapEngine {
+            number,
+            mode,
+
             corrected_n1_id: context.get_identifier(format!("TURB ENG CORRECTED N1:{}", number)),
             corrected_n2_id: context.get_identifier(format!("TURB ENG CORRECTED N2:{}", number)),
             uncorrected_n2_id: context.get_identifier(format!("ENGINE_N2:{}", number)),
@@ -53,10 +89,51 @@ impl LeapEngine {
             oil_pressure: Pressure::new::<psi>(0.),
 
             net_thrust: Mass::default(),
+
+            commanded_n1_id: context.get_identifier(format!("AUTOTHRUST_N1_COMMANDED:{}", number)),
+            commanded_n1: Ratio::default(),
+            model_n1_id: context.get_identifier(format!("ENGINE_MODEL_N1:{}", number)),
+            model_n2_id: context.get_identifier(format!("ENGINE_MODEL_N2:{}", number)),
+            model_egt_id: context.get_identifier(format!("ENGINE_MODEL_EGT:{}", number)),
+            physical_model: PhysicalEngineModel::new(),
         }
     }
 
-    pub fn update(&mut self, _: &UpdateContext) {}
+    /// Only has an effect when the engine is in [`EngineSimulationMode::Physical`],
+    /// in other modes the engine parameters are read from the simulator.
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        fuel_is_available: bool,
+        pneumatic: &impl EnginePneumaticLoads,
+        electrical_load: Power,
+    ) {
+        if self.mode != EngineSimulationMode::Physical {
+            return;
+        }
+
+        self.physical_model.update(
+            context,
+            self.commanded_n1,
+            fuel_is_available,
+            pneumatic.starter_air_pressure(self.number),
+            pneumatic.bleed_air_extraction(self.number),
+            electrical_load,
+        );
+
+        self.corrected_n1 = self.physical_model.corrected_n1(context);
+        self.corrected_n2 = self.physical_model.corrected_core_speed(context);
+        self.uncorrected_n2 = self.physical_model.core_speed();
+        self.update_parameters();
+    }
+
+    pub fn mode(&self) -> EngineSimulationMode {
+        self.mode
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.physical_model.is_burning()
+    }
 
     fn update_parameters(&mut self) {
         self.n2_speed = AngularVelocity::new::<revolution_per_minute>(
@@ -70,11 +147,27 @@ impl LeapEngine {
 }
 impl SimulationElement for LeapEngine {
     fn read(&mut self, reader: &mut SimulatorReader) {
-        self.corrected_n1 = reader.read(&self.corrected_n1_id);
-        self.corrected_n2 = reader.read(&self.corrected_n2_id);
-        self.uncorrected_n2 = reader.read(&self.uncorrected_n2_id);
         self.net_thrust = reader.read(&self.thrust_id);
-        self.update_parameters();
+
+        match self.mode {
+            EngineSimulationMode::Simulator => {
+                self.corrected_n1 = reader.read(&self.corrected_n1_id);
+                self.corrected_n2 = reader.read(&self.corrected_n2_id);
+                self.uncorrected_n2 = reader.read(&self.uncorrected_n2_id);
+                self.update_parameters();
+            }
+            EngineSimulationMode::Physical => {
+                self.commanded_n1 = reader.read(&self.commanded_n1_id);
+            }
+        }
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        if self.mode == EngineSimulationMode::Physical {
+            writer.write(&self.model_n1_id, self.physical_model.n1());
+            writer.write(&self.model_n2_id, self.physical_model.core_speed());
+            writer.write(&self.model_egt_id, self.physical_model.egt());
+        }
     }
 }
 impl EngineCorrectedN1 for LeapEngine {
@@ -114,3 +207,237 @@ impl Engine for LeapEngine {
         self.n2_speed
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::{
+        test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+        Aircraft, SimulationElementVisitor,
+    };
+    use ntest::assert_about_eq;
+    use std::time::Duration;
+    use uom::si::{
+        mass_rate::kilogram_per_second, power::kilowatt, thermodynamic_temperature::degree_celsius,
+        velocity::knot,
+    };
+
+    struct TestPneumatic {
+        starter_air_pressure: Pressure,
+        bleed_air_extraction: MassRate,
+    }
+    impl EnginePneumaticLoads for TestPneumatic {
+        fn starter_air_pressure(&self, _: usize) -> Pressure {
+            self.starter_air_pressure
+        }
+
+        fn bleed_air_extraction(&self, _: usize) -> MassRate {
+            self.bleed_air_extraction
+        }
+    }
+
+    struct TestAircraft {
+        engine: LeapEngine,
+        pneumatic: TestPneumatic,
+        fuel_is_available: bool,
+        electrical_load: Power,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext, mode: EngineSimulationMode) -> Self {
+            Self {
+                engine: LeapEngine::with_mode(context, 1, mode),
+                pneumatic: TestPneumatic {
+                    starter_air_pressure: Pressure::default(),
+                    bleed_air_extraction: MassRate::default(),
+                },
+                fuel_is_available: false,
+                electrical_load: Power::default(),
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.engine.update(
+                context,
+                self.fuel_is_available,
+                &self.pneumatic,
+                self.electrical_load,
+            );
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.engine.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed(mode: EngineSimulationMode) -> SimulationTestBed<TestAircraft> {
+        let mut test_bed = SimulationTestBed::new(|context| TestAircraft::new(context, mode));
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(15.));
+        test_bed.set_true_airspeed(Velocity::new::<knot>(0.));
+
+        test_bed
+    }
+
+    fn run_for(test_bed: &mut SimulationTestBed<TestAircraft>, duration: Duration) {
+        let delta = Duration::from_millis(100);
+        test_bed
+            .run_iterations_with_delta((duration.as_millis() / delta.as_millis()) as u32, delta);
+    }
+
+    fn start(test_bed: &mut SimulationTestBed<TestAircraft>) {
+        test_bed.command(|a| {
+            a.pneumatic.starter_air_pressure = Pressure::new::<psi>(35.);
+            a.fuel_is_available = true;
+        });
+        run_for(test_bed, Duration::from_secs(60));
+        test_bed.command(|a| a.pneumatic.starter_air_pressure = Pressure::default());
+        run_for(test_bed, Duration::from_secs(20));
+    }
+
+    fn n2(test_bed: &SimulationTestBed<TestAircraft>) -> f64 {
+        test_bed.query(|a| a.engine.uncorrected_n2().get::<percent>())
+    }
+
+    #[test]
+    fn simulator_mode_reads_spool_speeds_from_the_simulator() {
+        let mut test_bed = test_bed(EngineSimulationMode::Simulator);
+        test_bed.write_by_name("ENGINE_N2:1", 60.);
+        test_bed.write_by_name("TURB ENG CORRECTED N1:1", 20.);
+        test_bed.run();
+
+        assert_about_eq!(n2(&test_bed), 60.);
+        assert_about_eq!(
+            test_bed.query(|a| a.engine.corrected_n1().get::<percent>()),
+            20.
+        );
+        assert!(test_bed.query(|a| a.engine.is_above_minimum_idle()));
+    }
+
+    #[test]
+    fn physical_engine_is_initially_stopped() {
+        let mut test_bed = test_bed(EngineSimulationMode::Physical);
+        test_bed.run();
+
+        assert_about_eq!(n2(&test_bed), 0.);
+        assert!(test_bed.query(|a| a.engine.oil_pressure_is_low()));
+        assert!(!test_bed.query(|a| a.engine.is_running()));
+    }
+
+    #[test]
+    fn physical_engine_ignores_simulator_spool_speeds() {
+        let mut test_bed = test_bed(EngineSimulationMode::Physical);
+        test_bed.write_by_name("ENGINE_N2:1", 60.);
+        test_bed.run();
+
+        assert_about_eq!(n2(&test_bed), 0.);
+    }
+
+    #[test]
+    fn starter_air_alone_motors_the_core_below_light_off() {
+        let mut test_bed = test_bed(EngineSimulationMode::Physical);
+        test_bed.command(|a| a.pneumatic.starter_air_pressure = Pressure::new::<psi>(35.));
+        run_for(&mut test_bed, Duration::from_secs(60));
+
+        assert!(n2(&test_bed) > LeapEngineConstants::LIGHT_OFF_CORE_SPEED_PERCENT);
+        assert!(n2(&test_bed) < 35.);
+        assert!(!test_bed.query(|a| a.engine.is_running()));
+    }
+
+    #[test]
+    fn engine_starts_with_starter_air_and_fuel() {
+        let mut test_bed = test_bed(EngineSimulationMode::Physical);
+        start(&mut test_bed);
+
+        assert!(test_bed.query(|a| a.engine.is_running()));
+        assert!(test_bed.query(|a| a.engine.is_above_minimum_idle()));
+        assert!(!test_bed.query(|a| a.engine.oil_pressure_is_low()));
+
+        let egt: ThermodynamicTemperature = test_bed.read_by_name("ENGINE_MODEL_EGT:1");
+        assert!(egt.get::<degree_celsius>() > 400.);
+
+        let n1: Ratio = test_bed.read_by_name("ENGINE_MODEL_N1:1");
+        assert!((n1.get::<percent>() - LeapEngineConstants::IDLE_N1_PERCENT).abs() < 2.);
+    }
+
+    #[test]
+    fn engine_does_not_start_without_starter_air() {
+        let mut test_bed = test_bed(EngineSimulationMode::Physical);
+        test_bed.command(|a| a.fuel_is_available = true);
+        run_for(&mut test_bed, Duration::from_secs(60));
+
+        assert_about_eq!(n2(&test_bed), 0.);
+        assert!(!test_bed.query(|a| a.engine.is_running()));
+    }
+
+    #[test]
+    fn engine_accelerates_towards_commanded_n1() {
+        let mut test_bed = test_bed(EngineSimulationMode::Physical);
+        start(&mut test_bed);
+        let idle_n2 = n2(&test_bed);
+
+        test_bed.write_by_name("AUTOTHRUST_N1_COMMANDED:1", 80.);
+        run_for(&mut test_bed, Duration::from_secs(20));
+
+        assert!(n2(&test_bed) > idle_n2 + 20.);
+        let n1: Ratio = test_bed.read_by_name("ENGINE_MODEL_N1:1");
+        assert!((n1.get::<percent>() - 80.).abs() < 2.);
+    }
+
+    #[test]
+    fn engine_flames_out_and_runs_down_when_fuel_is_lost() {
+        let mut test_bed = test_bed(EngineSimulationMode::Physical);
+        start(&mut test_bed);
+
+        test_bed.command(|a| a.fuel_is_available = false);
+        run_for(&mut test_bed, Duration::from_secs(60));
+
+        assert!(!test_bed.query(|a| a.engine.is_running()));
+        assert!(!test_bed.query(|a| a.engine.is_above_minimum_idle()));
+        assert!(test_bed.query(|a| a.engine.oil_pressure_is_low()));
+    }
+
+    #[test]
+    fn flamed_out_engine_windmills_in_flight() {
+        let mut test_bed = test_bed(EngineSimulationMode::Physical);
+        start(&mut test_bed);
+
+        test_bed.set_true_airspeed(Velocity::new::<knot>(250.));
+        test_bed.command(|a| a.fuel_is_available = false);
+        run_for(&mut test_bed, Duration::from_secs(120));
+
+        assert!(n2(&test_bed) > 10.);
+        assert!(!test_bed.query(|a| a.engine.is_running()));
+    }
+
+    #[test]
+    fn bleed_and_electrical_extraction_reduce_core_speed_and_raise_egt() {
+        let mut unloaded = test_bed(EngineSimulationMode::Physical);
+        start(&mut unloaded);
+        run_for(&mut unloaded, Duration::from_secs(30));
+
+        let mut loaded = test_bed(EngineSimulationMode::Physical);
+        start(&mut loaded);
+        loaded.command(|a| {
+            a.pneumatic.bleed_air_extraction = MassRate::new::<kilogram_per_second>(1.);
+            a.electrical_load = Power::new::<kilowatt>(90.);
+        });
+        run_for(&mut loaded, Duration::from_secs(30));
+
+        let unloaded_egt: ThermodynamicTemperature = unloaded.read_by_name("ENGINE_MODEL_EGT:1");
+        let loaded_egt: ThermodynamicTemperature = loaded.read_by_name("ENGINE_MODEL_EGT:1");
+        assert!(n2(&loaded) < n2(&unloaded));
+        assert!(loaded_egt > unloaded_egt);
+    }
+
+    #[test]
+    fn corrected_speeds_are_higher_on_a_cold_day() {
+        let mut test_bed = test_bed(EngineSimulationMode::Physical);
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(-30.));
+        start(&mut test_bed);
+
+        assert!(test_bed.query(|a| a.engine.corrected_n2() > a.engine.uncorrected_n2()));
+    }
+}
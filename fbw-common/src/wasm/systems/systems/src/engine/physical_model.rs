@@ -0,0 +1,228 @@
+use std::marker::PhantomData;
+
+use uom::si::{
+    f64::*, mass_rate::kilogram_per_second, power::kilowatt, pressure::psi, ratio::percent,
+    thermodynamic_temperature::degree_celsius, thermodynamic_temperature::kelvin, velocity::knot,
+};
+
+use crate::simulation::UpdateContext;
+
+/// Selects where an engine's spool speeds and temperatures come from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EngineSimulationMode {
+    /// Spool speeds and temperatures are read from the simulator.
+    Simulator,
+    /// Spool speeds and temperatures are computed by the engine itself,
+    /// taking starter air, fuel and bleed and electrical loads into account.
+    Physical,
+}
+
+pub trait PhysicalEngineConstants {
+    const MIN_IDLE_CORE_SPEED_PERCENT: f64;
+    const MAX_CORE_SPEED_PERCENT: f64;
+    const IDLE_N1_PERCENT: f64;
+    const MAX_N1_PERCENT: f64;
+    const LIGHT_OFF_CORE_SPEED_PERCENT: f64;
+    const STARTER_FREE_RUNNING_CORE_SPEED_PERCENT: f64;
+    const IDLE_EGT_DEGREE_CELSIUS: f64;
+    const MAX_EGT_DEGREE_CELSIUS: f64;
+}
+
+/// A simplified model of a turbofan's spools. The core spool (N2 on a two spool engine,
+/// N3 on a three spool engine) is driven by the air starter until light-off, after which
+/// combustion accelerates it towards a speed set by the commanded N1. The fan follows the core.
+/// Bleed air and electrical load extraction drag the core down and raise the EGT.
+pub(super) struct PhysicalEngineModel<C: PhysicalEngineConstants> {
+    is_burning: bool,
+    has_reached_idle: bool,
+    core_speed: Ratio,
+    n1: Ratio,
+    egt: ThermodynamicTemperature,
+    constants: PhantomData<C>,
+}
+impl<C: PhysicalEngineConstants> PhysicalEngineModel<C> {
+    const STARTER_ACCELERATION_PERCENT_PER_SECOND_PER_PSI: f64 = 0.15;
+    const SUB_IDLE_COMBUSTION_TIME_CONSTANT_SECONDS: f64 = 15.;
+    const ABOVE_IDLE_COMBUSTION_TIME_CONSTANT_SECONDS: f64 = 2.5;
+    const RUNDOWN_TIME_CONSTANT_SECONDS: f64 = 10.;
+    const WINDMILL_CORE_SPEED_PERCENT_PER_KNOT: f64 = 0.07;
+    const FLAME_OUT_CORE_SPEED_PERCENT: f64 = 10.;
+    const IDLE_CAPTURE_MARGIN_PERCENT: f64 = 1.;
+    const N1_TIME_CONSTANT_SECONDS: f64 = 1.5;
+    const EGT_TIME_CONSTANT_SECONDS: f64 = 4.;
+
+    const BLEED_DECELERATION_PERCENT_PER_SECOND_PER_KG_PER_SECOND: f64 = 0.4;
+    const ELECTRICAL_DECELERATION_PERCENT_PER_SECOND_PER_KW: f64 = 0.004;
+    const BLEED_EGT_RISE_DEGREE_CELSIUS_PER_KG_PER_SECOND: f64 = 20.;
+    const ELECTRICAL_EGT_RISE_DEGREE_CELSIUS_PER_KW: f64 = 0.2;
+
+    const STANDARD_DAY_TEMPERATURE_KELVIN: f64 = 288.15;
+
+    pub(super) fn new() -> Self {
+        Self {
+            is_burning: false,
+            has_reached_idle: false,
+            core_speed: Ratio::default(),
+            n1: Ratio::default(),
+            egt: ThermodynamicTemperature::new::<degree_celsius>(15.),
+            constants: PhantomData,
+        }
+    }
+
+    pub(super) fn update(
+        &mut self,
+        context: &UpdateContext,
+        commanded_n1: Ratio,
+        fuel_is_available: bool,
+        starter_air_pressure: Pressure,
+        bleed_air_extraction: MassRate,
+        electrical_load: Power,
+    ) {
+        let delta = context.delta_as_secs_f64();
+        let core_speed = self.core_speed.get::<percent>();
+
+        if !fuel_is_available || core_speed < Self::FLAME_OUT_CORE_SPEED_PERCENT {
+            self.is_burning = false;
+        } else if core_speed >= C::LIGHT_OFF_CORE_SPEED_PERCENT {
+            self.is_burning = true;
+        }
+        // Once idle is reached the FADEC governs the engine, holding it at or above idle
+        // with the faster above idle response.
+        self.has_reached_idle = self.is_burning
+            && (self.has_reached_idle
+                || core_speed
+                    >= C::MIN_IDLE_CORE_SPEED_PERCENT - Self::IDLE_CAPTURE_MARGIN_PERCENT);
+
+        let load_deceleration = bleed_air_extraction.get::<kilogram_per_second>().max(0.)
+            * Self::BLEED_DECELERATION_PERCENT_PER_SECOND_PER_KG_PER_SECOND
+            + electrical_load.get::<kilowatt>().max(0.)
+                * Self::ELECTRICAL_DECELERATION_PERCENT_PER_SECOND_PER_KW;
+
+        let spool_acceleration = if self.is_burning {
+            let target = self.core_speed_target(commanded_n1);
+            let time_constant = if self.has_reached_idle {
+                Self::ABOVE_IDLE_COMBUSTION_TIME_CONSTANT_SECONDS
+            } else {
+                Self::SUB_IDLE_COMBUSTION_TIME_CONSTANT_SECONDS
+            };
+
+            (target - core_speed) / time_constant
+        } else {
+            let windmill_speed = context.true_airspeed().get::<knot>().max(0.)
+                * Self::WINDMILL_CORE_SPEED_PERCENT_PER_KNOT;
+
+            (windmill_speed - core_speed) / Self::RUNDOWN_TIME_CONSTANT_SECONDS
+        };
+
+        let starter_acceleration = starter_air_pressure.get::<psi>().max(0.)
+            * Self::STARTER_ACCELERATION_PERCENT_PER_SECOND_PER_PSI
+            * (1. - core_speed / C::STARTER_FREE_RUNNING_CORE_SPEED_PERCENT).max(0.);
+
+        self.core_speed = Ratio::new::<percent>(
+            (core_speed + (spool_acceleration + starter_acceleration - load_deceleration) * delta)
+                .clamp(0., C::MAX_CORE_SPEED_PERCENT),
+        );
+
+        self.n1 += (Ratio::new::<percent>(self.n1_for_core_speed()) - self.n1)
+            * Self::first_order_factor(delta, Self::N1_TIME_CONSTANT_SECONDS);
+
+        let egt_target = self.egt_target(context, bleed_air_extraction, electrical_load);
+        self.egt = ThermodynamicTemperature::new::<degree_celsius>(
+            self.egt.get::<degree_celsius>()
+                + (egt_target - self.egt.get::<degree_celsius>())
+                    * Self::first_order_factor(delta, Self::EGT_TIME_CONSTANT_SECONDS),
+        );
+    }
+
+    fn first_order_factor(delta: f64, time_constant: f64) -> f64 {
+        1. - (-delta / time_constant).exp()
+    }
+
+    fn thrust_demand(commanded_n1: Ratio) -> f64 {
+        ((commanded_n1.get::<percent>() - C::IDLE_N1_PERCENT)
+            / (C::MAX_N1_PERCENT - C::IDLE_N1_PERCENT))
+            .clamp(0., 1.)
+    }
+
+    fn core_speed_target(&self, commanded_n1: Ratio) -> f64 {
+        C::MIN_IDLE_CORE_SPEED_PERCENT
+            + Self::thrust_demand(commanded_n1)
+                * (C::MAX_CORE_SPEED_PERCENT - C::MIN_IDLE_CORE_SPEED_PERCENT)
+    }
+
+    fn core_speed_above_idle_ratio(&self) -> f64 {
+        ((self.core_speed.get::<percent>() - C::MIN_IDLE_CORE_SPEED_PERCENT)
+            / (C::MAX_CORE_SPEED_PERCENT - C::MIN_IDLE_CORE_SPEED_PERCENT))
+            .max(0.)
+    }
+
+    fn n1_for_core_speed(&self) -> f64 {
+        let core_speed = self.core_speed.get::<percent>();
+        if core_speed < C::MIN_IDLE_CORE_SPEED_PERCENT {
+            C::IDLE_N1_PERCENT * (core_speed / C::MIN_IDLE_CORE_SPEED_PERCENT).powi(2)
+        } else {
+            C::IDLE_N1_PERCENT
+                + self.core_speed_above_idle_ratio() * (C::MAX_N1_PERCENT - C::IDLE_N1_PERCENT)
+        }
+    }
+
+    fn egt_target(
+        &self,
+        context: &UpdateContext,
+        bleed_air_extraction: MassRate,
+        electrical_load: Power,
+    ) -> f64 {
+        let ambient = context.ambient_temperature().get::<degree_celsius>();
+        if !self.is_burning {
+            return ambient;
+        }
+
+        let core_speed = self.core_speed.get::<percent>();
+        let combustion_egt = if !self.has_reached_idle {
+            ambient
+                + (C::IDLE_EGT_DEGREE_CELSIUS - ambient) * core_speed
+                    / C::MIN_IDLE_CORE_SPEED_PERCENT
+        } else {
+            C::IDLE_EGT_DEGREE_CELSIUS
+                + self.core_speed_above_idle_ratio()
+                    * (C::MAX_EGT_DEGREE_CELSIUS - C::IDLE_EGT_DEGREE_CELSIUS)
+        };
+
+        combustion_egt
+            + bleed_air_extraction.get::<kilogram_per_second>().max(0.)
+                * Self::BLEED_EGT_RISE_DEGREE_CELSIUS_PER_KG_PER_SECOND
+            + electrical_load.get::<kilowatt>().max(0.)
+                * Self::ELECTRICAL_EGT_RISE_DEGREE_CELSIUS_PER_KW
+    }
+
+    fn corrected(&self, context: &UpdateContext, speed: Ratio) -> Ratio {
+        let theta =
+            context.ambient_temperature().get::<kelvin>() / Self::STANDARD_DAY_TEMPERATURE_KELVIN;
+
+        speed / theta.max(0.1).sqrt()
+    }
+
+    pub(super) fn is_burning(&self) -> bool {
+        self.is_burning
+    }
+
+    pub(super) fn core_speed(&self) -> Ratio {
+        self.core_speed
+    }
+
+    pub(super) fn corrected_core_speed(&self, context: &UpdateContext) -> Ratio {
+        self.corrected(context, self.core_speed)
+    }
+
+    pub(super) fn n1(&self) -> Ratio {
+        self.n1
+    }
+
+    pub(super) fn corrected_n1(&self, context: &UpdateContext) -> Ratio {
+        self.corrected(context, self.n1)
+    }
+
+    pub(super) fn egt(&self) -> ThermodynamicTemperature {
+        self.egt
+    }
+}
@@ -1,14 +1,32 @@
 use uom::si::{angular_velocity::revolution_per_minute, f64::*, pressure::psi, ratio::percent};
 
 use crate::{
-    shared::{EngineCorrectedN1, EngineCorrectedN2, EngineUncorrectedN2},
-    simulation::{Read, SimulationElement, SimulatorReader, UpdateContext},
+    shared::{EngineCorrectedN1, EngineCorrectedN2, EnginePneumaticLoads, EngineUncorrectedN2},
+    simulation::{Read, SimulationElement, SimulatorReader, SimulatorWriter, UpdateContext, Write},
 };
 
-use super::Engine;
+use super::{
+    physical_model::{EngineSimulationMode, PhysicalEngineConstants, PhysicalEngineModel},
+    Engine,
+};
 use crate::simulation::{InitContext, VariableIdentifier};
 
+struct TrentEngineConstants;
+impl PhysicalEngineConstants for TrentEngineConstants {
+    const MIN_IDLE_CORE_SPEED_PERCENT: f64 = 62.;
+    const MAX_CORE_SPEED_PERCENT: f64 = 100.;
+    const IDLE_N1_PERCENT: f64 = 19.;
+    const MAX_N1_PERCENT: f64 = 100.;
+    const LIGHT_OFF_CORE_SPEED_PERCENT: f64 = 18.;
+    const STARTER_FREE_RUNNING_CORE_SPEED_PERCENT: f64 = 60.;
+    const IDLE_EGT_DEGREE_CELSIUS: f64 = 460.;
+    const MAX_EGT_DEGREE_CELSIUS: f64 = 850.;
+}
+
 pub struct TrentEngine {
+    number: usize,
+    mode: EngineSimulationMode,
+
     thrust_id: VariableIdentifier,
 
     corrected_n1_id: VariableIdentifier,
@@ -26,6 +44,14 @@ pub struct TrentEngine {
     oil_pressure: Pressure,
 
     net_thrust: Mass,
+
+    commanded_n1_id: VariableIdentifier,
+    commanded_n1: Ratio,
+    model_n1_id: VariableIdentifier,
+    model_n2_id: VariableIdentifier,
+    model_n3_id: VariableIdentifier,
+    model_egt_id: VariableIdentifier,
+    physical_model: PhysicalEngineModel<TrentEngineConstants>,
 }
 impl TrentEngine {
     // 100% N1 @ 2900 RPM
@@ -42,8 +68,22 @@ impl TrentEngine {
 
     const LOW_OIL_PRESSURE_THRESHOLD_PSI: f64 = 18.;
 
+    // Position of the intermediate pressure spool speed between the fan and the core
+    const INTERMEDIATE_SPOOL_SPEED_RATIO: f64 = 0.85;
+
     pub fn new(context: &mut InitContext, number: usize) -> TrentEngine {
+        Self::with_mode(context, number, EngineSimulationMode::Simulator)
+    }
+
+    pub fn with_mode(
+        context: &mut InitContext,
+        number: usize,
+        mode: EngineSimulationMode,
+    ) -> TrentEngine {
         TrentEngine {
+            number,
+            mode,
+
             thrust_id: context.get_identifier(format!("TURB ENG JET THRUST:{}", number)),
 
             corrected_n1_id: context.get_identifier(format!("TURB ENG CORRECTED N1:{}", number)),
@@ -60,10 +100,61 @@ impl TrentEngine {
             oil_pressure: Pressure::new::<psi>(0.),
 
             net_thrust: Mass::default(),
+
+            commanded_n1_id: context.get_identifier(format!("AUTOTHRUST_N1_COMMANDED:{}", number)),
+            commanded_n1: Ratio::default(),
+            model_n1_id: context.get_identifier(format!("ENGINE_MODEL_N1:{}", number)),
+            model_n2_id: context.get_identifier(format!("ENGINE_MODEL_N2:{}", number)),
+            model_n3_id: context.get_identifier(format!("ENGINE_MODEL_N3:{}", number)),
+            model_egt_id: context.get_identifier(format!("ENGINE_MODEL_EGT:{}", number)),
+            physical_model: PhysicalEngineModel::new(),
         }
     }
 
-    pub fn update(&mut self, _: &UpdateContext) {}
+    /// Only has an effect when the engine is in [`EngineSimulationMode::Physical`],
+    /// in other modes the engine parameters are read from the simulator.
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        fuel_is_available: bool,
+        pneumatic: &impl EnginePneumaticLoads,
+        electrical_load: Power,
+    ) {
+        if self.mode != EngineSimulationMode::Physical {
+            return;
+        }
+
+        self.physical_model.update(
+            context,
+            self.commanded_n1,
+            fuel_is_available,
+            pneumatic.starter_air_pressure(self.number),
+            pneumatic.bleed_air_extraction(self.number),
+            electrical_load,
+        );
+
+        self.corrected_n1 = self.physical_model.corrected_n1(context);
+        self.uncorrected_n3 = self.physical_model.core_speed();
+        self.uncorrected_n2 = self
+            .intermediate_spool_speed(self.physical_model.n1(), self.physical_model.core_speed());
+        self.corrected_n2 = self.intermediate_spool_speed(
+            self.corrected_n1,
+            self.physical_model.corrected_core_speed(context),
+        );
+        self.update_parameters();
+    }
+
+    pub fn mode(&self) -> EngineSimulationMode {
+        self.mode
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.physical_model.is_burning()
+    }
+
+    fn intermediate_spool_speed(&self, n1: Ratio, n3: Ratio) -> Ratio {
+        n1 + (n3 - n1) * Self::INTERMEDIATE_SPOOL_SPEED_RATIO
+    }
 
     fn update_parameters(&mut self) {
         self.n3_speed = AngularVelocity::new::<revolution_per_minute>(
@@ -77,13 +168,33 @@ impl TrentEngine {
 }
 impl SimulationElement for TrentEngine {
     fn read(&mut self, reader: &mut SimulatorReader) {
-        self.corrected_n1 = reader.read(&self.corrected_n1_id);
-        self.corrected_n2 = reader.read(&self.corrected_n2_id);
-        self.uncorrected_n2 = reader.read(&self.uncorrected_n2_id);
-        self.uncorrected_n3 = reader.read(&self.uncorrected_n3_id);
         self.net_thrust = reader.read(&self.thrust_id);
 
-        self.update_parameters();
+        match self.mode {
+            EngineSimulationMode::Simulator => {
+                self.corrected_n1 = reader.read(&self.corrected_n1_id);
+                self.corrected_n2 = reader.read(&self.corrected_n2_id);
+                self.uncorrected_n2 = reader.read(&self.uncorrected_n2_id);
+                self.uncorrected_n3 = reader.read(&self.uncorrected_n3_id);
+
+                self.update_parameters();
+            }
+            EngineSimulationMode::Physical => {
+                self.commanded_n1 = reader.read(&self.commanded_n1_id);
+            }
+        }
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        if self.mode == EngineSimulationMode::Physical {
+            let n1 = self.physical_model.n1();
+            let n3 = self.physical_model.core_speed();
+
+            writer.write(&self.model_n1_id, n1);
+            writer.write(&self.model_n2_id, self.intermediate_spool_speed(n1, n3));
+            writer.write(&self.model_n3_id, n3);
+            writer.write(&self.model_egt_id, self.physical_model.egt());
+        }
     }
 }
 impl EngineCorrectedN1 for TrentEngine {
@@ -123,3 +234,112 @@ impl Engine for TrentEngine {
         self.n3_speed
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::{
+        test::{ReadByName, SimulationTestBed, TestBed},
+        Aircraft, SimulationElementVisitor,
+    };
+    use std::time::Duration;
+    use uom::si::velocity::knot;
+
+    struct TestPneumatic {
+        starter_air_pressure: Pressure,
+    }
+    impl EnginePneumaticLoads for TestPneumatic {
+        fn starter_air_pressure(&self, _: usize) -> Pressure {
+            self.starter_air_pressure
+        }
+
+        fn bleed_air_extraction(&self, _: usize) -> MassRate {
+            MassRate::default()
+        }
+    }
+
+    struct TestAircraft {
+        engine: TrentEngine,
+        pneumatic: TestPneumatic,
+        fuel_is_available: bool,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                engine: TrentEngine::with_mode(context, 1, EngineSimulationMode::Physical),
+                pneumatic: TestPneumatic {
+                    starter_air_pressure: Pressure::default(),
+                },
+                fuel_is_available: false,
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.engine.update(
+                context,
+                self.fuel_is_available,
+                &self.pneumatic,
+                Power::default(),
+            );
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.engine.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed() -> SimulationTestBed<TestAircraft> {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.set_true_airspeed(Velocity::new::<knot>(0.));
+
+        test_bed
+    }
+
+    fn start(test_bed: &mut SimulationTestBed<TestAircraft>) {
+        test_bed.command(|a| {
+            a.pneumatic.starter_air_pressure = Pressure::new::<psi>(35.);
+            a.fuel_is_available = true;
+        });
+        test_bed.run_iterations_with_delta(600, Duration::from_millis(100));
+        test_bed.command(|a| a.pneumatic.starter_air_pressure = Pressure::default());
+        test_bed.run_iterations_with_delta(200, Duration::from_millis(100));
+    }
+
+    #[test]
+    fn engine_starts_with_starter_air_and_fuel() {
+        let mut test_bed = test_bed();
+        start(&mut test_bed);
+
+        assert!(test_bed.query(|a| a.engine.is_running()));
+        assert!(test_bed.query(|a| a.engine.is_above_minimum_idle()));
+        assert!(!test_bed.query(|a| a.engine.oil_pressure_is_low()));
+    }
+
+    #[test]
+    fn intermediate_spool_runs_between_fan_and_core() {
+        let mut test_bed = test_bed();
+        start(&mut test_bed);
+
+        let n1: Ratio = test_bed.read_by_name("ENGINE_MODEL_N1:1");
+        let n2: Ratio = test_bed.read_by_name("ENGINE_MODEL_N2:1");
+        let n3: Ratio = test_bed.read_by_name("ENGINE_MODEL_N3:1");
+        assert!(n1 < n2);
+        assert!(n2 < n3);
+    }
+
+    #[test]
+    fn engine_flames_out_when_fuel_is_lost() {
+        let mut test_bed = test_bed();
+        start(&mut test_bed);
+
+        test_bed.command(|a| a.fuel_is_available = false);
+        test_bed.run_iterations_with_delta(600, Duration::from_millis(100));
+
+        assert!(!test_bed.query(|a| a.engine.is_running()));
+        assert!(!test_bed.query(|a| a.engine.is_above_minimum_idle()));
+    }
+}
@@ -0,0 +1,911 @@
+use crate::{
+    shared::{low_pass_filter::LowPassFilter, DelayedTrueLogicGate},
+    simulation::UpdateContext,
+};
+use std::time::Duration;
+use uom::si::{
+    angle::degree,
+    f64::{Angle, Length, Velocity},
+    length::foot,
+    velocity::{foot_per_minute, knot},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mode1Alert {
+    SinkRate,
+    PullUp,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mode2Alert {
+    Terrain,
+    PullUp,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mode3Alert {
+    DontSink,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mode4Alert {
+    TooLowGear,
+    TooLowFlaps,
+    TooLowTerrain,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mode5Alert {
+    Glideslope,
+    HardGlideslope,
+}
+
+/// The sensor data and crew selections the alert modes are computed from.
+pub struct AlertModesInputs {
+    pub radio_altitude: Option<Length>,
+    pub altitude: Option<Length>,
+    pub vertical_speed: Option<Velocity>,
+    pub computed_airspeed: Option<Velocity>,
+    pub gear_is_down: bool,
+    pub flaps_in_landing_configuration: bool,
+    /// The glideslope deviation, positive when the aircraft is below the beam.
+    pub glideslope_deviation: Option<Angle>,
+    pub glideslope_mode_is_inhibited: bool,
+    pub is_inhibited: bool,
+}
+
+/// The basic GPWS envelope modes:
+/// - Mode 1: excessive descent rate
+/// - Mode 2: excessive terrain closure rate
+/// - Mode 3: altitude loss after takeoff
+/// - Mode 4: unsafe terrain clearance (too low gear, flaps or terrain)
+/// - Mode 5: excessive deviation below the glideslope
+pub struct AlertModes {
+    is_airborne: bool,
+    airborne_confirmation: DelayedTrueLogicGate,
+    is_in_approach: bool,
+
+    previous_radio_altitude: Option<Length>,
+    radio_altitude_rate: Option<Velocity>,
+    radio_altitude_rate_filter: LowPassFilter<Velocity>,
+
+    mode_2_boundary_confirmation: DelayedTrueLogicGate,
+    mode_2_pull_up_delay: DelayedTrueLogicGate,
+    mode_2_exit_radio_altitude: Option<Length>,
+    mode_3_maximum_altitude: Option<Length>,
+    mode_4_terrain_clearance_filter: Length,
+
+    mode_1: Option<Mode1Alert>,
+    mode_2: Option<Mode2Alert>,
+    mode_3: Option<Mode3Alert>,
+    mode_4: Option<Mode4Alert>,
+    mode_5: Option<Mode5Alert>,
+}
+impl AlertModes {
+    const MINIMUM_RADIO_ALTITUDE_FEET: f64 = 10.;
+    const MAXIMUM_RADIO_ALTITUDE_FEET: f64 = 2450.;
+    const AIR_GROUND_RADIO_ALTITUDE_FEET: f64 = 25.;
+    const AIRBORNE_MINIMUM_AIRSPEED_KNOTS: f64 = 90.;
+    const AIRBORNE_CONFIRMATION_DURATION: Duration = Duration::from_secs(10);
+    const RADIO_ALTITUDE_RATE_FILTER_TIME_CONSTANT: Duration = Duration::from_millis(500);
+    const MODE_2_BOUNDARY_CONFIRMATION_DURATION: Duration = Duration::from_millis(200);
+    // The "TERRAIN TERRAIN" callout precedes the "PULL UP" warning.
+    const MODE_2_PULL_UP_DELAY: Duration = Duration::from_millis(1600);
+    const MODE_2_ALTITUDE_GAIN_TO_CLEAR_FEET: f64 = 300.;
+    const MODE_4B_FLOOR_FEET: f64 = 245.;
+    // One dot of glideslope deviation is approximately 0.4 degrees.
+    const GLIDESLOPE_DEGREES_PER_DOT: f64 = 0.4;
+
+    pub fn new() -> Self {
+        Self {
+            is_airborne: false,
+            airborne_confirmation: DelayedTrueLogicGate::new(Self::AIRBORNE_CONFIRMATION_DURATION),
+            is_in_approach: false,
+
+            previous_radio_altitude: None,
+            radio_altitude_rate: None,
+            radio_altitude_rate_filter: LowPassFilter::new(
+                Self::RADIO_ALTITUDE_RATE_FILTER_TIME_CONSTANT,
+            ),
+
+            mode_2_boundary_confirmation: DelayedTrueLogicGate::new(
+                Self::MODE_2_BOUNDARY_CONFIRMATION_DURATION,
+            ),
+            mode_2_pull_up_delay: DelayedTrueLogicGate::new(Self::MODE_2_PULL_UP_DELAY),
+            mode_2_exit_radio_altitude: None,
+            mode_3_maximum_altitude: None,
+            mode_4_terrain_clearance_filter: Length::default(),
+
+            mode_1: None,
+            mode_2: None,
+            mode_3: None,
+            mode_4: None,
+            mode_5: None,
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext, inputs: &AlertModesInputs) {
+        self.update_radio_altitude_rate(context, inputs.radio_altitude);
+        self.update_air_ground_state(context, inputs);
+        self.update_mode_4_terrain_clearance_filter(inputs.radio_altitude);
+        self.update_approach_takeoff_state(inputs);
+
+        match inputs.radio_altitude {
+            Some(radio_altitude)
+                if !inputs.is_inhibited
+                    && (Self::MINIMUM_RADIO_ALTITUDE_FEET..=Self::MAXIMUM_RADIO_ALTITUDE_FEET)
+                        .contains(&radio_altitude.get::<foot>()) =>
+            {
+                let altitude_rate = self.altitude_rate(inputs);
+
+                self.mode_1 = Self::mode_1(radio_altitude, altitude_rate);
+                self.update_mode_2(context, radio_altitude, inputs);
+                self.update_mode_3(radio_altitude, altitude_rate, inputs);
+                self.mode_4 = self.mode_4(radio_altitude, inputs);
+                self.mode_5 = Self::mode_5(radio_altitude, inputs);
+            }
+            _ => self.clear_alerts(),
+        }
+    }
+
+    /// Resets the alerts and the internal states, as happens when the computer loses power.
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    fn clear_alerts(&mut self) {
+        self.mode_1 = None;
+        self.mode_2 = None;
+        self.mode_3 = None;
+        self.mode_4 = None;
+        self.mode_5 = None;
+
+        self.mode_2_exit_radio_altitude = None;
+        self.mode_3_maximum_altitude = None;
+    }
+
+    fn update_radio_altitude_rate(
+        &mut self,
+        context: &UpdateContext,
+        radio_altitude: Option<Length>,
+    ) {
+        match (self.previous_radio_altitude, radio_altitude) {
+            (Some(previous), Some(current)) if !context.delta().is_zero() => {
+                let rate = Velocity::new::<foot_per_minute>(
+                    (current - previous).get::<foot>() / (context.delta_as_secs_f64() / 60.),
+                );
+
+                self.radio_altitude_rate = Some(if self.radio_altitude_rate.is_none() {
+                    self.radio_altitude_rate_filter.reset(rate);
+                    rate
+                } else {
+                    self.radio_altitude_rate_filter
+                        .update(context.delta(), rate)
+                });
+            }
+            (_, None) => self.radio_altitude_rate = None,
+            _ => {}
+        }
+
+        self.previous_radio_altitude = radio_altitude;
+    }
+
+    fn update_air_ground_state(&mut self, context: &UpdateContext, inputs: &AlertModesInputs) {
+        let (Some(radio_altitude), Some(computed_airspeed)) =
+            (inputs.radio_altitude, inputs.computed_airspeed)
+        else {
+            // Remain in the current state.
+            return;
+        };
+
+        self.airborne_confirmation.update(
+            context,
+            computed_airspeed.get::<knot>() > Self::AIRBORNE_MINIMUM_AIRSPEED_KNOTS
+                && radio_altitude.get::<foot>() > Self::AIR_GROUND_RADIO_ALTITUDE_FEET,
+        );
+
+        if self.is_airborne {
+            self.is_airborne = radio_altitude.get::<foot>() >= Self::AIR_GROUND_RADIO_ALTITUDE_FEET;
+        } else {
+            self.is_airborne = self.airborne_confirmation.output();
+        }
+    }
+
+    fn update_mode_4_terrain_clearance_filter(&mut self, radio_altitude: Option<Length>) {
+        if !self.is_airborne || self.is_in_approach {
+            self.mode_4_terrain_clearance_filter = Length::default();
+        } else if let Some(radio_altitude) = radio_altitude {
+            self.mode_4_terrain_clearance_filter = self
+                .mode_4_terrain_clearance_filter
+                .max(radio_altitude * 0.75);
+        }
+    }
+
+    fn update_approach_takeoff_state(&mut self, inputs: &AlertModesInputs) {
+        let Some(computed_airspeed) = inputs.computed_airspeed else {
+            return;
+        };
+
+        if self.is_in_approach {
+            // Passing below the mode 4B floor without an alert means the aircraft landed or
+            // is about to, thus a subsequent climb is a takeoff.
+            self.is_in_approach = !(inputs.radio_altitude.is_some_and(|radio_altitude| {
+                radio_altitude.get::<foot>() < Self::MODE_4B_FLOOR_FEET
+            }) && inputs.gear_is_down
+                && inputs.flaps_in_landing_configuration);
+        } else {
+            self.is_in_approach = self.mode_4_terrain_clearance_filter.get::<foot>()
+                > Self::mode_4a_upper_boundary(
+                    computed_airspeed,
+                    inputs.flaps_in_landing_configuration,
+                );
+        }
+    }
+
+    /// The inertial vertical speed is preferred over the radio altitude rate.
+    fn altitude_rate(&self, inputs: &AlertModesInputs) -> Option<Velocity> {
+        inputs.vertical_speed.or(self.radio_altitude_rate)
+    }
+
+    fn mode_1(radio_altitude: Length, altitude_rate: Option<Velocity>) -> Option<Mode1Alert> {
+        let sink_rate = -altitude_rate?.get::<foot_per_minute>();
+        if sink_rate <= 1000. {
+            return None;
+        }
+
+        let radio_altitude = radio_altitude.get::<foot>();
+        let pull_up_boundary = if sink_rate < 1700. {
+            1.3 * sink_rate - 1940.
+        } else {
+            0.4 * sink_rate - 410.
+        };
+        let sink_rate_boundary = 0.61 * sink_rate - 600.;
+
+        if radio_altitude <= pull_up_boundary {
+            Some(Mode1Alert::PullUp)
+        } else if radio_altitude <= sink_rate_boundary {
+            Some(Mode1Alert::SinkRate)
+        } else {
+            None
+        }
+    }
+
+    fn update_mode_2(
+        &mut self,
+        context: &UpdateContext,
+        radio_altitude: Length,
+        inputs: &AlertModesInputs,
+    ) {
+        let is_in_boundary = match (inputs.computed_airspeed, self.radio_altitude_rate) {
+            (Some(computed_airspeed), Some(radio_altitude_rate)) => Self::is_in_mode_2_boundary(
+                radio_altitude,
+                -radio_altitude_rate,
+                computed_airspeed,
+                inputs.flaps_in_landing_configuration,
+            ),
+            _ => false,
+        };
+        self.mode_2_boundary_confirmation
+            .update(context, is_in_boundary);
+
+        // Mode 2A warns with a pull up, mode 2B with the flaps in landing configuration or the
+        // gear down doesn't.
+        let is_mode_2a = !inputs.flaps_in_landing_configuration && !inputs.gear_is_down;
+        self.mode_2_pull_up_delay.update(
+            context,
+            self.mode_2_boundary_confirmation.output() && is_mode_2a,
+        );
+
+        if self.mode_2_boundary_confirmation.output() {
+            self.mode_2_exit_radio_altitude = None;
+            self.mode_2 = Some(if self.mode_2_pull_up_delay.output() {
+                Mode2Alert::PullUp
+            } else {
+                Mode2Alert::Terrain
+            });
+        } else if self.mode_2.is_some() && is_mode_2a {
+            // After leaving the boundary, the terrain alert continues until some altitude
+            // has been gained.
+            let exit_radio_altitude = *self
+                .mode_2_exit_radio_altitude
+                .get_or_insert(radio_altitude);
+            if (radio_altitude - exit_radio_altitude).get::<foot>()
+                < Self::MODE_2_ALTITUDE_GAIN_TO_CLEAR_FEET
+            {
+                self.mode_2 = Some(Mode2Alert::Terrain);
+            } else {
+                self.mode_2 = None;
+                self.mode_2_exit_radio_altitude = None;
+            }
+        } else {
+            self.mode_2 = None;
+            self.mode_2_exit_radio_altitude = None;
+        }
+    }
+
+    fn is_in_mode_2_boundary(
+        radio_altitude: Length,
+        closure_rate: Velocity,
+        computed_airspeed: Velocity,
+        flaps_in_landing_configuration: bool,
+    ) -> bool {
+        let radio_altitude = radio_altitude.get::<foot>();
+        let closure_rate = closure_rate.get::<foot_per_minute>();
+        if closure_rate <= 2000. {
+            return false;
+        }
+
+        let closure_rate_boundary = if closure_rate < 3500. {
+            0.7937 * closure_rate - 1557.5
+        } else {
+            0.19166 * closure_rate + 610.
+        };
+
+        if flaps_in_landing_configuration {
+            closure_rate < 10000. && radio_altitude < 775. && radio_altitude < closure_rate_boundary
+        } else {
+            let airspeed_boundary =
+                (8.8888 * computed_airspeed.get::<knot>() - 305.555).clamp(1650., 2450.);
+
+            radio_altitude < closure_rate_boundary && radio_altitude < airspeed_boundary
+        }
+    }
+
+    fn update_mode_3(
+        &mut self,
+        radio_altitude: Length,
+        altitude_rate: Option<Velocity>,
+        inputs: &AlertModesInputs,
+    ) {
+        let altitude = match inputs.altitude {
+            Some(altitude)
+                if !(self.is_in_approach
+                    || inputs.gear_is_down && inputs.flaps_in_landing_configuration)
+                    && radio_altitude.get::<foot>() <= 1500. =>
+            {
+                altitude
+            }
+            _ => {
+                self.mode_3_maximum_altitude = None;
+                self.mode_3 = None;
+                return;
+            }
+        };
+
+        let maximum_altitude = self.mode_3_maximum_altitude.get_or_insert(altitude);
+        if altitude >= *maximum_altitude {
+            *maximum_altitude = altitude;
+            self.mode_3 = None;
+            return;
+        }
+
+        let is_climbing =
+            altitude_rate.is_some_and(|rate| rate > Velocity::new::<foot_per_minute>(0.));
+        let maximum_altitude_loss = 0.09 * radio_altitude.get::<foot>() + 7.1;
+
+        self.mode_3 = if !is_climbing
+            && (*maximum_altitude - altitude).get::<foot>() > maximum_altitude_loss
+        {
+            Some(Mode3Alert::DontSink)
+        } else {
+            None
+        };
+    }
+
+    fn mode_4(&self, radio_altitude: Length, inputs: &AlertModesInputs) -> Option<Mode4Alert> {
+        let computed_airspeed = inputs.computed_airspeed?;
+        let radio_altitude_feet = radio_altitude.get::<foot>();
+        if !self.is_airborne || !(30. ..=1000.).contains(&radio_altitude_feet) {
+            return None;
+        }
+
+        let airspeed_knots = computed_airspeed.get::<knot>();
+        let flaps_in_landing_configuration = inputs.flaps_in_landing_configuration;
+
+        if self.is_in_approach && !inputs.gear_is_down {
+            // Mode 4A
+            if airspeed_knots < 190. && radio_altitude_feet < 500. {
+                Some(Mode4Alert::TooLowGear)
+            } else if airspeed_knots >= 190.
+                && radio_altitude_feet
+                    < Self::mode_4a_upper_boundary(
+                        computed_airspeed,
+                        flaps_in_landing_configuration,
+                    )
+            {
+                Some(if flaps_in_landing_configuration {
+                    Mode4Alert::TooLowGear
+                } else {
+                    Mode4Alert::TooLowTerrain
+                })
+            } else {
+                None
+            }
+        } else if self.is_in_approach && !flaps_in_landing_configuration {
+            // Mode 4B
+            if airspeed_knots < 159. && radio_altitude_feet < Self::MODE_4B_FLOOR_FEET {
+                Some(Mode4Alert::TooLowFlaps)
+            } else if airspeed_knots >= 159.
+                && radio_altitude_feet < Self::mode_4b_upper_boundary(computed_airspeed)
+            {
+                Some(Mode4Alert::TooLowTerrain)
+            } else {
+                None
+            }
+        } else if !self.is_in_approach && (!flaps_in_landing_configuration || !inputs.gear_is_down)
+        {
+            // Mode 4C
+            let boundary = Self::mode_4c_upper_boundary(computed_airspeed)
+                .min(self.mode_4_terrain_clearance_filter.get::<foot>());
+
+            if radio_altitude_feet < boundary {
+                Some(Mode4Alert::TooLowTerrain)
+            } else {
+                None
+            }
+        } else {
+            None
+        }
+    }
+
+    fn mode_4a_upper_boundary(
+        computed_airspeed: Velocity,
+        flaps_in_landing_configuration: bool,
+    ) -> f64 {
+        let maximum = if flaps_in_landing_configuration {
+            500.
+        } else {
+            1000.
+        };
+
+        (8.333 * computed_airspeed.get::<knot>() - 1083.33).clamp(500., maximum)
+    }
+
+    /// Mode 4B only applies with the flaps not in landing configuration.
+    fn mode_4b_upper_boundary(computed_airspeed: Velocity) -> f64 {
+        (8.333 * computed_airspeed.get::<knot>() - 1083.33).clamp(Self::MODE_4B_FLOOR_FEET, 1000.)
+    }
+
+    fn mode_4c_upper_boundary(computed_airspeed: Velocity) -> f64 {
+        (8.333 * computed_airspeed.get::<knot>() - 1083.33).clamp(500., 1000.)
+    }
+
+    fn mode_5(radio_altitude: Length, inputs: &AlertModesInputs) -> Option<Mode5Alert> {
+        let radio_altitude = radio_altitude.get::<foot>();
+        if inputs.glideslope_mode_is_inhibited
+            || !inputs.gear_is_down
+            || !(30. ..=1000.).contains(&radio_altitude)
+        {
+            return None;
+        }
+
+        let dots = inputs.glideslope_deviation?.get::<degree>() / Self::GLIDESLOPE_DEGREES_PER_DOT;
+        let glideslope_floor = if dots < 2.9 { -75. * dots + 247.5 } else { 30. };
+        let hard_glideslope_floor = if dots < 3.8 {
+            -66.66 * dots + 283.33
+        } else {
+            30.
+        };
+
+        if dots > 2. && radio_altitude > hard_glideslope_floor && radio_altitude < 350. {
+            Some(Mode5Alert::HardGlideslope)
+        } else if dots > 1.3 && radio_altitude > glideslope_floor {
+            Some(Mode5Alert::Glideslope)
+        } else {
+            None
+        }
+    }
+
+    pub fn mode_1_alert(&self) -> Option<Mode1Alert> {
+        self.mode_1
+    }
+
+    pub fn mode_2_alert(&self) -> Option<Mode2Alert> {
+        self.mode_2
+    }
+
+    pub fn mode_3_alert(&self) -> Option<Mode3Alert> {
+        self.mode_3
+    }
+
+    pub fn mode_4_alert(&self) -> Option<Mode4Alert> {
+        self.mode_4
+    }
+
+    pub fn mode_5_alert(&self) -> Option<Mode5Alert> {
+        self.mode_5
+    }
+
+    pub fn is_airborne(&self) -> bool {
+        self.is_airborne
+    }
+
+    pub fn is_in_approach(&self) -> bool {
+        self.is_in_approach
+    }
+
+    pub fn is_pull_up(&self) -> bool {
+        self.mode_1 == Some(Mode1Alert::PullUp) || self.mode_2 == Some(Mode2Alert::PullUp)
+    }
+
+    /// Modes 1 to 4 illuminate the red GPWS lights.
+    pub fn is_gpws_alert(&self) -> bool {
+        self.mode_1.is_some()
+            || self.mode_2.is_some()
+            || self.mode_3.is_some()
+            || self.mode_4.is_some()
+    }
+
+    /// Mode 5 illuminates the amber glideslope lights.
+    pub fn is_glideslope_caution(&self) -> bool {
+        self.mode_5.is_some()
+    }
+}
+impl Default for AlertModes {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        electrical::Electricity,
+        shared::{InternationalStandardAtmosphere, MachNumber},
+        simulation::{test::TestVariableRegistry, InitContext},
+    };
+    use ntest::assert_about_eq;
+    use uom::si::{acceleration::foot_per_second_squared, angle::radian, f64::Acceleration};
+
+    fn context(delta: Duration) -> UpdateContext {
+        let mut electricity = Electricity::new();
+        let mut registry: TestVariableRegistry = Default::default();
+        let mut init_context =
+            InitContext::new(Default::default(), &mut electricity, &mut registry);
+        let altitude = Length::new::<foot>(1000.);
+
+        UpdateContext::new(
+            &mut init_context,
+            delta,
+            0.,
+            Velocity::new::<knot>(150.),
+            Velocity::new::<knot>(150.),
+            Velocity::new::<knot>(150.),
+            altitude,
+            InternationalStandardAtmosphere::pressure_at_altitude(altitude),
+            InternationalStandardAtmosphere::temperature_at_altitude(altitude),
+            false,
+            Acceleration::new::<foot_per_second_squared>(0.),
+            Acceleration::new::<foot_per_second_squared>(0.),
+            Acceleration::new::<foot_per_second_squared>(0.),
+            Angle::new::<radian>(0.),
+            Angle::new::<radian>(0.),
+            MachNumber(0.),
+            Angle::new::<degree>(0.),
+        )
+    }
+
+    fn inputs(radio_altitude_feet: f64) -> AlertModesInputs {
+        AlertModesInputs {
+            radio_altitude: Some(Length::new::<foot>(radio_altitude_feet)),
+            altitude: Some(Length::new::<foot>(radio_altitude_feet)),
+            vertical_speed: Some(Velocity::new::<foot_per_minute>(0.)),
+            computed_airspeed: Some(Velocity::new::<knot>(150.)),
+            gear_is_down: false,
+            flaps_in_landing_configuration: false,
+            glideslope_deviation: None,
+            glideslope_mode_is_inhibited: false,
+            is_inhibited: false,
+        }
+    }
+
+    fn run(alert_modes: &mut AlertModes, inputs: &AlertModesInputs, duration: Duration) {
+        let step = Duration::from_millis(100);
+        let mut elapsed = Duration::ZERO;
+        while elapsed < duration {
+            alert_modes.update(&context(step), inputs);
+            elapsed += step;
+        }
+    }
+
+    /// Climbs to the given radio altitude long enough to be confirmed airborne.
+    fn airborne_at(radio_altitude_feet: f64) -> AlertModes {
+        let mut alert_modes = AlertModes::new();
+        run(
+            &mut alert_modes,
+            &inputs(radio_altitude_feet),
+            Duration::from_secs(11),
+        );
+
+        alert_modes
+    }
+
+    fn sink_rate(feet_per_minute: f64) -> Option<Velocity> {
+        Some(Velocity::new::<foot_per_minute>(-feet_per_minute))
+    }
+
+    #[test]
+    fn mode_1_is_silent_below_1000_feet_per_minute_sink_rate() {
+        assert_eq!(
+            AlertModes::mode_1(Length::new::<foot>(20.), sink_rate(1000.)),
+            None
+        );
+    }
+
+    #[test]
+    fn mode_1_sink_rate_boundary() {
+        // At 3000 ft/min, the sink rate boundary is at 1230 ft.
+        assert_eq!(
+            AlertModes::mode_1(Length::new::<foot>(1220.), sink_rate(3000.)),
+            Some(Mode1Alert::SinkRate)
+        );
+        assert_eq!(
+            AlertModes::mode_1(Length::new::<foot>(1240.), sink_rate(3000.)),
+            None
+        );
+    }
+
+    #[test]
+    fn mode_1_pull_up_boundary() {
+        // At 3000 ft/min, the pull up boundary is at 790 ft.
+        assert_eq!(
+            AlertModes::mode_1(Length::new::<foot>(780.), sink_rate(3000.)),
+            Some(Mode1Alert::PullUp)
+        );
+        assert_eq!(
+            AlertModes::mode_1(Length::new::<foot>(800.), sink_rate(3000.)),
+            Some(Mode1Alert::SinkRate)
+        );
+    }
+
+    #[test]
+    fn mode_2_is_silent_below_2000_feet_per_minute_closure_rate() {
+        assert!(!AlertModes::is_in_mode_2_boundary(
+            Length::new::<foot>(50.),
+            Velocity::new::<foot_per_minute>(2000.),
+            Velocity::new::<knot>(300.),
+            false,
+        ));
+    }
+
+    #[test]
+    fn mode_2a_closure_rate_boundary() {
+        // At 4000 ft/min, the closure rate boundary is at 1377 ft.
+        let closure_rate = Velocity::new::<foot_per_minute>(4000.);
+        let computed_airspeed = Velocity::new::<knot>(300.);
+
+        assert!(AlertModes::is_in_mode_2_boundary(
+            Length::new::<foot>(1370.),
+            closure_rate,
+            computed_airspeed,
+            false,
+        ));
+        assert!(!AlertModes::is_in_mode_2_boundary(
+            Length::new::<foot>(1385.),
+            closure_rate,
+            computed_airspeed,
+            false,
+        ));
+    }
+
+    #[test]
+    fn mode_2a_upper_limit_depends_on_airspeed() {
+        // At 200 kt, the upper limit is 1650 ft.
+        let closure_rate = Velocity::new::<foot_per_minute>(9000.);
+
+        assert!(AlertModes::is_in_mode_2_boundary(
+            Length::new::<foot>(1640.),
+            closure_rate,
+            Velocity::new::<knot>(200.),
+            false,
+        ));
+        assert!(!AlertModes::is_in_mode_2_boundary(
+            Length::new::<foot>(1660.),
+            closure_rate,
+            Velocity::new::<knot>(200.),
+            false,
+        ));
+        assert!(AlertModes::is_in_mode_2_boundary(
+            Length::new::<foot>(1660.),
+            closure_rate,
+            Velocity::new::<knot>(300.),
+            false,
+        ));
+    }
+
+    #[test]
+    fn mode_2b_upper_limit_is_775_feet() {
+        let closure_rate = Velocity::new::<foot_per_minute>(6000.);
+        let computed_airspeed = Velocity::new::<knot>(150.);
+
+        assert!(AlertModes::is_in_mode_2_boundary(
+            Length::new::<foot>(770.),
+            closure_rate,
+            computed_airspeed,
+            true,
+        ));
+        assert!(!AlertModes::is_in_mode_2_boundary(
+            Length::new::<foot>(780.),
+            closure_rate,
+            computed_airspeed,
+            true,
+        ));
+    }
+
+    #[test]
+    fn mode_3_altitude_loss_boundary() {
+        // At 500 ft radio altitude, the allowed altitude loss is 52 ft.
+        let mut alert_modes = AlertModes::new();
+        let mut climb = inputs(500.);
+        climb.altitude = Some(Length::new::<foot>(1000.));
+        alert_modes.update(&context(Duration::from_millis(100)), &climb);
+
+        let mut descent = inputs(500.);
+        descent.vertical_speed = sink_rate(500.);
+        descent.altitude = Some(Length::new::<foot>(950.));
+        alert_modes.update(&context(Duration::from_millis(100)), &descent);
+        assert_eq!(alert_modes.mode_3_alert(), None);
+
+        descent.altitude = Some(Length::new::<foot>(945.));
+        alert_modes.update(&context(Duration::from_millis(100)), &descent);
+        assert_eq!(alert_modes.mode_3_alert(), Some(Mode3Alert::DontSink));
+    }
+
+    #[test]
+    fn mode_3_is_silent_above_1500_feet() {
+        let mut alert_modes = AlertModes::new();
+        let mut descent = inputs(1600.);
+        descent.altitude = Some(Length::new::<foot>(2000.));
+        alert_modes.update(&context(Duration::from_millis(100)), &descent);
+
+        descent.vertical_speed = sink_rate(500.);
+        descent.altitude = Some(Length::new::<foot>(1800.));
+        alert_modes.update(&context(Duration::from_millis(100)), &descent);
+        assert_eq!(alert_modes.mode_3_alert(), None);
+    }
+
+    #[test]
+    fn mode_4a_too_low_gear_below_500_feet() {
+        let mut alert_modes = airborne_at(2000.);
+
+        alert_modes.update(&context(Duration::from_millis(100)), &inputs(510.));
+        assert_eq!(alert_modes.mode_4_alert(), None);
+
+        alert_modes.update(&context(Duration::from_millis(100)), &inputs(490.));
+        assert_eq!(alert_modes.mode_4_alert(), Some(Mode4Alert::TooLowGear));
+    }
+
+    #[test]
+    fn mode_4a_too_low_terrain_above_190_knots() {
+        // At 210 kt, the upper boundary is at 667 ft.
+        let mut alert_modes = airborne_at(2000.);
+        let mut approach = inputs(680.);
+        approach.computed_airspeed = Some(Velocity::new::<knot>(210.));
+
+        alert_modes.update(&context(Duration::from_millis(100)), &approach);
+        assert_eq!(alert_modes.mode_4_alert(), None);
+
+        approach.radio_altitude = Some(Length::new::<foot>(650.));
+        alert_modes.update(&context(Duration::from_millis(100)), &approach);
+        assert_eq!(alert_modes.mode_4_alert(), Some(Mode4Alert::TooLowTerrain));
+    }
+
+    #[test]
+    fn mode_4b_too_low_flaps_below_245_feet() {
+        let mut alert_modes = airborne_at(2000.);
+        let mut approach = inputs(250.);
+        approach.gear_is_down = true;
+
+        alert_modes.update(&context(Duration::from_millis(100)), &approach);
+        assert_eq!(alert_modes.mode_4_alert(), None);
+
+        approach.radio_altitude = Some(Length::new::<foot>(240.));
+        alert_modes.update(&context(Duration::from_millis(100)), &approach);
+        assert_eq!(alert_modes.mode_4_alert(), Some(Mode4Alert::TooLowFlaps));
+    }
+
+    #[test]
+    fn mode_4b_upper_boundary_increases_with_airspeed() {
+        assert_about_eq!(
+            AlertModes::mode_4b_upper_boundary(Velocity::new::<knot>(150.)),
+            AlertModes::MODE_4B_FLOOR_FEET
+        );
+        assert_about_eq!(
+            AlertModes::mode_4b_upper_boundary(Velocity::new::<knot>(200.)),
+            583.27,
+            0.01
+        );
+        assert_about_eq!(
+            AlertModes::mode_4b_upper_boundary(Velocity::new::<knot>(300.)),
+            1000.
+        );
+    }
+
+    #[test]
+    fn mode_4c_terrain_clearance_after_takeoff() {
+        // Having climbed to 400 ft, the terrain clearance floor is at 300 ft.
+        let mut alert_modes = airborne_at(400.);
+
+        alert_modes.update(&context(Duration::from_millis(100)), &inputs(310.));
+        assert_eq!(alert_modes.mode_4_alert(), None);
+
+        alert_modes.update(&context(Duration::from_millis(100)), &inputs(290.));
+        assert_eq!(alert_modes.mode_4_alert(), Some(Mode4Alert::TooLowTerrain));
+    }
+
+    #[test]
+    fn mode_4_requires_the_aircraft_to_be_airborne() {
+        let mut alert_modes = AlertModes::new();
+        alert_modes.update(&context(Duration::from_millis(100)), &inputs(100.));
+
+        assert_eq!(alert_modes.mode_4_alert(), None);
+    }
+
+    fn glideslope_inputs(radio_altitude_feet: f64, dots: f64) -> AlertModesInputs {
+        let mut inputs = inputs(radio_altitude_feet);
+        inputs.gear_is_down = true;
+        inputs.glideslope_deviation = Some(Angle::new::<degree>(
+            dots * AlertModes::GLIDESLOPE_DEGREES_PER_DOT,
+        ));
+
+        inputs
+    }
+
+    #[test]
+    fn mode_5_glideslope_boundary() {
+        assert_eq!(
+            AlertModes::mode_5(Length::new::<foot>(500.), &glideslope_inputs(500., 1.2)),
+            None
+        );
+        assert_eq!(
+            AlertModes::mode_5(Length::new::<foot>(500.), &glideslope_inputs(500., 1.5)),
+            Some(Mode5Alert::Glideslope)
+        );
+        // At 1.5 dots, the lower boundary is at 135 ft.
+        assert_eq!(
+            AlertModes::mode_5(Length::new::<foot>(130.), &glideslope_inputs(130., 1.5)),
+            None
+        );
+    }
+
+    #[test]
+    fn mode_5_hard_glideslope_boundary() {
+        assert_eq!(
+            AlertModes::mode_5(Length::new::<foot>(300.), &glideslope_inputs(300., 2.5)),
+            Some(Mode5Alert::HardGlideslope)
+        );
+        assert_eq!(
+            AlertModes::mode_5(Length::new::<foot>(400.), &glideslope_inputs(400., 2.5)),
+            Some(Mode5Alert::Glideslope)
+        );
+    }
+
+    #[test]
+    fn mode_5_requires_the_gear_down_and_no_inhibition() {
+        let mut inputs = glideslope_inputs(500., 2.5);
+        inputs.gear_is_down = false;
+        assert_eq!(AlertModes::mode_5(Length::new::<foot>(500.), &inputs), None);
+
+        let mut inputs = glideslope_inputs(500., 2.5);
+        inputs.glideslope_mode_is_inhibited = true;
+        assert_eq!(AlertModes::mode_5(Length::new::<foot>(500.), &inputs), None);
+    }
+
+    #[test]
+    fn alerts_are_inhibited_outside_of_radio_altitude_range() {
+        let mut alert_modes = AlertModes::new();
+        let mut descent = inputs(2500.);
+        descent.vertical_speed = sink_rate(9000.);
+        alert_modes.update(&context(Duration::from_millis(100)), &descent);
+        assert_eq!(alert_modes.mode_1_alert(), None);
+
+        descent.radio_altitude = Some(Length::new::<foot>(2400.));
+        alert_modes.update(&context(Duration::from_millis(100)), &descent);
+        assert_eq!(alert_modes.mode_1_alert(), Some(Mode1Alert::PullUp));
+
+        descent.is_inhibited = true;
+        alert_modes.update(&context(Duration::from_millis(100)), &descent);
+        assert!(!alert_modes.is_gpws_alert());
+    }
+}
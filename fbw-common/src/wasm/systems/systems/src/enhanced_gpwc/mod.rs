@@ -1,22 +1,34 @@
 use crate::{
     accept_iterable,
-    enhanced_gpwc::navigation_display::NavigationDisplay,
+    enhanced_gpwc::{
+        alert_modes::{
+            AlertModes, AlertModesInputs, Mode1Alert, Mode2Alert, Mode3Alert, Mode4Alert,
+        },
+        navigation_display::NavigationDisplay,
+    },
+    navigation::{
+        gps::{GpsReceiver, GPS_LATITUDE, GPS_LONGITUDE},
+        radio_altimeter::{RadioAltimeter, RADIO_ALTITUDE},
+    },
     shared::{
-        arinc429::{Arinc429Word, SignStatus},
+        arinc429::{Arinc429Bus, Arinc429LabelDefinition, Arinc429Word, SignStatus},
         AdirsMeasurementOutputs, ElectricalBusType, ElectricalBuses, LgciuGearExtension,
+        PositionPickoffUnit,
     },
     simulation::{
         InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
-        SimulatorWriter, VariableIdentifier, Write,
+        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
     },
 };
-use std::vec::Vec;
+use std::{time::Duration, vec::Vec};
 use uom::si::{
     angle::degree,
     f64::{Angle, Length, Velocity},
+    length::foot,
     velocity::foot_per_minute,
 };
 
+pub mod alert_modes;
 pub mod navigation_display;
 
 pub struct EnhancedGroundProximityWarningComputer {
@@ -37,6 +49,22 @@ pub struct EnhancedGroundProximityWarningComputer {
     navigation_displays: [NavigationDisplay; 2],
     gear_is_down: bool,
     terronnd_rendering_mode: u8,
+    flaps_3_angle: Angle,
+    flaps_full_angle: Angle,
+    alert_modes: AlertModes,
+    // GPWS overhead panel pushbuttons
+    gpws_sys_off_id: VariableIdentifier,
+    gpws_gs_off_id: VariableIdentifier,
+    gpws_flap_off_id: VariableIdentifier,
+    gpws_ldg_flap_3_id: VariableIdentifier,
+    gpws_sys_is_off: bool,
+    gpws_gs_mode_is_off: bool,
+    gpws_flap_mode_is_off: bool,
+    gpws_ldg_flap_3_is_on: bool,
+    glideslope_is_valid_id: VariableIdentifier,
+    glideslope_deviation_id: VariableIdentifier,
+    glideslope_is_valid: bool,
+    glideslope_deviation: Angle,
     // output variables of the EGPWC
     egpwc_destination_longitude_id: VariableIdentifier,
     egpwc_destination_latitude_id: VariableIdentifier,
@@ -47,14 +75,29 @@ pub struct EnhancedGroundProximityWarningComputer {
     egpwc_present_vertical_speed_id: VariableIdentifier,
     egpwc_gear_is_down_id: VariableIdentifier,
     egpwc_terronnd_rendering_mode: VariableIdentifier,
+    // Both alert outputs carry the same words
+    egpws_alert_discrete_word_1_ids: [VariableIdentifier; 2],
+    egpws_alert_discrete_word_2_ids: [VariableIdentifier; 2],
+    gpws_ground_state_id: VariableIdentifier,
+    gpws_approach_state_id: VariableIdentifier,
+    bus: Arinc429Bus,
 }
 
 impl EnhancedGroundProximityWarningComputer {
+    pub const ALERT_DISCRETE_WORD_1: Arinc429LabelDefinition =
+        Arinc429LabelDefinition::discrete(0o270, Duration::from_millis(100));
+    pub const ALERT_DISCRETE_WORD_2: Arinc429LabelDefinition =
+        Arinc429LabelDefinition::discrete(0o271, Duration::from_millis(100));
+
+    /// The flaps are in landing configuration when the flap position pickoff unit reaches
+    /// `flaps_full_angle`, or `flaps_3_angle` when the LDG FLAP 3 pushbutton is on.
     pub fn new(
         context: &mut InitContext,
         powered_by: ElectricalBusType,
         range_lookup: Vec<Length>,
         terronnd_rendering_mode: u8,
+        flaps_3_angle: Angle,
+        flaps_full_angle: Angle,
     ) -> Self {
         EnhancedGroundProximityWarningComputer {
             powered_by,
@@ -78,6 +121,22 @@ impl EnhancedGroundProximityWarningComputer {
             ],
             gear_is_down: true,
             terronnd_rendering_mode,
+            flaps_3_angle,
+            flaps_full_angle,
+            alert_modes: AlertModes::new(),
+            gpws_sys_off_id: context.get_identifier("GPWS_SYS_OFF".to_owned()),
+            gpws_gs_off_id: context.get_identifier("GPWS_GS_OFF".to_owned()),
+            gpws_flap_off_id: context.get_identifier("GPWS_FLAP_OFF".to_owned()),
+            gpws_ldg_flap_3_id: context.get_identifier("GPWS_FLAPS3".to_owned()),
+            gpws_sys_is_off: false,
+            gpws_gs_mode_is_off: false,
+            gpws_flap_mode_is_off: false,
+            gpws_ldg_flap_3_is_on: false,
+            glideslope_is_valid_id: context.get_identifier("RADIO_RECEIVER_GS_IS_VALID".to_owned()),
+            glideslope_deviation_id: context
+                .get_identifier("RADIO_RECEIVER_GS_DEVIATION".to_owned()),
+            glideslope_is_valid: false,
+            glideslope_deviation: Angle::default(),
             egpwc_destination_longitude_id: context.get_identifier("EGPWC_DEST_LONG".to_owned()),
             egpwc_destination_latitude_id: context.get_identifier("EGPWC_DEST_LAT".to_owned()),
             egpwc_present_latitude_id: context.get_identifier("EGPWC_PRESENT_LAT".to_owned()),
@@ -89,26 +148,142 @@ impl EnhancedGroundProximityWarningComputer {
             egpwc_gear_is_down_id: context.get_identifier("EGPWC_GEAR_IS_DOWN".to_owned()),
             egpwc_terronnd_rendering_mode: context
                 .get_identifier("EGPWC_TERRONND_RENDERING_MODE".to_owned()),
+            egpws_alert_discrete_word_1_ids: [1, 2].map(|output| {
+                context.get_identifier(format!("EGPWS_ALERT_{}_DISCRETE_WORD_1", output))
+            }),
+            egpws_alert_discrete_word_2_ids: [1, 2].map(|output| {
+                context.get_identifier(format!("EGPWS_ALERT_{}_DISCRETE_WORD_2", output))
+            }),
+            gpws_ground_state_id: context.get_identifier("GPWS_GROUND_STATE".to_owned()),
+            gpws_approach_state_id: context.get_identifier("GPWS_APPROACH_STATE".to_owned()),
+            bus: Arinc429Bus::new(
+                0,
+                &[Self::ALERT_DISCRETE_WORD_1, Self::ALERT_DISCRETE_WORD_2],
+            ),
         }
     }
 
-    fn update_position_data(&mut self, adirs_output: &impl AdirsMeasurementOutputs) {
-        // documentation hints:
-        //   - EGPWC has direct connection to GPS sensor && ADIRS_1
-        //   - uses direct GPS data if ADIRS_1 is unavailable
-        // TODO:
-        //   - implement logic as soon as GPS sensor is available
-        self.latitude = adirs_output.latitude(1);
-        self.longitude = adirs_output.longitude(1);
+    fn update_position_data(
+        &mut self,
+        adirs_output: &impl AdirsMeasurementOutputs,
+        gps_receiver: &impl GpsReceiver,
+    ) {
+        // The EGPWC uses the hybrid (GPIRS) position of ADIRU 1. It is also connected directly to a
+        // GPS receiver, whose position is used when the hybrid position is unavailable.
+        let latitude = adirs_output.hybrid_latitude(1);
+        let longitude = adirs_output.hybrid_longitude(1);
+        let gps_position = Self::gps_angle(gps_receiver, &GPS_LATITUDE)
+            .zip(Self::gps_angle(gps_receiver, &GPS_LONGITUDE));
+
+        (self.latitude, self.longitude) = match gps_position {
+            Some(gps_position)
+                if !latitude.is_normal_operation() || !longitude.is_normal_operation() =>
+            {
+                gps_position
+            }
+            _ => (latitude, longitude),
+        };
         self.altitude = adirs_output.altitude(1);
         self.heading = adirs_output.true_heading(1);
         self.vertical_speed = adirs_output.vertical_speed(1);
     }
 
+    fn gps_angle(
+        gps_receiver: &impl GpsReceiver,
+        label: &Arinc429LabelDefinition,
+    ) -> Option<Arinc429Word<Angle>> {
+        gps_receiver
+            .bus()
+            .receive_value(label.label())
+            .filter(|word| word.is_normal_operation())
+            .map(|word| Arinc429Word::new(Angle::new::<degree>(word.value()), word.ssm()))
+    }
+
+    fn update_alert_modes(
+        &mut self,
+        context: &UpdateContext,
+        adirs_output: &impl AdirsMeasurementOutputs,
+        radio_altimeters: [&impl RadioAltimeter; 2],
+        lgcius: &impl LgciuGearExtension,
+        flaps: &impl PositionPickoffUnit,
+    ) {
+        // The second radio altimeter is used when the first one has no valid data or isn't
+        // transmitting.
+        let radio_altitude = radio_altimeters.iter().find_map(|radio_altimeter| {
+            radio_altimeter
+                .bus()
+                .receive_value(RADIO_ALTITUDE.label())
+                .and_then(|word| word.normal_value())
+                .map(Length::new::<foot>)
+        });
+
+        let landing_flaps_angle = if self.gpws_ldg_flap_3_is_on {
+            self.flaps_3_angle
+        } else {
+            self.flaps_full_angle
+        };
+
+        self.alert_modes.update(
+            context,
+            &AlertModesInputs {
+                radio_altitude,
+                altitude: adirs_output.altitude(1).normal_value(),
+                vertical_speed: adirs_output.vertical_speed(1).normal_value(),
+                computed_airspeed: adirs_output.computed_airspeed(1).normal_value(),
+                gear_is_down: lgcius.main_down_and_locked(),
+                flaps_in_landing_configuration: self.gpws_flap_mode_is_off
+                    || flaps.angle() >= landing_flaps_angle,
+                glideslope_deviation: self
+                    .glideslope_is_valid
+                    .then_some(-self.glideslope_deviation),
+                glideslope_mode_is_inhibited: self.gpws_gs_mode_is_off,
+                is_inhibited: self.gpws_sys_is_off,
+            },
+        );
+    }
+
+    /// Encodes the alerts in the bits the flight warning system expects.
+    fn alert_discrete_words(&self) -> (Arinc429Word<u32>, Arinc429Word<u32>) {
+        let ssm = if self.is_powered {
+            SignStatus::NormalOperation
+        } else {
+            SignStatus::FailureWarning
+        };
+        let mut word_1 = Arinc429Word::new(0, ssm);
+        let mut word_2 = Arinc429Word::new(0, ssm);
+
+        let mode_1 = self.alert_modes.mode_1_alert();
+        let mode_4 = self.alert_modes.mode_4_alert();
+        word_1.set_bit(11, mode_1 == Some(Mode1Alert::SinkRate));
+        word_1.set_bit(12, self.alert_modes.is_pull_up());
+        word_1.set_bit(
+            13,
+            self.alert_modes.mode_2_alert() == Some(Mode2Alert::Terrain),
+        );
+        word_1.set_bit(
+            14,
+            self.alert_modes.mode_3_alert() == Some(Mode3Alert::DontSink),
+        );
+        word_1.set_bit(15, mode_4 == Some(Mode4Alert::TooLowGear));
+        word_1.set_bit(16, mode_4 == Some(Mode4Alert::TooLowFlaps));
+        word_1.set_bit(17, mode_4 == Some(Mode4Alert::TooLowTerrain));
+        word_1.set_bit(18, self.alert_modes.mode_5_alert().is_some());
+
+        word_2.set_bit(11, self.alert_modes.is_glideslope_caution());
+        word_2.set_bit(12, self.alert_modes.is_gpws_alert());
+        word_2.set_bit(13, self.alert_modes.is_pull_up());
+
+        (word_1, word_2)
+    }
+
     pub fn update(
         &mut self,
+        context: &UpdateContext,
         adirs_output: &impl AdirsMeasurementOutputs,
+        gps_receiver: &impl GpsReceiver,
+        radio_altimeters: [&impl RadioAltimeter; 2],
         lgcius: &impl LgciuGearExtension,
+        flaps: &impl PositionPickoffUnit,
     ) {
         if !self.is_powered {
             self.destination_longitude =
@@ -123,9 +298,11 @@ impl EnhancedGroundProximityWarningComputer {
                 Arinc429Word::new(Velocity::default(), SignStatus::FailureWarning);
 
             self.gear_is_down = false;
+            self.alert_modes.reset();
         } else {
-            self.update_position_data(adirs_output);
+            self.update_position_data(adirs_output, gps_receiver);
             self.gear_is_down = lgcius.main_down_and_locked();
+            self.update_alert_modes(context, adirs_output, radio_altimeters, lgcius, flaps);
         }
 
         self.navigation_displays.iter_mut().for_each(|display| {
@@ -135,6 +312,26 @@ impl EnhancedGroundProximityWarningComputer {
                 adirs_output.is_fully_aligned(1),
             )
         });
+
+        self.update_bus(context);
+    }
+
+    fn update_bus(&mut self, context: &UpdateContext) {
+        if self.is_powered {
+            let (alert_discrete_word_1, alert_discrete_word_2) = self.alert_discrete_words();
+            self.bus
+                .transmit_discrete(Self::ALERT_DISCRETE_WORD_1.label(), alert_discrete_word_1);
+            self.bus
+                .transmit_discrete(Self::ALERT_DISCRETE_WORD_2.label(), alert_discrete_word_2);
+        } else {
+            self.bus.stop_transmission();
+        }
+
+        self.bus.update(context.delta());
+    }
+
+    pub fn bus(&self) -> &Arinc429Bus {
+        &self.bus
     }
 }
 
@@ -157,6 +354,14 @@ impl SimulationElement for EnhancedGroundProximityWarningComputer {
             Angle::new::<degree>(destination_lat),
             SignStatus::from(destination_lat_ssm),
         );
+
+        self.gpws_sys_is_off = reader.read(&self.gpws_sys_off_id);
+        self.gpws_gs_mode_is_off = reader.read(&self.gpws_gs_off_id);
+        self.gpws_flap_mode_is_off = reader.read(&self.gpws_flap_off_id);
+        self.gpws_ldg_flap_3_is_on = reader.read(&self.gpws_ldg_flap_3_id);
+        self.glideslope_is_valid = reader.read(&self.glideslope_is_valid_id);
+        self.glideslope_deviation =
+            Angle::new::<degree>(reader.read(&self.glideslope_deviation_id));
     }
 
     fn write(&self, writer: &mut SimulatorWriter) {
@@ -182,6 +387,20 @@ impl SimulationElement for EnhancedGroundProximityWarningComputer {
             &self.egpwc_terronnd_rendering_mode,
             self.terronnd_rendering_mode,
         );
+
+        for id in &self.egpws_alert_discrete_word_1_ids {
+            self.bus
+                .write_received(writer, id, Self::ALERT_DISCRETE_WORD_1.label());
+        }
+        for id in &self.egpws_alert_discrete_word_2_ids {
+            self.bus
+                .write_received(writer, id, Self::ALERT_DISCRETE_WORD_2.label());
+        }
+        writer.write(&self.gpws_ground_state_id, !self.alert_modes.is_airborne());
+        writer.write(
+            &self.gpws_approach_state_id,
+            self.alert_modes.is_in_approach(),
+        );
     }
 
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
@@ -195,6 +414,7 @@ mod tests {
     use super::*;
     use crate::{
         electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+        navigation::gps::test::TestGpsReceiver,
         shared::PotentialOrigin,
         simulation::{
             test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
@@ -202,21 +422,25 @@ mod tests {
         },
     };
     use ntest::assert_about_eq;
+    use std::time::Duration;
     use uom::si::{
         angle::degree,
         electric_potential::volt,
         f64::*,
         length::{foot, nautical_mile},
-        velocity::foot_per_minute,
+        velocity::{foot_per_minute, knot},
     };
 
     struct TestAdirs {
         is_aligned: bool,
         latitude: Arinc429Word<Angle>,
         longitude: Arinc429Word<Angle>,
+        hybrid_latitude: Arinc429Word<Angle>,
+        hybrid_longitude: Arinc429Word<Angle>,
         heading: Arinc429Word<Angle>,
         vertical_speed: Arinc429Word<Velocity>,
         altitude: Arinc429Word<Length>,
+        computed_airspeed: Arinc429Word<Velocity>,
         angle_of_attack: Arinc429Word<Angle>,
     }
     impl TestAdirs {
@@ -225,9 +449,15 @@ mod tests {
                 is_aligned: false,
                 latitude: Arinc429Word::new(Angle::default(), SignStatus::FailureWarning),
                 longitude: Arinc429Word::new(Angle::default(), SignStatus::FailureWarning),
+                hybrid_latitude: Arinc429Word::new(Angle::default(), SignStatus::FailureWarning),
+                hybrid_longitude: Arinc429Word::new(Angle::default(), SignStatus::FailureWarning),
                 heading: Arinc429Word::new(Angle::default(), SignStatus::FailureWarning),
                 vertical_speed: Arinc429Word::new(Velocity::default(), SignStatus::FailureWarning),
                 altitude: Arinc429Word::new(Length::default(), SignStatus::FailureWarning),
+                computed_airspeed: Arinc429Word::new(
+                    Velocity::default(),
+                    SignStatus::FailureWarning,
+                ),
                 angle_of_attack: Arinc429Word::new(Angle::default(), SignStatus::FailureWarning),
             }
         }
@@ -238,6 +468,10 @@ mod tests {
                 Arinc429Word::new(Angle::new::<degree>(20.3), SignStatus::NormalOperation);
             self.longitude =
                 Arinc429Word::new(Angle::new::<degree>(30.3), SignStatus::NormalOperation);
+            self.hybrid_latitude =
+                Arinc429Word::new(Angle::new::<degree>(20.4), SignStatus::NormalOperation);
+            self.hybrid_longitude =
+                Arinc429Word::new(Angle::new::<degree>(30.4), SignStatus::NormalOperation);
             self.heading =
                 Arinc429Word::new(Angle::new::<degree>(310.0), SignStatus::NormalOperation);
             self.vertical_speed = Arinc429Word::new(
@@ -246,9 +480,29 @@ mod tests {
             );
             self.altitude =
                 Arinc429Word::new(Length::new::<foot>(15000.0), SignStatus::NormalOperation);
+            self.computed_airspeed =
+                Arinc429Word::new(Velocity::new::<knot>(250.0), SignStatus::NormalOperation);
             self.angle_of_attack =
                 Arinc429Word::new(Angle::new::<degree>(0.0), SignStatus::NormalOperation);
         }
+
+        fn set_vertical_speed(&mut self, vertical_speed: Velocity) {
+            self.vertical_speed = Arinc429Word::new(vertical_speed, SignStatus::NormalOperation);
+        }
+
+        fn set_altitude(&mut self, altitude: Length) {
+            self.altitude = Arinc429Word::new(altitude, SignStatus::NormalOperation);
+        }
+
+        fn lose_hybrid_position(&mut self) {
+            self.hybrid_latitude = Arinc429Word::new(Angle::default(), SignStatus::NoComputedData);
+            self.hybrid_longitude = Arinc429Word::new(Angle::default(), SignStatus::NoComputedData);
+        }
+
+        fn set_computed_airspeed(&mut self, computed_airspeed: Velocity) {
+            self.computed_airspeed =
+                Arinc429Word::new(computed_airspeed, SignStatus::NormalOperation);
+        }
     }
     impl AdirsMeasurementOutputs for TestAdirs {
         fn is_fully_aligned(&self, _adiru_number: usize) -> bool {
@@ -263,6 +517,14 @@ mod tests {
             self.longitude
         }
 
+        fn hybrid_latitude(&self, _adiru_number: usize) -> Arinc429Word<Angle> {
+            self.hybrid_latitude
+        }
+
+        fn hybrid_longitude(&self, _adiru_number: usize) -> Arinc429Word<Angle> {
+            self.hybrid_longitude
+        }
+
         fn heading(&self, _adiru_number: usize) -> Arinc429Word<Angle> {
             self.heading
         }
@@ -279,6 +541,10 @@ mod tests {
             self.altitude
         }
 
+        fn computed_airspeed(&self, _adiru_number: usize) -> Arinc429Word<Velocity> {
+            self.computed_airspeed
+        }
+
         fn angle_of_attack(&self, _adiru_number: usize) -> Arinc429Word<Angle> {
             self.angle_of_attack
         }
@@ -323,9 +589,65 @@ mod tests {
         }
     }
 
+    struct TestRadioAltimeter {
+        radio_altitude: Arinc429Word<Length>,
+        is_transmitting: bool,
+        bus: Arinc429Bus,
+    }
+    impl TestRadioAltimeter {
+        fn new() -> Self {
+            Self {
+                radio_altitude: Arinc429Word::new(Length::default(), SignStatus::NoComputedData),
+                is_transmitting: true,
+                bus: Arinc429Bus::new(0, &[RADIO_ALTITUDE]),
+            }
+        }
+
+        fn update(&mut self, context: &UpdateContext) {
+            if self.is_transmitting {
+                self.bus.transmit(
+                    RADIO_ALTITUDE.label(),
+                    Arinc429Word::new(
+                        self.radio_altitude.value().get::<foot>(),
+                        self.radio_altitude.ssm(),
+                    ),
+                );
+            } else {
+                self.bus.stop_transmission();
+            }
+
+            self.bus.update(context.delta());
+        }
+
+        fn set_radio_altitude(&mut self, radio_altitude: Length) {
+            self.radio_altitude = Arinc429Word::new(radio_altitude, SignStatus::NormalOperation);
+        }
+    }
+    impl RadioAltimeter for TestRadioAltimeter {
+        fn radio_altitude(&self) -> Arinc429Word<Length> {
+            self.radio_altitude
+        }
+
+        fn bus(&self) -> &Arinc429Bus {
+            &self.bus
+        }
+    }
+
+    struct TestFlaps {
+        angle: Angle,
+    }
+    impl PositionPickoffUnit for TestFlaps {
+        fn angle(&self) -> Angle {
+            self.angle
+        }
+    }
+
     struct EgpwcTestAircraft {
         adirs: TestAdirs,
+        gps_receiver: TestGpsReceiver,
         lgciu: TestLgciu,
+        radio_altimeters: [TestRadioAltimeter; 2],
+        flaps: TestFlaps,
         egpwc: EnhancedGroundProximityWarningComputer,
         powered_source_dc: TestElectricitySource,
         dc_1_bus: ElectricalBus,
@@ -335,7 +657,12 @@ mod tests {
         fn new(context: &mut InitContext) -> Self {
             Self {
                 adirs: TestAdirs::new(),
+                gps_receiver: TestGpsReceiver::new(),
                 lgciu: TestLgciu::new(),
+                radio_altimeters: [TestRadioAltimeter::new(), TestRadioAltimeter::new()],
+                flaps: TestFlaps {
+                    angle: Angle::default(),
+                },
                 egpwc: EnhancedGroundProximityWarningComputer::new(
                     context,
                     ElectricalBusType::DirectCurrent(1),
@@ -348,6 +675,8 @@ mod tests {
                         Length::new::<nautical_mile>(320.0),
                     ],
                     0,
+                    Angle::new::<degree>(163.7),
+                    Angle::new::<degree>(247.8),
                 ),
                 powered_source_dc: TestElectricitySource::powered(
                     context,
@@ -358,14 +687,33 @@ mod tests {
             }
         }
 
-        fn update(&mut self) {
-            self.egpwc.update(&self.adirs, &self.lgciu);
+        fn update(&mut self, context: &UpdateContext) {
+            self.radio_altimeters
+                .iter_mut()
+                .for_each(|radio_altimeter| radio_altimeter.update(context));
+            self.gps_receiver.update(context);
+            self.egpwc.update(
+                context,
+                &self.adirs,
+                &self.gps_receiver,
+                [&self.radio_altimeters[0], &self.radio_altimeters[1]],
+                &self.lgciu,
+                &self.flaps,
+            );
         }
 
         fn initialize_adiru(&mut self) {
             self.adirs.initialize();
         }
 
+        fn lose_hybrid_position(&mut self) {
+            self.adirs.lose_hybrid_position();
+        }
+
+        fn set_gps_position(&mut self, latitude: Angle, longitude: Angle) {
+            self.gps_receiver.set_position(Some((latitude, longitude)));
+        }
+
         fn gear_down(&mut self) {
             self.lgciu.set_gear_down(true);
         }
@@ -373,6 +721,39 @@ mod tests {
         fn set_elec_powered(&mut self, is_powered: bool) {
             self.is_elec_powered = is_powered;
         }
+
+        fn set_radio_altitude(&mut self, radio_altitude: Length) {
+            self.radio_altimeters[0].set_radio_altitude(radio_altitude);
+        }
+
+        fn fail_radio_altimeter_1(&mut self) {
+            self.radio_altimeters[0].radio_altitude =
+                Arinc429Word::new(Length::default(), SignStatus::FailureWarning);
+        }
+
+        fn stop_radio_altimeter_1_transmission(&mut self) {
+            self.radio_altimeters[0].is_transmitting = false;
+        }
+
+        fn set_radio_altimeter_2_altitude(&mut self, radio_altitude: Length) {
+            self.radio_altimeters[1].set_radio_altitude(radio_altitude);
+        }
+
+        fn set_vertical_speed(&mut self, vertical_speed: Velocity) {
+            self.adirs.set_vertical_speed(vertical_speed);
+        }
+
+        fn set_altitude(&mut self, altitude: Length) {
+            self.adirs.set_altitude(altitude);
+        }
+
+        fn set_computed_airspeed(&mut self, computed_airspeed: Velocity) {
+            self.adirs.set_computed_airspeed(computed_airspeed);
+        }
+
+        fn set_flaps_angle(&mut self, angle: Angle) {
+            self.flaps.angle = angle;
+        }
     }
     impl Aircraft for EgpwcTestAircraft {
         fn update_before_power_distribution(
@@ -389,8 +770,8 @@ mod tests {
             }
         }
 
-        fn update_after_power_distribution(&mut self, _: &UpdateContext) {
-            self.update();
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.update(context);
         }
     }
     impl SimulationElement for EgpwcTestAircraft {
@@ -531,11 +912,11 @@ mod tests {
         assert!(destination_long.is_failure_warning());
         let present_lat: Arinc429Word<Angle> = test_bed.read_arinc429_by_name("EGPWC_PRESENT_LAT");
         assert!(present_lat.is_normal_operation());
-        assert_about_eq!(present_lat.value().get::<degree>(), 20.3);
+        assert_about_eq!(present_lat.value().get::<degree>(), 20.4);
         let present_long: Arinc429Word<Angle> =
             test_bed.read_arinc429_by_name("EGPWC_PRESENT_LONG");
         assert!(present_long.is_normal_operation());
-        assert_about_eq!(present_long.value().get::<degree>(), 30.3);
+        assert_about_eq!(present_long.value().get::<degree>(), 30.4);
         let present_heading: Arinc429Word<Angle> =
             test_bed.read_arinc429_by_name("EGPWC_PRESENT_HEADING");
         assert!(present_heading.is_normal_operation());
@@ -563,6 +944,59 @@ mod tests {
         assert!(nd_terr_activate_fo);
     }
 
+    #[test]
+    fn uses_gps_position_when_adiru_1_is_unavailable() {
+        let mut test_bed = SimulationTestBed::new(EgpwcTestAircraft::new);
+        test_bed.command(|a| a.set_elec_powered(true));
+        test_bed.command(|a| {
+            a.set_gps_position(Angle::new::<degree>(47.3), Angle::new::<degree>(11.3))
+        });
+        test_bed.run();
+
+        let present_lat: Arinc429Word<Angle> = test_bed.read_arinc429_by_name("EGPWC_PRESENT_LAT");
+        assert!(present_lat.is_normal_operation());
+        assert_about_eq!(present_lat.value().get::<degree>(), 47.3, 0.001);
+        let present_long: Arinc429Word<Angle> =
+            test_bed.read_arinc429_by_name("EGPWC_PRESENT_LONG");
+        assert!(present_long.is_normal_operation());
+        assert_about_eq!(present_long.value().get::<degree>(), 11.3, 0.001);
+    }
+
+    #[test]
+    fn prefers_adiru_1_hybrid_position_over_gps_position() {
+        let mut test_bed = SimulationTestBed::new(EgpwcTestAircraft::new);
+        test_bed.command(|a| a.set_elec_powered(true));
+        test_bed.command(|a| a.initialize_adiru());
+        test_bed.command(|a| {
+            a.set_gps_position(Angle::new::<degree>(47.3), Angle::new::<degree>(11.3))
+        });
+        test_bed.run();
+
+        let present_lat: Arinc429Word<Angle> = test_bed.read_arinc429_by_name("EGPWC_PRESENT_LAT");
+        assert_about_eq!(present_lat.value().get::<degree>(), 20.4);
+        let present_long: Arinc429Word<Angle> =
+            test_bed.read_arinc429_by_name("EGPWC_PRESENT_LONG");
+        assert_about_eq!(present_long.value().get::<degree>(), 30.4);
+    }
+
+    #[test]
+    fn uses_gps_position_when_the_adiru_1_hybrid_position_is_unavailable() {
+        let mut test_bed = SimulationTestBed::new(EgpwcTestAircraft::new);
+        test_bed.command(|a| a.set_elec_powered(true));
+        test_bed.command(|a| a.initialize_adiru());
+        test_bed.command(|a| a.lose_hybrid_position());
+        test_bed.command(|a| {
+            a.set_gps_position(Angle::new::<degree>(47.3), Angle::new::<degree>(11.3))
+        });
+        test_bed.run();
+
+        let present_lat: Arinc429Word<Angle> = test_bed.read_arinc429_by_name("EGPWC_PRESENT_LAT");
+        assert_about_eq!(present_lat.value().get::<degree>(), 47.3, 0.001);
+        let present_long: Arinc429Word<Angle> =
+            test_bed.read_arinc429_by_name("EGPWC_PRESENT_LONG");
+        assert_about_eq!(present_long.value().get::<degree>(), 11.3, 0.001);
+    }
+
     #[test]
     fn powered_up_with_destination() {
         let mut test_bed = SimulationTestBed::new(EgpwcTestAircraft::new);
@@ -594,11 +1028,11 @@ mod tests {
         assert_about_eq!(destination_long.value().get::<degree>(), 25.3);
         let present_lat: Arinc429Word<Angle> = test_bed.read_arinc429_by_name("EGPWC_PRESENT_LAT");
         assert!(present_lat.is_normal_operation());
-        assert_about_eq!(present_lat.value().get::<degree>(), 20.3);
+        assert_about_eq!(present_lat.value().get::<degree>(), 20.4);
         let present_long: Arinc429Word<Angle> =
             test_bed.read_arinc429_by_name("EGPWC_PRESENT_LONG");
         assert!(present_long.is_normal_operation());
-        assert_about_eq!(present_long.value().get::<degree>(), 30.3);
+        assert_about_eq!(present_long.value().get::<degree>(), 30.4);
         let present_heading: Arinc429Word<Angle> =
             test_bed.read_arinc429_by_name("EGPWC_PRESENT_HEADING");
         assert!(present_heading.is_normal_operation());
@@ -625,4 +1059,380 @@ mod tests {
         let nd_terr_activate_fo: bool = test_bed.read_by_name("EGPWC_ND_R_TERRAIN_ACTIVE");
         assert!(!nd_terr_activate_fo);
     }
+
+    fn alert_test_bed() -> SimulationTestBed<EgpwcTestAircraft> {
+        let mut test_bed = SimulationTestBed::new(EgpwcTestAircraft::new);
+        test_bed.command(|a| a.set_elec_powered(true));
+        test_bed.command(|a| a.initialize_adiru());
+        test_bed.command(|a| a.set_vertical_speed(Velocity::new::<foot_per_minute>(0.)));
+
+        test_bed
+    }
+
+    /// Climbs high enough for the EGPWC to consider the aircraft airborne and in approach.
+    fn establish_approach(test_bed: &mut SimulationTestBed<EgpwcTestAircraft>, airspeed: f64) {
+        test_bed.command(|a| a.set_computed_airspeed(Velocity::new::<knot>(airspeed)));
+        test_bed.command(|a| a.set_radio_altitude(Length::new::<foot>(1000.)));
+        test_bed.run_with_delta(Duration::from_secs(11));
+    }
+
+    fn alert_discrete_words(
+        test_bed: &mut SimulationTestBed<EgpwcTestAircraft>,
+    ) -> (Arinc429Word<u32>, Arinc429Word<u32>) {
+        (
+            test_bed.read_by_name("EGPWS_ALERT_1_DISCRETE_WORD_1"),
+            test_bed.read_by_name("EGPWS_ALERT_1_DISCRETE_WORD_2"),
+        )
+    }
+
+    #[test]
+    fn alert_discrete_words_are_failure_warning_when_unpowered() {
+        let mut test_bed = SimulationTestBed::new(EgpwcTestAircraft::new);
+        test_bed.run();
+
+        let (word_1, word_2) = alert_discrete_words(&mut test_bed);
+        assert!(word_1.is_failure_warning());
+        assert!(word_2.is_failure_warning());
+    }
+
+    #[test]
+    fn no_alert_in_normal_descent() {
+        let mut test_bed = alert_test_bed();
+        test_bed.command(|a| a.set_radio_altitude(Length::new::<foot>(1000.)));
+        test_bed.command(|a| a.set_vertical_speed(Velocity::new::<foot_per_minute>(-700.)));
+        test_bed.run();
+
+        let (word_1, word_2) = alert_discrete_words(&mut test_bed);
+        assert!(word_1.is_normal_operation());
+        assert_eq!(word_1.value(), 0);
+        assert_eq!(word_2.value(), 0);
+    }
+
+    #[test]
+    fn mode_1_sink_rate_alert() {
+        let mut test_bed = alert_test_bed();
+        test_bed.command(|a| a.set_radio_altitude(Length::new::<foot>(1000.)));
+        test_bed.command(|a| a.set_vertical_speed(Velocity::new::<foot_per_minute>(-3000.)));
+        test_bed.run();
+
+        let (word_1, word_2) = alert_discrete_words(&mut test_bed);
+        assert!(word_1.get_bit(11));
+        assert!(!word_1.get_bit(12));
+        assert!(word_2.get_bit(12));
+        assert!(!word_2.get_bit(13));
+    }
+
+    #[test]
+    fn mode_1_pull_up_alert() {
+        let mut test_bed = alert_test_bed();
+        test_bed.command(|a| a.set_radio_altitude(Length::new::<foot>(500.)));
+        test_bed.command(|a| a.set_vertical_speed(Velocity::new::<foot_per_minute>(-3000.)));
+        test_bed.run();
+
+        let (word_1, word_2) = alert_discrete_words(&mut test_bed);
+        assert!(!word_1.get_bit(11));
+        assert!(word_1.get_bit(12));
+        assert!(word_2.get_bit(12));
+        assert!(word_2.get_bit(13));
+    }
+
+    #[test]
+    fn second_radio_altimeter_is_used_when_first_one_failed() {
+        let mut test_bed = alert_test_bed();
+        test_bed.command(|a| a.fail_radio_altimeter_1());
+        test_bed.command(|a| a.set_radio_altimeter_2_altitude(Length::new::<foot>(500.)));
+        test_bed.command(|a| a.set_vertical_speed(Velocity::new::<foot_per_minute>(-3000.)));
+        test_bed.run();
+
+        let (word_1, _) = alert_discrete_words(&mut test_bed);
+        assert!(word_1.get_bit(12));
+    }
+
+    #[test]
+    fn second_radio_altimeter_is_used_when_first_one_stopped_transmitting() {
+        let mut test_bed = alert_test_bed();
+        test_bed.command(|a| a.set_radio_altitude(Length::new::<foot>(3000.)));
+        test_bed.command(|a| a.stop_radio_altimeter_1_transmission());
+        test_bed.command(|a| a.set_radio_altimeter_2_altitude(Length::new::<foot>(500.)));
+        test_bed.command(|a| a.set_vertical_speed(Velocity::new::<foot_per_minute>(-3000.)));
+        test_bed.run();
+
+        let (word_1, _) = alert_discrete_words(&mut test_bed);
+        assert!(word_1.get_bit(12));
+    }
+
+    #[test]
+    fn transmits_alert_discrete_words_on_the_bus() {
+        let mut test_bed = alert_test_bed();
+        test_bed.command(|a| a.set_radio_altitude(Length::new::<foot>(500.)));
+        test_bed.command(|a| a.set_vertical_speed(Velocity::new::<foot_per_minute>(-3000.)));
+        test_bed.run();
+
+        let word_1 = test_bed
+            .query(|a| {
+                a.egpwc.bus().receive_discrete(
+                    EnhancedGroundProximityWarningComputer::ALERT_DISCRETE_WORD_1.label(),
+                )
+            })
+            .unwrap();
+        assert!(word_1.is_normal_operation());
+        assert!(word_1.get_bit(12));
+    }
+
+    #[test]
+    fn both_alert_outputs_carry_the_same_words() {
+        let mut test_bed = alert_test_bed();
+        test_bed.command(|a| a.set_radio_altitude(Length::new::<foot>(500.)));
+        test_bed.command(|a| a.set_vertical_speed(Velocity::new::<foot_per_minute>(-3000.)));
+        test_bed.run();
+
+        let output_1_word_1: f64 = test_bed.read_by_name("EGPWS_ALERT_1_DISCRETE_WORD_1");
+        let output_1_word_2: f64 = test_bed.read_by_name("EGPWS_ALERT_1_DISCRETE_WORD_2");
+        let output_2_word_1: f64 = test_bed.read_by_name("EGPWS_ALERT_2_DISCRETE_WORD_1");
+        let output_2_word_2: f64 = test_bed.read_by_name("EGPWS_ALERT_2_DISCRETE_WORD_2");
+        assert_eq!(output_1_word_1, output_2_word_1);
+        assert_eq!(output_1_word_2, output_2_word_2);
+    }
+
+    #[test]
+    fn writes_the_ground_and_approach_states() {
+        let mut test_bed = alert_test_bed();
+        test_bed.command(|a| a.set_radio_altitude(Length::new::<foot>(0.)));
+        test_bed.run();
+
+        let is_on_ground: bool = test_bed.read_by_name("GPWS_GROUND_STATE");
+        let is_in_approach: bool = test_bed.read_by_name("GPWS_APPROACH_STATE");
+        assert!(is_on_ground);
+        assert!(!is_in_approach);
+
+        establish_approach(&mut test_bed, 150.);
+
+        let is_on_ground: bool = test_bed.read_by_name("GPWS_GROUND_STATE");
+        let is_in_approach: bool = test_bed.read_by_name("GPWS_APPROACH_STATE");
+        assert!(!is_on_ground);
+        assert!(is_in_approach);
+    }
+
+    #[test]
+    fn stops_transmitting_when_unpowered() {
+        let mut test_bed = alert_test_bed();
+        test_bed.run();
+
+        test_bed.command(|a| a.set_elec_powered(false));
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!(test_bed
+            .query(|a| a
+                .egpwc
+                .bus()
+                .receive(EnhancedGroundProximityWarningComputer::ALERT_DISCRETE_WORD_1.label()))
+            .is_none());
+    }
+
+    #[test]
+    fn gpws_sys_off_inhibits_alerts() {
+        let mut test_bed = alert_test_bed();
+        test_bed.write_by_name("GPWS_SYS_OFF", true);
+        test_bed.command(|a| a.set_radio_altitude(Length::new::<foot>(500.)));
+        test_bed.command(|a| a.set_vertical_speed(Velocity::new::<foot_per_minute>(-3000.)));
+        test_bed.run();
+
+        let (word_1, word_2) = alert_discrete_words(&mut test_bed);
+        assert_eq!(word_1.value(), 0);
+        assert_eq!(word_2.value(), 0);
+    }
+
+    #[test]
+    fn no_alert_above_maximum_radio_altitude() {
+        let mut test_bed = alert_test_bed();
+        test_bed.command(|a| a.set_radio_altitude(Length::new::<foot>(2500.)));
+        test_bed.command(|a| a.set_vertical_speed(Velocity::new::<foot_per_minute>(-6000.)));
+        test_bed.run();
+
+        let (word_1, _) = alert_discrete_words(&mut test_bed);
+        assert_eq!(word_1.value(), 0);
+    }
+
+    #[test]
+    fn mode_2_terrain_alert_is_followed_by_pull_up() {
+        let mut test_bed = alert_test_bed();
+
+        // A terrain closure rate of 6000 ft/min.
+        let mut radio_altitude = 1500.;
+        let mut descend = |test_bed: &mut SimulationTestBed<EgpwcTestAircraft>, ticks: usize| {
+            for _ in 0..ticks {
+                radio_altitude -= 10.;
+                test_bed.command(|a| a.set_radio_altitude(Length::new::<foot>(radio_altitude)));
+                test_bed.run_with_delta(Duration::from_millis(100));
+            }
+        };
+
+        descend(&mut test_bed, 5);
+        let (word_1, word_2) = alert_discrete_words(&mut test_bed);
+        assert!(word_1.get_bit(13));
+        assert!(!word_1.get_bit(12));
+        assert!(word_2.get_bit(12));
+
+        descend(&mut test_bed, 20);
+        let (word_1, _) = alert_discrete_words(&mut test_bed);
+        assert!(!word_1.get_bit(13));
+        assert!(word_1.get_bit(12));
+    }
+
+    #[test]
+    fn mode_3_dont_sink_alert_after_altitude_loss() {
+        let mut test_bed = alert_test_bed();
+        test_bed.command(|a| a.set_radio_altitude(Length::new::<foot>(400.)));
+        test_bed.command(|a| a.set_altitude(Length::new::<foot>(1000.)));
+        test_bed.run();
+
+        test_bed.command(|a| a.set_radio_altitude(Length::new::<foot>(350.)));
+        test_bed.command(|a| a.set_altitude(Length::new::<foot>(950.)));
+        test_bed.command(|a| a.set_vertical_speed(Velocity::new::<foot_per_minute>(-500.)));
+        test_bed.run();
+
+        let (word_1, _) = alert_discrete_words(&mut test_bed);
+        assert!(word_1.get_bit(14));
+    }
+
+    #[test]
+    fn mode_3_is_not_active_when_climbing() {
+        let mut test_bed = alert_test_bed();
+        test_bed.command(|a| a.set_radio_altitude(Length::new::<foot>(400.)));
+        test_bed.command(|a| a.set_altitude(Length::new::<foot>(1000.)));
+        test_bed.run();
+
+        test_bed.command(|a| a.set_radio_altitude(Length::new::<foot>(350.)));
+        test_bed.command(|a| a.set_altitude(Length::new::<foot>(950.)));
+        test_bed.command(|a| a.set_vertical_speed(Velocity::new::<foot_per_minute>(500.)));
+        test_bed.run();
+
+        let (word_1, _) = alert_discrete_words(&mut test_bed);
+        assert!(!word_1.get_bit(14));
+    }
+
+    #[test]
+    fn mode_4_too_low_gear_alert() {
+        let mut test_bed = alert_test_bed();
+        establish_approach(&mut test_bed, 150.);
+
+        test_bed.command(|a| a.set_radio_altitude(Length::new::<foot>(400.)));
+        test_bed.run();
+
+        let (word_1, _) = alert_discrete_words(&mut test_bed);
+        assert!(word_1.get_bit(15));
+    }
+
+    #[test]
+    fn mode_4_too_low_flaps_alert() {
+        let mut test_bed = alert_test_bed();
+        test_bed.command(|a| a.gear_down());
+        establish_approach(&mut test_bed, 150.);
+
+        test_bed.command(|a| a.set_radio_altitude(Length::new::<foot>(200.)));
+        test_bed.run();
+
+        let (word_1, _) = alert_discrete_words(&mut test_bed);
+        assert!(word_1.get_bit(16));
+    }
+
+    #[test]
+    fn mode_4_no_alert_in_landing_configuration() {
+        let mut test_bed = alert_test_bed();
+        test_bed.command(|a| a.gear_down());
+        test_bed.command(|a| a.set_flaps_angle(Angle::new::<degree>(251.)));
+        establish_approach(&mut test_bed, 150.);
+
+        test_bed.command(|a| a.set_radio_altitude(Length::new::<foot>(200.)));
+        test_bed.run();
+
+        let (word_1, _) = alert_discrete_words(&mut test_bed);
+        assert!(!word_1.get_bit(16));
+    }
+
+    #[test]
+    fn flap_mode_off_inhibits_too_low_flaps_alert() {
+        let mut test_bed = alert_test_bed();
+        test_bed.write_by_name("GPWS_FLAP_OFF", true);
+        test_bed.command(|a| a.gear_down());
+        establish_approach(&mut test_bed, 150.);
+
+        test_bed.command(|a| a.set_radio_altitude(Length::new::<foot>(200.)));
+        test_bed.run();
+
+        let (word_1, _) = alert_discrete_words(&mut test_bed);
+        assert!(!word_1.get_bit(16));
+    }
+
+    #[test]
+    fn ldg_flap_3_considers_flaps_3_a_landing_configuration() {
+        let mut test_bed = alert_test_bed();
+        test_bed.write_by_name("GPWS_FLAPS3", true);
+        test_bed.command(|a| a.gear_down());
+        test_bed.command(|a| a.set_flaps_angle(Angle::new::<degree>(165.)));
+        establish_approach(&mut test_bed, 150.);
+
+        test_bed.command(|a| a.set_radio_altitude(Length::new::<foot>(200.)));
+        test_bed.run();
+
+        let (word_1, _) = alert_discrete_words(&mut test_bed);
+        assert!(!word_1.get_bit(16));
+    }
+
+    #[test]
+    fn mode_4_too_low_terrain_alert_after_takeoff() {
+        let mut test_bed = alert_test_bed();
+        test_bed.command(|a| a.set_computed_airspeed(Velocity::new::<knot>(200.)));
+        test_bed.command(|a| a.set_radio_altitude(Length::new::<foot>(600.)));
+        test_bed.run_with_delta(Duration::from_secs(11));
+
+        test_bed.command(|a| a.set_radio_altitude(Length::new::<foot>(400.)));
+        test_bed.run();
+
+        let (word_1, _) = alert_discrete_words(&mut test_bed);
+        assert!(word_1.get_bit(17));
+    }
+
+    #[test]
+    fn mode_5_glideslope_alert() {
+        let mut test_bed = alert_test_bed();
+        test_bed.command(|a| a.gear_down());
+        test_bed.command(|a| a.set_radio_altitude(Length::new::<foot>(800.)));
+        test_bed.write_by_name("RADIO_RECEIVER_GS_IS_VALID", true);
+        test_bed.write_by_name("RADIO_RECEIVER_GS_DEVIATION", -0.6);
+        test_bed.run();
+
+        let (word_1, word_2) = alert_discrete_words(&mut test_bed);
+        assert!(word_1.get_bit(18));
+        assert!(word_2.get_bit(11));
+        assert!(!word_2.get_bit(12));
+    }
+
+    #[test]
+    fn mode_5_is_not_active_above_glideslope() {
+        let mut test_bed = alert_test_bed();
+        test_bed.command(|a| a.gear_down());
+        test_bed.command(|a| a.set_radio_altitude(Length::new::<foot>(800.)));
+        test_bed.write_by_name("RADIO_RECEIVER_GS_IS_VALID", true);
+        test_bed.write_by_name("RADIO_RECEIVER_GS_DEVIATION", 0.6);
+        test_bed.run();
+
+        let (word_1, _) = alert_discrete_words(&mut test_bed);
+        assert!(!word_1.get_bit(18));
+    }
+
+    #[test]
+    fn gs_mode_off_inhibits_glideslope_alert() {
+        let mut test_bed = alert_test_bed();
+        test_bed.write_by_name("GPWS_GS_OFF", true);
+        test_bed.command(|a| a.gear_down());
+        test_bed.command(|a| a.set_radio_altitude(Length::new::<foot>(800.)));
+        test_bed.write_by_name("RADIO_RECEIVER_GS_IS_VALID", true);
+        test_bed.write_by_name("RADIO_RECEIVER_GS_DEVIATION", -0.6);
+        test_bed.run();
+
+        let (word_1, word_2) = alert_discrete_words(&mut test_bed);
+        assert!(!word_1.get_bit(18));
+        assert!(!word_2.get_bit(11));
+    }
 }
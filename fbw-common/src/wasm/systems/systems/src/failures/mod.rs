@@ -4,8 +4,9 @@ use crate::air_conditioning::{
 use crate::air_conditioning::{FdacId, OcsmId};
 use crate::integrated_modular_avionics::core_processing_input_output_module::CpiomId;
 use crate::shared::{
-    AirbusElectricPumpId, AirbusEngineDrivenPumpId, ElectricalBusType, FireDetectionLoopID,
-    FireDetectionZone, GearActuatorId, HydraulicColor, LgciuId, ProximityDetectorId,
+    AirbusElectricPumpId, AirbusEngineDrivenPumpId, ElectricalBusType, EngineBleedValveId,
+    FireDetectionLoopID, FireDetectionZone, GearActuatorId, HydraulicColor, LeakDetectionZone,
+    LgciuId, PotentialOrigin, ProbeType, ProximityDetectorId, WindowType,
 };
 use crate::simulation::SimulationElement;
 use fxhash::FxHashSet;
@@ -38,21 +39,36 @@ pub enum FailureType {
     BulkIsolValve,
     BulkExtractFan,
     CargoHeater,
+    AvionicsBlowerFan,
+    AvionicsExtractFan,
     // ATA24
     Generator(usize),
     ApuGenerator(usize),
+    GeneratorVoltageRegulator(PotentialOrigin),
+    GeneratorFeederFault(PotentialOrigin),
     TransformerRectifier(usize),
     StaticInverter,
     ElectricalBus(ElectricalBusType),
     // ATA26
     SetOnFire(FireDetectionZone),
     FireDetectionLoop(FireDetectionLoopID, FireDetectionZone),
+    // ATA28
+    FuelPump(usize),
+    FuelValve(usize),
+    FuelTankLeak(usize),
+    FuelQuantityIndication(usize),
+    FuelQuantityManagementSystem(usize),
     // ATA29
     ReservoirLeak(HydraulicColor),
     ReservoirAirLeak(HydraulicColor),
     ReservoirReturnLeak(HydraulicColor),
     EnginePumpOverheat(AirbusEngineDrivenPumpId),
     ElecPumpOverheat(AirbusElectricPumpId),
+    // ATA30
+    ProbeHeater(usize, ProbeType),
+    WindowHeater(usize, WindowType),
+    WindowHeatRegulation(usize),
+    EngineAntiIceValve(usize),
     // ATA32
     LgciuPowerSupply(LgciuId),
     LgciuInternalError(LgciuId),
@@ -64,6 +80,27 @@ pub enum FailureType {
     RadioAltimeter(usize),
     RadioAntennaInterrupted(usize),
     RadioAntennaDirectCoupling(usize),
+    InertialReference(usize),
+    GpsReceiver(usize),
+    // ATA35
+    CrewOxygenBottleLeak(usize),
+    CrewOxygenRegulatorLowPressure,
+    // ATA36
+    BleedAirLeak(LeakDetectionZone),
+    LeakDetectionLoop(LeakDetectionZone),
+    EngineBleedValveJammedOpen(usize, EngineBleedValveId),
+    EngineBleedValveJammedClosed(usize, EngineBleedValveId),
+    PrecoolerBlockage(usize),
+    EngineBleedOverpressure(usize),
+    EngineBleedOvertemperature(usize),
+    BleedMonitoringComputer(usize),
+    // ATA49
+    ApuStartMotor,
+    ApuFuelControlUnit,
+    ApuAirIntakeFlapJammed,
+    ApuEgtSensor,
+    ApuSpeedSensor(usize),
+    ApuInletPressureSensor,
 }
 
 pub struct Failure {
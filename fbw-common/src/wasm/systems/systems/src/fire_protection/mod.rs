@@ -0,0 +1,999 @@
+use std::time::Duration;
+
+use crate::{
+    apu::AuxiliaryPowerUnitFireOverheadPanel,
+    failures::{Failure, FailureType},
+    overhead::MomentaryPushButton,
+    shared::{
+        arinc429::{Arinc429Word, SignStatus},
+        random_from_range, DelayedTrueLogicGate, ElectricalBusType, ElectricalBuses,
+        EngineFirePushButtons, FireDetectionLoopID, FireDetectionZone, FireTestPushButtons,
+        LgciuWeightOnWheels,
+    },
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+    },
+};
+
+/// A push button which tests the fire detection and extinguishing systems.
+/// The test signal reaches the systems after a short delay.
+pub struct FireTestPushButton {
+    is_pressed_id: VariableIdentifier,
+    is_pressed: bool,
+    test_signal: DelayedTrueLogicGate,
+}
+impl FireTestPushButton {
+    // We add a delay between button press and response based on references
+    const DELAY_FIRE_TEST: Duration = Duration::from_millis(500);
+
+    pub fn new(context: &mut InitContext, variable_name: &str) -> Self {
+        Self {
+            is_pressed_id: context.get_identifier(variable_name.to_owned()),
+            is_pressed: false,
+            test_signal: DelayedTrueLogicGate::new(Self::DELAY_FIRE_TEST),
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext) {
+        self.test_signal.update(context, self.is_pressed);
+    }
+
+    pub fn is_pressed(&self) -> bool {
+        self.test_signal.output()
+    }
+}
+/// When used on its own, the push button tests all zones at once.
+impl FireTestPushButtons for FireTestPushButton {
+    fn is_pressed(&self, _zone: FireDetectionZone) -> bool {
+        self.is_pressed()
+    }
+}
+impl SimulationElement for FireTestPushButton {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.is_pressed = reader.read(&self.is_pressed_id);
+    }
+}
+
+/// The fire detection unit monitors the two fire detection loops of each zone.
+/// A fire is detected when both loops detect it, when one loop detects it while the other
+/// has failed, or when both loops fail within a short interval of each other.
+///
+/// The unit supports up to six zones, which are sent in the given order in its discrete word.
+pub struct FireDetectionUnit<const N: usize> {
+    fire_detection_loop: [FireDetectionLoop<N>; 2],
+
+    fire_detected_id: [VariableIdentifier; N],
+
+    fire_detected: [bool; N],
+    fire_detection_zones: [FireDetectionZone; N],
+    interval_between_loop_failures: [Duration; N],
+    apu_fire_on_ground: bool,
+    should_extinguish_apu_fire: DelayedTrueLogicGate,
+
+    // The FDU sends discrete signals to the overhead panel and arinc signals to the FWS
+    // Fixme: We assume a discrete word is sent, validate with references
+    discrete_word_id: VariableIdentifier,
+    discrete_word: Arinc429Word<u32>,
+}
+impl<const N: usize> FireDetectionUnit<N> {
+    const DELAY_APU_FIRE_EXTINGUISHING: Duration = Duration::from_secs(10);
+    const MAX_INTERVAL_BETWEEN_LOOP_FAILURES: Duration = Duration::from_secs(5);
+
+    pub fn new(
+        context: &mut InitContext,
+        fire_detection_zones: [FireDetectionZone; N],
+        loops_powered_by: [ElectricalBusType; 2],
+    ) -> Self {
+        Self {
+            fire_detection_loop: [
+                FireDetectionLoop::new(
+                    context,
+                    FireDetectionLoopID::A,
+                    &fire_detection_zones,
+                    loops_powered_by[0],
+                ),
+                FireDetectionLoop::new(
+                    context,
+                    FireDetectionLoopID::B,
+                    &fire_detection_zones,
+                    loops_powered_by[1],
+                ),
+            ],
+
+            fire_detected_id: fire_detection_zones.map(|zone| Self::init_identifier(context, zone)),
+
+            fire_detected: [false; N],
+            fire_detection_zones,
+            interval_between_loop_failures: [Duration::ZERO; N],
+            apu_fire_on_ground: false,
+            should_extinguish_apu_fire: DelayedTrueLogicGate::new(
+                Self::DELAY_APU_FIRE_EXTINGUISHING,
+            ),
+
+            discrete_word_id: context.get_identifier("FIRE_FDU_DISCRETE_WORD".to_owned()),
+            discrete_word: Arinc429Word::new(0, SignStatus::NoComputedData),
+        }
+    }
+
+    fn init_identifier(
+        context: &mut InitContext,
+        zone_id: FireDetectionZone,
+    ) -> VariableIdentifier {
+        if matches!(zone_id, FireDetectionZone::Engine(_)) {
+            context.get_identifier(format!("FIRE_DETECTED_ENG{}", zone_id))
+        } else {
+            context.get_identifier(format!("FIRE_DETECTED_{}", zone_id))
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        fire_test_push_buttons: &impl FireTestPushButtons,
+        lgciu: [&impl LgciuWeightOnWheels; 2],
+    ) {
+        let fire_test = self
+            .fire_detection_zones
+            .map(|zone| fire_test_push_buttons.is_pressed(zone));
+
+        self.interval_between_loop_failures = self.calculate_interval_between_failures(context);
+
+        self.fire_detected = self.fire_detection_determination(fire_test);
+
+        self.fire_detection_loop
+            .iter_mut()
+            .for_each(|l| l.update_was_powered());
+
+        // If a fire is detected in the APU while the aircraft is on the ground, the extinguishing system is automatically activated after a delay
+        self.apu_fire_on_ground = self.fire_detected(FireDetectionZone::Apu)
+            && !fire_test_push_buttons.is_pressed(FireDetectionZone::Apu)
+            && lgciu.iter().all(|a| a.left_and_right_gear_compressed(true));
+        self.should_extinguish_apu_fire
+            .update(context, self.apu_fire_on_ground);
+
+        self.update_discrete_word();
+    }
+
+    fn fire_detection_determination(&self, fire_test: [bool; N]) -> [bool; N] {
+        let mut fire_detected = [false; N];
+        for (((&zone, &interval_between_loop_failures), &fire_test_pb), fire_detected) in self
+            .fire_detection_zones
+            .iter()
+            .zip(&self.interval_between_loop_failures)
+            .zip(&fire_test)
+            .zip(&mut fire_detected)
+        {
+            *fire_detected = (self.fire_detection_loop[0]
+                .fire_detected_in_loop(zone, fire_test_pb)
+                && self.fire_detection_loop[1].fire_detected_in_loop(zone, fire_test_pb))
+                || (self
+                    .fire_detection_loop
+                    .iter()
+                    .any(|l| l.fire_detected_in_loop(zone, fire_test_pb))
+                    && self
+                        .fire_detection_loop
+                        .iter()
+                        .any(|l| l.loop_has_failed(zone)))
+                || (self
+                    .fire_detection_loop
+                    .iter()
+                    .all(|l| l.loop_has_failed(zone))
+                    && interval_between_loop_failures < Self::MAX_INTERVAL_BETWEEN_LOOP_FAILURES
+                    && !matches!(zone, FireDetectionZone::Mlg | FireDetectionZone::Cargo));
+        }
+        fire_detected
+    }
+
+    fn calculate_interval_between_failures(&self, context: &UpdateContext) -> [Duration; N] {
+        let mut interval = [Duration::ZERO; N];
+        for ((&zone, &interval_between_loop_failures), interval) in self
+            .fire_detection_zones
+            .iter()
+            .zip(&self.interval_between_loop_failures)
+            .zip(&mut interval)
+        {
+            *interval = if self
+                .fire_detection_loop
+                .iter()
+                .all(|l| !l.loop_has_failed(zone))
+            {
+                Duration::ZERO
+            } else if self
+                .fire_detection_loop
+                .iter()
+                .all(|l| l.loop_has_failed(zone))
+            {
+                interval_between_loop_failures
+            } else {
+                interval_between_loop_failures + context.delta()
+            }
+        }
+        interval
+    }
+
+    pub fn fire_detected(&self, zone: FireDetectionZone) -> bool {
+        self.fire_detection_zones
+            .iter()
+            .zip(self.fire_detected)
+            .any(|(&z, fire_detected)| z == zone && fire_detected)
+    }
+
+    pub fn should_extinguish_apu_fire(&self) -> bool {
+        self.should_extinguish_apu_fire.output()
+    }
+
+    pub fn apu_fire_on_ground(&self) -> bool {
+        self.apu_fire_on_ground
+    }
+
+    fn update_discrete_word(&mut self) {
+        // TODO: Add electrical supply for FDU, when not powered it should return NCD
+        self.discrete_word = Arinc429Word::new(0, SignStatus::NormalOperation);
+
+        // Fixme: The bit order is assumed as no references
+        // Bits 11 to 16 indicate a fire in each zone, bits 18 to 29 indicate the failure of
+        // loop A and B of each zone.
+        for (index, (&zone, fire_detected)) in self
+            .fire_detection_zones
+            .iter()
+            .zip(self.fire_detected)
+            .enumerate()
+        {
+            self.discrete_word.set_bit(11 + index as u8, fire_detected);
+            for (loop_index, fire_detection_loop) in self.fire_detection_loop.iter().enumerate() {
+                self.discrete_word.set_bit(
+                    18 + (2 * index + loop_index) as u8,
+                    fire_detection_loop.loop_has_failed(zone),
+                );
+            }
+        }
+    }
+}
+impl<const N: usize> SimulationElement for FireDetectionUnit<N> {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        for (id, fire_detected) in self.fire_detected_id.iter().zip(self.fire_detected) {
+            writer.write(id, fire_detected);
+        }
+        writer.write(&self.discrete_word_id, self.discrete_word);
+    }
+
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.fire_detection_loop, visitor);
+
+        visitor.visit(self);
+    }
+}
+
+struct FireDetectionLoop<const N: usize> {
+    loop_id: FireDetectionLoopID,
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+    was_powered_before: bool,
+    failures: [Failure; N],
+
+    fire_detectors: [FireDetector; N],
+}
+impl<const N: usize> FireDetectionLoop<N> {
+    fn new(
+        context: &mut InitContext,
+        loop_id: FireDetectionLoopID,
+        fire_detection_zones: &[FireDetectionZone; N],
+        powered_by: ElectricalBusType,
+    ) -> Self {
+        Self {
+            loop_id,
+            powered_by,
+            is_powered: false,
+            was_powered_before: false,
+            failures: fire_detection_zones
+                .map(|zone| Failure::new(FailureType::FireDetectionLoop(loop_id, zone))),
+
+            fire_detectors: fire_detection_zones.map(|zone| FireDetector::new(context, zone)),
+        }
+    }
+
+    fn fire_detected_in_loop(
+        &self,
+        fire_detection_zone: FireDetectionZone,
+        fire_test_pushbutton_is_pressed: bool,
+    ) -> bool {
+        !self.failure(fire_detection_zone).is_active()
+            && self.is_powered
+            && (self
+                .fire_detectors
+                .iter()
+                .find(|detector| fire_detection_zone == detector.zone_id())
+                .unwrap()
+                .fire_detected()
+                || fire_test_pushbutton_is_pressed)
+    }
+
+    fn loop_has_failed(&self, fire_detection_zone: FireDetectionZone) -> bool {
+        self.failure(fire_detection_zone).is_active()
+            || (!self.is_powered && self.was_powered_before)
+    }
+
+    fn failure(&self, fire_detection_zone: FireDetectionZone) -> &Failure {
+        self.failures
+            .iter()
+            .find(|&f| {
+                f.failure_type()
+                    == FailureType::FireDetectionLoop(self.loop_id, fire_detection_zone)
+            })
+            .unwrap()
+    }
+
+    /// This is to avoid a fire detection on initial load
+    fn update_was_powered(&mut self) {
+        self.was_powered_before = self.is_powered
+    }
+}
+impl<const N: usize> SimulationElement for FireDetectionLoop<N> {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.failures, visitor);
+        accept_iterable!(self.fire_detectors, visitor);
+        visitor.visit(self);
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+}
+
+// Electro-pneumatic fire detectors. There are multiple detectors in 3 fire zones per engine, one per pylon, one in the APU and one in the MLG
+// For simplicity here we simulate just one detection zone per engine, when we have deep engine simulation we can modify this accordingly
+struct FireDetector {
+    zone_id: FireDetectionZone,
+
+    fire_detection_id: VariableIdentifier,
+    fire_detected: bool,
+}
+impl FireDetector {
+    const ENGINE_ON_FIRE: &str = "ENG ON FIRE:";
+
+    fn new(context: &mut InitContext, fire_zone_id: FireDetectionZone) -> Self {
+        Self {
+            zone_id: fire_zone_id,
+
+            fire_detection_id: Self::init_identifier(context, fire_zone_id),
+            fire_detected: false,
+        }
+    }
+
+    fn init_identifier(
+        context: &mut InitContext,
+        zone_id: FireDetectionZone,
+    ) -> VariableIdentifier {
+        if matches!(zone_id, FireDetectionZone::Engine(_)) {
+            context.get_identifier(format!("{}{}", Self::ENGINE_ON_FIRE, zone_id))
+        } else {
+            context.get_identifier(format!("{}_ON_FIRE", zone_id))
+        }
+    }
+
+    fn zone_id(&self) -> FireDetectionZone {
+        self.zone_id
+    }
+
+    fn fire_detected(&self) -> bool {
+        self.fire_detected
+    }
+}
+impl SimulationElement for FireDetector {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.fire_detected = reader.read(&self.fire_detection_id);
+    }
+}
+
+/// The extinguishing agent bottles of an aircraft.
+///
+/// Engine bottles are armed by releasing the engine's fire push button and APU bottles by
+/// releasing the APU fire push button. Cargo bottles have no fire push button and are
+/// discharged directly by their agent push button.
+pub struct FireExtinguishingSystem<const N: usize> {
+    fire_extinguishing_bottles: [ExtinguishingAgentBottle; N],
+}
+impl<const N: usize> FireExtinguishingSystem<N> {
+    pub fn new(fire_extinguishing_bottles: [ExtinguishingAgentBottle; N]) -> Self {
+        Self {
+            fire_extinguishing_bottles,
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        engine_fire_push_buttons: &impl EngineFirePushButtons,
+        apu_fire_overhead: &AuxiliaryPowerUnitFireOverheadPanel,
+        fire_test_push_buttons: &impl FireTestPushButtons,
+        should_extinguish_apu_fire: bool,
+    ) {
+        for bottle in self.fire_extinguishing_bottles.iter_mut() {
+            let zone = bottle.zone();
+            let (is_armed, should_extinguish_fire) = match zone {
+                FireDetectionZone::Engine(number) => {
+                    (engine_fire_push_buttons.is_released(number), None)
+                }
+                FireDetectionZone::Apu => (
+                    apu_fire_overhead.fire_button_is_released(),
+                    Some(should_extinguish_apu_fire),
+                ),
+                FireDetectionZone::Cargo => (true, None),
+                FireDetectionZone::Mlg => (false, None),
+            };
+
+            bottle.update(
+                context,
+                is_armed,
+                fire_test_push_buttons.is_pressed(zone),
+                should_extinguish_fire,
+            );
+        }
+    }
+
+    pub fn discharged_bottle_count(&self, zone: FireDetectionZone) -> usize {
+        self.fire_extinguishing_bottles
+            .iter()
+            .filter(|bottle| bottle.zone() == zone && bottle.is_discharged())
+            .count()
+    }
+}
+impl<const N: usize> SimulationElement for FireExtinguishingSystem<N> {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.fire_extinguishing_bottles, visitor);
+
+        visitor.visit(self);
+    }
+}
+
+/// This struct represents the physical bottle of Halon 1301, and its possible states of armed, disarmed, full and empty
+/// There are two squibs per bottle, but for simplicity we simulate a "single" squib with two possible power sources
+pub struct ExtinguishingAgentBottle {
+    squib_armed_id: VariableIdentifier,
+    bottle_discharged_id: VariableIdentifier,
+    agent_pb: MomentaryPushButton,
+
+    zone: FireDetectionZone,
+    squib_is_armed: bool,
+    bottle_is_discharged: bool,
+    system_test: bool,
+
+    timer: Duration,
+    powered_by: [ElectricalBusType; 2],
+    is_powered: bool,
+}
+impl ExtinguishingAgentBottle {
+    const DISCHARGE_DELAY: Duration = Duration::from_secs(1);
+
+    pub fn new(
+        context: &mut InitContext,
+        id: &str,
+        zone: FireDetectionZone,
+        powered_by: [ElectricalBusType; 2],
+    ) -> Self {
+        Self {
+            squib_armed_id: context.get_identifier(format!("FIRE_SQUIB_{}_IS_ARMED", id)),
+            bottle_discharged_id: context
+                .get_identifier(format!("FIRE_SQUIB_{}_IS_DISCHARGED", id)),
+            agent_pb: MomentaryPushButton::new(context, &format!("FIRE_AGENT_{}", id)),
+
+            zone,
+            squib_is_armed: false,
+            bottle_is_discharged: false,
+            system_test: false,
+
+            timer: Duration::ZERO,
+            powered_by,
+            is_powered: false,
+        }
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        fire_push_button_is_released: bool,
+        fire_test_pushbutton_is_pressed: bool,
+        should_extinguish_fire: Option<bool>,
+    ) {
+        self.system_test = fire_test_pushbutton_is_pressed && self.is_powered;
+        self.squib_is_armed = self.is_powered && fire_push_button_is_released;
+        if self.is_powered
+            && ((self.squib_is_armed || should_extinguish_fire.unwrap_or(false))
+                && self.timer >= Self::DISCHARGE_DELAY)
+        {
+            // Once the bottle is discharged, it can't be recharged
+            self.bottle_is_discharged = true
+        } else if self.is_powered
+            && (((self.squib_is_armed)
+                && (self.agent_pb.is_pressed() || self.timer > Duration::ZERO))
+                || should_extinguish_fire.unwrap_or(false))
+            && self.timer <= Self::DISCHARGE_DELAY
+        {
+            self.timer += context.delta()
+        } else {
+            self.timer = Duration::ZERO
+        };
+    }
+
+    pub fn zone(&self) -> FireDetectionZone {
+        self.zone
+    }
+
+    pub fn is_discharged(&self) -> bool {
+        self.bottle_is_discharged
+    }
+}
+impl SimulationElement for ExtinguishingAgentBottle {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(
+            &self.squib_armed_id,
+            self.squib_is_armed || self.system_test,
+        );
+        writer.write(
+            &self.bottle_discharged_id,
+            self.bottle_is_discharged || self.system_test,
+        );
+    }
+
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.agent_pb.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = self.powered_by.iter().any(|&p| buses.is_powered(p));
+    }
+}
+
+/// Small module that sets each zone on fire when the failure is triggered. This is independent to the system implementation.
+pub struct SetOnFireModule<const N: usize> {
+    fire_id: [VariableIdentifier; N],
+
+    fire_detection_zones: [FireDetectionZone; N],
+    fire: [Failure; N],
+    should_set_zone_on_fire: [bool; N],
+    should_extinguish_zone: [bool; N],
+    // We use this to avoid having a previously discharged bottle extinguish a fire
+    discharged_bottle_count: [usize; N],
+    // We use this to know when to cancel the fire command when the failure is resolved
+    was_on_fire: [bool; N],
+}
+impl<const N: usize> SetOnFireModule<N> {
+    const EXTINGUISHING_PROBABILITY: f64 = 0.5;
+
+    pub fn new(context: &mut InitContext, fire_detection_zones: [FireDetectionZone; N]) -> Self {
+        Self {
+            fire_id: fire_detection_zones.map(|zone| Self::init_identifier(context, zone)),
+
+            fire_detection_zones,
+            fire: fire_detection_zones.map(|zone| Failure::new(FailureType::SetOnFire(zone))),
+            should_set_zone_on_fire: [false; N],
+            should_extinguish_zone: [false; N],
+            discharged_bottle_count: [0; N],
+            was_on_fire: [false; N],
+        }
+    }
+
+    fn init_identifier(
+        context: &mut InitContext,
+        zone_id: FireDetectionZone,
+    ) -> VariableIdentifier {
+        if matches!(zone_id, FireDetectionZone::Engine(_)) {
+            context.get_identifier(format!("ENG_{}_ON_FIRE", zone_id))
+        } else {
+            context.get_identifier(format!("{}_ON_FIRE", zone_id))
+        }
+    }
+
+    pub fn update<const M: usize>(
+        &mut self,
+        fire_extinguishing_system: &FireExtinguishingSystem<M>,
+    ) {
+        for id in 0..N {
+            self.should_set_zone_on_fire[id] = self.fire[id].is_active()
+                && !self.should_set_zone_on_fire[id]
+                && !self.was_on_fire[id]
+        }
+
+        let discharged_bottle_count = self
+            .fire_detection_zones
+            .map(|zone| fire_extinguishing_system.discharged_bottle_count(zone));
+        self.should_extinguish_zone =
+            self.zone_extinguishing_determination(discharged_bottle_count);
+        self.discharged_bottle_count = discharged_bottle_count;
+        self.was_on_fire = self.fire.each_ref().map(|f| f.is_active());
+    }
+
+    /// We check any "new" bottle discharges and then add a random factor on whether it should extinguish a fire
+    /// We also use this function to "extinguish" a fire if the user deselects the failure
+    fn zone_extinguishing_determination(&self, discharged_bottle_count: [usize; N]) -> [bool; N] {
+        let mut should_extinguish_zone = [false; N];
+        for (id, should_extinguish) in should_extinguish_zone.iter_mut().enumerate() {
+            *should_extinguish = (discharged_bottle_count[id] > self.discharged_bottle_count[id]
+                && random_from_range(0., 1.) < Self::EXTINGUISHING_PROBABILITY)
+                || (self.was_on_fire[id] && !self.fire[id].is_active());
+        }
+        should_extinguish_zone
+    }
+}
+impl<const N: usize> SimulationElement for SetOnFireModule<N> {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.fire, visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        for (id, zone) in self.fire_id.iter().enumerate() {
+            if self.should_set_zone_on_fire[id] {
+                writer.write(zone, true)
+            } else if self.should_extinguish_zone[id] {
+                writer.write(zone, false)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+        engine::EngineFireOverheadPanel,
+        shared::PotentialOrigin,
+        simulation::{
+            test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+            Aircraft,
+        },
+    };
+
+    struct TestLgciu {
+        compressed: bool,
+    }
+    impl LgciuWeightOnWheels for TestLgciu {
+        fn left_and_right_gear_compressed(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            self.compressed
+        }
+        fn right_gear_compressed(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            self.compressed
+        }
+        fn right_gear_extended(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            !self.compressed
+        }
+        fn left_gear_compressed(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            self.compressed
+        }
+        fn left_gear_extended(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            !self.compressed
+        }
+        fn left_and_right_gear_extended(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            !self.compressed
+        }
+        fn nose_gear_compressed(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            self.compressed
+        }
+        fn nose_gear_extended(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            !self.compressed
+        }
+    }
+
+    const ZONES: [FireDetectionZone; 3] = [
+        FireDetectionZone::Engine(1),
+        FireDetectionZone::Apu,
+        FireDetectionZone::Cargo,
+    ];
+
+    struct TestAircraft {
+        fire_test_pushbutton: FireTestPushButton,
+        fire_detection_unit: FireDetectionUnit<3>,
+        fire_extinguishing_system: FireExtinguishingSystem<4>,
+        set_zone_on_fire: SetOnFireModule<3>,
+        engine_fire_overhead: EngineFireOverheadPanel<1>,
+        apu_fire_overhead: AuxiliaryPowerUnitFireOverheadPanel,
+        lgciu: TestLgciu,
+
+        powered_source: TestElectricitySource,
+        dc_ess_bus: ElectricalBus,
+        dc_2_bus: ElectricalBus,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            let powered_by = [
+                ElectricalBusType::DirectCurrentEssential,
+                ElectricalBusType::DirectCurrent(2),
+            ];
+
+            Self {
+                fire_test_pushbutton: FireTestPushButton::new(context, "FIRE_TEST"),
+                fire_detection_unit: FireDetectionUnit::new(context, ZONES, powered_by),
+                fire_extinguishing_system: FireExtinguishingSystem::new([
+                    ExtinguishingAgentBottle::new(
+                        context,
+                        "1_ENG_1",
+                        FireDetectionZone::Engine(1),
+                        powered_by,
+                    ),
+                    ExtinguishingAgentBottle::new(
+                        context,
+                        "2_ENG_1",
+                        FireDetectionZone::Engine(1),
+                        powered_by,
+                    ),
+                    ExtinguishingAgentBottle::new(
+                        context,
+                        "1_APU_1",
+                        FireDetectionZone::Apu,
+                        powered_by,
+                    ),
+                    ExtinguishingAgentBottle::new(
+                        context,
+                        "1_CARGO_1",
+                        FireDetectionZone::Cargo,
+                        powered_by,
+                    ),
+                ]),
+                set_zone_on_fire: SetOnFireModule::new(context, ZONES),
+                engine_fire_overhead: EngineFireOverheadPanel::new(context),
+                apu_fire_overhead: AuxiliaryPowerUnitFireOverheadPanel::new(context),
+                lgciu: TestLgciu { compressed: false },
+
+                powered_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::Battery(1),
+                ),
+                dc_ess_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrentEssential),
+                dc_2_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(2)),
+            }
+        }
+
+        fn set_on_ground(&mut self, on_ground: bool) {
+            self.lgciu.compressed = on_ground;
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _context: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            electricity.supplied_by(&self.powered_source);
+            electricity.flow(&self.powered_source, &self.dc_ess_bus);
+            electricity.flow(&self.powered_source, &self.dc_2_bus);
+        }
+
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.fire_test_pushbutton.update(context);
+            self.fire_detection_unit.update(
+                context,
+                &self.fire_test_pushbutton,
+                [&self.lgciu, &self.lgciu],
+            );
+            self.fire_extinguishing_system.update(
+                context,
+                &self.engine_fire_overhead,
+                &self.apu_fire_overhead,
+                &self.fire_test_pushbutton,
+                self.fire_detection_unit.should_extinguish_apu_fire(),
+            );
+            self.set_zone_on_fire
+                .update(&self.fire_extinguishing_system);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<V: SimulationElementVisitor>(&mut self, visitor: &mut V) {
+            self.fire_test_pushbutton.accept(visitor);
+            self.fire_detection_unit.accept(visitor);
+            self.fire_extinguishing_system.accept(visitor);
+            self.set_zone_on_fire.accept(visitor);
+            self.engine_fire_overhead.accept(visitor);
+            self.apu_fire_overhead.accept(visitor);
+            self.dc_ess_bus.accept(visitor);
+            self.dc_2_bus.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed() -> SimulationTestBed<TestAircraft> {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.run();
+
+        test_bed
+    }
+
+    fn is_set(test_bed: &mut SimulationTestBed<TestAircraft>, name: &str) -> bool {
+        test_bed.read_by_name(name)
+    }
+
+    fn discrete_word(test_bed: &mut SimulationTestBed<TestAircraft>) -> Arinc429Word<u32> {
+        test_bed.read_by_name("FIRE_FDU_DISCRETE_WORD")
+    }
+
+    #[test]
+    fn fire_is_detected_in_each_zone() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("ENG ON FIRE:1", true);
+        test_bed.write_by_name("CARGO_ON_FIRE", true);
+        test_bed.run();
+
+        assert!(is_set(&mut test_bed, "FIRE_DETECTED_ENG1"));
+        assert!(!is_set(&mut test_bed, "FIRE_DETECTED_APU"));
+        assert!(is_set(&mut test_bed, "FIRE_DETECTED_CARGO"));
+    }
+
+    #[test]
+    fn fire_is_detected_by_a_single_loop_when_the_other_has_failed() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::FireDetectionLoop(
+            FireDetectionLoopID::B,
+            FireDetectionZone::Apu,
+        ));
+        test_bed.run_with_delta(Duration::from_secs(10));
+        test_bed.write_by_name("APU_ON_FIRE", true);
+        test_bed.run();
+
+        assert!(is_set(&mut test_bed, "FIRE_DETECTED_APU"));
+    }
+
+    #[test]
+    fn failing_both_loops_simultaneously_triggers_fire_detection_except_for_cargo() {
+        let mut test_bed = test_bed();
+        for loop_id in [FireDetectionLoopID::A, FireDetectionLoopID::B] {
+            test_bed.fail(FailureType::FireDetectionLoop(
+                loop_id,
+                FireDetectionZone::Engine(1),
+            ));
+            test_bed.fail(FailureType::FireDetectionLoop(
+                loop_id,
+                FireDetectionZone::Cargo,
+            ));
+        }
+        test_bed.run();
+
+        assert!(is_set(&mut test_bed, "FIRE_DETECTED_ENG1"));
+        assert!(!is_set(&mut test_bed, "FIRE_DETECTED_CARGO"));
+    }
+
+    #[test]
+    fn discrete_word_contains_fire_and_loop_failure_of_each_zone() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::FireDetectionLoop(
+            FireDetectionLoopID::B,
+            FireDetectionZone::Apu,
+        ));
+        test_bed.run_with_delta(Duration::from_secs(10));
+        test_bed.write_by_name("ENG ON FIRE:1", true);
+        test_bed.run();
+
+        let word = discrete_word(&mut test_bed);
+        assert!(word.is_normal_operation());
+        assert!(word.get_bit(11));
+        assert!(!word.get_bit(12));
+        assert!(!word.get_bit(13));
+        assert!(!word.get_bit(18));
+        assert!(!word.get_bit(19));
+        assert!(!word.get_bit(20));
+        assert!(word.get_bit(21));
+    }
+
+    #[test]
+    fn fire_test_detects_fire_in_all_zones_after_a_delay() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("FIRE_TEST", true);
+        test_bed.run_with_delta(Duration::from_millis(100));
+
+        assert!(!is_set(&mut test_bed, "FIRE_DETECTED_ENG1"));
+
+        test_bed.run_with_delta(Duration::from_millis(500));
+
+        assert!(is_set(&mut test_bed, "FIRE_DETECTED_ENG1"));
+        assert!(is_set(&mut test_bed, "FIRE_DETECTED_APU"));
+        assert!(is_set(&mut test_bed, "FIRE_DETECTED_CARGO"));
+        assert!(is_set(&mut test_bed, "FIRE_SQUIB_1_CARGO_1_IS_DISCHARGED"));
+    }
+
+    #[test]
+    fn releasing_the_engine_fire_push_button_arms_the_engine_bottles_only() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("FIRE_BUTTON_ENG1", true);
+        test_bed.run();
+
+        assert!(is_set(&mut test_bed, "FIRE_SQUIB_1_ENG_1_IS_ARMED"));
+        assert!(is_set(&mut test_bed, "FIRE_SQUIB_2_ENG_1_IS_ARMED"));
+        assert!(!is_set(&mut test_bed, "FIRE_SQUIB_1_APU_1_IS_ARMED"));
+    }
+
+    #[test]
+    fn releasing_the_apu_fire_push_button_arms_the_apu_bottle() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("FIRE_BUTTON_APU", true);
+        test_bed.run();
+
+        assert!(is_set(&mut test_bed, "FIRE_SQUIB_1_APU_1_IS_ARMED"));
+        assert!(!is_set(&mut test_bed, "FIRE_SQUIB_1_ENG_1_IS_ARMED"));
+    }
+
+    #[test]
+    fn armed_bottle_discharges_after_agent_push_button_is_pressed() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("FIRE_BUTTON_ENG1", true);
+        test_bed.write_by_name("OVHD_FIRE_AGENT_2_ENG_1_IS_PRESSED", true);
+        test_bed.run_with_delta(Duration::from_millis(500));
+        test_bed.write_by_name("OVHD_FIRE_AGENT_2_ENG_1_IS_PRESSED", false);
+        test_bed.run_with_delta(Duration::from_millis(600));
+        test_bed.run();
+
+        assert!(!is_set(&mut test_bed, "FIRE_SQUIB_1_ENG_1_IS_DISCHARGED"));
+        assert!(is_set(&mut test_bed, "FIRE_SQUIB_2_ENG_1_IS_DISCHARGED"));
+        assert_eq!(
+            test_bed.query(|a| a
+                .fire_extinguishing_system
+                .discharged_bottle_count(FireDetectionZone::Engine(1))),
+            1
+        );
+    }
+
+    #[test]
+    fn unarmed_bottle_does_not_discharge() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("OVHD_FIRE_AGENT_1_APU_1_IS_PRESSED", true);
+        test_bed.run_with_delta(Duration::from_secs(2));
+        test_bed.run();
+
+        assert!(!is_set(&mut test_bed, "FIRE_SQUIB_1_APU_1_IS_DISCHARGED"));
+    }
+
+    #[test]
+    fn cargo_bottle_discharges_without_fire_push_button() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("OVHD_FIRE_AGENT_1_CARGO_1_IS_PRESSED", true);
+        test_bed.run_with_delta(Duration::from_millis(600));
+        test_bed.run_with_delta(Duration::from_millis(600));
+        test_bed.run();
+
+        assert!(is_set(&mut test_bed, "FIRE_SQUIB_1_CARGO_1_IS_DISCHARGED"));
+    }
+
+    #[test]
+    fn apu_fire_on_ground_discharges_apu_bottle_automatically() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_on_ground(true));
+        test_bed.write_by_name("APU_ON_FIRE", true);
+        test_bed.run();
+
+        assert!(test_bed.query(|a| a.fire_detection_unit.apu_fire_on_ground()));
+
+        test_bed.run_with_delta(Duration::from_secs(10));
+        test_bed.run_with_delta(Duration::from_secs(1));
+        test_bed.run();
+
+        assert!(is_set(&mut test_bed, "FIRE_SQUIB_1_APU_1_IS_DISCHARGED"));
+    }
+
+    #[test]
+    fn apu_fire_in_flight_does_not_discharge_apu_bottle() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("APU_ON_FIRE", true);
+        test_bed.run_with_delta(Duration::from_secs(15));
+        test_bed.run();
+
+        assert!(!test_bed.query(|a| a.fire_detection_unit.apu_fire_on_ground()));
+        assert!(!is_set(&mut test_bed, "FIRE_SQUIB_1_APU_1_IS_DISCHARGED"));
+    }
+
+    #[test]
+    fn set_on_fire_failure_sets_and_resolves_zone_fire() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::SetOnFire(FireDetectionZone::Cargo));
+        test_bed.run();
+
+        assert!(is_set(&mut test_bed, "CARGO_ON_FIRE"));
+
+        test_bed.unfail(FailureType::SetOnFire(FireDetectionZone::Cargo));
+        test_bed.run();
+
+        assert!(!is_set(&mut test_bed, "CARGO_ON_FIRE"));
+    }
+}
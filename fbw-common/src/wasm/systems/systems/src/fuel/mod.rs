@@ -1,5 +1,6 @@
 use crate::{
-    shared::{ConsumePower, ElectricalBusType, ElectricalBuses},
+    failures::{Failure, FailureType},
+    shared::{ConsumePower, ElectricalBusType, ElectricalBuses, InternationalStandardAtmosphere},
     simulation::{
         InitContext, Read, Reader, SimulationElement, SimulationElementVisitor, SimulatorReader,
         SimulatorWriter, UpdateContext, VariableIdentifier, Write, Writer,
@@ -7,7 +8,10 @@ use crate::{
 };
 use nalgebra::Vector3;
 use num_traits::Zero;
-use uom::si::{electric_current::ampere, f64::*, mass::kilogram};
+use uom::si::{
+    electric_current::ampere, f64::*, length::foot, mass::kilogram, mass_rate::kilogram_per_hour,
+    pressure::psi, ratio::ratio,
+};
 
 pub const FUEL_GALLONS_TO_KG: f64 = 3.039075693483925;
 
@@ -59,23 +63,37 @@ impl FuelInfo<'_> {
             context,
             self.fuel_tank_id,
             Vector3::new(self.position.0, self.position.1, self.position.2),
+            Mass::new::<kilogram>(self.total_capacity_gallons * FUEL_GALLONS_TO_KG),
             write,
         )
     }
 }
 
+/// A fuel tank. When written, the quantity is owned by the tank and only taken over from the
+/// simulator when it was changed externally, for example by refuelling through the EFB.
+/// Otherwise the quantity is read from the simulator every frame.
 #[derive(Debug)]
 pub struct FuelTank {
     fuel_id: VariableIdentifier,
     location: Vector3<f64>,
+    capacity: Mass,
     quantity: Mass,
     write: bool,
 }
 impl FuelTank {
-    pub fn new(context: &mut InitContext, id: &str, location: Vector3<f64>, write: bool) -> Self {
+    const EXTERNAL_CHANGE_THRESHOLD_KG: f64 = 0.01;
+
+    pub fn new(
+        context: &mut InitContext,
+        id: &str,
+        location: Vector3<f64>,
+        capacity: Mass,
+        write: bool,
+    ) -> Self {
         FuelTank {
             fuel_id: context.get_identifier(id.to_owned()),
             location,
+            capacity,
             quantity: Mass::default(),
             write,
         }
@@ -85,6 +103,10 @@ impl FuelTank {
         self.location
     }
 
+    pub fn capacity(&self) -> Mass {
+        self.capacity
+    }
+
     pub fn quantity(&self) -> Mass {
         self.quantity
     }
@@ -92,11 +114,36 @@ impl FuelTank {
     pub fn set_quantity(&mut self, quantity: Mass) {
         self.quantity = quantity;
     }
+
+    /// Removes up to the given mass of fuel, returning the mass actually removed.
+    pub fn remove_fuel(&mut self, mass: Mass) -> Mass {
+        let removed = mass.max(Mass::default()).min(self.quantity);
+        self.quantity -= removed;
+
+        removed
+    }
+
+    /// Adds up to the given mass of fuel, returning the mass actually added.
+    pub fn add_fuel(&mut self, mass: Mass) -> Mass {
+        let added = mass
+            .max(Mass::default())
+            .min((self.capacity - self.quantity).max(Mass::default()));
+        self.quantity += added;
+
+        added
+    }
 }
 impl SimulationElement for FuelTank {
     fn read(&mut self, reader: &mut SimulatorReader) {
         let volume: f64 = reader.read(&self.fuel_id);
-        self.quantity = Mass::new::<kilogram>(volume * FUEL_GALLONS_TO_KG);
+        let simulator_quantity = Mass::new::<kilogram>(volume * FUEL_GALLONS_TO_KG);
+
+        if !self.write
+            || (simulator_quantity - self.quantity).abs().get::<kilogram>()
+                > Self::EXTERNAL_CHANGE_THRESHOLD_KG
+        {
+            self.quantity = simulator_quantity;
+        }
     }
 
     fn write(&self, writer: &mut SimulatorWriter) {
@@ -113,6 +160,27 @@ impl SimulationElement for FuelTank {
     }
 }
 
+/// A pump pressurising an engine feed line, drawing fuel from the given tank.
+#[derive(Clone, Copy, Debug)]
+pub struct FeedPump {
+    /// Index into the fuel system's pumps.
+    pub pump: usize,
+    /// Index into the fuel system's tanks.
+    pub tank: usize,
+    /// Pumps with a higher priority deliver a higher pressure and thus supply the feed line
+    /// before pumps with a lower priority do.
+    pub priority: u8,
+}
+
+/// Describes how an engine is supplied with fuel.
+#[derive(Clone, Copy, Debug)]
+pub struct EngineFeed {
+    pub pumps: &'static [FeedPump],
+    /// The tank from which the engine sucks fuel when none of the pumps available to its
+    /// feed line delivers pressure.
+    pub suction_tank: usize,
+}
+
 pub struct FuelSystem<const N: usize, const PUMP_COUNT: usize> {
     unlimited_fuel_id: VariableIdentifier,
     unlimited_fuel: bool,
@@ -122,8 +190,18 @@ pub struct FuelSystem<const N: usize, const PUMP_COUNT: usize> {
 
     fuel_tanks: [FuelTank; N],
     fuel_pumps: [FuelPump; PUMP_COUNT],
+    tank_leak_failures: [Failure; N],
 }
 impl<const N: usize, const PUMP_COUNT: usize> FuelSystem<N, PUMP_COUNT> {
+    const TANK_LEAK_RATE_KG_PER_HOUR: f64 = 3000.;
+
+    // Above this altitude dissolved air escapes the fuel and suction feed can no longer
+    // be relied upon.
+    const SUCTION_FEED_CEILING_FEET: f64 = 15000.;
+
+    const FEED_PUMP_DELIVERY_PRESSURE_PSI: f64 = 25.;
+    const SUCTION_FEED_PRESSURE_PSI: f64 = 2.;
+
     pub fn new(
         context: &mut InitContext,
         fuel_tanks: [FuelTank; N],
@@ -136,6 +214,103 @@ impl<const N: usize, const PUMP_COUNT: usize> FuelSystem<N, PUMP_COUNT> {
             fuel_total_weight: Mass::default(),
             fuel_tanks,
             fuel_pumps,
+            // Tank leaks are numbered from one, in the order in which the tanks are given.
+            tank_leak_failures: std::array::from_fn(|t| {
+                Failure::new(FailureType::FuelTankLeak(t + 1))
+            }),
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext) {
+        let leaked_mass = MassRate::new::<kilogram_per_hour>(Self::TANK_LEAK_RATE_KG_PER_HOUR)
+            * context.delta_as_time();
+        for t in 0..N {
+            if self.tank_leak_failures[t].is_active() {
+                self.remove_fuel(t, leaked_mass);
+            }
+        }
+    }
+
+    /// Supplies the engines with the fuel they demand. Each engine draws from the highest
+    /// priority running pumps available to its feed line, which includes the pumps of all other
+    /// feed lines that are connected to the crossfeed manifold. Without pump pressure an engine
+    /// sucks fuel from its suction tank, as long as the aircraft is low enough.
+    ///
+    /// Returns the fuel pressure in each engine's feed line, which is zero when the line
+    /// isn't supplied with fuel.
+    pub fn feed_engines<const E: usize>(
+        &mut self,
+        context: &UpdateContext,
+        feeds: &[EngineFeed; E],
+        connected_to_crossfeed: [bool; E],
+        demands: [MassRate; E],
+    ) -> [Pressure; E] {
+        let mut line_pressure = [Pressure::default(); E];
+        for (engine, feed) in feeds.iter().enumerate() {
+            let available_pumps: Vec<FeedPump> = feeds
+                .iter()
+                .enumerate()
+                .filter(|(other, _)| {
+                    *other == engine
+                        || (connected_to_crossfeed[engine] && connected_to_crossfeed[*other])
+                })
+                .flat_map(|(_, other_feed)| other_feed.pumps.iter().copied())
+                .filter(|feed_pump| {
+                    self.fuel_pumps[feed_pump.pump].is_running()
+                        && self.tank_has_fuel(feed_pump.tank)
+                })
+                .collect();
+
+            let demand = demands[engine].max(MassRate::default()) * context.delta_as_time();
+            if let Some(priority) = available_pumps.iter().map(|p| p.priority).max() {
+                let supplying_pumps: Vec<&FeedPump> = available_pumps
+                    .iter()
+                    .filter(|p| p.priority == priority)
+                    .collect();
+                let share = demand / supplying_pumps.len() as f64;
+                for feed_pump in supplying_pumps {
+                    self.remove_fuel(feed_pump.tank, share);
+                }
+
+                line_pressure[engine] = Pressure::new::<psi>(Self::FEED_PUMP_DELIVERY_PRESSURE_PSI);
+            } else if Self::suction_feed_is_possible(context)
+                && self.tank_has_fuel(feed.suction_tank)
+            {
+                self.remove_fuel(feed.suction_tank, demand);
+
+                line_pressure[engine] = Pressure::new::<psi>(Self::SUCTION_FEED_PRESSURE_PSI);
+            }
+        }
+
+        line_pressure
+    }
+
+    fn suction_feed_is_possible(context: &UpdateContext) -> bool {
+        context.ambient_pressure()
+            > InternationalStandardAtmosphere::pressure_at_altitude(Length::new::<foot>(
+                Self::SUCTION_FEED_CEILING_FEET,
+            ))
+    }
+
+    /// Moves fuel from one tank to another at the given rate, limited by the
+    /// fuel available and the space left in the receiving tank.
+    pub fn transfer_fuel(
+        &mut self,
+        context: &UpdateContext,
+        from: usize,
+        to: usize,
+        rate: MassRate,
+    ) {
+        let space_left =
+            (self.fuel_tanks[to].capacity() - self.fuel_tanks[to].quantity()).max(Mass::default());
+        let transferred =
+            self.fuel_tanks[from].remove_fuel((rate * context.delta_as_time()).min(space_left));
+        self.fuel_tanks[to].add_fuel(transferred);
+    }
+
+    fn remove_fuel(&mut self, t: usize, mass: Mass) {
+        if !self.unlimited_fuel {
+            self.fuel_tanks[t].remove_fuel(mass);
         }
     }
 
@@ -147,10 +322,18 @@ impl<const N: usize, const PUMP_COUNT: usize> FuelSystem<N, PUMP_COUNT> {
         self.unlimited_fuel || !self.fuel_tanks[t].quantity().is_zero()
     }
 
+    pub fn tank_is_full(&self, t: usize) -> bool {
+        self.fuel_tanks[t].quantity() >= self.fuel_tanks[t].capacity()
+    }
+
     pub fn set_tank_quantity(&mut self, t: usize, quantity: Mass) {
         self.fuel_tanks[t].set_quantity(quantity);
     }
 
+    pub fn pump_is_running(&self, p: usize) -> bool {
+        self.fuel_pumps[p].is_running()
+    }
+
     pub fn center_of_gravity(&self) -> Vector3<f64> {
         let positions = self.fuel_tanks.iter().map(|t| t.location());
         let masses = self.fuel_tanks.iter().map(|t| t.quantity());
@@ -176,6 +359,7 @@ impl<const N: usize, const PUMP_COUNT: usize> SimulationElement for FuelSystem<N
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         accept_iterable!(self.fuel_tanks, visitor);
         accept_iterable!(self.fuel_pumps, visitor);
+        accept_iterable!(self.tank_leak_failures, visitor);
         visitor.visit(self);
     }
 
@@ -185,34 +369,49 @@ impl<const N: usize, const PUMP_COUNT: usize> SimulationElement for FuelSystem<N
     }
 }
 
-#[derive(Debug)]
+/// An electrically driven fuel pump. It runs when switched on and powered.
 pub struct FuelPump {
-    pump_id: VariableIdentifier,
+    pump_switch_id: VariableIdentifier,
     properties: FuelPumpProperties,
     available_potential: ElectricPotential,
-    running: bool,
+    is_powered: bool,
+    is_switched_on: bool,
+    failure: Failure,
 }
 impl FuelPump {
     pub fn new(context: &mut InitContext, id: usize, properties: FuelPumpProperties) -> Self {
         Self {
-            pump_id: context.get_identifier(format!("FUELSYSTEM PUMP ACTIVE:{id}")),
+            pump_switch_id: context.get_identifier(format!("FUELSYSTEM PUMP SWITCH:{id}")),
             properties,
             available_potential: ElectricPotential::default(),
-            running: false,
+            is_powered: false,
+            is_switched_on: false,
+            failure: Failure::new(FailureType::FuelPump(id)),
         }
     }
+
+    pub fn is_running(&self) -> bool {
+        self.is_switched_on && self.is_powered && !self.failure.is_active()
+    }
 }
 impl SimulationElement for FuelPump {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn read(&mut self, reader: &mut SimulatorReader) {
-        self.running = reader.read(&self.pump_id);
+        self.is_switched_on = reader.read(&self.pump_switch_id);
     }
 
     fn receive_power(&mut self, buses: &impl ElectricalBuses) {
         self.available_potential = buses.potential_of(self.properties.powered_by).raw();
+        self.is_powered = buses.is_powered(self.properties.powered_by);
     }
 
     fn consume_power<T: ConsumePower>(&mut self, _: &UpdateContext, power: &mut T) {
-        let consumed_power = if self.running {
+        let consumed_power = if self.is_running() {
             self.available_potential
                 * ElectricCurrent::new::<ampere>(self.properties.consumption_current_ampere)
         } else {
@@ -221,3 +420,63 @@ impl SimulationElement for FuelPump {
         power.consume_from_bus(self.properties.powered_by, consumed_power);
     }
 }
+
+/// A motor driven fuel valve, such as a crossfeed, transfer or engine LP valve.
+/// Without power, or when jammed, the valve stays where it is.
+pub struct FuelValve {
+    open_amount_id: VariableIdentifier,
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+    open_amount: Ratio,
+    failure: Failure,
+}
+impl FuelValve {
+    const TRAVEL_TIME_SECONDS: f64 = 2.;
+
+    pub fn new(context: &mut InitContext, id: usize, powered_by: ElectricalBusType) -> Self {
+        Self {
+            open_amount_id: context.get_identifier(format!("FUEL_VALVE_{id}_OPEN_AMOUNT")),
+            powered_by,
+            is_powered: false,
+            open_amount: Ratio::default(),
+            failure: Failure::new(FailureType::FuelValve(id)),
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext, should_open: bool) {
+        if !self.is_powered || self.failure.is_active() {
+            return;
+        }
+
+        let travel = context.delta_as_secs_f64() / Self::TRAVEL_TIME_SECONDS;
+        let open_amount = self.open_amount.get::<ratio>();
+        self.open_amount = Ratio::new::<ratio>(if should_open {
+            (open_amount + travel).min(1.)
+        } else {
+            (open_amount - travel).max(0.)
+        });
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open_amount.get::<ratio>() > 0.5
+    }
+
+    pub fn open_amount(&self) -> Ratio {
+        self.open_amount
+    }
+}
+impl SimulationElement for FuelValve {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.open_amount_id, self.open_amount);
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+}
@@ -2,7 +2,7 @@ use crate::{
     shared::{ConsumePower, ControllerSignal, ElectricalBusType, ElectricalBuses},
     simulation::{
         InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
-        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+        SimulatorWriter, SnapshotReader, SnapshotWriter, UpdateContext, VariableIdentifier, Write,
     },
 };
 use std::f64::consts::PI;
@@ -294,6 +294,17 @@ impl SimulationElement for Brake {
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.temperature_id, self.temperature);
     }
+
+    fn save_state(&self, writer: &mut SnapshotWriter) {
+        writer.write(&self.temperature_id, self.temperature);
+    }
+
+    fn restore_state(&mut self, reader: &mut SnapshotReader) {
+        if let Some(temperature) = reader.restore(&self.temperature_id) {
+            self.temperature = temperature;
+            self.initialized = true;
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -427,7 +438,7 @@ mod tests {
     use super::*;
     use crate::simulation::{
         test::{ReadByName, SimulationTestBed, TestBed},
-        Aircraft,
+        Aircraft, StartState,
     };
     use uom::si::{
         length::meter,
@@ -523,6 +534,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn brake_temperature_is_restored_from_snapshot() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.run_without_delta();
+        let hot_temperature = ThermodynamicTemperature::new::<degree_celsius>(300.);
+        test_bed.command(|a| a.set_brake_temperature(hot_temperature));
+        let snapshot = test_bed.snapshot();
+
+        let mut test_bed =
+            SimulationTestBed::new_from_snapshot(StartState::Apron, TestAircraft::new, &snapshot);
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(15.));
+        test_bed.run_without_delta();
+
+        let brake_temperature: ThermodynamicTemperature =
+            test_bed.read_by_name("BRAKE_TEMPERATURE_0");
+        assert_eq!(brake_temperature, hot_temperature);
+    }
+
     #[test]
     fn brake_with_parking_brake() {
         let mut test_bed = SimulationTestBed::new(TestAircraft::new);
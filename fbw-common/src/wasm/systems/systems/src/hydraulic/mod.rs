@@ -15,7 +15,7 @@ use crate::shared::{
 };
 use crate::simulation::{
     InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
-    SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+    SimulatorWriter, SnapshotReader, SnapshotWriter, UpdateContext, VariableIdentifier, Write,
 };
 use nalgebra::Vector3;
 
@@ -2214,6 +2214,7 @@ impl FluidPhysics {
 
 pub struct Reservoir {
     level_id: VariableIdentifier,
+    content_id: VariableIdentifier,
     low_level_id: VariableIdentifier,
     low_air_press_id: VariableIdentifier,
     overheating_id: VariableIdentifier,
@@ -2262,6 +2263,7 @@ impl Reservoir {
     ) -> Self {
         Self {
             level_id: context.get_identifier(format!("HYD_{}_RESERVOIR_LEVEL", hyd_loop_id)),
+            content_id: context.get_identifier(format!("HYD_{}_RESERVOIR_CONTENT", hyd_loop_id)),
             low_level_id: context
                 .get_identifier(format!("HYD_{}_RESERVOIR_LEVEL_IS_LOW", hyd_loop_id)),
             low_air_press_id: context
@@ -2428,6 +2430,16 @@ impl SimulationElement for Reservoir {
         writer.write(&self.low_air_press_id, self.is_low_air_pressure());
         writer.write(&self.overheating_id, self.is_overheating());
     }
+
+    fn save_state(&self, writer: &mut SnapshotWriter) {
+        writer.write(&self.content_id, self.current_level);
+    }
+
+    fn restore_state(&mut self, reader: &mut SnapshotReader) {
+        if let Some(level) = reader.restore::<Volume>(&self.content_id) {
+            self.current_level = level.max(Volume::default()).min(self.max_capacity);
+        }
+    }
 }
 impl PressurizeableReservoir for Reservoir {
     fn available_volume(&self) -> Volume {
@@ -0,0 +1,407 @@
+use crate::{
+    icing_state::{ActiveDeicingController, IcingState, PassiveIcingElement},
+    simulation::{
+        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
+        VariableIdentifier, Write,
+    },
+};
+use std::time::Duration;
+use uom::si::{
+    f64::*,
+    ratio::{percent, ratio},
+};
+
+/// Ice accreting on the wing leading edges, the tail and the engine air inlets, and its effect
+/// on the aerodynamics and the engines. The penalties are written as factors for the flight
+/// model to apply, where a factor of 1 means that the surface is clean.
+///
+/// The wings and engine inlets are deiced by the wing and engine anti-ice systems. The tail
+/// isn't protected and only sheds its ice naturally.
+pub struct AirframeIcing<const ENGINES: usize> {
+    wings: [IcingState; 2],
+    tail: IcingState,
+    engine_inlets: [IcingState; ENGINES],
+
+    lift_factor_id: VariableIdentifier,
+    drag_factor_id: VariableIdentifier,
+    stall_margin_factor_id: VariableIdentifier,
+    engine_vibration_ids: [VariableIdentifier; ENGINES],
+    engine_n1_loss_ids: [VariableIdentifier; ENGINES],
+}
+impl<const ENGINES: usize> AirframeIcing<ENGINES> {
+    const WING_TIME_TO_FULLY_ICED: Duration = Duration::from_secs(600);
+    const WING_TIME_TO_PASSIVE_FULLY_DEICED: Duration = Duration::from_secs(900);
+    const WING_TIME_TO_ACTIVE_FULLY_DEICED: Duration = Duration::from_secs(120);
+
+    const TAIL_TIME_TO_FULLY_ICED: Duration = Duration::from_secs(900);
+    const TAIL_TIME_TO_PASSIVE_FULLY_DEICED: Duration = Duration::from_secs(1200);
+
+    const ENGINE_INLET_TIME_TO_FULLY_ICED: Duration = Duration::from_secs(300);
+    const ENGINE_INLET_TIME_TO_PASSIVE_FULLY_DEICED: Duration = Duration::from_secs(600);
+    const ENGINE_INLET_TIME_TO_ACTIVE_FULLY_DEICED: Duration = Duration::from_secs(60);
+
+    // Penalties of a fully iced surface.
+    const WING_LIFT_LOSS: f64 = 0.2;
+    const TAIL_LIFT_LOSS: f64 = 0.05;
+    const WING_DRAG_INCREASE: f64 = 0.6;
+    const TAIL_DRAG_INCREASE: f64 = 0.2;
+    const WING_STALL_MARGIN_LOSS: f64 = 0.3;
+
+    // Fan blade ice unbalances the fan and obstructs the inlet.
+    const MAX_ENGINE_VIBRATION_UNITS: f64 = 4.;
+    const MAX_ENGINE_N1_LOSS_PERCENT: f64 = 5.;
+
+    pub fn new(context: &mut InitContext) -> Self {
+        Self {
+            wings: [1, 2].map(|number| {
+                IcingState::new(
+                    context,
+                    &format!("WING_{}", number),
+                    Self::WING_TIME_TO_FULLY_ICED,
+                    Self::WING_TIME_TO_PASSIVE_FULLY_DEICED,
+                    Some(Self::WING_TIME_TO_ACTIVE_FULLY_DEICED),
+                )
+            }),
+            tail: IcingState::new(
+                context,
+                "TAIL",
+                Self::TAIL_TIME_TO_FULLY_ICED,
+                Self::TAIL_TIME_TO_PASSIVE_FULLY_DEICED,
+                None,
+            ),
+            engine_inlets: std::array::from_fn(|index| {
+                IcingState::new(
+                    context,
+                    &format!("ENGINE_INLET_{}", index + 1),
+                    Self::ENGINE_INLET_TIME_TO_FULLY_ICED,
+                    Self::ENGINE_INLET_TIME_TO_PASSIVE_FULLY_DEICED,
+                    Some(Self::ENGINE_INLET_TIME_TO_ACTIVE_FULLY_DEICED),
+                )
+            }),
+
+            lift_factor_id: context.get_identifier("AIRFRAME_ICING_LIFT_FACTOR".to_owned()),
+            drag_factor_id: context.get_identifier("AIRFRAME_ICING_DRAG_FACTOR".to_owned()),
+            stall_margin_factor_id: context
+                .get_identifier("AIRFRAME_ICING_STALL_MARGIN_FACTOR".to_owned()),
+            engine_vibration_ids: std::array::from_fn(|index| {
+                context.get_identifier(format!("ENGINE_ICING_{}_VIBRATION", index + 1))
+            }),
+            engine_n1_loss_ids: std::array::from_fn(|index| {
+                context.get_identifier(format!("ENGINE_ICING_{}_N1_LOSS", index + 1))
+            }),
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        wing_anti_ice: &[impl ActiveDeicingController; 2],
+        engine_anti_ice: &[impl ActiveDeicingController; ENGINES],
+    ) {
+        for (wing, deicing) in self.wings.iter_mut().zip(wing_anti_ice) {
+            wing.update(context, Some(deicing));
+        }
+
+        self.tail.update(context, None::<&PassiveIcingElement>);
+
+        for (engine_inlet, deicing) in self.engine_inlets.iter_mut().zip(engine_anti_ice) {
+            engine_inlet.update(context, Some(deicing));
+        }
+    }
+
+    fn wing_icing(&self) -> f64 {
+        self.wings
+            .iter()
+            .map(|wing| wing.icing_state_normalized().get::<ratio>())
+            .sum::<f64>()
+            / self.wings.len() as f64
+    }
+
+    fn tail_icing(&self) -> f64 {
+        self.tail.icing_state_normalized().get::<ratio>()
+    }
+
+    fn engine_inlet_icing(&self, engine_number: usize) -> f64 {
+        self.engine_inlets[engine_number - 1]
+            .icing_state_normalized()
+            .get::<ratio>()
+    }
+
+    pub fn lift_factor(&self) -> Ratio {
+        Ratio::new::<ratio>(
+            1. - Self::WING_LIFT_LOSS * self.wing_icing()
+                - Self::TAIL_LIFT_LOSS * self.tail_icing(),
+        )
+    }
+
+    pub fn drag_factor(&self) -> Ratio {
+        Ratio::new::<ratio>(
+            1. + Self::WING_DRAG_INCREASE * self.wing_icing()
+                + Self::TAIL_DRAG_INCREASE * self.tail_icing(),
+        )
+    }
+
+    /// The factor applied to the stall angle of attack.
+    pub fn stall_margin_factor(&self) -> Ratio {
+        Ratio::new::<ratio>(1. - Self::WING_STALL_MARGIN_LOSS * self.wing_icing())
+    }
+
+    /// The N1 vibration in units, which is added to the vibration of a clean fan.
+    pub fn engine_vibration(&self, engine_number: usize) -> f64 {
+        Self::MAX_ENGINE_VIBRATION_UNITS * self.engine_inlet_icing(engine_number)
+    }
+
+    pub fn engine_n1_loss(&self, engine_number: usize) -> Ratio {
+        Ratio::new::<percent>(
+            Self::MAX_ENGINE_N1_LOSS_PERCENT * self.engine_inlet_icing(engine_number),
+        )
+    }
+}
+impl<const ENGINES: usize> SimulationElement for AirframeIcing<ENGINES> {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.wings, visitor);
+        self.tail.accept(visitor);
+        accept_iterable!(self.engine_inlets, visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.lift_factor_id, self.lift_factor().get::<ratio>());
+        writer.write(&self.drag_factor_id, self.drag_factor().get::<ratio>());
+        writer.write(
+            &self.stall_margin_factor_id,
+            self.stall_margin_factor().get::<ratio>(),
+        );
+
+        for (engine_number, (vibration_id, n1_loss_id)) in self
+            .engine_vibration_ids
+            .iter()
+            .zip(&self.engine_n1_loss_ids)
+            .enumerate()
+        {
+            writer.write(vibration_id, self.engine_vibration(engine_number + 1));
+            writer.write(
+                n1_loss_id,
+                self.engine_n1_loss(engine_number + 1).get::<percent>(),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::test::{ReadByName, SimulationTestBed, TestBed, WriteByName};
+    use crate::simulation::{Aircraft, StartState};
+    use uom::si::{thermodynamic_temperature::degree_celsius, velocity::knot};
+
+    #[derive(Default)]
+    struct TestAntiIce {
+        is_on: bool,
+    }
+    impl ActiveDeicingController for TestAntiIce {
+        fn active_deicing_normalized_rate(&self) -> Ratio {
+            if self.is_on {
+                Ratio::new::<ratio>(1.)
+            } else {
+                Ratio::default()
+            }
+        }
+    }
+
+    struct TestAircraft {
+        icing: AirframeIcing<2>,
+        wing_anti_ice: [TestAntiIce; 2],
+        engine_anti_ice: [TestAntiIce; 2],
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                icing: AirframeIcing::new(context),
+                wing_anti_ice: Default::default(),
+                engine_anti_ice: Default::default(),
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.icing
+                .update(context, &self.wing_anti_ice, &self.engine_anti_ice);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.icing.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    struct AirframeIcingTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl AirframeIcingTestBed {
+        fn new() -> Self {
+            let mut test_bed = Self {
+                test_bed: SimulationTestBed::new_with_start_state(
+                    StartState::Cruise,
+                    TestAircraft::new,
+                ),
+            };
+            test_bed.set_true_airspeed(Velocity::new::<knot>(250.));
+            test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(-10.));
+
+            test_bed
+        }
+
+        fn and(self) -> Self {
+            self
+        }
+
+        fn in_icing_conditions(mut self) -> Self {
+            self.write_by_name("AMBIENT IN CLOUD", true);
+            self
+        }
+
+        fn out_of_icing_conditions(mut self) -> Self {
+            self.write_by_name("AMBIENT IN CLOUD", false);
+            self.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(10.));
+            self
+        }
+
+        fn wing_anti_ice_on(mut self) -> Self {
+            self.command(|a| a.wing_anti_ice.iter_mut().for_each(|wai| wai.is_on = true));
+            self
+        }
+
+        fn engine_anti_ice_on(mut self, engine_number: usize) -> Self {
+            self.command(|a| a.engine_anti_ice[engine_number - 1].is_on = true);
+            self
+        }
+
+        fn run_for(mut self, duration: Duration) -> Self {
+            self.test_bed.run_multiple_frames(duration);
+            self
+        }
+
+        fn lift_factor(&mut self) -> f64 {
+            self.read_by_name("AIRFRAME_ICING_LIFT_FACTOR")
+        }
+
+        fn drag_factor(&mut self) -> f64 {
+            self.read_by_name("AIRFRAME_ICING_DRAG_FACTOR")
+        }
+
+        fn stall_margin_factor(&mut self) -> f64 {
+            self.read_by_name("AIRFRAME_ICING_STALL_MARGIN_FACTOR")
+        }
+
+        fn engine_vibration(&mut self, engine_number: usize) -> f64 {
+            self.read_by_name(&format!("ENGINE_ICING_{}_VIBRATION", engine_number))
+        }
+
+        fn engine_n1_loss(&mut self, engine_number: usize) -> f64 {
+            self.read_by_name(&format!("ENGINE_ICING_{}_N1_LOSS", engine_number))
+        }
+    }
+    impl TestBed for AirframeIcingTestBed {
+        type Aircraft = TestAircraft;
+
+        fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            &mut self.test_bed
+        }
+    }
+
+    fn test_bed() -> AirframeIcingTestBed {
+        AirframeIcingTestBed::new()
+    }
+
+    fn test_bed_with() -> AirframeIcingTestBed {
+        test_bed()
+    }
+
+    #[test]
+    fn clean_airframe_has_no_penalties() {
+        let mut test_bed = test_bed().run_for(Duration::from_secs(600));
+
+        assert_eq!(test_bed.lift_factor(), 1.);
+        assert_eq!(test_bed.drag_factor(), 1.);
+        assert_eq!(test_bed.stall_margin_factor(), 1.);
+        assert_eq!(test_bed.engine_vibration(1), 0.);
+        assert_eq!(test_bed.engine_n1_loss(1), 0.);
+    }
+
+    #[test]
+    fn unprotected_airframe_is_penalised_in_icing_conditions() {
+        let mut test_bed = test_bed_with()
+            .in_icing_conditions()
+            .run_for(Duration::from_secs(900));
+
+        assert!(test_bed.lift_factor() < 0.8);
+        assert!(test_bed.drag_factor() > 1.6);
+        assert!(test_bed.stall_margin_factor() < 0.75);
+    }
+
+    #[test]
+    fn unprotected_engines_vibrate_and_lose_n1_in_icing_conditions() {
+        let mut test_bed = test_bed_with()
+            .in_icing_conditions()
+            .run_for(Duration::from_secs(600));
+
+        assert!(test_bed.engine_vibration(1) > 3.);
+        assert!(test_bed.engine_n1_loss(2) > 4.);
+    }
+
+    #[test]
+    fn wing_anti_ice_keeps_the_wing_clear() {
+        let mut test_bed = test_bed_with()
+            .wing_anti_ice_on()
+            .and()
+            .in_icing_conditions()
+            .run_for(Duration::from_secs(900));
+
+        assert_eq!(test_bed.stall_margin_factor(), 1.);
+        // The unprotected tail still accretes some ice.
+        assert!(test_bed.lift_factor() > 0.94);
+    }
+
+    #[test]
+    fn engine_anti_ice_only_protects_its_own_engine() {
+        let mut test_bed = test_bed_with()
+            .engine_anti_ice_on(1)
+            .and()
+            .in_icing_conditions()
+            .run_for(Duration::from_secs(600));
+
+        assert_eq!(test_bed.engine_vibration(1), 0.);
+        assert!(test_bed.engine_vibration(2) > 3.);
+    }
+
+    #[test]
+    fn late_wing_anti_ice_removes_accreted_ice() {
+        let mut test_bed = test_bed_with()
+            .in_icing_conditions()
+            .run_for(Duration::from_secs(900))
+            .wing_anti_ice_on()
+            .run_for(Duration::from_secs(120));
+
+        assert_eq!(test_bed.stall_margin_factor(), 1.);
+    }
+
+    #[test]
+    fn ice_melts_naturally_above_freezing() {
+        let mut test_bed = test_bed_with()
+            .in_icing_conditions()
+            .run_for(Duration::from_secs(900))
+            .out_of_icing_conditions()
+            .run_for(Duration::from_secs(1800));
+
+        assert_eq!(test_bed.lift_factor(), 1.);
+        assert_eq!(test_bed.engine_vibration(1), 0.);
+    }
+}
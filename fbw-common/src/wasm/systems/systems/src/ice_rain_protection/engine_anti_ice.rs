@@ -0,0 +1,334 @@
+use crate::{
+    failures::{Failure, FailureType},
+    icing_state::ActiveDeicingController,
+    shared::{ElectricalBusType, ElectricalBuses, EngineCorrectedN2},
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+    },
+};
+use std::time::Duration;
+use uom::si::{
+    f64::*,
+    ratio::{percent, ratio},
+};
+
+/// The ENG ANTI ICE pushbutton of one engine.
+pub struct EngineAntiIcePushButton {
+    is_on_id: VariableIdentifier,
+    is_on: bool,
+}
+impl EngineAntiIcePushButton {
+    pub fn new(context: &mut InitContext, number: usize) -> Self {
+        Self {
+            is_on_id: context
+                .get_identifier(format!("BUTTON_OVHD_ANTI_ICE_ENG_{}_POSITION", number)),
+            is_on: false,
+        }
+    }
+
+    pub fn is_on(&self) -> bool {
+        self.is_on
+    }
+}
+impl SimulationElement for EngineAntiIcePushButton {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.is_on = reader.read(&self.is_on_id);
+    }
+}
+
+/// The valve supplying HP compressor air to the engine air inlet lip.
+///
+/// The valve is electrically controlled and pneumatically operated. Its solenoid holds it
+/// closed, so the valve opens when the pushbutton is ON or when the solenoid loses power,
+/// provided that the engine is running to supply air. The FAULT light comes on during
+/// valve transit and when the valve position disagrees with the pushbutton.
+pub struct EngineAntiIceValve {
+    failure: Failure,
+    is_open_id: VariableIdentifier,
+    fault_id: VariableIdentifier,
+
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+
+    open_amount: Ratio,
+    has_fault: bool,
+}
+impl EngineAntiIceValve {
+    const ENGINE_RUNNING_N2_PERCENT: f64 = 50.;
+    const TRAVEL_TIME: Duration = Duration::from_secs(4);
+
+    pub fn new(context: &mut InitContext, number: usize, powered_by: ElectricalBusType) -> Self {
+        Self {
+            failure: Failure::new(FailureType::EngineAntiIceValve(number)),
+            is_open_id: context.get_identifier(format!("ENG_ANTI_ICE_{}_VALVE_OPEN", number)),
+            fault_id: context.get_identifier(format!("ENG_ANTI_ICE_{}_FAULT", number)),
+
+            powered_by,
+            is_powered: false,
+
+            open_amount: Ratio::default(),
+            has_fault: false,
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        push_button: &EngineAntiIcePushButton,
+        engine: &impl EngineCorrectedN2,
+    ) {
+        let engine_is_running =
+            engine.corrected_n2() > Ratio::new::<percent>(Self::ENGINE_RUNNING_N2_PERCENT);
+        let solenoid_allows_opening = push_button.is_on() || !self.is_powered;
+
+        let target_open_amount =
+            if solenoid_allows_opening && engine_is_running && !self.failure.is_active() {
+                Ratio::new::<ratio>(1.)
+            } else {
+                Ratio::default()
+            };
+
+        let max_travel =
+            Ratio::new::<ratio>(context.delta_as_secs_f64() / Self::TRAVEL_TIME.as_secs_f64());
+        self.open_amount += (target_open_amount - self.open_amount)
+            .max(-max_travel)
+            .min(max_travel);
+
+        let is_in_transit =
+            self.open_amount > Ratio::default() && self.open_amount < Ratio::new::<ratio>(1.);
+        self.has_fault =
+            engine_is_running && (is_in_transit || push_button.is_on() != self.is_open());
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open_amount >= Ratio::new::<ratio>(1.)
+    }
+
+    pub fn has_fault(&self) -> bool {
+        self.has_fault
+    }
+}
+impl ActiveDeicingController for EngineAntiIceValve {
+    fn active_deicing_normalized_rate(&self) -> Ratio {
+        self.open_amount
+    }
+}
+impl SimulationElement for EngineAntiIceValve {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.is_open_id, self.is_open());
+        writer.write(&self.fault_id, self.has_fault);
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::electrical::{test::TestElectricitySource, ElectricalBus, Electricity};
+    use crate::shared::PotentialOrigin;
+    use crate::simulation::test::{ReadByName, SimulationTestBed, TestBed, WriteByName};
+    use crate::simulation::Aircraft;
+    use uom::si::electric_potential::volt;
+
+    struct TestEngine {
+        corrected_n2: Ratio,
+    }
+    impl EngineCorrectedN2 for TestEngine {
+        fn corrected_n2(&self) -> Ratio {
+            self.corrected_n2
+        }
+    }
+
+    struct TestAircraft {
+        electricity_source: TestElectricitySource,
+        dc_1_bus: ElectricalBus,
+        push_button: EngineAntiIcePushButton,
+        valve: EngineAntiIceValve,
+        engine: TestEngine,
+        is_dc_1_powered: bool,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                electricity_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::EngineGenerator(1),
+                ),
+                dc_1_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(1)),
+                push_button: EngineAntiIcePushButton::new(context, 1),
+                valve: EngineAntiIceValve::new(context, 1, ElectricalBusType::DirectCurrent(1)),
+                engine: TestEngine {
+                    corrected_n2: Ratio::new::<percent>(60.),
+                },
+                is_dc_1_powered: true,
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            self.electricity_source
+                .power_with_potential(ElectricPotential::new::<volt>(28.));
+            electricity.supplied_by(&self.electricity_source);
+
+            if self.is_dc_1_powered {
+                electricity.flow(&self.electricity_source, &self.dc_1_bus);
+            }
+        }
+
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.valve.update(context, &self.push_button, &self.engine);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.dc_1_bus.accept(visitor);
+            self.push_button.accept(visitor);
+            self.valve.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    struct EngineAntiIceTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl EngineAntiIceTestBed {
+        fn new() -> Self {
+            Self {
+                test_bed: SimulationTestBed::new(TestAircraft::new),
+            }
+        }
+
+        fn and(self) -> Self {
+            self
+        }
+
+        fn push_button_on(mut self) -> Self {
+            self.write_by_name("BUTTON_OVHD_ANTI_ICE_ENG_1_POSITION", true);
+            self
+        }
+
+        fn engine_stopped(mut self) -> Self {
+            self.command(|a| a.engine.corrected_n2 = Ratio::default());
+            self
+        }
+
+        fn solenoid_unpowered(mut self) -> Self {
+            self.command(|a| a.is_dc_1_powered = false);
+            self
+        }
+
+        fn failed_valve(mut self) -> Self {
+            self.fail(FailureType::EngineAntiIceValve(1));
+            self
+        }
+
+        fn run_for(mut self, duration: Duration) -> Self {
+            self.test_bed.run_multiple_frames(duration);
+            self
+        }
+
+        fn valve_is_open(&mut self) -> bool {
+            self.read_by_name("ENG_ANTI_ICE_1_VALVE_OPEN")
+        }
+
+        fn has_fault(&mut self) -> bool {
+            self.read_by_name("ENG_ANTI_ICE_1_FAULT")
+        }
+    }
+    impl TestBed for EngineAntiIceTestBed {
+        type Aircraft = TestAircraft;
+
+        fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            &mut self.test_bed
+        }
+    }
+
+    fn test_bed() -> EngineAntiIceTestBed {
+        EngineAntiIceTestBed::new()
+    }
+
+    fn test_bed_with() -> EngineAntiIceTestBed {
+        test_bed()
+    }
+
+    #[test]
+    fn valve_is_closed_when_push_button_is_off() {
+        let mut test_bed = test_bed().run_for(Duration::from_secs(10));
+
+        assert!(!test_bed.valve_is_open());
+        assert!(!test_bed.has_fault());
+    }
+
+    #[test]
+    fn valve_opens_when_push_button_is_on() {
+        let mut test_bed = test_bed_with()
+            .push_button_on()
+            .run_for(Duration::from_secs(10));
+
+        assert!(test_bed.valve_is_open());
+        assert!(!test_bed.has_fault());
+    }
+
+    #[test]
+    fn fault_is_shown_during_valve_transit() {
+        let mut test_bed = test_bed_with()
+            .push_button_on()
+            .run_for(Duration::from_secs(1));
+
+        assert!(!test_bed.valve_is_open());
+        assert!(test_bed.has_fault());
+    }
+
+    #[test]
+    fn valve_stays_closed_when_engine_is_stopped() {
+        let mut test_bed = test_bed_with()
+            .push_button_on()
+            .and()
+            .engine_stopped()
+            .run_for(Duration::from_secs(10));
+
+        assert!(!test_bed.valve_is_open());
+        assert!(!test_bed.has_fault());
+    }
+
+    #[test]
+    fn valve_opens_when_solenoid_loses_power() {
+        let mut test_bed = test_bed_with()
+            .solenoid_unpowered()
+            .run_for(Duration::from_secs(10));
+
+        assert!(test_bed.valve_is_open());
+        assert!(test_bed.has_fault());
+    }
+
+    #[test]
+    fn failed_valve_stays_closed_and_shows_fault() {
+        let mut test_bed = test_bed_with()
+            .failed_valve()
+            .and()
+            .push_button_on()
+            .run_for(Duration::from_secs(10));
+
+        assert!(!test_bed.valve_is_open());
+        assert!(test_bed.has_fault());
+    }
+}
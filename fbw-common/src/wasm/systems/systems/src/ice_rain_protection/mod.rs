@@ -0,0 +1,37 @@
+use crate::simulation::{
+    InitContext, Read, SimulationElement, SimulatorReader, VariableIdentifier,
+};
+
+pub mod airframe_icing;
+pub mod engine_anti_ice;
+pub mod probe_heat;
+pub mod window_heat;
+
+/// The PROBE/WINDOW HEAT pushbutton. In AUTO the probes and windows are heated
+/// when the aircraft is in flight or an engine is running. When ON, they are
+/// heated at all times.
+pub struct ProbeWindowHeatPushButton {
+    is_on_id: VariableIdentifier,
+    is_on: bool,
+}
+impl ProbeWindowHeatPushButton {
+    pub fn new(context: &mut InitContext) -> Self {
+        Self {
+            is_on_id: context.get_identifier("MAN_PITOT_HEAT".to_owned()),
+            is_on: false,
+        }
+    }
+
+    pub fn is_on(&self) -> bool {
+        self.is_on
+    }
+
+    pub fn is_auto(&self) -> bool {
+        !self.is_on
+    }
+}
+impl SimulationElement for ProbeWindowHeatPushButton {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.is_on = reader.read(&self.is_on_id);
+    }
+}
@@ -0,0 +1,645 @@
+use super::ProbeWindowHeatPushButton;
+use crate::{
+    failures::{Failure, FailureType},
+    icing_state::{ActiveDeicingController, IcingState},
+    shared::{
+        AirDataProbes, ConsumePower, ElectricalBusType, ElectricalBuses, EngineCorrectedN2,
+        LgciuWeightOnWheels, ProbeType,
+    },
+    simulation::{
+        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
+        VariableIdentifier, Write,
+    },
+};
+use std::time::Duration;
+use uom::si::{
+    f64::*,
+    power::watt,
+    ratio::{percent, ratio},
+    thermodynamic_temperature::{degree_celsius, kelvin},
+    velocity::{knot, meter_per_second},
+};
+
+/// A probe with an electrical heater element. The temperature of the element results from
+/// the heater power and the heat carried away by the air flowing around the probe.
+struct HeatedProbe {
+    probe_type: ProbeType,
+    failure: Failure,
+    heat_fault_id: VariableIdentifier,
+
+    temperature: ThermodynamicTemperature,
+    heater_power: Power,
+    has_heat_fault: bool,
+}
+impl HeatedProbe {
+    const THERMAL_CAPACITY_JOULE_PER_KELVIN: f64 = 500.;
+    const STILL_AIR_HEAT_TRANSFER_WATT_PER_KELVIN: f64 = 2.;
+    const HEAT_TRANSFER_WATT_PER_KELVIN_PER_KNOT: f64 = 0.05;
+    const AIR_SPECIFIC_HEAT_JOULE_PER_KILOGRAM_KELVIN: f64 = 1005.;
+
+    /// Temperature rise of the element above freezing at which ice melts at the highest rate.
+    const FULL_DEICING_TEMPERATURE_RISE_KELVIN: f64 = 10.;
+
+    fn new(context: &mut InitContext, number: usize, probe_type: ProbeType) -> Self {
+        Self {
+            probe_type,
+            failure: Failure::new(FailureType::ProbeHeater(number, probe_type)),
+            heat_fault_id: context
+                .get_identifier(format!("PHC_{}_{}_HEAT_FAULT", number, probe_type)),
+
+            temperature: ThermodynamicTemperature::new::<degree_celsius>(15.),
+            heater_power: Power::default(),
+            has_heat_fault: false,
+        }
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        heating_is_commanded: bool,
+        heater_is_powered: bool,
+        is_on_ground: bool,
+    ) {
+        let nominal_power = self.nominal_power(is_on_ground);
+        let is_heating = heating_is_commanded
+            && heater_is_powered
+            && !self.failure.is_active()
+            && nominal_power > Power::default();
+
+        self.heater_power = if is_heating {
+            nominal_power
+        } else {
+            Power::default()
+        };
+        self.has_heat_fault =
+            heating_is_commanded && nominal_power > Power::default() && !is_heating;
+
+        self.update_temperature(context);
+    }
+
+    /// The pitot probe is heated at a reduced power on ground. The TAT probe is not heated
+    /// on ground, as it isn't aspirated and would overheat.
+    fn nominal_power(&self, is_on_ground: bool) -> Power {
+        Power::new::<watt>(match (self.probe_type, is_on_ground) {
+            (ProbeType::Pitot, true) => 100.,
+            (ProbeType::Pitot, false) => 200.,
+            (ProbeType::LeftStatic | ProbeType::RightStatic, _) => 25.,
+            (ProbeType::TotalAirTemperature, true) => 0.,
+            (ProbeType::TotalAirTemperature, false) => 150.,
+            (ProbeType::AngleOfAttack, _) => 150.,
+        })
+    }
+
+    fn update_temperature(&mut self, context: &UpdateContext) {
+        let heat_transfer_watt_per_kelvin = Self::STILL_AIR_HEAT_TRANSFER_WATT_PER_KELVIN
+            + Self::HEAT_TRANSFER_WATT_PER_KELVIN_PER_KNOT
+                * context.true_airspeed().get::<knot>().max(0.);
+
+        let equilibrium_temperature_kelvin = Self::recovery_temperature(context).get::<kelvin>()
+            + self.heater_power.get::<watt>() / heat_transfer_watt_per_kelvin;
+
+        // Exact solution of the first order heat balance, which stays stable for long frames.
+        let decay = (-heat_transfer_watt_per_kelvin * context.delta_as_secs_f64()
+            / Self::THERMAL_CAPACITY_JOULE_PER_KELVIN)
+            .exp();
+
+        self.temperature = ThermodynamicTemperature::new::<kelvin>(
+            equilibrium_temperature_kelvin
+                + (self.temperature.get::<kelvin>() - equilibrium_temperature_kelvin) * decay,
+        );
+    }
+
+    fn recovery_temperature(context: &UpdateContext) -> ThermodynamicTemperature {
+        let true_airspeed = context.true_airspeed().get::<meter_per_second>();
+
+        ThermodynamicTemperature::new::<kelvin>(
+            context.ambient_temperature().get::<kelvin>()
+                + true_airspeed.powi(2) / (2. * Self::AIR_SPECIFIC_HEAT_JOULE_PER_KILOGRAM_KELVIN),
+        )
+    }
+
+    fn has_heat_fault(&self) -> bool {
+        self.has_heat_fault
+    }
+}
+impl ActiveDeicingController for HeatedProbe {
+    fn active_deicing_normalized_rate(&self) -> Ratio {
+        Ratio::new::<ratio>(
+            (self.temperature.get::<degree_celsius>() / Self::FULL_DEICING_TEMPERATURE_RISE_KELVIN)
+                .clamp(0., 1.),
+        )
+    }
+}
+impl SimulationElement for HeatedProbe {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.heat_fault_id, self.has_heat_fault);
+    }
+}
+
+/// The Probe Heat Computer controls the heating of the air data probes of one ADR.
+///
+/// The probes are heated when the aircraft is in flight, when an engine is running or when
+/// the PROBE/WINDOW HEAT pushbutton is ON. A heat fault is reported for each probe that is
+/// commanded to be heated but isn't, because its heater failed or lost power.
+pub struct ProbeHeatComputer {
+    probes: Vec<HeatedProbe>,
+    pitot_icing: IcingState,
+
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+    heaters_powered_by: ElectricalBusType,
+    heaters_are_powered: bool,
+
+    heating_is_commanded: bool,
+}
+impl ProbeHeatComputer {
+    const ENGINE_RUNNING_N2_PERCENT: f64 = 50.;
+
+    const PITOT_TIME_TO_FULLY_ICED: Duration = Duration::from_secs(60);
+    const PITOT_TIME_TO_PASSIVE_FULLY_DEICED: Duration = Duration::from_secs(180);
+    const PITOT_TIME_TO_ACTIVE_FULLY_DEICED: Duration = Duration::from_secs(30);
+
+    pub fn new(
+        context: &mut InitContext,
+        number: usize,
+        probe_types: &[ProbeType],
+        powered_by: ElectricalBusType,
+        heaters_powered_by: ElectricalBusType,
+    ) -> Self {
+        Self {
+            probes: probe_types
+                .iter()
+                .map(|&probe_type| HeatedProbe::new(context, number, probe_type))
+                .collect(),
+            pitot_icing: IcingState::new(
+                context,
+                &format!("PITOT_{}", number),
+                Self::PITOT_TIME_TO_FULLY_ICED,
+                Self::PITOT_TIME_TO_PASSIVE_FULLY_DEICED,
+                Some(Self::PITOT_TIME_TO_ACTIVE_FULLY_DEICED),
+            ),
+
+            powered_by,
+            is_powered: false,
+            heaters_powered_by,
+            heaters_are_powered: false,
+
+            heating_is_commanded: false,
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        push_button: &ProbeWindowHeatPushButton,
+        engines: &[&impl EngineCorrectedN2],
+        lgciu: &impl LgciuWeightOnWheels,
+    ) {
+        let is_on_ground = lgciu.left_and_right_gear_compressed(false);
+        let any_engine_running = engines.iter().any(|engine| {
+            engine.corrected_n2() > Ratio::new::<percent>(Self::ENGINE_RUNNING_N2_PERCENT)
+        });
+
+        self.heating_is_commanded = push_button.is_on() || !is_on_ground || any_engine_running;
+
+        let heaters_are_powered = self.is_powered && self.heaters_are_powered;
+        for probe in &mut self.probes {
+            probe.update(
+                context,
+                self.heating_is_commanded,
+                heaters_are_powered,
+                is_on_ground,
+            );
+        }
+
+        let pitot = self
+            .probes
+            .iter()
+            .find(|probe| probe.probe_type == ProbeType::Pitot);
+        self.pitot_icing.update(context, pitot);
+    }
+
+    pub fn heating_is_commanded(&self) -> bool {
+        self.heating_is_commanded
+    }
+
+    fn probe(&self, probe_type: ProbeType) -> Option<&HeatedProbe> {
+        self.probes
+            .iter()
+            .find(|probe| probe.probe_type == probe_type)
+    }
+
+    #[cfg(test)]
+    fn probe_temperature(&self, probe_type: ProbeType) -> ThermodynamicTemperature {
+        self.probe(probe_type).unwrap().temperature
+    }
+}
+impl AirDataProbes for ProbeHeatComputer {
+    fn probe_heat_fault(&self, probe: ProbeType) -> bool {
+        self.probe(probe)
+            .is_some_and(|probe| probe.has_heat_fault())
+    }
+
+    fn pitot_blockage(&self) -> Ratio {
+        self.pitot_icing.icing_state_normalized()
+    }
+}
+impl SimulationElement for ProbeHeatComputer {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.probes, visitor);
+        self.pitot_icing.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+        self.heaters_are_powered = buses.is_powered(self.heaters_powered_by);
+    }
+
+    fn consume_power<T: ConsumePower>(&mut self, _: &UpdateContext, consumption: &mut T) {
+        for probe in &self.probes {
+            consumption.consume_from_bus(self.heaters_powered_by, probe.heater_power);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::electrical::{test::TestElectricitySource, ElectricalBus, Electricity};
+    use crate::shared::{PotentialOrigin, PowerConsumptionReport};
+    use crate::simulation::test::{ReadByName, SimulationTestBed, TestBed, WriteByName};
+    use crate::simulation::{Aircraft, StartState};
+    use uom::si::electric_potential::volt;
+
+    struct TestEngine {
+        corrected_n2: Ratio,
+    }
+    impl EngineCorrectedN2 for TestEngine {
+        fn corrected_n2(&self) -> Ratio {
+            self.corrected_n2
+        }
+    }
+
+    struct TestLgciu {
+        compressed: bool,
+    }
+    impl LgciuWeightOnWheels for TestLgciu {
+        fn left_and_right_gear_compressed(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            self.compressed
+        }
+        fn right_gear_compressed(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            self.compressed
+        }
+        fn right_gear_extended(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            !self.compressed
+        }
+        fn left_gear_compressed(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            self.compressed
+        }
+        fn left_gear_extended(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            !self.compressed
+        }
+        fn left_and_right_gear_extended(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            !self.compressed
+        }
+        fn nose_gear_compressed(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            self.compressed
+        }
+        fn nose_gear_extended(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            !self.compressed
+        }
+    }
+
+    struct TestAircraft {
+        electricity_source: TestElectricitySource,
+        ac_1_bus: ElectricalBus,
+        dc_1_bus: ElectricalBus,
+        phc: ProbeHeatComputer,
+        push_button: ProbeWindowHeatPushButton,
+        engine: TestEngine,
+        lgciu: TestLgciu,
+        is_ac_1_powered: bool,
+        power_consumption: Power,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                electricity_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::EngineGenerator(1),
+                ),
+                ac_1_bus: ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(1)),
+                dc_1_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(1)),
+                phc: ProbeHeatComputer::new(
+                    context,
+                    1,
+                    &[
+                        ProbeType::Pitot,
+                        ProbeType::LeftStatic,
+                        ProbeType::RightStatic,
+                        ProbeType::TotalAirTemperature,
+                        ProbeType::AngleOfAttack,
+                    ],
+                    ElectricalBusType::DirectCurrent(1),
+                    ElectricalBusType::AlternatingCurrent(1),
+                ),
+                push_button: ProbeWindowHeatPushButton::new(context),
+                engine: TestEngine {
+                    corrected_n2: Ratio::default(),
+                },
+                lgciu: TestLgciu { compressed: false },
+                is_ac_1_powered: true,
+                power_consumption: Power::default(),
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            self.electricity_source
+                .power_with_potential(ElectricPotential::new::<volt>(115.));
+            electricity.supplied_by(&self.electricity_source);
+            electricity.flow(&self.electricity_source, &self.dc_1_bus);
+
+            if self.is_ac_1_powered {
+                electricity.flow(&self.electricity_source, &self.ac_1_bus);
+            }
+        }
+
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.phc
+                .update(context, &self.push_button, &[&self.engine], &self.lgciu);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn process_power_consumption_report<T: PowerConsumptionReport>(
+            &mut self,
+            _: &UpdateContext,
+            report: &T,
+        ) {
+            self.power_consumption =
+                report.total_consumption_of(PotentialOrigin::EngineGenerator(1));
+        }
+
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.ac_1_bus.accept(visitor);
+            self.dc_1_bus.accept(visitor);
+            self.phc.accept(visitor);
+            self.push_button.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    struct ProbeHeatTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl ProbeHeatTestBed {
+        fn new() -> Self {
+            let mut test_bed = Self {
+                test_bed: SimulationTestBed::new_with_start_state(
+                    StartState::Cruise,
+                    TestAircraft::new,
+                ),
+            };
+            test_bed.set_true_airspeed(Velocity::new::<knot>(250.));
+            test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(-10.));
+
+            test_bed
+        }
+
+        fn and(self) -> Self {
+            self
+        }
+
+        fn on_ground(mut self) -> Self {
+            self.command(|a| a.lgciu.compressed = true);
+            self.set_on_ground(true);
+            self.set_true_airspeed(Velocity::default());
+            self
+        }
+
+        fn engine_running(mut self) -> Self {
+            self.command(|a| a.engine.corrected_n2 = Ratio::new::<percent>(60.));
+            self
+        }
+
+        fn push_button_on(mut self) -> Self {
+            self.write_by_name("MAN_PITOT_HEAT", true);
+            self
+        }
+
+        fn in_icing_conditions(mut self) -> Self {
+            self.write_by_name("AMBIENT IN CLOUD", true);
+            self
+        }
+
+        fn heaters_unpowered(mut self) -> Self {
+            self.command(|a| a.is_ac_1_powered = false);
+            self
+        }
+
+        fn failed_heater(mut self, probe: ProbeType) -> Self {
+            self.fail(FailureType::ProbeHeater(1, probe));
+            self
+        }
+
+        fn run_for(mut self, duration: Duration) -> Self {
+            self.test_bed.run_multiple_frames(duration);
+            self
+        }
+
+        fn heating_is_commanded(&self) -> bool {
+            self.query(|a| a.phc.heating_is_commanded())
+        }
+
+        fn probe_temperature(&self, probe: ProbeType) -> ThermodynamicTemperature {
+            self.query(|a| a.phc.probe_temperature(probe))
+        }
+
+        fn has_heat_fault(&mut self, probe: ProbeType) -> bool {
+            self.read_by_name(&format!("PHC_1_{}_HEAT_FAULT", probe))
+        }
+
+        fn pitot_blockage(&self) -> Ratio {
+            self.query(|a| a.phc.pitot_blockage())
+        }
+
+        fn power_consumption(&self) -> Power {
+            self.query(|a| a.power_consumption)
+        }
+    }
+    impl TestBed for ProbeHeatTestBed {
+        type Aircraft = TestAircraft;
+
+        fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            &mut self.test_bed
+        }
+    }
+
+    fn test_bed() -> ProbeHeatTestBed {
+        ProbeHeatTestBed::new()
+    }
+
+    fn test_bed_with() -> ProbeHeatTestBed {
+        test_bed()
+    }
+
+    #[test]
+    fn heating_is_commanded_in_flight() {
+        let test_bed = test_bed().run_for(Duration::from_secs(1));
+
+        assert!(test_bed.heating_is_commanded());
+    }
+
+    #[test]
+    fn heating_is_not_commanded_on_ground_with_engines_off() {
+        let test_bed = test_bed_with().on_ground().run_for(Duration::from_secs(1));
+
+        assert!(!test_bed.heating_is_commanded());
+    }
+
+    #[test]
+    fn heating_is_commanded_on_ground_with_an_engine_running() {
+        let test_bed = test_bed_with()
+            .on_ground()
+            .and()
+            .engine_running()
+            .run_for(Duration::from_secs(1));
+
+        assert!(test_bed.heating_is_commanded());
+    }
+
+    #[test]
+    fn heating_is_commanded_on_ground_when_push_button_is_on() {
+        let test_bed = test_bed_with()
+            .on_ground()
+            .and()
+            .push_button_on()
+            .run_for(Duration::from_secs(1));
+
+        assert!(test_bed.heating_is_commanded());
+    }
+
+    #[test]
+    fn heated_pitot_stays_above_freezing_in_flight() {
+        let test_bed = test_bed().run_for(Duration::from_secs(300));
+
+        assert!(
+            test_bed
+                .probe_temperature(ProbeType::Pitot)
+                .get::<degree_celsius>()
+                > 5.
+        );
+    }
+
+    #[test]
+    fn tat_probe_is_not_heated_on_ground() {
+        let test_bed = test_bed_with()
+            .on_ground()
+            .and()
+            .engine_running()
+            .run_for(Duration::from_secs(600));
+
+        assert!(
+            test_bed
+                .probe_temperature(ProbeType::TotalAirTemperature)
+                .get::<degree_celsius>()
+                < -5.
+        );
+        assert!(
+            test_bed
+                .probe_temperature(ProbeType::Pitot)
+                .get::<degree_celsius>()
+                > 5.
+        );
+    }
+
+    #[test]
+    fn no_heat_fault_when_heating_normally() {
+        let mut test_bed = test_bed().run_for(Duration::from_secs(1));
+
+        assert!(!test_bed.has_heat_fault(ProbeType::Pitot));
+        assert!(!test_bed.has_heat_fault(ProbeType::AngleOfAttack));
+    }
+
+    #[test]
+    fn failed_heater_reports_heat_fault() {
+        let mut test_bed = test_bed_with()
+            .failed_heater(ProbeType::LeftStatic)
+            .run_for(Duration::from_secs(1));
+
+        assert!(test_bed.has_heat_fault(ProbeType::LeftStatic));
+        assert!(!test_bed.has_heat_fault(ProbeType::RightStatic));
+    }
+
+    #[test]
+    fn failed_heater_does_not_report_heat_fault_when_heating_is_not_commanded() {
+        let mut test_bed = test_bed_with()
+            .on_ground()
+            .and()
+            .failed_heater(ProbeType::LeftStatic)
+            .run_for(Duration::from_secs(1));
+
+        assert!(!test_bed.has_heat_fault(ProbeType::LeftStatic));
+    }
+
+    #[test]
+    fn unpowered_heaters_report_heat_fault() {
+        let mut test_bed = test_bed_with()
+            .heaters_unpowered()
+            .run_for(Duration::from_secs(1));
+
+        assert!(test_bed.has_heat_fault(ProbeType::Pitot));
+        assert!(test_bed.has_heat_fault(ProbeType::TotalAirTemperature));
+    }
+
+    #[test]
+    fn unheated_pitot_ices_up_in_icing_conditions() {
+        let test_bed = test_bed_with()
+            .failed_heater(ProbeType::Pitot)
+            .and()
+            .in_icing_conditions()
+            .run_for(Duration::from_secs(120));
+
+        assert!(test_bed.pitot_blockage().get::<ratio>() > 0.9);
+    }
+
+    #[test]
+    fn heated_pitot_stays_clear_in_icing_conditions() {
+        let test_bed = test_bed_with()
+            .in_icing_conditions()
+            .run_for(Duration::from_secs(120));
+
+        assert!(test_bed.pitot_blockage().get::<ratio>() < 0.05);
+    }
+
+    #[test]
+    fn heaters_consume_power_when_heating() {
+        let test_bed = test_bed().run_for(Duration::from_secs(1));
+
+        assert!(test_bed.power_consumption() > Power::new::<watt>(500.));
+    }
+
+    #[test]
+    fn heaters_do_not_consume_power_when_not_heating() {
+        let test_bed = test_bed_with().on_ground().run_for(Duration::from_secs(1));
+
+        assert_eq!(test_bed.power_consumption(), Power::default());
+    }
+}
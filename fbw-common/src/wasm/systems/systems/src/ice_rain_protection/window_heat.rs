@@ -0,0 +1,753 @@
+use super::ProbeWindowHeatPushButton;
+use crate::{
+    failures::{Failure, FailureType},
+    icing_state::{ActiveDeicingController, IcingState},
+    shared::{
+        ConsumePower, ElectricalBusType, ElectricalBuses, EngineCorrectedN2, LgciuWeightOnWheels,
+        WindowType,
+    },
+    simulation::{
+        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
+        VariableIdentifier, Write,
+    },
+};
+use std::time::Duration;
+use uom::si::{
+    f64::*,
+    power::watt,
+    ratio::{percent, ratio},
+    thermodynamic_temperature::{degree_celsius, kelvin},
+    velocity::{knot, meter_per_second},
+};
+
+/// A window with a heating film embedded between its plies. The film temperature results from
+/// the heater power, the heat carried away by the air flowing over the outer ply and the heat
+/// exchanged with the cockpit through the inner ply.
+struct HeatedWindow {
+    window_type: WindowType,
+    failure: Failure,
+    heat_fault_id: VariableIdentifier,
+    fogged_id: VariableIdentifier,
+
+    heater_powered_by: ElectricalBusType,
+    heater_is_powered: bool,
+
+    temperature: ThermodynamicTemperature,
+    heater_power: Power,
+    thermostat_calls_for_heat: bool,
+    overheat_is_latched: bool,
+    has_heat_fault: bool,
+}
+impl HeatedWindow {
+    const AIR_SPECIFIC_HEAT_JOULE_PER_KILOGRAM_KELVIN: f64 = 1005.;
+
+    const COCKPIT_TEMPERATURE_DEGREE_CELSIUS: f64 = 22.;
+    const COCKPIT_DEW_POINT_DEGREE_CELSIUS: f64 = 10.;
+
+    const REGULATION_LOWER_LIMIT_DEGREE_CELSIUS: f64 = 35.;
+    const REGULATION_UPPER_LIMIT_DEGREE_CELSIUS: f64 = 42.;
+    const OVERHEAT_DEGREE_CELSIUS: f64 = 60.;
+
+    /// Temperature rise of the film above freezing at which ice melts at the highest rate.
+    const FULL_DEICING_TEMPERATURE_RISE_KELVIN: f64 = 10.;
+
+    fn new(
+        context: &mut InitContext,
+        number: usize,
+        window_type: WindowType,
+        heater_powered_by: ElectricalBusType,
+    ) -> Self {
+        Self {
+            window_type,
+            failure: Failure::new(FailureType::WindowHeater(number, window_type)),
+            heat_fault_id: context
+                .get_identifier(format!("WHC_{}_{}_HEAT_FAULT", number, window_type)),
+            fogged_id: context.get_identifier(format!("WHC_{}_{}_FOGGED", number, window_type)),
+
+            heater_powered_by,
+            heater_is_powered: false,
+
+            temperature: ThermodynamicTemperature::new::<degree_celsius>(15.),
+            heater_power: Power::default(),
+            thermostat_calls_for_heat: false,
+            overheat_is_latched: false,
+            has_heat_fault: false,
+        }
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        heating_is_commanded: bool,
+        computer_is_powered: bool,
+        regulation_has_failed: bool,
+        is_on_ground: bool,
+    ) {
+        self.update_regulation(computer_is_powered, regulation_has_failed);
+
+        let heater_is_available =
+            computer_is_powered && self.heater_is_powered && !self.failure.is_active();
+        let is_heating = heating_is_commanded
+            && heater_is_available
+            && self.thermostat_calls_for_heat
+            && !self.overheat_is_latched;
+
+        self.heater_power = if is_heating {
+            self.nominal_power(is_on_ground)
+        } else {
+            Power::default()
+        };
+        self.has_heat_fault =
+            heating_is_commanded && (!heater_is_available || self.overheat_is_latched);
+
+        self.update_temperature(context);
+    }
+
+    /// The computer switches the heater with a thermostat and cuts it off when the film
+    /// overheats. The overheat protection only resets when the computer loses power.
+    fn update_regulation(&mut self, computer_is_powered: bool, regulation_has_failed: bool) {
+        if !computer_is_powered {
+            self.overheat_is_latched = false;
+            self.thermostat_calls_for_heat = false;
+            return;
+        }
+
+        let temperature = self.temperature.get::<degree_celsius>();
+        if temperature > Self::OVERHEAT_DEGREE_CELSIUS {
+            self.overheat_is_latched = true;
+        }
+
+        self.thermostat_calls_for_heat =
+            if regulation_has_failed || temperature < Self::REGULATION_LOWER_LIMIT_DEGREE_CELSIUS {
+                true
+            } else if temperature > Self::REGULATION_UPPER_LIMIT_DEGREE_CELSIUS {
+                false
+            } else {
+                self.thermostat_calls_for_heat
+            };
+    }
+
+    /// The windshield is heated at low power on ground and at normal power in flight.
+    /// The side windows are only heated for anti-fogging and have a single power level.
+    fn nominal_power(&self, is_on_ground: bool) -> Power {
+        Power::new::<watt>(match (self.window_type, is_on_ground) {
+            (WindowType::Windshield, true) => 1500.,
+            (WindowType::Windshield, false) => 3000.,
+            (WindowType::SideWindow, _) => 600.,
+        })
+    }
+
+    fn thermal_capacity_joule_per_kelvin(&self) -> f64 {
+        match self.window_type {
+            WindowType::Windshield => 20000.,
+            WindowType::SideWindow => 5000.,
+        }
+    }
+
+    fn outer_heat_transfer_watt_per_kelvin(&self, context: &UpdateContext) -> f64 {
+        let (still_air, per_knot) = match self.window_type {
+            WindowType::Windshield => (15., 0.4),
+            WindowType::SideWindow => (5., 0.1),
+        };
+
+        still_air + per_knot * context.true_airspeed().get::<knot>().max(0.)
+    }
+
+    fn inner_heat_transfer_watt_per_kelvin(&self) -> f64 {
+        match self.window_type {
+            WindowType::Windshield => 15.,
+            WindowType::SideWindow => 5.,
+        }
+    }
+
+    fn update_temperature(&mut self, context: &UpdateContext) {
+        let outer_heat_transfer = self.outer_heat_transfer_watt_per_kelvin(context);
+        let inner_heat_transfer = self.inner_heat_transfer_watt_per_kelvin();
+        let total_heat_transfer = outer_heat_transfer + inner_heat_transfer;
+
+        let cockpit_temperature_kelvin = ThermodynamicTemperature::new::<degree_celsius>(
+            Self::COCKPIT_TEMPERATURE_DEGREE_CELSIUS,
+        )
+        .get::<kelvin>();
+        let equilibrium_temperature_kelvin = (outer_heat_transfer
+            * Self::recovery_temperature(context).get::<kelvin>()
+            + inner_heat_transfer * cockpit_temperature_kelvin
+            + self.heater_power.get::<watt>())
+            / total_heat_transfer;
+
+        // Exact solution of the first order heat balance, which stays stable for long frames.
+        let decay = (-total_heat_transfer * context.delta_as_secs_f64()
+            / self.thermal_capacity_joule_per_kelvin())
+        .exp();
+
+        self.temperature = ThermodynamicTemperature::new::<kelvin>(
+            equilibrium_temperature_kelvin
+                + (self.temperature.get::<kelvin>() - equilibrium_temperature_kelvin) * decay,
+        );
+    }
+
+    fn recovery_temperature(context: &UpdateContext) -> ThermodynamicTemperature {
+        let true_airspeed = context.true_airspeed().get::<meter_per_second>();
+
+        ThermodynamicTemperature::new::<kelvin>(
+            context.ambient_temperature().get::<kelvin>()
+                + true_airspeed.powi(2) / (2. * Self::AIR_SPECIFIC_HEAT_JOULE_PER_KILOGRAM_KELVIN),
+        )
+    }
+
+    /// Moisture of the cockpit air condenses on the inner ply when it is colder than the dew point.
+    fn is_fogged(&self) -> bool {
+        self.temperature.get::<degree_celsius>() < Self::COCKPIT_DEW_POINT_DEGREE_CELSIUS
+    }
+
+    fn has_heat_fault(&self) -> bool {
+        self.has_heat_fault
+    }
+}
+impl ActiveDeicingController for HeatedWindow {
+    fn active_deicing_normalized_rate(&self) -> Ratio {
+        Ratio::new::<ratio>(
+            (self.temperature.get::<degree_celsius>() / Self::FULL_DEICING_TEMPERATURE_RISE_KELVIN)
+                .clamp(0., 1.),
+        )
+    }
+}
+impl SimulationElement for HeatedWindow {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.heat_fault_id, self.has_heat_fault);
+        writer.write(&self.fogged_id, self.is_fogged());
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.heater_is_powered = buses.is_powered(self.heater_powered_by);
+    }
+
+    fn consume_power<T: ConsumePower>(&mut self, _: &UpdateContext, consumption: &mut T) {
+        consumption.consume_from_bus(self.heater_powered_by, self.heater_power);
+    }
+}
+
+/// The Window Heat Computer controls the heating of the windshield and side window on one
+/// side of the cockpit.
+///
+/// Like the probes, the windows are heated when the aircraft is in flight, when an engine is
+/// running or when the PROBE/WINDOW HEAT pushbutton is ON. A heat fault is reported for each
+/// window that is commanded to be heated but can't be, because its heater failed, lost power
+/// or was cut off by the overheat protection.
+pub struct WindowHeatComputer {
+    windshield: HeatedWindow,
+    side_window: HeatedWindow,
+    windshield_icing: IcingState,
+    regulation_failure: Failure,
+    windshield_visibility_id: VariableIdentifier,
+
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+
+    heating_is_commanded: bool,
+}
+impl WindowHeatComputer {
+    const ENGINE_RUNNING_N2_PERCENT: f64 = 50.;
+
+    const WINDSHIELD_TIME_TO_FULLY_ICED: Duration = Duration::from_secs(120);
+    const WINDSHIELD_TIME_TO_PASSIVE_FULLY_DEICED: Duration = Duration::from_secs(300);
+    const WINDSHIELD_TIME_TO_ACTIVE_FULLY_DEICED: Duration = Duration::from_secs(60);
+
+    pub fn new(
+        context: &mut InitContext,
+        number: usize,
+        powered_by: ElectricalBusType,
+        windshield_heater_powered_by: ElectricalBusType,
+        side_window_heater_powered_by: ElectricalBusType,
+    ) -> Self {
+        Self {
+            windshield: HeatedWindow::new(
+                context,
+                number,
+                WindowType::Windshield,
+                windshield_heater_powered_by,
+            ),
+            side_window: HeatedWindow::new(
+                context,
+                number,
+                WindowType::SideWindow,
+                side_window_heater_powered_by,
+            ),
+            windshield_icing: IcingState::new(
+                context,
+                &format!("WINDSHIELD_{}", number),
+                Self::WINDSHIELD_TIME_TO_FULLY_ICED,
+                Self::WINDSHIELD_TIME_TO_PASSIVE_FULLY_DEICED,
+                Some(Self::WINDSHIELD_TIME_TO_ACTIVE_FULLY_DEICED),
+            ),
+            regulation_failure: Failure::new(FailureType::WindowHeatRegulation(number)),
+            windshield_visibility_id: context
+                .get_identifier(format!("WHC_{}_WINDSHIELD_VISIBILITY", number)),
+
+            powered_by,
+            is_powered: false,
+
+            heating_is_commanded: false,
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        push_button: &ProbeWindowHeatPushButton,
+        engines: &[&impl EngineCorrectedN2],
+        lgciu: &impl LgciuWeightOnWheels,
+    ) {
+        let is_on_ground = lgciu.left_and_right_gear_compressed(false);
+        let any_engine_running = engines.iter().any(|engine| {
+            engine.corrected_n2() > Ratio::new::<percent>(Self::ENGINE_RUNNING_N2_PERCENT)
+        });
+
+        self.heating_is_commanded = push_button.is_on() || !is_on_ground || any_engine_running;
+
+        let regulation_has_failed = self.regulation_failure.is_active();
+        for window in [&mut self.windshield, &mut self.side_window] {
+            window.update(
+                context,
+                self.heating_is_commanded,
+                self.is_powered,
+                regulation_has_failed,
+                is_on_ground,
+            );
+        }
+
+        self.windshield_icing
+            .update(context, Some(&self.windshield));
+    }
+
+    pub fn heating_is_commanded(&self) -> bool {
+        self.heating_is_commanded
+    }
+
+    pub fn has_heat_fault(&self) -> bool {
+        self.windshield.has_heat_fault() || self.side_window.has_heat_fault()
+    }
+
+    /// The part of the windshield through which the pilot can see, which is reduced by ice
+    /// accreting on an unheated windshield.
+    pub fn windshield_visibility(&self) -> Ratio {
+        Ratio::new::<ratio>(1.) - self.windshield_icing.icing_state_normalized()
+    }
+
+    #[cfg(test)]
+    fn window_temperature(&self, window_type: WindowType) -> ThermodynamicTemperature {
+        match window_type {
+            WindowType::Windshield => self.windshield.temperature,
+            WindowType::SideWindow => self.side_window.temperature,
+        }
+    }
+}
+impl SimulationElement for WindowHeatComputer {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.windshield.accept(visitor);
+        self.side_window.accept(visitor);
+        self.windshield_icing.accept(visitor);
+        self.regulation_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(
+            &self.windshield_visibility_id,
+            self.windshield_visibility().get::<ratio>(),
+        );
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::electrical::{test::TestElectricitySource, ElectricalBus, Electricity};
+    use crate::shared::{PotentialOrigin, PowerConsumptionReport};
+    use crate::simulation::test::{ReadByName, SimulationTestBed, TestBed, WriteByName};
+    use crate::simulation::{Aircraft, StartState};
+    use uom::si::electric_potential::volt;
+
+    struct TestEngine {
+        corrected_n2: Ratio,
+    }
+    impl EngineCorrectedN2 for TestEngine {
+        fn corrected_n2(&self) -> Ratio {
+            self.corrected_n2
+        }
+    }
+
+    struct TestLgciu {
+        compressed: bool,
+    }
+    impl LgciuWeightOnWheels for TestLgciu {
+        fn left_and_right_gear_compressed(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            self.compressed
+        }
+        fn right_gear_compressed(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            self.compressed
+        }
+        fn right_gear_extended(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            !self.compressed
+        }
+        fn left_gear_compressed(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            self.compressed
+        }
+        fn left_gear_extended(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            !self.compressed
+        }
+        fn left_and_right_gear_extended(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            !self.compressed
+        }
+        fn nose_gear_compressed(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            self.compressed
+        }
+        fn nose_gear_extended(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            !self.compressed
+        }
+    }
+
+    struct TestAircraft {
+        electricity_source: TestElectricitySource,
+        ac_1_bus: ElectricalBus,
+        ac_ess_shed_bus: ElectricalBus,
+        dc_1_bus: ElectricalBus,
+        whc: WindowHeatComputer,
+        push_button: ProbeWindowHeatPushButton,
+        engine: TestEngine,
+        lgciu: TestLgciu,
+        is_ac_1_powered: bool,
+        power_consumption: Power,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                electricity_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::EngineGenerator(1),
+                ),
+                ac_1_bus: ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(1)),
+                ac_ess_shed_bus: ElectricalBus::new(
+                    context,
+                    ElectricalBusType::AlternatingCurrentEssentialShed,
+                ),
+                dc_1_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(1)),
+                whc: WindowHeatComputer::new(
+                    context,
+                    1,
+                    ElectricalBusType::DirectCurrent(1),
+                    ElectricalBusType::AlternatingCurrent(1),
+                    ElectricalBusType::AlternatingCurrentEssentialShed,
+                ),
+                push_button: ProbeWindowHeatPushButton::new(context),
+                engine: TestEngine {
+                    corrected_n2: Ratio::default(),
+                },
+                lgciu: TestLgciu { compressed: false },
+                is_ac_1_powered: true,
+                power_consumption: Power::default(),
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            self.electricity_source
+                .power_with_potential(ElectricPotential::new::<volt>(115.));
+            electricity.supplied_by(&self.electricity_source);
+            electricity.flow(&self.electricity_source, &self.dc_1_bus);
+            electricity.flow(&self.electricity_source, &self.ac_ess_shed_bus);
+
+            if self.is_ac_1_powered {
+                electricity.flow(&self.electricity_source, &self.ac_1_bus);
+            }
+        }
+
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.whc
+                .update(context, &self.push_button, &[&self.engine], &self.lgciu);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn process_power_consumption_report<T: PowerConsumptionReport>(
+            &mut self,
+            _: &UpdateContext,
+            report: &T,
+        ) {
+            self.power_consumption =
+                report.total_consumption_of(PotentialOrigin::EngineGenerator(1));
+        }
+
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.ac_1_bus.accept(visitor);
+            self.ac_ess_shed_bus.accept(visitor);
+            self.dc_1_bus.accept(visitor);
+            self.whc.accept(visitor);
+            self.push_button.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    struct WindowHeatTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl WindowHeatTestBed {
+        fn new() -> Self {
+            let mut test_bed = Self {
+                test_bed: SimulationTestBed::new_with_start_state(
+                    StartState::Cruise,
+                    TestAircraft::new,
+                ),
+            };
+            test_bed.set_true_airspeed(Velocity::new::<knot>(250.));
+            test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(-10.));
+
+            test_bed
+        }
+
+        fn and(self) -> Self {
+            self
+        }
+
+        fn on_ground(mut self) -> Self {
+            self.command(|a| a.lgciu.compressed = true);
+            self.set_on_ground(true);
+            self.set_true_airspeed(Velocity::default());
+            self.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(15.));
+            self
+        }
+
+        fn engine_running(mut self) -> Self {
+            self.command(|a| a.engine.corrected_n2 = Ratio::new::<percent>(60.));
+            self
+        }
+
+        fn in_icing_conditions(mut self) -> Self {
+            self.write_by_name("AMBIENT IN CLOUD", true);
+            self
+        }
+
+        fn windshield_heater_unpowered(mut self) -> Self {
+            self.command(|a| a.is_ac_1_powered = false);
+            self
+        }
+
+        fn failed_heater(mut self, window_type: WindowType) -> Self {
+            self.fail(FailureType::WindowHeater(1, window_type));
+            self
+        }
+
+        fn failed_regulation(mut self) -> Self {
+            self.fail(FailureType::WindowHeatRegulation(1));
+            self
+        }
+
+        fn run_for(mut self, duration: Duration) -> Self {
+            self.test_bed.run_multiple_frames(duration);
+            self
+        }
+
+        fn heating_is_commanded(&self) -> bool {
+            self.query(|a| a.whc.heating_is_commanded())
+        }
+
+        fn window_temperature(&self, window_type: WindowType) -> ThermodynamicTemperature {
+            self.query(|a| a.whc.window_temperature(window_type))
+        }
+
+        fn has_heat_fault(&mut self, window_type: WindowType) -> bool {
+            self.read_by_name(&format!("WHC_1_{}_HEAT_FAULT", window_type))
+        }
+
+        fn is_fogged(&mut self, window_type: WindowType) -> bool {
+            self.read_by_name(&format!("WHC_1_{}_FOGGED", window_type))
+        }
+
+        fn windshield_visibility(&mut self) -> f64 {
+            self.read_by_name("WHC_1_WINDSHIELD_VISIBILITY")
+        }
+
+        fn power_consumption(&self) -> Power {
+            self.query(|a| a.power_consumption)
+        }
+    }
+    impl TestBed for WindowHeatTestBed {
+        type Aircraft = TestAircraft;
+
+        fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            &mut self.test_bed
+        }
+    }
+
+    fn test_bed() -> WindowHeatTestBed {
+        WindowHeatTestBed::new()
+    }
+
+    fn test_bed_with() -> WindowHeatTestBed {
+        test_bed()
+    }
+
+    #[test]
+    fn heating_is_commanded_in_flight() {
+        let test_bed = test_bed().run_for(Duration::from_secs(1));
+
+        assert!(test_bed.heating_is_commanded());
+    }
+
+    #[test]
+    fn heating_is_not_commanded_on_ground_with_engines_off() {
+        let test_bed = test_bed_with().on_ground().run_for(Duration::from_secs(1));
+
+        assert!(!test_bed.heating_is_commanded());
+    }
+
+    #[test]
+    fn heating_is_commanded_on_ground_with_an_engine_running() {
+        let test_bed = test_bed_with()
+            .on_ground()
+            .and()
+            .engine_running()
+            .run_for(Duration::from_secs(1));
+
+        assert!(test_bed.heating_is_commanded());
+    }
+
+    #[test]
+    fn windshield_is_heated_at_low_power_on_ground() {
+        let ground = test_bed_with()
+            .on_ground()
+            .and()
+            .engine_running()
+            .run_for(Duration::from_secs(1));
+        let flight = test_bed().run_for(Duration::from_secs(1));
+
+        assert!(ground.power_consumption() > Power::default());
+        assert!(ground.power_consumption() < flight.power_consumption());
+    }
+
+    #[test]
+    fn heated_windows_are_regulated_on_ground() {
+        let test_bed = test_bed_with()
+            .on_ground()
+            .and()
+            .engine_running()
+            .run_for(Duration::from_secs(3600));
+
+        for window_type in [WindowType::Windshield, WindowType::SideWindow] {
+            let temperature = test_bed
+                .window_temperature(window_type)
+                .get::<degree_celsius>();
+            assert!((30. ..45.).contains(&temperature));
+        }
+    }
+
+    #[test]
+    fn failed_regulation_trips_overheat_protection() {
+        let mut test_bed = test_bed_with()
+            .on_ground()
+            .and()
+            .engine_running()
+            .and()
+            .failed_regulation()
+            .run_for(Duration::from_secs(3600));
+
+        assert!(test_bed.has_heat_fault(WindowType::Windshield));
+        assert!(test_bed.has_heat_fault(WindowType::SideWindow));
+        assert!(
+            test_bed
+                .window_temperature(WindowType::Windshield)
+                .get::<degree_celsius>()
+                < 61.
+        );
+    }
+
+    #[test]
+    fn no_heat_fault_when_heating_normally() {
+        let mut test_bed = test_bed().run_for(Duration::from_secs(1));
+
+        assert!(!test_bed.has_heat_fault(WindowType::Windshield));
+        assert!(!test_bed.has_heat_fault(WindowType::SideWindow));
+    }
+
+    #[test]
+    fn failed_heater_reports_heat_fault() {
+        let mut test_bed = test_bed_with()
+            .failed_heater(WindowType::SideWindow)
+            .run_for(Duration::from_secs(1));
+
+        assert!(test_bed.has_heat_fault(WindowType::SideWindow));
+        assert!(!test_bed.has_heat_fault(WindowType::Windshield));
+    }
+
+    #[test]
+    fn unpowered_heater_reports_heat_fault() {
+        let mut test_bed = test_bed_with()
+            .windshield_heater_unpowered()
+            .run_for(Duration::from_secs(1));
+
+        assert!(test_bed.has_heat_fault(WindowType::Windshield));
+        assert!(!test_bed.has_heat_fault(WindowType::SideWindow));
+    }
+
+    #[test]
+    fn heated_side_window_does_not_fog_in_flight() {
+        let mut test_bed = test_bed().run_for(Duration::from_secs(1800));
+
+        assert!(!test_bed.is_fogged(WindowType::SideWindow));
+    }
+
+    #[test]
+    fn unheated_side_window_fogs_in_flight() {
+        let mut test_bed = test_bed_with()
+            .failed_heater(WindowType::SideWindow)
+            .run_for(Duration::from_secs(1800));
+
+        assert!(test_bed.is_fogged(WindowType::SideWindow));
+    }
+
+    #[test]
+    fn unheated_windshield_ices_up_in_icing_conditions() {
+        let mut test_bed = test_bed_with()
+            .failed_heater(WindowType::Windshield)
+            .and()
+            .in_icing_conditions()
+            .run_for(Duration::from_secs(1800));
+
+        assert!(test_bed.windshield_visibility() < 0.1);
+    }
+
+    #[test]
+    fn heated_windshield_stays_clear_in_icing_conditions() {
+        let mut test_bed = test_bed_with()
+            .in_icing_conditions()
+            .run_for(Duration::from_secs(1800));
+
+        assert!(test_bed.windshield_visibility() > 0.95);
+    }
+
+    #[test]
+    fn heaters_do_not_consume_power_when_not_heating() {
+        let test_bed = test_bed_with().on_ground().run_for(Duration::from_secs(1));
+
+        assert_eq!(test_bed.power_consumption(), Power::default());
+    }
+}
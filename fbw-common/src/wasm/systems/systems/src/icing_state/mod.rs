@@ -89,6 +89,10 @@ impl IcingState {
             .max(Ratio::new::<ratio>(0.));
     }
 
+    pub fn icing_state_normalized(&self) -> Ratio {
+        self.icing_state_normalized
+    }
+
     fn is_in_icing_conditions(context: &UpdateContext) -> bool {
         context.ambient_temperature().get::<degree_celsius>() < Self::NO_ICING_TEMP_C
             && (context.is_in_cloud()
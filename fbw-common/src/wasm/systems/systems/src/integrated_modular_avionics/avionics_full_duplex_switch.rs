@@ -6,18 +6,35 @@ use crate::{
     shared::{power_supply_relay::PowerSupplyRelay, ElectricalBusType, ElectricalBuses},
     simulation::{
         InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
-        SimulatorWriter, VariableIdentifier, Write,
+        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
     },
 };
 use fxhash::FxHashMap;
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, rc::Rc, time::Duration};
+
+/// A message as it is stored on the switch, together with the simulation time at which it was sent.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AvionicsFullDuplexSwitchMessage<MessageData: Clone + PartialEq> {
+    message: AvionicsDataCommunicationNetworkMessage<MessageData>,
+    sent_at: Duration,
+}
+
+/// The messages shared by all switches which can reach each other within a network.
+pub type AvionicsFullDuplexSwitchMessages<MessageData> = Rc<
+    RefCell<
+        FxHashMap<
+            AvionicsDataCommunicationNetworkMessageIdentifier,
+            AvionicsFullDuplexSwitchMessage<MessageData>,
+        >,
+    >,
+>;
 
 enum PowerSupply {
     Single(ElectricalBusType),
     Relay(PowerSupplyRelay),
 }
 
-pub struct AvionicsFullDuplexSwitch<MessageData: Clone + Eq + PartialEq> {
+pub struct AvionicsFullDuplexSwitch<MessageData: Clone + PartialEq> {
     power_supply: PowerSupply,
     last_is_powered: bool,
     is_powered: bool,
@@ -26,17 +43,11 @@ pub struct AvionicsFullDuplexSwitch<MessageData: Clone + Eq + PartialEq> {
     failure_indication: bool,
     available_id: VariableIdentifier,
     routing_update_required: bool,
-    adcn_messages: Rc<
-        RefCell<
-            FxHashMap<
-                AvionicsDataCommunicationNetworkMessageIdentifier,
-                AvionicsDataCommunicationNetworkMessage<MessageData>,
-            >,
-        >,
-    >,
+    simulation_time: Duration,
+    adcn_messages: AvionicsFullDuplexSwitchMessages<MessageData>,
 }
 
-impl<MessageData: Clone + Eq + PartialEq> AvionicsFullDuplexSwitch<MessageData> {
+impl<MessageData: Clone + PartialEq> AvionicsFullDuplexSwitch<MessageData> {
     pub fn new_single_power_supply(
         context: &mut InitContext,
         id: u8,
@@ -51,6 +62,7 @@ impl<MessageData: Clone + Eq + PartialEq> AvionicsFullDuplexSwitch<MessageData>
             failure_indication: false,
             available_id: context.get_identifier(format!("AFDX_SWITCH_{}_AVAIL", id)),
             routing_update_required: false,
+            simulation_time: Duration::ZERO,
             adcn_messages: Rc::new(FxHashMap::default().into()),
         }
     }
@@ -73,6 +85,7 @@ impl<MessageData: Clone + Eq + PartialEq> AvionicsFullDuplexSwitch<MessageData>
             failure_indication: false,
             available_id: context.get_identifier(format!("AFDX_SWITCH_{}_AVAIL", id)),
             routing_update_required: false,
+            simulation_time: Duration::ZERO,
             adcn_messages: Rc::new(FxHashMap::default().into()),
         }
     }
@@ -81,7 +94,11 @@ impl<MessageData: Clone + Eq + PartialEq> AvionicsFullDuplexSwitch<MessageData>
         self.is_powered && !self.failure_indication
     }
 
-    pub fn update(&mut self) {
+    pub fn update(&mut self, context: &UpdateContext) {
+        // All switches advance their clock in the same step, which makes the time stamps of
+        // messages comparable between the networks.
+        self.simulation_time += context.delta();
+
         if let PowerSupply::Relay(ref power_supply_relay) = self.power_supply {
             self.is_powered = power_supply_relay.output_is_powered();
         }
@@ -98,34 +115,30 @@ impl<MessageData: Clone + Eq + PartialEq> AvionicsFullDuplexSwitch<MessageData>
         self.routing_update_required
     }
 
-    pub fn get_adcn_messages(
-        &self,
-    ) -> Rc<
-        RefCell<
-            FxHashMap<
-                AvionicsDataCommunicationNetworkMessageIdentifier,
-                AvionicsDataCommunicationNetworkMessage<MessageData>,
-            >,
-        >,
-    > {
+    pub fn get_adcn_messages(&self) -> AvionicsFullDuplexSwitchMessages<MessageData> {
         self.adcn_messages.clone()
     }
 
     pub fn set_adcn_messages(
         &mut self,
-        adcn_messages: Rc<
-            RefCell<
-                FxHashMap<
-                    AvionicsDataCommunicationNetworkMessageIdentifier,
-                    AvionicsDataCommunicationNetworkMessage<MessageData>,
-                >,
-            >,
-        >,
+        adcn_messages: AvionicsFullDuplexSwitchMessages<MessageData>,
     ) {
         self.adcn_messages = adcn_messages;
     }
+
+    /// Returns the time since the message with the given identifier was last sent,
+    /// or [`None`] if the message is not known to the switch.
+    pub fn message_age(
+        &self,
+        id: &AvionicsDataCommunicationNetworkMessageIdentifier,
+    ) -> Option<Duration> {
+        self.adcn_messages
+            .borrow()
+            .get(id)
+            .map(|message| self.simulation_time.saturating_sub(message.sent_at))
+    }
 }
-impl<MessageData: Clone + Eq + PartialEq> AvionicsDataCommunicationNetworkEndpoint
+impl<MessageData: Clone + PartialEq> AvionicsDataCommunicationNetworkEndpoint
     for AvionicsFullDuplexSwitch<MessageData>
 {
     type MessageData = MessageData;
@@ -134,7 +147,10 @@ impl<MessageData: Clone + Eq + PartialEq> AvionicsDataCommunicationNetworkEndpoi
         &self,
         id: &AvionicsDataCommunicationNetworkMessageIdentifier,
     ) -> Option<AvionicsDataCommunicationNetworkMessage<Self::MessageData>> {
-        self.adcn_messages.borrow().get(id).cloned()
+        self.adcn_messages
+            .borrow()
+            .get(id)
+            .map(|message| message.message.clone())
     }
 
     fn recv_value_and_then<
@@ -145,7 +161,7 @@ impl<MessageData: Clone + Eq + PartialEq> AvionicsDataCommunicationNetworkEndpoi
         f: F,
     ) -> Option<F> {
         if let Some(value) = self.adcn_messages.borrow().get(id) {
-            f(value);
+            f(&value.message);
             None
         } else {
             Some(f)
@@ -157,12 +173,16 @@ impl<MessageData: Clone + Eq + PartialEq> AvionicsDataCommunicationNetworkEndpoi
         id: &AvionicsDataCommunicationNetworkMessageIdentifier,
         value: AvionicsDataCommunicationNetworkMessage<Self::MessageData>,
     ) {
-        self.adcn_messages.borrow_mut().insert(*id, value);
+        self.adcn_messages.borrow_mut().insert(
+            *id,
+            AvionicsFullDuplexSwitchMessage {
+                message: value,
+                sent_at: self.simulation_time,
+            },
+        );
     }
 }
-impl<MessageData: Clone + Eq + PartialEq> SimulationElement
-    for AvionicsFullDuplexSwitch<MessageData>
-{
+impl<MessageData: Clone + PartialEq> SimulationElement for AvionicsFullDuplexSwitch<MessageData> {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         if let PowerSupply::Relay(ref mut power_supply_relay) = self.power_supply {
             power_supply_relay.accept(visitor);
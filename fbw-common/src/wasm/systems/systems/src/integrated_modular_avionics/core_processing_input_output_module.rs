@@ -1,7 +1,8 @@
 use super::{
-    avionics_full_duplex_switch::AvionicsFullDuplexSwitch,
+    avionics_full_duplex_switch::AvionicsFullDuplexSwitch, freshest_switch,
     AvionicsDataCommunicationNetworkEndpoint, AvionicsDataCommunicationNetworkMessage,
     AvionicsDataCommunicationNetworkMessageIdentifier,
+    AvionicsDataCommunicationNetworkMessageSource,
 };
 use crate::{
     shared::{ElectricalBusType, ElectricalBuses},
@@ -31,7 +32,7 @@ impl Display for CpiomId {
     }
 }
 
-pub struct CoreProcessingInputOutputModule<MessageData: Clone + Eq + PartialEq> {
+pub struct CoreProcessingInputOutputModule<MessageData: Clone + PartialEq> {
     power_supply: ElectricalBusType,
     is_powered: bool,
     available_id: VariableIdentifier,
@@ -40,7 +41,8 @@ pub struct CoreProcessingInputOutputModule<MessageData: Clone + Eq + PartialEq>
     connected_switches: Vec<Rc<RefCell<AvionicsFullDuplexSwitch<MessageData>>>>,
 }
 
-impl<MessageData: Clone + Eq + PartialEq> CoreProcessingInputOutputModule<MessageData> {
+impl<MessageData: Clone + PartialEq> CoreProcessingInputOutputModule<MessageData> {
+    /// The connected switches are expected to hold one switch per network, network A first.
     pub fn new(
         context: &mut InitContext,
         name: &str,
@@ -60,9 +62,41 @@ impl<MessageData: Clone + Eq + PartialEq> CoreProcessingInputOutputModule<Messag
     pub fn is_available(&self) -> bool {
         self.is_powered & !self.failure_indication
     }
+
+    /// Returns the network from which [`AvionicsDataCommunicationNetworkEndpoint::recv_value`]
+    /// receives the message with the given identifier.
+    pub fn message_source(
+        &self,
+        id: &AvionicsDataCommunicationNetworkMessageIdentifier,
+    ) -> AvionicsDataCommunicationNetworkMessageSource {
+        freshest_switch(self.reachable_switches(), id).map_or(
+            AvionicsDataCommunicationNetworkMessageSource::NoNetwork,
+            |(source, _)| source,
+        )
+    }
+
+    /// The switches through which the module can exchange messages. An unavailable module
+    /// neither sends nor receives, and nothing is exchanged through an unavailable switch.
+    fn reachable_switches(
+        &self,
+    ) -> impl Iterator<
+        Item = (
+            AvionicsDataCommunicationNetworkMessageSource,
+            &Rc<RefCell<AvionicsFullDuplexSwitch<MessageData>>>,
+        ),
+    > {
+        let is_available = self.is_available();
+        [
+            AvionicsDataCommunicationNetworkMessageSource::NetworkA,
+            AvionicsDataCommunicationNetworkMessageSource::NetworkB,
+        ]
+        .into_iter()
+        .zip(&self.connected_switches)
+        .filter(move |(_, switch)| is_available && switch.borrow().is_available())
+    }
 }
 
-impl<MessageData: Clone + Eq + PartialEq> AvionicsDataCommunicationNetworkEndpoint
+impl<MessageData: Clone + PartialEq> AvionicsDataCommunicationNetworkEndpoint
     for CoreProcessingInputOutputModule<MessageData>
 {
     type MessageData = MessageData;
@@ -71,10 +105,8 @@ impl<MessageData: Clone + Eq + PartialEq> AvionicsDataCommunicationNetworkEndpoi
         &self,
         id: &AvionicsDataCommunicationNetworkMessageIdentifier,
     ) -> Option<AvionicsDataCommunicationNetworkMessage<Self::MessageData>> {
-        // TODO: check if there is a newer message on the other networks
-        self.connected_switches
-            .iter()
-            .find_map(|switch| switch.borrow().recv_value(id))
+        freshest_switch(self.reachable_switches(), id)
+            .and_then(|(_, switch)| switch.borrow().recv_value(id))
     }
 
     fn recv_value_and_then<
@@ -82,15 +114,12 @@ impl<MessageData: Clone + Eq + PartialEq> AvionicsDataCommunicationNetworkEndpoi
     >(
         &self,
         id: &AvionicsDataCommunicationNetworkMessageIdentifier,
-        mut f: F,
+        f: F,
     ) -> Option<F> {
-        for switch in &self.connected_switches {
-            match switch.borrow().recv_value_and_then(id, f) {
-                None => return None,
-                Some(new_f) => f = new_f,
-            }
+        match freshest_switch(self.reachable_switches(), id) {
+            Some((_, switch)) => switch.borrow().recv_value_and_then(id, f),
+            None => Some(f),
         }
-        Some(f)
     }
 
     fn send_value(
@@ -98,13 +127,13 @@ impl<MessageData: Clone + Eq + PartialEq> AvionicsDataCommunicationNetworkEndpoi
         id: &AvionicsDataCommunicationNetworkMessageIdentifier,
         value: AvionicsDataCommunicationNetworkMessage<Self::MessageData>,
     ) {
-        for switch in &self.connected_switches {
-            switch.borrow_mut().send_value(id, value.clone());
+        for (_, switch) in self.reachable_switches() {
+            switch.borrow().send_value(id, value.clone());
         }
     }
 }
 
-impl<MessageData: Clone + Eq + PartialEq> SimulationElement
+impl<MessageData: Clone + PartialEq> SimulationElement
     for CoreProcessingInputOutputModule<MessageData>
 {
     fn read(&mut self, reader: &mut SimulatorReader) {
@@ -1,7 +1,8 @@
 use super::{
-    avionics_full_duplex_switch::AvionicsFullDuplexSwitch,
+    avionics_full_duplex_switch::AvionicsFullDuplexSwitch, freshest_switch,
     AvionicsDataCommunicationNetworkEndpoint, AvionicsDataCommunicationNetworkMessage,
     AvionicsDataCommunicationNetworkMessageIdentifier,
+    AvionicsDataCommunicationNetworkMessageSource,
 };
 use crate::{
     shared::{ElectricalBusType, ElectricalBuses},
@@ -12,7 +13,7 @@ use crate::{
 };
 use std::{cell::RefCell, rc::Rc};
 
-pub struct InputOutputModule<MessageData: Clone + Eq + PartialEq> {
+pub struct InputOutputModule<MessageData: Clone + PartialEq> {
     power_supply: ElectricalBusType,
     is_powered: bool,
     available_id: VariableIdentifier,
@@ -21,7 +22,8 @@ pub struct InputOutputModule<MessageData: Clone + Eq + PartialEq> {
     connected_switches: Vec<Rc<RefCell<AvionicsFullDuplexSwitch<MessageData>>>>,
 }
 
-impl<MessageData: Clone + Eq + PartialEq> InputOutputModule<MessageData> {
+impl<MessageData: Clone + PartialEq> InputOutputModule<MessageData> {
+    /// The connected switches are expected to hold one switch per network, network A first.
     pub fn new(
         context: &mut InitContext,
         name: &str,
@@ -41,9 +43,41 @@ impl<MessageData: Clone + Eq + PartialEq> InputOutputModule<MessageData> {
     pub fn is_available(&self) -> bool {
         self.is_powered & !self.failure_indication
     }
+
+    /// Returns the network from which [`AvionicsDataCommunicationNetworkEndpoint::recv_value`]
+    /// receives the message with the given identifier.
+    pub fn message_source(
+        &self,
+        id: &AvionicsDataCommunicationNetworkMessageIdentifier,
+    ) -> AvionicsDataCommunicationNetworkMessageSource {
+        freshest_switch(self.reachable_switches(), id).map_or(
+            AvionicsDataCommunicationNetworkMessageSource::NoNetwork,
+            |(source, _)| source,
+        )
+    }
+
+    /// The switches through which the module can exchange messages. An unavailable module
+    /// neither sends nor receives, and nothing is exchanged through an unavailable switch.
+    fn reachable_switches(
+        &self,
+    ) -> impl Iterator<
+        Item = (
+            AvionicsDataCommunicationNetworkMessageSource,
+            &Rc<RefCell<AvionicsFullDuplexSwitch<MessageData>>>,
+        ),
+    > {
+        let is_available = self.is_available();
+        [
+            AvionicsDataCommunicationNetworkMessageSource::NetworkA,
+            AvionicsDataCommunicationNetworkMessageSource::NetworkB,
+        ]
+        .into_iter()
+        .zip(&self.connected_switches)
+        .filter(move |(_, switch)| is_available && switch.borrow().is_available())
+    }
 }
 
-impl<MessageData: Clone + Eq + PartialEq> AvionicsDataCommunicationNetworkEndpoint
+impl<MessageData: Clone + PartialEq> AvionicsDataCommunicationNetworkEndpoint
     for InputOutputModule<MessageData>
 {
     type MessageData = MessageData;
@@ -52,10 +86,8 @@ impl<MessageData: Clone + Eq + PartialEq> AvionicsDataCommunicationNetworkEndpoi
         &self,
         id: &AvionicsDataCommunicationNetworkMessageIdentifier,
     ) -> Option<AvionicsDataCommunicationNetworkMessage<Self::MessageData>> {
-        // TODO: check if there is a newer message on the other networks
-        self.connected_switches
-            .iter()
-            .find_map(|switch| switch.borrow().recv_value(id))
+        freshest_switch(self.reachable_switches(), id)
+            .and_then(|(_, switch)| switch.borrow().recv_value(id))
     }
 
     fn recv_value_and_then<
@@ -63,15 +95,12 @@ impl<MessageData: Clone + Eq + PartialEq> AvionicsDataCommunicationNetworkEndpoi
     >(
         &self,
         id: &AvionicsDataCommunicationNetworkMessageIdentifier,
-        mut f: F,
+        f: F,
     ) -> Option<F> {
-        for switch in &self.connected_switches {
-            match switch.borrow().recv_value_and_then(id, f) {
-                None => return None,
-                Some(new_f) => f = new_f,
-            }
+        match freshest_switch(self.reachable_switches(), id) {
+            Some((_, switch)) => switch.borrow().recv_value_and_then(id, f),
+            None => Some(f),
         }
-        Some(f)
     }
 
     fn send_value(
@@ -79,13 +108,13 @@ impl<MessageData: Clone + Eq + PartialEq> AvionicsDataCommunicationNetworkEndpoi
         id: &AvionicsDataCommunicationNetworkMessageIdentifier,
         value: AvionicsDataCommunicationNetworkMessage<Self::MessageData>,
     ) {
-        for switch in &self.connected_switches {
-            switch.borrow_mut().send_value(id, value.clone());
+        for (_, switch) in self.reachable_switches() {
+            switch.borrow().send_value(id, value.clone());
         }
     }
 }
 
-impl<MessageData: Clone + Eq + PartialEq> SimulationElement for InputOutputModule<MessageData> {
+impl<MessageData: Clone + PartialEq> SimulationElement for InputOutputModule<MessageData> {
     fn read(&mut self, reader: &mut SimulatorReader) {
         self.failure_indication = reader.read(&self.failure_indication_id);
     }
@@ -1,8 +1,9 @@
 use self::{
+    avionics_full_duplex_switch::AvionicsFullDuplexSwitch,
     core_processing_input_output_module::CoreProcessingInputOutputModule,
     input_output_module::InputOutputModule,
 };
-use std::ops::Deref;
+use std::{cell::RefCell, ops::Deref, rc::Rc, time::Duration};
 
 pub mod avionics_full_duplex_switch;
 pub mod core_processing_input_output_module;
@@ -19,7 +20,7 @@ impl AvionicsDataCommunicationNetworkMessageIdentifier {
 }
 
 pub trait AvionicsDataCommunicationNetworkEndpoint {
-    type MessageData: Clone + Eq + PartialEq;
+    type MessageData: Clone + PartialEq;
 
     /// Receives a value based on the provided identifier.
     ///
@@ -49,8 +50,47 @@ pub trait AvionicsDataCommunicationNetworkEndpoint {
     );
 }
 
+/// The redundant network from which a module received a message.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AvionicsDataCommunicationNetworkMessageSource {
+    NetworkA,
+    NetworkB,
+    /// Neither network provides a fresh copy of the message.
+    NoNetwork,
+}
+
+/// Messages which have not been sent again within this time are considered stale.
+const STALE_MESSAGE_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Selects the switch holding the freshest copy of a message among the switches a module is
+/// connected to. Stale copies are ignored and network A is preferred when both copies are
+/// equally fresh.
+fn freshest_switch<'s, MessageData: Clone + PartialEq>(
+    switches: impl Iterator<
+        Item = (
+            AvionicsDataCommunicationNetworkMessageSource,
+            &'s Rc<RefCell<AvionicsFullDuplexSwitch<MessageData>>>,
+        ),
+    >,
+    id: &AvionicsDataCommunicationNetworkMessageIdentifier,
+) -> Option<(
+    AvionicsDataCommunicationNetworkMessageSource,
+    &'s Rc<RefCell<AvionicsFullDuplexSwitch<MessageData>>>,
+)> {
+    switches
+        .filter_map(|(source, switch)| {
+            switch
+                .borrow()
+                .message_age(id)
+                .filter(|&age| age <= STALE_MESSAGE_TIMEOUT)
+                .map(|age| (age, source, switch))
+        })
+        .min_by_key(|&(age, _, _)| age)
+        .map(|(_, source, switch)| (source, switch))
+}
+
 /// Represents an endpoint in the Avionics Data Communication Network.
-pub trait AvionicsDataCommunicationNetwork<'a, MessageData: Clone + Eq + PartialEq> {
+pub trait AvionicsDataCommunicationNetwork<'a, MessageData: Clone + PartialEq> {
     type NetworkEndpoint: AvionicsDataCommunicationNetworkEndpoint<MessageData = MessageData>;
     type NetworkEndpointRef: Deref<Target = Self::NetworkEndpoint>;
 
@@ -75,13 +115,12 @@ pub type AvionicsDataCommunicationNetworkMessage<MessageData> =
     AvionicsDataCommunicationNetworkMessageFunctionalDataSet<MessageData>;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct AvionicsDataCommunicationNetworkMessageFunctionalDataSet<
-    MessageData: Clone + Eq + PartialEq,
-> {
+pub struct AvionicsDataCommunicationNetworkMessageFunctionalDataSet<MessageData: Clone + PartialEq>
+{
     status: AvionicsDataCommunicationNetworkMessageFunctionalDataSetStatus,
     data: MessageData,
 }
-impl<MessageData: Clone + Eq + PartialEq>
+impl<MessageData: Clone + PartialEq>
     AvionicsDataCommunicationNetworkMessageFunctionalDataSet<MessageData>
 {
     pub fn new(
@@ -2,7 +2,7 @@ use std::time::Duration;
 
 use crate::{
     failures::{Failure, FailureType},
-    shared::arinc429::{Arinc429Word, SignStatus},
+    shared::arinc429::{Arinc429Bus, Arinc429LabelDefinition, Arinc429Word, SignStatus},
     shared::{
         height_over_ground, ElectricalBusType, ElectricalBuses, GearWheel, LandingGearHandle,
         LgciuDoorPosition, LgciuGearControl, LgciuGearExtension, LgciuId, LgciuInterface,
@@ -902,10 +902,21 @@ pub struct LandingGearControlInterfaceUnit {
 
     should_open_doors: bool,
     should_extend_gears: bool,
+
+    bus: Arinc429Bus,
 }
 impl LandingGearControlInterfaceUnit {
     const MAX_TRANSITION_DURATION: Duration = Duration::from_secs(30);
 
+    pub const DISCRETE_WORD_1: Arinc429LabelDefinition =
+        Arinc429LabelDefinition::discrete(0o020, Duration::from_millis(50));
+    pub const DISCRETE_WORD_2: Arinc429LabelDefinition =
+        Arinc429LabelDefinition::discrete(0o021, Duration::from_millis(50));
+    pub const DISCRETE_WORD_3: Arinc429LabelDefinition =
+        Arinc429LabelDefinition::discrete(0o022, Duration::from_millis(50));
+    pub const DISCRETE_WORD_4: Arinc429LabelDefinition =
+        Arinc429LabelDefinition::discrete(0o023, Duration::from_millis(50));
+
     pub fn new(
         context: &mut InitContext,
         lgciu_id: LgciuId,
@@ -969,6 +980,16 @@ impl LandingGearControlInterfaceUnit {
 
             should_open_doors: false,
             should_extend_gears: context.start_gear_down(),
+
+            bus: Arinc429Bus::new(
+                lgciu_number(lgciu_id),
+                &[
+                    Self::DISCRETE_WORD_1,
+                    Self::DISCRETE_WORD_2,
+                    Self::DISCRETE_WORD_3,
+                    Self::DISCRETE_WORD_4,
+                ],
+            ),
         }
     }
 
@@ -1024,6 +1045,29 @@ impl LandingGearControlInterfaceUnit {
 
         self.is_active_computer_previous_state = is_master_computer;
         self.is_powered_previous_state = self.is_powered;
+
+        self.update_bus(context);
+    }
+
+    fn update_bus(&mut self, context: &UpdateContext) {
+        if self.is_powered {
+            self.bus
+                .transmit_discrete(Self::DISCRETE_WORD_1.label(), self.discrete_word_1());
+            self.bus
+                .transmit_discrete(Self::DISCRETE_WORD_2.label(), self.discrete_word_2());
+            self.bus
+                .transmit_discrete(Self::DISCRETE_WORD_3.label(), self.discrete_word_3());
+            self.bus
+                .transmit_discrete(Self::DISCRETE_WORD_4.label(), self.discrete_word_4());
+        } else {
+            self.bus.stop_transmission();
+        }
+
+        self.bus.update(context.delta());
+    }
+
+    pub fn bus(&self) -> &Arinc429Bus {
+        &self.bus
     }
 
     fn update_monitoring(&mut self, context: &UpdateContext, gear_handle: &impl LandingGearHandle) {
@@ -1304,10 +1348,26 @@ impl SimulationElement for LandingGearControlInterfaceUnit {
 
         writer.write(&self.fault_ecam_id, self.status() != LgciuStatus::Ok);
 
-        writer.write(&self.discrete_word_1_id, self.discrete_word_1());
-        writer.write(&self.discrete_word_2_id, self.discrete_word_2());
-        writer.write(&self.discrete_word_3_id, self.discrete_word_3());
-        writer.write(&self.discrete_word_4_id, self.discrete_word_4());
+        self.bus.write_received(
+            writer,
+            &self.discrete_word_1_id,
+            Self::DISCRETE_WORD_1.label(),
+        );
+        self.bus.write_received(
+            writer,
+            &self.discrete_word_2_id,
+            Self::DISCRETE_WORD_2.label(),
+        );
+        self.bus.write_received(
+            writer,
+            &self.discrete_word_3_id,
+            Self::DISCRETE_WORD_3.label(),
+        );
+        self.bus.write_received(
+            writer,
+            &self.discrete_word_4_id,
+            Self::DISCRETE_WORD_4.label(),
+        );
     }
 }
 
@@ -1813,6 +1873,27 @@ mod tests {
         assert!(test_bed.contains_variable_with_name("LGCIU_2_DISCRETE_WORD_4"));
     }
 
+    #[test]
+    fn lgcius_transmit_discrete_words_on_the_bus() {
+        let mut test_bed = test_bed_on_ground_with().on_the_ground();
+        test_bed = test_bed.run_one_tick();
+
+        let word_1 = test_bed
+            .query(|a| {
+                a.lgcius
+                    .lgciu1()
+                    .bus()
+                    .receive_discrete(LandingGearControlInterfaceUnit::DISCRETE_WORD_1.label())
+            })
+            .unwrap();
+        assert!(word_1.is_normal_operation());
+        assert_eq!(
+            word_1.value(),
+            test_bed.query(|a| a.lgcius.lgciu1().discrete_word_1().value())
+        );
+        assert!(word_1.get_bit(29));
+    }
+
     #[test]
     fn all_weight_on_wheels_when_all_compressed() {
         let test_bed = run_test_bed_on_with_compression(
@@ -15,14 +15,17 @@ pub mod electrical;
 pub mod engine;
 pub mod enhanced_gpwc;
 pub mod failures;
+pub mod fire_protection;
 pub mod fuel;
 pub mod hydraulic;
+pub mod ice_rain_protection;
 pub mod icing_state;
 pub mod indicating_recording;
 pub mod integrated_modular_avionics;
 pub mod landing_gear;
 pub mod navigation;
 pub mod overhead;
+pub mod oxygen;
 pub mod payload;
 pub mod physics;
 pub mod pneumatic;
@@ -1,12 +1,15 @@
+use super::gps::{GpsReceiver, GPS_LATITUDE, GPS_LONGITUDE};
 use crate::air_conditioning::AdirsToAirCondInterface;
-use crate::shared::InternationalStandardAtmosphere;
+use crate::shared::{random_from_range, InternationalStandardAtmosphere};
 use crate::simulation::{InitContext, VariableIdentifier};
 use crate::{
+    failures::{Failure, FailureType},
     overhead::{IndicationLight, OnOffFaultPushButton},
     shared::{
-        arinc429::{Arinc429Word, SignStatus},
+        arinc429::{Arinc429Bus, Arinc429LabelDefinition, Arinc429Word, SignStatus},
         low_pass_filter::LowPassFilter,
-        AdirsDiscreteOutputs, AdirsMeasurementOutputs, MachNumber,
+        AdirsDiscreteOutputs, AdirsMeasurementOutputs, AirDataProbes, ElectricalBusType,
+        ElectricalBuses, MachNumber, ProbeType,
     },
     simulation::{
         Read, Reader, SimulationElement, SimulationElementVisitor, SimulatorReader,
@@ -15,21 +18,115 @@ use crate::{
 };
 use bitflags::bitflags;
 use nalgebra::{Rotation2, Vector2};
-use std::{fmt::Display, time::Duration};
+use std::{f64::consts::PI, fmt::Display, time::Duration};
 use uom::si::acceleration::meter_per_second_squared;
 use uom::si::pressure::inch_of_mercury;
 use uom::si::{
     angle::degree,
     angle::radian,
     angular_velocity::degree_per_second,
+    electric_potential::volt,
     f64::*,
     length::foot,
     pressure::hectopascal,
     ratio::ratio,
+    thermodynamic_temperature::degree_celsius,
     time::second,
     velocity::{foot_per_minute, foot_per_second, knot},
 };
 
+// Labels transmitted by the air data reference (ADR) of each ADIRU.
+pub const ADR_ALTITUDE: Arinc429LabelDefinition =
+    Arinc429LabelDefinition::bnr(0o203, 17, 1., Duration::from_micros(62_500));
+pub const ADR_BARO_CORRECTED_ALTITUDE_1: Arinc429LabelDefinition =
+    Arinc429LabelDefinition::bnr(0o204, 17, 1., Duration::from_micros(62_500));
+pub const ADR_MACH: Arinc429LabelDefinition =
+    Arinc429LabelDefinition::bnr(0o205, 16, 0.0000625, Duration::from_millis(125));
+pub const ADR_COMPUTED_AIRSPEED: Arinc429LabelDefinition =
+    Arinc429LabelDefinition::bnr(0o206, 14, 0.0625, Duration::from_millis(125));
+pub const ADR_MAX_AIRSPEED: Arinc429LabelDefinition =
+    Arinc429LabelDefinition::bnr(0o207, 12, 0.25, Duration::from_millis(125));
+pub const ADR_TRUE_AIRSPEED: Arinc429LabelDefinition =
+    Arinc429LabelDefinition::bnr(0o210, 15, 0.0625, Duration::from_millis(125));
+pub const ADR_TOTAL_AIR_TEMPERATURE: Arinc429LabelDefinition =
+    Arinc429LabelDefinition::bnr(0o211, 11, 0.25, Duration::from_millis(500));
+pub const ADR_BAROMETRIC_VERTICAL_SPEED: Arinc429LabelDefinition =
+    Arinc429LabelDefinition::bnr(0o212, 11, 16., Duration::from_micros(62_500));
+pub const ADR_STATIC_AIR_TEMPERATURE: Arinc429LabelDefinition =
+    Arinc429LabelDefinition::bnr(0o213, 11, 0.25, Duration::from_millis(500));
+pub const ADR_BARO_CORRECTED_ALTITUDE_2: Arinc429LabelDefinition =
+    Arinc429LabelDefinition::bnr(0o220, 17, 1., Duration::from_micros(62_500));
+pub const ADR_BARO_CORRECTION_1_HPA: Arinc429LabelDefinition =
+    Arinc429LabelDefinition::bnr(0o234, 16, 0.03125, Duration::from_millis(125));
+pub const ADR_BARO_CORRECTION_1_INHG: Arinc429LabelDefinition =
+    Arinc429LabelDefinition::bnr(0o235, 16, 0.0009765625, Duration::from_millis(125));
+pub const ADR_BARO_CORRECTION_2_HPA: Arinc429LabelDefinition =
+    Arinc429LabelDefinition::bnr(0o236, 16, 0.03125, Duration::from_millis(125));
+pub const ADR_BARO_CORRECTION_2_INHG: Arinc429LabelDefinition =
+    Arinc429LabelDefinition::bnr(0o237, 16, 0.0009765625, Duration::from_millis(125));
+pub const ADR_ANGLE_OF_ATTACK: Arinc429LabelDefinition =
+    Arinc429LabelDefinition::bnr(0o241, 12, 0.0439453125, Duration::from_micros(62_500));
+pub const ADR_CORRECTED_AVERAGE_STATIC_PRESSURE: Arinc429LabelDefinition =
+    Arinc429LabelDefinition::bnr(0o246, 16, 0.03125, Duration::from_millis(125));
+pub const ADR_DISCRETE_WORD_1: Arinc429LabelDefinition =
+    Arinc429LabelDefinition::discrete(0o270, Duration::from_millis(250));
+
+// Labels transmitted by the inertial reference (IR) of each ADIRU.
+pub const IR_WIND_DIRECTION: Arinc429LabelDefinition =
+    Arinc429LabelDefinition::bcd(0o015, 3, 1., Duration::from_millis(500));
+pub const IR_WIND_SPEED: Arinc429LabelDefinition =
+    Arinc429LabelDefinition::bcd(0o016, 3, 1., Duration::from_millis(500));
+pub const IR_HYBRID_LATITUDE: Arinc429LabelDefinition =
+    Arinc429LabelDefinition::bnr(0o254, 18, 0.0006866455078125, Duration::from_millis(200));
+pub const IR_HYBRID_LONGITUDE: Arinc429LabelDefinition =
+    Arinc429LabelDefinition::bnr(0o255, 18, 0.0006866455078125, Duration::from_millis(200));
+pub const IR_MAINT_WORD: Arinc429LabelDefinition =
+    Arinc429LabelDefinition::discrete(0o270, Duration::from_millis(200));
+pub const IR_LATITUDE: Arinc429LabelDefinition =
+    Arinc429LabelDefinition::bnr(0o310, 18, 0.0006866455078125, Duration::from_millis(200));
+pub const IR_LONGITUDE: Arinc429LabelDefinition =
+    Arinc429LabelDefinition::bnr(0o311, 18, 0.0006866455078125, Duration::from_millis(200));
+pub const IR_GROUND_SPEED: Arinc429LabelDefinition =
+    Arinc429LabelDefinition::bnr(0o312, 15, 0.125, Duration::from_millis(50));
+pub const IR_TRUE_TRACK: Arinc429LabelDefinition =
+    Arinc429LabelDefinition::bnr(0o313, 15, 0.0054931640625, Duration::from_millis(50));
+pub const IR_TRUE_HEADING: Arinc429LabelDefinition =
+    Arinc429LabelDefinition::bnr(0o314, 15, 0.0054931640625, Duration::from_millis(50));
+pub const IR_WIND_DIRECTION_BNR: Arinc429LabelDefinition =
+    Arinc429LabelDefinition::bnr(0o315, 12, 0.0439453125, Duration::from_millis(100));
+pub const IR_WIND_SPEED_BNR: Arinc429LabelDefinition =
+    Arinc429LabelDefinition::bnr(0o316, 8, 1., Duration::from_millis(100));
+pub const IR_TRACK: Arinc429LabelDefinition =
+    Arinc429LabelDefinition::bnr(0o317, 15, 0.0054931640625, Duration::from_millis(50));
+pub const IR_HEADING: Arinc429LabelDefinition =
+    Arinc429LabelDefinition::bnr(0o320, 15, 0.0054931640625, Duration::from_millis(50));
+pub const IR_DRIFT_ANGLE: Arinc429LabelDefinition =
+    Arinc429LabelDefinition::bnr(0o321, 12, 0.0439453125, Duration::from_millis(50));
+pub const IR_FLIGHT_PATH_ANGLE: Arinc429LabelDefinition =
+    Arinc429LabelDefinition::bnr(0o322, 12, 0.0439453125, Duration::from_millis(40));
+pub const IR_PITCH: Arinc429LabelDefinition =
+    Arinc429LabelDefinition::bnr(0o324, 14, 0.010986328125, Duration::from_millis(20));
+pub const IR_ROLL: Arinc429LabelDefinition =
+    Arinc429LabelDefinition::bnr(0o325, 14, 0.010986328125, Duration::from_millis(20));
+pub const IR_BODY_PITCH_RATE: Arinc429LabelDefinition =
+    Arinc429LabelDefinition::bnr(0o326, 13, 0.015625, Duration::from_millis(20));
+pub const IR_BODY_ROLL_RATE: Arinc429LabelDefinition =
+    Arinc429LabelDefinition::bnr(0o327, 13, 0.015625, Duration::from_millis(20));
+pub const IR_BODY_YAW_RATE: Arinc429LabelDefinition =
+    Arinc429LabelDefinition::bnr(0o330, 13, 0.015625, Duration::from_millis(20));
+pub const IR_BODY_LONGITUDINAL_ACC: Arinc429LabelDefinition =
+    Arinc429LabelDefinition::bnr(0o331, 12, 0.0009765625, Duration::from_millis(20));
+pub const IR_BODY_LATERAL_ACC: Arinc429LabelDefinition =
+    Arinc429LabelDefinition::bnr(0o332, 12, 0.0009765625, Duration::from_millis(20));
+pub const IR_BODY_NORMAL_ACC: Arinc429LabelDefinition =
+    Arinc429LabelDefinition::bnr(0o333, 12, 0.0009765625, Duration::from_millis(20));
+pub const IR_PITCH_ATT_RATE: Arinc429LabelDefinition =
+    Arinc429LabelDefinition::bnr(0o336, 13, 0.015625, Duration::from_millis(20));
+pub const IR_ROLL_ATT_RATE: Arinc429LabelDefinition =
+    Arinc429LabelDefinition::bnr(0o337, 13, 0.015625, Duration::from_millis(20));
+pub const IR_VERTICAL_SPEED: Arinc429LabelDefinition =
+    Arinc429LabelDefinition::bnr(0o365, 15, 1., Duration::from_millis(40));
+
 pub struct AirDataInertialReferenceSystemOverheadPanel {
     ir: [OnOffFaultPushButton; 3],
     mode_selectors: [InertialReferenceModeSelector; 3],
@@ -38,8 +135,6 @@ pub struct AirDataInertialReferenceSystemOverheadPanel {
 }
 impl AirDataInertialReferenceSystemOverheadPanel {
     const ADIRS_ON_BAT_NAME: &'static str = "ADIRS_ON_BAT";
-    const DURATION_AFTER_WHICH_ON_BAT_ILLUMINATES: Duration = Duration::from_millis(10500);
-    const ON_BAT_ILLUMINATION_DURATION: Duration = Duration::from_millis(5500);
 
     pub fn new(context: &mut InitContext) -> Self {
         Self {
@@ -62,23 +157,8 @@ impl AirDataInertialReferenceSystemOverheadPanel {
         }
     }
 
-    pub fn update(&mut self, context: &UpdateContext, adirs: &AirDataInertialReferenceSystem) {
-        self.mode_selectors.iter_mut().for_each(|mode_selector| {
-            mode_selector.update(context);
-        });
-
-        // Having the illumination logic here and in the mode selectors is
-        // a bit silly, as this depends on whether or not any IR is supplied by
-        // the battery. Once the battery supplying the IR is modelled, this logic
-        // can be moved.
-        self.on_bat
-            .set_illuminated(self.mode_selectors.iter().any(|mode_selector| {
-                let duration = mode_selector.not_off_duration();
-                duration >= Self::DURATION_AFTER_WHICH_ON_BAT_ILLUMINATES
-                    && duration
-                        < Self::DURATION_AFTER_WHICH_ON_BAT_ILLUMINATES
-                            + Self::ON_BAT_ILLUMINATION_DURATION
-            }));
+    pub fn update(&mut self, adirs: &AirDataInertialReferenceSystem) {
+        self.on_bat.set_illuminated(adirs.any_ir_is_on_battery());
 
         self.ir
             .iter_mut()
@@ -131,7 +211,6 @@ impl From<f64> for InertialReferenceMode {
 struct InertialReferenceModeSelector {
     mode_id: VariableIdentifier,
     mode: InertialReferenceMode,
-    not_off_duration: Duration,
 }
 impl InertialReferenceModeSelector {
     fn new(context: &mut InitContext, number: usize) -> Self {
@@ -140,7 +219,6 @@ impl InertialReferenceModeSelector {
             // We start in an aligned state to support starting on the
             // runway or in the air.
             mode: InertialReferenceMode::Navigation,
-            not_off_duration: Duration::from_secs(0),
         }
     }
 
@@ -151,18 +229,6 @@ impl InertialReferenceModeSelector {
     fn mode(&self) -> InertialReferenceMode {
         self.mode
     }
-
-    fn not_off_duration(&self) -> Duration {
-        self.not_off_duration
-    }
-
-    fn update(&mut self, context: &UpdateContext) {
-        if self.mode == InertialReferenceMode::Off {
-            self.not_off_duration = Duration::from_secs(0)
-        } else {
-            self.not_off_duration += context.delta();
-        }
-    }
 }
 impl SimulationElement for InertialReferenceModeSelector {
     fn read(&mut self, reader: &mut SimulatorReader) {
@@ -249,6 +315,15 @@ struct AdirsSimulatorData {
     baro_correction_2_id: VariableIdentifier,
     /// Baro correction for fo's side in hPa from the FCU
     baro_correction_2: Arinc429Word<f64>,
+
+    fm_present_latitude_ids: [VariableIdentifier; 2],
+    fm_present_longitude_ids: [VariableIdentifier; 2],
+    fm_set_heading_ids: [VariableIdentifier; 2],
+    /// Present position entered on the FMS for the IR alignment, from FM1 or else FM2.
+    present_latitude: Arinc429Word<Angle>,
+    present_longitude: Arinc429Word<Angle>,
+    /// Magnetic heading entered on the FMS while in ATT mode, from FM1 or else FM2.
+    set_heading: Arinc429Word<Angle>,
 }
 impl AdirsSimulatorData {
     const MACH: &'static str = "AIRSPEED MACH";
@@ -270,6 +345,9 @@ impl AdirsSimulatorData {
     const ANGLE_OF_ATTACK: &'static str = "INCIDENCE ALPHA";
     const BARO_CORRECTION_1_HPA: &'static str = "FCU_LEFT_EIS_BARO_HPA";
     const BARO_CORRECTION_2_HPA: &'static str = "FCU_RIGHT_EIS_BARO_HPA";
+    const FM_PRESENT_LATITUDE: &'static str = "IRS_PRESENT_LATITUDE";
+    const FM_PRESENT_LONGITUDE: &'static str = "IRS_PRESENT_LONGITUDE";
+    const FM_SET_HEADING: &'static str = "IRS_SET_HEADING";
 
     fn new(context: &mut InitContext) -> Self {
         Self {
@@ -330,8 +408,35 @@ impl AdirsSimulatorData {
 
             baro_correction_2_id: context.get_identifier(Self::BARO_CORRECTION_2_HPA.to_owned()),
             baro_correction_2: Arinc429Word::new(1013., SignStatus::FailureWarning),
+
+            fm_present_latitude_ids: Self::fm_ids(context, Self::FM_PRESENT_LATITUDE),
+            fm_present_longitude_ids: Self::fm_ids(context, Self::FM_PRESENT_LONGITUDE),
+            fm_set_heading_ids: Self::fm_ids(context, Self::FM_SET_HEADING),
+            present_latitude: Arinc429Word::new(Angle::default(), SignStatus::FailureWarning),
+            present_longitude: Arinc429Word::new(Angle::default(), SignStatus::FailureWarning),
+            set_heading: Arinc429Word::new(Angle::default(), SignStatus::FailureWarning),
+        }
+    }
+
+    fn fm_ids(context: &mut InitContext, name: &str) -> [VariableIdentifier; 2] {
+        [1, 2].map(|number| context.get_identifier(format!("FM{}_{}", number, name)))
+    }
+
+    fn read_from_fm(
+        reader: &mut SimulatorReader,
+        ids: &[VariableIdentifier; 2],
+    ) -> Arinc429Word<Angle> {
+        let fm1: Arinc429Word<Angle> = reader.read_arinc429(&ids[0]);
+        if fm1.is_normal_operation() {
+            fm1
+        } else {
+            reader.read_arinc429(&ids[1])
         }
     }
+
+    fn present_position_is_entered(&self) -> bool {
+        self.present_latitude.is_normal_operation() && self.present_longitude.is_normal_operation()
+    }
 }
 impl SimulationElement for AdirsSimulatorData {
     fn read(&mut self, reader: &mut SimulatorReader) {
@@ -359,6 +464,9 @@ impl SimulationElement for AdirsSimulatorData {
         self.angle_of_attack = reader.read(&self.angle_of_attack_id);
         self.baro_correction_1 = reader.read_arinc429(&self.baro_correction_1_id);
         self.baro_correction_2 = reader.read_arinc429(&self.baro_correction_2_id);
+        self.present_latitude = Self::read_from_fm(reader, &self.fm_present_latitude_ids);
+        self.present_longitude = Self::read_from_fm(reader, &self.fm_present_longitude_ids);
+        self.set_heading = Self::read_from_fm(reader, &self.fm_set_heading_ids);
     }
 }
 
@@ -401,6 +509,19 @@ impl AirDataInertialReferenceUnitProgramming {
     }
 }
 
+/// The buses supplying an ADIRU. The unit is normally supplied with AC, and switches over to
+/// the DC hot bus when AC is lost.
+#[derive(Clone, Copy)]
+pub struct AirDataInertialReferenceUnitPowerSupply {
+    ac: ElectricalBusType,
+    dc: ElectricalBusType,
+}
+impl AirDataInertialReferenceUnitPowerSupply {
+    pub fn new(ac: ElectricalBusType, dc: ElectricalBusType) -> Self {
+        Self { ac, dc }
+    }
+}
+
 pub struct AirDataInertialReferenceSystem {
     remaining_alignment_time_id: VariableIdentifier,
     configured_align_time_id: VariableIdentifier,
@@ -422,6 +543,7 @@ impl AirDataInertialReferenceSystem {
     pub fn new(
         context: &mut InitContext,
         programming: AirDataInertialReferenceUnitProgramming,
+        power_supplies: [AirDataInertialReferenceUnitPowerSupply; 3],
     ) -> Self {
         Self {
             remaining_alignment_time_id: context
@@ -431,8 +553,14 @@ impl AirDataInertialReferenceSystem {
             aircraft_preset_quick_mode_id: context
                 .get_identifier(Self::AIRCRAFT_PRESET_QUICK_MODE_KEY.to_owned()),
 
-            adirus: [1, 2, 3]
-                .map(|n| AirDataInertialReferenceUnit::new(context, n, programming.clone())),
+            adirus: [1, 2, 3].map(|n| {
+                AirDataInertialReferenceUnit::new(
+                    context,
+                    n,
+                    programming.clone(),
+                    power_supplies[n - 1],
+                )
+            }),
             configured_align_time: AlignTime::Realistic,
             aircraft_preset_quick_mode: false,
             simulator_data: AdirsSimulatorData::new(context),
@@ -443,6 +571,8 @@ impl AirDataInertialReferenceSystem {
         &mut self,
         context: &UpdateContext,
         overhead: &AirDataInertialReferenceSystemOverheadPanel,
+        probes: &[impl AirDataProbes; 3],
+        gps_receivers: [&impl GpsReceiver; 3],
     ) {
         // adirs_quick_mode is set by the Aircraft Presets to allow expedited presets without
         // changing the alignment time setting
@@ -452,15 +582,21 @@ impl AirDataInertialReferenceSystem {
             self.configured_align_time
         };
         let simulator_data = self.simulator_data;
-        self.adirus.iter_mut().for_each(|adiru| {
-            adiru.update(
-                context,
-                overhead,
-                align_time,
-                self.aircraft_preset_quick_mode,
-                simulator_data,
-            )
-        });
+        self.adirus
+            .iter_mut()
+            .zip(probes)
+            .zip(gps_receivers)
+            .for_each(|((adiru, probes), gps_receiver)| {
+                adiru.receive_gps_position(gps_receiver);
+                adiru.update(
+                    context,
+                    overhead,
+                    probes,
+                    align_time,
+                    self.aircraft_preset_quick_mode,
+                    simulator_data,
+                )
+            });
     }
 
     fn remaining_align_duration(&self) -> Duration {
@@ -478,6 +614,20 @@ impl AirDataInertialReferenceSystem {
     fn ir_has_fault(&self, number: usize) -> bool {
         self.adirus[number - 1].ir_has_fault()
     }
+
+    fn any_ir_is_on_battery(&self) -> bool {
+        self.adirus.iter().any(|adiru| adiru.ir_is_on_battery())
+    }
+
+    /// The output bus of the ADR of the given ADIRU, carrying the `ADR_` labels.
+    pub fn adr_bus(&self, adiru_number: usize) -> &Arinc429Bus {
+        self.adirus[adiru_number - 1].adr_bus()
+    }
+
+    /// The output bus of the IR of the given ADIRU, carrying the `IR_` labels.
+    pub fn ir_bus(&self, adiru_number: usize) -> &Arinc429Bus {
+        self.adirus[adiru_number - 1].ir_bus()
+    }
 }
 impl SimulationElement for AirDataInertialReferenceSystem {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
@@ -543,6 +693,14 @@ impl AdirsMeasurementOutputs for AirDataInertialReferenceSystem {
         self.adirus[adiru_number - 1].longitude()
     }
 
+    fn hybrid_latitude(&self, adiru_number: usize) -> Arinc429Word<Angle> {
+        self.adirus[adiru_number - 1].hybrid_latitude()
+    }
+
+    fn hybrid_longitude(&self, adiru_number: usize) -> Arinc429Word<Angle> {
+        self.adirus[adiru_number - 1].hybrid_longitude()
+    }
+
     fn heading(&self, adiru_number: usize) -> Arinc429Word<Angle> {
         self.adirus[adiru_number - 1].heading()
     }
@@ -559,6 +717,10 @@ impl AdirsMeasurementOutputs for AirDataInertialReferenceSystem {
         self.adirus[adiru_number - 1].altitude()
     }
 
+    fn computed_airspeed(&self, adiru_number: usize) -> Arinc429Word<Velocity> {
+        self.adirus[adiru_number - 1].computed_airspeed()
+    }
+
     fn angle_of_attack(&self, adiru_number: usize) -> Arinc429Word<Angle> {
         self.adirus[adiru_number - 1].angle_of_attack()
     }
@@ -579,11 +741,12 @@ impl AirDataInertialReferenceUnit {
         context: &mut InitContext,
         number: usize,
         programming: AirDataInertialReferenceUnitProgramming,
+        power_supply: AirDataInertialReferenceUnitPowerSupply,
     ) -> Self {
         Self {
             state_id: context.get_identifier(Self::state_id(number)),
             adr: AirDataReference::new(context, number, programming.vmo, programming.mmo),
-            ir: InertialReference::new(context, number),
+            ir: InertialReference::new(context, number, power_supply),
 
             low_speed_warning_thresholds: programming.low_speed_warning_thresholds,
             low_speed_warnings: [false; 4],
@@ -594,14 +757,19 @@ impl AirDataInertialReferenceUnit {
         &mut self,
         context: &UpdateContext,
         overhead: &AirDataInertialReferenceSystemOverheadPanel,
+        probes: &impl AirDataProbes,
         align_time: AlignTime,
         // TODO: this should be refactored (abstracted) away from the AirInertialReferenceUnit struct
         aircraft_preset_quick_mode: bool,
         simulator_data: AdirsSimulatorData,
     ) {
+        // The ADR and IR share the power supply of the unit, which the IR monitors.
+        let mode = self.ir.powered_mode(overhead);
         self.adr.update(
             context,
             overhead,
+            mode,
+            probes,
             aircraft_preset_quick_mode,
             simulator_data,
         );
@@ -617,6 +785,10 @@ impl AirDataInertialReferenceUnit {
         self.update_discrete_outputs();
     }
 
+    fn receive_gps_position(&mut self, gps_receiver: &impl GpsReceiver) {
+        self.ir.receive_gps_position(gps_receiver);
+    }
+
     fn is_fully_aligned(&self) -> bool {
         self.ir.is_fully_aligned()
     }
@@ -643,6 +815,18 @@ impl AirDataInertialReferenceUnit {
         self.ir.has_fault()
     }
 
+    fn ir_is_on_battery(&self) -> bool {
+        self.ir.is_on_battery()
+    }
+
+    fn adr_bus(&self) -> &Arinc429Bus {
+        &self.adr.bus
+    }
+
+    fn ir_bus(&self) -> &Arinc429Bus {
+        &self.ir.bus
+    }
+
     // When the ADR is unpowered (i.e. knob is set to OFF), all discretes go to open circuit (false).
     // Discrete #4 is inverted, so ground when below 260kts, OC otherwise.
     // When CAS is invalid but the ADR is otherwise powered (currently only possible when ADR p/b is off),
@@ -700,6 +884,14 @@ impl AirDataInertialReferenceUnit {
         self.ir.longitude()
     }
 
+    fn hybrid_latitude(&self) -> Arinc429Word<Angle> {
+        self.ir.hybrid_latitude()
+    }
+
+    fn hybrid_longitude(&self) -> Arinc429Word<Angle> {
+        self.ir.hybrid_longitude()
+    }
+
     fn heading(&self) -> Arinc429Word<Angle> {
         self.ir.heading()
     }
@@ -716,6 +908,10 @@ impl AirDataInertialReferenceUnit {
         self.adr.altitude()
     }
 
+    fn computed_airspeed(&self) -> Arinc429Word<Velocity> {
+        self.adr.computed_airspeed()
+    }
+
     fn ground_speed(&self) -> Arinc429Word<Velocity> {
         self.ir.ground_speed()
     }
@@ -851,10 +1047,6 @@ impl<T: Copy + Default + PartialOrd> AdirsArinc429Data<T> {
         }
     }
 
-    fn write_to<U: Write<T> + Writer>(&self, writer: &mut U) {
-        writer.write_arinc429(&self.id, self.value, self.ssm);
-    }
-
     fn write_to_converted<U: Write<f64> + Writer, V: Fn(T) -> f64>(
         &self,
         writer: &mut U,
@@ -862,6 +1054,58 @@ impl<T: Copy + Default + PartialOrd> AdirsArinc429Data<T> {
     ) {
         writer.write_arinc429(&self.id, convert(self.value), self.ssm);
     }
+
+    /// Writes the word as received from the bus, instead of the value computed this update.
+    fn write_received_to<U: Writer>(
+        &self,
+        writer: &mut U,
+        bus: &Arinc429Bus,
+        label: &Arinc429LabelDefinition,
+    ) {
+        bus.write_received(writer, &self.id, label.label());
+    }
+
+    /// Writes the angle as received from the bus, in the range of [0, 360).
+    fn write_received_heading_to<U: Writer>(
+        &self,
+        writer: &mut U,
+        bus: &Arinc429Bus,
+        label: &Arinc429LabelDefinition,
+    ) {
+        bus.write_received_converted(writer, &self.id, label.label(), |value| {
+            Angle::new::<degree>(value).normalised().get::<degree>()
+        });
+    }
+
+    fn transmit_on<V: Fn(T) -> f64>(
+        &self,
+        bus: &mut Arinc429Bus,
+        label: &Arinc429LabelDefinition,
+        convert: V,
+    ) {
+        bus.transmit(
+            label.label(),
+            Arinc429Word::new(convert(self.value), self.ssm),
+        );
+    }
+}
+impl AdirsArinc429Data<u32> {
+    /// Transmits the discrete word, of which the value holds the bits from bit 11 on.
+    fn transmit_discrete_on(&self, bus: &mut Arinc429Bus, label: &Arinc429LabelDefinition) {
+        bus.transmit_discrete(label.label(), Arinc429Word::new(self.value << 10, self.ssm));
+    }
+
+    /// Writes the discrete word as received from the bus, with the bits from bit 11 on.
+    fn write_received_discrete_to<U: Writer>(
+        &self,
+        writer: &mut U,
+        bus: &Arinc429Bus,
+        label: &Arinc429LabelDefinition,
+    ) {
+        bus.write_received_converted(writer, &self.id, label.label(), |value| {
+            ((value as u32) >> 10) as f64
+        });
+    }
 }
 
 #[derive(Clone, Copy)]
@@ -950,6 +1194,8 @@ struct AirDataReference {
     vertical_speed_filter: LowPassFilter<f64>,
 
     remaining_initialisation_duration: Option<Duration>,
+
+    bus: Arinc429Bus,
 }
 impl AirDataReference {
     const INITIALISATION_DURATION: Duration = Duration::from_secs(18);
@@ -1058,6 +1304,29 @@ impl AirDataReference {
 
             // Start fully initialised.
             remaining_initialisation_duration: Some(Duration::from_secs(0)),
+
+            bus: Arinc429Bus::new(
+                number as u8,
+                &[
+                    ADR_ALTITUDE,
+                    ADR_BARO_CORRECTED_ALTITUDE_1,
+                    ADR_MACH,
+                    ADR_COMPUTED_AIRSPEED,
+                    ADR_MAX_AIRSPEED,
+                    ADR_TRUE_AIRSPEED,
+                    ADR_TOTAL_AIR_TEMPERATURE,
+                    ADR_BAROMETRIC_VERTICAL_SPEED,
+                    ADR_STATIC_AIR_TEMPERATURE,
+                    ADR_BARO_CORRECTED_ALTITUDE_2,
+                    ADR_BARO_CORRECTION_1_HPA,
+                    ADR_BARO_CORRECTION_1_INHG,
+                    ADR_BARO_CORRECTION_2_HPA,
+                    ADR_BARO_CORRECTION_2_INHG,
+                    ADR_ANGLE_OF_ATTACK,
+                    ADR_CORRECTED_AVERAGE_STATIC_PRESSURE,
+                    ADR_DISCRETE_WORD_1,
+                ],
+            ),
         }
     }
 
@@ -1065,24 +1334,91 @@ impl AirDataReference {
         &mut self,
         context: &UpdateContext,
         overhead: &AirDataInertialReferenceSystemOverheadPanel,
+        mode: InertialReferenceMode,
+        probes: &impl AirDataProbes,
         aircraft_preset_quick_mode: bool,
         simulator_data: AdirsSimulatorData,
     ) {
         self.is_on = overhead.adr_is_on(self.number);
-        self.update_remaining_initialisation_duration(
-            context,
-            aircraft_preset_quick_mode,
-            overhead,
-        );
-        self.update_values(context, simulator_data);
-        self.update_discrete_word_1();
+        self.update_remaining_initialisation_duration(context, aircraft_preset_quick_mode, mode);
+        self.update_values(context, simulator_data, probes);
+        self.update_discrete_word_1(probes);
+        self.update_bus(context);
+    }
+
+    /// The ADR stops transmitting when its ADIRU is switched off.
+    fn update_bus(&mut self, context: &UpdateContext) {
+        if self.remaining_initialisation_duration.is_some() {
+            let bus = &mut self.bus;
+            self.altitude
+                .transmit_on(bus, &ADR_ALTITUDE, |value| value.get::<foot>());
+            self.baro_corrected_altitude_1.transmit_on(
+                bus,
+                &ADR_BARO_CORRECTED_ALTITUDE_1,
+                |value| value.get::<foot>(),
+            );
+            self.baro_corrected_altitude_2.transmit_on(
+                bus,
+                &ADR_BARO_CORRECTED_ALTITUDE_2,
+                |value| value.get::<foot>(),
+            );
+            self.mach.transmit_on(bus, &ADR_MACH, |value| value.0);
+            self.computed_airspeed
+                .transmit_on(bus, &ADR_COMPUTED_AIRSPEED, |value| value.get::<knot>());
+            self.max_airspeed
+                .transmit_on(bus, &ADR_MAX_AIRSPEED, |value| value.get::<knot>());
+            self.true_airspeed
+                .transmit_on(bus, &ADR_TRUE_AIRSPEED, |value| value.get::<knot>());
+            self.total_air_temperature
+                .transmit_on(bus, &ADR_TOTAL_AIR_TEMPERATURE, |value| {
+                    value.get::<degree_celsius>()
+                });
+            self.static_air_temperature
+                .transmit_on(bus, &ADR_STATIC_AIR_TEMPERATURE, |value| {
+                    value.get::<degree_celsius>()
+                });
+            self.barometric_vertical_speed.transmit_on(
+                bus,
+                &ADR_BAROMETRIC_VERTICAL_SPEED,
+                |value| value,
+            );
+            self.baro_correction_1_hpa
+                .transmit_on(bus, &ADR_BARO_CORRECTION_1_HPA, |value| {
+                    value.get::<hectopascal>()
+                });
+            self.baro_correction_1_inhg
+                .transmit_on(bus, &ADR_BARO_CORRECTION_1_INHG, |value| {
+                    value.get::<inch_of_mercury>()
+                });
+            self.baro_correction_2_hpa
+                .transmit_on(bus, &ADR_BARO_CORRECTION_2_HPA, |value| {
+                    value.get::<hectopascal>()
+                });
+            self.baro_correction_2_inhg
+                .transmit_on(bus, &ADR_BARO_CORRECTION_2_INHG, |value| {
+                    value.get::<inch_of_mercury>()
+                });
+            self.corrected_average_static_pressure.transmit_on(
+                bus,
+                &ADR_CORRECTED_AVERAGE_STATIC_PRESSURE,
+                |value| value.get::<hectopascal>(),
+            );
+            self.angle_of_attack
+                .transmit_on(bus, &ADR_ANGLE_OF_ATTACK, |value| value.get::<degree>());
+            self.discrete_word_1
+                .transmit_discrete_on(bus, &ADR_DISCRETE_WORD_1);
+        } else {
+            self.bus.stop_transmission();
+        }
+
+        self.bus.update(context.delta());
     }
 
     fn update_remaining_initialisation_duration(
         &mut self,
         context: &UpdateContext,
         aircraft_preset_quick_mode: bool,
-        overhead: &AirDataInertialReferenceSystemOverheadPanel,
+        mode: InertialReferenceMode,
     ) {
         let initialisation_duration: Duration = if aircraft_preset_quick_mode {
             Self::INITIALISATION_QUICK_DURATION
@@ -1092,7 +1428,7 @@ impl AirDataReference {
         self.remaining_initialisation_duration = remaining_initialisation_duration(
             context,
             initialisation_duration,
-            overhead.mode_of(self.number),
+            mode,
             self.remaining_initialisation_duration,
         );
     }
@@ -1128,7 +1464,18 @@ impl AirDataReference {
         })
     }
 
-    fn update_values(&mut self, context: &UpdateContext, simulator_data: AdirsSimulatorData) {
+    /// The ratio of the sensed to the actual dynamic pressure is reduced by ice blocking
+    /// the pitot probe, as the pressure in the probe then leaks away through the drain holes.
+    fn pitot_pressure_ratio(probes: &impl AirDataProbes) -> f64 {
+        (1. - probes.pitot_blockage().get::<ratio>()).clamp(0., 1.)
+    }
+
+    fn update_values(
+        &mut self,
+        context: &UpdateContext,
+        simulator_data: AdirsSimulatorData,
+        probes: &impl AirDataProbes,
+    ) {
         // For now some of the data will be read from the context. Later the context will no longer
         // contain this information (and instead all usages will be replaced by requests to the ADIRUs).
 
@@ -1243,8 +1590,11 @@ impl AirDataReference {
             self.barometric_vertical_speed
                 .set_normal_operation_value(self.vertical_speed_filter.output());
 
+            // The airspeeds are proportional to the square root of the sensed dynamic pressure.
+            let airspeed_factor = Self::pitot_pressure_ratio(probes).sqrt();
+
             // If CAS is below 30kn, output as 0 with SSM = NCD
-            let computed_airspeed = context.indicated_airspeed();
+            let computed_airspeed = context.indicated_airspeed() * airspeed_factor;
             self.computed_airspeed.normal_above_threshold_ncd_otherwise(
                 Velocity::new::<knot>(Self::MINIMUM_CAS),
                 computed_airspeed,
@@ -1260,13 +1610,13 @@ impl AirDataReference {
             // If mach is below 0.1, output as 0 with SSM = NCD
             self.mach.normal_above_threshold_ncd_otherwise(
                 MachNumber::from(Self::MINIMUM_MACH),
-                simulator_data.mach,
+                MachNumber::from(f64::from(simulator_data.mach) * airspeed_factor),
             );
 
             // If TAS is below 60 kts, output as 0 kt with SSM = NCD.
             self.true_airspeed.normal_above_threshold_ncd_otherwise(
                 Velocity::new::<knot>(Self::MINIMUM_TAS),
-                simulator_data.true_airspeed,
+                simulator_data.true_airspeed * airspeed_factor,
             );
 
             self.angle_of_attack.set_value(
@@ -1285,21 +1635,36 @@ impl AirDataReference {
         }
     }
 
-    fn update_discrete_word_1(&mut self) {
+    fn update_discrete_word_1(&mut self, probes: &impl AirDataProbes) {
         let mut discrete_word = AdrDiscrete1Flags::default();
 
         if self.is_on {
             // FIXME implement icing detector heat
-            // FIXME implement pitot heat
+            discrete_word.set(
+                AdrDiscrete1Flags::PITOT_HEAT,
+                probes.probe_heat_fault(ProbeType::Pitot),
+            );
 
             if !self.is_valid() {
                 discrete_word |= AdrDiscrete1Flags::ADR_STATUS_FAIL;
             }
 
-            // FIXME implement right static heat
-            // FIXME implement left static heat
-            // FIXME implement TAT heat
-            // FIXME implement NO1 AOA sensor heat
+            discrete_word.set(
+                AdrDiscrete1Flags::RIGHT_STATIC_HEAT,
+                probes.probe_heat_fault(ProbeType::RightStatic),
+            );
+            discrete_word.set(
+                AdrDiscrete1Flags::LEFT_STATIC_HEAT,
+                probes.probe_heat_fault(ProbeType::LeftStatic),
+            );
+            discrete_word.set(
+                AdrDiscrete1Flags::TAT_HEAT,
+                probes.probe_heat_fault(ProbeType::TotalAirTemperature),
+            );
+            discrete_word.set(
+                AdrDiscrete1Flags::AOA_1_SENSOR_HEAT,
+                probes.probe_heat_fault(ProbeType::AngleOfAttack),
+            );
             // FIXME implement NO2 AOA sensor heat
 
             if self.is_overspeed {
@@ -1335,6 +1700,10 @@ impl AirDataReference {
         self.computed_airspeed.value()
     }
 
+    fn computed_airspeed(&self) -> Arinc429Word<Velocity> {
+        Arinc429Word::new(self.computed_airspeed.value(), self.computed_airspeed.ssm())
+    }
+
     fn altitude(&self) -> Arinc429Word<Length> {
         Arinc429Word::new(self.altitude.value(), self.altitude.ssm())
     }
@@ -1372,28 +1741,51 @@ impl TrueAirspeedSource for AirDataReference {
 }
 impl SimulationElement for AirDataReference {
     fn write(&self, writer: &mut SimulatorWriter) {
+        let bus = &self.bus;
         self.baro_correction_1_hpa
-            .write_to_converted(writer, |value| value.get::<hectopascal>());
+            .write_received_to(writer, bus, &ADR_BARO_CORRECTION_1_HPA);
         self.baro_correction_1_inhg
-            .write_to_converted(writer, |value| value.get::<inch_of_mercury>());
+            .write_received_to(writer, bus, &ADR_BARO_CORRECTION_1_INHG);
         self.baro_correction_2_hpa
-            .write_to_converted(writer, |value| value.get::<hectopascal>());
+            .write_received_to(writer, bus, &ADR_BARO_CORRECTION_2_HPA);
         self.baro_correction_2_inhg
-            .write_to_converted(writer, |value| value.get::<inch_of_mercury>());
-        self.corrected_average_static_pressure
-            .write_to_converted(writer, |value| value.get::<hectopascal>());
-        self.altitude.write_to(writer);
-        self.baro_corrected_altitude_1.write_to(writer);
-        self.baro_corrected_altitude_2.write_to(writer);
-        self.computed_airspeed.write_to(writer);
-        self.max_airspeed.write_to(writer);
-        self.mach.write_to(writer);
-        self.barometric_vertical_speed.write_to(writer);
-        self.true_airspeed.write_to(writer);
-        self.static_air_temperature.write_to(writer);
-        self.total_air_temperature.write_to(writer);
-        self.angle_of_attack.write_to(writer);
-        self.discrete_word_1.write_to(writer);
+            .write_received_to(writer, bus, &ADR_BARO_CORRECTION_2_INHG);
+        self.corrected_average_static_pressure.write_received_to(
+            writer,
+            bus,
+            &ADR_CORRECTED_AVERAGE_STATIC_PRESSURE,
+        );
+        self.altitude.write_received_to(writer, bus, &ADR_ALTITUDE);
+        self.baro_corrected_altitude_1.write_received_to(
+            writer,
+            bus,
+            &ADR_BARO_CORRECTED_ALTITUDE_1,
+        );
+        self.baro_corrected_altitude_2.write_received_to(
+            writer,
+            bus,
+            &ADR_BARO_CORRECTED_ALTITUDE_2,
+        );
+        self.computed_airspeed
+            .write_received_to(writer, bus, &ADR_COMPUTED_AIRSPEED);
+        self.max_airspeed
+            .write_received_to(writer, bus, &ADR_MAX_AIRSPEED);
+        self.mach.write_received_to(writer, bus, &ADR_MACH);
+        self.barometric_vertical_speed.write_received_to(
+            writer,
+            bus,
+            &ADR_BAROMETRIC_VERTICAL_SPEED,
+        );
+        self.true_airspeed
+            .write_received_to(writer, bus, &ADR_TRUE_AIRSPEED);
+        self.static_air_temperature
+            .write_received_to(writer, bus, &ADR_STATIC_AIR_TEMPERATURE);
+        self.total_air_temperature
+            .write_received_to(writer, bus, &ADR_TOTAL_AIR_TEMPERATURE);
+        self.angle_of_attack
+            .write_received_to(writer, bus, &ADR_ANGLE_OF_ATTACK);
+        self.discrete_word_1
+            .write_received_discrete_to(writer, bus, &ADR_DISCRETE_WORD_1);
     }
 }
 
@@ -1446,9 +1838,88 @@ bitflags! {
     }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum InertialReferencePowerSource {
+    Unpowered,
+    Ac,
+    Dc,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum InertialReferenceState {
+    Off,
+    Align,
+    Navigation,
+    Attitude,
+    Fault,
+}
+
+/// The position error an IR accumulates in navigation. It grows with the time since the alignment
+/// completed, oscillates with the Schuler period, and is larger after a poor alignment.
+#[derive(Clone, Copy)]
+struct InertialDrift {
+    /// The rate at which the error grows, in nautical miles per hour.
+    rate: f64,
+    /// The amplitude of the Schuler oscillation, in nautical miles.
+    schuler_amplitude: f64,
+    /// The true bearing along which the error develops.
+    direction: Angle,
+}
+impl InertialDrift {
+    const SCHULER_PERIOD: Duration = Duration::from_secs(5064);
+    const MINIMUM_RATE_NM_PER_HOUR: f64 = 0.2;
+    const MAXIMUM_RATE_NM_PER_HOUR: f64 = 1.5;
+    const MINIMUM_SCHULER_AMPLITUDE_NM: f64 = 0.1;
+    const MAXIMUM_SCHULER_AMPLITUDE_NM: f64 = 0.5;
+
+    /// An alignment quality of 1 denotes an undisturbed alignment, higher values scale up the
+    /// error.
+    fn new(alignment_quality: f64) -> Self {
+        Self {
+            rate: random_from_range(
+                Self::MINIMUM_RATE_NM_PER_HOUR,
+                Self::MAXIMUM_RATE_NM_PER_HOUR,
+            ) * alignment_quality,
+            schuler_amplitude: random_from_range(
+                Self::MINIMUM_SCHULER_AMPLITUDE_NM,
+                Self::MAXIMUM_SCHULER_AMPLITUDE_NM,
+            ) * alignment_quality,
+            direction: Angle::new::<degree>(random_from_range(0., 360.)),
+        }
+    }
+
+    /// The east and north position error in nautical miles after the given time in navigation.
+    fn error(&self, time_since_alignment: Duration) -> Vector2<f64> {
+        let seconds = time_since_alignment.as_secs_f64();
+        let schuler_phase = 2. * PI * seconds / Self::SCHULER_PERIOD.as_secs_f64();
+        let magnitude = self.rate * seconds / 3600. + self.schuler_amplitude * schuler_phase.sin();
+
+        Vector2::new(
+            self.direction.sin().get::<ratio>(),
+            self.direction.cos().get::<ratio>(),
+        ) * magnitude
+    }
+}
+
 struct InertialReference {
     number: usize,
     is_on: bool,
+    /// The mode selected on the overhead panel, or OFF when the unit is unpowered.
+    mode: InertialReferenceMode,
+    state: InertialReferenceState,
+    failure: Failure,
+
+    power_supply: AirDataInertialReferenceUnitPowerSupply,
+    power_source: InertialReferencePowerSource,
+    dc_potential: ElectricPotential,
+    dc_failed_during_dc_operation: bool,
+    powered_duration: Duration,
+
+    /// Whether the FMS provided a present position for the current alignment.
+    has_present_position: bool,
+    /// The heading last entered on the FMS in ATT mode, and its offset from the
+    /// heading at the time of the entry.
+    set_heading: Option<(Angle, Angle)>,
     /// The remaining time to align, where 0 indicates the IR system is aligned.
     /// None indicates the IR system isn't aligning nor aligned.
     remaining_align_duration: Option<Duration>,
@@ -1461,6 +1932,17 @@ struct InertialReference {
     quick_realign_remaining_available_time: Duration,
     alignment_failed: bool,
 
+    /// The motion during the current alignment, relative to the maximum motion at which the
+    /// alignment can complete.
+    alignment_peak_motion: f64,
+    is_quick_realignment: bool,
+    drift: Option<InertialDrift>,
+    time_since_alignment: Duration,
+    gps_position: Option<(Angle, Angle)>,
+    /// The inertial position error when the hybrid position was last updated from GPS.
+    hybrid_reference_error: Vector2<f64>,
+    is_gps_aided: bool,
+
     pitch: AdirsArinc429Data<Angle>,
     roll: AdirsArinc429Data<Angle>,
     heading: AdirsArinc429Data<Angle>,
@@ -1490,10 +1972,18 @@ struct InertialReference {
     wind_direction_bnr: AdirsArinc429Data<Angle>,
     latitude: AdirsArinc429Data<Angle>,
     longitude: AdirsArinc429Data<Angle>,
+    /// Label 254, 5 Hz [-180, 180)
+    hybrid_latitude: AdirsArinc429Data<Angle>,
+    /// Label 255, 5 Hz [-180, 180)
+    hybrid_longitude: AdirsArinc429Data<Angle>,
     /// label 270
     maint_word: AdirsArinc429Data<u32>,
     align_discrete: AdirsDiscreteOutput<bool>,
     fault_warn_discrete: AdirsDiscreteOutput<bool>,
+    gps_aided_discrete: AdirsDiscreteOutput<bool>,
+
+    wind_is_transmitted: bool,
+    bus: Arinc429Bus,
 }
 impl InertialReference {
     const FAST_ALIGNMENT_TIME_IN_SECS: f64 = 90.;
@@ -1525,9 +2015,12 @@ impl InertialReference {
     const WIND_SPEED_BNR: &'static str = "WIND_SPEED_BNR";
     const LATITUDE: &'static str = "LATITUDE";
     const LONGITUDE: &'static str = "LONGITUDE";
+    const HYBRID_LATITUDE: &'static str = "HYBRID_LATITUDE";
+    const HYBRID_LONGITUDE: &'static str = "HYBRID_LONGITUDE";
     const MAINT_WORD: &'static str = "MAINT_WORD";
     const ALIGN_DISCRETE: &'static str = "ALIGN_DISCRETE";
     const FAULT_WARN_DISCRETE: &'static str = "FAULT_WARN_DISCRETE";
+    const GPS_AIDED_DISCRETE: &'static str = "GPS_AIDED_DISCRETE";
     const MINIMUM_TRUE_AIRSPEED_FOR_WIND_DETERMINATION_KNOTS: f64 = 100.;
     const MINIMUM_GROUND_SPEED_FOR_TRACK_KNOTS: f64 = 50.;
 
@@ -1536,12 +2029,34 @@ impl InertialReference {
     const QUICK_REALIGN_ALIGN_TIME: Duration = Duration::from_secs(180);
     const ALIGNMENT_VELOCITY_TIME_CONSTANT: Duration = Duration::from_millis(500);
     const MAX_ALIGNMENT_VELOCITY_FPS: f64 = 0.011;
+    const QUICK_REALIGNMENT_QUALITY_PENALTY: f64 = 0.5;
     const MAX_LATITUDE_FOR_ALIGNMENT: f64 = 82.;
+    const MINIMUM_DC_VOLTAGE: f64 = 18.;
+    const DURATION_AFTER_WHICH_ON_BAT_ILLUMINATES: Duration = Duration::from_millis(10500);
+    const ON_BAT_ILLUMINATION_DURATION: Duration = Duration::from_millis(5500);
 
-    fn new(context: &mut InitContext, number: usize) -> Self {
+    fn new(
+        context: &mut InitContext,
+        number: usize,
+        power_supply: AirDataInertialReferenceUnitPowerSupply,
+    ) -> Self {
         Self {
             number,
             is_on: true,
+            mode: InertialReferenceMode::Navigation,
+            state: InertialReferenceState::Navigation,
+            failure: Failure::new(FailureType::InertialReference(number)),
+
+            power_supply,
+            power_source: InertialReferencePowerSource::Ac,
+            dc_potential: ElectricPotential::default(),
+            dc_failed_during_dc_operation: false,
+            // Start past the battery test performed when powering up.
+            powered_duration: Self::DURATION_AFTER_WHICH_ON_BAT_ILLUMINATES
+                + Self::ON_BAT_ILLUMINATION_DURATION,
+
+            has_present_position: true,
+            set_heading: None,
             // We start in an aligned state to support starting on the
             // runway or in the air.
             remaining_align_duration: Some(Duration::from_secs(0)),
@@ -1555,6 +2070,14 @@ impl InertialReference {
             quick_realign_remaining_available_time: Duration::default(),
             alignment_failed: false,
 
+            alignment_peak_motion: 0.,
+            is_quick_realignment: false,
+            drift: None,
+            time_since_alignment: Duration::ZERO,
+            gps_position: None,
+            hybrid_reference_error: Vector2::default(),
+            is_gps_aided: false,
+
             pitch: AdirsArinc429Data::new_ir(context, number, Self::PITCH),
             roll: AdirsArinc429Data::new_ir(context, number, Self::ROLL),
             heading: AdirsArinc429Data::new_ir(context, number, Self::HEADING),
@@ -1588,6 +2111,8 @@ impl InertialReference {
             wind_speed_bnr: AdirsArinc429Data::new_ir(context, number, Self::WIND_SPEED_BNR),
             latitude: AdirsArinc429Data::new_ir(context, number, Self::LATITUDE),
             longitude: AdirsArinc429Data::new_ir(context, number, Self::LONGITUDE),
+            hybrid_latitude: AdirsArinc429Data::new_ir(context, number, Self::HYBRID_LATITUDE),
+            hybrid_longitude: AdirsArinc429Data::new_ir(context, number, Self::HYBRID_LONGITUDE),
             maint_word: AdirsArinc429Data::new_ir(context, number, Self::MAINT_WORD),
             align_discrete: AdirsDiscreteOutput::new_ir(context, number, Self::ALIGN_DISCRETE),
             fault_warn_discrete: AdirsDiscreteOutput::new_ir(
@@ -1595,6 +2120,45 @@ impl InertialReference {
                 number,
                 Self::FAULT_WARN_DISCRETE,
             ),
+            gps_aided_discrete: AdirsDiscreteOutput::new_ir(
+                context,
+                number,
+                Self::GPS_AIDED_DISCRETE,
+            ),
+
+            wind_is_transmitted: false,
+            bus: Arinc429Bus::new(
+                number as u8,
+                &[
+                    IR_WIND_DIRECTION,
+                    IR_WIND_SPEED,
+                    IR_HYBRID_LATITUDE,
+                    IR_HYBRID_LONGITUDE,
+                    IR_MAINT_WORD,
+                    IR_LATITUDE,
+                    IR_LONGITUDE,
+                    IR_GROUND_SPEED,
+                    IR_TRUE_TRACK,
+                    IR_TRUE_HEADING,
+                    IR_WIND_DIRECTION_BNR,
+                    IR_WIND_SPEED_BNR,
+                    IR_TRACK,
+                    IR_HEADING,
+                    IR_DRIFT_ANGLE,
+                    IR_FLIGHT_PATH_ANGLE,
+                    IR_PITCH,
+                    IR_ROLL,
+                    IR_BODY_PITCH_RATE,
+                    IR_BODY_ROLL_RATE,
+                    IR_BODY_YAW_RATE,
+                    IR_BODY_LONGITUDINAL_ACC,
+                    IR_BODY_LATERAL_ACC,
+                    IR_BODY_NORMAL_ACC,
+                    IR_PITCH_ATT_RATE,
+                    IR_ROLL_ATT_RATE,
+                    IR_VERTICAL_SPEED,
+                ],
+            ),
         }
     }
 
@@ -1608,36 +2172,232 @@ impl InertialReference {
         simulator_data: AdirsSimulatorData,
     ) {
         self.is_on = overhead.ir_is_on(self.number);
+        self.mode = self.powered_mode(overhead);
+
+        if self.mode == InertialReferenceMode::Off {
+            self.powered_duration = Duration::ZERO;
+        } else {
+            self.powered_duration += context.delta();
+        }
+
+        if overhead.mode_of(self.number) == InertialReferenceMode::Off {
+            self.dc_failed_during_dc_operation = false;
+        }
 
         self.update_body_velocity(context);
 
-        self.update_fault_flash_duration(context, overhead);
-        self.update_remaining_attitude_align_duration(
-            context,
-            overhead,
-            aircraft_preset_quick_mode,
-        );
-        self.update_remaining_align_duration(
-            context,
-            overhead,
-            configured_align_time,
-            simulator_data,
-        );
+        if self.failure.is_active() && self.mode != InertialReferenceMode::Off {
+            // A failed IR loses its alignment and has to be realigned once recovered.
+            self.remaining_align_duration = None;
+            self.remaining_attitude_initialisation_duration = None;
+            self.alignment_failed = false;
+            self.excess_motion = false;
+        } else {
+            self.update_fault_flash_duration(context);
+            self.update_remaining_attitude_align_duration(context, aircraft_preset_quick_mode);
+            self.update_remaining_align_duration(context, configured_align_time, simulator_data);
+        }
+        self.update_state();
+        self.update_set_heading(simulator_data);
+        self.update_drift(context);
 
         self.update_latitude(simulator_data);
         self.update_attitude_values(context, simulator_data);
-        self.update_heading_values(overhead, simulator_data);
-        self.update_non_attitude_values(context, true_airspeed_source, overhead, simulator_data);
-        self.update_maint_word(overhead);
+        self.update_heading_values(simulator_data);
+        self.update_non_attitude_values(context, true_airspeed_source, simulator_data);
+        self.update_hybrid_position(simulator_data);
+        self.update_maint_word();
         self.update_discrete_outputs();
+        self.update_bus(context);
     }
 
-    fn update_fault_flash_duration(
-        &mut self,
-        context: &UpdateContext,
+    /// The mode selected on the overhead panel, when the unit is powered.
+    fn powered_mode(
+        &self,
         overhead: &AirDataInertialReferenceSystemOverheadPanel,
-    ) {
-        if self.alignment_starting(overhead.mode_of(self.number)) {
+    ) -> InertialReferenceMode {
+        if self.power_source == InertialReferencePowerSource::Unpowered {
+            InertialReferenceMode::Off
+        } else {
+            overhead.mode_of(self.number)
+        }
+    }
+
+    fn update_state(&mut self) {
+        self.state = match self.mode {
+            InertialReferenceMode::Off => InertialReferenceState::Off,
+            _ if self.failure.is_active() => InertialReferenceState::Fault,
+            InertialReferenceMode::Attitude => InertialReferenceState::Attitude,
+            InertialReferenceMode::Navigation if self.is_fully_aligned() => {
+                InertialReferenceState::Navigation
+            }
+            InertialReferenceMode::Navigation => InertialReferenceState::Align,
+        };
+    }
+
+    /// In ATT mode the magnetic heading is unknown until it is entered on the FMS, after
+    /// which the IR follows the heading changes from the entered value.
+    fn update_set_heading(&mut self, simulator_data: AdirsSimulatorData) {
+        if self.state != InertialReferenceState::Attitude {
+            self.set_heading = None;
+        } else if self.is_attitude_aligned() && simulator_data.set_heading.is_normal_operation() {
+            let entered_heading = simulator_data.set_heading.value();
+            if self.set_heading.map(|(heading, _)| heading) != Some(entered_heading) {
+                self.set_heading =
+                    Some((entered_heading, entered_heading - simulator_data.heading));
+            }
+        }
+    }
+
+    fn receive_gps_position(&mut self, gps_receiver: &impl GpsReceiver) {
+        let bus = gps_receiver.bus();
+        let latitude = bus
+            .receive_value(GPS_LATITUDE.label())
+            .and_then(|word| word.normal_value());
+        let longitude = bus
+            .receive_value(GPS_LONGITUDE.label())
+            .and_then(|word| word.normal_value());
+
+        self.gps_position = latitude.zip(longitude).map(|(latitude, longitude)| {
+            (
+                Angle::new::<degree>(latitude),
+                Angle::new::<degree>(longitude),
+            )
+        });
+    }
+
+    /// A new drift is drawn each time an alignment completes, and is lost with the alignment.
+    fn update_drift(&mut self, context: &UpdateContext) {
+        if !self.is_fully_aligned() {
+            self.drift = None;
+        } else if self.drift.is_none() {
+            self.drift = Some(InertialDrift::new(self.alignment_quality()));
+            self.time_since_alignment = Duration::ZERO;
+            self.hybrid_reference_error = Vector2::default();
+        } else {
+            self.time_since_alignment += context.delta();
+        }
+    }
+
+    fn alignment_quality(&self) -> f64 {
+        1. + self.alignment_peak_motion
+            + if self.is_quick_realignment {
+                Self::QUICK_REALIGNMENT_QUALITY_PENALTY
+            } else {
+                0.
+            }
+    }
+
+    /// The east and north error of the inertial position in nautical miles.
+    fn position_error(&self) -> Vector2<f64> {
+        self.drift.map_or_else(Vector2::default, |drift| {
+            drift.error(self.time_since_alignment)
+        })
+    }
+
+    /// The hybrid (GPIRS) position is the GPS position while it is available. Without GPS, only
+    /// the inertial error accumulated since the last GPS update affects the hybrid position.
+    fn update_hybrid_position(&mut self, simulator_data: AdirsSimulatorData) {
+        let ssm = self.ssm(self.is_on && self.is_fully_aligned());
+        let gps_position = self.gps_position.filter(|_| self.is_fully_aligned());
+        self.is_gps_aided = gps_position.is_some();
+
+        let (latitude, longitude) = if let Some(gps_position) = gps_position {
+            self.hybrid_reference_error = self.position_error();
+            gps_position
+        } else {
+            offset_position(
+                simulator_data.latitude,
+                simulator_data.longitude,
+                self.position_error() - self.hybrid_reference_error,
+            )
+        };
+        self.hybrid_latitude.set_value(latitude, ssm);
+        self.hybrid_longitude.set_value(longitude, ssm);
+    }
+
+    /// The IR stops transmitting when its ADIRU is switched off.
+    fn update_bus(&mut self, context: &UpdateContext) {
+        if self.mode == InertialReferenceMode::Off {
+            self.bus.stop_transmission();
+        } else {
+            let bus = &mut self.bus;
+            // The BNR angles are transmitted in the range of [-180, 180).
+            let bnr_angle = |value: Angle| value.normalised_180().get::<degree>();
+
+            self.latitude
+                .transmit_on(bus, &IR_LATITUDE, |value| value.get::<degree>());
+            self.longitude
+                .transmit_on(bus, &IR_LONGITUDE, |value| value.get::<degree>());
+            self.hybrid_latitude
+                .transmit_on(bus, &IR_HYBRID_LATITUDE, |value| value.get::<degree>());
+            self.hybrid_longitude
+                .transmit_on(bus, &IR_HYBRID_LONGITUDE, |value| value.get::<degree>());
+            self.ground_speed
+                .transmit_on(bus, &IR_GROUND_SPEED, |value| value.get::<knot>());
+            self.true_track.transmit_on(bus, &IR_TRUE_TRACK, bnr_angle);
+            self.true_heading
+                .transmit_on(bus, &IR_TRUE_HEADING, bnr_angle);
+            self.track.transmit_on(bus, &IR_TRACK, bnr_angle);
+            self.heading.transmit_on(bus, &IR_HEADING, bnr_angle);
+            self.drift_angle
+                .transmit_on(bus, &IR_DRIFT_ANGLE, bnr_angle);
+            self.flight_path_angle
+                .transmit_on(bus, &IR_FLIGHT_PATH_ANGLE, bnr_angle);
+            self.pitch.transmit_on(bus, &IR_PITCH, bnr_angle);
+            self.roll.transmit_on(bus, &IR_ROLL, bnr_angle);
+            self.body_pitch_rate
+                .transmit_on(bus, &IR_BODY_PITCH_RATE, |value| {
+                    value.get::<degree_per_second>()
+                });
+            self.body_roll_rate
+                .transmit_on(bus, &IR_BODY_ROLL_RATE, |value| {
+                    value.get::<degree_per_second>()
+                });
+            self.body_yaw_rate
+                .transmit_on(bus, &IR_BODY_YAW_RATE, |value| {
+                    value.get::<degree_per_second>()
+                });
+            self.body_longitudinal_acc
+                .transmit_on(bus, &IR_BODY_LONGITUDINAL_ACC, |value| value.get::<ratio>());
+            self.body_lateral_acc
+                .transmit_on(bus, &IR_BODY_LATERAL_ACC, |value| value.get::<ratio>());
+            self.body_normal_acc
+                .transmit_on(bus, &IR_BODY_NORMAL_ACC, |value| value.get::<ratio>());
+            self.pitch_att_rate
+                .transmit_on(bus, &IR_PITCH_ATT_RATE, |value| {
+                    value.get::<degree_per_second>()
+                });
+            self.roll_att_rate
+                .transmit_on(bus, &IR_ROLL_ATT_RATE, |value| {
+                    value.get::<degree_per_second>()
+                });
+            self.vertical_speed
+                .transmit_on(bus, &IR_VERTICAL_SPEED, |value| value);
+            self.maint_word.transmit_discrete_on(bus, &IR_MAINT_WORD);
+
+            if self.wind_is_transmitted {
+                self.wind_direction
+                    .transmit_on(bus, &IR_WIND_DIRECTION, |value| value.get::<degree>());
+                self.wind_speed
+                    .transmit_on(bus, &IR_WIND_SPEED, |value| value.get::<knot>());
+                self.wind_direction_bnr
+                    .transmit_on(bus, &IR_WIND_DIRECTION_BNR, |value| value.get::<degree>());
+                self.wind_speed_bnr
+                    .transmit_on(bus, &IR_WIND_SPEED_BNR, |value| value.get::<knot>());
+            } else {
+                bus.stop_transmission_of(IR_WIND_DIRECTION.label());
+                bus.stop_transmission_of(IR_WIND_SPEED.label());
+                bus.stop_transmission_of(IR_WIND_DIRECTION_BNR.label());
+                bus.stop_transmission_of(IR_WIND_SPEED_BNR.label());
+            }
+        }
+
+        self.bus.update(context.delta());
+    }
+
+    fn update_fault_flash_duration(&mut self, context: &UpdateContext) {
+        if self.alignment_starting() {
             self.ir_fault_flash_duration = Some(Self::IR_FAULT_FLASH_DURATION);
         } else if let Some(flash_duration) = self.ir_fault_flash_duration {
             let remaining = subtract_delta_from_duration(context, flash_duration);
@@ -1652,7 +2412,6 @@ impl InertialReference {
     fn update_remaining_attitude_align_duration(
         &mut self,
         context: &UpdateContext,
-        overhead: &AirDataInertialReferenceSystemOverheadPanel,
         aircraft_preset_quick_mode: bool,
     ) {
         let initialisation_duration: Duration = if aircraft_preset_quick_mode {
@@ -1663,7 +2422,7 @@ impl InertialReference {
         self.remaining_attitude_initialisation_duration = remaining_initialisation_duration(
             context,
             initialisation_duration,
-            overhead.mode_of(self.number),
+            self.mode,
             self.remaining_attitude_initialisation_duration,
         );
     }
@@ -1675,15 +2434,12 @@ impl InertialReference {
     fn update_remaining_align_duration(
         &mut self,
         context: &UpdateContext,
-        overhead: &AirDataInertialReferenceSystemOverheadPanel,
         configured_align_time: AlignTime,
         simulator_data: AdirsSimulatorData,
     ) {
         // If the knob is moved out of NAV and back within 5 seconds while aligned, a quick re-alignment
         // is performed. This just zeros the velocities etc.
-        if self.is_fully_aligned()
-            && overhead.mode_of(self.number) != InertialReferenceMode::Navigation
-        {
+        if self.is_fully_aligned() && self.mode != InertialReferenceMode::Navigation {
             self.quick_realign_remaining_available_time = Self::QUICK_REALIGN_AVAILABLE_TIME;
         } else if !self.quick_realign_remaining_available_time.is_zero() {
             self.quick_realign_remaining_available_time = self
@@ -1697,6 +2453,7 @@ impl InertialReference {
         // setting and restart the flight.
         if let AlignTime::Instant = configured_align_time {
             self.remaining_align_duration = Some(Duration::from_secs_f64(0.));
+            self.has_present_position = true;
         } else {
             // If we exceeded the max alignment velocity, the alignment is restarted
             if self.is_aligning()
@@ -1706,30 +2463,51 @@ impl InertialReference {
                 self.excess_motion = true;
             }
 
-            self.remaining_align_duration =
-                match overhead.mode_of(self.number) {
-                    InertialReferenceMode::Navigation => match self.remaining_align_duration {
-                        Some(remaining) => {
-                            if Self::can_align(simulator_data) {
-                                Some(subtract_delta_from_duration(context, remaining))
-                            } else {
-                                Some(remaining)
-                            }
+            self.remaining_align_duration = match self.mode {
+                InertialReferenceMode::Navigation => match self.remaining_align_duration {
+                    Some(remaining) => {
+                        if Self::can_align(simulator_data) {
+                            Some(subtract_delta_from_duration(context, remaining))
+                        } else {
+                            Some(remaining)
                         }
-                        None => Some(self.total_alignment_duration(
+                    }
+                    None => {
+                        // A quick re-alignment keeps the present position, a full alignment
+                        // requires it to be entered again.
+                        self.is_quick_realignment =
+                            !self.quick_realign_remaining_available_time.is_zero();
+                        if !self.is_quick_realignment {
+                            self.has_present_position = false;
+                        }
+                        self.alignment_peak_motion = 0.;
+
+                        Some(self.total_alignment_duration(
                             configured_align_time,
                             simulator_data.latitude,
-                        )),
-                    },
-                    InertialReferenceMode::Off | InertialReferenceMode::Attitude => None,
-                };
+                        ))
+                    }
+                },
+                InertialReferenceMode::Off | InertialReferenceMode::Attitude => None,
+            };
+
+            if self.remaining_align_duration.is_some()
+                && simulator_data.present_position_is_entered()
+            {
+                self.has_present_position = true;
+            }
         }
 
         self.alignment_failed = self.is_aligning() && !Self::can_align(simulator_data);
 
-        if self.is_fully_aligned()
-            || overhead.mode_of(self.number) != InertialReferenceMode::Navigation
-        {
+        // Motion during the alignment degrades the accuracy of the resulting navigation.
+        if self.is_aligning() {
+            self.alignment_peak_motion = self
+                .alignment_peak_motion
+                .max(self.body_velocity_filter.output().amax() / Self::MAX_ALIGNMENT_VELOCITY_FPS);
+        }
+
+        if self.is_fully_aligned() || self.mode != InertialReferenceMode::Navigation {
             self.excess_motion = false;
         }
     }
@@ -1752,11 +2530,7 @@ impl InertialReference {
         context: &UpdateContext,
         simulator_data: AdirsSimulatorData,
     ) {
-        let ssm = if self.is_on && self.is_attitude_aligned() {
-            SignStatus::NormalOperation
-        } else {
-            SignStatus::NoComputedData
-        };
+        let ssm = self.ssm(self.is_on && self.is_attitude_aligned());
         // Calculate the attitudes and body rotation rates.
         // Correct the signs so that they conform to standard aeronautical norms.
         let pitch = -simulator_data.pitch;
@@ -1795,51 +2569,39 @@ impl InertialReference {
             .set_value(context.vert_accel() / g + pitch.cos() * roll.cos(), ssm);
     }
 
-    fn update_heading_values(
-        &mut self,
-        overhead: &AirDataInertialReferenceSystemOverheadPanel,
-        simulator_data: AdirsSimulatorData,
-    ) {
+    fn update_heading_values(&mut self, simulator_data: AdirsSimulatorData) {
         // TODO BNR labels (that most things use) are actually +/- 180
 
         // TODO tests for when should be mag or true in mag labels
 
         let heading_available = self.is_on
             && (self.is_fully_aligned()
-                || (overhead.mode_of(self.number) == InertialReferenceMode::Navigation
+                || (self.mode == InertialReferenceMode::Navigation
                     && self
                         .remaining_align_duration
                         .is_some_and(|duration| duration.as_secs() < 120)));
 
-        let true_heading_ssm = if heading_available {
-            SignStatus::NormalOperation
-        } else {
-            SignStatus::NoComputedData
-        };
         self.true_heading
-            .set_value(simulator_data.true_heading, true_heading_ssm);
+            .set_value(simulator_data.true_heading, self.ssm(heading_available));
 
-        // TODO in ATT mode NCD until heading initialised on MCDU
-        let magnetic_heading_ssm = if self.is_on
-            && (heading_available
-                || (overhead.mode_of(self.number) == InertialReferenceMode::Attitude
-                    && self.is_attitude_aligned()))
-        {
-            SignStatus::NormalOperation
+        if let Some((_, offset)) = self.set_heading {
+            self.heading.set_value(
+                (simulator_data.heading + offset).normalised(),
+                self.ssm(self.is_on),
+            );
         } else {
-            SignStatus::NoComputedData
-        };
-        self.heading.set_value(
-            if self.has_magnetic_data() {
-                simulator_data.heading
-            } else {
-                simulator_data.true_heading
-            },
-            magnetic_heading_ssm,
-        );
-    }
-
-    fn update_body_velocity(&mut self, context: &UpdateContext) {
+            self.heading.set_value(
+                if self.has_magnetic_data() {
+                    simulator_data.heading
+                } else {
+                    simulator_data.true_heading
+                },
+                self.ssm(heading_available),
+            );
+        }
+    }
+
+    fn update_body_velocity(&mut self, context: &UpdateContext) {
         let body_velocity = Vector2::new(
             context
                 .local_velocity()
@@ -1858,13 +2620,12 @@ impl InertialReference {
         &mut self,
         context: &UpdateContext,
         true_airspeed_source: &impl TrueAirspeedSource,
-        overhead: &AirDataInertialReferenceSystemOverheadPanel,
         simulator_data: AdirsSimulatorData,
     ) {
         // In ATT mode these labels are not even transmitted
         // In Align, NCD prior to NAV
 
-        let no_transmission = match overhead.mode_of(self.number) {
+        let no_transmission = match self.mode {
             InertialReferenceMode::Navigation => false,
             InertialReferenceMode::Off | InertialReferenceMode::Attitude => true,
         } || !self.is_on;
@@ -1876,12 +2637,12 @@ impl InertialReference {
             && true_airspeed_source.true_airspeed().value()
                 >= Velocity::new::<knot>(Self::MINIMUM_TRUE_AIRSPEED_FOR_WIND_DETERMINATION_KNOTS);
 
+        // The simvars can't express no transmission, so they indicate a failure warning instead.
+        self.wind_is_transmitted = !no_transmission;
         let ssm = if no_transmission {
-            SignStatus::FailureWarning // TODO should be no transmission
-        } else if true_airspeed_above_minimum_threshold && self.is_fully_aligned() {
-            SignStatus::NormalOperation
+            SignStatus::FailureWarning
         } else {
-            SignStatus::NoComputedData
+            self.ssm(true_airspeed_above_minimum_threshold && self.is_fully_aligned())
         };
 
         // if conditions are valid to calculate wind, we do so, otherwise we send zero
@@ -1929,7 +2690,6 @@ impl InertialReference {
         }
 
         // set all the labels...
-        // TODO build out bus implementation for correct period, bnr/bcd encoding and no transmission state
         self.wind_direction
             .set_value(Angle::new::<degree>(wind_direction.round()), ssm);
 
@@ -1957,14 +2717,9 @@ impl InertialReference {
         &mut self,
         context: &UpdateContext,
         true_airspeed_source: &impl TrueAirspeedSource,
-        overhead: &AirDataInertialReferenceSystemOverheadPanel,
         simulator_data: AdirsSimulatorData,
     ) {
-        let ssm = if self.is_on && self.is_fully_aligned() {
-            SignStatus::NormalOperation
-        } else {
-            SignStatus::NoComputedData
-        };
+        let ssm = self.ssm(self.is_on && self.is_fully_aligned());
 
         let ground_speed_above_minimum_threshold = simulator_data.ground_speed
             >= Velocity::new::<knot>(Self::MINIMUM_GROUND_SPEED_FOR_TRACK_KNOTS);
@@ -2027,14 +2782,18 @@ impl InertialReference {
         self.ground_speed
             .set_value(simulator_data.ground_speed, ssm);
 
-        self.latitude.set_value(simulator_data.latitude, ssm);
-        self.longitude.set_value(simulator_data.longitude, ssm);
+        let (latitude, longitude) = offset_position(
+            simulator_data.latitude,
+            simulator_data.longitude,
+            self.position_error(),
+        );
+        self.latitude.set_value(latitude, ssm);
+        self.longitude.set_value(longitude, ssm);
 
-        self.update_wind_velocity(context, true_airspeed_source, overhead, simulator_data);
+        self.update_wind_velocity(context, true_airspeed_source, simulator_data);
     }
 
-    fn update_maint_word(&mut self, overhead: &AirDataInertialReferenceSystemOverheadPanel) {
-        // TODO check status of these during mode transitions (first need to implement mode FSM)
+    fn update_maint_word(&mut self) {
         let mut maint_word: IrMaintFlags = IrMaintFlags::default();
 
         if !self.is_on {
@@ -2048,33 +2807,45 @@ impl InertialReference {
             maint_word |= IrMaintFlags::ALIGNMENT_NOT_READY;
         }
 
-        if overhead.mode_of(self.number) == InertialReferenceMode::Attitude {
+        if self.mode == InertialReferenceMode::Attitude {
             maint_word |= IrMaintFlags::REV_ATT_MODE;
         }
 
-        if overhead.mode_of(self.number) == InertialReferenceMode::Navigation {
+        if self.mode == InertialReferenceMode::Navigation {
             maint_word |= IrMaintFlags::NAV_MODE;
         }
 
-        // TODO request heading setting in att mode if not set
+        if self.set_heading.is_some() {
+            maint_word |= IrMaintFlags::VALID_SET_HEADING;
+        }
 
-        // TODO attitude invalid fault
+        if self.state == InertialReferenceState::Fault {
+            maint_word |= IrMaintFlags::IR_FAULT | IrMaintFlags::ATTITUDE_INVALID;
+        }
 
-        // TODO dc < 18 V
+        if self.dc_potential < ElectricPotential::new::<volt>(Self::MINIMUM_DC_VOLTAGE) {
+            maint_word |= IrMaintFlags::DC_FAIL;
+        }
 
-        // TODO on DC (re-implement ON BAT light properly at the same time)
+        if self.power_source == InertialReferencePowerSource::Dc {
+            maint_word |= IrMaintFlags::ON_DC;
+        }
 
         // TODO ADR input data fault
 
         // TODO unimportant nav fault
 
-        // TODO DC fault during DC operation last power up
+        if self.dc_failed_during_dc_operation {
+            maint_word |= IrMaintFlags::DC_FAIL_ON_DC;
+        }
 
         if self.alignment_failed {
             maint_word |= IrMaintFlags::ALIGN_FAULT;
         }
 
-        // TODO No IRS initial pos
+        if self.is_aligning() && !self.has_present_position {
+            maint_word |= IrMaintFlags::NO_IRS_INITIAL;
+        }
 
         if self.excess_motion {
             maint_word |= IrMaintFlags::EXCESS_MOTION_ERROR;
@@ -2104,17 +2875,16 @@ impl InertialReference {
 
         self.maint_word
             .set_value(maint_word.bits(), SignStatus::NormalOperation);
-
-        // TODO tests!
     }
 
     fn update_discrete_outputs(&mut self) {
         self.align_discrete.set_value(self.is_aligning());
         self.fault_warn_discrete.set_value(self.has_fault());
+        self.gps_aided_discrete.set_value(self.is_gps_aided);
     }
 
-    fn alignment_starting(&self, selected_mode: InertialReferenceMode) -> bool {
-        selected_mode != InertialReferenceMode::Off
+    fn alignment_starting(&self) -> bool {
+        self.mode != InertialReferenceMode::Off
             && self.remaining_attitude_initialisation_duration.is_none()
     }
 
@@ -2155,14 +2925,11 @@ impl InertialReference {
     }
 
     fn is_fully_aligned(&self) -> bool {
-        self.remaining_align_duration == Some(Duration::ZERO)
+        self.remaining_align_duration == Some(Duration::ZERO) && self.has_present_position
     }
 
     fn is_aligning(&self) -> bool {
-        match self.remaining_align_duration.as_ref() {
-            Some(remaining) => *remaining > Duration::from_secs(0),
-            None => false,
-        }
+        self.remaining_align_duration.is_some() && !self.is_fully_aligned()
     }
 
     fn remaining_align_duration(&self) -> Option<Duration> {
@@ -2174,7 +2941,25 @@ impl InertialReference {
     }
 
     fn has_fault(&self) -> bool {
-        self.ir_fault_flash_duration.is_some()
+        self.ir_fault_flash_duration.is_some() || self.state == InertialReferenceState::Fault
+    }
+
+    fn is_on_battery(&self) -> bool {
+        self.power_source == InertialReferencePowerSource::Dc
+            || (self.powered_duration >= Self::DURATION_AFTER_WHICH_ON_BAT_ILLUMINATES
+                && self.powered_duration
+                    < Self::DURATION_AFTER_WHICH_ON_BAT_ILLUMINATES
+                        + Self::ON_BAT_ILLUMINATION_DURATION)
+    }
+
+    fn ssm(&self, is_valid: bool) -> SignStatus {
+        if self.state == InertialReferenceState::Fault {
+            SignStatus::FailureWarning
+        } else if is_valid {
+            SignStatus::NormalOperation
+        } else {
+            SignStatus::NoComputedData
+        }
     }
 
     fn has_magnetic_data(&self) -> bool {
@@ -2189,6 +2974,14 @@ impl InertialReference {
         Arinc429Word::new(self.longitude.value(), self.longitude.ssm())
     }
 
+    fn hybrid_latitude(&self) -> Arinc429Word<Angle> {
+        Arinc429Word::new(self.hybrid_latitude.value(), self.hybrid_latitude.ssm())
+    }
+
+    fn hybrid_longitude(&self) -> Arinc429Word<Angle> {
+        Arinc429Word::new(self.hybrid_longitude.value(), self.hybrid_longitude.ssm())
+    }
+
     fn heading(&self) -> Arinc429Word<Angle> {
         Arinc429Word::new(self.heading.value(), self.heading.ssm())
     }
@@ -2209,45 +3002,87 @@ impl InertialReference {
     }
 }
 impl SimulationElement for InertialReference {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.dc_potential = buses.potential_of(self.power_supply.dc).raw();
+        let power_source = if buses.is_powered(self.power_supply.ac) {
+            InertialReferencePowerSource::Ac
+        } else if self.dc_potential >= ElectricPotential::new::<volt>(Self::MINIMUM_DC_VOLTAGE) {
+            InertialReferencePowerSource::Dc
+        } else {
+            InertialReferencePowerSource::Unpowered
+        };
+
+        if self.power_source == InertialReferencePowerSource::Dc
+            && power_source == InertialReferencePowerSource::Unpowered
+        {
+            self.dc_failed_during_dc_operation = true;
+        }
+        self.power_source = power_source;
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
-        self.pitch.write_to(writer);
-        self.roll.write_to(writer);
-
-        self.heading.write_to(writer);
-        self.true_heading.write_to(writer);
-        self.track.write_to(writer);
-        self.true_track.write_to(writer);
-        self.drift_angle.write_to(writer);
-        self.flight_path_angle.write_to(writer);
+        let bus = &self.bus;
+        self.pitch.write_received_to(writer, bus, &IR_PITCH);
+        self.roll.write_received_to(writer, bus, &IR_ROLL);
+
+        self.heading
+            .write_received_heading_to(writer, bus, &IR_HEADING);
+        self.true_heading
+            .write_received_heading_to(writer, bus, &IR_TRUE_HEADING);
+        self.track.write_received_heading_to(writer, bus, &IR_TRACK);
+        self.true_track
+            .write_received_heading_to(writer, bus, &IR_TRUE_TRACK);
+        self.drift_angle
+            .write_received_to(writer, bus, &IR_DRIFT_ANGLE);
+        self.flight_path_angle
+            .write_received_to(writer, bus, &IR_FLIGHT_PATH_ANGLE);
         self.body_pitch_rate
-            .write_to_converted(writer, |value| value.get::<degree_per_second>());
+            .write_received_to(writer, bus, &IR_BODY_PITCH_RATE);
         self.body_roll_rate
-            .write_to_converted(writer, |value| value.get::<degree_per_second>());
+            .write_received_to(writer, bus, &IR_BODY_ROLL_RATE);
         self.body_yaw_rate
-            .write_to_converted(writer, |value| value.get::<degree_per_second>());
+            .write_received_to(writer, bus, &IR_BODY_YAW_RATE);
         self.body_longitudinal_acc
-            .write_to_converted(writer, |value| value.get::<ratio>());
+            .write_received_to(writer, bus, &IR_BODY_LONGITUDINAL_ACC);
         self.body_lateral_acc
-            .write_to_converted(writer, |value| value.get::<ratio>());
+            .write_received_to(writer, bus, &IR_BODY_LATERAL_ACC);
         self.body_normal_acc
-            .write_to_converted(writer, |value| value.get::<ratio>());
+            .write_received_to(writer, bus, &IR_BODY_NORMAL_ACC);
         self.heading_rate
             .write_to_converted(writer, |value| value.get::<degree_per_second>());
         self.pitch_att_rate
-            .write_to_converted(writer, |value| value.get::<degree_per_second>());
+            .write_received_to(writer, bus, &IR_PITCH_ATT_RATE);
         self.roll_att_rate
-            .write_to_converted(writer, |value| value.get::<degree_per_second>());
-        self.vertical_speed.write_to(writer);
-        self.ground_speed.write_to(writer);
-        self.wind_direction.write_to(writer);
-        self.wind_direction_bnr.write_to(writer);
-        self.wind_speed.write_to(writer);
-        self.wind_speed_bnr.write_to(writer);
-        self.latitude.write_to(writer);
-        self.longitude.write_to(writer);
-        self.maint_word.write_to(writer);
+            .write_received_to(writer, bus, &IR_ROLL_ATT_RATE);
+        self.vertical_speed
+            .write_received_to(writer, bus, &IR_VERTICAL_SPEED);
+        self.ground_speed
+            .write_received_to(writer, bus, &IR_GROUND_SPEED);
+        self.wind_direction
+            .write_received_to(writer, bus, &IR_WIND_DIRECTION);
+        self.wind_direction_bnr
+            .write_received_to(writer, bus, &IR_WIND_DIRECTION_BNR);
+        self.wind_speed
+            .write_received_to(writer, bus, &IR_WIND_SPEED);
+        self.wind_speed_bnr
+            .write_received_to(writer, bus, &IR_WIND_SPEED_BNR);
+        self.latitude.write_received_to(writer, bus, &IR_LATITUDE);
+        self.longitude.write_received_to(writer, bus, &IR_LONGITUDE);
+        self.hybrid_latitude
+            .write_received_to(writer, bus, &IR_HYBRID_LATITUDE);
+        self.hybrid_longitude
+            .write_received_to(writer, bus, &IR_HYBRID_LONGITUDE);
+        self.maint_word
+            .write_received_discrete_to(writer, bus, &IR_MAINT_WORD);
         self.align_discrete.write_to(writer);
         self.fault_warn_discrete.write_to(writer);
+        self.gps_aided_discrete.write_to(writer);
     }
 }
 
@@ -2270,9 +3105,21 @@ fn subtract_delta_from_duration(context: &UpdateContext, duration: Duration) ->
     Duration::from_secs_f64((duration.as_secs_f64() - context.delta_as_secs_f64()).max(0.))
 }
 
+/// Offsets the position by the east and north distances in nautical miles of the given offset.
+fn offset_position(latitude: Angle, longitude: Angle, offset: Vector2<f64>) -> (Angle, Angle) {
+    // One minute of latitude is one nautical mile, while the minutes of longitude converge towards
+    // the poles.
+    let offset_latitude = (latitude + Angle::new::<degree>(offset.y / 60.))
+        .max(-Angle::HALF_TURN / 2.)
+        .min(Angle::HALF_TURN / 2.);
+    let offset_longitude = longitude
+        + Angle::new::<degree>(offset.x / (60. * latitude.cos().get::<ratio>().max(0.01)));
+
+    (offset_latitude, offset_longitude.normalised_180())
+}
+
 trait NormaliseAngleExt {
     fn normalised(self) -> Angle;
-    #[allow(dead_code)]
     fn normalised_180(self) -> Angle;
 }
 
@@ -2315,9 +3162,11 @@ impl NormaliseAngleExt for Angle {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::electrical::{test::TestElectricitySource, ElectricalBus, Electricity};
+    use crate::navigation::gps::test::TestGpsReceiver;
     use crate::simulation::test::{ReadByName, WriteByName};
     use crate::{
-        shared::arinc429::Arinc429Word,
+        shared::{arinc429::Arinc429Word, PotentialOrigin},
         simulation::{
             test::{SimulationTestBed, TestBed},
             Aircraft, SimulationElementVisitor, SimulatorWriter, UpdateContext,
@@ -2335,9 +3184,32 @@ mod tests {
         velocity::{foot_per_minute, knot},
     };
 
+    #[derive(Default)]
+    struct TestAirDataProbes {
+        heat_fault: Option<ProbeType>,
+        pitot_blockage: Ratio,
+    }
+    impl AirDataProbes for TestAirDataProbes {
+        fn probe_heat_fault(&self, probe: ProbeType) -> bool {
+            self.heat_fault == Some(probe)
+        }
+
+        fn pitot_blockage(&self) -> Ratio {
+            self.pitot_blockage
+        }
+    }
+
     struct TestAircraft {
+        ac_source: TestElectricitySource,
+        battery: TestElectricitySource,
+        ac_buses: [ElectricalBus; 3],
+        dc_hot_buses: [ElectricalBus; 3],
+        is_ac_powered: bool,
+
         adirs: AirDataInertialReferenceSystem,
         overhead: AirDataInertialReferenceSystemOverheadPanel,
+        probes: [TestAirDataProbes; 3],
+        gps_receiver: TestGpsReceiver,
     }
     impl TestAircraft {
         fn new(context: &mut InitContext) -> Self {
@@ -2364,19 +3236,89 @@ mod tests {
                 ],
             );
             Self {
-                adirs: AirDataInertialReferenceSystem::new(context, adirs_programming),
+                ac_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::EngineGenerator(1),
+                ),
+                battery: TestElectricitySource::powered(context, PotentialOrigin::Battery(1)),
+                ac_buses: [1, 2, 3]
+                    .map(|n| ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(n))),
+                dc_hot_buses: [1, 2, 3]
+                    .map(|n| ElectricalBus::new(context, ElectricalBusType::DirectCurrentHot(n))),
+                is_ac_powered: true,
+
+                adirs: AirDataInertialReferenceSystem::new(
+                    context,
+                    adirs_programming,
+                    [1, 2, 3].map(|n| {
+                        AirDataInertialReferenceUnitPowerSupply::new(
+                            ElectricalBusType::AlternatingCurrent(n),
+                            ElectricalBusType::DirectCurrentHot(n),
+                        )
+                    }),
+                ),
                 overhead: AirDataInertialReferenceSystemOverheadPanel::new(context),
+                probes: Default::default(),
+                gps_receiver: TestGpsReceiver::new(),
             }
         }
+
+        fn set_gps_position(&mut self, position: Option<(Angle, Angle)>) {
+            self.gps_receiver.set_position(position);
+        }
+
+        fn set_ac_power(&mut self, is_powered: bool) {
+            self.is_ac_powered = is_powered;
+        }
+
+        fn set_battery_potential(&mut self, potential: ElectricPotential) {
+            self.battery.set_potential(potential);
+        }
+
+        fn set_probe_heat_fault(&mut self, adiru_number: usize, probe: ProbeType) {
+            self.probes[adiru_number - 1].heat_fault = Some(probe);
+        }
+
+        fn set_pitot_blockage(&mut self, adiru_number: usize, blockage: Ratio) {
+            self.probes[adiru_number - 1].pitot_blockage = blockage;
+        }
     }
     impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            self.ac_source
+                .power_with_potential(ElectricPotential::new::<volt>(115.));
+            electricity.supplied_by(&self.ac_source);
+            electricity.supplied_by(&self.battery);
+
+            if self.is_ac_powered {
+                for bus in &self.ac_buses {
+                    electricity.flow(&self.ac_source, bus);
+                }
+            }
+            for bus in &self.dc_hot_buses {
+                electricity.flow(&self.battery, bus);
+            }
+        }
+
         fn update_after_power_distribution(&mut self, context: &UpdateContext) {
-            self.adirs.update(context, &self.overhead);
-            self.overhead.update(context, &self.adirs);
+            self.gps_receiver.update(context);
+            self.adirs.update(
+                context,
+                &self.overhead,
+                &self.probes,
+                [&self.gps_receiver; 3],
+            );
+            self.overhead.update(&self.adirs);
         }
     }
     impl SimulationElement for TestAircraft {
         fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            accept_iterable!(self.ac_buses, visitor);
+            accept_iterable!(self.dc_hot_buses, visitor);
             self.adirs.accept(visitor);
             self.overhead.accept(visitor);
 
@@ -2397,7 +3339,10 @@ mod tests {
                 test_bed: SimulationTestBed::new(TestAircraft::new),
             };
             adirs_test_bed.move_all_mode_selectors_to(InertialReferenceMode::Navigation);
-            adirs_test_bed.altimeter_setting_of(Pressure::new::<hectopascal>(1013.25))
+            adirs_test_bed
+                .altimeter_setting_of(Pressure::new::<hectopascal>(1013.25))
+                .and()
+                .present_position_entered()
         }
 
         fn and(self) -> Self {
@@ -2431,6 +3376,16 @@ mod tests {
             self
         }
 
+        fn probe_heat_fault(mut self, adiru_number: usize, probe: ProbeType) -> Self {
+            self.command(|a| a.set_probe_heat_fault(adiru_number, probe));
+            self
+        }
+
+        fn pitot_blockage_of(mut self, adiru_number: usize, blockage: Ratio) -> Self {
+            self.command(|a| a.set_pitot_blockage(adiru_number, blockage));
+            self
+        }
+
         fn true_airspeed_of(mut self, velocity: Velocity) -> Self {
             self.write_by_name(AdirsSimulatorData::TRUE_AIRSPEED, velocity);
             self
@@ -2581,6 +3536,79 @@ mod tests {
             self
         }
 
+        fn present_position_entered(mut self) -> Self {
+            self.write_arinc429_by_name(
+                &format!("FM1_{}", AdirsSimulatorData::FM_PRESENT_LATITUDE),
+                0.,
+                SignStatus::NormalOperation,
+            );
+            self.write_arinc429_by_name(
+                &format!("FM1_{}", AdirsSimulatorData::FM_PRESENT_LONGITUDE),
+                0.,
+                SignStatus::NormalOperation,
+            );
+            self
+        }
+
+        fn no_present_position_entered(mut self) -> Self {
+            self.write_arinc429_by_name(
+                &format!("FM1_{}", AdirsSimulatorData::FM_PRESENT_LATITUDE),
+                0.,
+                SignStatus::NoComputedData,
+            );
+            self.write_arinc429_by_name(
+                &format!("FM1_{}", AdirsSimulatorData::FM_PRESENT_LONGITUDE),
+                0.,
+                SignStatus::NoComputedData,
+            );
+            self
+        }
+
+        fn heading_entered(mut self, heading: Angle) -> Self {
+            self.write_arinc429_by_name(
+                &format!("FM2_{}", AdirsSimulatorData::FM_SET_HEADING),
+                heading.get::<degree>(),
+                SignStatus::NormalOperation,
+            );
+            self
+        }
+
+        fn gps_position_of(mut self, latitude: Angle, longitude: Angle) -> Self {
+            self.command(|a| a.set_gps_position(Some((latitude, longitude))));
+            self
+        }
+
+        fn gps_lost(mut self) -> Self {
+            self.command(|a| a.set_gps_position(None));
+            self
+        }
+
+        fn ac_power_lost(mut self) -> Self {
+            self.command(|a| a.set_ac_power(false));
+            self
+        }
+
+        fn ac_power_restored(mut self) -> Self {
+            self.command(|a| a.set_ac_power(true));
+            self
+        }
+
+        fn battery_potential_of(mut self, potential: ElectricPotential) -> Self {
+            self.command(|a| a.set_battery_potential(potential));
+            self
+        }
+
+        fn ir_failed(mut self, adiru_number: usize) -> Self {
+            self.fail(FailureType::InertialReference(adiru_number));
+            self
+        }
+
+        fn ir_maint_word_contains(&mut self, adiru_number: usize, flags: IrMaintFlags) -> bool {
+            IrMaintFlags::from_bits(self.maint_word(adiru_number).value())
+                .unwrap()
+                .contains(flags)
+        }
+
         fn ir_fault_light_illuminated(&mut self, number: usize) -> bool {
             self.read_by_name(&OnOffFaultPushButton::has_fault_id(&format!(
                 "ADIRS_IR_{}",
@@ -2771,6 +3799,22 @@ mod tests {
             ))
         }
 
+        fn adr_bus_value(
+            &self,
+            adiru_number: usize,
+            label: &Arinc429LabelDefinition,
+        ) -> Option<Arinc429Word<f64>> {
+            self.query(|a| a.adirs.adr_bus(adiru_number).receive_value(label.label()))
+        }
+
+        fn ir_bus_value(
+            &self,
+            adiru_number: usize,
+            label: &Arinc429LabelDefinition,
+        ) -> Option<Arinc429Word<f64>> {
+            self.query(|a| a.adirs.ir_bus(adiru_number).receive_value(label.label()))
+        }
+
         fn pitch(&mut self, adiru_number: usize) -> Arinc429Word<Angle> {
             self.read_arinc429_by_name(&output_data_id(
                 OutputDataType::Ir,
@@ -2975,6 +4019,47 @@ mod tests {
             ))
         }
 
+        fn hybrid_latitude(&mut self, adiru_number: usize) -> Arinc429Word<Angle> {
+            self.read_arinc429_by_name(&output_data_id(
+                OutputDataType::Ir,
+                adiru_number,
+                InertialReference::HYBRID_LATITUDE,
+            ))
+        }
+
+        fn hybrid_longitude(&mut self, adiru_number: usize) -> Arinc429Word<Angle> {
+            self.read_arinc429_by_name(&output_data_id(
+                OutputDataType::Ir,
+                adiru_number,
+                InertialReference::HYBRID_LONGITUDE,
+            ))
+        }
+
+        fn is_gps_aided(&mut self, adiru_number: usize) -> bool {
+            self.read_by_name(&output_data_id(
+                OutputDataType::Ir,
+                adiru_number,
+                InertialReference::GPS_AIDED_DISCRETE,
+            ))
+        }
+
+        /// The distance in nautical miles between the given position and the simulator's
+        /// position at the equator and prime meridian.
+        fn distance_from_origin(latitude: Angle, longitude: Angle) -> f64 {
+            60. * latitude.get::<degree>().hypot(longitude.get::<degree>())
+        }
+
+        /// Runs for the longest transmission period of the ADIRU labels,
+        /// such that every label is transmitted at least once.
+        fn run_for_a_transmission_period(&mut self) {
+            self.run_with_delta(Duration::from_millis(500));
+        }
+
+        /// Runs until the words on the buses of a ADIRU which stopped transmitting became stale.
+        fn run_until_the_transmitted_words_are_stale(&mut self) {
+            self.run_with_delta(Duration::from_secs(2));
+        }
+
         fn maint_word(&mut self, adiru_number: usize) -> Arinc429Word<u32> {
             self.read_arinc429_by_name(&output_data_id(
                 OutputDataType::Ir,
@@ -3264,6 +4349,8 @@ mod tests {
         test_bed.run_with_delta(Duration::from_secs(0));
 
         assert!(test_bed.is_aligned(adiru_number));
+
+        test_bed.run_for_a_transmission_period();
         let maint_word_flags = IrMaintFlags::from_bits(test_bed.maint_word(adiru_number).value());
         assert_eq!(
             maint_word_flags.unwrap() & IrMaintFlags::NAV_MODE,
@@ -3438,8 +4525,7 @@ mod tests {
         test_bed.run_without_delta();
 
         test_bed.run_with_delta(
-            AirDataInertialReferenceSystemOverheadPanel::DURATION_AFTER_WHICH_ON_BAT_ILLUMINATES
-                - Duration::from_millis(1),
+            InertialReference::DURATION_AFTER_WHICH_ON_BAT_ILLUMINATES - Duration::from_millis(1),
         );
         assert!(!test_bed.on_bat_light_illuminated());
 
@@ -3447,8 +4533,7 @@ mod tests {
         assert!(test_bed.on_bat_light_illuminated());
 
         test_bed.run_with_delta(
-            AirDataInertialReferenceSystemOverheadPanel::ON_BAT_ILLUMINATION_DURATION
-                - Duration::from_millis(1),
+            InertialReference::ON_BAT_ILLUMINATION_DURATION - Duration::from_millis(1),
         );
         assert!(test_bed.on_bat_light_illuminated());
 
@@ -3473,7 +4558,7 @@ mod tests {
             .ir_mode_selector_set_to(2, InertialReferenceMode::Navigation);
         test_bed.run_without_delta();
         test_bed.run_with_delta(
-            AirDataInertialReferenceSystemOverheadPanel::DURATION_AFTER_WHICH_ON_BAT_ILLUMINATES
+            InertialReference::DURATION_AFTER_WHICH_ON_BAT_ILLUMINATES
                 - additional_duration
                 - Duration::from_millis(1),
         );
@@ -3483,8 +4568,7 @@ mod tests {
         assert!(test_bed.on_bat_light_illuminated());
 
         test_bed.run_with_delta(
-            AirDataInertialReferenceSystemOverheadPanel::ON_BAT_ILLUMINATION_DURATION
-                + additional_duration
+            InertialReference::ON_BAT_ILLUMINATION_DURATION + additional_duration
                 - Duration::from_millis(1),
         );
         assert!(test_bed.on_bat_light_illuminated());
@@ -3498,9 +4582,7 @@ mod tests {
         let mut test_bed =
             test_bed_with().ir_mode_selector_set_to(1, InertialReferenceMode::Navigation);
         test_bed.run_without_delta();
-        test_bed.run_with_delta(
-            AirDataInertialReferenceSystemOverheadPanel::DURATION_AFTER_WHICH_ON_BAT_ILLUMINATES,
-        );
+        test_bed.run_with_delta(InertialReference::DURATION_AFTER_WHICH_ON_BAT_ILLUMINATES);
 
         assert!(test_bed.on_bat_light_illuminated());
 
@@ -3512,68 +4594,225 @@ mod tests {
         assert!(test_bed.on_bat_light_illuminated());
     }
 
-    #[test]
-    #[timeout(500)]
-    fn remaining_alignment_time_counts_down_to_0_seconds() {
-        let mut test_bed =
-            test_bed_with().ir_mode_selector_set_to(1, InertialReferenceMode::Navigation);
-        test_bed.run_without_delta();
+    #[rstest]
+    #[case(1)]
+    #[case(2)]
+    #[case(3)]
+    fn on_bat_light_illuminates_and_the_ir_remains_aligned_when_running_on_dc(
+        #[case] adiru_number: usize,
+    ) {
+        let mut test_bed = all_adirus_aligned_test_bed_with().ac_power_lost();
+        test_bed.run();
 
-        assert!(
-            test_bed.remaining_alignment_time() > Duration::from_secs(0),
-            "Test precondition: alignment time should be greater than 0 seconds."
-        );
+        assert!(test_bed.is_aligned(adiru_number));
+        assert!(test_bed.on_bat_light_illuminated());
+        assert!(test_bed.ir_maint_word_contains(adiru_number, IrMaintFlags::ON_DC));
 
-        while test_bed.remaining_alignment_time() > Duration::from_secs(0) {
-            test_bed.run();
-        }
+        test_bed = test_bed.then_continue_with().ac_power_restored();
+        test_bed.run();
+
+        assert!(!test_bed.on_bat_light_illuminated());
+        assert!(!test_bed.ir_maint_word_contains(adiru_number, IrMaintFlags::ON_DC));
     }
 
-    #[test]
-    fn remaining_alignment_time_is_0_seconds_when_nothing_is_aligning() {
-        let mut test_bed = test_bed_with().all_mode_selectors_off();
+    #[rstest]
+    #[case(1)]
+    #[case(2)]
+    #[case(3)]
+    fn dc_fail_is_indicated_when_the_dc_supply_is_below_18_volts(#[case] adiru_number: usize) {
+        let mut test_bed = all_adirus_aligned_test_bed_with()
+            .battery_potential_of(ElectricPotential::new::<volt>(17.9));
         test_bed.run();
 
-        assert_eq!(test_bed.remaining_alignment_time(), Duration::from_secs(0));
+        assert!(test_bed.is_aligned(adiru_number));
+        assert!(test_bed.ir_maint_word_contains(adiru_number, IrMaintFlags::DC_FAIL));
+        assert!(!test_bed.ir_maint_word_contains(adiru_number, IrMaintFlags::ON_DC));
     }
 
-    #[test]
-    fn remaining_alignment_time_is_the_longest_out_of_all_aligning_adirus() {
-        let mut test_bed =
-            test_bed_with().ir_mode_selector_set_to(1, InertialReferenceMode::Navigation);
-        test_bed.run_without_delta();
-        test_bed.run_with_delta(Duration::from_secs(60));
-        let single_adiru_remaining_alignment_time = test_bed.remaining_alignment_time();
-
-        test_bed = test_bed
-            .then_continue_with()
-            .ir_mode_selector_set_to(2, InertialReferenceMode::Navigation);
+    #[rstest]
+    #[case(1)]
+    #[case(2)]
+    #[case(3)]
+    fn the_adiru_is_off_when_ac_is_lost_and_dc_is_below_18_volts(#[case] adiru_number: usize) {
+        let mut test_bed = all_adirus_aligned_test_bed_with()
+            .ac_power_lost()
+            .and()
+            .battery_potential_of(ElectricPotential::new::<volt>(17.9));
         test_bed.run();
 
-        assert!(test_bed.remaining_alignment_time() > single_adiru_remaining_alignment_time);
+        assert!(test_bed.align_state(adiru_number) == AlignState::Off);
+        test_bed.assert_all_ir_data_available(false, adiru_number);
+        test_bed.assert_adr_data_valid(false, adiru_number);
     }
 
-    #[test]
-    fn remaining_alignment_time_is_greater_than_zero_when_a_single_adiru_is_aligned_but_another_is_still_aligning(
+    #[rstest]
+    #[case(1)]
+    #[case(2)]
+    #[case(3)]
+    fn losing_dc_while_running_on_dc_is_remembered_until_the_mode_selector_is_turned_off(
+        #[case] adiru_number: usize,
     ) {
-        let mut test_bed =
-            test_bed_with().ir_mode_selector_set_to(1, InertialReferenceMode::Navigation);
-        test_bed.run_without_delta();
+        let mut test_bed = all_adirus_aligned_test_bed_with().ac_power_lost();
+        test_bed.run();
 
-        while test_bed.remaining_alignment_time() > Duration::from_secs(0) {
-            test_bed.run();
-        }
+        test_bed = test_bed
+            .then_continue_with()
+            .battery_potential_of(ElectricPotential::new::<volt>(0.));
+        test_bed.run();
 
         test_bed = test_bed
             .then_continue_with()
-            .ir_mode_selector_set_to(2, InertialReferenceMode::Navigation);
+            .ac_power_restored()
+            .and()
+            .battery_potential_of(ElectricPotential::new::<volt>(28.));
         test_bed.run();
 
-        assert!(test_bed.remaining_alignment_time() > Duration::from_secs(0));
-    }
+        assert!(test_bed.is_aligning(adiru_number));
+        assert!(test_bed.ir_maint_word_contains(adiru_number, IrMaintFlags::DC_FAIL_ON_DC));
 
-    mod adr {
-        use ntest::{assert_false, assert_true};
+        test_bed = test_bed
+            .then_continue_with()
+            .ir_mode_selector_set_to(adiru_number, InertialReferenceMode::Off);
+        test_bed.run();
+        test_bed = test_bed
+            .then_continue_with()
+            .ir_mode_selector_set_to(adiru_number, InertialReferenceMode::Navigation);
+        test_bed.run();
+
+        assert!(!test_bed.ir_maint_word_contains(adiru_number, IrMaintFlags::DC_FAIL_ON_DC));
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(2)]
+    #[case(3)]
+    fn alignment_does_not_complete_before_a_present_position_is_entered(
+        #[case] adiru_number: usize,
+    ) {
+        let mut test_bed = all_adirus_unaligned_test_bed_with()
+            .no_present_position_entered()
+            .and()
+            .align_time_configured_as(AlignTime::Fast)
+            .and()
+            .ir_mode_selector_set_to(adiru_number, InertialReferenceMode::Navigation);
+        test_bed.run_without_delta();
+        test_bed.run_with_delta(Duration::from_secs_f64(
+            InertialReference::FAST_ALIGNMENT_TIME_IN_SECS + 1.,
+        ));
+
+        assert!(test_bed.is_aligning(adiru_number));
+        assert!(test_bed.ir_maint_word_contains(adiru_number, IrMaintFlags::NO_IRS_INITIAL));
+
+        test_bed = test_bed.then_continue_with().present_position_entered();
+        test_bed.run();
+
+        assert!(test_bed.is_aligned(adiru_number));
+        assert!(!test_bed.ir_maint_word_contains(adiru_number, IrMaintFlags::NO_IRS_INITIAL));
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(2)]
+    #[case(3)]
+    fn a_failed_ir_indicates_a_fault_and_loses_its_alignment(#[case] adiru_number: usize) {
+        let mut test_bed = all_adirus_aligned_test_bed_with().ir_failed(adiru_number);
+        test_bed.run();
+
+        assert!(test_bed.ir_fault_light_illuminated(adiru_number));
+        assert!(test_bed.pitch(adiru_number).is_failure_warning());
+        assert!(test_bed.heading(adiru_number).is_failure_warning());
+        assert!(test_bed.ir_maint_word_contains(
+            adiru_number,
+            IrMaintFlags::IR_FAULT | IrMaintFlags::ATTITUDE_INVALID
+        ));
+        assert!(!test_bed.is_aligned(adiru_number));
+    }
+
+    #[test]
+    #[timeout(500)]
+    fn remaining_alignment_time_counts_down_to_0_seconds() {
+        let mut test_bed =
+            test_bed_with().ir_mode_selector_set_to(1, InertialReferenceMode::Navigation);
+        test_bed.run_without_delta();
+
+        assert!(
+            test_bed.remaining_alignment_time() > Duration::from_secs(0),
+            "Test precondition: alignment time should be greater than 0 seconds."
+        );
+
+        while test_bed.remaining_alignment_time() > Duration::from_secs(0) {
+            test_bed.run();
+        }
+    }
+
+    #[test]
+    fn remaining_alignment_time_is_0_seconds_when_nothing_is_aligning() {
+        let mut test_bed = test_bed_with().all_mode_selectors_off();
+        test_bed.run();
+
+        assert_eq!(test_bed.remaining_alignment_time(), Duration::from_secs(0));
+    }
+
+    #[test]
+    fn remaining_alignment_time_is_the_longest_out_of_all_aligning_adirus() {
+        let mut test_bed =
+            test_bed_with().ir_mode_selector_set_to(1, InertialReferenceMode::Navigation);
+        test_bed.run_without_delta();
+        test_bed.run_with_delta(Duration::from_secs(60));
+        let single_adiru_remaining_alignment_time = test_bed.remaining_alignment_time();
+
+        test_bed = test_bed
+            .then_continue_with()
+            .ir_mode_selector_set_to(2, InertialReferenceMode::Navigation);
+        test_bed.run();
+
+        assert!(test_bed.remaining_alignment_time() > single_adiru_remaining_alignment_time);
+    }
+
+    #[test]
+    fn remaining_alignment_time_is_greater_than_zero_when_a_single_adiru_is_aligned_but_another_is_still_aligning(
+    ) {
+        let mut test_bed =
+            test_bed_with().ir_mode_selector_set_to(1, InertialReferenceMode::Navigation);
+        test_bed.run_without_delta();
+
+        while test_bed.remaining_alignment_time() > Duration::from_secs(0) {
+            test_bed.run();
+        }
+
+        test_bed = test_bed
+            .then_continue_with()
+            .ir_mode_selector_set_to(2, InertialReferenceMode::Navigation);
+        test_bed.run();
+
+        assert!(test_bed.remaining_alignment_time() > Duration::from_secs(0));
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(2)]
+    #[case(3)]
+    fn adiru_stops_transmitting_when_switched_off(#[case] adiru_number: usize) {
+        let mut test_bed = all_adirus_aligned_test_bed();
+        test_bed.run();
+        assert!(test_bed
+            .adr_bus_value(adiru_number, &ADR_ALTITUDE)
+            .is_some());
+        assert!(test_bed.ir_bus_value(adiru_number, &IR_PITCH).is_some());
+
+        test_bed = test_bed
+            .then_continue_with()
+            .ir_mode_selector_set_to(adiru_number, InertialReferenceMode::Off);
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!(test_bed
+            .adr_bus_value(adiru_number, &ADR_ALTITUDE)
+            .is_none());
+        assert!(test_bed.ir_bus_value(adiru_number, &IR_PITCH).is_none());
+    }
+
+    mod adr {
+        use ntest::{assert_false, assert_true};
 
         use crate::shared::InternationalStandardAtmosphere;
 
@@ -3594,6 +4833,7 @@ mod tests {
             test_bed.assert_adr_data_valid(false, adiru_number);
 
             test_bed.run_with_delta(Duration::from_millis(1));
+            test_bed.run_for_a_transmission_period();
             test_bed.assert_adr_data_valid(true, adiru_number);
         }
 
@@ -3609,7 +4849,7 @@ mod tests {
             test_bed = test_bed
                 .then_continue_with()
                 .ir_mode_selector_set_to(adiru_number, InertialReferenceMode::Off);
-            test_bed.run();
+            test_bed.run_until_the_transmitted_words_are_stale();
             test_bed.assert_adr_data_valid(false, adiru_number);
         }
 
@@ -3679,14 +4919,16 @@ mod tests {
                     .baro_correction_1_inhg(adiru_number)
                     .normal_value()
                     .unwrap(),
-                29.80
+                29.80,
+                0.001
             );
             assert_about_eq!(
                 test_bed
                     .baro_correction_2_inhg(adiru_number)
                     .normal_value()
                     .unwrap(),
-                29.80
+                29.80,
+                0.001
             );
         }
 
@@ -3778,6 +5020,29 @@ mod tests {
             );
         }
 
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn computed_airspeed_is_transmitted_on_the_bus(#[case] adiru_number: usize) {
+            let mut test_bed = all_adirus_aligned_test_bed();
+            test_bed.set_indicated_airspeed(Velocity::new::<knot>(250.));
+            test_bed.run();
+
+            let computed_airspeed = test_bed
+                .adr_bus_value(adiru_number, &ADR_COMPUTED_AIRSPEED)
+                .unwrap();
+            assert!(computed_airspeed.is_normal_operation());
+            assert_about_eq!(
+                computed_airspeed.value(),
+                test_bed
+                    .computed_airspeed(adiru_number)
+                    .value()
+                    .get::<knot>(),
+                0.0625
+            );
+        }
+
         #[rstest]
         #[case(1)]
         #[case(2)]
@@ -3909,6 +5174,76 @@ mod tests {
                 .contains(AdrDiscrete1Flags::OVERSPEED_WARNING));
         }
 
+        #[rstest]
+        #[case(ProbeType::Pitot, AdrDiscrete1Flags::PITOT_HEAT)]
+        #[case(ProbeType::LeftStatic, AdrDiscrete1Flags::LEFT_STATIC_HEAT)]
+        #[case(ProbeType::RightStatic, AdrDiscrete1Flags::RIGHT_STATIC_HEAT)]
+        #[case(ProbeType::TotalAirTemperature, AdrDiscrete1Flags::TAT_HEAT)]
+        #[case(ProbeType::AngleOfAttack, AdrDiscrete1Flags::AOA_1_SENSOR_HEAT)]
+        fn probe_heat_fault_is_indicated_in_discrete_word_1(
+            #[case] probe: ProbeType,
+            #[case] flag: AdrDiscrete1Flags,
+        ) {
+            let mut test_bed = all_adirus_aligned_test_bed_with().probe_heat_fault(2, probe);
+            test_bed.run();
+
+            let discrete_word_flags =
+                AdrDiscrete1Flags::from_bits(test_bed.adr_discrete_word_1(2).value()).unwrap();
+            assert_eq!(discrete_word_flags.bits(), flag.bits());
+
+            let other_discrete_word_flags =
+                AdrDiscrete1Flags::from_bits(test_bed.adr_discrete_word_1(1).value()).unwrap();
+            assert_false!(other_discrete_word_flags.contains(flag));
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn blocked_pitot_reduces_airspeeds(#[case] adiru_number: usize) {
+            let mut test_bed = all_adirus_aligned_test_bed_with()
+                .mach_of(MachNumber(0.5))
+                .true_airspeed_of(Velocity::new::<knot>(300.))
+                .pitot_blockage_of(adiru_number, Ratio::new::<ratio>(0.75));
+            test_bed.set_indicated_airspeed(Velocity::new::<knot>(250.));
+            test_bed.run();
+
+            assert_about_eq!(
+                test_bed
+                    .computed_airspeed(adiru_number)
+                    .normal_value()
+                    .unwrap()
+                    .get::<knot>(),
+                125.
+            );
+            assert_about_eq!(
+                f64::from(test_bed.mach(adiru_number).normal_value().unwrap()),
+                0.25
+            );
+            assert_about_eq!(
+                test_bed
+                    .true_airspeed(adiru_number)
+                    .normal_value()
+                    .unwrap()
+                    .get::<knot>(),
+                150.
+            );
+        }
+
+        #[test]
+        fn fully_blocked_pitot_makes_computed_airspeed_ncd() {
+            let mut test_bed =
+                all_adirus_aligned_test_bed_with().pitot_blockage_of(1, Ratio::new::<ratio>(1.));
+            test_bed.set_indicated_airspeed(Velocity::new::<knot>(250.));
+            test_bed.run();
+
+            assert_eq!(
+                test_bed.computed_airspeed(1).ssm(),
+                SignStatus::NoComputedData
+            );
+            assert!(test_bed.computed_airspeed(2).is_normal_operation());
+        }
+
         #[rstest]
         #[case(1)]
         #[case(2)]
@@ -3964,7 +5299,7 @@ mod tests {
                     .unwrap()
                     .get::<foot_per_minute>(),
                 vertical_speed.get::<foot_per_minute>(),
-                10.
+                16.
             );
         }
 
@@ -4055,12 +5390,14 @@ mod tests {
             ));
             test_bed.run();
 
-            assert_eq!(
+            assert_about_eq!(
                 test_bed
                     .angle_of_attack(adiru_number)
                     .normal_value()
-                    .unwrap(),
-                angle
+                    .unwrap()
+                    .get::<degree>(),
+                angle.get::<degree>(),
+                0.044
             );
         }
 
@@ -4076,7 +5413,14 @@ mod tests {
             ));
             test_bed.run();
 
-            assert_eq!(test_bed.angle_of_attack(adiru_number).value(), angle);
+            assert_about_eq!(
+                test_bed
+                    .angle_of_attack(adiru_number)
+                    .value()
+                    .get::<degree>(),
+                angle.get::<degree>(),
+                0.044
+            );
             assert_eq!(
                 test_bed.angle_of_attack(adiru_number).ssm(),
                 SignStatus::NoComputedData
@@ -4127,7 +5471,7 @@ mod tests {
             test_bed = test_bed
                 .then_continue_with()
                 .ir_mode_selector_set_to(adiru_number, InertialReferenceMode::Off);
-            test_bed.run();
+            test_bed.run_until_the_transmitted_words_are_stale();
             test_bed.assert_all_ir_data_available(false, adiru_number);
         }
 
@@ -4139,7 +5483,9 @@ mod tests {
             #[case] adiru_number: usize,
         ) {
             let mut test_bed = all_adirus_aligned_test_bed_with()
-                .ir_mode_selector_set_to(adiru_number, InertialReferenceMode::Attitude);
+                .ir_mode_selector_set_to(adiru_number, InertialReferenceMode::Attitude)
+                .and()
+                .heading_entered(Angle::new::<degree>(160.));
             test_bed.run();
 
             test_bed.assert_ir_attitude_data_available(true, adiru_number);
@@ -4165,6 +5511,7 @@ mod tests {
             test_bed.assert_ir_heading_data_available(false, adiru_number);
 
             test_bed.run_with_delta(Duration::from_millis(1));
+            test_bed.run_for_a_transmission_period();
             test_bed.assert_ir_attitude_data_available(true, adiru_number);
             test_bed.assert_ir_heading_data_available(false, adiru_number);
         }
@@ -4173,11 +5520,9 @@ mod tests {
         #[case(1)]
         #[case(2)]
         #[case(3)]
-        fn in_att_mode_attitude_and_heading_are_available_28_seconds_after_alignment_began(
+        fn in_att_mode_attitude_is_available_28_seconds_after_alignment_began_and_heading_once_entered(
             #[case] adiru_number: usize,
         ) {
-            // Note that in reality the HDG part needs HDG entry through the MCDU. As we haven't implemented
-            // that feature yet, for now we'll just make it available after 28 seconds in ATT mode.
             let mut test_bed = test_bed_with()
                 .ir_mode_selector_set_to(adiru_number, InertialReferenceMode::Attitude);
             test_bed.run_without_delta();
@@ -4189,7 +5534,14 @@ mod tests {
             test_bed.assert_ir_heading_data_available(false, adiru_number);
 
             test_bed.run_with_delta(Duration::from_millis(1));
+            test_bed.run_for_a_transmission_period();
             test_bed.assert_ir_attitude_data_available(true, adiru_number);
+            test_bed.assert_ir_heading_data_available(false, adiru_number);
+
+            test_bed = test_bed
+                .then_continue_with()
+                .heading_entered(Angle::new::<degree>(160.));
+            test_bed.run();
             test_bed.assert_ir_heading_data_available(true, adiru_number);
 
             let maint_word_flags =
@@ -4200,6 +5552,64 @@ mod tests {
             );
         }
 
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn in_att_mode_the_heading_follows_heading_changes_from_the_entered_heading(
+            #[case] adiru_number: usize,
+        ) {
+            let mut test_bed = all_adirus_aligned_test_bed_with()
+                .ir_mode_selector_set_to(adiru_number, InertialReferenceMode::Attitude)
+                .and()
+                .heading_of(Angle::new::<degree>(100.));
+            test_bed.run();
+
+            assert!(!test_bed.ir_maint_word_contains(adiru_number, IrMaintFlags::VALID_SET_HEADING));
+
+            test_bed = test_bed
+                .then_continue_with()
+                .heading_entered(Angle::new::<degree>(120.));
+            test_bed.run();
+
+            assert!(test_bed.ir_maint_word_contains(adiru_number, IrMaintFlags::VALID_SET_HEADING));
+            assert_about_eq!(
+                test_bed.heading(adiru_number).value().get::<degree>(),
+                120.,
+                0.0055
+            );
+
+            test_bed = test_bed
+                .then_continue_with()
+                .heading_of(Angle::new::<degree>(110.));
+            test_bed.run();
+
+            assert_about_eq!(
+                test_bed.heading(adiru_number).value().get::<degree>(),
+                130.,
+                0.0055
+            );
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn the_entered_heading_is_forgotten_when_leaving_att_mode(#[case] adiru_number: usize) {
+            let mut test_bed = all_adirus_aligned_test_bed_with()
+                .ir_mode_selector_set_to(adiru_number, InertialReferenceMode::Attitude)
+                .and()
+                .heading_entered(Angle::new::<degree>(120.));
+            test_bed.run();
+
+            test_bed = test_bed
+                .then_continue_with()
+                .ir_mode_selector_set_to(adiru_number, InertialReferenceMode::Navigation);
+            test_bed.run();
+
+            assert!(!test_bed.ir_maint_word_contains(adiru_number, IrMaintFlags::VALID_SET_HEADING));
+        }
+
         #[rstest]
         #[case(1)]
         #[case(2)]
@@ -4220,7 +5630,29 @@ mod tests {
             let mut test_bed = all_adirus_aligned_test_bed_with().pitch_of(angle);
             test_bed.run();
 
-            assert_eq!(test_bed.pitch(adiru_number).normal_value().unwrap(), -angle);
+            assert_about_eq!(
+                test_bed
+                    .pitch(adiru_number)
+                    .normal_value()
+                    .unwrap()
+                    .get::<degree>(),
+                -angle.get::<degree>(),
+                0.011
+            );
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn pitch_is_transmitted_on_the_bus(#[case] adiru_number: usize) {
+            let angle = Angle::new::<degree>(5.);
+            let mut test_bed = all_adirus_aligned_test_bed_with().pitch_of(angle);
+            test_bed.run();
+
+            let pitch = test_bed.ir_bus_value(adiru_number, &IR_PITCH).unwrap();
+            assert!(pitch.is_normal_operation());
+            assert_about_eq!(pitch.value(), -5., 0.011);
         }
 
         #[rstest]
@@ -4232,7 +5664,15 @@ mod tests {
             let mut test_bed = all_adirus_aligned_test_bed_with().roll_of(angle);
             test_bed.run();
 
-            assert_eq!(test_bed.roll(adiru_number).normal_value().unwrap(), -angle);
+            assert_about_eq!(
+                test_bed
+                    .roll(adiru_number)
+                    .normal_value()
+                    .unwrap()
+                    .get::<degree>(),
+                -angle.get::<degree>(),
+                0.011
+            );
         }
 
         #[rstest]
@@ -4312,7 +5752,8 @@ mod tests {
                     .unwrap()
                     .get::<percent>(),
                 (acc / g + test_bed.pitch(adiru_number).normal_value().unwrap().sin())
-                    .get::<ratio>()
+                    .get::<ratio>(),
+                0.002
             );
         }
 
@@ -4338,7 +5779,8 @@ mod tests {
                 (acc / g
                     - test_bed.pitch(adiru_number).normal_value().unwrap().cos()
                         * test_bed.roll(adiru_number).normal_value().unwrap().sin())
-                .get::<ratio>()
+                .get::<ratio>(),
+                0.002
             );
         }
 
@@ -4364,7 +5806,8 @@ mod tests {
                 (acc / g
                     + test_bed.pitch(adiru_number).normal_value().unwrap().cos()
                         * test_bed.roll(adiru_number).normal_value().unwrap().cos())
-                .get::<ratio>()
+                .get::<ratio>(),
+                0.002
             );
         }
 
@@ -4377,9 +5820,14 @@ mod tests {
             let mut test_bed = all_adirus_aligned_test_bed_with().heading_of(angle);
             test_bed.run();
 
-            assert_eq!(
-                test_bed.heading(adiru_number).normal_value().unwrap(),
-                angle
+            assert_about_eq!(
+                test_bed
+                    .heading(adiru_number)
+                    .normal_value()
+                    .unwrap()
+                    .get::<degree>(),
+                angle.get::<degree>(),
+                0.0055
             );
         }
 
@@ -4392,9 +5840,14 @@ mod tests {
             let mut test_bed = all_adirus_aligned_test_bed_with().true_heading_of(angle);
             test_bed.run();
 
-            assert_eq!(
-                test_bed.true_heading(adiru_number).normal_value().unwrap(),
-                angle
+            assert_about_eq!(
+                test_bed
+                    .true_heading(adiru_number)
+                    .normal_value()
+                    .unwrap()
+                    .get::<degree>(),
+                angle.get::<degree>(),
+                0.0055
             );
         }
 
@@ -4474,11 +5927,13 @@ mod tests {
             assert!(test_bed.heading(adiru_number).is_normal_operation());
             assert_about_eq!(
                 test_bed.true_heading(adiru_number).value().get::<degree>(),
-                true_heading.get::<degree>()
+                true_heading.get::<degree>(),
+                0.0055
             );
             assert_about_eq!(
                 test_bed.heading(adiru_number).value().get::<degree>(),
-                mag_heading.get::<degree>()
+                mag_heading.get::<degree>(),
+                0.0055
             );
         }
 
@@ -4540,7 +5995,15 @@ mod tests {
                 ));
             test_bed.run();
 
-            assert_eq!(test_bed.track(adiru_number).normal_value().unwrap(), angle);
+            assert_about_eq!(
+                test_bed
+                    .track(adiru_number)
+                    .normal_value()
+                    .unwrap()
+                    .get::<degree>(),
+                angle.get::<degree>(),
+                0.0055
+            );
         }
 
         #[rstest]
@@ -4559,9 +6022,14 @@ mod tests {
                 ));
             test_bed.run();
 
-            assert_eq!(
-                test_bed.true_track(adiru_number).normal_value().unwrap(),
-                angle
+            assert_about_eq!(
+                test_bed
+                    .true_track(adiru_number)
+                    .normal_value()
+                    .unwrap()
+                    .get::<degree>(),
+                angle.get::<degree>(),
+                0.0055
             );
         }
 
@@ -4579,7 +6047,15 @@ mod tests {
                 ));
             test_bed.run();
 
-            assert_eq!(test_bed.track(adiru_number).normal_value().unwrap(), angle);
+            assert_about_eq!(
+                test_bed
+                    .track(adiru_number)
+                    .normal_value()
+                    .unwrap()
+                    .get::<degree>(),
+                angle.get::<degree>(),
+                0.0055
+            );
         }
 
         #[rstest]
@@ -4598,9 +6074,14 @@ mod tests {
                 ));
             test_bed.run();
 
-            assert_eq!(
-                test_bed.true_track(adiru_number).normal_value().unwrap(),
-                angle
+            assert_about_eq!(
+                test_bed
+                    .true_track(adiru_number)
+                    .normal_value()
+                    .unwrap()
+                    .get::<degree>(),
+                angle.get::<degree>(),
+                0.0055
             );
         }
 
@@ -4654,11 +6135,13 @@ mod tests {
             assert!(test_bed.track(adiru_number).is_normal_operation());
             assert_about_eq!(
                 test_bed.true_track(adiru_number).value().get::<degree>(),
-                true_track.get::<degree>()
+                true_track.get::<degree>(),
+                0.0055
             );
             assert_about_eq!(
                 test_bed.track(adiru_number).value().get::<degree>(),
-                mag_track.get::<degree>()
+                mag_track.get::<degree>(),
+                0.0055
             );
         }
 
@@ -4686,7 +6169,8 @@ mod tests {
                     .normal_value()
                     .unwrap()
                     .get::<degree>(),
-                (track - heading).get::<degree>()
+                (track - heading).get::<degree>(),
+                0.044
             );
         }
 
@@ -4738,7 +6222,8 @@ mod tests {
                 vs.atan2(Velocity::new::<knot>(
                     InertialReference::MINIMUM_GROUND_SPEED_FOR_TRACK_KNOTS,
                 ))
-                .get::<degree>()
+                .get::<degree>(),
+                0.044
             );
         }
 
@@ -4861,6 +6346,34 @@ mod tests {
             test_bed.assert_wind_direction_and_velocity_zero(adiru_number);
         }
 
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn wind_is_not_transmitted_in_attitude_mode(#[case] adiru_number: usize) {
+            let mut test_bed = all_adirus_aligned_test_bed_with()
+                .wind_of(Angle::new::<degree>(150.), Velocity::new::<knot>(40.))
+                .and()
+                .true_airspeed_of(Velocity::new::<knot>(200.));
+            test_bed.run();
+            assert!(test_bed
+                .ir_bus_value(adiru_number, &IR_WIND_SPEED)
+                .is_some());
+
+            test_bed = test_bed
+                .then_continue_with()
+                .ir_mode_selector_set_to(adiru_number, InertialReferenceMode::Attitude);
+            test_bed.run_with_delta(Duration::from_secs(2));
+
+            assert!(test_bed
+                .ir_bus_value(adiru_number, &IR_WIND_SPEED)
+                .is_none());
+            assert!(test_bed
+                .ir_bus_value(adiru_number, &IR_WIND_DIRECTION_BNR)
+                .is_none());
+            assert!(test_bed.ir_bus_value(adiru_number, &IR_PITCH).is_some());
+        }
+
         #[rstest]
         #[case(1)]
         #[case(2)]
@@ -4885,9 +6398,14 @@ mod tests {
             let mut test_bed = all_adirus_aligned_test_bed_with().latitude_of(latitude);
             test_bed.run();
 
-            assert_eq!(
-                test_bed.latitude(adiru_number).normal_value().unwrap(),
-                latitude
+            assert_about_eq!(
+                test_bed
+                    .latitude(adiru_number)
+                    .normal_value()
+                    .unwrap()
+                    .get::<degree>(),
+                latitude.get::<degree>(),
+                0.0007
             );
         }
 
@@ -4900,9 +6418,187 @@ mod tests {
             let mut test_bed = all_adirus_aligned_test_bed_with().longitude_of(longitude);
             test_bed.run();
 
+            assert_about_eq!(
+                test_bed
+                    .longitude(adiru_number)
+                    .normal_value()
+                    .unwrap()
+                    .get::<degree>(),
+                longitude.get::<degree>(),
+                0.0007
+            );
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn position_drifts_with_time_since_alignment(#[case] adiru_number: usize) {
+            let mut test_bed = all_adirus_aligned_test_bed_with()
+                .latitude_of(Angle::default())
+                .and()
+                .longitude_of(Angle::default());
+            test_bed.run();
+
+            // After two Schuler periods only the drift rate remains, which is between 0.2 and 1.5
+            // NM/h for an undisturbed alignment.
+            test_bed.run_with_delta(InertialDrift::SCHULER_PERIOD * 2);
+
+            let error = AdirsTestBed::distance_from_origin(
+                test_bed.latitude(adiru_number).normal_value().unwrap(),
+                test_bed.longitude(adiru_number).normal_value().unwrap(),
+            );
+            assert!((0.5..=4.3).contains(&error));
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn drift_is_reset_by_a_new_alignment(#[case] adiru_number: usize) {
+            let mut test_bed = all_adirus_aligned_test_bed_with()
+                .latitude_of(Angle::default())
+                .and()
+                .longitude_of(Angle::default());
+            test_bed.run();
+            test_bed.run_with_delta(InertialDrift::SCHULER_PERIOD * 2);
+
+            test_bed = test_bed.ir_mode_selector_set_to(adiru_number, InertialReferenceMode::Off);
+            test_bed.run_with_delta(Duration::from_secs(10));
+            test_bed = test_bed
+                .ir_mode_selector_set_to(adiru_number, InertialReferenceMode::Navigation)
+                .and()
+                .align_time_configured_as(AlignTime::Instant);
+            test_bed.run();
+
+            assert_eq!(
+                test_bed.latitude(adiru_number).normal_value().unwrap(),
+                Angle::default()
+            );
             assert_eq!(
                 test_bed.longitude(adiru_number).normal_value().unwrap(),
-                longitude
+                Angle::default()
+            );
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn hybrid_position_is_the_gps_position_when_gps_is_available(#[case] adiru_number: usize) {
+            let latitude = Angle::new::<degree>(10.);
+            let longitude = Angle::new::<degree>(20.);
+            let mut test_bed = all_adirus_aligned_test_bed_with()
+                .gps_position_of(latitude, longitude)
+                .and()
+                .latitude_of(Angle::default());
+            test_bed.run();
+            test_bed.run_with_delta(InertialDrift::SCHULER_PERIOD * 2);
+
+            assert_about_eq!(
+                test_bed
+                    .hybrid_latitude(adiru_number)
+                    .normal_value()
+                    .unwrap()
+                    .get::<degree>(),
+                10.,
+                0.001
+            );
+            assert_about_eq!(
+                test_bed
+                    .hybrid_longitude(adiru_number)
+                    .normal_value()
+                    .unwrap()
+                    .get::<degree>(),
+                20.,
+                0.001
+            );
+            assert!(test_bed.is_gps_aided(adiru_number));
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn hybrid_position_is_the_inertial_position_without_gps(#[case] adiru_number: usize) {
+            let mut test_bed = all_adirus_aligned_test_bed_with()
+                .latitude_of(Angle::default())
+                .and()
+                .longitude_of(Angle::default());
+            test_bed.run();
+            test_bed.run_with_delta(InertialDrift::SCHULER_PERIOD * 2);
+
+            assert_eq!(
+                test_bed.hybrid_latitude(adiru_number).normal_value(),
+                test_bed.latitude(adiru_number).normal_value()
+            );
+            assert_eq!(
+                test_bed.hybrid_longitude(adiru_number).normal_value(),
+                test_bed.longitude(adiru_number).normal_value()
+            );
+            assert!(!test_bed.is_gps_aided(adiru_number));
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn hybrid_position_only_drifts_from_the_last_gps_update(#[case] adiru_number: usize) {
+            let mut test_bed = all_adirus_aligned_test_bed_with()
+                .latitude_of(Angle::default())
+                .and()
+                .longitude_of(Angle::default())
+                .and()
+                .gps_position_of(Angle::default(), Angle::default());
+            test_bed.run();
+            test_bed.run_with_delta(InertialDrift::SCHULER_PERIOD);
+
+            test_bed = test_bed.gps_lost();
+            test_bed.run_with_delta(InertialDrift::SCHULER_PERIOD);
+
+            let inertial_error = AdirsTestBed::distance_from_origin(
+                test_bed.latitude(adiru_number).normal_value().unwrap(),
+                test_bed.longitude(adiru_number).normal_value().unwrap(),
+            );
+            let hybrid_error = AdirsTestBed::distance_from_origin(
+                test_bed
+                    .hybrid_latitude(adiru_number)
+                    .normal_value()
+                    .unwrap(),
+                test_bed
+                    .hybrid_longitude(adiru_number)
+                    .normal_value()
+                    .unwrap(),
+            );
+            assert_about_eq!(hybrid_error, inertial_error / 2., 0.1);
+            assert!(!test_bed.is_gps_aided(adiru_number));
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn transmits_hybrid_position(#[case] adiru_number: usize) {
+            let mut test_bed = all_adirus_aligned_test_bed_with()
+                .gps_position_of(Angle::new::<degree>(10.), Angle::new::<degree>(20.));
+            test_bed.run();
+            test_bed.run();
+
+            assert_about_eq!(
+                test_bed
+                    .ir_bus_value(adiru_number, &IR_HYBRID_LATITUDE)
+                    .unwrap()
+                    .value(),
+                10.,
+                0.001
+            );
+            assert_about_eq!(
+                test_bed
+                    .ir_bus_value(adiru_number, &IR_HYBRID_LONGITUDE)
+                    .unwrap()
+                    .value(),
+                20.,
+                0.001
             );
         }
     }
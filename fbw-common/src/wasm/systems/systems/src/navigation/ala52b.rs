@@ -1,12 +1,12 @@
 use super::radio_altimeter::{
-    AntennaInstallation, RadioAltimeter, TransceiverPair, TransceiverPairResponse,
+    AntennaInstallation, RadioAltimeter, TransceiverPair, TransceiverPairResponse, RADIO_ALTITUDE,
 };
 use crate::failures::{Failure, FailureType};
-use crate::shared::arinc429::{Arinc429Word, SignStatus};
+use crate::shared::arinc429::{Arinc429Bus, Arinc429Word, SignStatus};
 use crate::shared::{random_from_range, ConsumePower, ElectricalBusType, ElectricalBuses};
 use crate::simulation::{
     InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
-    SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+    SimulatorWriter, UpdateContext, VariableIdentifier,
 };
 use std::time::Duration;
 use uom::si::angle::degree;
@@ -339,6 +339,7 @@ pub struct Ala52BRadioAltimeter {
 
     // Outputs
     radio_altitude_id: VariableIdentifier,
+    bus: Arinc429Bus,
 }
 
 impl Ala52BRadioAltimeter {
@@ -382,6 +383,7 @@ impl Ala52BRadioAltimeter {
             },
             failure: Failure::new(FailureType::RadioAltimeter(number)),
             radio_altitude_id: context.get_identifier(Self::radio_altitude_id(number)),
+            bus: Arinc429Bus::new(number as u8, &[RADIO_ALTITUDE]),
         }
     }
 
@@ -390,6 +392,11 @@ impl Ala52BRadioAltimeter {
     }
 
     pub fn update(&mut self, context: &UpdateContext, transceivers: &Ala52BTransceiverPair) {
+        self.update_runtime(context, transceivers);
+        self.update_bus(context);
+    }
+
+    fn update_runtime(&mut self, context: &UpdateContext, transceivers: &Ala52BTransceiverPair) {
         if self.is_powered {
             self.unpowered_for = Duration::ZERO;
         } else {
@@ -418,6 +425,21 @@ impl Ala52BRadioAltimeter {
         }
     }
 
+    /// The radio altitude is only transmitted while the unit is powered and has a runtime.
+    fn update_bus(&mut self, context: &UpdateContext) {
+        if self.is_powered && self.runtime.is_some() {
+            let radio_altitude = self.radio_altitude();
+            self.bus.transmit(
+                RADIO_ALTITUDE.label(),
+                Arinc429Word::new(radio_altitude.value().get::<foot>(), radio_altitude.ssm()),
+            );
+        } else {
+            self.bus.stop_transmission();
+        }
+
+        self.bus.update(context.delta());
+    }
+
     pub fn has_failed(&self) -> bool {
         self.failure.is_active()
     }
@@ -441,6 +463,10 @@ impl RadioAltimeter for Ala52BRadioAltimeter {
             Arinc429Word::new(Length::new::<foot>(0.), SignStatus::FailureWarning)
         }
     }
+
+    fn bus(&self) -> &Arinc429Bus {
+        &self.bus
+    }
 }
 
 impl SimulationElement for Ala52BRadioAltimeter {
@@ -451,12 +477,8 @@ impl SimulationElement for Ala52BRadioAltimeter {
     }
 
     fn write(&self, writer: &mut SimulatorWriter) {
-        let radio_altitude = self.radio_altitude();
-        writer.write_arinc429(
-            &self.radio_altitude_id,
-            radio_altitude.value(),
-            radio_altitude.ssm(),
-        );
+        self.bus
+            .write_received(writer, &self.radio_altitude_id, RADIO_ALTITUDE.label());
     }
 
     fn receive_power(&mut self, buses: &impl ElectricalBuses) {
@@ -659,6 +681,14 @@ mod tests {
             self
         }
 
+        fn transmitted_height(&self, number: usize) -> Option<Arinc429Word<f64>> {
+            self.query(|a| {
+                a.radio_altimeter(number)
+                    .bus()
+                    .receive_value(RADIO_ALTITUDE.label())
+            })
+        }
+
         fn assert_radio_altimeter_has_failed(&mut self, number: usize) {
             assert!(self.query(|a| a.radio_altimeter(number).has_failed()));
         }
@@ -786,7 +816,7 @@ mod tests {
         ));
 
         test_bed.command(|a| a.set_ac_1_power(true));
-        test_bed.run_with_delta(Duration::from_millis(1));
+        test_bed.run_with_delta(RADIO_ALTITUDE.period());
 
         test_bed.assert_radio_altitude_normal_operation(1);
     }
@@ -835,6 +865,45 @@ mod tests {
         test_bed.assert_radio_altimeter_has_failed(1);
     }
 
+    #[test]
+    fn transmits_the_radio_altitude_on_the_bus() {
+        let mut test_bed = test_bed_with().height_over_ground(Length::new::<foot>(500.));
+        test_bed.run_with_delta(Duration::from_millis(
+            Ala52BRadioAltimeter::MAXIMUM_STARTUP_TIME_MILLIS,
+        ));
+
+        let transmitted_height = test_bed.transmitted_height(1).unwrap();
+        assert!(transmitted_height.is_normal_operation());
+        assert_about_eq!(
+            transmitted_height.value(),
+            test_bed.measured_height(1).value().get::<foot>()
+        );
+    }
+
+    #[test]
+    fn stops_transmitting_when_unpowered() {
+        let mut test_bed = test_bed_with().height_over_ground(Length::new::<foot>(500.));
+        test_bed.run_with_delta(Duration::from_millis(
+            Ala52BRadioAltimeter::MAXIMUM_STARTUP_TIME_MILLIS,
+        ));
+
+        test_bed.command(|a| a.set_ac_1_power(false));
+        test_bed.run_with_delta(Duration::from_millis(100));
+
+        assert!(test_bed.transmitted_height(1).is_none());
+    }
+
+    #[test]
+    fn stops_transmitting_when_failed() {
+        let mut test_bed = test_bed_with()
+            .height_over_ground(Length::new::<foot>(500.))
+            .and()
+            .failed_radio_altimeter(1);
+        test_bed.run_with_delta(Duration::from_millis(1));
+
+        assert!(test_bed.transmitted_height(1).is_none());
+    }
+
     #[test]
     fn consumes_power_when_operating_normally() {
         let mut test_bed = test_bed_with().height_over_ground(Length::new::<foot>(500.));
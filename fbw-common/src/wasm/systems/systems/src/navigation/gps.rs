@@ -0,0 +1,361 @@
+use crate::failures::{Failure, FailureType};
+use crate::shared::arinc429::{Arinc429Bus, Arinc429LabelDefinition, Arinc429Word, SignStatus};
+use crate::shared::{ElectricalBusType, ElectricalBuses};
+use crate::simulation::{
+    InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader, UpdateContext,
+    VariableIdentifier,
+};
+use std::time::Duration;
+use uom::si::angle::degree;
+use uom::si::f64::Angle;
+
+/// Label 110, the GPS latitude in degrees.
+pub const GPS_LATITUDE: Arinc429LabelDefinition =
+    Arinc429LabelDefinition::bnr(0o110, 18, 0.0006866455078125, Duration::from_millis(200));
+
+/// Label 111, the GPS longitude in degrees.
+pub const GPS_LONGITUDE: Arinc429LabelDefinition =
+    Arinc429LabelDefinition::bnr(0o111, 18, 0.0006866455078125, Duration::from_millis(200));
+
+/// This trait describes a GPS receiver which transmits the position it computes from the satellite
+/// signals on its output bus.
+pub trait GpsReceiver {
+    fn bus(&self) -> &Arinc429Bus;
+}
+
+/// A GPS receiver as found in the multi mode receivers. Its position is used by the ADIRUs to
+/// compute the hybrid (GPIRS) position and by the EGPWC when the hybrid position of ADIRU 1 is
+/// unavailable.
+pub struct GpsReceiverUnit {
+    failure: Failure,
+
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+
+    latitude_id: VariableIdentifier,
+    longitude_id: VariableIdentifier,
+    latitude: Angle,
+    longitude: Angle,
+
+    /// The remaining time until enough satellites are tracked to compute a position.
+    remaining_acquisition_duration: Duration,
+
+    bus: Arinc429Bus,
+}
+
+impl GpsReceiverUnit {
+    const LATITUDE: &'static str = "GPS POSITION LAT";
+    const LONGITUDE: &'static str = "GPS POSITION LON";
+
+    /// The time it takes to acquire the satellites after a loss of power or a failure.
+    const ACQUISITION_DURATION: Duration = Duration::from_secs(75);
+
+    pub fn new(context: &mut InitContext, number: usize, powered_by: ElectricalBusType) -> Self {
+        Self {
+            failure: Failure::new(FailureType::GpsReceiver(number)),
+            powered_by,
+            is_powered: false,
+            latitude_id: context.get_identifier(Self::LATITUDE.to_owned()),
+            longitude_id: context.get_identifier(Self::LONGITUDE.to_owned()),
+            latitude: Angle::default(),
+            longitude: Angle::default(),
+            // We start with the satellites acquired to support starting on the runway or in the
+            // air.
+            remaining_acquisition_duration: Duration::ZERO,
+            bus: Arinc429Bus::new(number as u8, &[GPS_LATITUDE, GPS_LONGITUDE]),
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext) {
+        if self.is_powered && !self.failure.is_active() {
+            self.remaining_acquisition_duration = self
+                .remaining_acquisition_duration
+                .saturating_sub(context.delta());
+        } else {
+            self.remaining_acquisition_duration = Self::ACQUISITION_DURATION;
+        }
+
+        self.update_bus(context);
+    }
+
+    /// The position is only transmitted while the receiver is powered and not failed. Until the
+    /// satellites are acquired, it is transmitted as no computed data.
+    fn update_bus(&mut self, context: &UpdateContext) {
+        if self.is_powered && !self.failure.is_active() {
+            let ssm = if self.has_acquired_satellites() {
+                SignStatus::NormalOperation
+            } else {
+                SignStatus::NoComputedData
+            };
+            self.bus.transmit(
+                GPS_LATITUDE.label(),
+                Arinc429Word::new(self.latitude.get::<degree>(), ssm),
+            );
+            self.bus.transmit(
+                GPS_LONGITUDE.label(),
+                Arinc429Word::new(self.longitude.get::<degree>(), ssm),
+            );
+        } else {
+            self.bus.stop_transmission();
+        }
+
+        self.bus.update(context.delta());
+    }
+
+    fn has_acquired_satellites(&self) -> bool {
+        self.remaining_acquisition_duration.is_zero()
+    }
+}
+
+impl GpsReceiver for GpsReceiverUnit {
+    fn bus(&self) -> &Arinc429Bus {
+        &self.bus
+    }
+}
+
+impl SimulationElement for GpsReceiverUnit {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.latitude = reader.read(&self.latitude_id);
+        self.longitude = reader.read(&self.longitude_id);
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use super::*;
+
+    /// A GPS receiver which transmits the position it is given, for testing the systems which
+    /// receive the GPS position.
+    pub(crate) struct TestGpsReceiver {
+        position: Option<(Angle, Angle)>,
+        bus: Arinc429Bus,
+    }
+    impl TestGpsReceiver {
+        pub(crate) fn new() -> Self {
+            Self {
+                position: None,
+                bus: Arinc429Bus::new(1, &[GPS_LATITUDE, GPS_LONGITUDE]),
+            }
+        }
+
+        pub(crate) fn set_position(&mut self, position: Option<(Angle, Angle)>) {
+            self.position = position;
+        }
+
+        pub(crate) fn update(&mut self, context: &UpdateContext) {
+            if let Some((latitude, longitude)) = self.position {
+                self.bus.transmit(
+                    GPS_LATITUDE.label(),
+                    Arinc429Word::new(latitude.get::<degree>(), SignStatus::NormalOperation),
+                );
+                self.bus.transmit(
+                    GPS_LONGITUDE.label(),
+                    Arinc429Word::new(longitude.get::<degree>(), SignStatus::NormalOperation),
+                );
+            } else {
+                self.bus.stop_transmission();
+            }
+
+            self.bus.update(context.delta());
+        }
+    }
+    impl GpsReceiver for TestGpsReceiver {
+        fn bus(&self) -> &Arinc429Bus {
+            &self.bus
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::electrical::test::TestElectricitySource;
+    use crate::electrical::{ElectricalBus, Electricity};
+    use crate::shared::PotentialOrigin;
+    use crate::simulation::test::{SimulationTestBed, TestBed, WriteByName};
+    use crate::simulation::{Aircraft, InitContext, SimulationElementVisitor};
+    use ntest::assert_about_eq;
+
+    struct TestAircraft {
+        electricity_source: TestElectricitySource,
+        ac_1_bus: ElectricalBus,
+        gps_receiver: GpsReceiverUnit,
+        is_ac_1_powered: bool,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                electricity_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::EngineGenerator(1),
+                ),
+                ac_1_bus: ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(1)),
+                gps_receiver: GpsReceiverUnit::new(
+                    context,
+                    1,
+                    ElectricalBusType::AlternatingCurrent(1),
+                ),
+                is_ac_1_powered: true,
+            }
+        }
+
+        fn set_ac_1_power(&mut self, is_powered: bool) {
+            self.is_ac_1_powered = is_powered;
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            electricity.supplied_by(&self.electricity_source);
+
+            if self.is_ac_1_powered {
+                electricity.flow(&self.electricity_source, &self.ac_1_bus);
+            }
+        }
+
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.gps_receiver.update(context);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.gps_receiver.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    struct GpsTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl GpsTestBed {
+        fn new() -> Self {
+            let mut gps_test_bed = Self {
+                test_bed: SimulationTestBed::new(TestAircraft::new),
+            };
+            gps_test_bed.write_by_name(GpsReceiverUnit::LATITUDE, Angle::new::<degree>(47.26));
+            gps_test_bed.write_by_name(GpsReceiverUnit::LONGITUDE, Angle::new::<degree>(11.34));
+
+            gps_test_bed
+        }
+
+        fn no_power(mut self) -> Self {
+            self.command(|a| a.set_ac_1_power(false));
+            self
+        }
+
+        fn powered(mut self) -> Self {
+            self.command(|a| a.set_ac_1_power(true));
+            self
+        }
+
+        fn failed_gps_receiver(mut self) -> Self {
+            self.fail(FailureType::GpsReceiver(1));
+            self
+        }
+
+        fn transmitted_latitude(&self) -> Option<Arinc429Word<f64>> {
+            self.query(|a| a.gps_receiver.bus().receive_value(GPS_LATITUDE.label()))
+        }
+
+        fn transmitted_longitude(&self) -> Option<Arinc429Word<f64>> {
+            self.query(|a| a.gps_receiver.bus().receive_value(GPS_LONGITUDE.label()))
+        }
+    }
+    impl TestBed for GpsTestBed {
+        type Aircraft = TestAircraft;
+
+        fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            &mut self.test_bed
+        }
+    }
+
+    fn test_bed() -> GpsTestBed {
+        GpsTestBed::new()
+    }
+
+    #[test]
+    fn transmits_the_position() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+
+        let latitude = test_bed.transmitted_latitude().unwrap();
+        let longitude = test_bed.transmitted_longitude().unwrap();
+        assert!(latitude.is_normal_operation());
+        assert!(longitude.is_normal_operation());
+        assert_about_eq!(latitude.value(), 47.26, 0.001);
+        assert_about_eq!(longitude.value(), 11.34, 0.001);
+    }
+
+    #[test]
+    fn stops_transmitting_when_unpowered() {
+        let mut test_bed = test_bed().no_power();
+        test_bed.run();
+
+        assert!(test_bed.transmitted_latitude().is_none());
+        assert!(test_bed.transmitted_longitude().is_none());
+    }
+
+    #[test]
+    fn stops_transmitting_when_failed() {
+        let mut test_bed = test_bed().failed_gps_receiver();
+        test_bed.run();
+
+        assert!(test_bed.transmitted_latitude().is_none());
+        assert!(test_bed.transmitted_longitude().is_none());
+    }
+
+    #[test]
+    fn transmits_no_computed_data_while_acquiring_satellites_after_power_loss() {
+        let mut test_bed = test_bed().no_power();
+        test_bed.run();
+
+        test_bed = test_bed.powered();
+        test_bed.run_with_delta(Duration::from_secs(74));
+
+        assert!(test_bed
+            .transmitted_latitude()
+            .unwrap()
+            .is_no_computed_data());
+        assert!(test_bed
+            .transmitted_longitude()
+            .unwrap()
+            .is_no_computed_data());
+    }
+
+    #[test]
+    fn transmits_the_position_once_satellites_are_acquired() {
+        let mut test_bed = test_bed().no_power();
+        test_bed.run();
+
+        test_bed = test_bed.powered();
+        test_bed.run_with_delta(Duration::from_secs(74));
+        test_bed.run_with_delta(Duration::from_secs(2));
+
+        assert!(test_bed
+            .transmitted_latitude()
+            .unwrap()
+            .is_normal_operation());
+        assert!(test_bed
+            .transmitted_longitude()
+            .unwrap()
+            .is_normal_operation());
+    }
+}
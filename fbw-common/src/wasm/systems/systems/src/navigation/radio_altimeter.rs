@@ -1,6 +1,11 @@
-use crate::shared::arinc429::Arinc429Word;
+use crate::shared::arinc429::{Arinc429Bus, Arinc429LabelDefinition, Arinc429Word};
+use std::time::Duration;
 use uom::si::f64::{Length, Time};
 
+/// Label 164, the radio altitude in feet.
+pub const RADIO_ALTITUDE: Arinc429LabelDefinition =
+    Arinc429LabelDefinition::bnr(0o164, 16, 0.125, Duration::from_millis(25));
+
 /// This struct describes the installation of a physical Antenna in relation to an aircraft's
 /// center of gravity.
 #[derive(Debug, Copy, Clone, PartialEq)]
@@ -61,4 +66,6 @@ impl TransceiverPairResponse {
 
 pub trait RadioAltimeter {
     fn radio_altitude(&self) -> Arinc429Word<Length>;
+    /// The output bus, which carries the [`RADIO_ALTITUDE`] label.
+    fn bus(&self) -> &Arinc429Bus;
 }
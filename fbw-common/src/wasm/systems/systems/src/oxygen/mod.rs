@@ -0,0 +1,894 @@
+use std::time::Duration;
+
+use crate::{
+    failures::{Failure, FailureType},
+    overhead::{MomentaryPushButton, OnOffPushButton},
+    shared::{
+        AverageExt, CabinSimulation, ElectricalBusType, ElectricalBuses,
+        InternationalStandardAtmosphere,
+    },
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, SnapshotReader, SnapshotWriter, UpdateContext, VariableIdentifier, Write,
+    },
+};
+
+use uom::si::{
+    f64::*,
+    length::foot,
+    mass::kilogram,
+    mass_rate::kilogram_per_second,
+    pressure::{pascal, psi},
+    thermodynamic_temperature::{degree_celsius, kelvin},
+};
+
+/// The oxygen controls on the overhead panel: the CREW SUPPLY push button, the guarded
+/// MASK MAN ON push button and the TMR RESET push button of the passenger oxygen timer.
+pub struct OxygenOverheadPanel {
+    crew_supply_pb: OnOffPushButton,
+    mask_man_on_pb: MomentaryPushButton,
+    tmr_reset_pb: MomentaryPushButton,
+}
+impl OxygenOverheadPanel {
+    pub fn new(context: &mut InitContext) -> Self {
+        Self {
+            crew_supply_pb: OnOffPushButton::new_on(context, "OXYGEN_CREW_SUPPLY"),
+            mask_man_on_pb: MomentaryPushButton::new(context, "OXYGEN_MASK_MAN_ON"),
+            tmr_reset_pb: MomentaryPushButton::new(context, "OXYGEN_TMR_RESET"),
+        }
+    }
+
+    pub fn crew_supply_is_on(&self) -> bool {
+        self.crew_supply_pb.is_on()
+    }
+
+    pub fn mask_man_on_is_pressed(&self) -> bool {
+        self.mask_man_on_pb.is_pressed()
+    }
+
+    pub fn tmr_reset_is_pressed(&self) -> bool {
+        self.tmr_reset_pb.is_pressed()
+    }
+}
+impl SimulationElement for OxygenOverheadPanel {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.crew_supply_pb.accept(visitor);
+        self.mask_man_on_pb.accept(visitor);
+        self.tmr_reset_pb.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
+/// A high pressure bottle of gaseous oxygen. The pressure in the bottle follows from the
+/// remaining oxygen and the temperature of the compartment in which the bottle is installed.
+pub struct CrewOxygenBottle {
+    pressure_id: VariableIdentifier,
+    low_pressure_id: VariableIdentifier,
+    content_id: VariableIdentifier,
+
+    content: Mass,
+    temperature: ThermodynamicTemperature,
+    initialized: bool,
+    leak_failure: Failure,
+}
+impl CrewOxygenBottle {
+    /// The internal volume of a 115 cu ft (3260 l of gaseous oxygen) bottle.
+    const VOLUME_CUBIC_METER: f64 = 0.0255;
+    const SPECIFIC_GAS_CONSTANT_OXYGEN: f64 = 259.8;
+
+    const FULL_PRESSURE_PSI: f64 = 1850.;
+    const FULL_PRESSURE_REFERENCE_TEMPERATURE_DEGREE_CELSIUS: f64 = 21.;
+    const LOW_PRESSURE_PSI: f64 = 400.;
+
+    /// The time it takes the bottle to follow a change of the compartment temperature.
+    const THERMAL_TIME_CONSTANT: Duration = Duration::from_secs(1200);
+
+    /// A leaking bottle loses half of its content in this time.
+    const LEAK_HALF_LIFE: Duration = Duration::from_secs(1800);
+
+    pub fn new(context: &mut InitContext, number: usize) -> Self {
+        Self {
+            pressure_id: context.get_identifier(format!("OXYGEN_CREW_BOTTLE_{}_PRESSURE", number)),
+            low_pressure_id: context
+                .get_identifier(format!("OXYGEN_CREW_BOTTLE_{}_LOW_PRESSURE", number)),
+            content_id: context.get_identifier(format!("OXYGEN_CREW_BOTTLE_{}_CONTENT", number)),
+
+            content: Self::content_at(
+                Pressure::new::<psi>(Self::FULL_PRESSURE_PSI),
+                ThermodynamicTemperature::new::<degree_celsius>(
+                    Self::FULL_PRESSURE_REFERENCE_TEMPERATURE_DEGREE_CELSIUS,
+                ),
+            ),
+            temperature: ThermodynamicTemperature::new::<degree_celsius>(
+                Self::FULL_PRESSURE_REFERENCE_TEMPERATURE_DEGREE_CELSIUS,
+            ),
+            initialized: false,
+            leak_failure: Failure::new(FailureType::CrewOxygenBottleLeak(number)),
+        }
+    }
+
+    fn content_at(pressure: Pressure, temperature: ThermodynamicTemperature) -> Mass {
+        Mass::new::<kilogram>(
+            pressure.get::<pascal>() * Self::VOLUME_CUBIC_METER
+                / (Self::SPECIFIC_GAS_CONSTANT_OXYGEN * temperature.get::<kelvin>()),
+        )
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        compartment_temperature: ThermodynamicTemperature,
+        consumption: MassRate,
+    ) {
+        if !self.initialized {
+            self.temperature = compartment_temperature;
+            self.initialized = true;
+        }
+
+        let temperature_difference =
+            compartment_temperature.get::<kelvin>() - self.temperature.get::<kelvin>();
+        self.temperature = ThermodynamicTemperature::new::<kelvin>(
+            self.temperature.get::<kelvin>()
+                + temperature_difference
+                    * (1.
+                        - (-context.delta_as_secs_f64()
+                            / Self::THERMAL_TIME_CONSTANT.as_secs_f64())
+                        .exp()),
+        );
+
+        let mut content = self.content - consumption * context.delta_as_time();
+        if self.leak_failure.is_active() {
+            content *=
+                0.5_f64.powf(context.delta_as_secs_f64() / Self::LEAK_HALF_LIFE.as_secs_f64());
+        }
+        self.content = content.max(Mass::default());
+    }
+
+    pub fn pressure(&self) -> Pressure {
+        Pressure::new::<pascal>(
+            self.content.get::<kilogram>()
+                * Self::SPECIFIC_GAS_CONSTANT_OXYGEN
+                * self.temperature.get::<kelvin>()
+                / Self::VOLUME_CUBIC_METER,
+        )
+    }
+
+    pub fn has_low_pressure(&self) -> bool {
+        self.pressure() < Pressure::new::<psi>(Self::LOW_PRESSURE_PSI)
+    }
+
+    fn content(&self) -> Mass {
+        self.content
+    }
+}
+impl SimulationElement for CrewOxygenBottle {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.leak_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.pressure_id, self.pressure());
+        writer.write(&self.low_pressure_id, self.has_low_pressure());
+    }
+
+    fn save_state(&self, writer: &mut SnapshotWriter) {
+        writer.write(&self.content_id, self.content);
+    }
+
+    fn restore_state(&mut self, reader: &mut SnapshotReader) {
+        if let Some(content) = reader.restore(&self.content_id) {
+            self.content = content;
+        }
+    }
+}
+
+/// A crew oxygen mask. The mask delivers oxygen on demand while it is in use. In normal mode
+/// the oxygen is diluted with cabin air, such that the inhaled oxygen partial pressure equals
+/// the one at sea level. In 100% mode the mask delivers pure oxygen.
+pub struct CrewOxygenMask {
+    is_in_use_id: VariableIdentifier,
+    is_100_percent_id: VariableIdentifier,
+
+    is_in_use: bool,
+    is_100_percent: bool,
+}
+impl CrewOxygenMask {
+    const BREATHING_VOLUME_LITER_PER_MINUTE: f64 = 30.;
+    const OXYGEN_FRACTION_OF_AIR: f64 = 0.21;
+    const BREATHING_TEMPERATURE_DEGREE_CELSIUS: f64 = 20.;
+
+    pub fn new(context: &mut InitContext, number: usize) -> Self {
+        Self {
+            is_in_use_id: context.get_identifier(format!("OXYGEN_CREW_MASK_{}_IS_IN_USE", number)),
+            is_100_percent_id: context
+                .get_identifier(format!("OXYGEN_CREW_MASK_{}_IS_100_PERCENT", number)),
+
+            is_in_use: false,
+            is_100_percent: false,
+        }
+    }
+
+    fn oxygen_demand(&self, cabin_pressure: Pressure) -> MassRate {
+        if !self.is_in_use {
+            return MassRate::default();
+        }
+
+        let oxygen_fraction = if self.is_100_percent {
+            1.
+        } else {
+            let required_oxygen_fraction = (Self::OXYGEN_FRACTION_OF_AIR
+                * InternationalStandardAtmosphere::ground_pressure().get::<pascal>()
+                / cabin_pressure.get::<pascal>().max(1.))
+            .clamp(Self::OXYGEN_FRACTION_OF_AIR, 1.);
+
+            (required_oxygen_fraction - Self::OXYGEN_FRACTION_OF_AIR)
+                / (1. - Self::OXYGEN_FRACTION_OF_AIR)
+        };
+
+        let oxygen_density = cabin_pressure.get::<pascal>()
+            / (CrewOxygenBottle::SPECIFIC_GAS_CONSTANT_OXYGEN
+                * ThermodynamicTemperature::new::<degree_celsius>(
+                    Self::BREATHING_TEMPERATURE_DEGREE_CELSIUS,
+                )
+                .get::<kelvin>());
+
+        MassRate::new::<kilogram_per_second>(
+            oxygen_fraction * Self::BREATHING_VOLUME_LITER_PER_MINUTE / 1000. / 60.
+                * oxygen_density,
+        )
+    }
+}
+impl SimulationElement for CrewOxygenMask {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.is_in_use = reader.read(&self.is_in_use_id);
+        self.is_100_percent = reader.read(&self.is_100_percent_id);
+    }
+}
+
+/// The crew oxygen system supplies the crew masks from one or more high pressure bottles.
+/// The oxygen passes through a pressure regulator and the low pressure supply valve,
+/// which is opened by the CREW SUPPLY push button.
+pub struct CrewOxygenSystem<const BOTTLES: usize, const MASKS: usize> {
+    supply_valve_is_open_id: VariableIdentifier,
+    regulator_low_pressure_id: VariableIdentifier,
+
+    bottles: [CrewOxygenBottle; BOTTLES],
+    masks: [CrewOxygenMask; MASKS],
+
+    supply_valve_powered_by: ElectricalBusType,
+    supply_valve_is_powered: bool,
+    supply_valve_is_open: bool,
+    regulated_pressure: Pressure,
+    regulator_failure: Failure,
+}
+impl<const BOTTLES: usize, const MASKS: usize> CrewOxygenSystem<BOTTLES, MASKS> {
+    const REGULATED_PRESSURE_PSI: f64 = 78.;
+    const FAILED_REGULATOR_PRESSURE_PSI: f64 = 30.;
+
+    /// Below this pressure the REGUL LO PR indication is shown and the masks are no
+    /// longer supplied.
+    const REGULATOR_LOW_PRESSURE_PSI: f64 = 50.;
+
+    pub fn new(context: &mut InitContext, supply_valve_powered_by: ElectricalBusType) -> Self {
+        Self {
+            supply_valve_is_open_id: context
+                .get_identifier("OXYGEN_CREW_SUPPLY_VALVE_IS_OPEN".to_owned()),
+            regulator_low_pressure_id: context
+                .get_identifier("OXYGEN_CREW_REGULATOR_LOW_PRESSURE".to_owned()),
+
+            bottles: std::array::from_fn(|index| CrewOxygenBottle::new(context, index + 1)),
+            masks: std::array::from_fn(|index| CrewOxygenMask::new(context, index + 1)),
+
+            supply_valve_powered_by,
+            supply_valve_is_powered: false,
+            supply_valve_is_open: false,
+            regulated_pressure: Pressure::default(),
+            regulator_failure: Failure::new(FailureType::CrewOxygenRegulatorLowPressure),
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        overhead: &OxygenOverheadPanel,
+        cabin: &impl CabinSimulation,
+    ) {
+        self.supply_valve_is_open = overhead.crew_supply_is_on() && self.supply_valve_is_powered;
+
+        let bottle_pressure = self
+            .bottles
+            .iter()
+            .map(|bottle| bottle.pressure())
+            .fold(Pressure::default(), Pressure::max);
+
+        self.regulated_pressure = if !self.supply_valve_is_open {
+            Pressure::default()
+        } else if self.regulator_failure.is_active() {
+            Pressure::new::<psi>(Self::FAILED_REGULATOR_PRESSURE_PSI).min(bottle_pressure)
+        } else {
+            Pressure::new::<psi>(Self::REGULATED_PRESSURE_PSI).min(bottle_pressure)
+        };
+
+        let consumption = if self.masks_are_supplied() {
+            self.masks
+                .iter()
+                .map(|mask| mask.oxygen_demand(cabin.cabin_pressure()))
+                .fold(MassRate::default(), |total, demand| total + demand)
+        } else {
+            MassRate::default()
+        };
+
+        // The bottles share a common manifold, thus they are drawn from in proportion
+        // to their remaining content.
+        let total_content = self
+            .bottles
+            .iter()
+            .fold(Mass::default(), |total, bottle| total + bottle.content());
+        let compartment_temperature = cabin.cabin_temperature().iter().average();
+        self.bottles.iter_mut().for_each(|bottle| {
+            let share = if total_content > Mass::default() {
+                (bottle.content() / total_content).value
+            } else {
+                0.
+            };
+
+            bottle.update(context, compartment_temperature, consumption * share)
+        });
+    }
+
+    fn regulator_has_low_pressure(&self) -> bool {
+        self.regulated_pressure < Pressure::new::<psi>(Self::REGULATOR_LOW_PRESSURE_PSI)
+    }
+
+    pub fn masks_are_supplied(&self) -> bool {
+        self.supply_valve_is_open && !self.regulator_has_low_pressure()
+    }
+
+    pub fn bottle_pressure(&self, number: usize) -> Pressure {
+        self.bottles[number - 1].pressure()
+    }
+}
+impl<const BOTTLES: usize, const MASKS: usize> SimulationElement
+    for CrewOxygenSystem<BOTTLES, MASKS>
+{
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.bottles, visitor);
+        accept_iterable!(self.masks, visitor);
+        self.regulator_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.supply_valve_is_open_id, self.supply_valve_is_open);
+        writer.write(
+            &self.regulator_low_pressure_id,
+            self.supply_valve_is_open && self.regulator_has_low_pressure(),
+        );
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.supply_valve_is_powered = buses.is_powered(self.supply_valve_powered_by);
+    }
+}
+
+/// The passenger oxygen masks are released automatically when the cabin altitude exceeds
+/// the deployment altitude, or manually with the MASK MAN ON push button. The masks are
+/// supplied by chemical oxygen generators, which produce oxygen for a limited time once
+/// activated. The PASSENGER SYS ON light remains on until the timer is reset with the
+/// TMR RESET push button. The TMR RESET FAULT light comes on when the generators are
+/// exhausted and the timer hasn't been reset.
+pub struct PassengerOxygenSystem {
+    masks_deployed_id: VariableIdentifier,
+    passenger_light_on_id: VariableIdentifier,
+    tmr_reset_fault_id: VariableIdentifier,
+    generators_active_id: VariableIdentifier,
+
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+    masks_deployed: bool,
+    generator_duration: Duration,
+    generator_running_time: Duration,
+}
+impl PassengerOxygenSystem {
+    const DEPLOYMENT_CABIN_ALTITUDE_FEET: f64 = 14000.;
+
+    pub fn new(
+        context: &mut InitContext,
+        powered_by: ElectricalBusType,
+        generator_duration: Duration,
+    ) -> Self {
+        Self {
+            masks_deployed_id: context.get_identifier("OXYGEN_MASKS_DEPLOYED".to_owned()),
+            passenger_light_on_id: context.get_identifier("OXYGEN_PASSENGER_LIGHT_ON".to_owned()),
+            tmr_reset_fault_id: context.get_identifier("OXYGEN_TMR_RESET_FAULT".to_owned()),
+            generators_active_id: context
+                .get_identifier("OXYGEN_PASSENGER_GENERATORS_ACTIVE".to_owned()),
+
+            powered_by,
+            is_powered: false,
+            masks_deployed: false,
+            generator_duration,
+            generator_running_time: Duration::ZERO,
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        overhead: &OxygenOverheadPanel,
+        cabin: &impl CabinSimulation,
+    ) {
+        let cabin_altitude =
+            InternationalStandardAtmosphere::altitude_from_pressure(cabin.cabin_pressure());
+        let deployment_is_commanded = cabin_altitude
+            > Length::new::<foot>(Self::DEPLOYMENT_CABIN_ALTITUDE_FEET)
+            || overhead.mask_man_on_is_pressed();
+
+        if self.is_powered && deployment_is_commanded {
+            self.masks_deployed = true;
+        } else if self.is_powered && overhead.tmr_reset_is_pressed() {
+            self.masks_deployed = false;
+            self.generator_running_time = Duration::ZERO;
+        }
+
+        if self.masks_deployed {
+            self.generator_running_time =
+                (self.generator_running_time + context.delta()).min(self.generator_duration);
+        }
+    }
+
+    pub fn masks_deployed(&self) -> bool {
+        self.masks_deployed
+    }
+
+    pub fn generators_are_active(&self) -> bool {
+        self.masks_deployed && self.generator_running_time < self.generator_duration
+    }
+
+    fn generators_are_exhausted(&self) -> bool {
+        self.masks_deployed && self.generator_running_time >= self.generator_duration
+    }
+}
+impl SimulationElement for PassengerOxygenSystem {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.masks_deployed_id, self.masks_deployed);
+        writer.write(
+            &self.passenger_light_on_id,
+            self.is_powered && self.masks_deployed,
+        );
+        writer.write(
+            &self.tmr_reset_fault_id,
+            self.is_powered && self.generators_are_exhausted(),
+        );
+        writer.write(&self.generators_active_id, self.generators_are_active());
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+        shared::PotentialOrigin,
+        simulation::{
+            test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+            Aircraft, StartState,
+        },
+    };
+    use ntest::assert_about_eq;
+    use uom::si::pressure::hectopascal;
+
+    struct TestCabin {
+        pressure: Pressure,
+        temperature: ThermodynamicTemperature,
+    }
+    impl CabinSimulation for TestCabin {
+        fn cabin_temperature(&self) -> Vec<ThermodynamicTemperature> {
+            vec![self.temperature; 2]
+        }
+
+        fn cabin_pressure(&self) -> Pressure {
+            self.pressure
+        }
+    }
+
+    struct TestAircraft {
+        overhead: OxygenOverheadPanel,
+        crew_oxygen: CrewOxygenSystem<1, 2>,
+        passenger_oxygen: PassengerOxygenSystem,
+        cabin: TestCabin,
+
+        powered_source: TestElectricitySource,
+        dc_ess_bus: ElectricalBus,
+        dc_ess_shed_bus: ElectricalBus,
+    }
+    impl TestAircraft {
+        const GENERATOR_DURATION: Duration = Duration::from_secs(15 * 60);
+
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                overhead: OxygenOverheadPanel::new(context),
+                crew_oxygen: CrewOxygenSystem::new(
+                    context,
+                    ElectricalBusType::DirectCurrentEssentialShed,
+                ),
+                passenger_oxygen: PassengerOxygenSystem::new(
+                    context,
+                    ElectricalBusType::DirectCurrentEssential,
+                    Self::GENERATOR_DURATION,
+                ),
+                cabin: TestCabin {
+                    pressure: InternationalStandardAtmosphere::ground_pressure(),
+                    temperature: ThermodynamicTemperature::new::<degree_celsius>(21.),
+                },
+
+                powered_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::Battery(1),
+                ),
+                dc_ess_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrentEssential),
+                dc_ess_shed_bus: ElectricalBus::new(
+                    context,
+                    ElectricalBusType::DirectCurrentEssentialShed,
+                ),
+            }
+        }
+
+        fn set_cabin_altitude(&mut self, altitude: Length) {
+            self.cabin.pressure = InternationalStandardAtmosphere::pressure_at_altitude(altitude);
+        }
+
+        fn set_cabin_temperature(&mut self, temperature: ThermodynamicTemperature) {
+            self.cabin.temperature = temperature;
+        }
+
+        fn unpower(&mut self) {
+            self.powered_source.unpower();
+        }
+
+        fn bottle_pressure(&self) -> Pressure {
+            self.crew_oxygen.bottle_pressure(1)
+        }
+
+        fn crew_masks_are_supplied(&self) -> bool {
+            self.crew_oxygen.masks_are_supplied()
+        }
+
+        fn passenger_generators_are_active(&self) -> bool {
+            self.passenger_oxygen.generators_are_active()
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _context: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            electricity.supplied_by(&self.powered_source);
+            electricity.flow(&self.powered_source, &self.dc_ess_bus);
+            electricity.flow(&self.powered_source, &self.dc_ess_shed_bus);
+        }
+
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.crew_oxygen
+                .update(context, &self.overhead, &self.cabin);
+            self.passenger_oxygen
+                .update(context, &self.overhead, &self.cabin);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<V: SimulationElementVisitor>(&mut self, visitor: &mut V) {
+            self.overhead.accept(visitor);
+            self.crew_oxygen.accept(visitor);
+            self.passenger_oxygen.accept(visitor);
+            self.dc_ess_bus.accept(visitor);
+            self.dc_ess_shed_bus.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed() -> SimulationTestBed<TestAircraft> {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.write_by_name("OVHD_OXYGEN_CREW_SUPPLY_PB_IS_ON", true);
+        test_bed.run();
+
+        test_bed
+    }
+
+    fn run_for(test_bed: &mut SimulationTestBed<TestAircraft>, duration: Duration) {
+        test_bed.run_iterations_with_delta(
+            (duration.as_secs_f64() / 10.).ceil() as u32,
+            Duration::from_secs(10),
+        );
+    }
+
+    fn bottle_pressure(test_bed: &SimulationTestBed<TestAircraft>) -> Pressure {
+        test_bed.query(|a| a.bottle_pressure())
+    }
+
+    fn is_set(test_bed: &mut SimulationTestBed<TestAircraft>, name: &str) -> bool {
+        test_bed.read_by_name(name)
+    }
+
+    fn press(test_bed: &mut SimulationTestBed<TestAircraft>, name: &str) {
+        test_bed.write_by_name(name, true);
+        test_bed.run();
+        test_bed.write_by_name(name, false);
+        test_bed.run();
+    }
+
+    #[test]
+    fn full_bottle_has_nominal_pressure_at_reference_temperature() {
+        let test_bed = test_bed();
+
+        assert_about_eq!(bottle_pressure(&test_bed).get::<psi>(), 1850., 1.);
+    }
+
+    #[test]
+    fn bottle_pressure_follows_compartment_temperature() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| {
+            a.set_cabin_temperature(ThermodynamicTemperature::new::<degree_celsius>(45.))
+        });
+        run_for(&mut test_bed, Duration::from_secs(3 * 3600));
+
+        // The pressure of the gas rises in proportion to its absolute temperature.
+        assert_about_eq!(
+            bottle_pressure(&test_bed).get::<psi>(),
+            1850. * (45. + 273.15) / (21. + 273.15),
+            5.
+        );
+    }
+
+    #[test]
+    fn bottle_pressure_is_written() {
+        let mut test_bed = test_bed();
+
+        let pressure: Pressure = test_bed.read_by_name("OXYGEN_CREW_BOTTLE_1_PRESSURE");
+        assert_about_eq!(pressure.get::<psi>(), 1850., 1.);
+        assert!(!is_set(&mut test_bed, "OXYGEN_CREW_BOTTLE_1_LOW_PRESSURE"));
+    }
+
+    #[test]
+    fn unused_masks_do_not_consume_oxygen() {
+        let mut test_bed = test_bed();
+        let pressure_before = bottle_pressure(&test_bed);
+        run_for(&mut test_bed, Duration::from_secs(3600));
+
+        assert_about_eq!(
+            bottle_pressure(&test_bed).get::<psi>(),
+            pressure_before.get::<psi>()
+        );
+    }
+
+    #[test]
+    fn mask_in_use_consumes_oxygen() {
+        let mut test_bed = test_bed();
+        let pressure_before = bottle_pressure(&test_bed);
+        test_bed.write_by_name("OXYGEN_CREW_MASK_1_IS_IN_USE", true);
+        test_bed.write_by_name("OXYGEN_CREW_MASK_1_IS_100_PERCENT", true);
+        run_for(&mut test_bed, Duration::from_secs(600));
+
+        assert!(test_bed.query(|a| a.crew_masks_are_supplied()));
+        assert!(bottle_pressure(&test_bed) < pressure_before - Pressure::new::<psi>(100.));
+    }
+
+    #[test]
+    fn diluted_mask_consumes_less_oxygen_than_100_percent_mask() {
+        let mut diluted = test_bed();
+        diluted.command(|a| a.set_cabin_altitude(Length::new::<foot>(8000.)));
+        diluted.write_by_name("OXYGEN_CREW_MASK_1_IS_IN_USE", true);
+        run_for(&mut diluted, Duration::from_secs(600));
+
+        let mut hundred_percent = test_bed();
+        hundred_percent.command(|a| a.set_cabin_altitude(Length::new::<foot>(8000.)));
+        hundred_percent.write_by_name("OXYGEN_CREW_MASK_1_IS_IN_USE", true);
+        hundred_percent.write_by_name("OXYGEN_CREW_MASK_1_IS_100_PERCENT", true);
+        run_for(&mut hundred_percent, Duration::from_secs(600));
+
+        assert!(bottle_pressure(&diluted) < Pressure::new::<psi>(1850.));
+        assert!(bottle_pressure(&diluted) > bottle_pressure(&hundred_percent));
+    }
+
+    #[test]
+    fn diluted_mask_doesnt_consume_oxygen_at_sea_level() {
+        let mut test_bed = test_bed();
+        let pressure_before = bottle_pressure(&test_bed);
+        test_bed.write_by_name("OXYGEN_CREW_MASK_1_IS_IN_USE", true);
+        run_for(&mut test_bed, Duration::from_secs(600));
+
+        assert_about_eq!(
+            bottle_pressure(&test_bed).get::<psi>(),
+            pressure_before.get::<psi>()
+        );
+    }
+
+    #[test]
+    fn masks_are_not_supplied_when_crew_supply_is_off() {
+        let mut test_bed = test_bed();
+        let pressure_before = bottle_pressure(&test_bed);
+        test_bed.write_by_name("OVHD_OXYGEN_CREW_SUPPLY_PB_IS_ON", false);
+        test_bed.write_by_name("OXYGEN_CREW_MASK_1_IS_IN_USE", true);
+        test_bed.write_by_name("OXYGEN_CREW_MASK_1_IS_100_PERCENT", true);
+        run_for(&mut test_bed, Duration::from_secs(600));
+
+        assert!(!test_bed.query(|a| a.crew_masks_are_supplied()));
+        assert!(!is_set(&mut test_bed, "OXYGEN_CREW_SUPPLY_VALVE_IS_OPEN"));
+        assert_about_eq!(
+            bottle_pressure(&test_bed).get::<psi>(),
+            pressure_before.get::<psi>()
+        );
+    }
+
+    #[test]
+    fn supply_valve_closes_when_unpowered() {
+        let mut test_bed = test_bed();
+        assert!(is_set(&mut test_bed, "OXYGEN_CREW_SUPPLY_VALVE_IS_OPEN"));
+
+        test_bed.command(|a| a.unpower());
+        test_bed.run();
+
+        assert!(!is_set(&mut test_bed, "OXYGEN_CREW_SUPPLY_VALVE_IS_OPEN"));
+        assert!(!test_bed.query(|a| a.crew_masks_are_supplied()));
+    }
+
+    #[test]
+    fn leaking_bottle_loses_pressure_until_low_pressure_is_indicated() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::CrewOxygenBottleLeak(1));
+        run_for(&mut test_bed, Duration::from_secs(1800));
+
+        assert_about_eq!(bottle_pressure(&test_bed).get::<psi>(), 1850. / 2., 5.);
+        assert!(!is_set(&mut test_bed, "OXYGEN_CREW_BOTTLE_1_LOW_PRESSURE"));
+
+        run_for(&mut test_bed, Duration::from_secs(3600));
+
+        assert!(is_set(&mut test_bed, "OXYGEN_CREW_BOTTLE_1_LOW_PRESSURE"));
+    }
+
+    #[test]
+    fn regulator_low_pressure_failure_stops_mask_supply() {
+        let mut test_bed = test_bed();
+        assert!(!is_set(&mut test_bed, "OXYGEN_CREW_REGULATOR_LOW_PRESSURE"));
+
+        let pressure_before = bottle_pressure(&test_bed);
+        test_bed.fail(FailureType::CrewOxygenRegulatorLowPressure);
+        test_bed.write_by_name("OXYGEN_CREW_MASK_1_IS_IN_USE", true);
+        test_bed.write_by_name("OXYGEN_CREW_MASK_1_IS_100_PERCENT", true);
+        run_for(&mut test_bed, Duration::from_secs(600));
+
+        assert!(is_set(&mut test_bed, "OXYGEN_CREW_REGULATOR_LOW_PRESSURE"));
+        assert!(!test_bed.query(|a| a.crew_masks_are_supplied()));
+        assert_about_eq!(
+            bottle_pressure(&test_bed).get::<psi>(),
+            pressure_before.get::<psi>()
+        );
+    }
+
+    #[test]
+    fn bottle_content_is_restored_from_snapshot() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::CrewOxygenBottleLeak(1));
+        run_for(&mut test_bed, Duration::from_secs(1800));
+        let pressure = bottle_pressure(&test_bed);
+        let snapshot = test_bed.snapshot();
+
+        let mut test_bed =
+            SimulationTestBed::new_from_snapshot(StartState::Apron, TestAircraft::new, &snapshot);
+        test_bed.run();
+
+        assert_about_eq!(
+            bottle_pressure(&test_bed).get::<psi>(),
+            pressure.get::<psi>(),
+            1.
+        );
+    }
+
+    #[test]
+    fn passenger_masks_are_not_deployed_below_deployment_cabin_altitude() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_cabin_altitude(Length::new::<foot>(13500.)));
+        test_bed.run();
+
+        assert!(!is_set(&mut test_bed, "OXYGEN_MASKS_DEPLOYED"));
+        assert!(!is_set(&mut test_bed, "OXYGEN_PASSENGER_LIGHT_ON"));
+    }
+
+    #[test]
+    fn passenger_masks_deploy_above_deployment_cabin_altitude() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_cabin_altitude(Length::new::<foot>(14500.)));
+        test_bed.run();
+
+        assert!(is_set(&mut test_bed, "OXYGEN_MASKS_DEPLOYED"));
+        assert!(is_set(&mut test_bed, "OXYGEN_PASSENGER_LIGHT_ON"));
+        assert!(test_bed.query(|a| a.passenger_generators_are_active()));
+    }
+
+    #[test]
+    fn passenger_masks_remain_deployed_after_cabin_altitude_decreases() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_cabin_altitude(Length::new::<foot>(25000.)));
+        test_bed.run();
+        test_bed.command(|a| a.set_cabin_altitude(Length::new::<foot>(8000.)));
+        test_bed.run();
+
+        assert!(is_set(&mut test_bed, "OXYGEN_MASKS_DEPLOYED"));
+    }
+
+    #[test]
+    fn passenger_masks_deploy_when_mask_man_on_is_pressed() {
+        let mut test_bed = test_bed();
+        press(&mut test_bed, "OVHD_OXYGEN_MASK_MAN_ON_IS_PRESSED");
+
+        assert!(is_set(&mut test_bed, "OXYGEN_MASKS_DEPLOYED"));
+    }
+
+    #[test]
+    fn passenger_masks_dont_deploy_when_unpowered() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.unpower());
+        test_bed.command(|a| a.set_cabin_altitude(Length::new::<foot>(25000.)));
+        press(&mut test_bed, "OVHD_OXYGEN_MASK_MAN_ON_IS_PRESSED");
+
+        assert!(!is_set(&mut test_bed, "OXYGEN_MASKS_DEPLOYED"));
+    }
+
+    #[test]
+    fn tmr_reset_restows_masks_below_deployment_cabin_altitude() {
+        let mut test_bed = test_bed();
+        press(&mut test_bed, "OVHD_OXYGEN_MASK_MAN_ON_IS_PRESSED");
+        press(&mut test_bed, "OVHD_OXYGEN_TMR_RESET_IS_PRESSED");
+
+        assert!(!is_set(&mut test_bed, "OXYGEN_MASKS_DEPLOYED"));
+        assert!(!is_set(&mut test_bed, "OXYGEN_PASSENGER_LIGHT_ON"));
+    }
+
+    #[test]
+    fn tmr_reset_has_no_effect_above_deployment_cabin_altitude() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_cabin_altitude(Length::new::<foot>(25000.)));
+        test_bed.run();
+        press(&mut test_bed, "OVHD_OXYGEN_TMR_RESET_IS_PRESSED");
+
+        assert!(is_set(&mut test_bed, "OXYGEN_MASKS_DEPLOYED"));
+    }
+
+    #[test]
+    fn chemical_generators_are_exhausted_after_their_duration() {
+        let mut test_bed = test_bed();
+        press(&mut test_bed, "OVHD_OXYGEN_MASK_MAN_ON_IS_PRESSED");
+        run_for(&mut test_bed, Duration::from_secs(14 * 60));
+
+        assert!(test_bed.query(|a| a.passenger_generators_are_active()));
+        assert!(!is_set(&mut test_bed, "OXYGEN_TMR_RESET_FAULT"));
+
+        run_for(&mut test_bed, Duration::from_secs(2 * 60));
+
+        assert!(!test_bed.query(|a| a.passenger_generators_are_active()));
+        assert!(is_set(&mut test_bed, "OXYGEN_TMR_RESET_FAULT"));
+    }
+
+    #[test]
+    fn rapid_decompression_deploys_passenger_masks() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_cabin_altitude(Length::new::<foot>(8000.)));
+        test_bed.run();
+        assert!(!is_set(&mut test_bed, "OXYGEN_MASKS_DEPLOYED"));
+
+        // After a rapid decompression the cabin pressure equals the exterior pressure.
+        test_bed.command(|a| a.cabin.pressure = Pressure::new::<hectopascal>(250.));
+        test_bed.run();
+
+        assert!(is_set(&mut test_bed, "OXYGEN_MASKS_DEPLOYED"));
+    }
+}
@@ -2,8 +2,9 @@ use crate::{
     failures::{Failure, FailureType},
     pneumatic::valve::*,
     shared::{
-        ControllerSignal, ElectricalBusType, ElectricalBuses, EngineCorrectedN1, EngineCorrectedN2,
-        HydraulicColor, PneumaticValve,
+        ControllerSignal, DelayedTrueLogicGate, ElectricalBusType, ElectricalBuses,
+        EngineBleedValveId, EngineCorrectedN1, EngineCorrectedN2, HydraulicColor,
+        LeakDetectionZone, PneumaticValve,
     },
     simulation::{
         InitContext, Read, Reader, SimulationElement, SimulationElementVisitor, SimulatorReader,
@@ -11,9 +12,12 @@ use crate::{
     },
 };
 
+use std::time::Duration;
+
 use uom::si::{
     f64::*,
     mass::kilogram,
+    mass_rate::kilogram_per_second,
     pressure::{pascal, psi},
     ratio::{percent, ratio},
     temperature_interval,
@@ -22,6 +26,7 @@ use uom::si::{
 };
 
 pub mod valve;
+pub mod wing_anti_ice;
 
 pub trait PneumaticValveSignal {
     fn new(target_open_amount: Ratio) -> Self;
@@ -531,9 +536,12 @@ pub struct Precooler {
     heat_transfer_coefficient: f64,
     internal_connector: PneumaticContainerConnector,
     exhaust: PneumaticExhaust,
+    is_blocked: bool,
+    has_lost_cooling: bool,
 }
 impl Precooler {
     const HEAT_CAPACITY_CONSTANT_PRESSURE: f64 = 1.005e3;
+    const BLOCKED_FLOW_FACTOR: f64 = 0.05;
 
     /// The `heat_transfer_coefficient` contains both the heat transfer coefficient and the area of exchange.
     /// Typical values of the heat transfer coefficient for air to air coolers are 60-180 W/(m^2*K).
@@ -542,6 +550,8 @@ impl Precooler {
             heat_transfer_coefficient,
             internal_connector: PneumaticContainerConnector::new(),
             exhaust: PneumaticExhaust::new(3., 3., Pressure::new::<psi>(0.)),
+            is_blocked: false,
+            has_lost_cooling: false,
         }
     }
 
@@ -557,8 +567,13 @@ impl Precooler {
                 - container_one.temperature().get::<degree_celsius>(),
         );
 
+        let heat_transfer_coefficient = if self.has_lost_cooling {
+            0.
+        } else {
+            self.heat_transfer_coefficient
+        };
         let mass_energy_change = temperature_gradient / Self::HEAT_CAPACITY_CONSTANT_PRESSURE
-            * (self.heat_transfer_coefficient * context.delta_as_secs_f64());
+            * (heat_transfer_coefficient * context.delta_as_secs_f64());
 
         supply.update_temperature(-mass_energy_change / supply.mass().get::<kilogram>());
         container_one
@@ -566,8 +581,23 @@ impl Precooler {
 
         self.exhaust.update_move_fluid(context, supply);
         self.internal_connector
+            .with_transfer_speed_factor(Ratio::new::<ratio>(if self.is_blocked {
+                Self::BLOCKED_FLOW_FACTOR
+            } else {
+                1.
+            }))
             .update_move_fluid(context, container_one, container_two);
     }
+
+    /// A blocked precooler core only lets a fraction of the bleed air through.
+    pub fn set_blocked(&mut self, is_blocked: bool) {
+        self.is_blocked = is_blocked;
+    }
+
+    /// Once the cooling is lost, the bleed air leaves the precooler at the temperature it entered.
+    pub fn set_cooling_lost(&mut self, has_lost_cooling: bool) {
+        self.has_lost_cooling = has_lost_cooling;
+    }
 }
 
 pub struct VariableVolumeContainer {
@@ -896,6 +926,254 @@ impl ControllerSignal<ThermodynamicTemperature> for BleedTemperatureSensor {
     }
 }
 
+/// A rupture in a bleed air duct. While the leak failure is active, the hot bleed air escapes
+/// from the duct into the surrounding zone of the airframe.
+pub struct BleedAirDuctLeak {
+    failure: Failure,
+
+    leak_speed: f64,
+    fluid_flow: MassRate,
+    leaking_air_temperature: ThermodynamicTemperature,
+}
+impl BleedAirDuctLeak {
+    /// The `leak_speed` determines how quickly the duct is emptied through the rupture.
+    pub fn new(zone: LeakDetectionZone, leak_speed: f64) -> Self {
+        Self {
+            failure: Failure::new(FailureType::BleedAirLeak(zone)),
+            leak_speed,
+            fluid_flow: MassRate::default(),
+            leaking_air_temperature: ThermodynamicTemperature::new::<degree_celsius>(15.),
+        }
+    }
+
+    pub fn update_move_fluid(
+        &mut self,
+        context: &UpdateContext,
+        duct: &mut impl PneumaticContainer,
+    ) {
+        let mass_flow = if self.failure.is_active() && duct.pressure() > context.ambient_pressure()
+        {
+            duct.get_mass_flow_for_target_pressure(
+                context.ambient_pressure(),
+                context.ambient_temperature(),
+            ) * (1. - (-self.leak_speed * context.delta_as_secs_f64()).exp())
+        } else {
+            Mass::default()
+        };
+
+        self.leaking_air_temperature = duct.temperature();
+        duct.change_fluid_amount(
+            mass_flow,
+            context.ambient_temperature(),
+            context.ambient_pressure(),
+        );
+
+        self.fluid_flow = -mass_flow / context.delta_as_time();
+    }
+
+    /// Used while the leaking duct is isolated from its supply, in which case no air escapes.
+    pub fn update_isolated(&mut self) {
+        self.fluid_flow = MassRate::default();
+    }
+
+    pub fn fluid_flow(&self) -> MassRate {
+        self.fluid_flow
+    }
+
+    pub fn leaking_air_temperature(&self) -> ThermodynamicTemperature {
+        self.leaking_air_temperature
+    }
+}
+impl SimulationElement for BleedAirDuctLeak {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
+/// A leak detection loop made of sensing elements along a bleed air duct. The sensing elements
+/// contain a eutectic salt which becomes conductive once the temperature of the surrounding zone
+/// exceeds the alarm temperature. The zone is heated by the air escaping from a duct leak.
+pub struct LeakDetectionLoop {
+    leak_detected_id: VariableIdentifier,
+
+    failure: Failure,
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+
+    alarm_temperature: ThermodynamicTemperature,
+    zone_temperature: ThermodynamicTemperature,
+}
+impl LeakDetectionLoop {
+    /// The leak flow at which the zone reaches the temperature of the leaking air.
+    const FULL_ZONE_HEATING_LEAK_FLOW_KG_PER_S: f64 = 0.1;
+    const ZONE_TEMPERATURE_TIME_CONSTANT_S: f64 = 5.;
+
+    pub fn new(
+        context: &mut InitContext,
+        zone: LeakDetectionZone,
+        alarm_temperature: ThermodynamicTemperature,
+        powered_by: ElectricalBusType,
+    ) -> Self {
+        Self {
+            leak_detected_id: context.get_identifier(format!("PNEU_{}_LEAK_DETECTED", zone)),
+            failure: Failure::new(FailureType::LeakDetectionLoop(zone)),
+            powered_by,
+            is_powered: false,
+            alarm_temperature,
+            zone_temperature: ThermodynamicTemperature::new::<degree_celsius>(15.),
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext, leak: &BleedAirDuctLeak) {
+        let heating_ratio = (leak.fluid_flow().get::<kilogram_per_second>()
+            / Self::FULL_ZONE_HEATING_LEAK_FLOW_KG_PER_S)
+            .clamp(0., 1.);
+
+        let ambient_temperature = context.ambient_temperature().get::<kelvin>();
+        let target_temperature = ambient_temperature
+            + (leak.leaking_air_temperature().get::<kelvin>() - ambient_temperature).max(0.)
+                * heating_ratio;
+
+        let zone_temperature = self.zone_temperature.get::<kelvin>();
+        self.zone_temperature = ThermodynamicTemperature::new::<kelvin>(
+            zone_temperature
+                + (target_temperature - zone_temperature)
+                    * (1.
+                        - (-context.delta_as_secs_f64() / Self::ZONE_TEMPERATURE_TIME_CONSTANT_S)
+                            .exp()),
+        );
+    }
+
+    pub fn leak_detected(&self) -> bool {
+        self.is_powered
+            && !self.failure.is_active()
+            && self.zone_temperature > self.alarm_temperature
+    }
+}
+impl SimulationElement for LeakDetectionLoop {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.leak_detected_id, self.leak_detected());
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+}
+
+/// The mechanical jam failures of an engine bleed valve. A valve jammed closed takes precedence
+/// over a valve jammed open.
+pub struct EngineBleedValveJam {
+    jammed_open: Failure,
+    jammed_closed: Failure,
+}
+impl EngineBleedValveJam {
+    pub fn new(engine_number: usize, valve: EngineBleedValveId) -> Self {
+        Self {
+            jammed_open: Failure::new(FailureType::EngineBleedValveJammedOpen(
+                engine_number,
+                valve,
+            )),
+            jammed_closed: Failure::new(FailureType::EngineBleedValveJammedClosed(
+                engine_number,
+                valve,
+            )),
+        }
+    }
+
+    /// The open amount the valve is stuck at, if any.
+    pub fn open_amount(&self) -> Option<Ratio> {
+        if self.jammed_closed.is_active() {
+            Some(Ratio::new::<ratio>(0.))
+        } else if self.jammed_open.is_active() {
+            Some(Ratio::new::<ratio>(1.))
+        } else {
+            None
+        }
+    }
+}
+impl SimulationElement for EngineBleedValveJam {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.jammed_open.accept(visitor);
+        self.jammed_closed.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
+pub struct BleedOverheatMonitor {
+    temperature_over_257_for_55s: DelayedTrueLogicGate,
+    temperature_over_270_for_15s: DelayedTrueLogicGate,
+    temperature_over_290_for_5s: DelayedTrueLogicGate,
+}
+impl BleedOverheatMonitor {
+    pub fn new() -> Self {
+        Self {
+            temperature_over_257_for_55s: DelayedTrueLogicGate::new(Duration::from_secs(55)),
+            temperature_over_270_for_15s: DelayedTrueLogicGate::new(Duration::from_secs(15)),
+            temperature_over_290_for_5s: DelayedTrueLogicGate::new(Duration::from_secs(5)),
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext, temperature: ThermodynamicTemperature) {
+        self.temperature_over_257_for_55s.update(
+            context,
+            temperature > ThermodynamicTemperature::new::<degree_celsius>(257.),
+        );
+        self.temperature_over_270_for_15s.update(
+            context,
+            temperature > ThermodynamicTemperature::new::<degree_celsius>(270.),
+        );
+        self.temperature_over_290_for_5s.update(
+            context,
+            temperature > ThermodynamicTemperature::new::<degree_celsius>(290.),
+        );
+    }
+
+    pub fn has_overheat(&self) -> bool {
+        self.temperature_over_257_for_55s.output()
+            || self.temperature_over_270_for_15s.output()
+            || self.temperature_over_290_for_5s.output()
+    }
+}
+impl Default for BleedOverheatMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub struct BleedOverpressureMonitor {
+    pressure_over_60_psig_for_15s: DelayedTrueLogicGate,
+}
+impl BleedOverpressureMonitor {
+    pub fn new() -> Self {
+        Self {
+            pressure_over_60_psig_for_15s: DelayedTrueLogicGate::new(Duration::from_secs(15)),
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext, pressure: Pressure) {
+        self.pressure_over_60_psig_for_15s
+            .update(context, pressure > Pressure::new::<psi>(60.));
+    }
+
+    pub fn has_overpressure(&self) -> bool {
+        self.pressure_over_60_psig_for_15s.output()
+    }
+}
+impl Default for BleedOverpressureMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 struct Solenoid {
     is_energized: bool,
     is_powered: bool,
@@ -1241,6 +1519,37 @@ mod tests {
         assert!(supply.temperature() > ThermodynamicTemperature::new::<degree_celsius>(15.));
     }
 
+    #[test]
+    fn blocked_precooler_passes_less_air() {
+        let context = context(Duration::from_secs(1), Length::new::<foot>(0.));
+
+        let outlet_pressure = |is_blocked: bool| {
+            let mut from = PneumaticPipe::new(
+                Volume::new::<cubic_meter>(1.),
+                Pressure::new::<psi>(29.4),
+                ThermodynamicTemperature::new::<degree_celsius>(200.),
+            );
+            let mut supply = PneumaticPipe::new(
+                Volume::new::<cubic_meter>(1.),
+                Pressure::new::<psi>(14.7),
+                ThermodynamicTemperature::new::<degree_celsius>(15.),
+            );
+            let mut to = PneumaticPipe::new(
+                Volume::new::<cubic_meter>(1.),
+                Pressure::new::<psi>(14.7),
+                ThermodynamicTemperature::new::<degree_celsius>(200.),
+            );
+
+            let mut precooler = Precooler::new(1.);
+            precooler.set_blocked(is_blocked);
+            precooler.update(&context, &mut from, &mut supply, &mut to);
+
+            to.pressure()
+        };
+
+        assert!(outlet_pressure(true) + Pressure::new::<psi>(1.) < outlet_pressure(false));
+    }
+
     #[test]
     fn pressure_increases_for_temperature_increase() {
         let mut pipe = PneumaticPipe::new(
@@ -1454,4 +1763,169 @@ mod tests {
             1e-2
         );
     }
+
+    mod leak_detection {
+        use super::*;
+        use crate::{
+            electrical::{test::TestElectricitySource, ElectricalBus},
+            shared::PotentialOrigin,
+            simulation::{
+                test::{ReadByName, SimulationTestBed, TestBed},
+                Aircraft,
+            },
+        };
+
+        struct HotBleedAirSupply;
+        impl ControllerSignal<TargetPressureTemperatureSignal> for HotBleedAirSupply {
+            fn signal(&self) -> Option<TargetPressureTemperatureSignal> {
+                Some(TargetPressureTemperatureSignal::new(
+                    Pressure::new::<psi>(55.),
+                    ThermodynamicTemperature::new::<degree_celsius>(200.),
+                ))
+            }
+        }
+
+        struct TestAircraft {
+            electricity_source: TestElectricitySource,
+            dc_1_bus: ElectricalBus,
+            is_dc_1_powered: bool,
+
+            duct: CompressionChamber,
+            leak: BleedAirDuctLeak,
+            leak_detection_loop: LeakDetectionLoop,
+        }
+        impl TestAircraft {
+            fn new(context: &mut InitContext) -> Self {
+                Self {
+                    electricity_source: TestElectricitySource::powered(
+                        context,
+                        PotentialOrigin::Battery(1),
+                    ),
+                    dc_1_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(1)),
+                    is_dc_1_powered: true,
+                    duct: CompressionChamber::new(Volume::new::<cubic_meter>(1.)),
+                    leak: BleedAirDuctLeak::new(LeakDetectionZone::Wing(1), 0.1),
+                    leak_detection_loop: LeakDetectionLoop::new(
+                        context,
+                        LeakDetectionZone::Wing(1),
+                        ThermodynamicTemperature::new::<degree_celsius>(124.),
+                        ElectricalBusType::DirectCurrent(1),
+                    ),
+                }
+            }
+        }
+        impl Aircraft for TestAircraft {
+            fn update_before_power_distribution(
+                &mut self,
+                _: &UpdateContext,
+                electricity: &mut crate::electrical::Electricity,
+            ) {
+                electricity.supplied_by(&self.electricity_source);
+
+                if self.is_dc_1_powered {
+                    electricity.flow(&self.electricity_source, &self.dc_1_bus);
+                }
+            }
+
+            fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+                self.duct.update(&HotBleedAirSupply);
+                self.leak.update_move_fluid(context, &mut self.duct);
+                self.leak_detection_loop.update(context, &self.leak);
+            }
+        }
+        impl SimulationElement for TestAircraft {
+            fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+                self.leak.accept(visitor);
+                self.leak_detection_loop.accept(visitor);
+
+                visitor.visit(self);
+            }
+        }
+
+        fn test_bed() -> SimulationTestBed<TestAircraft> {
+            SimulationTestBed::new(TestAircraft::new)
+        }
+
+        fn run_for(test_bed: &mut SimulationTestBed<TestAircraft>, duration: Duration) {
+            for _ in 0..duration.as_secs() {
+                test_bed.run_with_delta(Duration::from_secs(1));
+            }
+        }
+
+        #[test]
+        fn duct_does_not_leak_without_failure() {
+            let mut test_bed = test_bed();
+            run_for(&mut test_bed, Duration::from_secs(5));
+
+            assert_about_eq!(
+                test_bed
+                    .query(|a| a.leak.fluid_flow())
+                    .get::<kilogram_per_second>(),
+                0.
+            );
+            assert!(!test_bed.query(|a| a.leak_detection_loop.leak_detected()));
+        }
+
+        #[test]
+        fn leaking_duct_vents_air_to_ambient() {
+            let mut test_bed = test_bed();
+            test_bed.fail(FailureType::BleedAirLeak(LeakDetectionZone::Wing(1)));
+            test_bed.run_with_delta(Duration::from_secs(1));
+
+            assert!(
+                test_bed
+                    .query(|a| a.leak.fluid_flow())
+                    .get::<kilogram_per_second>()
+                    > 0.
+            );
+            assert!(test_bed.query(|a| a.duct.pressure()) < Pressure::new::<psi>(55.));
+        }
+
+        #[test]
+        fn leak_is_detected_once_the_zone_heats_up() {
+            let mut test_bed = test_bed();
+            test_bed.fail(FailureType::BleedAirLeak(LeakDetectionZone::Wing(1)));
+            test_bed.run_with_delta(Duration::from_secs(1));
+
+            assert!(!test_bed.query(|a| a.leak_detection_loop.leak_detected()));
+
+            run_for(&mut test_bed, Duration::from_secs(20));
+
+            assert!(test_bed.query(|a| a.leak_detection_loop.leak_detected()));
+            let is_detected: bool = test_bed.read_by_name("PNEU_WING_1_LEAK_DETECTED");
+            assert!(is_detected);
+        }
+
+        #[test]
+        fn leak_is_no_longer_detected_once_the_zone_cools_down() {
+            let mut test_bed = test_bed();
+            test_bed.fail(FailureType::BleedAirLeak(LeakDetectionZone::Wing(1)));
+            run_for(&mut test_bed, Duration::from_secs(20));
+
+            test_bed.unfail(FailureType::BleedAirLeak(LeakDetectionZone::Wing(1)));
+            run_for(&mut test_bed, Duration::from_secs(30));
+
+            assert!(!test_bed.query(|a| a.leak_detection_loop.leak_detected()));
+        }
+
+        #[test]
+        fn failed_loop_does_not_detect_leak() {
+            let mut test_bed = test_bed();
+            test_bed.fail(FailureType::BleedAirLeak(LeakDetectionZone::Wing(1)));
+            test_bed.fail(FailureType::LeakDetectionLoop(LeakDetectionZone::Wing(1)));
+            run_for(&mut test_bed, Duration::from_secs(20));
+
+            assert!(!test_bed.query(|a| a.leak_detection_loop.leak_detected()));
+        }
+
+        #[test]
+        fn unpowered_loop_does_not_detect_leak() {
+            let mut test_bed = test_bed();
+            test_bed.command(|a| a.is_dc_1_powered = false);
+            test_bed.fail(FailureType::BleedAirLeak(LeakDetectionZone::Wing(1)));
+            run_for(&mut test_bed, Duration::from_secs(20));
+
+            assert!(!test_bed.query(|a| a.leak_detection_loop.leak_detected()));
+        }
+    }
 }
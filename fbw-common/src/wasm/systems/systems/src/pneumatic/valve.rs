@@ -116,6 +116,7 @@ pub struct SolenoidValve<const N: usize> {
     characteristics: PneumaticValveCharacteristics<N>,
     solenoid: Solenoid,
     open_amount: Ratio,
+    jammed_open_amount: Option<Ratio>,
 }
 impl<const N: usize> SolenoidValve<N> {
     pub fn new(
@@ -127,6 +128,7 @@ impl<const N: usize> SolenoidValve<N> {
             characteristics,
             solenoid: Solenoid::new(powered_by),
             open_amount: Ratio::default(),
+            jammed_open_amount: None,
         }
     }
 
@@ -152,11 +154,15 @@ impl<const N: usize> SolenoidValve<N> {
         let current_open_amount = self.open_amount.get::<ratio>();
         let open_amount_change = context.delta_as_secs_f64() * self.characteristics.valve_speed;
 
-        self.open_amount = Ratio::new::<ratio>(if target_open_amount > current_open_amount {
-            target_open_amount.min(current_open_amount + open_amount_change)
+        self.open_amount = if let Some(jammed_open_amount) = self.jammed_open_amount {
+            jammed_open_amount
         } else {
-            target_open_amount.max(current_open_amount - open_amount_change)
-        });
+            Ratio::new::<ratio>(if target_open_amount > current_open_amount {
+                target_open_amount.min(current_open_amount + open_amount_change)
+            } else {
+                target_open_amount.max(current_open_amount - open_amount_change)
+            })
+        };
 
         self.connector
             .with_transfer_speed_factor(self.open_amount)
@@ -170,6 +176,12 @@ impl<const N: usize> SolenoidValve<N> {
     pub fn open_amount(&self) -> Ratio {
         self.open_amount
     }
+
+    /// While jammed, the valve remains at the given open amount regardless of its solenoid and
+    /// of the pressure acting on it.
+    pub fn set_jammed(&mut self, jammed_open_amount: Option<Ratio>) {
+        self.jammed_open_amount = jammed_open_amount;
+    }
 }
 impl<const N: usize> PneumaticValve for SolenoidValve<N> {
     fn is_open(&self) -> bool {
@@ -189,6 +201,8 @@ pub struct ElectroPneumaticValve {
     connector: PneumaticContainerConnector,
     is_powered: bool,
     powered_by: ElectricalBusType,
+    jammed_open_amount: Option<Ratio>,
+    has_lost_regulation: bool,
 }
 impl ElectroPneumaticValve {
     const SPRING_CHARACTERISTIC: f64 = 1.;
@@ -199,6 +213,8 @@ impl ElectroPneumaticValve {
             connector: PneumaticContainerConnector::new(),
             is_powered: false,
             powered_by,
+            jammed_open_amount: None,
+            has_lost_regulation: false,
         }
     }
 
@@ -208,7 +224,9 @@ impl ElectroPneumaticValve {
         container_one: &mut impl PneumaticContainer,
         container_two: &mut impl PneumaticContainer,
     ) {
-        if !self.is_powered {
+        if let Some(jammed_open_amount) = self.jammed_open_amount {
+            self.open_amount = jammed_open_amount;
+        } else if !self.is_powered {
             self.set_open_amount_from_pressure_difference(
                 container_one.pressure() - container_two.pressure(),
             )
@@ -239,6 +257,17 @@ impl ElectroPneumaticValve {
     pub fn open_amount(&self) -> Ratio {
         self.open_amount
     }
+
+    /// While jammed, the valve remains at the given open amount regardless of the commanded
+    /// position and of the pressure acting on it.
+    pub fn set_jammed(&mut self, jammed_open_amount: Option<Ratio>) {
+        self.jammed_open_amount = jammed_open_amount;
+    }
+
+    /// Without its regulation, the valve opens fully whenever it is commanded open.
+    pub fn set_regulation_lost(&mut self, has_lost_regulation: bool) {
+        self.has_lost_regulation = has_lost_regulation;
+    }
 }
 impl PneumaticValve for ElectroPneumaticValve {
     fn is_open(&self) -> bool {
@@ -250,9 +279,14 @@ impl ControllablePneumaticValve for ElectroPneumaticValve {
         &mut self,
         controller: &U,
     ) {
-        if self.is_powered {
+        if self.is_powered && self.jammed_open_amount.is_none() {
             if let Some(signal) = controller.signal() {
-                self.open_amount = signal.target_open_amount();
+                self.open_amount =
+                    if self.has_lost_regulation && signal.target_open_amount() > Ratio::default() {
+                        Ratio::new::<ratio>(1.)
+                    } else {
+                        signal.target_open_amount()
+                    };
             }
         }
     }
@@ -1,6 +1,4 @@
-use std::time::Duration;
-
-use crate::UpdateContext;
+use std::{marker::PhantomData, time::Duration};
 
 use uom::si::{
     f64::*,
@@ -11,8 +9,8 @@ use uom::si::{
     volume::cubic_meter,
 };
 
-use systems::{
-    accept_iterable,
+use crate::{
+    icing_state::ActiveDeicingController,
     pneumatic::{
         valve::DefaultValve, valve::PneumaticExhaust, ControllablePneumaticValve,
         PneumaticContainer, PneumaticPipe, PneumaticValveSignal, WingAntiIcePushButtonMode,
@@ -23,11 +21,23 @@ use systems::{
         ElectricalBuses, LgciuWeightOnWheels, PneumaticValve,
     },
     simulation::{
-        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter,
+        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
         VariableIdentifier, Write,
     },
 };
 
+// The aircraft specific parts of the wing anti ice system.
+// The ducts are sized by the heated slat sections, and the
+// ground test is timed by whichever computer controls the valves.
+pub trait WingAntiIceConstants {
+    const PIPE_VOLUME_CUBIC_METER: f64; // Piccolo ducts of one wing
+    const EXHAUST_SPEED: f64; // Regulate wing_anti_ice_tweak_exhaust
+    const VALVE_TRANSFER_SPEED: f64; // Regulate wing_anti_ice_tweak_time_to_open
+    const VALVE_SETPOINT_STD_DEV_PSI: f64; // Scatter of the regulated pressure between valves
+    const CONTROLLER_POWERED_BY: ElectricalBusType;
+    const GROUND_TEST_DURATION: Duration;
+}
+
 // The valve itself is a DefaultValve. The only thing
 // we need to re-implement is the controller, that sets
 // whether or not the valve should be open.
@@ -66,37 +76,38 @@ impl PneumaticValveSignal for WingAntiIceValveSignal {
 }
 
 // This is the actual controller. It holds the push button status.
-// - After 30 seconds, the ON light would turn off.
+// - After the ground test, the ON light would turn off.
 // - After takeoff, it should be turned on again.
-pub struct WingAntiIceValveController {
+pub struct WingAntiIceValveController<C: WingAntiIceConstants> {
     valve_pid: PidController, // PID controller for the valve - to regulate pressure
     valve_setpoint: f64,
     controller_signals_on: bool, // Status of the ON light. If button is pushed and
     // the test is finished, the ON light should turn off.
     supplier_pressurized: bool,
+    constants: PhantomData<C>,
 }
-impl WingAntiIceValveController {
+impl<C: WingAntiIceConstants> WingAntiIceValveController<C> {
     const WAI_VALVE_MEAN_SETPOINT: f64 = 22.5;
-    const WAI_VALVE_STD_DEV_SETPOINT: f64 = 2.5;
-    pub fn new() -> Self {
+    fn new() -> Self {
         let random_setpoint = Self::choose_valve_setpoint();
         Self {
             valve_setpoint: random_setpoint,
-            // Setpoint is 22.5 +/- 2.5 (psi)
+            // Setpoint is 22.5 (psi), scattered by the aircraft specific deviation
             valve_pid: PidController::new(0.05, 0.01, 0., 0., 1., random_setpoint, 1.),
             controller_signals_on: false,
             supplier_pressurized: false,
+            constants: PhantomData,
         }
     }
 
     fn choose_valve_setpoint() -> f64 {
-        if cfg!(test) {
-            Self::WAI_VALVE_MEAN_SETPOINT
-        } else {
+        if C::VALVE_SETPOINT_STD_DEV_PSI > 0. {
             random_from_normal_distribution(
                 Self::WAI_VALVE_MEAN_SETPOINT,
-                Self::WAI_VALVE_STD_DEV_SETPOINT,
+                C::VALVE_SETPOINT_STD_DEV_PSI,
             )
+        } else {
+            Self::WAI_VALVE_MEAN_SETPOINT
         }
     }
 
@@ -125,7 +136,9 @@ impl WingAntiIceValveController {
 // This is the part that interacts with the valve, via DefaultValve.update_open_amount.
 // That method has if let Some(signal) = controller.signal(). The right hand side
 // is what is returned from this implementation.
-impl ControllerSignal<WingAntiIceValveSignal> for WingAntiIceValveController {
+impl<C: WingAntiIceConstants> ControllerSignal<WingAntiIceValveSignal>
+    for WingAntiIceValveController<C>
+{
     fn signal(&self) -> Option<WingAntiIceValveSignal> {
         match self.controller_signals_on {
             false => Some(WingAntiIceValveSignal::new_closed()),
@@ -208,27 +221,28 @@ impl WingAntiIceConsumer {
     }
 }
 
-pub struct WingAntiIceRelay {
-    system_test_timer: Duration, // Timer to count up to 30 seconds
-    system_test_done: bool,      // Timer reached 30 seconds while on the ground
+pub struct WingAntiIceRelay<C: WingAntiIceConstants> {
+    system_test_timer: Duration, // Timer to count up to the ground test duration
+    system_test_done: bool,      // Timer reached the ground test duration while on the ground
     signal_on: bool,             // Status of the ON light. If button is pushed and
     // the test is finished, the ON light should turn off.
     powered_by: ElectricalBusType,
     is_powered: bool,
     ground_timer_id: VariableIdentifier,
     system_on_id: VariableIdentifier,
+    constants: PhantomData<C>,
 }
-impl WingAntiIceRelay {
-    const WAI_TEST_TIME: Duration = Duration::from_secs(30);
+impl<C: WingAntiIceConstants> WingAntiIceRelay<C> {
     pub fn new(context: &mut InitContext) -> Self {
         Self {
             system_test_timer: Duration::from_secs(0),
             system_test_done: false,
             signal_on: false,
-            powered_by: ElectricalBusType::DirectCurrentEssentialShed,
+            powered_by: C::CONTROLLER_POWERED_BY,
             is_powered: false,
             ground_timer_id: context.get_identifier("PNEU_WING_ANTI_ICE_GROUND_TIMER".to_owned()),
             system_on_id: context.get_identifier("PNEU_WING_ANTI_ICE_SYSTEM_ON".to_owned()),
+            constants: PhantomData,
         }
     }
 
@@ -243,7 +257,7 @@ impl WingAntiIceRelay {
         lgciu: [&impl LgciuWeightOnWheels; 2],
     ) {
         // Even if the button is pushed, we need to check if either
-        // the plane is airborne or it is within the 30 second timeframe.
+        // the plane is airborne or it is within the ground test timeframe.
         // Also, we need to check if the supplier is pressurized
         // since the valve is pneumatically operated.
         let is_on_ground =
@@ -254,8 +268,8 @@ impl WingAntiIceRelay {
         } else if wing_anti_ice_button_pos == WingAntiIcePushButtonMode::On {
             if is_on_ground && !self.system_test_done {
                 self.system_test_timer += context.delta();
-                self.system_test_timer = self.system_test_timer.min(Self::WAI_TEST_TIME);
-                if self.system_test_timer == Self::WAI_TEST_TIME {
+                self.system_test_timer = self.system_test_timer.min(C::GROUND_TEST_DURATION);
+                if self.system_test_timer == C::GROUND_TEST_DURATION {
                     self.system_test_done = true;
                     self.signal_on = false;
                 } else {
@@ -285,7 +299,7 @@ impl WingAntiIceRelay {
         self.system_test_done = false;
     }
 }
-impl SimulationElement for WingAntiIceRelay {
+impl<C: WingAntiIceConstants> SimulationElement for WingAntiIceRelay<C> {
     fn receive_power(&mut self, buses: &impl ElectricalBuses) {
         if self.is_powered != buses.is_powered(self.powered_by) {
             self.relay_reset();
@@ -306,11 +320,11 @@ impl SimulationElement for WingAntiIceRelay {
 // WING A.ICE L(R) VALVE OPEN
 // WING A.ICE OPEN ON GND
 // WING A.ICE L(R) HI PR
-pub struct WingAntiIceSystem {
+pub struct WingAntiIceSystem<C: WingAntiIceConstants> {
     wai_exhaust: PneumaticExhaust,
     wai_valve: DefaultValve,
     wai_consumer: WingAntiIceConsumer,
-    wai_valve_controller: WingAntiIceValveController,
+    wai_valve_controller: WingAntiIceValveController<C>,
     wai_has_fault: bool,
     wai_high_pressure: bool,
     wai_low_pressure: bool,
@@ -322,41 +336,23 @@ pub struct WingAntiIceSystem {
 
     wai_high_pressure_id: VariableIdentifier,
     wai_low_pressure_id: VariableIdentifier,
+
+    constants: PhantomData<C>,
 }
-impl WingAntiIceSystem {
+impl<C: WingAntiIceConstants> WingAntiIceSystem<C> {
     const WAI_MIN_PRESSURE: f64 = 1.; //BAR
     const WAI_MAX_PRESSURE: f64 = 2.1; //BAR
-    const WAI_EXHAUST_SPEED: f64 = 0.129; // Regulate wing_anti_ice_tweak_exhaust
-    const WAI_VALVE_TRANSFER_SPEED: f64 = 1.2; // Regulate wing_anti_ice_tweak_time_to_open
-
-    // Each WAI duct is made of
-    // Flow Trimming Restrictor 47mm diameter
-    // Lagged Supply Duct 50mm diameter
-    // Telescopic Duct 73.66mm to 57.15mm diameter
-    // Piccolo Ducts 63.5mm to 44mm diameter
-    // Flexible Ducts
-    // Slat 2 --> 3.335m
-    // Slat 3 --> 2.8m
-    // Slat 4 --> 2.736m
-    // Slat 5 --> 2.593m
-    // Total length 11.464m
-    // 3.335m * 0.050m diameter
-    // 2.8m   * 0.0635m diameter
-    // 2.736m * 0.055m diameter
-    // 2.593m * 0.044m diameter
-    // 0.458m * 0.05715mm diameter
-    // Total volume of ducts is around 2m^3. Assuming telescopic duct retracted
-    const WAI_PIPE_VOLUME: f64 = 2.;
+    const WAI_FULL_DEICING_TEMPERATURE: f64 = 50.; // DEG C
 
     pub fn new(context: &mut InitContext, number: usize) -> Self {
-        let wai_pipe_volume: Volume = Volume::new::<cubic_meter>(Self::WAI_PIPE_VOLUME);
+        let wai_pipe_volume: Volume = Volume::new::<cubic_meter>(C::PIPE_VOLUME_CUBIC_METER);
 
         Self {
             // At 22000ft, flow rate is given at 0.327kg/s
             // Leaking failure not simulated
             wai_exhaust: PneumaticExhaust::new(
-                Self::WAI_EXHAUST_SPEED,
-                Self::WAI_EXHAUST_SPEED,
+                C::EXHAUST_SPEED,
+                C::EXHAUST_SPEED,
                 Pressure::new::<psi>(0.),
             ),
             // If the pressure increases to 2.1 bar (30.4579 psi)
@@ -384,6 +380,8 @@ impl WingAntiIceSystem {
                 "PNEU_WING_ANTI_ICE_{}_CONSUMER_TEMPERATURE",
                 number
             )),
+
+            constants: PhantomData,
         }
     }
 
@@ -397,7 +395,11 @@ impl WingAntiIceSystem {
             precooler_pressure > context.ambient_pressure() + Pressure::new::<psi>(10.);
     }
 
-    fn update_valve_controller(&mut self, context: &UpdateContext, wai_relay: &WingAntiIceRelay) {
+    fn update_valve_controller(
+        &mut self,
+        context: &UpdateContext,
+        wai_relay: &WingAntiIceRelay<C>,
+    ) {
         self.wai_valve_controller.valve_pid.next_control_output(
             self.wai_consumer_pressure().get::<psi>(),
             Some(context.delta()),
@@ -441,17 +443,14 @@ impl WingAntiIceSystem {
         self.wai_low_pressure
     }
 
-    #[cfg(test)]
     pub fn wai_valve_controller_on(&self) -> bool {
         self.wai_valve_controller.controller_signals_on()
     }
 
-    #[cfg(test)]
     pub fn is_precoooler_pressurised(&self) -> bool {
         self.wai_bleed_pressurised
     }
 
-    #[cfg(test)]
     pub fn wai_mass_flow(&self) -> MassRate {
         self.wai_exhaust.fluid_flow()
     }
@@ -460,7 +459,7 @@ impl WingAntiIceSystem {
         &mut self,
         context: &UpdateContext,
         engine_system: &mut impl PneumaticContainer,
-        wai_relay: &WingAntiIceRelay,
+        wai_relay: &WingAntiIceRelay<C>,
     ) {
         self.update_pressure_above_minimum(context, engine_system.pressure());
 
@@ -481,7 +480,7 @@ impl WingAntiIceSystem {
             context,
             engine_system,
             &mut self.wai_consumer,
-            Self::WAI_VALVE_TRANSFER_SPEED,
+            C::VALVE_TRANSFER_SPEED,
         );
 
         // Check if HIGH or LOW pressure
@@ -505,7 +504,18 @@ impl WingAntiIceSystem {
         }
     }
 }
-impl SimulationElement for WingAntiIceSystem {
+// The slats are deiced at the highest rate once the air in the piccolo ducts
+// is well above freezing, and keep deicing while they cool down after the valve closes.
+impl<C: WingAntiIceConstants> ActiveDeicingController for WingAntiIceSystem<C> {
+    fn active_deicing_normalized_rate(&self) -> Ratio {
+        Ratio::new::<ratio>(
+            (self.wai_consumer_temperature().get::<degree_celsius>()
+                / Self::WAI_FULL_DEICING_TEMPERATURE)
+                .clamp(0., 1.),
+        )
+    }
+}
+impl<C: WingAntiIceConstants> SimulationElement for WingAntiIceSystem<C> {
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.wai_pressure_id, self.wai_consumer_pressure());
         writer.write(&self.wai_temperature_id, self.wai_consumer_temperature());
@@ -519,17 +529,17 @@ impl SimulationElement for WingAntiIceSystem {
 // Each WingAntiIceSystem contains a consumer, a valve,
 // an exhaust and a valve controller.
 // There is one (shared) ground sense relay.
-pub struct WingAntiIceComplex {
+pub struct WingAntiIceComplex<C: WingAntiIceConstants> {
     // Left and Right wing
-    wai_systems: [WingAntiIceSystem; 2],
-    wai_relay: WingAntiIceRelay,
+    wai_systems: [WingAntiIceSystem<C>; 2],
+    wai_relay: WingAntiIceRelay<C>,
     wai_system_has_fault: bool,
     wai_selected: bool,
 
     wai_selected_id: VariableIdentifier,
     wai_fault_id: VariableIdentifier,
 }
-impl WingAntiIceComplex {
+impl<C: WingAntiIceConstants> WingAntiIceComplex<C> {
     pub fn new(context: &mut InitContext) -> Self {
         Self {
             wai_systems: [
@@ -546,37 +556,34 @@ impl WingAntiIceComplex {
         }
     }
 
-    #[cfg(test)]
+    pub fn wai_systems(&self) -> &[WingAntiIceSystem<C>; 2] {
+        &self.wai_systems
+    }
+
     pub fn is_wai_valve_closed(&self, number: usize) -> bool {
         self.wai_systems[number].is_wai_valve_closed()
     }
 
-    #[cfg(test)]
     pub fn wai_consumer_pressure(&self, number: usize) -> Pressure {
         self.wai_systems[number].wai_consumer_pressure()
     }
 
-    #[cfg(test)]
     pub fn wai_timer(&self) -> Duration {
         self.wai_relay.get_timer()
     }
 
-    #[cfg(test)]
     pub fn wai_consumer_temperature(&self, number: usize) -> ThermodynamicTemperature {
         self.wai_systems[number].wai_consumer_temperature()
     }
 
-    #[cfg(test)]
     pub fn wai_valve_controller_on(&self, number: usize) -> bool {
         self.wai_systems[number].wai_valve_controller_on()
     }
 
-    #[cfg(test)]
     pub fn is_precoooler_pressurised(&self, number: usize) -> bool {
         self.wai_systems[number].is_precoooler_pressurised()
     }
 
-    #[cfg(test)]
     pub fn wai_mass_flow(&self, number: usize) -> MassRate {
         self.wai_systems[number].wai_mass_flow()
     }
@@ -584,7 +591,7 @@ impl WingAntiIceComplex {
     pub fn update(
         &mut self,
         context: &UpdateContext,
-        engine_systems: &mut [impl PneumaticContainer; 2],
+        engine_systems: [&mut impl PneumaticContainer; 2],
         wai_mode: WingAntiIcePushButtonMode,
         lgciu: [&impl LgciuWeightOnWheels; 2],
     ) {
@@ -601,12 +608,12 @@ impl WingAntiIceComplex {
         self.wai_selected = wai_mode == WingAntiIcePushButtonMode::On;
     }
 }
-impl WingAntiIceSelected for WingAntiIceComplex {
+impl<C: WingAntiIceConstants> WingAntiIceSelected for WingAntiIceComplex<C> {
     fn is_wai_selected(&self) -> bool {
         self.wai_selected
     }
 }
-impl SimulationElement for WingAntiIceComplex {
+impl<C: WingAntiIceConstants> SimulationElement for WingAntiIceComplex<C> {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         accept_iterable!(self.wai_systems, visitor);
         self.wai_relay.accept(visitor);
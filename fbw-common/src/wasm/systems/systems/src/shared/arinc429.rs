@@ -1,3 +1,7 @@
+use std::time::Duration;
+
+use crate::simulation::{VariableIdentifier, Writer};
+
 #[derive(Clone, Copy)]
 pub struct Arinc429Word<T: Copy> {
     value: T,
@@ -130,9 +134,406 @@ pub(crate) fn to_arinc429(value: f64, ssm: SignStatus) -> f64 {
     int_value as f64
 }
 
+/// A 32 bit ARINC 429 word as transmitted on a bus. The bits are numbered from 1 to 32:
+/// - 1-8: the label, transmitted most significant bit first.
+/// - 9-10: the source/destination identifier (SDI).
+/// - 11-29: the data.
+/// - 30-31: the sign/status matrix (SSM).
+/// - 32: the parity bit, which makes the number of set bits odd.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Arinc429RawWord(u32);
+impl Arinc429RawWord {
+    const SDI_SHIFT: u32 = 8;
+    const DATA_SHIFT: u32 = 10;
+    const DATA_MASK: u32 = 0x7_ffff;
+    const SSM_SHIFT: u32 = 29;
+    const PARITY_BIT: u32 = 1 << 31;
+
+    /// Creates a word from the octal label, the SDI, the 19 data bits and the SSM bits.
+    pub fn new(label: u8, sdi: u8, data: u32, ssm: u8) -> Self {
+        debug_assert!(sdi <= 0b11);
+        debug_assert!(ssm <= 0b11);
+
+        let bits = label.reverse_bits() as u32
+            | ((sdi as u32 & 0b11) << Self::SDI_SHIFT)
+            | ((data & Self::DATA_MASK) << Self::DATA_SHIFT)
+            | ((ssm as u32 & 0b11) << Self::SSM_SHIFT);
+
+        if bits.count_ones() % 2 == 0 {
+            Self(bits | Self::PARITY_BIT)
+        } else {
+            Self(bits)
+        }
+    }
+
+    pub fn from_bits(bits: u32) -> Self {
+        Self(bits)
+    }
+
+    pub fn bits(&self) -> u32 {
+        self.0
+    }
+
+    /// The label as it is usually written, in octal.
+    pub fn label(&self) -> u8 {
+        (self.0 as u8).reverse_bits()
+    }
+
+    pub fn sdi(&self) -> u8 {
+        ((self.0 >> Self::SDI_SHIFT) & 0b11) as u8
+    }
+
+    /// The data bits 11 to 29, where bit 11 is the least significant bit.
+    pub fn data(&self) -> u32 {
+        (self.0 >> Self::DATA_SHIFT) & Self::DATA_MASK
+    }
+
+    pub fn ssm(&self) -> u8 {
+        ((self.0 >> Self::SSM_SHIFT) & 0b11) as u8
+    }
+
+    pub fn has_valid_parity(&self) -> bool {
+        self.0.count_ones() % 2 == 1
+    }
+}
+
+/// The encoding of the data bits of a label.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Arinc429Encoding {
+    /// Two's complement binary with the sign in bit 29 and the given number of significant bits
+    /// below it. Values outside of the range are clamped.
+    Bnr {
+        significant_bits: u8,
+        resolution: f64,
+    },
+    /// Binary coded decimal with the given number of digits. The most significant digit starts at
+    /// bit 29 and is truncated to three bits when the digits don't fit in the data bits.
+    /// The SSM holds the sign, thus a failure warning can't be encoded and stops the transmission.
+    Bcd { digits: u8, resolution: f64 },
+    /// Individual bits, as set by [`Arinc429Word::set_bit`].
+    ///
+    /// [`Arinc429Word::set_bit`]: struct.Arinc429Word.html#method.set_bit
+    Discrete,
+}
+impl Arinc429Encoding {
+    const DATA_BITS: u32 = 19;
+
+    /// Encodes the value into data and SSM bits, or `None` when the word can't be transmitted.
+    fn encode(&self, word: Arinc429Word<f64>) -> Option<(u32, u8)> {
+        match *self {
+            Arinc429Encoding::Bnr {
+                significant_bits,
+                resolution,
+            } => {
+                let significant_bits = significant_bits as u32;
+                debug_assert!(significant_bits < Self::DATA_BITS);
+
+                let maximum = (1_i64 << significant_bits) - 1;
+                let raw = ((word.value() / resolution).round() as i64).clamp(-maximum - 1, maximum);
+                let data = ((raw as u32) & ((1 << (significant_bits + 1)) - 1))
+                    << (Self::DATA_BITS - 1 - significant_bits);
+
+                Some((data, u64::from(word.ssm()) as u8))
+            }
+            Arinc429Encoding::Bcd { digits, resolution } => {
+                let ssm = match word.ssm() {
+                    SignStatus::FailureWarning => return None,
+                    SignStatus::NoComputedData => 0b01,
+                    SignStatus::FunctionalTest => 0b10,
+                    SignStatus::NormalOperation if word.value() < 0. => 0b11,
+                    SignStatus::NormalOperation => 0b00,
+                };
+
+                let (lowest_bit, most_significant_digit_maximum) = Self::bcd_layout(digits);
+                let lower_digits_maximum = 10_u64.pow(digits as u32 - 1);
+                let maximum = most_significant_digit_maximum * lower_digits_maximum
+                    + lower_digits_maximum
+                    - 1;
+                let mut remaining = ((word.value().abs() / resolution).round() as u64).min(maximum);
+                let mut data = 0;
+                for digit in 0..digits as u32 {
+                    data |= ((remaining % 10) as u32) << (lowest_bit + 4 * digit);
+                    remaining /= 10;
+                }
+
+                Some((data, ssm))
+            }
+            Arinc429Encoding::Discrete => {
+                let ssm = match word.ssm() {
+                    SignStatus::NormalOperation => 0b00,
+                    SignStatus::NoComputedData => 0b01,
+                    SignStatus::FunctionalTest => 0b10,
+                    SignStatus::FailureWarning => 0b11,
+                };
+
+                Some(((word.value() as u32) >> 10, ssm))
+            }
+        }
+    }
+
+    fn decode(&self, data: u32, ssm: u8) -> Arinc429Word<f64> {
+        match *self {
+            Arinc429Encoding::Bnr {
+                significant_bits,
+                resolution,
+            } => {
+                let significant_bits = significant_bits as u32;
+                let raw = data >> (Self::DATA_BITS - 1 - significant_bits);
+                let sign_extended =
+                    ((raw << (31 - significant_bits)) as i32) >> (31 - significant_bits);
+
+                Arinc429Word::new(sign_extended as f64 * resolution, (ssm as u32).into())
+            }
+            Arinc429Encoding::Bcd { digits, resolution } => {
+                let (lowest_bit, _) = Self::bcd_layout(digits);
+                let value = (0..digits as u32).rev().fold(0, |value, digit| {
+                    value * 10 + ((data >> (lowest_bit + 4 * digit)) & 0b1111)
+                }) as f64
+                    * resolution;
+
+                match ssm {
+                    0b00 => Arinc429Word::new(value, SignStatus::NormalOperation),
+                    0b11 => Arinc429Word::new(-value, SignStatus::NormalOperation),
+                    0b01 => Arinc429Word::new(value, SignStatus::NoComputedData),
+                    _ => Arinc429Word::new(value, SignStatus::FunctionalTest),
+                }
+            }
+            Arinc429Encoding::Discrete => {
+                let ssm = match ssm {
+                    0b00 => SignStatus::NormalOperation,
+                    0b01 => SignStatus::NoComputedData,
+                    0b10 => SignStatus::FunctionalTest,
+                    _ => SignStatus::FailureWarning,
+                };
+
+                Arinc429Word::new((data << 10) as f64, ssm)
+            }
+        }
+    }
+
+    /// Returns the lowest data bit of the BCD digits and the maximum of the most significant digit.
+    fn bcd_layout(digits: u8) -> (u32, u64) {
+        debug_assert!((1..=5).contains(&digits));
+
+        let lowest_bit = Self::DATA_BITS.saturating_sub(4 * digits as u32);
+        let most_significant_digit_bits = Self::DATA_BITS - lowest_bit - 4 * (digits as u32 - 1);
+
+        (lowest_bit, ((1 << most_significant_digit_bits) - 1).min(9))
+    }
+}
+
+/// Defines how a label is encoded and how often it is transmitted.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Arinc429LabelDefinition {
+    label: u8,
+    encoding: Arinc429Encoding,
+    period: Duration,
+}
+impl Arinc429LabelDefinition {
+    pub const fn new(label: u8, encoding: Arinc429Encoding, period: Duration) -> Self {
+        Self {
+            label,
+            encoding,
+            period,
+        }
+    }
+
+    pub const fn bnr(label: u8, significant_bits: u8, resolution: f64, period: Duration) -> Self {
+        Self::new(
+            label,
+            Arinc429Encoding::Bnr {
+                significant_bits,
+                resolution,
+            },
+            period,
+        )
+    }
+
+    pub const fn bcd(label: u8, digits: u8, resolution: f64, period: Duration) -> Self {
+        Self::new(label, Arinc429Encoding::Bcd { digits, resolution }, period)
+    }
+
+    pub const fn discrete(label: u8, period: Duration) -> Self {
+        Self::new(label, Arinc429Encoding::Discrete, period)
+    }
+
+    pub fn label(&self) -> u8 {
+        self.label
+    }
+
+    pub fn encoding(&self) -> Arinc429Encoding {
+        self.encoding
+    }
+
+    pub fn period(&self) -> Duration {
+        self.period
+    }
+
+    /// Encodes the word, or returns `None` when the encoding can't represent it.
+    pub fn encode(&self, sdi: u8, word: Arinc429Word<f64>) -> Option<Arinc429RawWord> {
+        self.encoding
+            .encode(word)
+            .map(|(data, ssm)| Arinc429RawWord::new(self.label, sdi, data, ssm))
+    }
+
+    pub fn decode(&self, word: Arinc429RawWord) -> Arinc429Word<f64> {
+        debug_assert!(word.label() == self.label);
+        self.encoding.decode(word.data(), word.ssm())
+    }
+}
+
+/// The output bus of an ARINC 429 transmitter.
+///
+/// The transmitter sets the words it wants to transmit and updates the bus, which transmits each
+/// label once per period of the label. Receivers get the last transmitted word of a label, as long
+/// as it isn't stale. A label which is no longer transmitted, such as when the transmitter lost
+/// power, becomes stale after a few periods. This differs from a word with no computed data,
+/// which is still transmitted.
+pub struct Arinc429Bus {
+    sdi: u8,
+    labels: Vec<Arinc429BusLabel>,
+}
+impl Arinc429Bus {
+    const STALE_AFTER_PERIODS: u32 = 3;
+
+    pub fn new(sdi: u8, definitions: &[Arinc429LabelDefinition]) -> Self {
+        Self {
+            sdi,
+            labels: definitions
+                .iter()
+                .map(|&definition| Arinc429BusLabel::new(definition))
+                .collect(),
+        }
+    }
+
+    /// Sets the word to transmit for the label from the next transmission on.
+    pub fn transmit(&mut self, label: u8, word: Arinc429Word<f64>) {
+        let sdi = self.sdi;
+        let bus_label = self.label_mut(label);
+        bus_label.pending = bus_label.definition.encode(sdi, word);
+    }
+
+    pub fn transmit_discrete(&mut self, label: u8, word: Arinc429Word<u32>) {
+        self.transmit(label, Arinc429Word::new(word.value() as f64, word.ssm()));
+    }
+
+    pub fn stop_transmission_of(&mut self, label: u8) {
+        self.label_mut(label).pending = None;
+    }
+
+    pub fn stop_transmission(&mut self) {
+        self.labels
+            .iter_mut()
+            .for_each(|bus_label| bus_label.pending = None);
+    }
+
+    pub fn update(&mut self, delta: Duration) {
+        self.labels
+            .iter_mut()
+            .for_each(|bus_label| bus_label.update(delta));
+    }
+
+    /// Returns the last transmitted word of the label,
+    /// or `None` when the label isn't being transmitted.
+    pub fn receive(&self, label: u8) -> Option<Arinc429RawWord> {
+        let bus_label = self.label(label);
+        if bus_label.since_transmission <= bus_label.definition.period * Self::STALE_AFTER_PERIODS {
+            bus_label.transmitted
+        } else {
+            None
+        }
+    }
+
+    pub fn receive_value(&self, label: u8) -> Option<Arinc429Word<f64>> {
+        self.receive(label)
+            .map(|word| self.label(label).definition.decode(word))
+    }
+
+    pub fn receive_discrete(&self, label: u8) -> Option<Arinc429Word<u32>> {
+        self.receive_value(label)
+            .map(|word| Arinc429Word::new(word.value() as u32, word.ssm()))
+    }
+
+    /// Writes the received word of the label to the variable, as a receiver on the bus sees it.
+    /// A label which isn't being transmitted is written as zero, which reads as a failure warning.
+    pub fn write_received<T: Writer>(
+        &self,
+        writer: &mut T,
+        identifier: &VariableIdentifier,
+        label: u8,
+    ) {
+        self.write_received_converted(writer, identifier, label, |value| value);
+    }
+
+    /// Writes the received word of the label to the variable, converting the decoded value
+    /// into the range the variable is read in.
+    pub fn write_received_converted<T: Writer, U: Fn(f64) -> f64>(
+        &self,
+        writer: &mut T,
+        identifier: &VariableIdentifier,
+        label: u8,
+        convert: U,
+    ) {
+        writer.write_f64(
+            identifier,
+            self.receive_value(label).map_or(0., |word| {
+                Arinc429Word::new(convert(word.value()), word.ssm()).into()
+            }),
+        );
+    }
+
+    fn label(&self, label: u8) -> &Arinc429BusLabel {
+        self.labels
+            .iter()
+            .find(|bus_label| bus_label.definition.label == label)
+            .unwrap_or_else(|| panic!("Label {:o} isn't defined on the bus.", label))
+    }
+
+    fn label_mut(&mut self, label: u8) -> &mut Arinc429BusLabel {
+        self.labels
+            .iter_mut()
+            .find(|bus_label| bus_label.definition.label == label)
+            .unwrap_or_else(|| panic!("Label {:o} isn't defined on the bus.", label))
+    }
+}
+
+struct Arinc429BusLabel {
+    definition: Arinc429LabelDefinition,
+    pending: Option<Arinc429RawWord>,
+    transmitted: Option<Arinc429RawWord>,
+    since_transmission: Duration,
+    until_next_transmission: Duration,
+}
+impl Arinc429BusLabel {
+    fn new(definition: Arinc429LabelDefinition) -> Self {
+        Self {
+            definition,
+            pending: None,
+            transmitted: None,
+            since_transmission: Duration::ZERO,
+            until_next_transmission: Duration::ZERO,
+        }
+    }
+
+    fn update(&mut self, delta: Duration) {
+        self.since_transmission += delta;
+        self.until_next_transmission = self.until_next_transmission.saturating_sub(delta);
+
+        if self.until_next_transmission.is_zero() {
+            if let Some(word) = self.pending {
+                self.transmitted = Some(word);
+                self.since_transmission = Duration::ZERO;
+            }
+
+            self.until_next_transmission = self.definition.period;
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use ntest::assert_about_eq;
     use rand::Rng;
     use rstest::rstest;
 
@@ -189,4 +590,243 @@ mod tests {
         }
         assert_eq!(expected_ssm, result.ssm());
     }
+
+    const ALTITUDE: Arinc429LabelDefinition =
+        Arinc429LabelDefinition::bnr(0o203, 17, 1., Duration::from_millis(62));
+    const WIND_DIRECTION: Arinc429LabelDefinition =
+        Arinc429LabelDefinition::bcd(0o015, 3, 1., Duration::from_millis(500));
+    const DISCRETE_WORD: Arinc429LabelDefinition =
+        Arinc429LabelDefinition::discrete(0o270, Duration::from_millis(100));
+
+    #[test]
+    fn raw_word_transmits_label_most_significant_bit_first() {
+        let word = Arinc429RawWord::new(0o203, 0, 0, 0);
+
+        assert_eq!(word.bits() & 0xff, 0b1100_0001);
+        assert_eq!(word.label(), 0o203);
+    }
+
+    #[test]
+    fn raw_word_fields_are_symmetric() {
+        let word = Arinc429RawWord::new(0o164, 0b10, 0x5_a5a5, 0b01);
+
+        assert_eq!(word.label(), 0o164);
+        assert_eq!(word.sdi(), 0b10);
+        assert_eq!(word.data(), 0x5_a5a5);
+        assert_eq!(word.ssm(), 0b01);
+    }
+
+    #[rstest]
+    #[case(0o000, 0)]
+    #[case(0o203, 0x1234)]
+    #[case(0o377, 0x7_ffff)]
+    fn raw_word_has_odd_parity(#[case] label: u8, #[case] data: u32) {
+        let word = Arinc429RawWord::new(label, 0b11, data, 0b11);
+
+        assert!(word.has_valid_parity());
+        assert!(!Arinc429RawWord::from_bits(word.bits() ^ (1 << 12)).has_valid_parity());
+    }
+
+    #[rstest]
+    #[case(35000.)]
+    #[case(-1500.)]
+    #[case(0.)]
+    fn bnr_conversion_is_symmetric(#[case] value: f64) {
+        let word = ALTITUDE
+            .encode(0, Arinc429Word::new(value, SignStatus::NormalOperation))
+            .unwrap();
+
+        let result = ALTITUDE.decode(word);
+
+        assert_eq!(result.value(), value);
+        assert_eq!(result.ssm(), SignStatus::NormalOperation);
+    }
+
+    #[test]
+    fn bnr_rounds_to_resolution() {
+        let definition = Arinc429LabelDefinition::bnr(0o164, 16, 0.125, Duration::from_millis(25));
+
+        let word = definition
+            .encode(0, Arinc429Word::new(12.3, SignStatus::NormalOperation))
+            .unwrap();
+
+        assert_eq!(definition.decode(word).value(), 12.25);
+    }
+
+    #[test]
+    fn bnr_clamps_values_outside_of_range() {
+        let word = ALTITUDE
+            .encode(0, Arinc429Word::new(200000., SignStatus::NormalOperation))
+            .unwrap();
+
+        assert_eq!(ALTITUDE.decode(word).value(), 131071.);
+    }
+
+    #[rstest]
+    #[case(SignStatus::FailureWarning, 0b00)]
+    #[case(SignStatus::NoComputedData, 0b01)]
+    #[case(SignStatus::FunctionalTest, 0b10)]
+    #[case(SignStatus::NormalOperation, 0b11)]
+    fn bnr_encodes_ssm(#[case] ssm: SignStatus, #[case] expected_bits: u8) {
+        let word = ALTITUDE.encode(0, Arinc429Word::new(0., ssm)).unwrap();
+
+        assert_eq!(word.ssm(), expected_bits);
+        assert_eq!(ALTITUDE.decode(word).ssm(), ssm);
+    }
+
+    #[test]
+    fn bcd_encodes_decimal_digits() {
+        let word = WIND_DIRECTION
+            .encode(0, Arinc429Word::new(275., SignStatus::NormalOperation))
+            .unwrap();
+
+        assert_eq!(word.data() >> 7, 0x275);
+        assert_eq!(WIND_DIRECTION.decode(word).value(), 275.);
+    }
+
+    #[test]
+    fn bcd_encodes_sign_in_ssm() {
+        let word = WIND_DIRECTION
+            .encode(0, Arinc429Word::new(-12., SignStatus::NormalOperation))
+            .unwrap();
+
+        assert_eq!(word.ssm(), 0b11);
+        assert_eq!(WIND_DIRECTION.decode(word).value(), -12.);
+        assert_eq!(
+            WIND_DIRECTION.decode(word).ssm(),
+            SignStatus::NormalOperation
+        );
+    }
+
+    #[test]
+    fn bcd_truncates_most_significant_digit_when_digits_dont_fit() {
+        let definition = Arinc429LabelDefinition::bcd(0o234, 5, 0.1, Duration::from_millis(125));
+
+        let word = definition
+            .encode(0, Arinc429Word::new(1013.2, SignStatus::NormalOperation))
+            .unwrap();
+        assert_about_eq!(definition.decode(word).value(), 1013.2);
+
+        let word = definition
+            .encode(0, Arinc429Word::new(9999.9, SignStatus::NormalOperation))
+            .unwrap();
+        assert_about_eq!(definition.decode(word).value(), 7999.9);
+    }
+
+    #[test]
+    fn bcd_failure_warning_cannot_be_encoded() {
+        assert!(WIND_DIRECTION
+            .encode(0, Arinc429Word::new(0., SignStatus::FailureWarning))
+            .is_none());
+    }
+
+    #[rstest]
+    #[case(SignStatus::NormalOperation, 0b00)]
+    #[case(SignStatus::NoComputedData, 0b01)]
+    #[case(SignStatus::FunctionalTest, 0b10)]
+    #[case(SignStatus::FailureWarning, 0b11)]
+    fn discrete_keeps_bit_positions(#[case] ssm: SignStatus, #[case] expected_bits: u8) {
+        let mut discrete = Arinc429Word::new(0, ssm);
+        discrete.set_bit(11, true);
+        discrete.set_bit(29, true);
+
+        let word = DISCRETE_WORD
+            .encode(0, Arinc429Word::new(discrete.value() as f64, ssm))
+            .unwrap();
+
+        assert_eq!(word.data(), 1 | (1 << 18));
+        assert_eq!(word.ssm(), expected_bits);
+        assert_eq!(DISCRETE_WORD.decode(word).value() as u32, discrete.value());
+        assert_eq!(DISCRETE_WORD.decode(word).ssm(), ssm);
+    }
+
+    fn bus() -> Arinc429Bus {
+        Arinc429Bus::new(0b01, &[ALTITUDE, WIND_DIRECTION, DISCRETE_WORD])
+    }
+
+    #[test]
+    fn bus_transmits_word_with_sdi() {
+        let mut bus = bus();
+        bus.transmit(0o203, Arinc429Word::new(1000., SignStatus::NormalOperation));
+        bus.update(Duration::from_millis(10));
+
+        let word = bus.receive(0o203).unwrap();
+        assert_eq!(word.sdi(), 0b01);
+        assert_eq!(bus.receive_value(0o203).unwrap().value(), 1000.);
+    }
+
+    #[test]
+    fn bus_receives_nothing_before_first_transmission() {
+        let mut bus = bus();
+        bus.update(Duration::from_millis(10));
+
+        assert!(bus.receive(0o203).is_none());
+    }
+
+    #[test]
+    fn bus_transmits_once_per_period() {
+        let mut bus = bus();
+        bus.transmit(0o203, Arinc429Word::new(1000., SignStatus::NormalOperation));
+        bus.update(Duration::from_millis(10));
+
+        bus.transmit(0o203, Arinc429Word::new(2000., SignStatus::NormalOperation));
+        bus.update(Duration::from_millis(30));
+        assert_eq!(bus.receive_value(0o203).unwrap().value(), 1000.);
+
+        bus.update(Duration::from_millis(40));
+        assert_eq!(bus.receive_value(0o203).unwrap().value(), 2000.);
+    }
+
+    #[test]
+    fn bus_label_becomes_stale_when_no_longer_transmitted() {
+        let mut bus = bus();
+        bus.transmit(0o203, Arinc429Word::new(1000., SignStatus::NormalOperation));
+        bus.update(Duration::from_millis(10));
+
+        bus.stop_transmission();
+        bus.update(Duration::from_millis(100));
+        assert!(bus.receive(0o203).is_some());
+
+        bus.update(Duration::from_millis(100));
+        assert!(bus.receive(0o203).is_none());
+    }
+
+    #[test]
+    fn bus_no_computed_data_is_still_transmitted() {
+        let mut bus = bus();
+        for _ in 0..10 {
+            bus.transmit(0o203, Arinc429Word::new(0., SignStatus::NoComputedData));
+            bus.update(Duration::from_millis(100));
+        }
+
+        assert!(bus.receive_value(0o203).unwrap().is_no_computed_data());
+    }
+
+    #[test]
+    fn bus_stops_transmitting_bcd_failure_warning() {
+        let mut bus = bus();
+        bus.transmit(0o015, Arinc429Word::new(90., SignStatus::NormalOperation));
+        bus.update(Duration::from_millis(10));
+        assert!(bus.receive(0o015).is_some());
+
+        bus.transmit(0o015, Arinc429Word::new(0., SignStatus::FailureWarning));
+        bus.update(Duration::from_secs(2));
+
+        assert!(bus.receive(0o015).is_none());
+    }
+
+    #[test]
+    fn bus_transmits_discrete_words() {
+        let mut discrete = Arinc429Word::new(0, SignStatus::NormalOperation);
+        discrete.set_bit(14, true);
+
+        let mut bus = bus();
+        bus.transmit_discrete(0o270, discrete);
+        bus.update(Duration::from_millis(10));
+
+        let result = bus.receive_discrete(0o270).unwrap();
+        assert!(result.get_bit(14));
+        assert!(!result.get_bit(15));
+        assert!(result.is_normal_operation());
+    }
 }
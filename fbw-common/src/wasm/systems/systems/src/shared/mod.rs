@@ -54,6 +54,11 @@ pub trait EngineFirePushButtons {
     fn is_released(&self, engine_number: usize) -> bool;
 }
 
+pub trait FireTestPushButtons {
+    /// Indicates if the fire test of the given zone is in progress.
+    fn is_pressed(&self, zone: FireDetectionZone) -> bool;
+}
+
 pub trait EmergencyElectricalRatPushButton {
     fn is_pressed(&self) -> bool;
 }
@@ -232,6 +237,13 @@ pub trait EngineStartState {
     fn engine_mode_selector(&self) -> EngineModeSelector;
 }
 
+pub trait EnginePneumaticLoads {
+    /// The gauge pressure of the air driving the engine's air starter.
+    fn starter_air_pressure(&self, engine_number: usize) -> Pressure;
+    /// The air taken from the engine's compressor bleed ports.
+    fn bleed_air_extraction(&self, engine_number: usize) -> MassRate;
+}
+
 pub trait EngineBleedPushbutton<const N: usize> {
     fn engine_bleed_pushbuttons_are_auto(&self) -> [bool; N];
 }
@@ -247,10 +259,13 @@ pub trait AdirsMeasurementOutputs {
     fn is_fully_aligned(&self, adiru_number: usize) -> bool;
     fn latitude(&self, adiru_number: usize) -> Arinc429Word<Angle>;
     fn longitude(&self, adiru_number: usize) -> Arinc429Word<Angle>;
+    fn hybrid_latitude(&self, adiru_number: usize) -> Arinc429Word<Angle>;
+    fn hybrid_longitude(&self, adiru_number: usize) -> Arinc429Word<Angle>;
     fn heading(&self, adiru_number: usize) -> Arinc429Word<Angle>;
     fn true_heading(&self, adiru_number: usize) -> Arinc429Word<Angle>;
     fn vertical_speed(&self, adiru_number: usize) -> Arinc429Word<Velocity>;
     fn altitude(&self, adiru_number: usize) -> Arinc429Word<Length>;
+    fn computed_airspeed(&self, adiru_number: usize) -> Arinc429Word<Velocity>;
     fn angle_of_attack(&self, adiru_number: usize) -> Arinc429Word<Angle>;
 }
 
@@ -261,6 +276,51 @@ pub trait AdirsDiscreteOutputs {
     fn low_speed_warning_4(&self, adiru_number: usize) -> bool;
 }
 
+/// The heated air data probes feeding an air data reference.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum ProbeType {
+    Pitot,
+    LeftStatic,
+    RightStatic,
+    TotalAirTemperature,
+    AngleOfAttack,
+}
+
+impl Display for ProbeType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProbeType::Pitot => write!(f, "PITOT"),
+            ProbeType::LeftStatic => write!(f, "LEFT_STATIC"),
+            ProbeType::RightStatic => write!(f, "RIGHT_STATIC"),
+            ProbeType::TotalAirTemperature => write!(f, "TAT"),
+            ProbeType::AngleOfAttack => write!(f, "AOA"),
+        }
+    }
+}
+
+/// The heated windows controlled by a window heat computer.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum WindowType {
+    Windshield,
+    SideWindow,
+}
+
+impl Display for WindowType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WindowType::Windshield => write!(f, "WINDSHIELD"),
+            WindowType::SideWindow => write!(f, "WINDOW"),
+        }
+    }
+}
+
+pub trait AirDataProbes {
+    /// Whether the probe is commanded to be heated, but isn't.
+    fn probe_heat_fault(&self, probe: ProbeType) -> bool;
+    /// The part of the pitot probe inlet which is blocked by ice.
+    fn pitot_blockage(&self) -> Ratio;
+}
+
 pub enum GearWheel {
     NOSE = 0,
     LEFT = 1,
@@ -1099,6 +1159,7 @@ pub enum FireDetectionZone {
     Engine(usize),
     Apu,
     Mlg,
+    Cargo,
 }
 
 impl Display for FireDetectionZone {
@@ -1106,6 +1167,7 @@ impl Display for FireDetectionZone {
         match self {
             FireDetectionZone::Apu => write!(f, "APU"),
             FireDetectionZone::Mlg => write!(f, "MLG"),
+            FireDetectionZone::Cargo => write!(f, "CARGO"),
             FireDetectionZone::Engine(number) => write!(f, "{}", number),
         }
     }
@@ -1117,6 +1179,30 @@ pub enum FireDetectionLoopID {
     B,
 }
 
+#[derive(Clone, Copy, Eq, PartialEq, Hash)]
+pub enum LeakDetectionZone {
+    Wing(usize),
+    Pylon(usize),
+    Apu,
+}
+
+impl Display for LeakDetectionZone {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LeakDetectionZone::Wing(number) => write!(f, "WING_{}", number),
+            LeakDetectionZone::Pylon(number) => write!(f, "PYLON_{}", number),
+            LeakDetectionZone::Apu => write!(f, "APU"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Eq, PartialEq, Hash)]
+pub enum EngineBleedValveId {
+    HighPressure,
+    PressureRegulating,
+    FanAir,
+}
+
 pub trait Clamp {
     /// Restrict a value to a certain interval unless it is NaN.
     ///
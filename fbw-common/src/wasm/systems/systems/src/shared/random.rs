@@ -1,80 +1,116 @@
-#[cfg(any(target_arch = "wasm32", doc))]
-pub use wasm::*;
-
-#[cfg(any(target_arch = "wasm32", doc))]
-mod wasm {
-    use rand::rngs::SmallRng;
-    use rand::{Rng, SeedableRng};
-    use rand_distr::{Distribution, Normal};
-    use std::mem::MaybeUninit;
-    use std::sync::Once;
-
-    static RAND_INIT: Once = Once::new();
-    static mut RAND: MaybeUninit<SmallRng> = MaybeUninit::uninit();
-
-    pub fn random_number() -> u8 {
-        // SAFETY: WASM is single-threaded, and we're not passing references to `RAND` around.
-        RAND_INIT.call_once(|| unsafe {
-            RAND = MaybeUninit::new(SmallRng::from_os_rng());
-        });
-
-        // SAFETY: `RAND` was initialized above.
-        unsafe { (*RAND.as_mut_ptr()).random() }
+//! Random numbers for the simulation.
+//!
+//! Each [`Simulation`] owns a [`RandomSource`] seeded with an explicit seed. While the simulation
+//! constructs or updates its aircraft, the random functions in this module draw from that source,
+//! such that a simulation given the same seed and inputs produces the same results on every run.
+//! Outside of a simulation the functions draw from a per thread source with a fixed seed.
+//!
+//! [`Simulation`]: ../../simulation/struct.Simulation.html
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+use rand_distr::{Distribution, Normal};
+use std::cell::RefCell;
+
+const UNOWNED_SEED: u64 = 0;
+
+thread_local! {
+    static ACTIVE: RefCell<SmallRng> = RefCell::new(SmallRng::seed_from_u64(UNOWNED_SEED));
+}
+
+/// Returns a seed taken from the operating system, for when runs shouldn't repeat each other.
+pub fn entropy_seed() -> u64 {
+    SmallRng::from_os_rng().random()
+}
+
+/// A seeded source of random numbers, owned by a simulation.
+pub struct RandomSource {
+    rng: SmallRng,
+}
+impl RandomSource {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: SmallRng::seed_from_u64(seed),
+        }
     }
 
-    pub fn random_from_range(from: f64, to: f64) -> f64 {
-        // SAFETY: WASM is single-threaded, and we're not passing references to `RAND` around.
-        RAND_INIT.call_once(|| unsafe {
-            RAND = MaybeUninit::new(SmallRng::from_os_rng());
-        });
+    /// Makes the random functions draw from this source until [`deactivate`] is called.
+    ///
+    /// [`deactivate`]: #method.deactivate
+    pub(crate) fn activate(&mut self) {
+        self.exchange();
+    }
 
-        // SAFETY: `RAND` was initialized above.
-        unsafe { (*RAND.as_mut_ptr()).random_range(from..to) }
+    /// Takes back the state of this source, such that the random functions
+    /// draw from the source which was active before.
+    pub(crate) fn deactivate(&mut self) {
+        self.exchange();
     }
 
-    // Generates a random number based on normal distribution
-    pub fn random_from_normal_distribution(mean: f64, std_dev: f64) -> f64 {
-        // SAFETY: WASM is single-threaded, and we're not passing references to `RAND` around.
-        RAND_INIT.call_once(|| unsafe {
-            RAND = MaybeUninit::new(SmallRng::from_os_rng());
-        });
-
-        let normal = Normal::new(mean, std_dev).unwrap();
-        let limit_offset = 4. * std_dev;
-
-        // SAFETY: `RAND` was initialized above.
-        unsafe {
-            normal
-                .sample(&mut *RAND.as_mut_ptr())
-                .max(mean - limit_offset)
-                .min(mean + limit_offset)
-        }
+    fn exchange(&mut self) {
+        ACTIVE.with(|active| std::mem::swap(&mut *active.borrow_mut(), &mut self.rng));
     }
 }
 
-#[cfg(not(any(target_arch = "wasm32", doc)))]
-pub use not_wasm::*;
+pub fn random_number() -> u8 {
+    ACTIVE.with(|rng| rng.borrow_mut().random())
+}
 
-#[cfg(not(any(target_arch = "wasm32", doc)))]
-mod not_wasm {
-    use rand::Rng;
-    use rand_distr::{Distribution, Normal};
+pub fn random_from_range(from: f64, to: f64) -> f64 {
+    ACTIVE.with(|rng| rng.borrow_mut().random_range(from..to))
+}
+
+/// Random value from normal distribution. Output limited to -4 / +4 sigma
+pub fn random_from_normal_distribution(mean: f64, std_dev: f64) -> f64 {
+    let normal = Normal::new(mean, std_dev).unwrap();
+    let limit_offset = 4. * std_dev;
+
+    ACTIVE
+        .with(|rng| normal.sample(&mut *rng.borrow_mut()))
+        .max(mean - limit_offset)
+        .min(mean + limit_offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    pub fn random_number() -> u8 {
-        rand::rng().random()
+    fn draw(source: &mut RandomSource) -> Vec<f64> {
+        source.activate();
+        let values = (0..10).map(|_| random_from_range(0., 1.)).collect();
+        source.deactivate();
+
+        values
+    }
+
+    #[test]
+    fn same_seed_draws_same_numbers() {
+        assert_eq!(
+            draw(&mut RandomSource::new(42)),
+            draw(&mut RandomSource::new(42))
+        );
     }
 
-    pub fn random_from_range(from: f64, to: f64) -> f64 {
-        rand::rng().random_range(from..to)
+    #[test]
+    fn different_seeds_draw_different_numbers() {
+        assert_ne!(
+            draw(&mut RandomSource::new(1)),
+            draw(&mut RandomSource::new(2))
+        );
     }
 
-    /// Random value from normal distribution. Output limited to -4 / +4 sigma
-    pub fn random_from_normal_distribution(mean: f64, std_dev: f64) -> f64 {
-        let normal = Normal::new(mean, std_dev).unwrap();
-        let limit_offset = 4. * std_dev;
-        normal
-            .sample(&mut rand::rng())
-            .max(mean - limit_offset)
-            .min(mean + limit_offset)
+    #[test]
+    fn source_continues_where_it_left_off() {
+        let mut interleaved = RandomSource::new(42);
+        let mut other = RandomSource::new(7);
+        let mut values = draw(&mut interleaved);
+        draw(&mut other);
+        values.extend(draw(&mut interleaved));
+
+        let mut uninterrupted = RandomSource::new(42);
+        uninterrupted.activate();
+        let expected: Vec<f64> = (0..20).map(|_| random_from_range(0., 1.)).collect();
+        uninterrupted.deactivate();
+
+        assert_eq!(values, expected);
     }
 }
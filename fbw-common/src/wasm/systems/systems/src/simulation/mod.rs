@@ -2,7 +2,7 @@ use std::time::Duration;
 
 mod update_context;
 use crate::electrical::{ElectricalElementIdentifier, ElectricalElementIdentifierProvider};
-use crate::shared::{from_bool, ElectricalBusType};
+use crate::shared::{from_bool, ElectricalBusType, RandomSource};
 use crate::{
     electrical::Electricity,
     failures::FailureType,
@@ -10,7 +10,7 @@ use crate::{
     shared::arinc825::{from_arinc825, to_arinc825, Arinc825Word},
     shared::{to_bool, ConsumePower, ElectricalBuses, MachNumber, PowerConsumptionReport},
 };
-use fxhash::FxHashSet;
+use fxhash::{FxHashMap, FxHashSet};
 use uom::si::mass_rate::kilogram_per_second;
 use uom::si::{
     acceleration::foot_per_second_squared, angle::degree, angular_velocity::revolution_per_minute,
@@ -21,7 +21,11 @@ use uom::si::{
 };
 pub use update_context::*;
 
+pub mod recording;
+mod snapshot;
 pub mod test;
+use snapshot::{RestoreStateVisitor, SaveStateVisitor};
+pub use snapshot::{SimulationSnapshot, SnapshotReader, SnapshotWriter};
 
 /// Trait for a type which can read and write simulator data.
 /// Using this trait implementors can abstract away the way the code
@@ -329,6 +333,39 @@ pub trait SimulationElement {
 
     /// Receives a failure in order to activate or deactivate it.
     fn receive_failure(&mut self, _active_failures: &FxHashSet<FailureType>) {}
+
+    /// Saves state which should persist across sessions into a [`SimulationSnapshot`].
+    /// Only state which cannot be derived from the simulator belongs in the snapshot,
+    /// such as the charge of a battery or the temperature of a brake.
+    /// # Examples
+    /// ```rust
+    /// # use systems::simulation::{SimulationElement, SnapshotReader, SnapshotWriter,
+    /// # Write, VariableIdentifier};
+    /// struct MySimulationElement {
+    ///     charge: f64,
+    /// }
+    /// impl SimulationElement for MySimulationElement {
+    ///     fn save_state(&self, writer: &mut SnapshotWriter) {
+    ///         // The identifier would ordinarily be retrieved from the registry.
+    ///         writer.write(&VariableIdentifier::default(), self.charge);
+    ///     }
+    ///
+    ///     fn restore_state(&mut self, reader: &mut SnapshotReader) {
+    ///         if let Some(charge) = reader.restore(&VariableIdentifier::default()) {
+    ///             self.charge = charge;
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    /// [`SimulationSnapshot`]: struct.SimulationSnapshot.html
+    fn save_state(&self, _writer: &mut SnapshotWriter) {}
+
+    /// Restores state previously saved by [`save_state`]. This is called once, right after
+    /// the aircraft has been constructed by [`Simulation::new_from_snapshot`].
+    ///
+    /// [`save_state`]: #method.save_state
+    /// [`Simulation::new_from_snapshot`]: struct.Simulation.html#method.new_from_snapshot
+    fn restore_state(&mut self, _reader: &mut SnapshotReader) {}
 }
 
 /// Trait for visitors that visit the aircraft's system simulation to call
@@ -360,23 +397,70 @@ pub struct Simulation<T: Aircraft> {
     aircraft: T,
     electricity: Electricity,
     update_context: UpdateContext,
+    variable_names: FxHashMap<VariableIdentifier, String>,
+    random: RandomSource,
 }
 impl<T: Aircraft> Simulation<T> {
+    /// Creates a simulation of the aircraft constructed by the given function.
+    ///
+    /// All random numbers drawn by the aircraft's elements come from a source seeded with
+    /// `random_seed`. Given the same seed and the same inputs, the simulation produces
+    /// the same outputs.
     pub fn new<U: FnOnce(&mut InitContext) -> T>(
         start_state: StartState,
+        random_seed: u64,
         aircraft_ctor_fn: U,
         registry: &mut impl VariableRegistry,
     ) -> Self {
+        let mut random = RandomSource::new(random_seed);
         let mut electricity = Electricity::new();
-        let mut context = InitContext::new(start_state, &mut electricity, registry);
+        let mut registry = NamingVariableRegistry::new(registry);
+        let mut context = InitContext::new(start_state, &mut electricity, &mut registry);
         let update_context = UpdateContext::new_for_simulation(&mut context);
+
+        random.activate();
+        let aircraft = (aircraft_ctor_fn)(&mut context);
+        random.deactivate();
+
         Self {
-            aircraft: (aircraft_ctor_fn)(&mut context),
+            aircraft,
             electricity,
             update_context,
+            variable_names: registry.variable_names,
+            random,
         }
     }
 
+    /// Creates a simulation of which the aircraft's persistent state is restored
+    /// from the given snapshot, as previously taken by [`snapshot`].
+    ///
+    /// [`snapshot`]: #method.snapshot
+    pub fn new_from_snapshot<U: FnOnce(&mut InitContext) -> T>(
+        start_state: StartState,
+        random_seed: u64,
+        aircraft_ctor_fn: U,
+        registry: &mut impl VariableRegistry,
+        snapshot: &SimulationSnapshot,
+    ) -> Self {
+        let mut simulation = Self::new(start_state, random_seed, aircraft_ctor_fn, registry);
+
+        let values = snapshot.to_identifiers(&simulation.variable_names);
+        simulation
+            .aircraft
+            .accept(&mut RestoreStateVisitor::new(&values));
+
+        simulation
+    }
+
+    /// Takes a snapshot of the aircraft's persistent state.
+    pub fn snapshot(&mut self) -> SimulationSnapshot {
+        let mut values = FxHashMap::default();
+        self.aircraft
+            .accept(&mut SaveStateVisitor::new(&mut values));
+
+        SimulationSnapshot::from_identifiers(values, &self.variable_names)
+    }
+
     /// Execute a single run of the simulation using the specified `delta` duration
     /// as the amount of time that has passed since the previous run.
     ///
@@ -422,7 +506,7 @@ impl<T: Aircraft> Simulation<T> {
     /// #     }
     /// # }
     /// let mut registry = MyVariableRegistry::new();
-    /// let mut simulation = Simulation::new(Default::default(), MyAircraft::new, &mut registry);
+    /// let mut simulation = Simulation::new(Default::default(), 0, MyAircraft::new, &mut registry);
     /// let mut reader_writer = MySimulatorReaderWriter::new();
     /// // For each frame, call the tick function.
     /// simulation.tick(Duration::from_millis(50), 20., &mut reader_writer)
@@ -434,6 +518,7 @@ impl<T: Aircraft> Simulation<T> {
         simulation_time: f64,
         reader_writer: &mut impl SimulatorReaderWriter,
     ) {
+        self.random.activate();
         self.electricity.pre_tick();
 
         let mut reader = SimulatorReader::new(reader_writer);
@@ -459,6 +544,17 @@ impl<T: Aircraft> Simulation<T> {
         let mut writer = SimulatorWriter::new(reader_writer);
         let mut visitor = SimulationToSimulatorVisitor::new(&mut writer);
         self.aircraft.accept(&mut visitor);
+        self.random.deactivate();
+    }
+
+    /// Writes the current state of the aircraft to the simulator without executing a tick.
+    ///
+    /// This is useful when running outside of the simulator, as it seeds the reader writer
+    /// with the initial state of the aircraft (e.g. push buttons which are initially ON).
+    pub fn write_state(&mut self, reader_writer: &mut impl SimulatorReaderWriter) {
+        let mut writer = SimulatorWriter::new(reader_writer);
+        let mut visitor = SimulationToSimulatorVisitor::new(&mut writer);
+        self.accept(&mut visitor);
     }
 
     pub fn update_active_failures(&mut self, active_failures: FxHashSet<FailureType>) {
@@ -486,6 +582,29 @@ impl<T: Aircraft> Simulation<T> {
     }
 }
 
+/// Remembers the names of the variables used by the aircraft, such that
+/// snapshots can refer to variables by name.
+struct NamingVariableRegistry<'a, T: VariableRegistry> {
+    registry: &'a mut T,
+    variable_names: FxHashMap<VariableIdentifier, String>,
+}
+impl<'a, T: VariableRegistry> NamingVariableRegistry<'a, T> {
+    fn new(registry: &'a mut T) -> Self {
+        Self {
+            registry,
+            variable_names: FxHashMap::default(),
+        }
+    }
+}
+impl<T: VariableRegistry> VariableRegistry for NamingVariableRegistry<'_, T> {
+    fn get(&mut self, name: String) -> VariableIdentifier {
+        let identifier = self.registry.get(name.clone());
+        self.variable_names.insert(identifier, name);
+
+        identifier
+    }
+}
+
 struct FailureSimulationElementVisitor {
     active_failures: FxHashSet<FailureType>,
 }
@@ -0,0 +1,785 @@
+//! Recording and replaying of [`Simulation`] sessions.
+//!
+//! A [`SimulationRecorder`] sits between the [`Simulation`] and the simulator. It writes every
+//! value read from the simulator, every value written to the simulator, the `delta` and
+//! `simulation_time` of each tick and the active failures into a line based log.
+//! A [`SimulationReplay`] feeds such a log back into a freshly constructed [`Simulation`] and
+//! compares the values written by the simulation with those found in the recording.
+//!
+//! The log consists of comma separated lines, each starting with a record type:
+//! - `H,<version>,<start state>,<random seed>`: header, always the first line.
+//! - `V,<index>,<name>`: declares the variable with the given name under the given index.
+//! - `T,<delta in seconds>,<simulation time>`: starts a new tick.
+//! - `F[,<failure id>...]`: the set of active failures changed to the given failures.
+//! - `R,<index>,<value>`: a value read from the simulator during the current tick.
+//! - `W,<index>,<value>`: a value written to the simulator during the current tick.
+//!
+//! Failures are stored using the same numeric identifiers as passed to
+//! `MsfsSimulationBuilder::with_failures`, thus the recorder and replay need to be given
+//! the same failure list.
+//!
+//! Flights within the simulator are recorded by configuring the simulation using
+//! `MsfsSimulationBuilder::with_recording`.
+//!
+//! [`Simulation`]: ../struct.Simulation.html
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, BufWriter, Write},
+    path::Path,
+    time::Duration,
+};
+
+use fxhash::{FxHashMap, FxHashSet};
+
+use crate::failures::FailureType;
+
+use super::{
+    Aircraft, InitContext, Simulation, SimulatorReaderWriter, StartState, VariableIdentifier,
+    VariableRegistry,
+};
+
+const FORMAT_VERSION: u32 = 2;
+
+/// Records a simulation session into a log which can later be replayed by
+/// a [`SimulationReplay`].
+///
+/// # Examples
+/// ```rust
+/// # use std::time::Duration;
+/// # use systems::simulation::{Aircraft, InitContext, Simulation, SimulationElement,
+/// # SimulatorReaderWriter, VariableIdentifier};
+/// # use systems::simulation::recording::SimulationRecorder;
+/// # use systems::simulation::test::TestVariableRegistry;
+/// # struct MyAircraft {}
+/// # impl MyAircraft {
+/// #     fn new(_: &mut InitContext) -> Self {
+/// #         Self {}
+/// #     }
+/// # }
+/// # impl Aircraft for MyAircraft {}
+/// # impl SimulationElement for MyAircraft {}
+/// # struct MySimulatorReaderWriter {}
+/// # impl SimulatorReaderWriter for MySimulatorReaderWriter {
+/// #     fn read(&mut self, identifier: &VariableIdentifier) -> f64 { 0.0 }
+/// #     fn write(&mut self, identifier: &VariableIdentifier, value: f64) { }
+/// # }
+/// let mut registry = TestVariableRegistry::default();
+/// let mut recorder = SimulationRecorder::new(Vec::new(), Default::default(), 0);
+/// let mut simulation = Simulation::new(
+///     Default::default(),
+///     0,
+///     MyAircraft::new,
+///     &mut recorder.registry(&mut registry),
+/// );
+///
+/// let mut reader_writer = MySimulatorReaderWriter {};
+/// recorder.tick(&mut simulation, Duration::from_millis(50), 20., &mut reader_writer);
+///
+/// let log = recorder.finish().unwrap();
+/// ```
+pub struct SimulationRecorder<W: Write> {
+    output: W,
+    error: Option<io::Error>,
+    identifier_to_index: FxHashMap<VariableIdentifier, usize>,
+    failure_type_to_identifier: FxHashMap<FailureType, u64>,
+}
+impl SimulationRecorder<BufWriter<File>> {
+    /// Creates a recorder which writes the session to the file at the given path.
+    pub fn create<P: AsRef<Path>>(
+        path: P,
+        start_state: StartState,
+        random_seed: u64,
+    ) -> io::Result<Self> {
+        Ok(Self::new(
+            BufWriter::new(File::create(path)?),
+            start_state,
+            random_seed,
+        ))
+    }
+}
+impl<W: Write> SimulationRecorder<W> {
+    /// Creates a recorder of a simulation with the given start state and random seed.
+    /// The same values are used to construct the simulation when replaying.
+    pub fn new(output: W, start_state: StartState, random_seed: u64) -> Self {
+        let mut recorder = Self {
+            output,
+            error: None,
+            identifier_to_index: FxHashMap::default(),
+            failure_type_to_identifier: FxHashMap::default(),
+        };
+
+        recorder.write_line(format_args!(
+            "H,{},{},{}",
+            FORMAT_VERSION,
+            f64::from(start_state),
+            random_seed
+        ));
+
+        recorder
+    }
+
+    /// Provides the identifiers of failures which can be recorded. Failures without
+    /// an identifier are not recorded.
+    pub fn with_failures(mut self, failures: impl IntoIterator<Item = (u64, FailureType)>) -> Self {
+        self.failure_type_to_identifier
+            .extend(failures.into_iter().map(|(id, failure)| (failure, id)));
+        self
+    }
+
+    /// Wraps the given registry, such that the names of all variables used by the
+    /// simulation end up in the recording. Pass the returned registry to [`Simulation::new`].
+    ///
+    /// [`Simulation::new`]: ../struct.Simulation.html#method.new
+    pub fn registry<'a, T: VariableRegistry>(
+        &'a mut self,
+        registry: &'a mut T,
+    ) -> RecordingVariableRegistry<'a, T, W> {
+        RecordingVariableRegistry {
+            registry,
+            recorder: self,
+        }
+    }
+
+    /// Executes a single tick of the simulation while recording all values
+    /// read from and written to the simulator.
+    pub fn tick<T: Aircraft>(
+        &mut self,
+        simulation: &mut Simulation<T>,
+        delta: Duration,
+        simulation_time: f64,
+        reader_writer: &mut impl SimulatorReaderWriter,
+    ) {
+        self.write_line(format_args!(
+            "T,{},{}",
+            delta.as_secs_f64(),
+            simulation_time
+        ));
+
+        let mut recording_reader_writer = RecordingReaderWriter {
+            reader_writer,
+            recorder: self,
+        };
+        simulation.tick(delta, simulation_time, &mut recording_reader_writer);
+    }
+
+    /// Passes the active failures to the simulation and records them.
+    pub fn update_active_failures<T: Aircraft>(
+        &mut self,
+        simulation: &mut Simulation<T>,
+        active_failures: FxHashSet<FailureType>,
+    ) {
+        let mut identifiers: Vec<u64> = active_failures
+            .iter()
+            .filter_map(|failure_type| self.failure_type_to_identifier.get(failure_type))
+            .copied()
+            .collect();
+        identifiers.sort_unstable();
+
+        let mut line = String::from("F");
+        identifiers
+            .iter()
+            .for_each(|id| line.push_str(&format!(",{}", id)));
+        self.write_line(format_args!("{}", line));
+
+        simulation.update_active_failures(active_failures);
+    }
+
+    /// Flushes the recording and returns the underlying output.
+    /// Returns the first error which occurred while recording, if any.
+    pub fn finish(mut self) -> io::Result<W> {
+        if let Some(error) = self.error.take() {
+            return Err(error);
+        }
+
+        self.output.flush()?;
+        Ok(self.output)
+    }
+
+    fn index_of(&mut self, identifier: &VariableIdentifier) -> usize {
+        match self.identifier_to_index.get(identifier) {
+            Some(index) => *index,
+            None => self.declare(identifier, &format!("#UNNAMED_{:?}", identifier)),
+        }
+    }
+
+    fn declare(&mut self, identifier: &VariableIdentifier, name: &str) -> usize {
+        if let Some(index) = self.identifier_to_index.get(identifier) {
+            return *index;
+        }
+
+        let index = self.identifier_to_index.len();
+        self.identifier_to_index.insert(*identifier, index);
+        self.write_line(format_args!("V,{},{}", index, name));
+
+        index
+    }
+
+    fn record_read(&mut self, identifier: &VariableIdentifier, value: f64) {
+        let index = self.index_of(identifier);
+        self.write_line(format_args!("R,{},{}", index, value));
+    }
+
+    fn record_write(&mut self, identifier: &VariableIdentifier, value: f64) {
+        let index = self.index_of(identifier);
+        self.write_line(format_args!("W,{},{}", index, value));
+    }
+
+    fn write_line(&mut self, args: std::fmt::Arguments) {
+        if self.error.is_none() {
+            if let Err(error) = writeln!(self.output, "{}", args) {
+                self.error = Some(error);
+            }
+        }
+    }
+}
+
+/// A [`VariableRegistry`] which declares every variable retrieved through it in the recording.
+pub struct RecordingVariableRegistry<'a, T: VariableRegistry, W: Write> {
+    registry: &'a mut T,
+    recorder: &'a mut SimulationRecorder<W>,
+}
+impl<T: VariableRegistry, W: Write> VariableRegistry for RecordingVariableRegistry<'_, T, W> {
+    fn get(&mut self, name: String) -> VariableIdentifier {
+        let identifier = self.registry.get(name.clone());
+        self.recorder.declare(&identifier, &name);
+
+        identifier
+    }
+}
+
+/// A [`SimulatorReaderWriter`] which passes all calls on to the wrapped reader writer
+/// and records the values involved.
+pub struct RecordingReaderWriter<'a, T: SimulatorReaderWriter, W: Write> {
+    reader_writer: &'a mut T,
+    recorder: &'a mut SimulationRecorder<W>,
+}
+impl<T: SimulatorReaderWriter, W: Write> SimulatorReaderWriter for RecordingReaderWriter<'_, T, W> {
+    fn read(&mut self, identifier: &VariableIdentifier) -> f64 {
+        let value = self.reader_writer.read(identifier);
+        self.recorder.record_read(identifier, value);
+
+        value
+    }
+
+    fn write(&mut self, identifier: &VariableIdentifier, value: f64) {
+        self.recorder.record_write(identifier, value);
+        self.reader_writer.write(identifier, value);
+    }
+}
+
+#[derive(Default)]
+struct RecordedTick {
+    delta: Duration,
+    simulation_time: f64,
+    active_failures: Option<Vec<u64>>,
+    reads: Vec<(usize, f64)>,
+    writes: Vec<(usize, f64)>,
+}
+
+/// A difference between a value written during the recorded session
+/// and the value written while replaying it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReplayMismatch {
+    pub tick: usize,
+    pub variable: String,
+    pub recorded: Option<f64>,
+    pub replayed: Option<f64>,
+}
+
+/// The outcome of replaying a recorded session.
+#[derive(Debug, Default)]
+pub struct ReplayReport {
+    ticks: usize,
+    mismatches: Vec<ReplayMismatch>,
+}
+impl ReplayReport {
+    pub fn ticks(&self) -> usize {
+        self.ticks
+    }
+
+    pub fn mismatches(&self) -> &[ReplayMismatch] {
+        &self.mismatches
+    }
+
+    pub fn is_identical(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+}
+
+/// A recorded simulation session which can be replayed deterministically
+/// against a [`Simulation`].
+///
+/// [`Simulation`]: ../struct.Simulation.html
+pub struct SimulationReplay {
+    start_state: StartState,
+    random_seed: u64,
+    variable_names: Vec<String>,
+    ticks: Vec<RecordedTick>,
+    identifier_to_failure_type: FxHashMap<u64, FailureType>,
+    tolerance: f64,
+}
+impl SimulationReplay {
+    /// Opens the recording at the given path.
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::parse(BufReader::new(File::open(path)?))
+    }
+
+    /// Parses a recording as created by the [`SimulationRecorder`].
+    pub fn parse<R: BufRead>(input: R) -> io::Result<Self> {
+        let mut replay = Self {
+            start_state: Default::default(),
+            random_seed: 0,
+            variable_names: vec![],
+            ticks: vec![],
+            identifier_to_failure_type: FxHashMap::default(),
+            tolerance: 0.,
+        };
+
+        let mut pending_failures: Option<Vec<u64>> = None;
+        for (line_number, line) in input.lines().enumerate() {
+            let line = line?;
+            let mut fields = line.splitn(3, ',');
+            let record_type = fields.next().unwrap_or_default();
+
+            match (line_number, record_type) {
+                (0, "H") => {
+                    let mut fields = line.split(',').skip(1);
+                    let version: u32 = parse_field(fields.next(), line_number)?;
+                    if version != FORMAT_VERSION {
+                        return Err(invalid_data(
+                            line_number,
+                            &format!("unsupported recording version {}", version),
+                        ));
+                    }
+
+                    replay.start_state = parse_field::<f64>(fields.next(), line_number)?.into();
+                    replay.random_seed = parse_field(fields.next(), line_number)?;
+                }
+                (0, _) => return Err(invalid_data(line_number, "missing recording header")),
+                (_, "V") => {
+                    let index: usize = parse_field(fields.next(), line_number)?;
+                    let name = fields
+                        .next()
+                        .ok_or_else(|| invalid_data(line_number, "missing variable name"))?;
+                    if index != replay.variable_names.len() {
+                        return Err(invalid_data(line_number, "variable declared out of order"));
+                    }
+
+                    replay.variable_names.push(name.to_owned());
+                }
+                (_, "T") => {
+                    replay.ticks.push(RecordedTick {
+                        delta: Duration::from_secs_f64(parse_field(fields.next(), line_number)?),
+                        simulation_time: parse_field(fields.next(), line_number)?,
+                        active_failures: pending_failures.take(),
+                        ..Default::default()
+                    });
+                }
+                (_, "F") => {
+                    let failures = line
+                        .split(',')
+                        .skip(1)
+                        .map(|id| parse_field(Some(id), line_number))
+                        .collect::<io::Result<Vec<u64>>>()?;
+                    pending_failures = Some(failures);
+                }
+                (_, "R") | (_, "W") => {
+                    let index: usize = parse_field(fields.next(), line_number)?;
+                    let value: f64 = parse_field(fields.next(), line_number)?;
+                    if index >= replay.variable_names.len() {
+                        return Err(invalid_data(line_number, "undeclared variable"));
+                    }
+
+                    let tick = replay
+                        .ticks
+                        .last_mut()
+                        .ok_or_else(|| invalid_data(line_number, "value outside of a tick"))?;
+                    if record_type == "R" {
+                        tick.reads.push((index, value));
+                    } else {
+                        tick.writes.push((index, value));
+                    }
+                }
+                _ => {
+                    return Err(invalid_data(
+                        line_number,
+                        &format!("unknown record type '{}'", record_type),
+                    ))
+                }
+            }
+        }
+
+        Ok(replay)
+    }
+
+    /// Provides the failures by identifier, such that recorded failures can be
+    /// activated during the replay. Use the same failures as given to the recorder.
+    pub fn with_failures(mut self, failures: impl IntoIterator<Item = (u64, FailureType)>) -> Self {
+        self.identifier_to_failure_type.extend(failures);
+        self
+    }
+
+    /// Sets the absolute difference allowed between a recorded and replayed value
+    /// before it is reported as a mismatch. Defaults to exact equality.
+    pub fn with_tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    pub fn start_state(&self) -> StartState {
+        self.start_state
+    }
+
+    pub fn random_seed(&self) -> u64 {
+        self.random_seed
+    }
+
+    pub fn ticks(&self) -> usize {
+        self.ticks.len()
+    }
+
+    /// Constructs a new simulation using the given aircraft constructor, feeds the
+    /// recorded session into it and compares the written values with the recording.
+    pub fn run<T: Aircraft, U: FnOnce(&mut InitContext) -> T>(
+        &self,
+        aircraft_ctor_fn: U,
+    ) -> ReplayReport {
+        let mut registry = ReplayVariableRegistry::default();
+        let mut simulation = Simulation::new(
+            self.start_state,
+            self.random_seed,
+            aircraft_ctor_fn,
+            &mut registry,
+        );
+        let identifiers: Vec<VariableIdentifier> = self
+            .variable_names
+            .iter()
+            .map(|name| registry.get(name.clone()))
+            .collect();
+
+        let mut reader_writer = ReplayReaderWriter::default();
+        let mut report = ReplayReport::default();
+        for (tick_index, tick) in self.ticks.iter().enumerate() {
+            if let Some(active_failures) = &tick.active_failures {
+                simulation.update_active_failures(
+                    active_failures
+                        .iter()
+                        .filter_map(|id| self.identifier_to_failure_type.get(id))
+                        .copied()
+                        .collect(),
+                );
+            }
+
+            for (index, value) in &tick.reads {
+                reader_writer.variables.insert(identifiers[*index], *value);
+            }
+
+            reader_writer.written.clear();
+            simulation.tick(tick.delta, tick.simulation_time, &mut reader_writer);
+
+            self.compare_writes(
+                tick_index,
+                tick,
+                &identifiers,
+                &registry,
+                &reader_writer.written,
+                &mut report,
+            );
+        }
+
+        report.ticks = self.ticks.len();
+        report
+    }
+
+    fn compare_writes(
+        &self,
+        tick_index: usize,
+        tick: &RecordedTick,
+        identifiers: &[VariableIdentifier],
+        registry: &ReplayVariableRegistry,
+        written: &FxHashMap<VariableIdentifier, f64>,
+        report: &mut ReplayReport,
+    ) {
+        let mut recorded: FxHashMap<VariableIdentifier, f64> = FxHashMap::default();
+        for (index, value) in &tick.writes {
+            recorded.insert(identifiers[*index], *value);
+        }
+
+        for (identifier, recorded_value) in &recorded {
+            let replayed_value = written.get(identifier).copied();
+            let is_equal = replayed_value.is_some_and(|replayed_value| {
+                (replayed_value.is_nan() && recorded_value.is_nan())
+                    || (replayed_value - recorded_value).abs() <= self.tolerance
+            });
+
+            if !is_equal {
+                report.mismatches.push(ReplayMismatch {
+                    tick: tick_index,
+                    variable: registry.name_of(identifier),
+                    recorded: Some(*recorded_value),
+                    replayed: replayed_value,
+                });
+            }
+        }
+
+        for (identifier, replayed_value) in written {
+            if !recorded.contains_key(identifier) {
+                report.mismatches.push(ReplayMismatch {
+                    tick: tick_index,
+                    variable: registry.name_of(identifier),
+                    recorded: None,
+                    replayed: Some(*replayed_value),
+                });
+            }
+        }
+    }
+}
+
+fn parse_field<T: std::str::FromStr>(field: Option<&str>, line_number: usize) -> io::Result<T> {
+    field
+        .and_then(|field| field.trim().parse().ok())
+        .ok_or_else(|| invalid_data(line_number, "malformed field"))
+}
+
+fn invalid_data(line_number: usize, message: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("line {}: {}", line_number + 1, message),
+    )
+}
+
+#[derive(Default)]
+struct ReplayVariableRegistry {
+    name_to_identifier: FxHashMap<String, VariableIdentifier>,
+    next_identifier: VariableIdentifier,
+}
+impl ReplayVariableRegistry {
+    fn name_of(&self, identifier: &VariableIdentifier) -> String {
+        self.name_to_identifier
+            .iter()
+            .find(|(_, id)| *id == identifier)
+            .map(|(name, _)| name.clone())
+            .unwrap_or_default()
+    }
+}
+impl VariableRegistry for ReplayVariableRegistry {
+    fn get(&mut self, name: String) -> VariableIdentifier {
+        *self.name_to_identifier.entry(name).or_insert_with(|| {
+            let identifier = self.next_identifier;
+            self.next_identifier = identifier.next();
+
+            identifier
+        })
+    }
+}
+
+/// Provides the recorded values to the simulation and captures the values written by it.
+#[derive(Default)]
+struct ReplayReaderWriter {
+    variables: FxHashMap<VariableIdentifier, f64>,
+    written: FxHashMap<VariableIdentifier, f64>,
+}
+impl SimulatorReaderWriter for ReplayReaderWriter {
+    fn read(&mut self, identifier: &VariableIdentifier) -> f64 {
+        *self.variables.get(identifier).unwrap_or(&0.)
+    }
+
+    fn write(&mut self, identifier: &VariableIdentifier, value: f64) {
+        self.variables.insert(*identifier, value);
+        self.written.insert(*identifier, value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        failures::Failure,
+        shared::random_from_range,
+        simulation::{
+            test::TestVariableRegistry, Read, SimulationElement, SimulationElementVisitor,
+            SimulatorReader, SimulatorWriter, UpdateContext, Write,
+        },
+    };
+    use ntest::assert_about_eq;
+
+    const FAILURES: [(u64, FailureType); 2] = [
+        (24_000, FailureType::TransformerRectifier(1)),
+        (24_001, FailureType::TransformerRectifier(2)),
+    ];
+
+    struct TestAircraft {
+        input_id: VariableIdentifier,
+        output_id: VariableIdentifier,
+        failed_id: VariableIdentifier,
+        factor: f64,
+        input: f64,
+        output: f64,
+        failure: Failure,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext, factor: f64) -> Self {
+            Self {
+                input_id: context.get_identifier("INPUT".to_owned()),
+                output_id: context.get_identifier("OUTPUT".to_owned()),
+                failed_id: context.get_identifier("IS_FAILED".to_owned()),
+                factor,
+                input: 0.,
+                output: 0.,
+                failure: Failure::new(FailureType::TransformerRectifier(1)),
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            // The noise only replays identically when the simulation is seeded as recorded.
+            self.output += self.input * self.factor * context.delta_as_secs_f64()
+                + random_from_range(0., 0.01);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.failure.accept(visitor);
+
+            visitor.visit(self);
+        }
+
+        fn read(&mut self, reader: &mut SimulatorReader) {
+            self.input = reader.read(&self.input_id);
+        }
+
+        fn write(&self, writer: &mut SimulatorWriter) {
+            writer.write(&self.output_id, self.output);
+            writer.write(&self.failed_id, self.failure.is_active());
+        }
+    }
+
+    #[derive(Default)]
+    struct TestReaderWriter {
+        variables: FxHashMap<VariableIdentifier, f64>,
+    }
+    impl SimulatorReaderWriter for TestReaderWriter {
+        fn read(&mut self, identifier: &VariableIdentifier) -> f64 {
+            *self.variables.get(identifier).unwrap_or(&0.)
+        }
+
+        fn write(&mut self, identifier: &VariableIdentifier, value: f64) {
+            self.variables.insert(*identifier, value);
+        }
+    }
+
+    fn record_session() -> Vec<u8> {
+        let mut registry = TestVariableRegistry::default();
+        let mut recorder =
+            SimulationRecorder::new(Vec::new(), StartState::Apron, 42).with_failures(FAILURES);
+        let mut simulation = Simulation::new(
+            StartState::Apron,
+            42,
+            |context| TestAircraft::new(context, 2.),
+            &mut recorder.registry(&mut registry),
+        );
+        let input_id = registry.get("INPUT".to_owned());
+
+        let mut reader_writer = TestReaderWriter::default();
+        for i in 0..10 {
+            reader_writer.write(&input_id, i as f64 * 0.7);
+            if i == 5 {
+                let mut failures = FxHashSet::default();
+                failures.insert(FailureType::TransformerRectifier(1));
+                recorder.update_active_failures(&mut simulation, failures);
+            }
+
+            recorder.tick(
+                &mut simulation,
+                Duration::from_millis(33 + i),
+                10. + i as f64,
+                &mut reader_writer,
+            );
+        }
+
+        recorder.finish().unwrap()
+    }
+
+    #[test]
+    fn recording_contains_header_and_variable_names() {
+        let recording = String::from_utf8(record_session()).unwrap();
+
+        assert!(recording.starts_with("H,2,2,42\n"));
+        assert!(recording.contains(",INPUT\n"));
+        assert!(recording.contains(",OUTPUT\n"));
+        assert!(recording.contains("F,24000\n"));
+    }
+
+    #[test]
+    fn parses_recorded_session() {
+        let replay = SimulationReplay::parse(record_session().as_slice()).unwrap();
+
+        assert_eq!(replay.start_state(), StartState::Apron);
+        assert_eq!(replay.random_seed(), 42);
+        assert_eq!(replay.ticks(), 10);
+        assert_about_eq!(replay.ticks[3].delta.as_secs_f64(), 0.036);
+        assert_about_eq!(replay.ticks[3].simulation_time, 13.);
+    }
+
+    #[test]
+    fn replay_of_unchanged_aircraft_is_identical() {
+        let replay = SimulationReplay::parse(record_session().as_slice())
+            .unwrap()
+            .with_failures(FAILURES);
+
+        let report = replay.run(|context| TestAircraft::new(context, 2.));
+
+        assert_eq!(report.ticks(), 10);
+        assert!(report.is_identical(), "{:?}", report.mismatches());
+    }
+
+    #[test]
+    fn replay_without_failures_reports_failure_output_mismatch() {
+        let replay = SimulationReplay::parse(record_session().as_slice()).unwrap();
+
+        let report = replay.run(|context| TestAircraft::new(context, 2.));
+
+        assert!(!report.is_identical());
+        assert!(report
+            .mismatches()
+            .iter()
+            .all(|mismatch| mismatch.variable == "IS_FAILED" && mismatch.tick >= 5));
+    }
+
+    #[test]
+    fn replay_of_changed_aircraft_reports_mismatches() {
+        let replay = SimulationReplay::parse(record_session().as_slice())
+            .unwrap()
+            .with_failures(FAILURES);
+
+        let report = replay.run(|context| TestAircraft::new(context, 3.));
+
+        assert!(!report.is_identical());
+        assert!(report
+            .mismatches()
+            .iter()
+            .all(|mismatch| mismatch.variable == "OUTPUT"));
+    }
+
+    #[test]
+    fn replay_within_tolerance_is_identical() {
+        let replay = SimulationReplay::parse(record_session().as_slice())
+            .unwrap()
+            .with_failures(FAILURES)
+            .with_tolerance(1.);
+
+        let report = replay.run(|context| TestAircraft::new(context, 2.1));
+
+        assert!(report.is_identical());
+    }
+
+    #[test]
+    fn parsing_recording_without_header_fails() {
+        assert!(SimulationReplay::parse("T,0.1,1\n".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn parsing_recording_with_undeclared_variable_fails() {
+        assert!(SimulationReplay::parse("H,2,6,0\nT,0.1,1\nR,0,1\n".as_bytes()).is_err());
+    }
+}
@@ -0,0 +1,375 @@
+//! Snapshots of the persistent state of a [`Simulation`].
+//!
+//! Some state outlives a single session, such as the charge of a battery or the temperature of
+//! the brakes. Elements save such state through [`SimulationElement::save_state`] and restore
+//! it through [`SimulationElement::restore_state`]. Both use the element's own variable
+//! identifiers, such that the snapshot can store the state under the variable's name.
+//!
+//! In the simulator, the snapshot is restored when the aircraft is loaded and saved when it is
+//! unloaded (see `MsfsSimulationBuilder::with_snapshot`). The systems runner restores and saves
+//! snapshots through its command line options.
+//!
+//! The snapshot file consists of comma separated lines, each starting with a record type:
+//! - `S,<version>`: header, always the first line.
+//! - `P,<name>,<value>`: the persisted value of the variable with the given name.
+//!
+//! Values of variables which are no longer used by the aircraft are ignored when restoring,
+//! and elements keep their initial state for values missing from the snapshot.
+//!
+//! [`Simulation`]: ../struct.Simulation.html
+//! [`SimulationElement::save_state`]: ../trait.SimulationElement.html#method.save_state
+//! [`SimulationElement::restore_state`]: ../trait.SimulationElement.html#method.restore_state
+use std::{
+    collections::BTreeMap,
+    fs::File,
+    io::{self, BufRead, BufReader, BufWriter, Write},
+    path::Path,
+};
+
+use fxhash::FxHashMap;
+
+use super::{
+    Read, Reader, SimulationElement, SimulationElementVisitor, VariableIdentifier, Writer,
+};
+
+const FORMAT_VERSION: u32 = 1;
+
+/// The persistent state of a [`Simulation`], by variable name.
+///
+/// [`Simulation`]: ../struct.Simulation.html
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SimulationSnapshot {
+    values: BTreeMap<String, f64>,
+}
+impl SimulationSnapshot {
+    /// Opens the snapshot at the given path.
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::parse(BufReader::new(File::open(path)?))
+    }
+
+    /// Parses a snapshot as written by [`write_to`].
+    ///
+    /// [`write_to`]: #method.write_to
+    pub fn parse<R: BufRead>(input: R) -> io::Result<Self> {
+        let mut snapshot = Self::default();
+        for (line_number, line) in input.lines().enumerate() {
+            let line = line?;
+            let (record_type, fields) = line.split_once(',').unwrap_or((&line, ""));
+
+            match (line_number, record_type) {
+                (0, "S") => {
+                    let version: u32 = parse_field(Some(fields), line_number)?;
+                    if version != FORMAT_VERSION {
+                        return Err(invalid_data(
+                            line_number,
+                            &format!("unsupported snapshot version {}", version),
+                        ));
+                    }
+                }
+                (0, _) => return Err(invalid_data(line_number, "missing snapshot header")),
+                (_, "P") => {
+                    let (name, value) = fields
+                        .rsplit_once(',')
+                        .ok_or_else(|| invalid_data(line_number, "malformed value"))?;
+                    snapshot
+                        .values
+                        .insert(name.to_owned(), parse_field(Some(value), line_number)?);
+                }
+                _ => {
+                    return Err(invalid_data(
+                        line_number,
+                        &format!("unknown record type '{}'", record_type),
+                    ))
+                }
+            }
+        }
+
+        Ok(snapshot)
+    }
+
+    /// Saves the snapshot to the file at the given path.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let mut output = BufWriter::new(File::create(path)?);
+        self.write_to(&mut output)?;
+        output.flush()
+    }
+
+    pub fn write_to<W: Write>(&self, output: &mut W) -> io::Result<()> {
+        writeln!(output, "S,{}", FORMAT_VERSION)?;
+        for (name, value) in &self.values {
+            writeln!(output, "P,{},{}", name, value)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn value(&self, name: &str) -> Option<f64> {
+        self.values.get(name).copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub(super) fn from_identifiers(
+        values: FxHashMap<VariableIdentifier, f64>,
+        variable_names: &FxHashMap<VariableIdentifier, String>,
+    ) -> Self {
+        Self {
+            values: values
+                .into_iter()
+                .filter_map(|(identifier, value)| {
+                    variable_names
+                        .get(&identifier)
+                        .map(|name| (name.clone(), value))
+                })
+                .collect(),
+        }
+    }
+
+    pub(super) fn to_identifiers(
+        &self,
+        variable_names: &FxHashMap<VariableIdentifier, String>,
+    ) -> FxHashMap<VariableIdentifier, f64> {
+        variable_names
+            .iter()
+            .filter_map(|(identifier, name)| self.value(name).map(|value| (*identifier, value)))
+            .collect()
+    }
+}
+
+/// Writes the persistent state of elements into a snapshot.
+pub struct SnapshotWriter<'a> {
+    values: &'a mut FxHashMap<VariableIdentifier, f64>,
+}
+impl<'a> SnapshotWriter<'a> {
+    fn new(values: &'a mut FxHashMap<VariableIdentifier, f64>) -> Self {
+        Self { values }
+    }
+}
+impl Writer for SnapshotWriter<'_> {
+    fn write_f64(&mut self, identifier: &VariableIdentifier, value: f64) {
+        self.values.insert(*identifier, value);
+    }
+}
+
+/// Reads the persistent state of elements from a snapshot.
+pub struct SnapshotReader<'a> {
+    values: &'a FxHashMap<VariableIdentifier, f64>,
+}
+impl<'a> SnapshotReader<'a> {
+    fn new(values: &'a FxHashMap<VariableIdentifier, f64>) -> Self {
+        Self { values }
+    }
+
+    /// Reads the value saved for the given identifier, or `None` when
+    /// the snapshot doesn't contain a value for it.
+    pub fn restore<T: Copy>(&mut self, identifier: &VariableIdentifier) -> Option<T>
+    where
+        Self: Read<T>,
+    {
+        if self.values.contains_key(identifier) {
+            Some(Read::<T>::read(self, identifier))
+        } else {
+            None
+        }
+    }
+}
+impl Reader for SnapshotReader<'_> {
+    fn read_f64(&mut self, identifier: &VariableIdentifier) -> f64 {
+        self.values.get(identifier).copied().unwrap_or_default()
+    }
+}
+
+pub(super) struct SaveStateVisitor<'a> {
+    writer: SnapshotWriter<'a>,
+}
+impl<'a> SaveStateVisitor<'a> {
+    pub fn new(values: &'a mut FxHashMap<VariableIdentifier, f64>) -> Self {
+        Self {
+            writer: SnapshotWriter::new(values),
+        }
+    }
+}
+impl SimulationElementVisitor for SaveStateVisitor<'_> {
+    fn visit<T: SimulationElement>(&mut self, visited: &mut T) {
+        visited.save_state(&mut self.writer);
+    }
+}
+
+pub(super) struct RestoreStateVisitor<'a> {
+    reader: SnapshotReader<'a>,
+}
+impl<'a> RestoreStateVisitor<'a> {
+    pub fn new(values: &'a FxHashMap<VariableIdentifier, f64>) -> Self {
+        Self {
+            reader: SnapshotReader::new(values),
+        }
+    }
+}
+impl SimulationElementVisitor for RestoreStateVisitor<'_> {
+    fn visit<T: SimulationElement>(&mut self, visited: &mut T) {
+        visited.restore_state(&mut self.reader);
+    }
+}
+
+fn parse_field<T: std::str::FromStr>(field: Option<&str>, line_number: usize) -> io::Result<T> {
+    field
+        .and_then(|field| field.trim().parse().ok())
+        .ok_or_else(|| invalid_data(line_number, "malformed field"))
+}
+
+fn invalid_data(line_number: usize, message: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("line {}: {}", line_number + 1, message),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::{
+        test::TestVariableRegistry, Aircraft, InitContext, Simulation, SimulatorReaderWriter,
+        StartState, UpdateContext, Write,
+    };
+    use ntest::assert_about_eq;
+    use std::time::Duration;
+
+    struct TestAircraft {
+        charge_id: VariableIdentifier,
+        charge: f64,
+        unsaved: f64,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                charge_id: context.get_identifier("CHARGE".to_owned()),
+                charge: 10.,
+                unsaved: 1.,
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.charge -= context.delta_as_secs_f64();
+            self.unsaved += context.delta_as_secs_f64();
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn save_state(&self, writer: &mut SnapshotWriter) {
+            writer.write(&self.charge_id, self.charge);
+        }
+
+        fn restore_state(&mut self, reader: &mut SnapshotReader) {
+            if let Some(charge) = reader.restore(&self.charge_id) {
+                self.charge = charge;
+            }
+        }
+    }
+
+    #[derive(Default)]
+    struct TestReaderWriter {}
+    impl SimulatorReaderWriter for TestReaderWriter {
+        fn read(&mut self, _: &VariableIdentifier) -> f64 {
+            0.
+        }
+
+        fn write(&mut self, _: &VariableIdentifier, _: f64) {}
+    }
+
+    fn simulation(snapshot: Option<&SimulationSnapshot>) -> Simulation<TestAircraft> {
+        let mut registry = TestVariableRegistry::default();
+        match snapshot {
+            Some(snapshot) => Simulation::new_from_snapshot(
+                StartState::Apron,
+                0,
+                TestAircraft::new,
+                &mut registry,
+                snapshot,
+            ),
+            None => Simulation::new(StartState::Apron, 0, TestAircraft::new, &mut registry),
+        }
+    }
+
+    fn snapshot_after_ticks(ticks: u32) -> SimulationSnapshot {
+        let mut simulation = simulation(None);
+        for _ in 0..ticks {
+            simulation.tick(Duration::from_secs(1), 0., &mut TestReaderWriter::default());
+        }
+
+        simulation.snapshot()
+    }
+
+    #[test]
+    fn snapshot_contains_saved_state_by_name() {
+        let snapshot = snapshot_after_ticks(3);
+
+        assert_eq!(snapshot.len(), 1);
+        assert_about_eq!(snapshot.value("CHARGE").unwrap(), 7.);
+        assert_eq!(snapshot.value("UNSAVED"), None);
+    }
+
+    #[test]
+    fn simulation_from_snapshot_restores_saved_state() {
+        let snapshot = snapshot_after_ticks(3);
+
+        let simulation = simulation(Some(&snapshot));
+
+        assert_about_eq!(simulation.aircraft().charge, 7.);
+        assert_about_eq!(simulation.aircraft().unsaved, 1.);
+    }
+
+    #[test]
+    fn state_missing_from_snapshot_keeps_initial_value() {
+        let simulation = simulation(Some(&SimulationSnapshot::default()));
+
+        assert_about_eq!(simulation.aircraft().charge, 10.);
+    }
+
+    #[test]
+    fn unknown_variables_are_ignored_when_restoring() {
+        let snapshot =
+            SimulationSnapshot::parse("S,1\nP,REMOVED,5\nP,CHARGE,3\n".as_bytes()).unwrap();
+
+        let simulation = simulation(Some(&snapshot));
+
+        assert_about_eq!(simulation.aircraft().charge, 3.);
+    }
+
+    #[test]
+    fn written_snapshot_can_be_parsed() {
+        let snapshot = snapshot_after_ticks(2);
+        let mut output = Vec::new();
+        snapshot.write_to(&mut output).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output.clone()).unwrap(),
+            "S,1\nP,CHARGE,8\n"
+        );
+        assert_eq!(
+            SimulationSnapshot::parse(output.as_slice()).unwrap(),
+            snapshot
+        );
+    }
+
+    #[test]
+    fn parsing_snapshot_without_header_fails() {
+        assert!(SimulationSnapshot::parse("P,CHARGE,3\n".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn parsing_snapshot_of_other_version_fails() {
+        assert!(SimulationSnapshot::parse("S,2\nP,CHARGE,3\n".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn parsing_malformed_value_fails() {
+        assert!(SimulationSnapshot::parse("S,1\nP,CHARGE\n".as_bytes()).is_err());
+        assert!(SimulationSnapshot::parse("S,1\nP,CHARGE,abc\n".as_bytes()).is_err());
+    }
+}
@@ -1,5 +1,5 @@
 use fxhash::{FxHashMap, FxHashSet};
-use rand::Rng;
+use rand::{rngs::SmallRng, Rng, SeedableRng};
 use std::{cell::Ref, time::Duration};
 use uom::si::{
     acceleration::foot_per_second_squared,
@@ -20,14 +20,13 @@ use crate::{
 
 use super::{
     Aircraft, Read, Reader, Simulation, SimulationElement, SimulationElementVisitor,
-    SimulationToSimulatorVisitor, SimulatorReaderWriter, SimulatorWriter, UpdateContext, Write,
-    Writer,
+    SimulatorReaderWriter, UpdateContext, Write, Writer,
 };
 use crate::landing_gear::LandingGear;
 use crate::shared::arinc429::{from_arinc429, to_arinc429, Arinc429Word, SignStatus};
 use crate::simulation::update_context::Delta;
 use crate::simulation::{
-    DeltaContext, InitContext, StartState, VariableIdentifier, VariableRegistry,
+    DeltaContext, InitContext, SimulationSnapshot, StartState, VariableIdentifier, VariableRegistry,
 };
 
 pub trait TestBed {
@@ -58,6 +57,10 @@ pub trait TestBed {
         self.test_bed_mut().fail(failure_type);
     }
 
+    fn snapshot(&mut self) -> SimulationSnapshot {
+        self.test_bed_mut().snapshot()
+    }
+
     fn unfail(&mut self, failure_type: FailureType) {
         self.test_bed_mut().unfail(failure_type);
     }
@@ -238,11 +241,15 @@ where
 /// [`SimulationElement`]: ../trait.SimulationElement.html
 pub struct SimulationTestBed<T: Aircraft> {
     reader_writer: TestReaderWriter,
+    frame_delta_rng: SmallRng,
     simulation: Simulation<T>,
     variable_registry: TestVariableRegistry,
     failures: FxHashSet<FailureType>,
 }
 impl<T: Aircraft> SimulationTestBed<T> {
+    /// The seed of the simulation's random source, such that each run of a test is identical.
+    pub const RANDOM_SEED: u64 = 1;
+
     pub fn new<U: FnOnce(&mut InitContext) -> T>(aircraft_ctor_fn: U) -> Self {
         Self::new_with_start_state(Default::default(), aircraft_ctor_fn)
     }
@@ -252,9 +259,43 @@ impl<T: Aircraft> SimulationTestBed<T> {
         aircraft_ctor_fn: U,
     ) -> Self {
         let mut variable_registry = TestVariableRegistry::default();
+        let simulation = Simulation::new(
+            start_state,
+            Self::RANDOM_SEED,
+            aircraft_ctor_fn,
+            &mut variable_registry,
+        );
+
+        Self::new_with_simulation(simulation, variable_registry)
+    }
+
+    /// Creates an instance of which the aircraft's persistent state is restored from
+    /// the given snapshot. Use this to start a test from a state reached in another test bed.
+    pub fn new_from_snapshot<U: FnOnce(&mut InitContext) -> T>(
+        start_state: StartState,
+        aircraft_ctor_fn: U,
+        snapshot: &SimulationSnapshot,
+    ) -> Self {
+        let mut variable_registry = TestVariableRegistry::default();
+        let simulation = Simulation::new_from_snapshot(
+            start_state,
+            Self::RANDOM_SEED,
+            aircraft_ctor_fn,
+            &mut variable_registry,
+            snapshot,
+        );
+
+        Self::new_with_simulation(simulation, variable_registry)
+    }
+
+    fn new_with_simulation(
+        simulation: Simulation<T>,
+        variable_registry: TestVariableRegistry,
+    ) -> Self {
         let mut test_bed = Self {
             reader_writer: TestReaderWriter::new(),
-            simulation: Simulation::new(start_state, aircraft_ctor_fn, &mut variable_registry),
+            frame_delta_rng: SmallRng::seed_from_u64(Self::RANDOM_SEED),
+            simulation,
             variable_registry,
             failures: FxHashSet::default(),
         };
@@ -278,9 +319,7 @@ impl<T: Aircraft> SimulationTestBed<T> {
     /// (e.g. `OnOffFaultPushButton::new_on` would be a push button which initially
     /// is ON).
     fn seed(&mut self) {
-        let mut writer = SimulatorWriter::new(&mut self.reader_writer);
-        let mut visitor = SimulationToSimulatorVisitor::new(&mut writer);
-        self.simulation.accept(&mut visitor);
+        self.simulation.write_state(&mut self.reader_writer);
     }
 
     /// Runs a single 1 second duration [Simulation] tick on the contained [Aircraft].
@@ -301,12 +340,10 @@ impl<T: Aircraft> SimulationTestBed<T> {
     /// [`Aircraft`]: ../trait.Aircraft.html
     /// [`Simulation`]: ../struct.Simulation.html
     pub fn run_multiple_frames(&mut self, delta: Duration) {
-        let mut rng = rand::rng();
-
         let mut executed_duration = Duration::from_secs(0);
         while executed_duration < delta {
             // Randomly set delta for 12 to 200ms, giving a simulated 83 to 5 fps refresh
-            let current_delta = Duration::from_millis(rng.random_range(12..200));
+            let current_delta = Duration::from_millis(self.frame_delta_rng.random_range(12..200));
 
             if executed_duration + current_delta > delta {
                 self.simulation.tick(
@@ -326,6 +363,10 @@ impl<T: Aircraft> SimulationTestBed<T> {
         }
     }
 
+    pub fn snapshot(&mut self) -> SimulationSnapshot {
+        self.simulation.snapshot()
+    }
+
     fn fail(&mut self, failure_type: FailureType) {
         self.failures.insert(failure_type);
         self.simulation
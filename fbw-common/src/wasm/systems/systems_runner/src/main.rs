@@ -0,0 +1,224 @@
+//! Runs the A320 or A380 system simulation outside of the simulator.
+//!
+//! The runner executes a scenario of timed variable writes and failure activations against the
+//! aircraft at a fixed delta, and writes the selected output variables as CSV. The persistent
+//! state of the aircraft can be restored from and saved to a snapshot file.
+mod runner;
+mod scenario;
+
+use std::{
+    env,
+    error::Error,
+    fs::File,
+    io::{self, BufWriter, Write},
+    time::Duration,
+};
+
+use a320_systems::{A320, A320_FAILURES};
+use a380_systems::{A380, A380_FAILURES};
+use runner::{RunOptions, Runner};
+use scenario::Scenario;
+use systems::{
+    engine::physical_model::EngineSimulationMode,
+    failures::FailureType,
+    simulation::{Aircraft, InitContext, SimulationSnapshot, StartState},
+};
+
+const USAGE: &str = "\
+Usage: systems_runner <a320|a380> <scenario file> [options]
+
+Options:
+  --start-state <state>   hangar, apron, taxi, runway, climb, cruise, approach or final (default: apron)
+  --seed <n>              seed of the simulation's random numbers (default: 0)
+  --engine-mode <mode>    simulator or physical, where the engines' spools are computed by the
+                          systems instead of read from the simulator (default: simulator)
+  --delta <ms>            fixed duration of each simulation tick (default: 50)
+  --duration <s>          duration of the run (default: time of the last scenario event)
+  --sample <s>            interval between output rows (default: 1)
+  --var <name>            variable to write to the output, can be repeated
+  --output <file>         file to write the CSV to (default: standard output)
+  --restore <file>        snapshot to restore the persistent aircraft state from
+  --save-snapshot <file>  file to save the persistent aircraft state to at the end of the run";
+
+#[derive(Clone, Copy)]
+enum AircraftType {
+    A320,
+    A380,
+}
+
+struct Arguments {
+    aircraft: AircraftType,
+    scenario: String,
+    start_state: StartState,
+    random_seed: u64,
+    engine_mode: EngineSimulationMode,
+    delta: Duration,
+    duration: Option<Duration>,
+    sample_interval: Duration,
+    output_variables: Vec<String>,
+    output: Option<String>,
+    restore: Option<String>,
+    save_snapshot: Option<String>,
+}
+impl Arguments {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, Box<dyn Error>> {
+        let aircraft = match args.next().as_deref() {
+            Some("a320") => AircraftType::A320,
+            Some("a380") => AircraftType::A380,
+            _ => return Err("expected aircraft 'a320' or 'a380'".into()),
+        };
+        let scenario = args.next().ok_or("expected a scenario file")?;
+
+        let mut arguments = Self {
+            aircraft,
+            scenario,
+            start_state: StartState::Apron,
+            random_seed: 0,
+            engine_mode: EngineSimulationMode::Simulator,
+            delta: Duration::from_millis(50),
+            duration: None,
+            sample_interval: Duration::from_secs(1),
+            output_variables: vec![],
+            output: None,
+            restore: None,
+            save_snapshot: None,
+        };
+
+        while let Some(option) = args.next() {
+            let value = args
+                .next()
+                .ok_or_else(|| format!("option '{}' requires a value", option))?;
+
+            match option.as_str() {
+                "--start-state" => arguments.start_state = Self::parse_start_state(&value)?,
+                "--seed" => arguments.random_seed = value.parse()?,
+                "--engine-mode" => arguments.engine_mode = Self::parse_engine_mode(&value)?,
+                "--delta" => arguments.delta = Duration::from_millis(value.parse()?),
+                "--duration" => arguments.duration = Some(Duration::from_secs_f64(value.parse()?)),
+                "--sample" => arguments.sample_interval = Duration::from_secs_f64(value.parse()?),
+                "--var" => arguments.output_variables.push(value),
+                "--output" => arguments.output = Some(value),
+                "--restore" => arguments.restore = Some(value),
+                "--save-snapshot" => arguments.save_snapshot = Some(value),
+                _ => return Err(format!("unknown option '{}'", option).into()),
+            }
+        }
+
+        Ok(arguments)
+    }
+
+    fn parse_start_state(value: &str) -> Result<StartState, Box<dyn Error>> {
+        Ok(match value {
+            "hangar" => StartState::Hangar,
+            "apron" => StartState::Apron,
+            "taxi" => StartState::Taxi,
+            "runway" => StartState::Runway,
+            "climb" => StartState::Climb,
+            "cruise" => StartState::Cruise,
+            "approach" => StartState::Approach,
+            "final" => StartState::Final,
+            _ => return Err(format!("unknown start state '{}'", value).into()),
+        })
+    }
+
+    fn parse_engine_mode(value: &str) -> Result<EngineSimulationMode, Box<dyn Error>> {
+        Ok(match value {
+            "simulator" => EngineSimulationMode::Simulator,
+            "physical" => EngineSimulationMode::Physical,
+            _ => return Err(format!("unknown engine mode '{}'", value).into()),
+        })
+    }
+}
+
+fn main() {
+    if let Err(error) = run() {
+        eprintln!("{}\n\n{}", error, USAGE);
+        std::process::exit(1);
+    }
+}
+
+fn run() -> Result<(), Box<dyn Error>> {
+    let arguments = Arguments::parse(env::args().skip(1))?;
+    let scenario = Scenario::from_file(&arguments.scenario)?;
+    let options = RunOptions {
+        delta: arguments.delta,
+        duration: arguments.duration.unwrap_or_else(|| scenario.end_time()),
+        sample_interval: arguments.sample_interval,
+        output_variables: arguments.output_variables,
+    };
+
+    let mut output: Box<dyn Write> = match &arguments.output {
+        Some(path) => Box::new(BufWriter::new(File::create(path)?)),
+        None => Box::new(BufWriter::new(io::stdout().lock())),
+    };
+
+    let snapshot = arguments
+        .restore
+        .as_ref()
+        .map(SimulationSnapshot::open)
+        .transpose()?;
+
+    match arguments.aircraft {
+        AircraftType::A320 => run_aircraft(
+            runner(
+                arguments.start_state,
+                arguments.random_seed,
+                |context| A320::with_engine_mode(context, arguments.engine_mode),
+                A320_FAILURES,
+                &snapshot,
+            ),
+            &scenario,
+            &options,
+            arguments.save_snapshot.as_deref(),
+            &mut output,
+        ),
+        AircraftType::A380 => run_aircraft(
+            runner(
+                arguments.start_state,
+                arguments.random_seed,
+                |context| A380::with_engine_mode(context, arguments.engine_mode),
+                A380_FAILURES,
+                &snapshot,
+            ),
+            &scenario,
+            &options,
+            arguments.save_snapshot.as_deref(),
+            &mut output,
+        ),
+    }
+}
+
+fn runner<T: Aircraft, U: FnOnce(&mut InitContext) -> T>(
+    start_state: StartState,
+    random_seed: u64,
+    aircraft_ctor_fn: U,
+    failures: &[(u64, FailureType)],
+    snapshot: &Option<SimulationSnapshot>,
+) -> Runner<T> {
+    match snapshot {
+        Some(snapshot) => Runner::new_from_snapshot(
+            start_state,
+            random_seed,
+            aircraft_ctor_fn,
+            failures,
+            snapshot,
+        ),
+        None => Runner::new(start_state, random_seed, aircraft_ctor_fn, failures),
+    }
+}
+
+fn run_aircraft<T: Aircraft>(
+    mut runner: Runner<T>,
+    scenario: &Scenario,
+    options: &RunOptions,
+    save_snapshot: Option<&str>,
+    output: &mut impl Write,
+) -> Result<(), Box<dyn Error>> {
+    runner.run(scenario, options, output)?;
+
+    if let Some(path) = save_snapshot {
+        runner.snapshot().save(path)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,487 @@
+use std::{error::Error, io::Write, time::Duration};
+
+use fxhash::{FxHashMap, FxHashSet};
+use systems::{
+    failures::FailureType,
+    simulation::{
+        Aircraft, InitContext, Simulation, SimulationSnapshot, SimulatorReaderWriter, StartState,
+        VariableIdentifier, VariableRegistry,
+    },
+};
+
+use crate::scenario::{Scenario, ScenarioAction};
+
+/// Values the simulator would ordinarily provide, in the units the simulation expects them in.
+/// Scenarios can override any of these by setting them at `t=0s`.
+const DEFAULT_ENVIRONMENT: [(&str, f64); 7] = [
+    ("IS_READY", 1.),
+    ("AMBIENT PRESSURE", 29.92),
+    ("SEA LEVEL PRESSURE", 1013.25),
+    ("AMBIENT TEMPERATURE", 15.),
+    ("TOTAL AIR TEMPERATURE", 15.),
+    ("AMBIENT DENSITY", 0.0023769),
+    ("UNLIMITED FUEL", 0.),
+];
+const DEFAULT_IN_FLIGHT_ENVIRONMENT: [(&str, f64); 5] = [
+    ("AIRSPEED INDICATED", 250.),
+    ("AIRSPEED TRUE", 270.),
+    ("PRESSURE ALTITUDE", 5000.),
+    ("INDICATED ALTITUDE", 5000.),
+    ("PLANE ALT ABOVE GROUND", 5000.),
+];
+const GEAR_CONTACT_POINTS: usize = 6;
+const ON_GROUND_GEAR_COMPRESSION_PERCENT: f64 = 95.;
+
+pub struct RunOptions {
+    pub delta: Duration,
+    pub duration: Duration,
+    pub sample_interval: Duration,
+    pub output_variables: Vec<String>,
+}
+
+/// Runs a [`Simulation`] outside of the simulator, using an in memory store of variables
+/// in place of the simulator.
+pub struct Runner<T: Aircraft> {
+    simulation: Simulation<T>,
+    registry: RunnerVariableRegistry,
+    reader_writer: RunnerReaderWriter,
+    identifier_to_failure_type: FxHashMap<u64, FailureType>,
+    active_failures: FxHashSet<FailureType>,
+}
+impl<T: Aircraft> Runner<T> {
+    pub fn new<U: FnOnce(&mut InitContext) -> T>(
+        start_state: StartState,
+        random_seed: u64,
+        aircraft_ctor_fn: U,
+        failures: &[(u64, FailureType)],
+    ) -> Self {
+        let mut registry = RunnerVariableRegistry::default();
+        let simulation = Simulation::new(start_state, random_seed, aircraft_ctor_fn, &mut registry);
+
+        Self::new_with_simulation(start_state, simulation, registry, failures)
+    }
+
+    /// Creates a runner of which the aircraft continues from the persistent state
+    /// stored in the given snapshot.
+    pub fn new_from_snapshot<U: FnOnce(&mut InitContext) -> T>(
+        start_state: StartState,
+        random_seed: u64,
+        aircraft_ctor_fn: U,
+        failures: &[(u64, FailureType)],
+        snapshot: &SimulationSnapshot,
+    ) -> Self {
+        let mut registry = RunnerVariableRegistry::default();
+        let simulation = Simulation::new_from_snapshot(
+            start_state,
+            random_seed,
+            aircraft_ctor_fn,
+            &mut registry,
+            snapshot,
+        );
+
+        Self::new_with_simulation(start_state, simulation, registry, failures)
+    }
+
+    fn new_with_simulation(
+        start_state: StartState,
+        simulation: Simulation<T>,
+        registry: RunnerVariableRegistry,
+        failures: &[(u64, FailureType)],
+    ) -> Self {
+        let mut runner = Self {
+            simulation,
+            registry,
+            reader_writer: RunnerReaderWriter::default(),
+            identifier_to_failure_type: failures.iter().copied().collect(),
+            active_failures: FxHashSet::default(),
+        };
+
+        runner.simulation.write_state(&mut runner.reader_writer);
+        runner.write_default_environment(start_state);
+
+        runner
+    }
+
+    /// Runs the scenario with a fixed delta, writing the output variables as CSV.
+    /// A row is written at the start and whenever the sample interval elapsed.
+    pub fn run(
+        &mut self,
+        scenario: &Scenario,
+        options: &RunOptions,
+        output: &mut impl Write,
+    ) -> Result<(), Box<dyn Error>> {
+        if options.delta.is_zero() {
+            return Err("the delta must be greater than zero".into());
+        }
+
+        self.verify_failures(scenario)?;
+
+        let output_identifiers: Vec<VariableIdentifier> = options
+            .output_variables
+            .iter()
+            .map(|name| self.registry.get(name.clone()))
+            .collect();
+        writeln!(output, "time,{}", options.output_variables.join(","))?;
+
+        let mut time = Duration::ZERO;
+        let mut next_sample_time = Duration::ZERO;
+        let mut events = scenario.events().iter().peekable();
+        loop {
+            while let Some(event) = events.next_if(|event| event.time() <= time) {
+                self.execute(event.action());
+            }
+
+            if time >= next_sample_time {
+                self.write_sample(time, &output_identifiers, output)?;
+                next_sample_time += options.sample_interval.max(options.delta);
+            }
+
+            if time >= options.duration {
+                break;
+            }
+
+            time += options.delta;
+            self.simulation
+                .tick(options.delta, time.as_secs_f64(), &mut self.reader_writer);
+        }
+
+        output.flush()?;
+
+        Ok(())
+    }
+
+    pub fn snapshot(&mut self) -> SimulationSnapshot {
+        self.simulation.snapshot()
+    }
+
+    fn verify_failures(&self, scenario: &Scenario) -> Result<(), Box<dyn Error>> {
+        for event in scenario.events() {
+            if let ScenarioAction::Fail(id) | ScenarioAction::Unfail(id) = event.action() {
+                if !self.identifier_to_failure_type.contains_key(id) {
+                    return Err(
+                        format!("unknown failure identifier {} for this aircraft", id).into(),
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn execute(&mut self, action: &ScenarioAction) {
+        match action {
+            ScenarioAction::Set(name, value) => {
+                let identifier = self.registry.get(name.clone());
+                self.reader_writer.write(&identifier, *value);
+            }
+            ScenarioAction::Fail(id) => {
+                self.active_failures
+                    .insert(self.identifier_to_failure_type[id]);
+                self.simulation
+                    .update_active_failures(self.active_failures.clone());
+            }
+            ScenarioAction::Unfail(id) => {
+                self.active_failures
+                    .remove(&self.identifier_to_failure_type[id]);
+                self.simulation
+                    .update_active_failures(self.active_failures.clone());
+            }
+        }
+    }
+
+    fn write_default_environment(&mut self, start_state: StartState) {
+        let is_on_ground = matches!(
+            start_state,
+            StartState::Hangar | StartState::Apron | StartState::Taxi | StartState::Runway
+        );
+
+        let mut environment = DEFAULT_ENVIRONMENT.to_vec();
+        if is_on_ground {
+            environment.push(("SIM ON GROUND", 1.));
+        } else {
+            environment.extend(DEFAULT_IN_FLIGHT_ENVIRONMENT);
+        }
+
+        for (name, value) in environment {
+            let identifier = self.registry.get(name.to_owned());
+            self.reader_writer.write(&identifier, value);
+        }
+
+        if is_on_ground {
+            for index in 0..GEAR_CONTACT_POINTS {
+                let name = if index == 0 {
+                    "CONTACT POINT COMPRESSION".to_owned()
+                } else {
+                    format!("CONTACT POINT COMPRESSION:{}", index)
+                };
+                let identifier = self.registry.get(name);
+                self.reader_writer
+                    .write(&identifier, ON_GROUND_GEAR_COMPRESSION_PERCENT);
+            }
+        }
+    }
+
+    fn write_sample(
+        &mut self,
+        time: Duration,
+        identifiers: &[VariableIdentifier],
+        output: &mut impl Write,
+    ) -> Result<(), Box<dyn Error>> {
+        write!(output, "{}", time.as_secs_f64())?;
+        for identifier in identifiers {
+            write!(output, ",{}", self.reader_writer.read(identifier))?;
+        }
+        writeln!(output)?;
+
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct RunnerVariableRegistry {
+    name_to_identifier: FxHashMap<String, VariableIdentifier>,
+    next_identifier: VariableIdentifier,
+}
+impl VariableRegistry for RunnerVariableRegistry {
+    fn get(&mut self, name: String) -> VariableIdentifier {
+        *self.name_to_identifier.entry(name).or_insert_with(|| {
+            let identifier = self.next_identifier;
+            self.next_identifier = identifier.next();
+
+            identifier
+        })
+    }
+}
+
+#[derive(Default)]
+struct RunnerReaderWriter {
+    variables: FxHashMap<VariableIdentifier, f64>,
+}
+impl SimulatorReaderWriter for RunnerReaderWriter {
+    fn read(&mut self, identifier: &VariableIdentifier) -> f64 {
+        *self.variables.get(identifier).unwrap_or(&0.)
+    }
+
+    fn write(&mut self, identifier: &VariableIdentifier, value: f64) {
+        self.variables.insert(*identifier, value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use a320_systems::{A320, A320_FAILURES};
+    use a380_systems::{A380, A380_FAILURES};
+    use ntest::assert_about_eq;
+    use systems::{
+        engine::physical_model::EngineSimulationMode,
+        simulation::recording::{SimulationRecorder, SimulationReplay},
+    };
+
+    fn run<T: Aircraft>(mut runner: Runner<T>, scenario: &str, variables: &[&str]) -> String {
+        let mut output = Vec::new();
+        runner
+            .run(
+                &scenario.parse().unwrap(),
+                &RunOptions {
+                    delta: Duration::from_millis(100),
+                    duration: Duration::from_secs(5),
+                    sample_interval: Duration::from_secs(1),
+                    output_variables: variables.iter().map(|name| name.to_string()).collect(),
+                },
+                &mut output,
+            )
+            .unwrap();
+
+        String::from_utf8(output).unwrap()
+    }
+
+    fn column(csv: &str, row: usize, column: usize) -> f64 {
+        csv.lines()
+            .nth(row)
+            .unwrap()
+            .split(',')
+            .nth(column)
+            .unwrap()
+            .parse()
+            .unwrap()
+    }
+
+    #[test]
+    fn writes_header_and_a_row_per_sample() {
+        let csv = run(
+            Runner::new(StartState::Apron, 0, A320::new, A320_FAILURES),
+            "",
+            &["ELEC_DC_BAT_BUS_IS_POWERED"],
+        );
+
+        assert_eq!(csv.lines().next(), Some("time,ELEC_DC_BAT_BUS_IS_POWERED"));
+        assert_eq!(csv.lines().count(), 7);
+        assert_eq!(column(&csv, 6, 0), 5.);
+    }
+
+    #[test]
+    fn a320_ac_bus_is_powered_once_external_power_is_connected() {
+        let csv = run(
+            Runner::new(StartState::Apron, 0, A320::new, A320_FAILURES),
+            "t=2s set EXT_PWR_AVAIL:1=1\nt=2s set OVHD_ELEC_EXT_PWR_PB_IS_AVAILABLE=1\nt=2s set OVHD_ELEC_EXT_PWR_PB_IS_ON=1",
+            &["ELEC_AC_1_BUS_IS_POWERED"],
+        );
+
+        assert_eq!(column(&csv, 1, 1), 0.);
+        assert_eq!(column(&csv, 6, 1), 1.);
+    }
+
+    #[test]
+    fn a380_runs_scenario() {
+        let csv = run(
+            Runner::new(StartState::Apron, 0, A380::new, A380_FAILURES),
+            "t=1s set OVHD_ELEC_BAT_1_PB_IS_AUTO=1",
+            &["ELEC_DC_HOT_1_BUS_IS_POWERED"],
+        );
+
+        assert_eq!(csv.lines().count(), 7);
+    }
+
+    #[test]
+    fn a320_engines_windmill_in_physical_mode() {
+        let csv = run(
+            Runner::new(
+                StartState::Cruise,
+                0,
+                |context| A320::with_engine_mode(context, EngineSimulationMode::Physical),
+                A320_FAILURES,
+            ),
+            "",
+            &["ENGINE_MODEL_N2:1"],
+        );
+
+        assert!(column(&csv, 6, 1) > column(&csv, 1, 1));
+    }
+
+    #[test]
+    fn continues_from_snapshot() {
+        let mut runner = Runner::new(StartState::Apron, 0, A320::new, A320_FAILURES);
+        let initial_charge = runner.snapshot().value("ELEC_BAT_1_CHARGE").unwrap();
+        runner
+            .run(
+                &"t=0s set OVHD_ELEC_BAT_1_PB_IS_AUTO=1".parse().unwrap(),
+                &RunOptions {
+                    delta: Duration::from_millis(100),
+                    duration: Duration::from_secs(60),
+                    sample_interval: Duration::from_secs(60),
+                    output_variables: vec![],
+                },
+                &mut Vec::new(),
+            )
+            .unwrap();
+        let snapshot = runner.snapshot();
+        let charge = snapshot.value("ELEC_BAT_1_CHARGE").unwrap();
+        assert!(charge < initial_charge);
+
+        let mut runner =
+            Runner::new_from_snapshot(StartState::Apron, 0, A320::new, A320_FAILURES, &snapshot);
+
+        assert_about_eq!(
+            runner.snapshot().value("ELEC_BAT_1_CHARGE").unwrap(),
+            charge
+        );
+    }
+
+    #[test]
+    fn runs_with_the_same_seed_are_identical() {
+        let scenario =
+            "t=0s set OVHD_ELEC_BAT_1_PB_IS_AUTO=1\nt=0s set OVHD_ELEC_BAT_2_PB_IS_AUTO=1";
+        let variables = ["ELEC_BAT_1_CURRENT", "ELEC_BAT_2_CURRENT"];
+
+        assert_eq!(
+            run(
+                Runner::new(StartState::Apron, 7, A320::new, A320_FAILURES),
+                scenario,
+                &variables
+            ),
+            run(
+                Runner::new(StartState::Apron, 7, A320::new, A320_FAILURES),
+                scenario,
+                &variables
+            )
+        );
+    }
+
+    #[test]
+    fn a320_replay_of_recorded_session_is_identical() {
+        let mut registry = RunnerVariableRegistry::default();
+        let mut recorder = SimulationRecorder::new(Vec::new(), StartState::Apron, 3)
+            .with_failures(A320_FAILURES.iter().copied());
+        let mut simulation = Simulation::new(
+            StartState::Apron,
+            3,
+            A320::new,
+            &mut recorder.registry(&mut registry),
+        );
+
+        let mut reader_writer = RunnerReaderWriter::default();
+        for (name, value) in DEFAULT_ENVIRONMENT.into_iter().chain([
+            ("SIM ON GROUND", 1.),
+            ("OVHD_ELEC_BAT_1_PB_IS_AUTO", 1.),
+            ("OVHD_ELEC_BAT_2_PB_IS_AUTO", 1.),
+        ]) {
+            reader_writer.write(&registry.get(name.to_owned()), value);
+        }
+
+        for i in 1..=50 {
+            if i == 25 {
+                recorder.update_active_failures(
+                    &mut simulation,
+                    [FailureType::TransformerRectifier(1)].into_iter().collect(),
+                );
+            }
+
+            recorder.tick(
+                &mut simulation,
+                Duration::from_millis(100),
+                i as f64 * 0.1,
+                &mut reader_writer,
+            );
+        }
+
+        let recording = recorder.finish().unwrap();
+        let report = SimulationReplay::parse(recording.as_slice())
+            .unwrap()
+            .with_failures(A320_FAILURES.iter().copied())
+            .run(A320::new);
+
+        assert_eq!(report.ticks(), 50);
+        assert!(report.is_identical(), "{:?}", report.mismatches());
+    }
+
+    #[test]
+    fn activates_failures() {
+        let mut runner = Runner::new(StartState::Apron, 0, A320::new, A320_FAILURES);
+        runner.execute(&ScenarioAction::Fail(24_020));
+
+        assert!(runner.active_failures.contains(&FailureType::Generator(1)));
+
+        runner.execute(&ScenarioAction::Unfail(24_020));
+
+        assert!(runner.active_failures.is_empty());
+    }
+
+    #[test]
+    fn rejects_unknown_failure() {
+        let mut runner = Runner::new(StartState::Apron, 0, A320::new, A320_FAILURES);
+
+        assert!(runner
+            .run(
+                &"t=1s fail 1".parse().unwrap(),
+                &RunOptions {
+                    delta: Duration::from_millis(100),
+                    duration: Duration::from_secs(1),
+                    sample_interval: Duration::from_secs(1),
+                    output_variables: vec![],
+                },
+                &mut Vec::new(),
+            )
+            .is_err());
+    }
+}
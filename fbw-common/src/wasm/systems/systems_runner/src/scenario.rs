@@ -0,0 +1,254 @@
+use std::{error::Error, fmt, fs, path::Path, time::Duration};
+
+/// An action which is executed at a given time within a [`Scenario`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum ScenarioAction {
+    /// Writes the value to the variable with the given name.
+    Set(String, f64),
+    /// Activates the failure with the given identifier.
+    Fail(u64),
+    /// Deactivates the failure with the given identifier.
+    Unfail(u64),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScenarioEvent {
+    time: Duration,
+    action: ScenarioAction,
+}
+impl ScenarioEvent {
+    pub fn time(&self) -> Duration {
+        self.time
+    }
+
+    pub fn action(&self) -> &ScenarioAction {
+        &self.action
+    }
+}
+
+/// A list of timed variable writes and failure (de)activations.
+///
+/// Each non-empty line of a scenario file has the form `t=<time> <action>`, where the time
+/// is given in milliseconds (`ms`), seconds (`s`) or minutes (`min`). Supported actions are:
+/// - `set <variable name>=<value>`
+/// - `fail <failure identifier>`
+/// - `unfail <failure identifier>`
+///
+/// Everything following a `#` is a comment. For example:
+/// ```text
+/// # Battery power up
+/// t=0s set OVHD_ELEC_BAT_1_PB_IS_AUTO=1
+/// t=30s fail 24020
+/// ```
+#[derive(Debug, Default, PartialEq)]
+pub struct Scenario {
+    events: Vec<ScenarioEvent>,
+}
+impl Scenario {
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn Error>> {
+        Ok(fs::read_to_string(path)?.parse()?)
+    }
+
+    /// The events ordered by time. Events with the same time retain their order of definition.
+    pub fn events(&self) -> &[ScenarioEvent] {
+        &self.events
+    }
+
+    pub fn end_time(&self) -> Duration {
+        self.events
+            .last()
+            .map(|event| event.time)
+            .unwrap_or_default()
+    }
+
+    fn parse_line(line: &str) -> Result<Option<ScenarioEvent>, String> {
+        let line = match line.split_once('#') {
+            Some((content, _)) => content,
+            None => line,
+        }
+        .trim();
+
+        if line.is_empty() {
+            return Ok(None);
+        }
+
+        let (time, action) = line
+            .split_once(char::is_whitespace)
+            .ok_or("expected '<time> <action>'")?;
+        let time = Self::parse_time(time)?;
+
+        let action = action.trim();
+        let (keyword, argument) = action
+            .split_once(char::is_whitespace)
+            .unwrap_or((action, ""));
+        let argument = argument.trim();
+        let action = match keyword {
+            "set" => {
+                let (name, value) = argument
+                    .rsplit_once('=')
+                    .ok_or("expected 'set <variable>=<value>'")?;
+                let value = value
+                    .trim()
+                    .parse()
+                    .map_err(|_| format!("invalid value '{}'", value.trim()))?;
+
+                ScenarioAction::Set(name.trim().to_owned(), value)
+            }
+            "fail" => ScenarioAction::Fail(Self::parse_failure_identifier(argument)?),
+            "unfail" => ScenarioAction::Unfail(Self::parse_failure_identifier(argument)?),
+            _ => return Err(format!("unknown action '{}'", keyword)),
+        };
+
+        Ok(Some(ScenarioEvent { time, action }))
+    }
+
+    fn parse_time(time: &str) -> Result<Duration, String> {
+        let value = time
+            .strip_prefix("t=")
+            .ok_or_else(|| format!("expected time as 't=<time>', found '{}'", time))?;
+
+        let (number, seconds_per_unit) = if let Some(number) = value.strip_suffix("ms") {
+            (number, 0.001)
+        } else if let Some(number) = value.strip_suffix("min") {
+            (number, 60.)
+        } else if let Some(number) = value.strip_suffix('s') {
+            (number, 1.)
+        } else {
+            return Err(format!("time '{}' lacks a unit (ms, s or min)", value));
+        };
+
+        match number.parse::<f64>() {
+            Ok(number) if number >= 0. => Ok(Duration::from_secs_f64(number * seconds_per_unit)),
+            _ => Err(format!("invalid time '{}'", value)),
+        }
+    }
+
+    fn parse_failure_identifier(identifier: &str) -> Result<u64, String> {
+        identifier
+            .replace('_', "")
+            .parse()
+            .map_err(|_| format!("invalid failure identifier '{}'", identifier))
+    }
+}
+impl std::str::FromStr for Scenario {
+    type Err = ScenarioParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut events = vec![];
+        for (index, line) in s.lines().enumerate() {
+            match Self::parse_line(line) {
+                Ok(Some(event)) => events.push(event),
+                Ok(None) => {}
+                Err(message) => {
+                    return Err(ScenarioParseError {
+                        line: index + 1,
+                        message,
+                    })
+                }
+            }
+        }
+
+        // A stable sort keeps events with equal times in their order of definition.
+        events.sort_by_key(|event| event.time);
+
+        Ok(Self { events })
+    }
+}
+
+#[derive(Debug)]
+pub struct ScenarioParseError {
+    line: usize,
+    message: String,
+}
+impl fmt::Display for ScenarioParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "scenario line {}: {}", self.line, self.message)
+    }
+}
+impl Error for ScenarioParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(scenario: &str) -> Scenario {
+        scenario.parse().unwrap()
+    }
+
+    #[test]
+    fn parses_set_action() {
+        let scenario = parse("t=30s set OVHD_ELEC_BAT_1_PB_IS_AUTO=1");
+
+        assert_eq!(
+            scenario.events(),
+            &[ScenarioEvent {
+                time: Duration::from_secs(30),
+                action: ScenarioAction::Set("OVHD_ELEC_BAT_1_PB_IS_AUTO".to_owned(), 1.)
+            }]
+        );
+    }
+
+    #[test]
+    fn parses_set_action_for_variable_name_containing_spaces() {
+        let scenario = parse("t=0s set AMBIENT TEMPERATURE = -12.5");
+
+        assert_eq!(
+            scenario.events()[0].action(),
+            &ScenarioAction::Set("AMBIENT TEMPERATURE".to_owned(), -12.5)
+        );
+    }
+
+    #[test]
+    fn parses_failure_actions() {
+        let scenario = parse("t=1s fail 24_020\nt=2s unfail 24020");
+
+        assert_eq!(scenario.events()[0].action(), &ScenarioAction::Fail(24020));
+        assert_eq!(
+            scenario.events()[1].action(),
+            &ScenarioAction::Unfail(24020)
+        );
+    }
+
+    #[test]
+    fn parses_time_units() {
+        let scenario = parse("t=500ms fail 1\nt=1.5s fail 2\nt=2min fail 3");
+
+        assert_eq!(scenario.events()[0].time(), Duration::from_millis(500));
+        assert_eq!(scenario.events()[1].time(), Duration::from_millis(1500));
+        assert_eq!(scenario.events()[2].time(), Duration::from_secs(120));
+    }
+
+    #[test]
+    fn ignores_comments_and_empty_lines() {
+        let scenario = parse("# Cold and dark\n\n  t=1s fail 1 # GEN 1\n");
+
+        assert_eq!(scenario.events().len(), 1);
+    }
+
+    #[test]
+    fn orders_events_by_time_retaining_definition_order() {
+        let scenario = parse("t=10s fail 1\nt=5s fail 2\nt=10s fail 3");
+
+        assert_eq!(scenario.events()[0].action(), &ScenarioAction::Fail(2));
+        assert_eq!(scenario.events()[1].action(), &ScenarioAction::Fail(1));
+        assert_eq!(scenario.events()[2].action(), &ScenarioAction::Fail(3));
+        assert_eq!(scenario.end_time(), Duration::from_secs(10));
+    }
+
+    #[test]
+    fn reports_line_of_invalid_entry() {
+        let error = "t=1s fail 1\nt=2 fail 2".parse::<Scenario>().unwrap_err();
+
+        assert_eq!(error.line, 2);
+    }
+
+    #[test]
+    fn rejects_unknown_action() {
+        assert!("t=1s explode".parse::<Scenario>().is_err());
+    }
+
+    #[test]
+    fn rejects_set_without_value() {
+        assert!("t=1s set A32NX_SOMETHING".parse::<Scenario>().is_err());
+    }
+}
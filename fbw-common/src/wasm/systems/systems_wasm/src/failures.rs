@@ -12,6 +12,12 @@ impl Failures {
         self.identifier_to_failure_type.extend(failures);
     }
 
+    pub(super) fn identified_failures(&self) -> impl Iterator<Item = (u64, FailureType)> + '_ {
+        self.identifier_to_failure_type
+            .iter()
+            .map(|(id, failure_type)| (*id, *failure_type))
+    }
+
     pub(super) fn handle_failure_update(&mut self, data: &str) {
         let visitor = FailureIdVisitor(&self.identifier_to_failure_type);
         self.active_failures =
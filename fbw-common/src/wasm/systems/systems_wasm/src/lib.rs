@@ -26,14 +26,18 @@ use failures::Failures;
 use fxhash::FxHashMap;
 use std::cell::RefCell;
 use std::fmt::{Display, Formatter};
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::PathBuf;
 use std::rc::Rc;
 use std::{error::Error, time::Duration};
-use systems::shared::ElectricalBusType;
+use systems::shared::{entropy_seed, ElectricalBusType};
 use systems::simulation::{InitContext, StartState};
 use systems::{
     failures::FailureType,
     simulation::{
-        Aircraft, Simulation, SimulatorReaderWriter, VariableIdentifier, VariableRegistry,
+        recording::SimulationRecorder, Aircraft, Simulation, SimulationSnapshot,
+        SimulatorReaderWriter, VariableIdentifier, VariableRegistry,
     },
 };
 
@@ -42,9 +46,12 @@ use systems::{
 pub struct MsfsSimulationBuilder<'a, 'b> {
     variable_registry: Option<MsfsVariableRegistry>,
     start_state: StartState,
+    random_seed: u64,
     sim_connect: &'a mut SimConnect<'b>,
     failures: Failures,
     aspects: Vec<Box<dyn Aspect>>,
+    snapshot_path: Option<PathBuf>,
+    recording_path: Option<PathBuf>,
 }
 
 impl<'a, 'b> MsfsSimulationBuilder<'a, 'b> {
@@ -58,9 +65,12 @@ impl<'a, 'b> MsfsSimulationBuilder<'a, 'b> {
         Self {
             variable_registry: Some(MsfsVariableRegistry::new(key_prefix.into())),
             start_state: start_state_variable_value.read().into(),
+            random_seed: entropy_seed(),
             sim_connect,
             failures: Failures::default(),
             aspects: vec![],
+            snapshot_path: None,
+            recording_path: None,
         }
     }
 
@@ -69,14 +79,90 @@ impl<'a, 'b> MsfsSimulationBuilder<'a, 'b> {
         aircraft_ctor_fn: U,
     ) -> Result<(Simulation<T>, MsfsHandler), Box<dyn Error>> {
         let mut registry = self.variable_registry.unwrap();
-        let simulation = Simulation::new(self.start_state, aircraft_ctor_fn, &mut registry);
+        let mut recorder = match &self.recording_path {
+            Some(path) => Some(
+                SimulationRecorder::create(path, self.start_state, self.random_seed)?
+                    .with_failures(self.failures.identified_failures()),
+            ),
+            None => None,
+        };
+        let simulation = match &mut recorder {
+            Some(recorder) => Self::create_simulation(
+                self.start_state,
+                self.random_seed,
+                self.snapshot_path.as_ref(),
+                aircraft_ctor_fn,
+                &mut recorder.registry(&mut registry),
+            ),
+            None => Self::create_simulation(
+                self.start_state,
+                self.random_seed,
+                self.snapshot_path.as_ref(),
+                aircraft_ctor_fn,
+                &mut registry,
+            ),
+        };
 
         Ok((
             simulation,
-            MsfsHandler::new(registry, self.aspects, self.failures, self.sim_connect)?,
+            MsfsHandler::new(
+                registry,
+                self.aspects,
+                self.failures,
+                self.snapshot_path,
+                recorder,
+                self.sim_connect,
+            )?,
         ))
     }
 
+    fn create_simulation<T: Aircraft, U: FnOnce(&mut InitContext) -> T>(
+        start_state: StartState,
+        random_seed: u64,
+        snapshot_path: Option<&PathBuf>,
+        aircraft_ctor_fn: U,
+        registry: &mut impl VariableRegistry,
+    ) -> Simulation<T> {
+        // Without a snapshot, such as on the very first flight, the aircraft starts from its
+        // initial state.
+        match snapshot_path.and_then(|path| SimulationSnapshot::open(path).ok()) {
+            Some(snapshot) => Simulation::new_from_snapshot(
+                start_state,
+                random_seed,
+                aircraft_ctor_fn,
+                registry,
+                &snapshot,
+            ),
+            None => Simulation::new(start_state, random_seed, aircraft_ctor_fn, registry),
+        }
+    }
+
+    /// Restores the persistent state of the aircraft, such as the charge of the batteries,
+    /// from the snapshot at the given path when it exists. The snapshot is saved to the same
+    /// path when the simulation is unloaded, such that the next flight continues from it.
+    pub fn with_snapshot(mut self, path: &str) -> Self {
+        self.snapshot_path = Some(path.into());
+        self
+    }
+
+    /// Records every value read from and written to the simulator, together with the active
+    /// failures, into a file at the given path. The recording is finished when the simulation
+    /// is unloaded and can be replayed using a `SimulationReplay`.
+    ///
+    /// A recording replays identically only when the same random seed is used,
+    /// thus consider combining this with [`with_random_seed`](Self::with_random_seed).
+    pub fn with_recording(mut self, path: &str) -> Self {
+        self.recording_path = Some(path.into());
+        self
+    }
+
+    /// Seeds the simulation's random numbers with the given seed, such that a flight can be
+    /// repeated exactly. By default a seed taken from the operating system is used.
+    pub fn with_random_seed(mut self, seed: u64) -> Self {
+        self.random_seed = seed;
+        self
+    }
+
     /// Adds an aspect. An aspect is a concern that should be handled by the bridging layer.
     /// The function passed to this method is used to configure the aspect.
     pub fn with_aspect<T: FnOnce(&mut MsfsAspectBuilder) -> Result<(), Box<dyn Error>>>(
@@ -181,12 +267,16 @@ pub struct MsfsHandler {
     failures: Rc<RefCell<Failures>>,
     _commbus: CommBus<'static>,
     time: Time,
+    snapshot_path: Option<PathBuf>,
+    recorder: Option<SimulationRecorder<BufWriter<File>>>,
 }
 impl MsfsHandler {
     fn new(
         variables: MsfsVariableRegistry,
         aspects: Vec<Box<dyn Aspect>>,
         failures: Failures,
+        snapshot_path: Option<PathBuf>,
+        recorder: Option<SimulationRecorder<BufWriter<File>>>,
         sim_connect: &mut SimConnect,
     ) -> Result<Self, Box<dyn Error>> {
         let failures = Rc::new(RefCell::new(failures));
@@ -204,6 +294,8 @@ impl MsfsHandler {
             failures,
             _commbus: commbus,
             time: Time::new(sim_connect)?,
+            snapshot_path,
+            recorder,
         })
     }
 
@@ -218,9 +310,24 @@ impl MsfsHandler {
                 if !self.time.is_pausing() {
                     let delta_time = self.time.take();
                     self.pre_tick(sim_connect, delta_time)?;
-                    self.read_failures_into_simulation(simulation);
 
-                    simulation.tick(delta_time, self.time.simulation_time(), self);
+                    if let Some(mut recorder) = self.recorder.take() {
+                        if let Some(active_failures) =
+                            self.failures.borrow_mut().get_updated_active_failures()
+                        {
+                            recorder.update_active_failures(simulation, active_failures);
+                        }
+
+                        let simulation_time = self.time.simulation_time();
+                        recorder.tick(simulation, delta_time, simulation_time, self);
+
+                        self.recorder = Some(recorder);
+                    } else {
+                        self.read_failures_into_simulation(simulation);
+
+                        simulation.tick(delta_time, self.time.simulation_time(), self);
+                    }
+
                     self.post_tick(sim_connect)?;
                 }
             }
@@ -233,6 +340,15 @@ impl MsfsHandler {
                     self.handle_message(&message);
                 }
             },
+            MSFSEvent::PreKill => {
+                if let Some(path) = &self.snapshot_path {
+                    simulation.snapshot().save(path)?;
+                }
+
+                if let Some(recorder) = self.recorder.take() {
+                    recorder.finish()?;
+                }
+            }
             _ => {}
         }
 